@@ -0,0 +1,105 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use futures::TryStreamExt;
+use twitch_api::helix::{
+    chat::GetChattersRequest, BorrowedResponse, HelixClient, Request, RequestGet,
+};
+
+fn chatters_body() -> Vec<u8> {
+    let chatters = (0..1000)
+        .map(|i| {
+            format!(
+                r#"{{"user_id":"{}","user_login":"chatter{i}","user_name":"Chatter{i}"}}"#,
+                100_000_000 + i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    format!(r#"{{"data":[{chatters}],"pagination":{{"cursor":"eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6NX19"}},"total":1000}}"#)
+        .into_bytes()
+}
+
+fn parse_chatters(c: &mut Criterion) {
+    let req = GetChattersRequest::new("1234", "4321").first(1000);
+    let uri = req.get_uri().unwrap();
+    let body = twitch_api::client::Bytes::from(chatters_body());
+
+    let mut group = c.benchmark_group("parse 1000 chatters");
+    group.bench_function("owned", |b| {
+        b.iter(|| {
+            let response = http::Response::new(body.clone());
+            GetChattersRequest::parse_response(Some(req.clone()), &uri, black_box(response))
+                .unwrap()
+        })
+    });
+    group.bench_function("borrowed", |b| {
+        b.iter(|| {
+            let response = http::Response::new(body.clone());
+            let response =
+                BorrowedResponse::parse_response(Some(req.clone()), &uri, black_box(response))
+                    .unwrap();
+            black_box(response.data().unwrap().data.len())
+        })
+    });
+    group.finish();
+}
+
+/// Answers every request with the same body
+#[derive(Clone)]
+struct Replay(twitch_api::client::Bytes);
+
+impl twitch_api::HttpClient for Replay {
+    type Error = std::convert::Infallible;
+
+    fn req(
+        &self,
+        _: twitch_api::client::Request,
+    ) -> twitch_api::client::BoxedFuture<'_, Result<twitch_api::client::Response, Self::Error>>
+    {
+        let body = self.0.clone();
+        Box::pin(async move { Ok(http::Response::new(body)) })
+    }
+}
+
+/// Compare with `--features stream-parse` to see the difference of parsing pages item by item
+fn stream_chatters(c: &mut Criterion) {
+    let body = chatters_body();
+    // a single page, so the stream ends after it
+    let body = String::from_utf8(body)
+        .unwrap()
+        .replace(r#""cursor":"eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6NX19""#, "");
+    let client = HelixClient::with_client(Replay(body.into()));
+    let token = twitch_oauth2::UserToken::from_existing_unchecked(
+        twitch_oauth2::AccessToken::new("totallyvalidtoken".to_string()),
+        None,
+        twitch_oauth2::ClientId::new("validclientid".to_string()),
+        None,
+        "justintv".into(),
+        "1337".into(),
+        None,
+        None,
+    );
+
+    let mut group = c.benchmark_group("stream 1000 chatters");
+    group.bench_function("make_stream", |b| {
+        b.iter(|| {
+            let req = GetChattersRequest::new("1234", "4321").first(1000);
+            let stream = twitch_api::helix::make_stream(
+                req,
+                &token,
+                &client,
+                std::collections::VecDeque::from,
+            );
+            futures::executor::block_on(stream.try_collect::<Vec<_>>()).unwrap()
+        })
+    });
+    group.bench_function("get_chatters", |b| {
+        b.iter(|| {
+            let stream = client.get_chatters("1234", "4321", 1000, &token);
+            futures::executor::block_on(stream.try_collect::<Vec<_>>()).unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse_chatters, stream_chatters);
+criterion_main!(benches);
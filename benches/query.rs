@@ -0,0 +1,15 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use twitch_api::helix::{users::GetUsersRequest, Request};
+
+fn get_users_uri(c: &mut Criterion) {
+    let ids: Vec<String> = (0..100).map(|i| (141981764 + i * 7).to_string()).collect();
+    let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+    let req = GetUsersRequest::ids(&ids[..]);
+
+    c.bench_function("GetUsersRequest::get_uri with 100 ids", |b| {
+        b.iter(|| black_box(&req).get_uri().unwrap())
+    });
+}
+
+criterion_group!(benches, get_users_uri);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+//! Resolve a channel and post an announcement in it
+//!
+//! Runs against Twitch with a user token, or against the [twitch-cli](https://github.com/twitchdev/twitch-cli) mock servers with `--mock`.
+mod common;
+
+use common::{chat, Args, Error};
+use twitch_api::HelixClient;
+use twitch_oauth2::Scope;
+
+const USAGE: &str = "\
+Usage: chat_announcement [--mock] [--token <token>] [--channel <login>] --announce <message> [--color <color>]
+
+  --mock                use the twitch-cli mock servers instead of Twitch, needs feature mock_api
+  --token <token>       user access token, defaults to env TWITCH_TOKEN
+  --channel <login>     channel to send the announcement to, defaults to the user of the token
+  --announce <message>  the announcement to send
+  --color <color>       color of the announcement, defaults to primary";
+
+fn main() {
+    if let Err(err) = run() {
+        common::report(err.as_ref());
+        std::process::exit(1);
+    }
+}
+
+#[tokio::main]
+async fn run() -> Result<(), Error> {
+    let _ = dotenvy::dotenv(); // Eat error
+    let args = Args::parse(USAGE)?;
+    let message = args
+        .announce
+        .as_deref()
+        .ok_or_else(|| format!("missing --announce\n\n{USAGE}"))?;
+    args.target.apply();
+
+    let client: HelixClient<reqwest::Client> = HelixClient::default();
+    let token = common::user_token(
+        &client,
+        args.target,
+        args.token.clone(),
+        vec![Scope::ModeratorManageAnnouncements],
+    )
+    .await?;
+
+    let channel = chat::resolve_channel(&client, args.channel.as_deref(), &token).await?;
+    chat::announce(&client, &channel, message, &args.color, &token).await
+}
@@ -0,0 +1,62 @@
+//! A small bot that resolves a channel, lists its chatters, posts an announcement and prints chat messages received over EventSub WebSocket
+//!
+//! Runs against Twitch with a user token, or against the [twitch-cli](https://github.com/twitchdev/twitch-cli) mock servers with `--mock`.
+//! Press ctrl-c to stop at any point.
+//!
+//! Each step is also its own example, see `channel_chatters`, `chat_announcement` and `eventsub_chat`.
+mod common;
+
+use common::{chat, Args, Error, Shutdown};
+use twitch_api::HelixClient;
+use twitch_oauth2::Scope;
+
+const USAGE: &str = "\
+Usage: chat_bot [--mock] [--token <token>] [--channel <login>] [--announce <message>] [--color <color>] [--events <amount>]
+
+  --mock                use the twitch-cli mock servers instead of Twitch, needs feature mock_api
+  --token <token>       user access token, defaults to env TWITCH_TOKEN
+  --channel <login>     channel to join, defaults to the user of the token
+  --announce <message>  send an announcement to the channel
+  --color <color>       color of the announcement, defaults to primary
+  --events <amount>     stop after this many chat messages, defaults to running until ctrl-c";
+
+fn main() {
+    if let Err(err) = run() {
+        common::report(err.as_ref());
+        std::process::exit(1);
+    }
+}
+
+#[tokio::main]
+async fn run() -> Result<(), Error> {
+    let _ = dotenvy::dotenv(); // Eat error
+    let args = Args::parse(USAGE)?;
+    args.target.apply();
+
+    let client: HelixClient<reqwest::Client> = HelixClient::default();
+    let token = common::user_token(
+        &client,
+        args.target,
+        args.token.clone(),
+        vec![
+            Scope::ModeratorReadChatters,
+            Scope::ModeratorManageAnnouncements,
+            Scope::UserReadChat,
+        ],
+    )
+    .await?;
+    let shutdown = Shutdown::on_ctrl_c();
+
+    let channel = chat::resolve_channel(&client, args.channel.as_deref(), &token).await?;
+
+    chat::list_chatters(&client, &channel, &token, shutdown.clone()).await?;
+    if shutdown.is_requested() {
+        return Ok(());
+    }
+
+    if let Some(message) = &args.announce {
+        chat::announce(&client, &channel, message, &args.color, &token).await?;
+    }
+
+    chat::listen(&client, &channel, args.events, &token, shutdown).await
+}
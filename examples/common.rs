@@ -0,0 +1,199 @@
+//! Token and mock plumbing shared by the examples, include it with `mod common;`
+//!
+//! The steps of the chat examples are in [`chat`].
+#![allow(dead_code)]
+
+#[path = "common_chat.rs"]
+pub mod chat;
+
+use twitch_api::HelixClient;
+use twitch_oauth2::{Scope, TwitchToken, UserToken};
+
+pub type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Location of the twitch-cli mock api, started with `twitch mock-api start`
+pub const MOCK_API_URL: &str = "http://localhost:8080";
+/// Location of the twitch-cli EventSub WebSocket server, started with `twitch event websocket start-server --port 8081`
+pub const MOCK_EVENTSUB_WEBSOCKET_URL: &str = "ws://127.0.0.1:8081/ws";
+
+/// Where the example sends its requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    /// The Twitch API, using a token from `--token` or `TWITCH_TOKEN`
+    Twitch,
+    /// The [twitch-cli](https://github.com/twitchdev/twitch-cli) mock servers, using a token for `MOCK_USER_ID`
+    ///
+    /// Needs the `mock_api` feature.
+    Mock,
+}
+
+/// Arguments shared by the examples
+pub struct Args {
+    /// `--mock`
+    pub target: Target,
+    /// `--token <token>`
+    pub token: Option<String>,
+    /// `--channel <login>`
+    pub channel: Option<String>,
+    /// `--announce <message>`
+    pub announce: Option<String>,
+    /// `--color <color>`, defaults to `primary`
+    pub color: String,
+    /// `--events <amount>`
+    pub events: Option<usize>,
+}
+
+impl Args {
+    /// Parse the arguments of the example, only accepting the ones listed in `usage`
+    ///
+    /// Prints `usage` and exits for `-h` or `--help`.
+    pub fn parse(usage: &str) -> Result<Self, Error> {
+        let mut parsed = Args {
+            target: Target::Twitch,
+            token: None,
+            channel: None,
+            announce: None,
+            color: "primary".to_owned(),
+            events: None,
+        };
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            if arg == "-h" || arg == "--help" {
+                println!("{usage}");
+                std::process::exit(0);
+            }
+            if !arg.starts_with("--") || !usage.contains(&format!("{arg} ")) {
+                return Err(format!("unknown argument {arg:?}\n\n{usage}").into());
+            }
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("missing value for {arg}\n\n{usage}"))
+            };
+            match arg.as_str() {
+                "--mock" => parsed.target = Target::Mock,
+                "--token" => parsed.token = Some(value()?),
+                "--channel" => parsed.channel = Some(value()?),
+                "--announce" => parsed.announce = Some(value()?),
+                "--color" => parsed.color = value()?,
+                "--events" => parsed.events = Some(value()?.parse()?),
+                other => return Err(format!("unknown argument {other:?}\n\n{usage}").into()),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+impl Target {
+    /// Point the Helix, OAuth2 and EventSub WebSocket urls to the mock servers.
+    ///
+    /// Urls already set in the environment are kept. This has to be called before the first request is made, as the urls are only read once.
+    pub fn apply(self) {
+        if self != Target::Mock {
+            return;
+        }
+        for (var, url) in [
+            ("TWITCH_HELIX_URL", format!("{MOCK_API_URL}/mock/")),
+            ("TWITCH_OAUTH2_URL", format!("{MOCK_API_URL}/auth/")),
+            (
+                "TWITCH_EVENTSUB_WEBSOCKET_URL",
+                MOCK_EVENTSUB_WEBSOCKET_URL.to_owned(),
+            ),
+        ] {
+            if std::env::var_os(var).is_none() {
+                std::env::set_var(var, url);
+            }
+        }
+    }
+}
+
+/// Get a user token for `target`
+///
+/// For [`Target::Twitch`], `token` or the `TWITCH_TOKEN` environment variable is validated and checked for `scopes`.
+/// For [`Target::Mock`], a token with `scopes` is requested from the mock auth server for `MOCK_USER_ID`,
+/// with the client from `MOCK_CLIENT_ID` and `MOCK_CLIENT_SECRET`. These are printed by `twitch mock-api generate`.
+/// Without the `mock_api` feature, [`Target::Mock`] fails.
+pub async fn user_token(
+    client: &HelixClient<'static, reqwest::Client>,
+    target: Target,
+    token: Option<String>,
+    scopes: Vec<Scope>,
+) -> Result<UserToken, Error> {
+    match target {
+        Target::Twitch => {
+            let token = token
+                .or_else(|| std::env::var("TWITCH_TOKEN").ok())
+                .ok_or("Please set env: TWITCH_TOKEN or pass a token with --token")?;
+            let token = UserToken::from_existing(
+                client,
+                twitch_oauth2::AccessToken::new(token),
+                None,
+                None,
+            )
+            .await?;
+            let missing: Vec<_> = scopes
+                .iter()
+                .filter(|scope| !token.scopes().contains(scope))
+                .collect();
+            if !missing.is_empty() {
+                eprintln!("warning: the token is missing the scopes {missing:?}");
+            }
+            Ok(token)
+        }
+        #[cfg(feature = "mock_api")]
+        Target::Mock => {
+            let var = |name: &str| {
+                std::env::var(name).map_err(|_| {
+                    format!("Please set env: {name}, it's printed by `twitch mock-api generate`")
+                })
+            };
+            let client_id = twitch_oauth2::ClientId::new(var("MOCK_CLIENT_ID")?);
+            let client_secret = twitch_oauth2::ClientSecret::new(var("MOCK_CLIENT_SECRET")?);
+            let user_id = twitch_api::types::UserId::new(var("MOCK_USER_ID")?);
+            Ok(UserToken::mock_token(client, client_id, client_secret, &user_id, scopes).await?)
+        }
+        #[cfg(not(feature = "mock_api"))]
+        Target::Mock => Err(
+            "--mock needs the twitch-cli mock, build the example with `--features \"mock_api twitch_oauth2/mock_api\"`".into(),
+        ),
+    }
+}
+
+/// A handle that resolves once ctrl-c is pressed
+///
+/// Clones share the same signal, so every stream can be stopped by the same ctrl-c.
+#[derive(Clone)]
+pub struct Shutdown(tokio::sync::watch::Receiver<bool>);
+
+impl Shutdown {
+    /// Start listening for ctrl-c
+    pub fn on_ctrl_c() -> Self {
+        let (tx, rx) = tokio::sync::watch::channel(false);
+        tokio::spawn(async move {
+            match tokio::signal::ctrl_c().await {
+                Ok(()) => {
+                    let _ = tx.send(true);
+                    tx.closed().await
+                }
+                // Without a signal handler we never shut down
+                Err(_) => std::future::pending().await,
+            }
+        });
+        Self(rx)
+    }
+
+    /// Wait until ctrl-c is pressed
+    pub async fn wait(&mut self) { let _ = self.0.wait_for(|&stop| stop).await; }
+
+    /// Check if ctrl-c has been pressed
+    pub fn is_requested(&self) -> bool { *self.0.borrow() }
+}
+
+/// Print an error and all its sources
+pub fn report(err: &(dyn std::error::Error + 'static)) {
+    println!("Error: {err}");
+    let mut e = err;
+    while let Some(cause) = e.source() {
+        println!("Caused by: {cause}");
+        e = cause;
+    }
+}
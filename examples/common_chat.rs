@@ -0,0 +1,248 @@
+//! The steps of the chat examples, loaded as `common::chat`: resolve a channel, stream its chatters, post an announcement and print chat messages received over EventSub WebSocket
+
+use super::{Error, Shutdown};
+use futures::TryStreamExt;
+use twitch_api::{
+    helix::{
+        self,
+        chat::{ChattersError, SendChatAnnouncementBodyError},
+        ClientExtError, ClientRequestError, HelixRequestPostError,
+    },
+    HelixClient,
+};
+use twitch_oauth2::UserToken;
+
+/// Resolve the channel to join, defaulting to the user of the token
+pub async fn resolve_channel(
+    client: &HelixClient<'static, reqwest::Client>,
+    login: Option<&str>,
+    token: &UserToken,
+) -> Result<helix::users::User, Error> {
+    let login = login.unwrap_or_else(|| token.login.as_str());
+    match client.get_user_from_login(login, token).await {
+        Ok(Some(user)) => {
+            println!(
+                "channel: {} ({}), id {}",
+                user.display_name, user.login, user.id
+            );
+            Ok(user)
+        }
+        Ok(None) => Err(format!("there is no channel named {login:?}").into()),
+        Err(ClientExtError::Other(e)) => Err(format!("{login:?} is not a login: {e}").into()),
+        Err(ClientExtError::ClientError(e)) => Err(e.into()),
+    }
+}
+
+/// Stream the chatters of the channel, stopping early on ctrl-c
+pub async fn list_chatters(
+    client: &HelixClient<'static, reqwest::Client>,
+    channel: &helix::users::User,
+    token: &UserToken,
+    mut shutdown: Shutdown,
+) -> Result<(), Error> {
+    let mut chatters = client.get_chatters(&*channel.id, &*token.user_id, 1000, token);
+    let mut count = 0;
+    loop {
+        tokio::select! {
+            chatter = chatters.try_next() => match chatter {
+                Ok(Some(chatter)) => {
+                    count += 1;
+                    println!("chatter: {}", chatter.user_login);
+                }
+                Ok(None) => break,
+                Err(ClientExtError::Other(ChattersError::NotModerator(e))) => {
+                    eprintln!(
+                        "can't list chatters, {} is not a moderator in {}: {}",
+                        token.login, channel.login, e.message
+                    );
+                    return Ok(());
+                }
+                Err(e) => return Err(e.into()),
+            },
+            _ = shutdown.wait() => {
+                println!(
+                    "stopped after {count} chatters in {} pages",
+                    chatters.pages_fetched()
+                );
+                return Ok(());
+            }
+        }
+    }
+    match chatters.total() {
+        Some(total) => println!("{count} chatters, twitch counts {total}"),
+        None => println!("{count} chatters"),
+    }
+    Ok(())
+}
+
+/// Send an announcement to the channel
+pub async fn announce(
+    client: &HelixClient<'static, reqwest::Client>,
+    channel: &helix::users::User,
+    message: &str,
+    color: &str,
+    token: &UserToken,
+) -> Result<(), Error> {
+    match client
+        .send_chat_announcement(&*channel.id, &*token.user_id, message, color, token)
+        .await
+    {
+        Ok(_) => {
+            println!("sent announcement");
+            Ok(())
+        }
+        Err(ClientExtError::Other(SendChatAnnouncementBodyError::Color(_))) => {
+            Err(format!("{color:?} is not an announcement color").into())
+        }
+        Err(ClientExtError::Other(e)) => Err(format!("announcement not sent: {e}").into()),
+        Err(ClientExtError::ClientError(ClientRequestError::HelixRequestPostError(
+            HelixRequestPostError::Error {
+                status, message, ..
+            },
+        ))) if status == reqwest::StatusCode::FORBIDDEN => {
+            eprintln!(
+                "can't send announcements, {} is not a moderator in {}: {message}",
+                token.login, channel.login
+            );
+            Ok(())
+        }
+        Err(ClientExtError::ClientError(e)) => Err(e.into()),
+    }
+}
+
+#[cfg(feature = "eventsub")]
+pub use websocket::listen;
+
+#[cfg(feature = "eventsub")]
+mod websocket {
+    use super::super::{Error, Shutdown};
+    use futures::StreamExt;
+    use tokio_tungstenite::tungstenite;
+    use twitch_api::{
+        eventsub::{
+            self,
+            event::websocket::{EventsubWebsocketData, ReconnectPayload, WelcomePayload},
+            Event,
+        },
+        helix::{self, ClientRequestError, HelixRequestPostError},
+        HelixClient,
+    };
+    use twitch_oauth2::UserToken;
+
+    type WebSocket = tokio_tungstenite::WebSocketStream<
+        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+    >;
+
+    /// Subscribe to chat messages over EventSub WebSocket and print them
+    ///
+    /// Returns after `events` messages, on ctrl-c, or when the connection is closed.
+    pub async fn listen(
+        client: &HelixClient<'static, reqwest::Client>,
+        channel: &helix::users::User,
+        events: Option<usize>,
+        token: &UserToken,
+        mut shutdown: Shutdown,
+    ) -> Result<(), Error> {
+        let mut socket = connect(twitch_api::TWITCH_EVENTSUB_WEBSOCKET_URL.as_str()).await?;
+        let mut subscribed = false;
+        let mut received = 0;
+        loop {
+            let msg = tokio::select! {
+                msg = socket.next() => match msg {
+                    Some(msg) => msg?,
+                    None => return Ok(()),
+                },
+                _ = shutdown.wait() => {
+                    socket.close(None).await?;
+                    return Ok(());
+                }
+            };
+            let text = match msg {
+                tungstenite::Message::Text(text) => text,
+                tungstenite::Message::Close(frame) => {
+                    println!("connection closed: {frame:?}");
+                    return Ok(());
+                }
+                _ => continue,
+            };
+            match Event::parse_websocket(&text)? {
+                EventsubWebsocketData::Welcome {
+                    payload: WelcomePayload { session },
+                    ..
+                } if !subscribed => {
+                    // The welcome after a reconnect is for the same session, which keeps its subscriptions
+                    subscribed = true;
+                    subscribe(client, channel, session.id.into_owned(), token).await?;
+                }
+                EventsubWebsocketData::Reconnect {
+                    payload: ReconnectPayload { session },
+                    ..
+                } => {
+                    let url = session
+                        .reconnect_url
+                        .ok_or("reconnect message without a url")?;
+                    println!("reconnecting to {url}");
+                    // The old connection is dropped after the new one is established
+                    socket = connect(&url).await?;
+                }
+                EventsubWebsocketData::Notification { payload, .. } => match payload {
+                    Event::ChannelChatMessageV1(eventsub::Payload {
+                        message: eventsub::Message::Notification(message),
+                        ..
+                    }) => {
+                        println!(
+                            "[{}] {}: {}",
+                            message.broadcaster_user_login,
+                            message.chatter_user_name,
+                            message.message.text
+                        );
+                        received += 1;
+                        if events.map_or(false, |events| received >= events) {
+                            socket.close(None).await?;
+                            return Ok(());
+                        }
+                    }
+                    other => println!("event: {other:?}"),
+                },
+                EventsubWebsocketData::Revocation { metadata, .. } => {
+                    return Err(format!("subscription was revoked: {metadata:?}").into());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    async fn connect(url: &str) -> Result<WebSocket, Error> {
+        let (socket, _) = tokio_tungstenite::connect_async(url).await?;
+        Ok(socket)
+    }
+
+    async fn subscribe(
+        client: &HelixClient<'static, reqwest::Client>,
+        channel: &helix::users::User,
+        session_id: eventsub::EventSubSessionId,
+        token: &UserToken,
+    ) -> Result<(), Error> {
+        let subscription =
+            eventsub::channel::ChannelChatMessageV1::new(channel.id.clone(), token.user_id.clone());
+        match client
+            .create_eventsub_subscription(
+                subscription,
+                eventsub::Transport::websocket(session_id),
+                token,
+            )
+            .await
+        {
+            Ok(_) => println!("listening to chat in {}", channel.login),
+            // The twitch-cli WebSocket server forwards triggered events without a subscription
+            Err(ClientRequestError::HelixRequestPostError(HelixRequestPostError::Error {
+                status,
+                ..
+            })) if status == reqwest::StatusCode::NOT_FOUND => println!(
+                "subscriptions are not supported here, trigger events with `twitch event trigger channel.chat.message --transport=websocket`"
+            ),
+            Err(e) => return Err(e.into()),
+        }
+        Ok(())
+    }
+}
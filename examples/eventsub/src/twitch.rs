@@ -15,7 +15,7 @@ use twitch_api::{
     eventsub::{
         self as twitch_eventsub,
         stream::{StreamOfflineV1, StreamOfflineV1Payload, StreamOnlineV1, StreamOnlineV1Payload},
-        Event, EventType, Status,
+        Event, EventType, SubscriptionStatus,
     },
     helix,
     twitch_oauth2::{AppAccessToken, ClientId, ClientSecret, TwitchToken},
@@ -42,7 +42,12 @@ pub async fn eventsub_register(
         interval.tick().await;
         tracing::info!("checking subs");
         let subs = client
-            .get_eventsub_subscriptions(Status::Enabled, None, None, &*token.read().await)
+            .get_eventsub_subscriptions(
+                SubscriptionStatus::Enabled,
+                None,
+                None,
+                &*token.read().await,
+            )
             .map_ok(|events| {
                 futures::stream::iter(events.subscriptions.into_iter().map(Ok::<_, eyre::Report>))
             })
@@ -0,0 +1,39 @@
+//! Resolve a channel and print its chat messages received over EventSub WebSocket
+//!
+//! Runs against Twitch with a user token, or against the [twitch-cli](https://github.com/twitchdev/twitch-cli) mock servers with `--mock`.
+//! Press ctrl-c to stop.
+mod common;
+
+use common::{chat, Args, Error, Shutdown};
+use twitch_api::HelixClient;
+use twitch_oauth2::Scope;
+
+const USAGE: &str = "\
+Usage: eventsub_chat [--mock] [--token <token>] [--channel <login>] [--events <amount>]
+
+  --mock                use the twitch-cli mock servers instead of Twitch, needs feature mock_api
+  --token <token>       user access token, defaults to env TWITCH_TOKEN
+  --channel <login>     channel to read chat in, defaults to the user of the token
+  --events <amount>     stop after this many chat messages, defaults to running until ctrl-c";
+
+fn main() {
+    if let Err(err) = run() {
+        common::report(err.as_ref());
+        std::process::exit(1);
+    }
+}
+
+#[tokio::main]
+async fn run() -> Result<(), Error> {
+    let _ = dotenvy::dotenv(); // Eat error
+    let args = Args::parse(USAGE)?;
+    args.target.apply();
+
+    let client: HelixClient<reqwest::Client> = HelixClient::default();
+    let token =
+        common::user_token(&client, args.target, args.token, vec![Scope::UserReadChat]).await?;
+    let shutdown = Shutdown::on_ctrl_c();
+
+    let channel = chat::resolve_channel(&client, args.channel.as_deref(), &token).await?;
+    chat::listen(&client, &channel, args.events, &token, shutdown).await
+}
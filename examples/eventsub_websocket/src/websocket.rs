@@ -149,7 +149,7 @@ impl WebsocketClient {
             self.token =
                 crate::util::get_access_token(self.client.get_client(), &self.opts).await?;
         }
-        let transport = eventsub::Transport::websocket(data.id.clone());
+        let transport = eventsub::Transport::websocket(data.id.clone().into_owned());
         self.client
             .create_eventsub_subscription(
                 eventsub::channel::ChannelBanV1::broadcaster_user_id(self.user_id.clone()),
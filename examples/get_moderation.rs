@@ -37,7 +37,7 @@ async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>>
     println!(
         "{:?}",
         client
-            .get_moderators_in_channel_from_id(broadcaster_id, &token)
+            .get_moderators_in_channel_from_id(broadcaster_id, None, &token)
             .try_collect::<Vec<_>>()
             .await?,
     );
@@ -73,7 +73,7 @@ async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>>
         .expect("no channel found");
 
     let _s: Vec<_> = client
-        .search_categories("Just", &token)
+        .search_categories("Just", None, &token)
         .try_collect()
         .await?;
     let _s: Vec<_> = client
@@ -106,7 +106,7 @@ pub async fn moderation<'a, C: twitch_api::HttpClient + Sync>(
     println!(
         "{:?}",
         client
-            .get_moderators_in_channel_from_id(broadcaster_id, token)
+            .get_moderators_in_channel_from_id(broadcaster_id, None, token)
             .try_collect::<Vec<_>>()
             .await?,
     );
@@ -0,0 +1,65 @@
+//! Serialize arbitrary request bodies
+//!
+//! The body must be valid JSON, containing the fields as they were given.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use twitch_api::helix::{self, HelixRequestBody};
+
+#[derive(Debug, arbitrary::Arbitrary)]
+enum Color {
+    Blue,
+    Green,
+    Orange,
+    Purple,
+    Primary,
+}
+
+#[derive(Debug, arbitrary::Arbitrary)]
+enum Input {
+    BanUser {
+        user_id: String,
+        reason: String,
+        duration: Option<u32>,
+    },
+    SendChatAnnouncement {
+        message: String,
+        color: Color,
+    },
+}
+
+fuzz_target!(|input: Input| match input {
+    Input::BanUser {
+        user_id,
+        reason,
+        duration,
+    } => {
+        let body = helix::moderation::BanUserBody::new(user_id.as_str(), reason.as_str(), duration);
+        let json = parse(&body);
+        assert_eq!(json["data"]["user_id"], *user_id);
+        assert_eq!(json["data"]["reason"], *reason);
+        assert_eq!(
+            json["data"].get("duration").and_then(|d| d.as_u64()),
+            duration.map(u64::from)
+        );
+    }
+    Input::SendChatAnnouncement { message, color } => {
+        let color = match color {
+            Color::Blue => "blue",
+            Color::Green => "green",
+            Color::Orange => "orange",
+            Color::Purple => "purple",
+            Color::Primary => "primary",
+        };
+        let body = helix::chat::SendChatAnnouncementBody::new(message.as_str(), color)
+            .expect("color should be valid");
+        let json = parse(&body);
+        assert_eq!(json["message"], *message);
+        assert_eq!(json["color"], color);
+    }
+});
+
+fn parse(body: &impl HelixRequestBody) -> serde_json::Value {
+    let bytes = body.try_to_body().expect("body should serialize");
+    serde_json::from_slice(&bytes).expect("body should be valid json")
+}
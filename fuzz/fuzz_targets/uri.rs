@@ -0,0 +1,122 @@
+//! Build uris from arbitrary request fields
+//!
+//! The uri must be valid, and parsing its query must give back the fields.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use twitch_api::{
+    helix::{self, Request as _},
+    types::{self, LanguageCode},
+};
+
+#[derive(Debug, arbitrary::Arbitrary)]
+enum Input {
+    SearchChannels {
+        query: String,
+        after: Option<String>,
+        first: Option<usize>,
+        live_only: Option<bool>,
+    },
+    GetStreams {
+        after: Option<String>,
+        before: Option<String>,
+        first: Option<usize>,
+        game_id: Vec<String>,
+        language: Option<String>,
+        user_id: Vec<String>,
+        user_login: Vec<String>,
+    },
+}
+
+fuzz_target!(|input: Input| match input {
+    Input::SearchChannels {
+        query,
+        after,
+        first,
+        live_only,
+    } => {
+        let mut req = helix::search::SearchChannelsRequest::query(query.clone());
+        req.after = after.clone().map(|after| helix::Cursor::from(after).into());
+        req.first = first.map(helix::PageSize::unchecked);
+        req.live_only = live_only;
+
+        let mut expected = vec![("query", query)];
+        expected.extend(after.map(|after| ("after", after)));
+        expected.extend(first.map(|first| ("first", first.to_string())));
+        expected.extend(live_only.map(|live_only| ("live_only", live_only.to_string())));
+        check(&req, &expected);
+    }
+    Input::GetStreams {
+        after,
+        before,
+        first,
+        game_id,
+        language,
+        user_id,
+        user_login,
+    } => {
+        let mut req = helix::streams::GetStreamsRequest::default();
+        req.after = after.clone().map(|after| helix::Cursor::from(after).into());
+        req.before = before
+            .clone()
+            .map(|before| helix::Cursor::from(before).into());
+        req.first = first.map(helix::PageSize::unchecked);
+        req.game_id = game_id
+            .iter()
+            .cloned()
+            .map(types::CategoryId::from)
+            .collect::<Vec<_>>()
+            .into();
+        req.language = language.as_deref().map(LanguageCode::from);
+        req.user_id = user_id
+            .iter()
+            .cloned()
+            .map(types::UserId::from)
+            .collect::<Vec<_>>()
+            .into();
+        req.user_login = user_login
+            .iter()
+            .cloned()
+            .map(types::UserName::from)
+            .collect::<Vec<_>>()
+            .into();
+
+        let mut expected = vec![];
+        expected.extend(after.map(|after| ("after", after)));
+        expected.extend(before.map(|before| ("before", before)));
+        expected.extend(first.map(|first| ("first", first.to_string())));
+        expected.extend(game_id.into_iter().map(|id| ("game_id", id)));
+        // unknown codes are kept in lowercase
+        expected.extend(language.map(|language| ("language", language.to_lowercase())));
+        expected.extend(user_id.into_iter().map(|id| ("user_id", id)));
+        expected.extend(user_login.into_iter().map(|login| ("user_login", login)));
+        check(&req, &expected);
+    }
+});
+
+fn check<R: helix::Request>(req: &R, expected: &[(&str, String)]) {
+    let query = req.query().expect("query should serialize");
+    let uri = match req.get_uri() {
+        Ok(uri) => uri,
+        // http::Uri can't be longer than u16::MAX
+        Err(_) if query.len() >= u16::MAX as usize - R::PATH.len() - 64 => return,
+        Err(e) => panic!("invalid uri for query {query:?}: {e}"),
+    };
+    assert_eq!(uri.query().unwrap_or_default(), query);
+    assert!(
+        query
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"&=+%*-._".contains(&b)),
+        "query is not encoded: {query}"
+    );
+    url::Url::parse(&uri.to_string()).expect("uri should be a valid url");
+
+    let pairs: Vec<_> = url::form_urlencoded::parse(query.as_bytes())
+        .into_owned()
+        .collect();
+    let expected: Vec<_> = expected
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.clone()))
+        .collect();
+    assert_eq!(pairs, expected);
+}
@@ -98,6 +98,22 @@ pub type Request = http::Request<Bytes>;
 /// The response type we're expecting with body
 pub type Response = http::Response<Bytes>;
 
+/// Timeout for a [`Request`], added to its [extensions](http::Request::extensions) by [`HelixClient`](crate::helix::HelixClient)
+///
+/// The [reqwest](https://crates.io/crates/reqwest) and [ureq](https://crates.io/crates/ureq) clients apply it to the request they send.
+/// Other clients ignore it, but a custom [`HttpClient`](crate::HttpClient) can read it with `request.extensions().get::<RequestTimeout>()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RequestTimeout(pub std::time::Duration);
+
+/// Maximum size of the body of the response to a [`Request`], in bytes, added to its [extensions](http::Request::extensions) by [`HelixClient`](crate::helix::HelixClient)
+///
+/// The `TowerService` client fails with `TowerError::ResponseTooLarge` as soon as the body is larger.
+/// The [reqwest](https://crates.io/crates/reqwest) client doesn't read a body with a larger `Content-Length`, and stops reading other bodies once they are larger.
+/// The [`HelixClient`](crate::helix::HelixClient) then sees that the response is too large and fails with [`ResponseTooLarge`](crate::helix::ClientRequestError::ResponseTooLarge).
+/// Other clients ignore it and read the full body, which is checked after it has been received.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaxResponseSize(pub usize);
+
 /// Extension trait for [`Response`]
 pub trait ResponseExt {
     /// Return the body as a vector of bytes
@@ -206,6 +222,45 @@ pub enum CompatError<E> {
     Other(#[from] E),
 }
 
+/// Make requests with only a client id, without an access token
+///
+/// Accepted by the `req_*` methods of [`HelixClient`](crate::HelixClient) in place of a token, for example against a mock server or recorded responses.
+/// The requests carry `Client-Id` but no `Authorization` header.
+/// Requests for endpoints that need [scopes](crate::helix::Request::SCOPE) fail with [`ClientRequestError::NoToken`](crate::helix::ClientRequestError::NoToken) before they are sent.
+///
+/// # Examples
+///
+/// ```rust, no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: twitch_api::helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = twitch_api::helix::HelixClient::default();
+/// use twitch_api::{client::NoAuth, helix};
+///
+/// let auth = NoAuth::new("myclientid");
+/// let emotes = client
+///     .req_get(helix::chat::GetGlobalEmotesRequest::new(), &auth)
+///     .await?;
+/// # Ok(()) }
+/// ```
+#[cfg(feature = "helix")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NoAuth {
+    pub(crate) client_id: String,
+}
+
+#[cfg(feature = "helix")]
+impl NoAuth {
+    /// Make requests as the application with `client_id`
+    pub fn new(client_id: impl Into<String>) -> Self {
+        Self {
+            client_id: client_id.into(),
+        }
+    }
+
+    /// The client id sent with the requests
+    pub fn client_id(&self) -> &str { &self.client_id }
+}
+
 #[cfg(feature = "helix")]
 impl<'c, C: Client + Sync + 'c> twitch_oauth2::client::Client for crate::HelixClient<'c, C> {
     type Error = CompatError<<C as Client>::Error>;
@@ -9,15 +9,25 @@ impl Client for ReqwestClient {
     fn req(&self, request: Request) -> BoxedFuture<'static, Result<Response, Self::Error>> {
         // Reqwest plays really nice here and has a try_from on `http::Request` -> `reqwest::Request`
         use std::convert::TryFrom;
-        let req = match reqwest::Request::try_from(request) {
+        let timeout = request.extensions().get::<RequestTimeout>().copied();
+        let limit = request.extensions().get::<MaxResponseSize>().copied();
+        let mut req = match reqwest::Request::try_from(request) {
             Ok(req) => req,
             Err(e) => return Box::pin(async { Err(e) }),
         };
+        if let Some(RequestTimeout(timeout)) = timeout {
+            *req.timeout_mut() = Some(timeout);
+        }
         // We need to "call" the execute outside the async closure to not capture self.
         let fut = self.execute(req);
         Box::pin(async move {
             // Await the request and translate to `http::Response`
             let mut response = fut.await?;
+            let content_length = response
+                .headers()
+                .get(http::header::CONTENT_LENGTH)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u64>().ok());
             let mut result = http::Response::builder().status(response.status());
             let headers = result
                 .headers_mut()
@@ -25,8 +35,28 @@ impl Client for ReqwestClient {
                 .expect("expected to get headers mut when building response");
             std::mem::swap(headers, response.headers_mut());
             let result = result.version(response.version());
+            let body = match limit {
+                // the body is not read, the `Content-Length` is kept to show that it's too large
+                Some(MaxResponseSize(limit))
+                    if content_length.map_or(false, |l| l > limit as u64) =>
+                {
+                    Bytes::new()
+                }
+                // stops reading once the body is larger than the limit
+                Some(MaxResponseSize(limit)) => {
+                    let mut body = Vec::new();
+                    while let Some(chunk) = response.chunk().await? {
+                        body.extend_from_slice(&chunk);
+                        if body.len() > limit {
+                            break;
+                        }
+                    }
+                    body.into()
+                }
+                None => response.bytes().await?,
+            };
             Ok(result
-                .body(response.bytes().await?)
+                .body(body)
                 .expect("mismatch reqwest -> http conversion should not fail"))
         })
     }
@@ -64,4 +94,26 @@ mod tests {
             .unwrap();
         super::ReqwestClient::default_client();
     }
+
+    #[test]
+    #[cfg(feature = "helix")]
+    fn helix_from_client() {
+        use crate::helix::HelixClient;
+
+        let shared = std::sync::Arc::new(super::ReqwestClient::new());
+        let a: HelixClient<std::sync::Arc<super::ReqwestClient>> = shared.clone().into();
+        let b = HelixClient::with_client(shared.clone());
+        assert!(std::sync::Arc::ptr_eq(a.get_client(), b.get_client()));
+        assert!(std::sync::Arc::ptr_eq(&a.clone_client(), &shared));
+
+        let proxied = super::ReqwestClient::builder()
+            .proxy(reqwest::Proxy::all("http://127.0.0.1:8080").unwrap())
+            .build()
+            .unwrap();
+        let client: HelixClient<super::ReqwestClient> = proxied.clone().into();
+        assert_eq!(
+            format!("{:?}", client.clone_client()),
+            format!("{proxied:?}")
+        );
+    }
 }
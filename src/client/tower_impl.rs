@@ -13,6 +13,11 @@ pub enum TowerError {
     ServiceError(#[source] Box<dyn std::error::Error + Send + Sync>),
     /// couldn't create body from service response
     BodyError(#[source] Box<dyn std::error::Error + Send + Sync>),
+    /// response body is larger than the limit of {limit} bytes
+    ResponseTooLarge {
+        /// The [maximum size](crate::client::MaxResponseSize) of the body
+        limit: usize,
+    },
 }
 
 impl<S, ReqBody, ResBody> Client for TowerService<S, ReqBody>
@@ -30,6 +35,7 @@ where
 
     fn req(&self, request: Request) -> BoxedFuture<'static, Result<Response, Self::Error>> {
         let mut service = self.0.clone();
+        let limit = request.extensions().get::<MaxResponseSize>().copied();
         Box::pin(async move {
             futures::future::poll_fn(|cx| service.poll_ready(cx))
                 .await
@@ -41,10 +47,25 @@ where
                 .map_err(|e| TowerError::ServiceError(e.into()))?
                 .into_parts();
 
-            let b = http_body_util::BodyExt::collect(body)
-                .await
-                .map_err(|e| TowerError::BodyError(e.into()))?
-                .to_bytes();
+            let b = match limit {
+                // stops reading once the body is larger than the limit
+                Some(MaxResponseSize(limit)) => {
+                    http_body_util::BodyExt::collect(http_body_util::Limited::new(body, limit))
+                        .await
+                        .map_err(|e| {
+                            if e.is::<http_body_util::LengthLimitError>() {
+                                TowerError::ResponseTooLarge { limit }
+                            } else {
+                                TowerError::BodyError(e)
+                            }
+                        })?
+                        .to_bytes()
+                }
+                None => http_body_util::BodyExt::collect(body)
+                    .await
+                    .map_err(|e| TowerError::BodyError(e.into()))?
+                    .to_bytes(),
+            };
 
             Ok(http::Response::from_parts(parts, b))
         })
@@ -71,3 +92,94 @@ impl<S: Default, ReqBody> Default for TowerService<S, ReqBody> {
 impl<S: Clone, ReqBody> Clone for TowerService<S, ReqBody> {
     fn clone(&self) -> Self { TowerService(self.0.clone(), std::marker::PhantomData) }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    type Body = http_body_util::combinators::BoxBody<Bytes, std::convert::Infallible>;
+
+    /// Streams a 1 MiB body in 1 KiB chunks, counting the chunks that were read
+    #[derive(Clone, Default)]
+    struct Streaming(Arc<AtomicUsize>);
+
+    impl Service<http::Request<Bytes>> for Streaming {
+        type Error = std::convert::Infallible;
+        type Future = futures::future::Ready<Result<Self::Response, Self::Error>>;
+        type Response = http::Response<Body>;
+
+        fn poll_ready(
+            &mut self,
+            _: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Result<(), Self::Error>> {
+            std::task::Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _: http::Request<Bytes>) -> Self::Future {
+            let read = self.0.clone();
+            let chunks = futures::stream::repeat_with(move || {
+                read.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, std::convert::Infallible>(hyper::body::Frame::data(Bytes::from_static(
+                    &[b'a'; 1024],
+                )))
+            })
+            .take(1024);
+            let body = http_body_util::BodyExt::boxed(http_body_util::StreamBody::new(chunks));
+            futures::future::ready(Ok(http::Response::new(body)))
+        }
+    }
+
+    fn request(limit: Option<usize>) -> Request {
+        let mut request = http::Request::new(Bytes::new());
+        if let Some(limit) = limit {
+            request.extensions_mut().insert(MaxResponseSize(limit));
+        }
+        request
+    }
+
+    #[tokio::test]
+    async fn response_too_large() {
+        let service = Streaming::default();
+        let client: TowerService<_, Bytes> = TowerService::new(service.clone());
+        match client.req(request(Some(10 * 1024))).await {
+            Err(TowerError::ResponseTooLarge { limit }) => assert_eq!(limit, 10 * 1024),
+            e => panic!("unexpected result: {e:?}"),
+        }
+        // the chunk over the limit is the last one read
+        assert_eq!(service.0.load(Ordering::SeqCst), 11);
+
+        let service = Streaming::default();
+        let client: TowerService<_, Bytes> = TowerService::new(service.clone());
+        let response = client.req(request(None)).await.unwrap();
+        assert_eq!(response.body().len(), 1024 * 1024);
+        assert_eq!(service.0.load(Ordering::SeqCst), 1024);
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "helix", feature = "twitch_oauth2"))]
+    async fn helix_response_too_large() {
+        let service = Streaming::default();
+        let client =
+            crate::HelixClient::with_client(TowerService::<_, Bytes>::new(service.clone()))
+                .with_max_response_size(10 * 1024);
+        let token = crate::tests::app_token();
+        match client
+            .req_get(
+                crate::helix::users::GetUsersRequest::logins(&["justintv"][..]),
+                &token,
+            )
+            .await
+        {
+            Err(crate::helix::ClientRequestError::RequestError(TowerError::ResponseTooLarge {
+                limit,
+            })) => assert_eq!(limit, 10 * 1024),
+            e => panic!("unexpected result: {e:?}"),
+        }
+        assert_eq!(service.0.load(Ordering::SeqCst), 11);
+    }
+}
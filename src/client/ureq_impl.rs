@@ -39,6 +39,9 @@ impl Client for UreqAgent {
                 req = req.set(header.as_str(), value);
             }
         }
+        if let Some(RequestTimeout(timeout)) = request.extensions().get() {
+            req = req.timeout(*timeout);
+        }
         Box::pin(async move {
             let body = request.into_body();
             let response = match req
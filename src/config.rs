@@ -0,0 +1,209 @@
+//! Types for reading a bot or app configuration with [`serde`]
+//!
+//! Configuration files are written by people, so these types accept more than the API does.
+//! A [`ScopeList`] keeps scopes it doesn't know instead of failing, and a [`UserIdOrLogin`] can be written as a number, a numeric string or a login.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use twitch_api::config::{ScopeList, UserIdOrLogin};
+//!
+//! #[derive(serde_derive::Deserialize)]
+//! struct Config {
+//!     channel: UserIdOrLogin,
+//!     scopes: ScopeList,
+//! }
+//!
+//! let config: Config = serde_json::from_str(
+//!     r#"{"channel": 141981764, "scopes": ["chat:read", "moderator:manage:banned_users"]}"#,
+//! )?;
+//! assert_eq!(config.channel.id().map(|id| id.as_str()), Some("141981764"));
+//! assert_eq!(
+//!     config.scopes.0,
+//!     [
+//!         twitch_oauth2::Scope::ChatRead,
+//!         twitch_oauth2::Scope::ModeratorManageBannedUsers
+//!     ]
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use serde::de;
+use twitch_oauth2::Scope;
+
+use crate::types;
+
+/// A list of [scopes](Scope), deserialized from an array of strings
+///
+/// Scopes this version doesn't know are kept as [`Scope::Other`] instead of failing, see [`unknown`](ScopeList::unknown).
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde_derive::Serialize)]
+#[serde(transparent)]
+pub struct ScopeList(pub Vec<Scope>);
+
+impl ScopeList {
+    /// The scopes that are not known to [`twitch_oauth2`], most likely a typo in the configuration
+    pub fn unknown(&self) -> impl Iterator<Item = &str> + '_ {
+        self.0.iter().filter_map(|scope| match scope {
+            Scope::Other(s) => Some(&**s),
+            _ => None,
+        })
+    }
+}
+
+impl From<Vec<Scope>> for ScopeList {
+    fn from(scopes: Vec<Scope>) -> Self { Self(scopes) }
+}
+
+impl From<ScopeList> for Vec<Scope> {
+    fn from(scopes: ScopeList) -> Self { scopes.0 }
+}
+
+impl std::ops::Deref for ScopeList {
+    type Target = [Scope];
+
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl<'de> de::Deserialize<'de> for ScopeList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: de::Deserializer<'de> {
+        let scopes: Vec<String> = de::Deserialize::deserialize(deserializer)?;
+        Ok(Self(scopes.into_iter().map(Scope::parse).collect()))
+    }
+}
+
+/// A user or channel, given either by id or by login
+///
+/// Deserializes from a number or a string of digits as an [id](UserIdOrLogin::Id), any other string is a [login](UserIdOrLogin::Login).
+/// Logins are not validated or normalized, [`HelixClient::get_user_from_login`](crate::helix::HelixClient::get_user_from_login) does that when looking them up.
+///
+/// Serializes as a string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde_derive::Serialize)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum UserIdOrLogin {
+    /// The id of the user
+    Id(types::UserId),
+    /// The login of the user
+    Login(types::UserName),
+}
+
+impl UserIdOrLogin {
+    /// The id, if this was given by id
+    pub fn id(&self) -> Option<&types::UserIdRef> {
+        match self {
+            Self::Id(id) => Some(id),
+            Self::Login(_) => None,
+        }
+    }
+
+    /// The login, if this was given by login
+    pub fn login(&self) -> Option<&types::UserNameRef> {
+        match self {
+            Self::Id(_) => None,
+            Self::Login(login) => Some(login),
+        }
+    }
+}
+
+impl std::str::FromStr for UserIdOrLogin {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            Ok(Self::Id(s.into()))
+        } else {
+            Ok(Self::Login(s.into()))
+        }
+    }
+}
+
+impl<'de> de::Deserialize<'de> for UserIdOrLogin {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: de::Deserializer<'de> {
+        struct Visitor;
+
+        impl de::Visitor<'_> for Visitor {
+            type Value = UserIdOrLogin;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a user id or login")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(UserIdOrLogin::Id(v.to_string().into()))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                u64::try_from(v)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+                    .and_then(|v| self.visit_u64(v))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                match v.parse() {
+                    Ok(v) => Ok(v),
+                    Err(e) => match e {},
+                }
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, serde_derive::Deserialize)]
+    struct Config {
+        channel: UserIdOrLogin,
+        scopes: ScopeList,
+    }
+
+    #[test]
+    fn toml_config() {
+        let config: Config = toml::from_str(
+            r#"
+            channel = 141981764
+            scopes = ["chat:read", "chat:edit", "channel:read:everything"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.channel, UserIdOrLogin::Id("141981764".into()));
+        assert_eq!(&config.scopes[..2], [Scope::ChatRead, Scope::ChatEdit]);
+        assert_eq!(
+            config.scopes.unknown().collect::<Vec<_>>(),
+            ["channel:read:everything"]
+        );
+    }
+
+    #[test]
+    fn json_config() {
+        for (channel, expected) in [
+            ("141981764", UserIdOrLogin::Id("141981764".into())),
+            (r#""141981764""#, UserIdOrLogin::Id("141981764".into())),
+            (r#""twitchdev""#, UserIdOrLogin::Login("twitchdev".into())),
+        ] {
+            let config: Config = serde_json::from_str(&format!(
+                r#"{{"channel": {channel}, "scopes": ["user:read:chat", "not:a:scope"]}}"#
+            ))
+            .unwrap();
+            assert_eq!(config.channel, expected);
+            assert_eq!(config.scopes[0], Scope::UserReadChat);
+            assert_eq!(config.scopes.unknown().collect::<Vec<_>>(), ["not:a:scope"]);
+        }
+        assert!(serde_json::from_str::<UserIdOrLogin>("-1").is_err());
+        assert!(serde_json::from_str::<UserIdOrLogin>("[]").is_err());
+    }
+
+    #[test]
+    fn roundtrip() {
+        let config = r#"["chat:read","not:a:scope"]"#;
+        let scopes: ScopeList = serde_json::from_str(config).unwrap();
+        assert_eq!(serde_json::to_string(&scopes).unwrap(), config);
+        let id: UserIdOrLogin = serde_json::from_str("141981764").unwrap();
+        assert_eq!(serde_json::to_string(&id).unwrap(), r#""141981764""#);
+    }
+}
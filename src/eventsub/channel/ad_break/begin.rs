@@ -4,12 +4,14 @@
 use super::*;
 /// [`channel.ad_break.begin`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelad_breakbegin): a user runs a midroll commercial break, either manually or automatically via ads manager.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelAdBreakBeginV1 {
     /// The ID of the broadcaster that you want to get Channel Ad Break begin notifications for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -36,6 +38,7 @@ impl EventSubscription for ChannelAdBreakBeginV1 {
 
 /// [`channel.ad_break.begin`](ChannelAdBreakBeginV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelAdBreakBeginV1Payload {
@@ -43,20 +46,27 @@ pub struct ChannelAdBreakBeginV1Payload {
     pub duration_seconds: i32,
     #[serde(alias = "timestamp")]
     /// The UTC timestamp of when the ad break began, in RFC3339 format. Note that there is potential delay between this event, when the streamer requested the ad break, and when the viewers will see ads.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub started_at: types::Timestamp,
     /// Indicates if the ad was automatically scheduled via Ads Manager
     pub is_automatic: bool,
     /// The broadcaster’s user ID for the channel the ad was run on.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The broadcaster’s user login for the channel the ad was run on.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The broadcaster’s user display name for the channel the ad was run on.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The ID of the user that requested the ad. For automatic ads, this will be the ID of the broadcaster.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub requester_user_id: types::UserId,
     /// The login of the user that requested the ad.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub requester_user_login: types::UserName,
     /// The display name of the user that requested the ad.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub requester_user_name: types::DisplayName,
 }
 
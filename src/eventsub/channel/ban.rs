@@ -4,12 +4,14 @@ use super::*;
 
 /// [`channel.ban`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelban): a viewer is banned from the specified channel.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelBanV1 {
     /// The broadcaster user ID for the channel you want to get ban notifications for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -34,36 +36,54 @@ impl EventSubscription for ChannelBanV1 {
 
 /// [`channel.ban`](ChannelBanV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelBanV1Payload {
     /// The user ID for the user who was banned on the specified channel.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
     /// The user login for the user who was banned on the specified channel.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_login: types::UserName,
     /// The requested broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_name: types::DisplayName,
     /// The requested broadcaster ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The requested broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The requested broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The user ID of the issuer of the ban.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub moderator_user_id: types::UserId,
     /// The user login of the issuer of the ban.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub moderator_user_login: types::UserName,
     /// The user name of the issuer of the ban.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub moderator_user_name: types::DisplayName,
     /// The reason behind the ban.
     pub reason: String,
     /// The UTC date and time (in RFC3339 format) of when the user was banned or put in a timeout.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub banned_at: types::Timestamp,
     /// Will be null if permanent ban. If it is a timeout, this field shows when the timeout will end.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub ends_at: Option<types::Timestamp>,
     /// Indicates whether the ban is permanent (true) or a timeout (false). If true, ends_at will be null.
     pub is_permanent: bool,
 }
+
+impl crate::eventsub::BroadcasterInfo for ChannelBanV1Payload {
+    fn broadcaster_id(&self) -> &types::UserIdRef { &self.broadcaster_user_id }
+
+    fn broadcaster_login(&self) -> &types::UserNameRef { &self.broadcaster_user_login }
+}
 #[cfg(test)]
 #[test]
 fn parse_payload() {
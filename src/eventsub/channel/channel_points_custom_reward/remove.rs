@@ -4,15 +4,18 @@
 use super::*;
 /// [`channel.channel_points_custom_reward.remove`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelchannel_points_custom_rewardremove): a custom channel points reward has been removed from the specified channel.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPointsCustomRewardRemoveV1 {
     /// The broadcaster user ID for the channel you want to receive channel points custom reward remove notifications for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// Optional. Specify a reward id to only receive notifications for a specific reward.
     #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub reward_id: Option<types::RewardId>,
 }
 
@@ -46,28 +49,37 @@ impl EventSubscription for ChannelPointsCustomRewardRemoveV1 {
 
 /// [`channel.channel_points_custom_reward.remove`](ChannelPointsCustomRewardRemoveV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPointsCustomRewardRemoveV1Payload {
     /// Custom background color for the reward. Format: Hex with # prefix. Example: #FA1ED2.
     pub background_color: String,
     /// The requested broadcaster ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The requested broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The requested broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::UserName,
     /// Timestamp of the cooldown expiration. null if the reward isn’t on cooldown.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub cooldown_expires_at: Option<types::Timestamp>,
     /// The reward cost.
     pub cost: i64,
     /// Set of default images of 1x, 2x and 4x sizes for the reward.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<serde_json::Value>"))]
     pub default_image: Option<types::Image>,
     /// Whether a cooldown is enabled and what the cooldown is in seconds.
+    #[cfg_attr(feature = "schemars", schemars(with = "serde_json::Value"))]
     pub global_cooldown: types::GlobalCooldown,
     /// The reward identifier.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::RewardId,
     /// Set of custom images of 1x, 2x and 4x sizes for the reward. Can be null if no images have been uploaded.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<serde_json::Value>"))]
     pub image: Option<types::Image>,
     /// Is the reward currently enabled. If false, the reward won’t show up to viewers.
     pub is_enabled: bool,
@@ -78,8 +90,10 @@ pub struct ChannelPointsCustomRewardRemoveV1Payload {
     /// Does the viewer need to enter information when redeeming the reward.
     pub is_user_input_required: bool,
     /// Whether a maximum per stream is enabled and what the maximum is.
+    #[cfg_attr(feature = "schemars", schemars(with = "serde_json::Value"))]
     pub max_per_stream: types::Max,
     /// Whether a maximum per user per stream is enabled and what the maximum is.
+    #[cfg_attr(feature = "schemars", schemars(with = "serde_json::Value"))]
     pub max_per_user_per_stream: types::Max,
     /// The reward description.
     pub prompt: String,
@@ -4,15 +4,18 @@
 use super::*;
 /// [`channel.channel_points_custom_reward_redemption.add`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelchannel_points_custom_reward_redemptionadd): a viewer has redeemed a custom channel points reward on the specified channel.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPointsCustomRewardRedemptionAddV1 {
     /// The broadcaster user ID for the channel you want to receive channel points custom reward redemption add notifications for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// Optional. Specify a reward id to only receive notifications for a specific reward.
     #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub reward_id: Option<types::RewardId>,
 }
 
@@ -47,18 +50,24 @@ impl EventSubscription for ChannelPointsCustomRewardRedemptionAddV1 {
 // FIXME: Same as update
 /// [`channel.channel_points_custom_reward_redemption.add`](ChannelPointsCustomRewardRedemptionAddV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPointsCustomRewardRedemptionAddV1Payload {
     /// The requested broadcaster ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The requested broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The requested broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The redemption identifier.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::RedemptionId,
     /// RFC3339 timestamp of when the reward was redeemed.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub redeemed_at: types::Timestamp,
     /// Basic information about the reward that was redeemed, at the time it was redeemed.
     pub reward: Reward,
@@ -67,10 +76,13 @@ pub struct ChannelPointsCustomRewardRedemptionAddV1Payload {
     /// The user input provided. Empty string if not provided.
     pub user_input: String,
     /// User ID of the user that redeemed the reward.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
     /// Login of the user that redeemed the reward.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_login: types::UserName,
     /// Display name of the user that redeemed the reward.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_name: types::DisplayName,
 }
 
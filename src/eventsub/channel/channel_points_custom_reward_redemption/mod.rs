@@ -19,12 +19,14 @@ pub use update::{
 
 /// Basic information about the reward that was redeemed, at the time it was redeemed.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Reward {
     /// The reward cost.
     pub cost: i64,
     /// The reward identifier.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::RewardId,
     /// The reward description.
     pub prompt: String,
@@ -36,6 +38,7 @@ pub struct Reward {
 ///
 /// See also [`CustomRewardRedemptionStatus`](crate::helix::points::CustomRewardRedemptionStatus)
 #[derive(PartialEq, Eq, Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum RedemptionStatus {
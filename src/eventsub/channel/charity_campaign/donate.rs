@@ -4,11 +4,13 @@
 use super::*;
 /// [`channel.charity_campaign.donate`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelcharity_campaigndonate): a user donates to the broadcaster’s charity campaign.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 pub struct ChannelCharityCampaignDonateV1 {
     /// The ID of the broadcaster that you want to receive notifications about when users donate to their campaign.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -33,27 +35,36 @@ impl EventSubscription for ChannelCharityCampaignDonateV1 {
 
 /// [`channel.charity_campaign.donate`](ChannelCharityCampaignDonateV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelCharityCampaignDonateV1Payload {
     /// An ID that identifies the donation. The ID is unique across campaigns.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::CharityDonationId,
     /// An ID that identifies the charity campaign.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub campaign_id: types::CharityCampaignId,
     /// An ID that identifies the broadcaster that’s running the campaign.
     #[serde(alias = "broadcaster_user_id")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_id: types::UserId,
     /// An ID that identifies the charity campaign.
     #[serde(alias = "broadcaster_user_login")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_login: types::UserName,
     /// An ID that identifies the broadcaster that’s running the campaign.
     #[serde(alias = "broadcaster_user_name")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_name: types::DisplayName,
     /// An ID that identifies the user that donated to the campaign.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
     /// The user’s login name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_login: types::UserName,
     /// The user’s display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_name: types::DisplayName,
     /// The charity’s name.
     pub charity_name: String,
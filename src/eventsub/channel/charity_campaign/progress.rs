@@ -4,11 +4,13 @@
 use super::*;
 /// [`channel.charity_campaign.progress`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelcharity_campaignprogress): progress is made towards the campaign’s goal or when the broadcaster changes the fundraising goal.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 pub struct ChannelCharityCampaignProgressV1 {
     /// The ID of the broadcaster that you want to receive notifications about when their campaign makes progress or is updated.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -33,16 +35,21 @@ impl EventSubscription for ChannelCharityCampaignProgressV1 {
 
 /// [`channel.charity_campaign.progress`](ChannelCharityCampaignProgressV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelCharityCampaignProgressV1Payload {
     /// An ID that identifies the charity campaign.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::CharityCampaignId,
     /// An ID that identifies the broadcaster that’s running the campaign.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_id: types::UserId,
     /// An ID that identifies the charity campaign.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_login: types::UserName,
     /// An ID that identifies the broadcaster that’s running the campaign.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_name: types::DisplayName,
     /// The charity’s name.
     pub charity_name: String,
@@ -4,11 +4,13 @@
 use super::*;
 /// [`channel.charity_campaign.stop`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelcharity_campaignstop): a broadcaster stops a charity campaign.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 pub struct ChannelCharityCampaignStopV1 {
     /// The ID of the broadcaster that you want to receive notifications about when they stop a charity campaign.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -33,16 +35,21 @@ impl EventSubscription for ChannelCharityCampaignStopV1 {
 
 /// [`channel.charity_campaign.stop`](ChannelCharityCampaignStopV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelCharityCampaignStopV1Payload {
     /// An ID that identifies the charity campaign.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::CharityCampaignId,
     /// An ID that identifies the broadcaster that ran the campaign.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_id: types::UserId,
     /// The broadcaster’s login name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_login: types::UserName,
     /// The broadcaster’s display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_name: types::DisplayName,
     /// The charity’s name.
     pub charity_name: String,
@@ -57,6 +64,7 @@ pub struct ChannelCharityCampaignStopV1Payload {
     /// An object that contains the campaign’s target fundraising goal.
     pub target_amount: crate::extra::DonationAmount,
     /// The UTC timestamp (in RFC3339 format) of when the broadcaster stopped the campaign.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub stopped_at: types::Timestamp,
 }
 
@@ -4,15 +4,18 @@
 use super::*;
 /// [`channel.chat.clear_user_messages`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelchatclear_user_messages): a moderator or bot clears all messages for a specific user.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelChatClearUserMessagesV1 {
     /// User ID of the channel to receive chat clear user messages events for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The user ID to read chat as.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
 }
 
@@ -41,22 +44,29 @@ impl EventSubscription for ChannelChatClearUserMessagesV1 {
 
 /// [`channel.chat.clear_user_messages`](ChannelChatClearUserMessagesV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelChatClearUserMessagesV1Payload {
     /// The broadcaster user ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The ID of the user that was banned or put in a timeout.
     ///
     /// All of their messages are deleted.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub target_user_id: types::UserId,
     /// The user name of the user that was banned or put in a timeout.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub target_user_name: types::DisplayName,
     /// The user login of the user that was banned or put in a timeout.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub target_user_login: types::UserName,
 }
 
@@ -5,15 +5,18 @@ use super::*;
 
 /// [`channel.chat.message`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchatmessage): a user sends a message to a specific chat room.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelChatMessageV1 {
     /// User ID of the channel to receive chat message events for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The user ID to read chat as.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
 }
 
@@ -45,22 +48,30 @@ impl EventSubscription for ChannelChatMessageV1 {
 
 /// [`channel.chat.message`](ChannelChatMessageV1Payload) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelChatMessageV1Payload {
     /// The broadcaster user ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::UserName,
     /// The broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The user ID of the user that sent the message.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub chatter_user_id: types::UserId,
     /// The user name of the user that sent the message.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub chatter_user_name: types::UserName,
     /// The user login of the user that sent the message.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub chatter_user_login: types::UserName,
     /// A UUID that identifies the message.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub message_id: types::MsgId,
     /// The structured chat message.
     pub message: Message,
@@ -73,17 +84,26 @@ pub struct ChannelChatMessageV1Payload {
     /// The color of the user's name in the chat room.
     /// This is a hexadecimal RGB color code in the form, `#<RGB>`.
     /// This may be empty if it is never set.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub color: types::HexColor,
     /// Metadata if this message is a reply.
     pub reply: Option<Reply>,
     /// The ID of a channel points custom reward that was redeemed.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub channel_points_custom_reward_id: Option<types::RewardId>,
     /// An ID for the type of animation selected as part of an “animate my message” redemption.
     pub channel_points_animation_id: Option<String>,
 }
 
+impl crate::eventsub::BroadcasterInfo for ChannelChatMessageV1Payload {
+    fn broadcaster_id(&self) -> &types::UserIdRef { &self.broadcaster_user_id }
+
+    fn broadcaster_login(&self) -> &types::UserNameRef { &self.broadcaster_user_login }
+}
+
 /// The type a message.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(rename_all = "snake_case")]
 pub enum MessageType {
@@ -103,13 +123,16 @@ pub enum MessageType {
 
 /// Chat badge
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Badge {
     /// An ID that identifies this set of chat badges. For example, Bits or Subscriber.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub set_id: types::BadgeSetId,
     /// An ID that identifies this version of the badge. The ID can be any value.
     /// For example, for Bits, the ID is the Bits tier level, but for World of Warcraft, it could be Alliance or Horde.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::ChatBadgeId,
     /// Contains metadata related to the chat badges in the badges tag.
     /// Currently, this tag contains metadata only for subscriber badges, to indicate the number of months the user has been a subscriber.
@@ -118,6 +141,7 @@ pub struct Badge {
 
 /// Metadata for cheer messages
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Cheer {
@@ -127,26 +151,35 @@ pub struct Cheer {
 
 /// Metadata for reply messages
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Reply {
     /// An ID that uniquely identifies the parent message that this message is replying to.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub parent_message_id: types::MsgId,
     /// The message body of the parent message.
     pub parent_message_body: String,
     /// User ID of the sender of the parent message.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub parent_user_id: types::UserId,
     /// User name of the sender of the parent message.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub parent_user_name: types::UserName,
     /// User login of the sender of the parent message.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub parent_user_login: types::UserName,
     /// An ID that identifies the parent message of the reply thread.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub thread_message_id: types::MsgId,
     /// User ID of the sender of the thread's parent message.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub thread_user_id: types::UserId,
     /// User name of the sender of the thread's parent message.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub thread_user_name: types::UserName,
     /// User login of the sender of the thread's parent message.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub thread_user_login: types::UserName,
 }
 
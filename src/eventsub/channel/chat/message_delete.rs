@@ -4,15 +4,18 @@
 use super::*;
 /// [`channel.chat.message_delete`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelchatmessage_delete): a moderator removes a specific message.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelChatMessageDeleteV1 {
     /// User ID of the channel to receive chat message delete events for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The user ID to read chat as.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
 }
 
@@ -41,22 +44,30 @@ impl EventSubscription for ChannelChatMessageDeleteV1 {
 
 /// [`channel.chat.message_delete`](ChannelChatMessageDeleteV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelChatMessageDeleteV1Payload {
     /// The broadcaster user ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The ID of the user whose message was deleted.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub target_user_id: types::UserId,
     /// The user name of the user whose message was deleted.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub target_user_name: types::DisplayName,
     /// The user login of the user whose message was deleted.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub target_user_login: types::UserName,
     /// A UUID that identifies the message that was removed.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub message_id: types::MsgId,
 }
 
@@ -26,6 +26,7 @@ pub use notification::{ChannelChatNotificationV1, ChannelChatNotificationV1Paylo
 /// A message
 // XXX: this struct can never be deny_unknown_fields
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct Message {
     /// The chat message in plain text.
@@ -36,6 +37,7 @@ pub struct Message {
 
 /// A chat message fragment
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum Fragment {
@@ -81,6 +83,7 @@ impl Fragment {
 
 /// A cheermote fragment
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Cheermote {
@@ -98,43 +101,55 @@ pub struct Cheermote {
 
 /// An emote fragment
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Emote {
     /// An ID that uniquely identifies this emote.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::EmoteId,
     /// An ID that identifies the emote set that the emote belongs to.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub emote_set_id: types::EmoteSetId,
     /// The ID of the broadcaster who owns the emote.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub owner_id: types::UserId,
     /// The formats that the emote is available in. For example, if the emote is available only as a static PNG, the array contains only static. But if the emote is available as a static PNG and an animated GIF, the array contains static and animated. The possible formats are:
     ///
     /// * `animated` — An animated GIF is available for this emote.
     /// * `static` — A static PNG file is available for this emote.
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<String>"))]
     pub format: Vec<types::EmoteAnimationSetting>,
 }
 
 /// A user mention fragment
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Mention {
     /// The user ID of the mentioned user.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
     /// The user name of the mentioned user.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_name: types::DisplayName,
     /// The user login of the mentioned user.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_login: types::UserName,
 }
 
 /// A badge
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Badge {
     /// An ID that identifies this set of chat badges. For example, Bits or Subscriber.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub set_id: types::BadgeSetId,
     /// An ID that identifies this version of the badge. The ID can be any value. For example, for Bits, the ID is the Bits tier level, but for World of Warcraft, it could be Alliance or Horde.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::ChatBadgeId,
     /// Contains metadata related to the chat badges in the badges tag. Currently, this tag contains metadata only for subscriber badges, to indicate the number of months the user has been a subscriber.
     pub info: String,
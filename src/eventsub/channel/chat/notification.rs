@@ -4,15 +4,18 @@
 use super::*;
 /// [`channel.chat.notification`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelchatnotification): an event that appears in chat occurs, such as someone subscribing to the channel or a subscription is gifted.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelChatNotificationV1 {
     /// User ID of the channel to receive chat notification events for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The user ID to read chat as.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
 }
 
@@ -42,13 +45,17 @@ impl EventSubscription for ChannelChatNotificationV1 {
 // XXX: this struct can never be deny_unknown_fields
 /// [`channel.chat.notification`](ChannelChatNotificationV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct ChannelChatNotificationV1Payload {
     /// The broadcaster user ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The user ID of the user that sent the message.
     #[serde(flatten)]
@@ -58,6 +65,7 @@ pub struct ChannelChatNotificationV1Payload {
     /// The message Twitch shows in the chat room for this notice.
     pub system_message: String,
     /// A UUID that identifies the message.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub message_id: types::MsgId,
     /// The structured chat message
     pub message: Message,
@@ -175,6 +183,25 @@ impl<'de> serde::Deserialize<'de> for Chatter {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Chatter {
+    fn schema_name() -> String { "Chatter".to_owned() }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct Chatter {
+            chatter_user_id: Option<String>,
+            chatter_user_name: Option<String>,
+            chatter_user_login: Option<String>,
+            color: Option<String>,
+            chatter_is_anonymous: bool,
+        }
+
+        Chatter::json_schema(gen)
+    }
+}
+
 /// All possible notifications in [`ChannelChatNotificationV1Payload`]
 // XXX: this struct can never be deny_unknown_fields
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -258,8 +285,63 @@ impl crate::eventsub::NamedField for BitsBadgeTier {
     const NAME: &'static str = "bits_badge_tier";
 }
 
+// The variants are (de)serialized with `enum_field_as_inner`, which schemars can't see through.
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Notification {
+    fn schema_name() -> String { "Notification".to_owned() }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        #[derive(schemars::JsonSchema)]
+        #[serde(tag = "notice_type", rename_all = "snake_case")]
+        #[allow(dead_code)]
+        enum Notification {
+            #[serde(rename = "sub")]
+            Subscription {
+                sub: Subscription,
+            },
+            #[serde(rename = "resub")]
+            Resubscription {
+                resub: Resubscription,
+            },
+            SubGift {
+                sub_gift: SubGift,
+            },
+            CommunitySubGift {
+                community_sub_gift: CommunitySubGift,
+            },
+            GiftPaidUpgrade {
+                gift_paid_upgrade: GiftPaidUpgrade,
+            },
+            PrimePaidUpgrade {
+                prime_paid_upgrade: PrimePaidUpgrade,
+            },
+            Raid {
+                raid: Raid,
+            },
+            Unraid {
+                unraid: Unraid,
+            },
+            PayItForward {
+                pay_it_forward: PayItForward,
+            },
+            Announcement {
+                announcement: Announcement,
+            },
+            CharityDonation {
+                charity_donation: CharityDonation,
+            },
+            BitsBadgeTier {
+                bits_badge_tier: BitsBadgeTier,
+            },
+        }
+
+        Notification::json_schema(gen)
+    }
+}
+
 /// A subscription notification
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Subscription {
@@ -268,6 +350,7 @@ pub struct Subscription {
     /// * `1000` — First level of paid or Prime subscription
     /// * `2000` — Second level of paid subscription
     /// * `3000` — Third level of paid subscription
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub sub_tier: types::SubscriptionTier,
     /// Indicates if the subscription was obtained through Amazon Prime.
     pub is_prime: bool,
@@ -372,8 +455,27 @@ impl<'de> serde::Deserialize<'de> for Gifter {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Gifter {
+    fn schema_name() -> String { "Gifter".to_owned() }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct Gifter {
+            gifter_user_id: Option<String>,
+            gifter_user_name: Option<String>,
+            gifter_user_login: Option<String>,
+            gifter_is_anonymous: Option<bool>,
+        }
+
+        Gifter::json_schema(gen)
+    }
+}
+
 /// A resubcription notification
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct Resubscription {
     /// The total number of months the user has subscribed.
@@ -387,6 +489,7 @@ pub struct Resubscription {
     /// * `1000` — First level of paid or Prime subscription
     /// * `2000` — Second level of paid subscription
     /// * `3000` — Third level of paid subscription
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub sub_tier: types::SubscriptionTier,
     /// Indicates if the resub was obtained through Amazon Prime.
     pub is_prime: bool,
@@ -400,6 +503,7 @@ pub struct Resubscription {
 
 /// A subscription gift notification
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct SubGift {
@@ -408,18 +512,23 @@ pub struct SubGift {
     /// The amount of gifts the gifter has given in this channel. Null if anonymous.
     pub cumulative_total: Option<i32>,
     /// The user ID of the subscription gift recipient.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub recipient_user_id: types::UserId,
     /// The user name of the subscription gift recipient.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub recipient_user_name: types::DisplayName,
     /// The user login of the subscription gift recipient.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub recipient_user_login: types::UserName,
     /// The type of subscription plan being used. Possible values are:
     ///
     /// * `1000` — First level of paid subscription
     /// * `2000` — Second level of paid subscription
     /// * `3000` — Third level of paid subscription
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub sub_tier: types::SubscriptionTier,
     /// The ID of the associated community gift. Null if not associated with a community gift.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub community_gift_id: Option<types::CommunityGiftId>,
 }
 
@@ -427,10 +536,12 @@ pub struct SubGift {
 ///
 /// Contains the id for [`SubGift::community_gift_id`]
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct CommunitySubGift {
     /// The ID of the associated community gift.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::CommunityGiftId,
     /// Number of subscriptions being gifted.
     pub total: i32,
@@ -439,6 +550,7 @@ pub struct CommunitySubGift {
     /// * `1000` — First level of paid subscription
     /// * `2000` — Second level of paid subscription
     /// * `3000` — Third level of paid subscription
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub sub_tier: types::SubscriptionTier,
     /// The amount of gifts the gifter has given in this channel. Null if anonymous.
     pub cumulative_total: Option<i32>,
@@ -446,6 +558,7 @@ pub struct CommunitySubGift {
 
 /// A gift notification for a paid upgrade of a previously gifted subscription.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GiftPaidUpgrade {
     /// The gifter
@@ -455,6 +568,7 @@ pub struct GiftPaidUpgrade {
 
 /// A notification for a paid upgrade of a previous Twitch Prime channel subscription.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct PrimePaidUpgrade {
@@ -463,19 +577,24 @@ pub struct PrimePaidUpgrade {
     /// * `1000` — First level of paid subscription
     /// * `2000` — Second level of paid subscription
     /// * `3000` — Third level of paid subscription
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub sub_tier: types::SubscriptionTier,
 }
 
 /// A raid notification
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Raid {
     /// The user ID of the broadcaster raiding this channel.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
     /// The user name of the broadcaster raiding this channel.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_name: types::DisplayName,
     /// The login name of the broadcaster raiding this channel.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_login: types::UserName,
     /// The number of viewers raiding this channel from the broadcaster’s channel.
     pub viewer_count: i32,
@@ -485,6 +604,7 @@ pub struct Raid {
 
 /// A unraid notification
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Unraid {}
@@ -493,6 +613,7 @@ pub struct Unraid {}
 ///
 /// This event is triggered when a user gifts a subscription to another user in the channel when they were themselves gifted a subscription by another user in the channel.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct PayItForward {
@@ -500,15 +621,19 @@ pub struct PayItForward {
     #[serde(flatten)]
     pub gifter: Gifter,
     /// The user ID of the subscription gift recipient.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub recipient_user_id: Option<types::UserId>,
     /// The user name of the subscription gift recipient.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub recipient_user_name: Option<types::DisplayName>,
     /// The user login of the subscription gift recipient.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub recipient_user_login: Option<types::UserName>,
 }
 
 /// A announcement notification
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Announcement {
@@ -518,6 +643,7 @@ pub struct Announcement {
 
 /// A charity donation notification
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct CharityDonation {
@@ -529,6 +655,7 @@ pub struct CharityDonation {
 
 /// A bits badge tier upgrade notification
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct BitsBadgeTier {
@@ -4,12 +4,14 @@ use super::*;
 
 /// [`channel.cheer`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelcheer): a user cheers on the specified channel.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelCheerV1 {
     /// The broadcaster user ID for the channel you want to get cheer notifications for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -34,29 +36,42 @@ impl EventSubscription for ChannelCheerV1 {
 
 /// [`channel.cheer`](ChannelCheerV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelCheerV1Payload {
     /// The number of bits cheered.
     pub bits: i64,
     /// The requested broadcaster ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The requested broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The requested broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// Whether the user cheered anonymously or not.
     pub is_anonymous: bool,
     /// The message sent with the cheer.
     pub message: String,
     /// The user ID for the user who cheered on the specified channel. This is null/empty if is_anonymous is true.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub user_id: Option<types::UserId>,
     /// The user login for the user who cheered on the specified channel. This is null/empty if is_anonymous is true.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub user_login: Option<types::UserName>,
     /// The user display name for the user who cheered on the specified channel. This is null/empty if is_anonymous is true.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub user_name: Option<types::DisplayName>,
 }
 
+impl crate::eventsub::BroadcasterInfo for ChannelCheerV1Payload {
+    fn broadcaster_id(&self) -> &types::UserIdRef { &self.broadcaster_user_id }
+
+    fn broadcaster_login(&self) -> &types::UserNameRef { &self.broadcaster_user_login }
+}
+
 #[cfg(test)]
 #[test]
 fn parse_payload() {
@@ -5,6 +5,7 @@ use super::*;
 
 /// [`channel.follow` v1](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelfollow): a specified channel receives a follow.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[deprecated(note = "use `ChannelFollowV2` instead")]
@@ -12,6 +13,7 @@ use super::*;
 pub struct ChannelFollowV1 {
     /// The broadcaster user ID for the channel you want to get follow notifications for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -35,25 +37,39 @@ impl EventSubscription for ChannelFollowV1 {
 
 /// [`channel.follow`](ChannelFollowV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelFollowV1Payload {
     /// The requested broadcaster ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The requested broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The requested broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The user ID for the user now following the specified channel.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
     /// The user display name for the user now following the specified channel.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_name: types::DisplayName,
     /// The user login for the user now following the specified channel.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_login: types::UserName,
     /// RFC3339 timestamp of when the follow occurred.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub followed_at: types::Timestamp,
 }
 
+impl crate::eventsub::BroadcasterInfo for ChannelFollowV1Payload {
+    fn broadcaster_id(&self) -> &types::UserIdRef { &self.broadcaster_user_id }
+
+    fn broadcaster_login(&self) -> &types::UserNameRef { &self.broadcaster_user_login }
+}
+
 #[cfg(test)]
 #[test]
 fn parse_payload_v1() {
@@ -92,15 +108,18 @@ fn parse_payload_v1() {
 
 /// [`channel.follow` v2](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelfollow): a specified channel receives a follow.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelFollowV2 {
     /// The broadcaster user ID for the channel you want to get follow notifications for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The ID of the moderator of the channel you want to get follow notifications for. If you have authorization from the broadcaster rather than a moderator, specify the broadcaster’s user ID here.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub moderator_user_id: types::UserId,
 }
 
@@ -129,25 +148,39 @@ impl EventSubscription for ChannelFollowV2 {
 
 /// [`channel.follow`](ChannelFollowV2) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelFollowV2Payload {
     /// The requested broadcaster ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The requested broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The requested broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The user ID for the user now following the specified channel.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
     /// The user display name for the user now following the specified channel.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_name: types::DisplayName,
     /// The user login for the user now following the specified channel.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_login: types::UserName,
     /// RFC3339 timestamp of when the follow occurred.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub followed_at: types::Timestamp,
 }
 
+impl crate::eventsub::BroadcasterInfo for ChannelFollowV2Payload {
+    fn broadcaster_id(&self) -> &types::UserIdRef { &self.broadcaster_user_id }
+
+    fn broadcaster_login(&self) -> &types::UserNameRef { &self.broadcaster_user_login }
+}
+
 #[cfg(test)]
 #[test]
 fn parse_payload_v2() {
@@ -4,12 +4,14 @@
 use super::*;
 /// [`channel.goal.begin`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#begin-goals-event): a specified broadcaster begins a goal.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelGoalBeginV1 {
     /// The ID of the broadcaster to get notified about.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -34,19 +36,25 @@ impl EventSubscription for ChannelGoalBeginV1 {
 
 /// [`channel.goal.begin`](ChannelGoalBeginV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelGoalBeginV1Payload {
     /// An ID that uniquely identifies this goal.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::CreatorGoalId,
     /// An ID that uniquely identifies the broadcaster.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The broadcaster’s display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The broadcaster’s user handle.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The type of goal.
     #[serde(rename = "type")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub type_: types::CreatorGoalType,
     /// A description of the goal, if specified. The description may contain a maximum of 40 characters.
     pub description: String,
@@ -55,6 +63,7 @@ pub struct ChannelGoalBeginV1Payload {
     /// The goal’s target value. For example, if the broadcaster has 200 followers before creating the goal, and their goal is to double that number, this field is set to 400.
     pub target_amount: i64,
     /// The UTC timestamp in RFC 3339 format, which indicates when the broadcaster created the goal.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub started_at: types::Timestamp,
 }
 
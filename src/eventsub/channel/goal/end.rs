@@ -4,12 +4,14 @@
 use super::*;
 /// [`channel.goal.end`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#end-goals-event): a specified broadcaster ends a goal.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelGoalEndV1 {
     /// The ID of the broadcaster to get notified about.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -34,19 +36,25 @@ impl EventSubscription for ChannelGoalEndV1 {
 
 /// [`channel.goal.end`](ChannelGoalEndV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelGoalEndV1Payload {
     /// An ID that uniquely identifies this goal.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::CreatorGoalId,
     /// An ID that uniquely identifies the broadcaster.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The broadcaster’s display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The broadcaster’s user handle.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The type of goal.
     #[serde(rename = "type")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub type_: types::CreatorGoalType,
     /// A description of the goal, if specified. The description may contain a maximum of 40 characters.
     pub description: String,
@@ -57,8 +65,10 @@ pub struct ChannelGoalEndV1Payload {
     /// The goal’s target value.
     pub target_amount: i64,
     /// The UTC timestamp in RFC 3339 format, which indicates when the broadcaster created the goal.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub started_at: types::Timestamp,
     /// The UTC timestamp in RFC 3339 format, which indicates when the broadcaster reached the goal.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub ended_at: types::Timestamp,
 }
 
@@ -4,6 +4,7 @@
 use super::*;
 /// [`channel.hype_train.begin`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelhype_trainbegin): a hype train begins on the specified channel.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
@@ -11,6 +12,7 @@ pub struct ChannelHypeTrainBeginV1 {
     // FIXME: Twitch docs say "want to hype train"
     /// The broadcaster user ID for the channel you want hype train begin notifications for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -35,18 +37,24 @@ impl EventSubscription for ChannelHypeTrainBeginV1 {
 
 /// [`channel.hype_train.begin`](ChannelHypeTrainBeginV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelHypeTrainBeginV1Payload {
     /// The Hype Train ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::HypeTrainId,
     /// The requested broadcaster ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The requested broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The requested broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The time at which the hype train expires. The expiration is extended when the hype train reaches a new level.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub expires_at: types::Timestamp,
     /// The number of points required to reach the next level.
     pub goal: i64,
@@ -55,6 +63,7 @@ pub struct ChannelHypeTrainBeginV1Payload {
     /// The number of points contributed to the hype train at the current level.
     pub progress: i64,
     /// The timestamp at which the hype train started.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub started_at: types::Timestamp,
     // FIXME: Contains a maximum of two user objects
     /// The contributors with the most points contributed.
@@ -4,6 +4,7 @@
 use super::*;
 /// [`channel.hype_train.end`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelhype_trainend): a hype train ends on the specified channel.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
@@ -11,6 +12,7 @@ pub struct ChannelHypeTrainEndV1 {
     // FIXME: Twitch docs say "want to hype train"
     /// The broadcaster user ID for the channel you want hype train end notifications for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -35,24 +37,32 @@ impl EventSubscription for ChannelHypeTrainEndV1 {
 
 /// [`channel.hype_train.end`](ChannelHypeTrainEndV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelHypeTrainEndV1Payload {
     /// The Hype Train ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::HypeTrainId,
     /// The requested broadcaster ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The requested broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The requested broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The timestamp at which the hype train cooldown ends so that the next hype train can start.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub cooldown_ends_at: types::Timestamp,
     /// The timestamp at which the hype train ended.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub ended_at: types::Timestamp,
     /// Current level of hype train event.
     pub level: i64,
     /// The timestamp at which the hype train started.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub started_at: types::Timestamp,
     /// The contributors with the most points contributed.
     pub top_contributions: Vec<Contribution>,
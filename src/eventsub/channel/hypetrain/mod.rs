@@ -18,6 +18,7 @@ pub use progress::{ChannelHypeTrainProgressV1, ChannelHypeTrainProgressV1Payload
 // FIXME: Is this always the same as helix::endpoints::hypetrain::ContributionType?
 /// Type of contribution
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(rename_all = "lowercase")]
 pub enum ContributionType {
@@ -31,6 +32,7 @@ pub enum ContributionType {
 
 /// A contribution to hype train
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Contribution {
@@ -40,9 +42,12 @@ pub struct Contribution {
     /// Type of contribution. Valid values include bits, subscription.
     pub type_: ContributionType,
     /// The ID of the user.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
     /// The login of the user.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_login: types::UserName,
     /// The display name of the user.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_name: types::DisplayName,
 }
@@ -4,12 +4,14 @@
 use super::*;
 /// [`channel.poll.begin`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelpollbegin): a poll begins on the specified channel.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPollBeginV1 {
     /// The broadcaster user ID of the channel for which “poll begin” notifications will be received.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -36,26 +38,34 @@ impl EventSubscription for ChannelPollBeginV1 {
 
 /// [`channel.poll.begin`](ChannelPollBeginV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPollBeginV1Payload {
     /// The Bits voting settings for the poll.
     pub bits_voting: BitsVoting,
     /// The requested broadcaster ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The requested broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The requested broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The Channel Points voting settings for the poll.
     pub channel_points_voting: ChannelPointsVoting,
     /// An array of choices for the poll.
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<serde_json::Value>"))]
     pub choices: Vec<types::PollChoice>,
     /// The time the poll will end.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub ends_at: types::Timestamp,
     /// ID of the poll.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::PollId,
     /// The time the poll started.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub started_at: types::Timestamp,
     /// Question displayed for the poll.
     pub title: String,
@@ -4,12 +4,14 @@
 use super::*;
 /// [`channel.poll.end`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelpollend): a poll ends on the specified channel.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPollEndV1 {
     /// The broadcaster user ID of the channel for which “poll end” notifications will be received.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -36,28 +38,37 @@ impl EventSubscription for ChannelPollEndV1 {
 
 /// [`channel.poll.end`](ChannelPollEndV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPollEndV1Payload {
     /// The Bits voting settings for the poll.
     pub bits_voting: BitsVoting,
     /// The requested broadcaster ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The requested broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The requested broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The Channel Points voting settings for the poll.
     pub channel_points_voting: ChannelPointsVoting,
     /// An array of choices for the poll. Includes vote counts.
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<serde_json::Value>"))]
     pub choices: Vec<types::PollChoice>,
     /// The time the poll ended.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub ended_at: types::Timestamp,
     /// ID of the poll.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::PollId,
     /// The time the poll started.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub started_at: types::Timestamp,
     /// The status of the poll.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub status: types::PollStatus,
     /// Question displayed for the poll.
     pub title: String,
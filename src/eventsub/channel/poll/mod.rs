@@ -17,6 +17,7 @@ pub use progress::{ChannelPollProgressV1, ChannelPollProgressV1Payload};
 
 /// Bits voting settings for a poll
 #[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct BitsVoting {
@@ -29,6 +30,7 @@ pub struct BitsVoting {
 
 /// Channel Points voting settings
 #[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPointsVoting {
@@ -4,12 +4,14 @@
 use super::*;
 /// [`channel.prediction.begin`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelpredictionbegin-beta): a Prediction begins on the specified channel
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPredictionBeginV1 {
     /// The broadcaster user ID of the channel for which “prediction begin” notifications will be received.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -36,22 +38,30 @@ impl EventSubscription for ChannelPredictionBeginV1 {
 
 /// [`channel.prediction.begin`](ChannelPredictionBeginV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPredictionBeginV1Payload {
     /// The requested broadcaster ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The requested broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The requested broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// Channel Points Prediction ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::PredictionId,
     /// The time the Channel Points Prediction will automatically lock.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub locks_at: types::Timestamp,
     /// An array of outcomes for the Channel Points Prediction.
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<serde_json::Value>"))]
     pub outcomes: Vec<types::PredictionOutcome>,
     /// The time the Channel Points Prediction started.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub started_at: types::Timestamp,
     /// Title for the Channel Points Prediction.
     pub title: String,
@@ -4,12 +4,14 @@
 use super::*;
 /// [`channel.prediction.end`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelpredictionend): a Prediction ends on the specified channel.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPredictionEndV1 {
     /// The broadcaster user ID of the channel for which “prediction end” notifications will be received.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -36,28 +38,38 @@ impl EventSubscription for ChannelPredictionEndV1 {
 
 /// [`channel.prediction.end`](ChannelPredictionEndV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPredictionEndV1Payload {
     /// The requested broadcaster ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The requested broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The requested broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The time the Channel Points Prediction ended.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub ended_at: types::Timestamp,
     /// Channel Points Prediction ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::PredictionId,
     /// An array of outcomes for the Channel Points Prediction. Includes top_predictors.
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<serde_json::Value>"))]
     pub outcomes: Vec<types::PredictionOutcome>,
     /// The time the Channel Points Prediction started.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub started_at: types::Timestamp,
     /// The status of the Channel Points Prediction. Valid values are resolved and canceled.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub status: types::PredictionStatus,
     /// Title for the Channel Points Prediction.
     pub title: String,
     /// ID of the winning outcome.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub winning_outcome_id: types::PredictionOutcomeId,
 }
 
@@ -3,15 +3,18 @@ use super::*;
 
 /// [`channel.raid`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelraid): a a broadcaster raids another broadcaster’s channel.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelRaidV1 {
     /// The broadcaster user ID that created the channel raid you want to get notifications for. Use this parameter if you want to know when a specific broadcaster raids another broadcaster.
     #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub from_broadcaster_user_id: Option<types::UserId>,
     /// The broadcaster user ID that received the channel raid you want to get notifications for. Use this parameter if you want to know when a specific broadcaster is raided by another broadcaster.
     #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub to_broadcaster_user_id: Option<types::UserId>,
 }
 
@@ -44,25 +47,39 @@ impl EventSubscription for ChannelRaidV1 {
 
 /// [`channel.raid`](ChannelRaidV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelRaidV1Payload {
     /// The broadcaster ID that created the raid.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub from_broadcaster_user_id: types::UserId,
     /// The broadcaster login that created the raid.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub from_broadcaster_user_login: types::UserName,
     /// The broadcaster display name that created the raid.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub from_broadcaster_user_name: types::DisplayName,
     /// The broadcaster ID that received the raid.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub to_broadcaster_user_id: types::UserId,
     /// The broadcaster login that received the raid.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub to_broadcaster_user_login: types::UserName,
     /// The broadcaster display name that received the raid.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub to_broadcaster_user_name: types::DisplayName,
     /// The number of viewers in the raid.
     pub viewers: i64,
 }
 
+/// The broadcaster that is being raided
+impl crate::eventsub::BroadcasterInfo for ChannelRaidV1Payload {
+    fn broadcaster_id(&self) -> &types::UserIdRef { &self.to_broadcaster_user_id }
+
+    fn broadcaster_login(&self) -> &types::UserNameRef { &self.to_broadcaster_user_login }
+}
+
 #[cfg(test)]
 #[test]
 fn parse_payload() {
@@ -98,3 +115,24 @@ fn parse_payload() {
     let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
     crate::tests::roundtrip(&val)
 }
+
+#[cfg(test)]
+#[test]
+fn broadcaster_info() {
+    use crate::eventsub::BroadcasterInfo;
+
+    let payload: ChannelRaidV1Payload = serde_json::from_str(
+        r#"{
+            "from_broadcaster_user_id": "1234",
+            "from_broadcaster_user_login": "cool_user",
+            "from_broadcaster_user_name": "Cool_User",
+            "to_broadcaster_user_id": "1337",
+            "to_broadcaster_user_login": "cooler_user",
+            "to_broadcaster_user_name": "Cooler_User",
+            "viewers": 9001
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(payload.broadcaster_id().as_str(), "1337");
+    assert_eq!(payload.broadcaster_login().as_str(), "cooler_user");
+}
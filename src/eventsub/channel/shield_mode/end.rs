@@ -4,13 +4,16 @@
 use super::*;
 /// [`channel.shield_mode.end`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelshield_modeend): an user responds to a prediction on the specified channel
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelShieldModeEndV1 {
     /// The ID of the broadcaster that you want to receive notifications about when they deactivate  Shield Mode.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The ID of the broadcaster or one of the broadcaster’s moderators.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub moderator_user_id: types::UserId,
 }
 
@@ -41,22 +44,30 @@ impl EventSubscription for ChannelShieldModeEndV1 {
 
 /// [`channel.shield_mode.end`](ChannelShieldModeEndV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelShieldModeEndV1Payload {
     /// An ID that identifies the broadcaster whose Shield Mode status was updated.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The broadcaster’s login name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The broadcaster’s display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// An ID that identifies the moderator that updated the Shield Mode’s status. If the broadcaster updated the status, this ID will be the same as broadcaster_user_id.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub moderator_user_id: types::UserId,
     /// The moderator’s login name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub moderator_user_login: types::UserName,
     /// The moderator’s display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub moderator_user_name: types::DisplayName,
     /// The UTC timestamp (in RFC3339 format) of when the moderator deactivated Shield Mode.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub ended_at: types::Timestamp,
 }
 
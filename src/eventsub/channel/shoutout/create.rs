@@ -4,15 +4,18 @@
 use super::*;
 /// [`channel.shoutout.create`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelshoutoutcreate): a Prediction begins on the specified channel
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelShoutoutCreateV1 {
     /// The ID of the broadcaster that you want to receive notifications about when they send a Shoutout.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The ID of the broadcaster that gave the Shoutout or one of the broadcaster’s moderators.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub moderator_user_id: types::UserId,
 }
 
@@ -43,34 +46,47 @@ impl EventSubscription for ChannelShoutoutCreateV1 {
 
 /// [`channel.shoutout.create`](ChannelShoutoutCreateV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelShoutoutCreateV1Payload {
     /// An ID that identifies the broadcaster that sent the Shoutout.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The broadcaster’s login name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The broadcaster’s display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// An ID that identifies the broadcaster that received the Shoutout.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub to_broadcaster_user_id: types::UserId,
     /// The broadcaster’s login name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub to_broadcaster_user_login: types::UserName,
     /// The broadcaster’s display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub to_broadcaster_user_name: types::DisplayName,
     /// An ID that identifies the moderator that sent the Shoutout. If the broadcaster sent the Shoutout, this ID is the same as the ID in [`broadcaster_user_id`](Self::broadcaster_user_id).
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub moderator_user_id: types::UserId,
     /// The moderator’s login name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub moderator_user_login: types::UserName,
     /// The moderator’s display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub moderator_user_name: types::DisplayName,
     /// The number of users that were watching the broadcaster’s stream at the time of the Shoutout.
     pub viewer_count: i64,
     /// The UTC timestamp (in RFC3339 format) of when the moderator sent the Shoutout.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub started_at: types::Timestamp,
     /// The UTC timestamp (in RFC3339 format) of when the broadcaster may send a Shoutout to a different broadcaster.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub cooldown_ends_at: types::Timestamp,
     /// The UTC timestamp (in RFC3339 format) of when the broadcaster may send another Shoutout to the broadcaster in [`to_broadcaster_user_id`](Self::to_broadcaster_user_id).
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub target_cooldown_ends_at: types::Timestamp,
 }
 
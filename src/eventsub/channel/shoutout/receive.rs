@@ -4,15 +4,18 @@
 use super::*;
 /// [`channel.shoutout.receive`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelshoutoutreceive): a Prediction begins on the specified channel
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelShoutoutReceiveV1 {
     /// The ID of the broadcaster that you want to receive notifications about when they receive a Shoutout.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The ID of the broadcaster that received the Shoutout or one of the broadcaster’s moderators.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub moderator_user_id: types::UserId,
 }
 
@@ -43,24 +46,32 @@ impl EventSubscription for ChannelShoutoutReceiveV1 {
 
 /// [`channel.shoutout.receive`](ChannelShoutoutReceiveV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelShoutoutReceiveV1Payload {
     /// An ID that identifies the broadcaster that received the Shoutout.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The broadcaster’s login name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The broadcaster’s display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// An ID that identifies the broadcaster that sent the Shoutout.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub from_broadcaster_user_id: types::UserId,
     /// The broadcaster’s login name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub from_broadcaster_user_login: types::UserName,
     /// The broadcaster’s display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub from_broadcaster_user_name: types::DisplayName,
     /// The number of users that were watching the from-broadcaster’s stream at the time of the Shoutout.
     pub viewer_count: i64,
     /// The UTC timestamp (in RFC3339 format) of when the moderator sent the Shoutout.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub started_at: types::Timestamp,
 }
 
@@ -6,12 +6,14 @@ use super::*;
 
 /// [`channel.subscribe`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelsubscribe): a specified channel receives a subscriber. This does not include resubscribes.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelSubscribeV1 {
     /// The broadcaster user ID for the channel you want to get subscribe notifications for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -36,27 +38,41 @@ impl EventSubscription for ChannelSubscribeV1 {
 
 /// [`channel.subscribe`](ChannelSubscribeV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelSubscribeV1Payload {
     /// The requested broadcaster ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The requested broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The requested broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// Whether the subscription is a gift.
     pub is_gift: bool,
     /// The tier of the subscription. Valid values are 1000, 2000, and 3000.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub tier: types::SubscriptionTier,
     /// The user ID for the user who subscribed to the specified channel.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
     /// The user login for the user who subscribed to the specified channel.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_login: types::UserName,
     /// The user display name for the user who subscribed to the specified channel.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_name: types::DisplayName,
 }
 
+impl crate::eventsub::BroadcasterInfo for ChannelSubscribeV1Payload {
+    fn broadcaster_id(&self) -> &types::UserIdRef { &self.broadcaster_user_id }
+
+    fn broadcaster_login(&self) -> &types::UserNameRef { &self.broadcaster_user_login }
+}
+
 #[cfg(test)]
 #[test]
 fn parse_payload() {
@@ -4,12 +4,14 @@
 use super::*;
 /// [`channel.subscription.end`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelSubscriptionEnd): a subscription to the specified channel expires.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelSubscriptionEndV1 {
     /// The broadcaster user ID for the channel you want to get subscription end notifications for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -34,22 +36,30 @@ impl EventSubscription for ChannelSubscriptionEndV1 {
 
 /// [`channel.subscription.end`](ChannelSubscriptionEndV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelSubscriptionEndV1Payload {
     /// The broadcaster user ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::DisplayName,
     /// The broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::UserName,
     /// The user ID for the user whose subscription ended.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
     /// The user login for the user whose subscription ended.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_login: types::DisplayName,
     /// The user display name for the user whose subscription ended.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_name: types::UserName,
     /// The tier of the subscription that ended. Valid values are 1000, 2000, and 3000.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub tier: types::SubscriptionTier,
     /// Whether the subscription was a gift.
     pub is_gift: bool,
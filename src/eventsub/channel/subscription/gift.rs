@@ -4,12 +4,14 @@
 use super::*;
 /// [`channel.subscription.gift`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelsubscriptiongift): a subscription to the specified channel expires.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelSubscriptionGiftV1 {
     /// The broadcaster user ID for the channel you want to get subscription gift notifications for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -34,28 +36,36 @@ impl EventSubscription for ChannelSubscriptionGiftV1 {
 
 /// [`channel.subscription.gift`](ChannelSubscriptionGiftV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelSubscriptionGiftV1Payload {
     /// The broadcaster user ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::DisplayName,
     /// The broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::UserName,
     /// The number of subscriptions gifted by this user in the channel. This value is null for anonymous gifts or if the gifter has opted out of sharing this information.
     pub cumulative_total: Option<i64>,
     /// Whether the subscription gift was anonymous.
     pub is_anonymous: bool,
     /// The tier of subscriptions in the subscription gift.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub tier: types::SubscriptionTier,
     /// The number of subscriptions in the subscription gift.
     pub total: i64,
     /// The user ID of the user who sent the subscription gift. Set to null if it was an anonymous subscription gift.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub user_id: Option<types::UserId>,
     /// The user login of the user who sent the gift. Set to null if it was an anonymous subscription gift.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub user_login: Option<types::DisplayName>,
     /// The user display name of the user who sent the gift. Set to null if it was an anonymous subscription gift.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub user_name: Option<types::UserName>,
 }
 
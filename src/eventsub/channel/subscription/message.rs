@@ -4,12 +4,14 @@
 use super::*;
 /// [`channel.subscription.message`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelsubscriptionmessage): a subscription to the specified channel expires.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelSubscriptionMessageV1 {
     /// The broadcaster user ID for the channel you want to get resubscription chat message notifications for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -34,14 +36,18 @@ impl EventSubscription for ChannelSubscriptionMessageV1 {
 
 /// [`channel.subscription.message`](ChannelSubscriptionMessageV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelSubscriptionMessageV1Payload {
     /// The broadcaster user ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The broadcaster display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     // FIXME: Twitch says this is `cumulative_total`, see https://github.com/twitchdev/issues/issues/415
     /// The total number of months the user has been subscribed to the channel.
@@ -53,17 +59,22 @@ pub struct ChannelSubscriptionMessageV1Payload {
     /// The number of consecutive months the user’s current subscription has been active. This value is null if the user has opted out of sharing this information.
     pub streak_months: Option<i64>,
     /// The tier of the user’s subscription.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub tier: types::SubscriptionTier,
     /// The user ID of the user who sent a resubscription chat message.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
     /// The user login of the user who sent a resubscription chat message.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_login: types::UserName,
     /// The user display name of the user who a resubscription chat message.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_name: types::DisplayName,
 }
 
 /// A message attached to a [`ChannelSubscriptionMessageV1Payload`]
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct SubscriptionMessage {
@@ -71,6 +82,7 @@ pub struct SubscriptionMessage {
     pub text: String,
     /// An array that includes the emote ID and start and end positions for where the emote appears in the text.
     #[serde(deserialize_with = "crate::deserialize_default_from_null")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<serde_json::Value>"))]
     pub emotes: Vec<types::ResubscriptionEmote>,
 }
 
@@ -4,12 +4,14 @@ use super::*;
 
 /// [`channel.update`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelupdate) subscription type sends notifications when a broadcaster updates the category, title, mature flag, or broadcast language for their channel.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelUpdateV2 {
     /// The broadcaster user ID for the channel you want to get updates for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -33,36 +35,50 @@ impl EventSubscription for ChannelUpdateV2 {
 
 /// [`channel.update`](ChannelUpdateV2) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelUpdateV2Payload {
     /// The broadcaster’s user ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The requested broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The broadcaster’s user display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The channel’s stream title.
     pub title: String,
     /// The channel’s broadcast language.
     pub language: String,
     /// The channel’s category ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub category_id: types::CategoryId,
     /// The category name.
     pub category_name: String,
     /// Array of content classification label IDs currently applied on the Channel.
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<String>"))]
     pub content_classification_labels: Vec<types::ContentClassificationId>,
 }
 
+impl crate::eventsub::BroadcasterInfo for ChannelUpdateV2Payload {
+    fn broadcaster_id(&self) -> &types::UserIdRef { &self.broadcaster_user_id }
+
+    fn broadcaster_login(&self) -> &types::UserNameRef { &self.broadcaster_user_login }
+}
+
 #[deprecated(note = "Use `ChannelUpdateV2` instead")]
 /// version 1 of [`channel.update`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelupdate) subscription type sends notifications when a broadcaster updates the category, title, mature flag, or broadcast language for their channel.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelUpdateV1 {
     /// The broadcaster user ID for the channel you want to get updates for.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
 }
 
@@ -87,20 +103,25 @@ impl EventSubscription for ChannelUpdateV1 {
 /// [`channel.update`](ChannelUpdateV1) response payload.
 #[deprecated(note = "Use `ChannelUpdateV2` instead")]
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelUpdateV1Payload {
     /// The broadcaster’s user ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_id: types::UserId,
     /// The requested broadcaster login.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_login: types::UserName,
     /// The broadcaster’s user display name.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub broadcaster_user_name: types::DisplayName,
     /// The channel’s stream title.
     pub title: String,
     /// The channel’s broadcast language.
     pub language: String,
     /// The channel’s category ID.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub category_id: types::CategoryId,
     /// The category name.
     pub category_name: String,
@@ -108,6 +129,12 @@ pub struct ChannelUpdateV1Payload {
     pub is_mature: bool,
 }
 
+impl crate::eventsub::BroadcasterInfo for ChannelUpdateV1Payload {
+    fn broadcaster_id(&self) -> &types::UserIdRef { &self.broadcaster_user_id }
+
+    fn broadcaster_login(&self) -> &types::UserNameRef { &self.broadcaster_user_login }
+}
+
 #[cfg(test)]
 #[test]
 fn parse_payload_v2() {
@@ -5,6 +5,7 @@ use super::*;
 
 /// [`conduit.shard.disabled`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#conduitsharddisabled)
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
@@ -14,7 +15,8 @@ pub struct ConduitShardDisabledV1 {
     pub client_id: String,
     /// Optional. The conduit ID to receive events for. If omitted, events for all of this client’s conduits are sent.
     #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
-    pub conduit_id: Option<String>,
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub conduit_id: Option<eventsub::ConduitId>,
 }
 
 impl ConduitShardDisabledV1 {
@@ -27,7 +29,7 @@ impl ConduitShardDisabledV1 {
     }
 
     /// The conduit ID to receive events for. If omitted, events for all of this client’s conduits are sent.
-    pub fn conduit_id(mut self, conduit_id: impl Into<String>) -> Self {
+    pub fn conduit_id(mut self, conduit_id: impl Into<eventsub::ConduitId>) -> Self {
         self.conduit_id = Some(conduit_id.into());
         self
     }
@@ -46,13 +48,16 @@ impl EventSubscription for ConduitShardDisabledV1 {
 
 /// [`conduit.shard.disabled`](ConduitShardDisabledV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ConduitShardDisabledV1Payload {
     /// The conduit ID.
-    pub conduit_id: String,
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub conduit_id: eventsub::ConduitId,
     /// The shard ID within the conduit.
-    pub shard_id: String,
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub shard_id: eventsub::ConduitShardId,
     /// The status of the disabled shard.
     pub status: eventsub::ShardStatus,
     /// The transport details about the disable shard.
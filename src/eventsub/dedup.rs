@@ -0,0 +1,218 @@
+//! Deduplication of redelivered notifications
+
+use std::{
+    collections::{HashSet, VecDeque},
+    convert::TryFrom,
+};
+
+use crate::types;
+
+use super::{NotificationMetadata, PayloadParseError};
+
+/// Remembers the message ids of recent notifications to detect redeliveries
+///
+/// Twitch can send the same notification more than once, with the same message id, so handlers should ignore ids they have already processed.
+/// Ids are remembered for [`window`](MessageDeduplicator::with_window), counted from the newest message timestamp seen,
+/// and at most [`capacity`](MessageDeduplicator::with_capacity) ids are kept, forgetting the oldest first.
+/// This keeps memory bounded no matter how many notifications arrive.
+///
+/// Messages with a timestamp older than the window are never reported as new, as Twitch recommends ignoring them.
+///
+/// To share a deduplicator between handlers, put it behind a [`Mutex`](std::sync::Mutex).
+///
+/// # Examples
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use twitch_api::eventsub::MessageDeduplicator;
+/// use twitch_api::types::Timestamp;
+///
+/// let dedup = std::sync::Mutex::new(MessageDeduplicator::new());
+/// let ts = Timestamp::try_from("2023-03-01T00:00:00Z").unwrap();
+///
+/// assert!(dedup
+///     .lock()
+///     .unwrap()
+///     .check_and_insert("befa7b53-d79d-478f-86b9-120f112b044e", &ts));
+/// // the same message again
+/// assert!(!dedup
+///     .lock()
+///     .unwrap()
+///     .check_and_insert("befa7b53-d79d-478f-86b9-120f112b044e", &ts));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MessageDeduplicator {
+    window: std::time::Duration,
+    capacity: usize,
+    seen: HashSet<Box<str>>,
+    /// Unix timestamp in nanoseconds and id, in the order they were inserted
+    order: VecDeque<(i128, Box<str>)>,
+    newest: Option<i128>,
+}
+
+impl MessageDeduplicator {
+    /// Default maximum amount of message ids to remember
+    pub const DEFAULT_CAPACITY: usize = 10_000;
+    /// Default duration message ids are remembered for, Twitch redelivers notifications for up to 10 minutes.
+    pub const DEFAULT_WINDOW: std::time::Duration = std::time::Duration::from_secs(10 * 60);
+
+    /// Create a deduplicator with the [default window](Self::DEFAULT_WINDOW) and [default capacity](Self::DEFAULT_CAPACITY)
+    pub fn new() -> Self {
+        Self {
+            window: Self::DEFAULT_WINDOW,
+            capacity: Self::DEFAULT_CAPACITY,
+            seen: HashSet::new(),
+            order: VecDeque::new(),
+            newest: None,
+        }
+    }
+
+    /// Set how long message ids are remembered for, counted from the newest message timestamp seen
+    pub fn with_window(mut self, window: std::time::Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Set the maximum amount of message ids to remember, at least one id is always remembered
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        while self.order.len() > self.capacity {
+            self.evict_oldest();
+        }
+        self
+    }
+
+    /// Amount of message ids currently remembered
+    pub fn len(&self) -> usize { self.order.len() }
+
+    /// Returns `true` if no message ids are remembered
+    pub fn is_empty(&self) -> bool { self.order.is_empty() }
+
+    /// Check if the message with `id`, sent at `timestamp`, is new, and remember it.
+    ///
+    /// Returns `false` if the message was seen before or is older than the window.
+    pub fn check_and_insert(&mut self, id: &str, timestamp: &types::TimestampRef) -> bool {
+        let timestamp = timestamp.to_utc().unix_timestamp_nanos();
+        let newest = self
+            .newest
+            .map_or(timestamp, |newest| newest.max(timestamp));
+        self.newest = Some(newest);
+        let cutoff =
+            newest.saturating_sub(i128::try_from(self.window.as_nanos()).unwrap_or(i128::MAX));
+
+        while matches!(self.order.front(), Some((seen_at, _)) if *seen_at < cutoff) {
+            self.evict_oldest();
+        }
+        if timestamp < cutoff || self.seen.contains(id) {
+            return false;
+        }
+        if self.order.len() >= self.capacity {
+            self.evict_oldest();
+        }
+        self.seen.insert(id.into());
+        self.order.push_back((timestamp, id.into()));
+        true
+    }
+
+    /// Check if a notification received over a webhook is new, and remember it.
+    ///
+    /// Uses the `Twitch-Eventsub-Message-Id` and `Twitch-Eventsub-Message-Timestamp` headers, see [`check_and_insert`](Self::check_and_insert).
+    /// Verify the request with [`Event::verify_payload`](super::Event::verify_payload) first, so that forged requests can't fill the deduplicator.
+    pub fn check_http<B>(&mut self, request: &http::Request<B>) -> Result<bool, PayloadParseError> {
+        let header = |name| {
+            request
+                .headers()
+                .get(name)
+                .ok_or(PayloadParseError::MalformedEvent)
+                .and_then(|v| std::str::from_utf8(v.as_bytes()).map_err(Into::into))
+        };
+        let id = header("Twitch-Eventsub-Message-Id")?;
+        let timestamp =
+            types::Timestamp::new(header("Twitch-Eventsub-Message-Timestamp")?.to_owned())
+                .map_err(|_| PayloadParseError::MalformedEvent)?;
+        Ok(self.check_and_insert(id, &timestamp))
+    }
+
+    /// Check if a notification received over a websocket is new, and remember it.
+    ///
+    /// Uses the [`message_id`](NotificationMetadata::message_id) and [`message_timestamp`](NotificationMetadata::message_timestamp), see [`check_and_insert`](Self::check_and_insert).
+    pub fn check_websocket(&mut self, metadata: &NotificationMetadata<'_>) -> bool {
+        self.check_and_insert(&metadata.message_id, &metadata.message_timestamp)
+    }
+
+    fn evict_oldest(&mut self) {
+        if let Some((_, id)) = self.order.pop_front() {
+            self.seen.remove(&id);
+        }
+    }
+}
+
+impl Default for MessageDeduplicator {
+    fn default() -> Self { Self::new() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts(s: &str) -> types::Timestamp { types::Timestamp::new(s.to_owned()).unwrap() }
+
+    #[test]
+    fn duplicates_in_window() {
+        let mut dedup = MessageDeduplicator::new();
+        assert!(dedup.check_and_insert("a", &ts("2023-03-01T00:00:00Z")));
+        assert!(dedup.check_and_insert("b", &ts("2023-03-01T00:00:01Z")));
+        assert!(!dedup.check_and_insert("a", &ts("2023-03-01T00:09:59Z")));
+        assert!(!dedup.check_and_insert("b", &ts("2023-03-01T00:00:01Z")));
+        assert_eq!(dedup.len(), 2);
+    }
+
+    #[test]
+    fn duplicates_outside_window() {
+        let mut dedup = MessageDeduplicator::new().with_window(std::time::Duration::from_secs(60));
+        assert!(dedup.check_and_insert("a", &ts("2023-03-01T00:00:00Z")));
+        assert!(dedup.check_and_insert("b", &ts("2023-03-01T00:01:30Z")));
+        // "a" was forgotten when "b" moved the window
+        assert_eq!(dedup.len(), 1);
+        assert!(dedup.check_and_insert("a", &ts("2023-03-01T00:01:31Z")));
+        // too old, even if never seen
+        assert!(!dedup.check_and_insert("c", &ts("2023-03-01T00:00:10Z")));
+        assert!(!dedup.check_and_insert("b", &ts("2023-03-01T00:01:31Z")));
+    }
+
+    #[test]
+    fn bounded_under_load() {
+        let mut dedup = MessageDeduplicator::new().with_capacity(100);
+        for i in 0..10_000 {
+            assert!(dedup.check_and_insert(&i.to_string(), &ts("2023-03-01T00:00:00Z")));
+            assert!(dedup.len() <= 100);
+        }
+        // the newest ids are still remembered, the oldest were evicted
+        assert!(!dedup.check_and_insert("9999", &ts("2023-03-01T00:00:00Z")));
+        assert!(!dedup.check_and_insert("9900", &ts("2023-03-01T00:00:00Z")));
+        assert!(dedup.check_and_insert("0", &ts("2023-03-01T00:00:00Z")));
+        assert_eq!(dedup.len(), 100);
+        assert_eq!(dedup.seen.len(), 100);
+    }
+
+    #[test]
+    fn http() {
+        let request = |id: &str| {
+            http::Request::builder()
+                .header("Twitch-Eventsub-Message-Id", id)
+                .header(
+                    "Twitch-Eventsub-Message-Timestamp",
+                    "2023-03-01T00:00:00.123Z",
+                )
+                .body(Vec::<u8>::new())
+                .unwrap()
+        };
+        let mut dedup = MessageDeduplicator::new();
+        assert!(dedup.check_http(&request("a")).unwrap());
+        assert!(!dedup.check_http(&request("a")).unwrap());
+        assert!(dedup.check_http(&request("b")).unwrap());
+        assert!(dedup
+            .check_http(&http::Request::builder().body(Vec::<u8>::new()).unwrap())
+            .is_err());
+    }
+}
@@ -89,6 +89,7 @@ macro_rules! make_event_type {
     ) => {
         #[doc = $enum_docs]
         #[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+        #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
         #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
         #[non_exhaustive]
         pub enum $enum_name {
@@ -723,3 +724,90 @@ impl Event {
         }
     }
 }
+
+macro_rules! export_schemas {
+    ($map:ident; $( $(#[$meta:meta])* $module:ident::$event:ident);* $(;)?) => {
+        $(
+            $(#[$meta])*
+            $map.insert(
+                (
+                    <$module::$event as EventSubscription>::EVENT_TYPE.to_str(),
+                    <$module::$event as EventSubscription>::VERSION,
+                ),
+                schemars::schema_for!(<$module::$event as EventSubscription>::Payload),
+            );
+        )*
+    };
+}
+
+/// The [JSON schemas](schemars::schema::RootSchema) of the payloads of all events, keyed by subscription type and version
+///
+/// The schema of a condition is `schemars::schema_for!(channel::ChannelBanV1)`.
+///
+/// # Examples
+///
+/// ```rust
+/// let schemas = twitch_api::eventsub::export_schemas();
+/// let follow = &schemas[&("channel.follow", "2")];
+/// assert_eq!(
+///     follow
+///         .schema
+///         .metadata
+///         .as_ref()
+///         .and_then(|m| m.title.as_deref()),
+///     Some("ChannelFollowV2Payload")
+/// );
+/// ```
+#[cfg(feature = "schemars")]
+pub fn export_schemas(
+) -> std::collections::BTreeMap<(&'static str, &'static str), schemars::schema::RootSchema> {
+    let mut schemas = std::collections::BTreeMap::new();
+    fill_events!(export_schemas(schemas;));
+    schemas
+}
+
+#[cfg(all(test, feature = "schemars"))]
+mod schema_tests {
+    use super::*;
+
+    fn properties(schema: &schemars::schema::RootSchema) -> Vec<&str> {
+        schema
+            .schema
+            .object
+            .as_ref()
+            .expect("payloads are objects")
+            .properties
+            .keys()
+            .map(|k| k.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn export_schemas() {
+        let schemas = super::export_schemas();
+        assert!(schemas.contains_key(&("channel.follow", "1")));
+        assert!(schemas.contains_key(&("channel.follow", "2")));
+
+        let ban = properties(&schemas[&("channel.ban", "1")]);
+        for name in ["user_id", "banned_at", "ends_at", "is_permanent"] {
+            assert!(ban.contains(&name), "{name} missing in {ban:?}");
+        }
+
+        let notification = &schemas[&("channel.chat.notification", "1")];
+        let notification = serde_json::to_value(notification).unwrap();
+        let notification = notification.to_string();
+        for name in ["chatter_is_anonymous", "notice_type", "sub_gift", "badges"] {
+            assert!(notification.contains(name), "{name} missing");
+        }
+    }
+
+    #[test]
+    fn condition_schema() {
+        let condition = schemars::schema_for!(channel::ChannelBanV1);
+        assert_eq!(properties(&condition), ["broadcaster_user_id"]);
+        let transport = schemars::schema_for!(Transport);
+        assert!(serde_json::to_string(&transport)
+            .unwrap()
+            .contains("secret"));
+    }
+}
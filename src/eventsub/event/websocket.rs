@@ -50,7 +50,7 @@ pub struct WelcomePayload<'a> {
 pub struct SessionData<'a> {
     /// An ID that uniquely identifies this WebSocket connection. Use this ID to set the session_id field in all subscription requests.
     #[serde(borrow = "'a")]
-    pub id: Cow<'a, str>,
+    pub id: Cow<'a, crate::eventsub::EventSubSessionIdRef>,
     /// The connection’s status, which is set to connected.
     #[serde(borrow = "'a")]
     pub status: Cow<'a, str>,
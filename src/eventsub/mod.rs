@@ -218,15 +218,50 @@ use crate::parse_json;
 
 pub mod channel;
 pub mod conduit;
+#[cfg(feature = "time")]
+mod dedup;
 pub mod event;
 pub mod stream;
 pub mod user;
 
+#[cfg(feature = "schemars")]
+#[cfg_attr(nightly, doc(cfg(feature = "schemars")))]
+pub use event::export_schemas;
 #[doc(inline)]
 pub use event::{Event, EventType};
 
 pub use event::websocket::*;
 
+#[cfg(feature = "time")]
+#[cfg_attr(nightly, doc(cfg(feature = "time")))]
+pub use dedup::MessageDeduplicator;
+
+#[doc(no_inline)]
+pub use crate::extra::{
+    ConduitId, ConduitIdRef, ConduitShardId, ConduitShardIdRef, EventSubSessionId,
+    EventSubSessionIdRef, WebhookSecret,
+};
+
+/// A payload of an event in a broadcaster's channel
+///
+/// Used to write code that works with any event that names a broadcaster, e.g. to reply in their chat.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api::{eventsub::BroadcasterInfo, types};
+///
+/// fn channel(event: &impl BroadcasterInfo) -> &types::UserNameRef {
+///     event.broadcaster_login()
+/// }
+/// ```
+pub trait BroadcasterInfo {
+    /// ID of the broadcaster
+    fn broadcaster_id(&self) -> &types::UserIdRef;
+    /// Login of the broadcaster
+    fn broadcaster_login(&self) -> &types::UserNameRef;
+}
+
 /// An EventSub subscription.
 pub trait EventSubscription: DeserializeOwned + serde::Serialize + PartialEq + Clone {
     /// Payload for given subscription
@@ -251,6 +286,7 @@ pub trait EventSubscription: DeserializeOwned + serde::Serialize + PartialEq + C
 
 /// Verification Request
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct VerificationRequest {
@@ -566,7 +602,7 @@ pub struct EventSubscriptionInformation<E: EventSubscription> {
     /// ID of the subscription.
     pub id: types::EventSubId,
     /// Status of EventSub subscription
-    pub status: Status,
+    pub status: SubscriptionStatus,
     /// How much the subscription counts against your limit.
     pub cost: usize,
     /// Subscription-specific parameters.
@@ -583,7 +619,8 @@ pub struct EventSubscriptionInformation<E: EventSubscription> {
     pub version: String,
 }
 
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 /// Webhook transport
@@ -595,19 +632,12 @@ pub struct WebhookTransport {
     /// # Notes
     ///
     /// Secret must be between 10 and 100 characters
-    pub secret: String,
-}
-
-impl std::fmt::Debug for WebhookTransport {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("WebhookTransport")
-            .field("callback", &self.callback)
-            .field("secret", &"[redacted]")
-            .finish()
-    }
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub secret: WebhookSecret,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 /// Websocket transport
@@ -615,10 +645,12 @@ pub struct WebsocketTransport {
     /// An ID that identifies the WebSocket to send notifications to.
     ///
     /// When you connect to EventSub using WebSockets, the server returns the ID in the Welcome message.
-    pub session_id: String,
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub session_id: EventSubSessionId,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 /// Conduit transport
@@ -626,11 +658,13 @@ pub struct ConduitTransport {
     /// An ID that identifies the conduit to send notifications to.
     ///
     /// When you create a conduit, the server returns the conduit ID.
-    pub conduit_id: String,
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub conduit_id: ConduitId,
 }
 
 /// Transport setting for event notification
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "method", rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum Transport {
@@ -644,24 +678,27 @@ pub enum Transport {
 
 impl Transport {
     /// Convenience method for making a webhook transport
-    pub fn webhook(callback: impl std::string::ToString, secret: String) -> Transport {
+    pub fn webhook(
+        callback: impl std::string::ToString,
+        secret: impl Into<WebhookSecret>,
+    ) -> Transport {
         Transport::Webhook(WebhookTransport {
             callback: callback.to_string(),
-            secret,
+            secret: secret.into(),
         })
     }
 
     /// Convenience method for making a websocket transport
-    pub fn websocket(session_id: impl std::string::ToString) -> Transport {
+    pub fn websocket(session_id: impl Into<EventSubSessionId>) -> Transport {
         Transport::Websocket(WebsocketTransport {
-            session_id: session_id.to_string(),
+            session_id: session_id.into(),
         })
     }
 
     /// Convenience method for making a conduit transport
-    pub fn conduit(conduit_id: impl std::string::ToString) -> Transport {
+    pub fn conduit(conduit_id: impl Into<ConduitId>) -> Transport {
         Transport::Conduit(ConduitTransport {
-            conduit_id: conduit_id.to_string(),
+            conduit_id: conduit_id.into(),
         })
     }
 
@@ -739,27 +776,32 @@ impl Transport {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 /// Websocket transport
 pub struct WebsocketTransportResponse {
     /// An ID that identifies the WebSocket that notifications are sent to.
-    pub session_id: String,
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub session_id: EventSubSessionId,
     /// The UTC date and time that the WebSocket connection was established.
     ///
     /// # Notes
     ///
     /// Only returned on helix response
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub connected_at: Option<types::Timestamp>,
     /// The UTC date and time that the WebSocket connection was lost.
     ///
     /// # Notes
     ///
     /// Only returned on helix response
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub disconnected_at: Option<types::Timestamp>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 /// Webhook transport
@@ -769,18 +811,21 @@ pub struct WebhookTransportResponse {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 /// Conduit transport
 pub struct ConduitTransportResponse {
     /// The conduit ID
-    pub conduit_id: String,
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub conduit_id: ConduitId,
 }
 
 /// Transport response on event notification
 ///
 /// Does not include secret.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "method", rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum TransportResponse {
@@ -870,6 +915,7 @@ impl TransportResponse {
 ///
 /// Currently, only webhooks are supported
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(rename_all = "lowercase")]
 pub enum TransportMethod {
@@ -879,10 +925,14 @@ pub enum TransportMethod {
     Websocket,
 }
 
-/// Subscription request status
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// Status of an EventSub subscription
+///
+/// Serializes as the string Twitch uses, e.g `"enabled"`. Statuses not known to this crate are kept in [`Other`](SubscriptionStatus::Other).
+/// Apart from [`Enabled`](SubscriptionStatus::Enabled) and [`WebhookCallbackVerificationPending`](SubscriptionStatus::WebhookCallbackVerificationPending),
+/// every known status is the reason Twitch stopped sending notifications, see [`is_failed`](SubscriptionStatus::is_failed).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[non_exhaustive]
-pub enum Status {
+pub enum SubscriptionStatus {
     /// Twitch has verified your callback and is able to send you notifications.
     #[serde(rename = "enabled")]
     Enabled,
@@ -934,6 +984,81 @@ pub enum Status {
     /// The client failed to reconnect to the Twitch WebSocket server within the required time after a Reconnect Message.
     #[serde(rename = "websocket_failed_to_reconnect")]
     WebsocketFailedToReconnect,
+    /// A status not known to this crate
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Renamed to [`SubscriptionStatus`]
+#[deprecated(note = "renamed to `SubscriptionStatus`")]
+pub type Status = SubscriptionStatus;
+
+impl SubscriptionStatus {
+    /// Statuses of subscriptions that Twitch stopped sending notifications for, see [`is_failed`](SubscriptionStatus::is_failed)
+    pub const FAILED: &'static [SubscriptionStatus] = &[
+        SubscriptionStatus::WebhookCallbackVerificationFailed,
+        SubscriptionStatus::NotificationFailuresExceeded,
+        SubscriptionStatus::AuthorizationRevoked,
+        SubscriptionStatus::ModeratorRemoved,
+        SubscriptionStatus::UserRemoved,
+        SubscriptionStatus::VersionRemoved,
+        SubscriptionStatus::BetaMaintenance,
+        SubscriptionStatus::WebsocketDisconnected,
+        SubscriptionStatus::WebsocketFailedPingPong,
+        SubscriptionStatus::WebsocketReceivedInboundTraffic,
+        SubscriptionStatus::WebsocketConnectionUnused,
+        SubscriptionStatus::WebsocketInternalError,
+        SubscriptionStatus::WebsocketNetworkTimeout,
+        SubscriptionStatus::WebsocketNetworkError,
+        SubscriptionStatus::WebsocketFailedToReconnect,
+    ];
+
+    /// The string Twitch uses for this status
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Enabled => "enabled",
+            Self::WebhookCallbackVerificationPending => "webhook_callback_verification_pending",
+            Self::WebhookCallbackVerificationFailed => "webhook_callback_verification_failed",
+            Self::NotificationFailuresExceeded => "notification_failures_exceeded",
+            Self::AuthorizationRevoked => "authorization_revoked",
+            Self::ModeratorRemoved => "moderator_removed",
+            Self::UserRemoved => "user_removed",
+            Self::VersionRemoved => "version_removed",
+            Self::BetaMaintenance => "beta_maintenance",
+            Self::WebsocketDisconnected => "websocket_disconnected",
+            Self::WebsocketFailedPingPong => "websocket_failed_ping_pong",
+            Self::WebsocketReceivedInboundTraffic => "websocket_received_inbound_traffic",
+            Self::WebsocketConnectionUnused => "websocket_connection_unused",
+            Self::WebsocketInternalError => "websocket_internal_error",
+            Self::WebsocketNetworkTimeout => "websocket_network_timeout",
+            Self::WebsocketNetworkError => "websocket_network_error",
+            Self::WebsocketFailedToReconnect => "websocket_failed_to_reconnect",
+            Self::Other(status) => status,
+        }
+    }
+
+    /// Twitch sends notifications for the subscription
+    pub fn is_enabled(&self) -> bool { matches!(self, Self::Enabled) }
+
+    /// Twitch stopped sending notifications for the subscription, it can be deleted
+    ///
+    /// [`Other`](SubscriptionStatus::Other) statuses are not failed, as it's not known what they mean.
+    pub fn is_failed(&self) -> bool { Self::FAILED.contains(self) }
+}
+
+impl std::fmt::Display for SubscriptionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for SubscriptionStatus {
+    fn schema_name() -> String { "SubscriptionStatus".to_owned() }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <String as schemars::JsonSchema>::json_schema(gen)
+    }
 }
 
 /// General information about an EventSub subscription.
@@ -942,6 +1067,7 @@ pub enum Status {
 ///
 /// See also [`EventSubscriptionInformation`]
 #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[cfg(feature = "eventsub")]
 #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
@@ -952,11 +1078,13 @@ pub struct EventSubSubscription {
     // FIXME: Should be [eventsub::Condition]
     pub condition: serde_json::Value,
     /// RFC3339 timestamp indicating when the subscription was created.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub created_at: types::Timestamp,
     /// ID of the subscription.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub id: types::EventSubId,
     /// Status of the subscription.
-    pub status: Status,
+    pub status: SubscriptionStatus,
     /// Notification delivery specific information. Includes the transport method and callback URL.
     pub transport: TransportResponse,
     /// The category of the subscription.
@@ -968,12 +1096,14 @@ pub struct EventSubSubscription {
 
 /// General information about a [Conduit](https://dev.twitch.tv/docs/eventsub/handling-conduit-events/)
 #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[cfg(feature = "eventsub")]
 #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
 pub struct Conduit {
     /// Conduit ID
-    pub id: String,
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub id: ConduitId,
     /// Number of shards associated with this conduit
     pub shard_count: usize,
 }
@@ -982,12 +1112,14 @@ pub struct Conduit {
 ///
 /// A shard is a Webhook or WebSocket connection, while a conduit is a collection of shards. The conduit transport type is for backend server applications and requires app access tokens.
 #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[cfg(feature = "eventsub")]
 #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
 pub struct Shard {
     /// Shard ID.
-    pub id: String,
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub id: ConduitShardId,
 
     /// The transport details that you want Twitch to use when sending you notifications.
     pub transport: Transport,
@@ -995,9 +1127,9 @@ pub struct Shard {
 
 impl Shard {
     /// Create a shard with a transport set
-    pub fn new(id: impl std::string::ToString, transport: Transport) -> Self {
+    pub fn new(id: impl Into<ConduitShardId>, transport: Transport) -> Self {
         Self {
-            id: id.to_string(),
+            id: id.into(),
 
             transport,
         }
@@ -1006,6 +1138,7 @@ impl Shard {
 
 /// The shard status.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum ShardStatus {
@@ -1045,10 +1178,12 @@ pub enum ShardStatus {
 
 /// A structured error that occurred with a shard
 #[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct ShardError {
     /// Shard ID.
-    pub id: String,
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub id: ConduitShardId,
 
     /// The error that occurred while updating the shard.
     pub message: String,
@@ -1059,10 +1194,12 @@ pub struct ShardError {
 
 /// A shard when described by Twitch
 #[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct ShardResponse {
     /// Shard ID.
-    pub id: String,
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub id: ConduitShardId,
 
     /// The shard status. The subscriber receives events only for enabled shards.
     pub status: ShardStatus,
@@ -1320,3 +1457,81 @@ mod test {
         assert!(crate::eventsub::Event::verify_payload(&request, secret));
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_transport_fixtures() {
+    let transports: Vec<Transport> =
+        crate::tests::roundtrip_fixture(include_str!("fixtures/transports.json"), &[]);
+    assert!(transports[0].is_webhook());
+    assert!(transports[1].is_websocket());
+    assert!(transports[2].is_conduit());
+
+    // notifications don't include `connected_at` and `disconnected_at`
+    let transports: Vec<TransportResponse> = crate::tests::roundtrip_fixture(
+        include_str!("fixtures/transport_responses.json"),
+        &["/*/connected_at", "/*/disconnected_at"],
+    );
+    assert_eq!(transports.len(), 5);
+}
+
+#[cfg(test)]
+#[test]
+fn test_subscription_status() {
+    let mut known = vec![
+        SubscriptionStatus::Enabled,
+        SubscriptionStatus::WebhookCallbackVerificationPending,
+    ];
+    known.extend_from_slice(SubscriptionStatus::FAILED);
+    assert_eq!(known.len(), 17);
+    for status in known {
+        let json = serde_json::to_string(&status).unwrap();
+        assert_eq!(json, format!("\"{}\"", status.as_str()));
+        assert_eq!(
+            serde_json::from_str::<SubscriptionStatus>(&json).unwrap(),
+            status
+        );
+        assert!(!matches!(status, SubscriptionStatus::Other(_)));
+    }
+
+    let other: SubscriptionStatus = serde_json::from_str(r#""some_new_status""#).unwrap();
+    assert_eq!(
+        other,
+        SubscriptionStatus::Other("some_new_status".to_owned())
+    );
+    assert_eq!(
+        serde_json::to_string(&other).unwrap(),
+        r#""some_new_status""#
+    );
+    assert!(!other.is_failed());
+    assert!(!other.is_enabled());
+    assert!(SubscriptionStatus::UserRemoved.is_failed());
+    assert!(!SubscriptionStatus::WebhookCallbackVerificationPending.is_failed());
+}
+
+/// Checks that the common payloads implement [`BroadcasterInfo`] and work with [`HelixClient::get_channel_for_event`](crate::helix::HelixClient::get_channel_for_event)
+#[cfg(test)]
+fn _broadcaster_info_payloads() {
+    fn info<P: BroadcasterInfo>() {}
+
+    info::<channel::ChannelFollowV2Payload>();
+    info::<channel::ChannelSubscribeV1Payload>();
+    info::<channel::ChannelCheerV1Payload>();
+    info::<channel::ChannelRaidV1Payload>();
+    info::<channel::ChannelChatMessageV1Payload>();
+    info::<channel::ChannelBanV1Payload>();
+    info::<stream::StreamOnlineV1Payload>();
+
+    #[cfg(all(feature = "helix", feature = "client"))]
+    async fn _helper<'c, C: crate::HttpClient + Sync>(
+        client: &'c crate::HelixClient<'c, C>,
+        follow: &channel::ChannelFollowV2Payload,
+        raid: &channel::ChannelRaidV1Payload,
+        message: &channel::ChannelChatMessageV1Payload,
+        token: &twitch_oauth2::UserToken,
+    ) {
+        let _ = client.get_channel_for_event(follow, token).await;
+        let _ = client.get_channel_for_event(raid, token).await;
+        let _ = client.get_channel_for_event(message, token).await;
+    }
+}
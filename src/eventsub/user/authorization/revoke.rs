@@ -3,6 +3,7 @@
 use super::*;
 /// [`user.authorization.revoke`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#userauthorizationrevoke): a user has revoked authorization for your client id.  Use this webhook to meet government requirements for handling user data, such as GDPR, LGPD, or CCPA.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
@@ -28,16 +29,20 @@ impl EventSubscription for UserAuthorizationRevokeV1 {
 
 /// [`user.authorization.revoke`](UserAuthorizationRevokeV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct UserAuthorizationRevokeV1Payload {
     /// The client_id of the application with revoked user access.
     pub client_id: String,
     /// The user id for the user who has revoked authorization for your client id.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub user_id: types::UserId,
     /// The user login for the user who has revoked authorization for your client id. This is null if the user no longer exists.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub user_login: Option<types::UserName>,
     /// The user name for the user who has revoked authorization for your client id. This is null if the user no longer exists.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub user_name: Option<types::DisplayName>,
 }
 
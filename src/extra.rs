@@ -1,5 +1,9 @@
+#[cfg(feature = "helix")]
+use crate::types;
+
 #[cfg(any(feature = "eventsub", feature = "helix"))]
 #[derive(Clone, Debug, PartialEq, Eq, serde_derive::Serialize, serde_derive::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 /// Represents a donation "amount"
@@ -18,6 +22,7 @@ pub struct DonationAmount {
 #[cfg(any(feature = "eventsub", feature = "helix"))]
 /// Valid colors for announcements
 #[derive(PartialEq, Eq, serde_derive::Deserialize, serde_derive::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum AnnouncementColor {
@@ -64,3 +69,273 @@ impl std::convert::TryFrom<&str> for AnnouncementColor {
         })
     }
 }
+
+/// An ID that identifies an EventSub WebSocket session, returned in the [welcome message](crate::eventsub::WelcomePayload)
+#[cfg(feature = "eventsub")]
+#[aliri_braid::braid(serde)]
+pub struct EventSubSessionId;
+
+/// An ID that identifies a [conduit](crate::eventsub::Conduit)
+#[cfg(feature = "eventsub")]
+#[aliri_braid::braid(serde)]
+pub struct ConduitId;
+
+/// An ID that identifies a [shard](crate::eventsub::Shard) of a conduit
+///
+/// Shard ids are numbers starting at `0` for the first shard of a conduit. A [`ConduitId`] is not a shard id.
+///
+/// ```compile_fail
+/// use twitch_api::extra::{ConduitId, ConduitShardId};
+///
+/// let conduit_id = ConduitId::from("bfcfc993-26b1-b876-44d9-afe75a379dac");
+/// let shard_id: ConduitShardId = conduit_id;
+/// ```
+#[cfg(feature = "eventsub")]
+#[aliri_braid::braid(serde)]
+pub struct ConduitShardId;
+
+/// The secret of a webhook transport, used to [verify](crate::eventsub::Event::verify_payload) notifications
+///
+/// The secret must be between 10 and 100 characters. [`Debug`] and [`Display`](std::fmt::Display) don't show the secret,
+/// use [`secret`](WebhookSecret::secret) to get it.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api::extra::WebhookSecret;
+///
+/// let secret = WebhookSecret::from("secretabcd");
+/// assert_eq!(secret.to_string(), "[redacted]");
+/// assert_eq!(secret.secret(), "secretabcd");
+/// ```
+#[cfg(feature = "eventsub")]
+#[derive(Clone, PartialEq, Eq, Hash, serde_derive::Serialize, serde_derive::Deserialize)]
+#[serde(transparent)]
+pub struct WebhookSecret(String);
+
+#[cfg(feature = "eventsub")]
+impl WebhookSecret {
+    /// Create a secret
+    pub fn new(secret: impl Into<String>) -> Self { Self(secret.into()) }
+
+    /// The secret
+    pub fn secret(&self) -> &str { &self.0 }
+
+    /// The secret as bytes, for [`Event::verify_payload`](crate::eventsub::Event::verify_payload)
+    pub fn as_bytes(&self) -> &[u8] { self.0.as_bytes() }
+
+    /// Take the secret
+    pub fn into_secret(self) -> String { self.0 }
+}
+
+#[cfg(feature = "eventsub")]
+impl std::fmt::Debug for WebhookSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("WebhookSecret").field(&"[redacted]").finish()
+    }
+}
+
+#[cfg(feature = "eventsub")]
+impl std::fmt::Display for WebhookSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.write_str("[redacted]") }
+}
+
+#[cfg(feature = "eventsub")]
+impl std::str::FromStr for WebhookSecret {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> { Ok(Self::new(s)) }
+}
+
+#[cfg(feature = "eventsub")]
+impl From<String> for WebhookSecret {
+    fn from(secret: String) -> Self { Self(secret) }
+}
+
+#[cfg(feature = "eventsub")]
+impl From<&str> for WebhookSecret {
+    fn from(secret: &str) -> Self { Self::new(secret) }
+}
+
+#[cfg(all(test, feature = "eventsub"))]
+#[test]
+fn test_webhook_secret_redacted() {
+    let secret = WebhookSecret::from("secretabcd");
+    assert_eq!(format!("{secret:?}"), r#"WebhookSecret("[redacted]")"#);
+    assert_eq!(
+        format!("{secret:#?}"),
+        "WebhookSecret(\n    \"[redacted]\",\n)"
+    );
+    assert_eq!(secret.to_string(), "[redacted]");
+    assert_eq!(secret.secret(), "secretabcd");
+    assert_eq!(serde_json::to_string(&secret).unwrap(), r#""secretabcd""#);
+    assert_eq!(
+        serde_json::from_str::<WebhookSecret>(r#""secretabcd""#).unwrap(),
+        secret
+    );
+    assert_eq!("secretabcd".parse::<WebhookSecret>(), Ok(secret));
+}
+
+#[cfg(all(test, feature = "eventsub"))]
+#[test]
+fn test_eventsub_ids_roundtrip() {
+    fn roundtrip<T>(id: &str)
+    where
+        T: serde::Serialize
+            + serde::de::DeserializeOwned
+            + std::str::FromStr
+            + std::fmt::Display
+            + PartialEq
+            + std::fmt::Debug,
+        <T as std::str::FromStr>::Err: std::fmt::Debug, {
+        let json = serde_json::to_string(id).unwrap();
+        let parsed: T = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.to_string(), id);
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+        assert_eq!(id.parse::<T>().unwrap(), parsed);
+    }
+
+    roundtrip::<EventSubSessionId>("AgoQMpdhHZ-dSoyv7NLALgOGHhIGY2VsbC1j");
+    roundtrip::<ConduitId>("bfcfc993-26b1-b876-44d9-afe75a379dac");
+    roundtrip::<ConduitShardId>("4");
+}
+
+/// Maximum length of a Twitch login
+#[cfg(feature = "helix")]
+pub const MAX_LOGIN_LENGTH: usize = 25;
+
+/// Minimum length of a Twitch login
+#[cfg(feature = "helix")]
+pub const MIN_LOGIN_LENGTH: usize = 4;
+
+/// Normalize user input into a login
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api::{extra::UserNameExt, types};
+///
+/// assert_eq!(
+///     types::UserName::normalize(" @TwitchDev ").unwrap(),
+///     types::UserName::from("twitchdev")
+/// );
+/// assert!(types::UserName::normalize("not a login").is_err());
+/// ```
+#[cfg(feature = "helix")]
+pub trait UserNameExt: Sized {
+    /// Strip a leading `@` and surrounding whitespace from `input` and lowercase it.
+    ///
+    /// Fails if the result is not a valid login, logins are [4](MIN_LOGIN_LENGTH) to [25](MAX_LOGIN_LENGTH) characters of `a-z`, `0-9` and `_`.
+    fn normalize(input: &str) -> Result<Self, InvalidLoginError>;
+}
+
+#[cfg(feature = "helix")]
+impl UserNameExt for types::UserName {
+    fn normalize(input: &str) -> Result<Self, InvalidLoginError> {
+        let input = input.trim();
+        let login = input.strip_prefix('@').unwrap_or(input).to_lowercase();
+        if let Some(character) = login
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || *c == '_'))
+        {
+            return Err(InvalidLoginError::Character { character });
+        }
+        if !(MIN_LOGIN_LENGTH..=MAX_LOGIN_LENGTH).contains(&login.len()) {
+            return Err(InvalidLoginError::Length {
+                length: login.len(),
+            });
+        }
+        Ok(login.into())
+    }
+}
+
+/// Error returned by [`UserNameExt::normalize`]
+#[cfg(feature = "helix")]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum InvalidLoginError {
+    /// The login is too short or too long
+    #[error("login must be between four and twenty-five characters, got {length}")]
+    Length {
+        /// Length of the login
+        length: usize,
+    },
+    /// The login contains a character that is not allowed
+    #[error("login can not contain {character:?}")]
+    Character {
+        /// The first character that is not allowed
+        character: char,
+    },
+}
+
+/// Check if a login and a display name refer to the same name, ignoring case
+///
+/// Display names can also be localized, in which case they never match the login.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api::{extra::eq_ignore_case, types};
+///
+/// assert!(eq_ignore_case(
+///     types::UserNameRef::from_str("twitchdev"),
+///     types::DisplayNameRef::from_str("TwitchDev")
+/// ));
+/// ```
+#[cfg(feature = "helix")]
+pub fn eq_ignore_case(login: &types::UserNameRef, display_name: &types::DisplayNameRef) -> bool {
+    login.as_str().to_lowercase() == display_name.as_str().to_lowercase()
+}
+
+#[cfg(all(test, feature = "helix"))]
+#[test]
+fn test_normalize_login() {
+    for (input, login) in [
+        ("twitchdev", "twitchdev"),
+        ("TwitchDev", "twitchdev"),
+        ("@TwitchDev", "twitchdev"),
+        ("  @twitch_dev \n", "twitch_dev"),
+        ("abcd", "abcd"),
+        ("a234567890123456789012345", "a234567890123456789012345"),
+    ] {
+        assert_eq!(
+            types::UserName::normalize(input),
+            Ok(types::UserName::from(login)),
+            "{input:?}"
+        );
+    }
+
+    for (input, error) in [
+        ("", InvalidLoginError::Length { length: 0 }),
+        ("@", InvalidLoginError::Length { length: 0 }),
+        ("abc", InvalidLoginError::Length { length: 3 }),
+        (
+            "a2345678901234567890123456",
+            InvalidLoginError::Length { length: 26 },
+        ),
+        (
+            "@@twitchdev",
+            InvalidLoginError::Character { character: '@' },
+        ),
+        (
+            "twitch dev",
+            InvalidLoginError::Character { character: ' ' },
+        ),
+        (
+            "twitch-dev",
+            InvalidLoginError::Character { character: '-' },
+        ),
+        ("twitchdév", InvalidLoginError::Character { character: 'é' }),
+    ] {
+        assert_eq!(types::UserName::normalize(input), Err(error), "{input:?}");
+    }
+
+    assert!(eq_ignore_case(
+        types::UserNameRef::from_str("twitchdev"),
+        types::DisplayNameRef::from_str("TwitchDev")
+    ));
+    assert!(!eq_ignore_case(
+        types::UserNameRef::from_str("twitchdev"),
+        types::DisplayNameRef::from_str("TwitchDevs")
+    ));
+}
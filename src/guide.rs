@@ -0,0 +1,6 @@
+//! Long-form guides that combine several parts of the crate
+//!
+//! The snippets in the guides are compiled as doc tests, so they stay up to date with the crate.
+
+#[doc = include_str!("../docs/guide/chat_bot.md")]
+pub mod chat_bot {}
@@ -3,12 +3,19 @@ use crate::client::ResponseExt;
 
 use super::*;
 
+mod bound;
 pub(crate) mod client_ext;
+mod coalesce;
 #[cfg(feature = "unsupported")]
 mod custom;
 
 #[doc(inline)]
-pub use client_ext::ClientExtError;
+pub use bound::BoundHelixClient;
+#[doc(inline)]
+pub use client_ext::{
+    ClientExtError, FollowersFromLoginError, MergedScheduleError, NotFoundError, TokenHealth,
+    UserFromLoginError,
+};
 
 #[cfg(feature = "client")]
 impl<C: crate::HttpClient + crate::client::ClientDefault<'static>> Default
@@ -71,9 +78,17 @@ impl<C: crate::HttpClient + crate::client::ClientDefault<'static>> Default
 #[cfg(feature = "helix")] // this is needed due to a bug?
 pub struct HelixClient<'a, C: 'a> {
     pub(crate) client: C,
+    pub(crate) max_response_size: usize,
+    pub(crate) timeout: Option<std::time::Duration>,
+    pub(crate) retries: u32,
+    pub(crate) validation: bool,
+    pub(crate) coalescing: Option<std::sync::Arc<coalesce::InFlight>>,
     pub(crate) _pd: std::marker::PhantomData<&'a ()>, // TODO: Implement rate limiter...
 }
 
+/// Default maximum size of a response body, see [`HelixClient::with_max_response_size`]
+pub const DEFAULT_MAX_RESPONSE_SIZE: usize = 10 * 1024 * 1024;
+
 #[cfg(feature = "helix")]
 impl<'a, C> Clone for HelixClient<'a, C>
 where C: crate::HttpClient + Clone + 'a
@@ -81,20 +96,320 @@ where C: crate::HttpClient + Clone + 'a
     fn clone(&self) -> Self {
         Self {
             client: self.client.clone(),
+            max_response_size: self.max_response_size,
+            timeout: self.timeout,
+            retries: self.retries,
+            validation: self.validation,
+            coalescing: self.coalescing.clone(),
             _pd: self._pd,
         }
     }
 }
 
+#[cfg(feature = "helix")]
+impl<'a, C: crate::HttpClient + 'a> From<C> for HelixClient<'a, C> {
+    /// Create a new client with an existing client, see [`HelixClient::with_client`]
+    fn from(client: C) -> Self { HelixClient::with_client(client) }
+}
+
 impl<'a, C: crate::HttpClient + 'a> HelixClient<'a, C> {
     /// Create a new client with an existing client
+    ///
+    /// The client is used as is, so settings like proxies, TLS and connection pools are kept.
+    /// Clones of a [`reqwest::Client`](https://docs.rs/reqwest/latest/reqwest/struct.Client.html) share the same connection pool,
+    /// so the same client can be used for this [`HelixClient`] and other requests.
+    /// To share any other client, wrap it in an [`Arc`](std::sync::Arc).
+    ///
+    /// For hyper, wrap a client with your own connector in a [`TowerService`](crate::client::TowerService).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+    /// use twitch_api::helix::HelixClient;
+    ///
+    /// // a client configured with your own settings
+    /// let http_client = reqwest::Client::default();
+    /// let client: HelixClient<reqwest::Client> = HelixClient::with_client(http_client.clone());
+    /// // or
+    /// let client: HelixClient<reqwest::Client> = http_client.into();
+    /// ```
     pub fn with_client(client: C) -> HelixClient<'a, C> {
         HelixClient {
             client,
+            max_response_size: DEFAULT_MAX_RESPONSE_SIZE,
+            timeout: None,
+            retries: 0,
+            validation: false,
+            coalescing: None,
             _pd: std::marker::PhantomData,
         }
     }
 
+    /// Set the maximum size of a response body, in bytes. Defaults to [10 MiB](DEFAULT_MAX_RESPONSE_SIZE).
+    ///
+    /// Responses with a larger body, or a larger `Content-Length`, fail with [`ClientRequestError::ResponseTooLarge`] before they are parsed.
+    ///
+    /// # Notes
+    ///
+    /// The limit is passed to the [`HttpClient`](crate::HttpClient) as a [`MaxResponseSize`](crate::client::MaxResponseSize).
+    /// The tower and reqwest clients stop reading a body once it is too large, other clients return the full body, which is checked after it has been received.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+    /// use twitch_api::helix::HelixClient;
+    ///
+    /// let client: HelixClient<reqwest::Client> =
+    ///     HelixClient::new().with_max_response_size(1024 * 1024);
+    /// ```
+    pub fn with_max_response_size(mut self, limit: usize) -> Self {
+        self.max_response_size = limit;
+        self
+    }
+
+    /// The maximum size of a response body, see [`with_max_response_size`](HelixClient::with_max_response_size)
+    pub fn max_response_size(&self) -> usize { self.max_response_size }
+
+    /// Set the timeout of requests. Defaults to no timeout.
+    ///
+    /// Endpoints known to be slow, like [Get Chatters](crate::helix::chat::GetChattersRequest), use their [`TIMEOUT_HINT`](Request::TIMEOUT_HINT) instead.
+    /// Some helpers, like [`get_chatters_with_timeout`](HelixClient::get_chatters_with_timeout), also take a timeout that is used over both.
+    ///
+    /// The timeout is passed to the [`HttpClient`](crate::HttpClient) as a [`RequestTimeout`](crate::client::RequestTimeout), not every client applies it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+    /// use twitch_api::helix::HelixClient;
+    ///
+    /// let client: HelixClient<reqwest::Client> =
+    ///     HelixClient::new().with_timeout(std::time::Duration::from_secs(5));
+    /// ```
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The timeout of requests, see [`with_timeout`](HelixClient::with_timeout)
+    pub fn timeout(&self) -> Option<std::time::Duration> { self.timeout }
+
+    /// Set how many times a request on an [`IdempotentPost`] endpoint is sent again after a transient failure. Defaults to no retries.
+    ///
+    /// Transient failures are errors of the [`HttpClient`](crate::HttpClient) and `500`, `502`, `503` and `504` responses.
+    /// Requests are sent again right away, as the client doesn't depend on a runtime to wait with.
+    /// Only [`req_post_idempotent`](HelixClient::req_post_idempotent), and the helpers using it, retry requests.
+    /// Other requests that change something are never sent twice, as the first attempt might have succeeded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+    /// use twitch_api::helix::HelixClient;
+    ///
+    /// let client: HelixClient<reqwest::Client> = HelixClient::new().with_retries(2);
+    /// ```
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// How many times a request is retried, see [`with_retries`](HelixClient::with_retries)
+    pub fn retries(&self) -> u32 { self.retries }
+
+    /// Check requests before sending them. Defaults to off.
+    ///
+    /// Currently, this checks that the token is for the user a [`TokenBoundRequest`] must be made by,
+    /// failing with [`ClientRequestError::TokenUserMismatch`] instead of a `401` or `403` from Twitch.
+    /// Tokens without a user, like [app access tokens](twitch_oauth2::AppAccessToken), fail the check as well, as these requests need a user access token.
+    /// With the `tracing` feature, missing [optional scopes](crate::helix::Request::opt_scopes) that the request makes use of are logged as a warning,
+    /// see [`check_scopes`](crate::helix::request::check_scopes).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+    /// use twitch_api::helix::HelixClient;
+    ///
+    /// let client: HelixClient<reqwest::Client> = HelixClient::new().with_validation(true);
+    /// ```
+    pub fn with_validation(mut self, validation: bool) -> Self {
+        self.validation = validation;
+        self
+    }
+
+    /// Whether requests are checked before sending them, see [`with_validation`](HelixClient::with_validation)
+    pub fn validation(&self) -> bool { self.validation }
+
+    /// Share one response between identical [`GET`](RequestGet) requests in flight at the same time. Defaults to off.
+    ///
+    /// Requests are identical if they have the same uri, and their tokens have the same client id and user.
+    /// The first request is sent, the others wait for its response and parse it themselves.
+    /// If the first request fails before getting a response, the others are sent as usual.
+    /// Only requests with [`req_get`](HelixClient::req_get) and [`req_get_borrowed`](HelixClient::req_get_borrowed), and the helpers using them, are coalesced.
+    /// Requests that change something are always sent.
+    ///
+    /// Clones of this client share the requests in flight.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+    /// use twitch_api::helix::HelixClient;
+    ///
+    /// let client: HelixClient<reqwest::Client> = HelixClient::new().with_coalescing(true);
+    /// ```
+    pub fn with_coalescing(mut self, coalescing: bool) -> Self {
+        self.coalescing = coalescing.then(Default::default);
+        self
+    }
+
+    /// Whether identical requests share a response, see [`with_coalescing`](HelixClient::with_coalescing)
+    pub fn coalescing(&self) -> bool { self.coalescing.is_some() }
+
+    /// Check `request` with `token` if [validation](HelixClient::with_validation) is on
+    ///
+    /// Requests that need scopes are always rejected without a token, see [`NoAuth`](crate::client::NoAuth).
+    pub(crate) fn validate<R, T>(
+        &self,
+        request: &R,
+        token: &T,
+    ) -> Result<(), ClientRequestError<<C as crate::HttpClient>::Error>>
+    where
+        R: Request,
+        T: HelixAuth + ?Sized,
+    {
+        if token.auth_bearer().is_none() {
+            let required = request.scopes();
+            if !required.matches(&[]) {
+                return Err(ClientRequestError::NoToken {
+                    path: R::PATH,
+                    required,
+                });
+            }
+        }
+        if !self.validation {
+            return Ok(());
+        }
+        // Missing required scopes are left to Twitch, tokens that were never validated don't know their scopes
+        #[cfg(feature = "tracing")]
+        let _ = super::request::check_scopes(request, token.auth_scopes());
+        let Some(expected) = request
+            .as_token_bound()
+            .and_then(|request| request.token_bound_user_id())
+        else {
+            return Ok(());
+        };
+        match token.auth_user_id() {
+            Some(got) if got == expected => Ok(()),
+            got => Err(ClientRequestError::TokenUserMismatch {
+                expected: expected.to_owned(),
+                got: got.map(ToOwned::to_owned),
+            }),
+        }
+    }
+
+    /// Send a request, checking that the response is not larger than [`max_response_size`](HelixClient::max_response_size)
+    ///
+    /// The request times out after `timeout`, or the [timeout hint](Request::TIMEOUT_HINT) of the endpoint, or the [client timeout](HelixClient::with_timeout), in that order.
+    pub(crate) async fn send<R: Request>(
+        &self,
+        req: crate::client::Request,
+        timeout: Option<std::time::Duration>,
+    ) -> Result<crate::client::Response, ClientRequestError<<C as crate::HttpClient>::Error>> {
+        self.send_with_retries::<R>(req, timeout, 0)
+            .await
+            .map(|(response, _)| response)
+    }
+
+    /// Send a [`GET`](RequestGet) request like [`send`](HelixClient::send), sharing the response with identical requests if [coalescing](HelixClient::with_coalescing) is on
+    pub(crate) async fn send_get<R, T>(
+        &self,
+        req: crate::client::Request,
+        timeout: Option<std::time::Duration>,
+        token: &T,
+    ) -> Result<crate::client::Response, ClientRequestError<<C as crate::HttpClient>::Error>>
+    where
+        R: Request,
+        T: HelixAuth + ?Sized,
+    {
+        let Some(in_flight) = &self.coalescing else {
+            return self.send::<R>(req, timeout).await;
+        };
+        let key = coalesce::Key {
+            uri: req.uri().clone(),
+            client_id: token.auth_client_id().to_owned(),
+            user_id: token.auth_user_id().map(ToOwned::to_owned),
+        };
+        match in_flight.join(key) {
+            coalesce::Join::Leader(leader) => {
+                let response = self.send::<R>(req, timeout).await?;
+                leader.finish(&response);
+                Ok(response)
+            }
+            coalesce::Join::Waiter(waiter) => match waiter.await {
+                Ok(response) => Ok(response),
+                Err(_) => self.send::<R>(req, timeout).await,
+            },
+            coalesce::Join::Full => self.send::<R>(req, timeout).await,
+        }
+    }
+
+    /// Send a request like [`send`](HelixClient::send), sending it again up to `retries` times after a transient failure
+    ///
+    /// Returns the response and whether the request was sent more than once.
+    pub(crate) async fn send_with_retries<R: Request>(
+        &self,
+        mut req: crate::client::Request,
+        timeout: Option<std::time::Duration>,
+        retries: u32,
+    ) -> Result<(crate::client::Response, bool), ClientRequestError<<C as crate::HttpClient>::Error>>
+    {
+        #[cfg(feature = "tracing")]
+        warn_deprecated::<R>();
+        if let Some(timeout) = timeout.or(R::TIMEOUT_HINT).or(self.timeout) {
+            req.extensions_mut()
+                .insert(crate::client::RequestTimeout(timeout));
+        }
+        req.extensions_mut()
+            .insert(crate::client::MaxResponseSize(self.max_response_size));
+        let uri = req.uri().clone();
+        let mut attempt = 0;
+        let response = loop {
+            let next = (attempt < retries).then(|| clone_request(&req));
+            let result = self.client.req(req).await;
+            match next {
+                Some(next)
+                    if result
+                        .as_ref()
+                        .map_or(true, |response| is_transient(response.status())) =>
+                {
+                    req = next;
+                    attempt += 1;
+                }
+                _ => break result.map_err(ClientRequestError::RequestError)?,
+            }
+        };
+        let content_length = response
+            .headers()
+            .get(http::header::CONTENT_LENGTH)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+        let got_hint = content_length
+            .unwrap_or_default()
+            .max(response.body().len() as u64);
+        if got_hint > self.max_response_size as u64 {
+            return Err(ClientRequestError::ResponseTooLarge {
+                limit: self.max_response_size,
+                got_hint,
+                uri,
+            });
+        }
+        Ok((response, attempt > 0))
+    }
+
     /// Create a new [`HelixClient`] with a default [`HttpClient`][crate::HttpClient]
     pub fn new() -> HelixClient<'a, C>
     where C: crate::client::ClientDefault<'a> {
@@ -103,6 +418,8 @@ impl<'a, C: crate::HttpClient + 'a> HelixClient<'a, C> {
     }
 
     /// Retrieve a clone of the [`HttpClient`][crate::HttpClient] inside this [`HelixClient`]
+    ///
+    /// Useful to make requests that are not to Twitch with the same client.
     pub fn clone_client(&self) -> C
     where C: Clone {
         self.client.clone()
@@ -111,6 +428,85 @@ impl<'a, C: crate::HttpClient + 'a> HelixClient<'a, C> {
     /// Retrieve a reference of the [`HttpClient`][crate::HttpClient] inside this [`HelixClient`]
     pub fn get_client(&self) -> &C { &self.client }
 
+    /// Open a connection to Helix ahead of the first request, returning how long it took
+    ///
+    /// Sends an unauthenticated `GET` to the [Helix url](crate::TWITCH_HELIX_URL), so [`HttpClient`](crate::HttpClient)s that pool connections
+    /// can reuse the connection for the next request instead of doing the TLS handshake then.
+    /// Twitch answers with `404 Not Found`, any response is fine, only errors of the [`HttpClient`](crate::HttpClient) fail.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: twitch_api::helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = twitch_api::helix::HelixClient::default();
+    /// let latency = client.warm_up().await?;
+    /// println!("connected to helix in {latency:?}");
+    /// # Ok(()) }
+    /// ```
+    pub async fn warm_up(
+        &self,
+    ) -> Result<std::time::Duration, ClientRequestError<<C as crate::HttpClient>::Error>> {
+        let mut req = http::Request::builder()
+            .method(http::Method::GET)
+            .uri(crate::TWITCH_HELIX_URL.as_str())
+            .body(Default::default())
+            .map_err(CreateRequestError::from)?;
+        if let Some(timeout) = self.timeout {
+            req.extensions_mut()
+                .insert(crate::client::RequestTimeout(timeout));
+        }
+        let started = std::time::Instant::now();
+        self.client
+            .req(req)
+            .await
+            .map_err(ClientRequestError::RequestError)?;
+        Ok(started.elapsed())
+    }
+
+    /// Check that Helix can be reached and accepts `token`, with a request to [Get Global Emotes](crate::helix::chat::GetGlobalEmotesRequest)
+    ///
+    /// Never fails, errors of the request are reported in the [`HelixHealth`]. The response is not parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: twitch_api::helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = twitch_api::helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// let health = client.health_check(&token).await;
+    /// if !health.authorized {
+    ///     println!("helix is unavailable: {health:?}");
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn health_check<T>(&self, token: &T) -> HelixHealth
+    where T: TwitchToken + ?Sized {
+        let started = std::time::Instant::now();
+        let request = crate::helix::chat::GetGlobalEmotesRequest::new();
+        let result =
+            match request.create_request(token.token().secret(), token.client_id().as_str()) {
+                Ok(req) => self
+                    .send::<crate::helix::chat::GetGlobalEmotesRequest>(req, None)
+                    .await
+                    .map(|response| response.status()),
+                Err(e) => Err(e.into()),
+            };
+        let latency = started.elapsed();
+        let status = result.as_ref().ok().copied();
+        HelixHealth {
+            reachable: !matches!(
+                result,
+                Err(ClientRequestError::RequestError(_) | ClientRequestError::CreateRequestError(_))
+            ),
+            authorized: status.map_or(false, |status| status.is_success()),
+            status,
+            latency,
+        }
+    }
+
     /// Request on a valid [`RequestGet`] endpoint
     ///
     /// ```rust,no_run
@@ -137,20 +533,122 @@ impl<'a, C: crate::HttpClient + 'a> HelixClient<'a, C> {
     where
         R: Request<Response = D> + Request + RequestGet,
         D: serde::de::DeserializeOwned + PartialEq,
-        T: TwitchToken + ?Sized,
+        T: HelixAuth + ?Sized,
+        C: Send,
+    {
+        self.req_get_with_timeout(request, token, None).await
+    }
+
+    /// Request on a valid [`RequestGet`] endpoint, timing out after `timeout` if given, see [`send`](HelixClient::send)
+    pub(crate) async fn req_get_with_timeout<R, D, T>(
+        &'a self,
+        request: R,
+        token: &T,
+        timeout: Option<std::time::Duration>,
+    ) -> Result<Response<R, D>, ClientRequestError<<C as crate::HttpClient>::Error>>
+    where
+        R: Request<Response = D> + Request + RequestGet,
+        D: serde::de::DeserializeOwned + PartialEq,
+        T: HelixAuth + ?Sized,
         C: Send,
     {
-        let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        self.validate(&request, token)?;
+        let req = request.create_request(
+            token.auth_bearer().unwrap_or_default(),
+            token.auth_client_id(),
+        )?;
         let uri = req.uri().clone();
         let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?
+            .send_get::<R, T>(req, timeout, token)
+            .await?
             .into_response_vec();
         <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
     }
 
+    /// Request on a valid [`RequestGetBorrowed`] endpoint, keeping the body so the data can borrow from it
+    ///
+    /// See [`BorrowedResponse`] for when to use this instead of [`req_get`](HelixClient::req_get).
+    pub async fn req_get_borrowed<R, T>(
+        &'a self,
+        request: R,
+        token: &T,
+    ) -> Result<BorrowedResponse<R>, ClientRequestError<<C as crate::HttpClient>::Error>>
+    where
+        R: RequestGetBorrowed,
+        T: HelixAuth + ?Sized,
+        C: Send,
+    {
+        self.validate(&request, token)?;
+        let req = request.create_request(
+            token.auth_bearer().unwrap_or_default(),
+            token.auth_client_id(),
+        )?;
+        let uri = req.uri().clone();
+        let response = self.send_get::<R, T>(req, None, token).await?;
+        BorrowedResponse::parse_response(Some(request), &uri, response).map_err(Into::into)
+    }
+
+    /// Request on a valid [`RequestGet`] endpoint, only getting the data if it changed since `validator` was returned
+    ///
+    /// Sends the [validator](CacheValidator) as `If-None-Match` and `If-Modified-Since`.
+    /// Twitch answers with `304 Not Modified` and no body if the data is the same, which is returned as [`Conditional::NotModified`] without parsing.
+    /// Otherwise the response is parsed like in [`req_get`](HelixClient::req_get) and returned with the validator for the next request.
+    ///
+    /// Without a validator, or if Twitch doesn't support conditional requests for the endpoint, the data is always [modified](Conditional::Modified).
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    ///
+    /// let mut cached: Option<(Vec<helix::chat::GlobalEmote>, helix::CacheValidator)> = None;
+    /// let request = helix::chat::GetGlobalEmotesRequest::new();
+    /// let response = client
+    ///     .req_get_conditional(request, cached.as_ref().map(|(_, v)| v), &token)
+    ///     .await?;
+    /// if let helix::Conditional::Modified { data, validator } = response {
+    ///     cached = Some((data.data, validator));
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn req_get_conditional<R, D, T>(
+        &'a self,
+        request: R,
+        validator: Option<&CacheValidator>,
+        token: &T,
+    ) -> Result<Conditional<Response<R, D>>, ClientRequestError<<C as crate::HttpClient>::Error>>
+    where
+        R: Request<Response = D> + Request + RequestGet,
+        D: serde::de::DeserializeOwned + PartialEq,
+        T: HelixAuth + ?Sized,
+        C: Send,
+    {
+        self.validate(&request, token)?;
+        let mut req = request.create_request(
+            token.auth_bearer().unwrap_or_default(),
+            token.auth_client_id(),
+        )?;
+        if let Some(validator) = validator {
+            validator.apply(req.headers_mut());
+        }
+        let uri = req.uri().clone();
+        let response = self.send::<R>(req, None).await?;
+        if response.status() == http::StatusCode::NOT_MODIFIED {
+            return Ok(Conditional::NotModified);
+        }
+        let validator = CacheValidator::from_headers(response.headers());
+        let response = <R>::parse_response(Some(request), &uri, response.into_response_vec())?;
+        Ok(Conditional::Modified {
+            data: response,
+            validator,
+        })
+    }
+
     /// Request on a valid [`RequestPost`] endpoint
     pub async fn req_post<R, B, D, T>(
         &'a self,
@@ -162,20 +660,50 @@ impl<'a, C: crate::HttpClient + 'a> HelixClient<'a, C> {
         R: Request<Response = D> + Request + RequestPost<Body = B>,
         B: HelixRequestBody,
         D: serde::de::DeserializeOwned + PartialEq,
-        T: TwitchToken + ?Sized,
+        T: HelixAuth + ?Sized,
     {
-        let req =
-            request.create_request(body, token.token().secret(), token.client_id().as_str())?;
+        self.validate(&request, token)?;
+        let req = request.create_request(
+            body,
+            token.auth_bearer().unwrap_or_default(),
+            token.auth_client_id(),
+        )?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?
-            .into_response_vec();
+        let response = self.send::<R>(req, None).await?.into_response_vec();
         <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
     }
 
+    /// Request on a valid [`IdempotentPost`] endpoint, retrying transient failures
+    ///
+    /// The request is sent again up to [`retries`](HelixClient::with_retries) times.
+    /// If a retried request is rejected with `409 Conflict`, an earlier attempt most likely went through,
+    /// and [`ClientRequestError::AlreadyExists`] is returned instead of the error.
+    pub async fn req_post_idempotent<R, B, D, T>(
+        &'a self,
+        request: R,
+        body: B,
+        token: &T,
+    ) -> Result<Response<R, D>, ClientRequestError<<C as crate::HttpClient>::Error>>
+    where
+        R: Request<Response = D> + Request + IdempotentPost<Body = B>,
+        B: HelixRequestBody,
+        D: serde::de::DeserializeOwned + PartialEq,
+        T: HelixAuth + ?Sized,
+    {
+        self.validate(&request, token)?;
+        let req = request.create_request(
+            body,
+            token.auth_bearer().unwrap_or_default(),
+            token.auth_client_id(),
+        )?;
+        let uri = req.uri().clone();
+        let (response, retried) = self.send_with_retries::<R>(req, None, self.retries).await?;
+        if retried && response.status() == http::StatusCode::CONFLICT {
+            return Err(ClientRequestError::AlreadyExists { uri });
+        }
+        <R>::parse_response(Some(request), &uri, response.into_response_vec()).map_err(Into::into)
+    }
+
     /// Request on a valid [`RequestPatch`] endpoint
     pub async fn req_patch<R, B, D, T>(
         &'a self,
@@ -187,17 +715,16 @@ impl<'a, C: crate::HttpClient + 'a> HelixClient<'a, C> {
         R: Request<Response = D> + Request + RequestPatch<Body = B>,
         B: HelixRequestBody,
         D: serde::de::DeserializeOwned + PartialEq,
-        T: TwitchToken + ?Sized,
+        T: HelixAuth + ?Sized,
     {
-        let req =
-            request.create_request(body, token.token().secret(), token.client_id().as_str())?;
+        self.validate(&request, token)?;
+        let req = request.create_request(
+            body,
+            token.auth_bearer().unwrap_or_default(),
+            token.auth_client_id(),
+        )?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?
-            .into_response_vec();
+        let response = self.send::<R>(req, None).await?.into_response_vec();
         <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
     }
 
@@ -210,16 +737,15 @@ impl<'a, C: crate::HttpClient + 'a> HelixClient<'a, C> {
     where
         R: Request<Response = D> + Request + RequestDelete,
         D: serde::de::DeserializeOwned + PartialEq,
-        T: TwitchToken + ?Sized,
+        T: HelixAuth + ?Sized,
     {
-        let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        self.validate(&request, token)?;
+        let req = request.create_request(
+            token.auth_bearer().unwrap_or_default(),
+            token.auth_client_id(),
+        )?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?
-            .into_response_vec();
+        let response = self.send::<R>(req, None).await?.into_response_vec();
         <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
     }
 
@@ -234,17 +760,385 @@ impl<'a, C: crate::HttpClient + 'a> HelixClient<'a, C> {
         R: Request<Response = D> + Request + RequestPut<Body = B>,
         B: HelixRequestBody,
         D: serde::de::DeserializeOwned + PartialEq,
-        T: TwitchToken + ?Sized,
+        T: HelixAuth + ?Sized,
     {
-        let req =
-            request.create_request(body, token.token().secret(), token.client_id().as_str())?;
+        self.validate(&request, token)?;
+        let req = request.create_request(
+            body,
+            token.auth_bearer().unwrap_or_default(),
+            token.auth_client_id(),
+        )?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?
-            .into_response_vec();
+        let response = self.send::<R>(req, None).await?.into_response_vec();
         <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
     }
 }
+
+/// Result of [`HelixClient::health_check`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct HelixHealth {
+    /// Twitch answered the request
+    pub reachable: bool,
+    /// Twitch accepted the token, the request succeeded
+    pub authorized: bool,
+    /// The status of the response, if Twitch answered
+    pub status: Option<http::StatusCode>,
+    /// How long the request took, including failed requests
+    pub latency: std::time::Duration,
+}
+
+/// What the `req_*` methods of [`HelixClient`] need from a token
+///
+/// Implemented for every [`TwitchToken`], and for [`NoAuth`](crate::client::NoAuth) to make requests without a token.
+/// The helpers of [`HelixClient`] still take a [`TwitchToken`].
+pub trait HelixAuth {
+    /// The client id, sent as `Client-Id`
+    fn auth_client_id(&self) -> &str;
+    /// The access token, sent as the bearer in `Authorization`. `None` sends no `Authorization` header
+    fn auth_bearer(&self) -> Option<&str>;
+    /// The user of the token, `None` for tokens without a user
+    fn auth_user_id(&self) -> Option<&crate::types::UserIdRef>;
+    /// The scopes of the token, empty without a token
+    fn auth_scopes(&self) -> &[twitch_oauth2::Scope];
+}
+
+impl<T: TwitchToken + ?Sized> HelixAuth for T {
+    fn auth_client_id(&self) -> &str { self.client_id().as_str() }
+
+    fn auth_bearer(&self) -> Option<&str> { Some(self.token().secret()) }
+
+    fn auth_user_id(&self) -> Option<&crate::types::UserIdRef> { self.user_id() }
+
+    fn auth_scopes(&self) -> &[twitch_oauth2::Scope] { self.scopes() }
+}
+
+impl HelixAuth for crate::client::NoAuth {
+    fn auth_client_id(&self) -> &str { &self.client_id }
+
+    fn auth_bearer(&self) -> Option<&str> { None }
+
+    fn auth_user_id(&self) -> Option<&crate::types::UserIdRef> { None }
+
+    fn auth_scopes(&self) -> &[twitch_oauth2::Scope] { &[] }
+}
+
+/// Responses to a retried request that are worth sending the request again for
+fn is_transient(status: http::StatusCode) -> bool {
+    matches!(
+        status,
+        http::StatusCode::INTERNAL_SERVER_ERROR
+            | http::StatusCode::BAD_GATEWAY
+            | http::StatusCode::SERVICE_UNAVAILABLE
+            | http::StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// Log that `R` is [deprecated](Request::DEPRECATED), only the first time it's sent in this process
+#[cfg(feature = "tracing")]
+fn warn_deprecated<R: Request>() {
+    static WARNED: std::sync::Mutex<Vec<&'static str>> = std::sync::Mutex::new(Vec::new());
+
+    let Some(hint) = R::DEPRECATED else {
+        return;
+    };
+    let request = std::any::type_name::<R>();
+    {
+        let mut warned = WARNED.lock().unwrap_or_else(|e| e.into_inner());
+        if warned.contains(&request) {
+            return;
+        }
+        warned.push(request);
+    }
+    tracing::warn!(
+        path = R::PATH,
+        request,
+        hint,
+        "request to a deprecated endpoint"
+    );
+}
+
+/// Copy a request to send it again, [`http::Request`] isn't [`Clone`]
+fn clone_request(req: &crate::client::Request) -> crate::client::Request {
+    let mut clone = http::Request::new(req.body().clone());
+    *clone.method_mut() = req.method().clone();
+    *clone.uri_mut() = req.uri().clone();
+    *clone.version_mut() = req.version();
+    *clone.headers_mut() = req.headers().clone();
+    *clone.extensions_mut() = req.extensions().clone();
+    clone
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod deprecated_tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Collects the fields of every event
+    #[derive(Clone, Default)]
+    pub(super) struct Capture(Arc<Mutex<Vec<String>>>);
+
+    impl Capture {
+        /// Events with `text` in their fields
+        pub(super) fn events_containing(&self, text: &str) -> Vec<String> {
+            self.0
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|e| e.contains(text))
+                .cloned()
+                .collect()
+        }
+
+        fn deprecation_warnings(&self) -> Vec<String> {
+            self.events_containing("deprecated endpoint")
+        }
+    }
+
+    impl tracing::Subscriber for Capture {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool { true }
+
+        fn new_span(&self, _: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            tracing::span::Id::from_u64(1)
+        }
+
+        fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            struct Fields(String);
+            impl tracing::field::Visit for Fields {
+                fn record_debug(
+                    &mut self,
+                    field: &tracing::field::Field,
+                    value: &dyn std::fmt::Debug,
+                ) {
+                    self.0 += &format!("{}={:?} ", field.name(), value);
+                }
+            }
+            let mut fields = Fields(String::new());
+            event.record(&mut fields);
+            self.0.lock().unwrap().push(fields.0);
+        }
+
+        fn enter(&self, _: &tracing::span::Id) {}
+
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    /// Only used here, so no other test sends it first
+    #[derive(PartialEq, Eq, serde_derive::Serialize, Clone, Debug)]
+    struct OldRequest {}
+
+    impl Request for OldRequest {
+        type Response = Vec<serde_json::Value>;
+
+        const DEPRECATED: Option<&'static str> = Some("use the new endpoint");
+        const PATH: &'static str = "old/endpoint";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+    }
+
+    impl RequestGet for OldRequest {}
+
+    #[tokio::test]
+    async fn deprecated_requests_warn_once() {
+        let capture = Capture::default();
+        let _guard = tracing::subscriber::set_default(capture.clone());
+        let mock = crate::tests::MockClient::new();
+        let client = mock.helix();
+        let token = crate::tests::app_token();
+
+        for _ in 0..3 {
+            mock.respond("old/endpoint", 200, r#"{"data":[]}"#);
+            client.req_get(OldRequest {}, &token).await.unwrap();
+        }
+        assert_eq!(mock.requests().len(), 3);
+        let warnings = capture.deprecation_warnings();
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+        assert!(warnings[0].contains("use the new endpoint"));
+        assert!(warnings[0].contains("old/endpoint"));
+    }
+
+    #[tokio::test]
+    async fn other_requests_dont_warn() {
+        let capture = Capture::default();
+        let _guard = tracing::subscriber::set_default(capture.clone());
+        let mock = crate::tests::MockClient::new();
+        let client = mock.helix();
+        let token = crate::tests::app_token();
+
+        mock.respond("users", 200, r#"{"data":[]}"#);
+        client
+            .req_get(crate::helix::users::GetUsersRequest::new(), &token)
+            .await
+            .unwrap();
+        assert_eq!(capture.deprecation_warnings(), Vec::<String>::new());
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod opt_scope_tests {
+    use super::deprecated_tests::Capture;
+    use twitch_oauth2::Scope;
+
+    const MISSING: &str = "missing optional scopes";
+    const SETTINGS: &str = r#"{"data":[{"broadcaster_id":"1234","slow_mode":false,"slow_mode_wait_time":null,"follower_mode":false,"follower_mode_duration":null,"subscriber_mode":false,"emote_mode":false,"unique_chat_mode":false}]}"#;
+
+    #[tokio::test]
+    async fn unused_optional_scope() {
+        let capture = Capture::default();
+        let _guard = tracing::subscriber::set_default(capture.clone());
+        let mock = crate::tests::MockClient::new();
+        mock.respond("chat/settings", 200, SETTINGS);
+        let client = mock.helix().with_validation(true);
+        let token = crate::tests::user_token("broadcaster", "1234");
+
+        client
+            .req_get(
+                crate::helix::chat::GetChatSettingsRequest::broadcaster_id("1234"),
+                &token,
+            )
+            .await
+            .unwrap();
+        assert_eq!(capture.events_containing(MISSING), Vec::<String>::new());
+    }
+
+    #[tokio::test]
+    async fn used_optional_scope() {
+        let capture = Capture::default();
+        let _guard = tracing::subscriber::set_default(capture.clone());
+        let mock = crate::tests::MockClient::new();
+        mock.respond("chat/settings", 200, SETTINGS)
+            .respond("chat/settings", 200, SETTINGS)
+            .respond("chat/settings", 200, SETTINGS);
+        let request = || {
+            crate::helix::chat::GetChatSettingsRequest::broadcaster_id("1234").moderator_id("4321")
+        };
+
+        // only checked with validation
+        let token = crate::tests::user_token("moderator", "4321");
+        mock.helix().req_get(request(), &token).await.unwrap();
+        assert_eq!(capture.events_containing(MISSING), Vec::<String>::new());
+
+        let client = mock.helix().with_validation(true);
+        client.req_get(request(), &token).await.unwrap();
+        let warnings = capture.events_containing(MISSING);
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+        assert!(warnings[0].contains("chat/settings"));
+
+        // the request is sent either way, without the extra data
+        let token = crate::tests::user_token_with_scopes(
+            "moderator",
+            "4321",
+            vec![Scope::ModeratorReadChatSettings],
+        );
+        client.req_get(request(), &token).await.unwrap();
+        assert_eq!(capture.events_containing(MISSING).len(), 1);
+        assert_eq!(mock.requests().len(), 3);
+    }
+}
+
+#[cfg(test)]
+mod health_tests {
+    #[tokio::test]
+    async fn warm_up() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "",
+            404,
+            r#"{"error":"Not Found","status":404,"message":""}"#,
+        );
+        let client = mock.helix();
+
+        client.warm_up().await.unwrap();
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].0, http::Method::GET);
+        assert_eq!(requests[0].1, crate::TWITCH_HELIX_URL.as_str());
+        assert!(mock.request_headers()[0]
+            .get(http::header::AUTHORIZATION)
+            .is_none());
+        // an error of the http client still fails
+        assert!(client.warm_up().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn health_check() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("chat/emotes/global", 200, r#"{"data":[],"template":""}"#)
+            .respond(
+                "chat/emotes/global",
+                401,
+                r#"{"error":"Unauthorized","status":401,"message":"Invalid OAuth token"}"#,
+            );
+        let client = mock.helix();
+        let token = crate::tests::app_token();
+
+        let health = client.health_check(&token).await;
+        assert!(health.reachable && health.authorized);
+        assert_eq!(health.status, Some(http::StatusCode::OK));
+        let health = client.health_check(&token).await;
+        assert!(health.reachable && !health.authorized);
+        assert_eq!(health.status, Some(http::StatusCode::UNAUTHORIZED));
+        let health = client.health_check(&token).await;
+        assert!(!health.reachable && !health.authorized);
+        assert_eq!(health.status, None);
+
+        let requests = mock.requests();
+        assert_eq!(requests[0].1.path(), "/helix/chat/emotes/global");
+        assert_eq!(
+            mock.request_headers()[0][http::header::AUTHORIZATION],
+            "Bearer mocktoken"
+        );
+    }
+}
+
+#[cfg(test)]
+mod no_auth_tests {
+    use crate::client::NoAuth;
+    use crate::helix::{self, ClientRequestError};
+
+    #[tokio::test]
+    async fn scopeless_request() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("chat/emotes/global", 200, r#"{"data":[],"template":""}"#);
+        let client = mock.helix();
+
+        let response = client
+            .req_get(
+                helix::chat::GetGlobalEmotesRequest::new(),
+                &NoAuth::new("mockclientid"),
+            )
+            .await
+            .unwrap();
+        assert!(response.data.is_empty());
+        let headers = &mock.request_headers()[0];
+        assert_eq!(headers["Client-Id"], "mockclientid");
+        assert!(headers.get(http::header::AUTHORIZATION).is_none());
+    }
+
+    #[tokio::test]
+    async fn scoped_request() {
+        let mock = crate::tests::MockClient::new();
+        let client = mock.helix();
+
+        let err = client
+            .req_get(
+                helix::moderation::GetModeratorsRequest::broadcaster_id("1234"),
+                &NoAuth::new("mockclientid"),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ClientRequestError::NoToken {
+                path: "moderation/moderators",
+                ..
+            }
+        ));
+        assert_eq!(err.phase(), helix::Phase::Build);
+        assert!(mock.requests().is_empty());
+    }
+}
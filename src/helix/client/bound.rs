@@ -0,0 +1,266 @@
+//! A [HelixClient] bound to a token, see [`HelixClient::on_behalf_of`]
+use crate::helix::{
+    self,
+    moderation::{ModerationActionError, ModerationContext, NoUserIdOnToken},
+    ClientExtError, ClientRequestError, HelixClient,
+};
+use crate::types;
+use twitch_oauth2::TwitchToken;
+
+type ClientError<C> = ClientRequestError<<C as crate::HttpClient>::Error>;
+
+/// A [`HelixClient`] bound to a user token, acting as the user of the token in the chat of one broadcaster
+///
+/// Created with [`HelixClient::on_behalf_of`]. The helpers take neither the token nor the ids of the broadcaster and the moderator,
+/// they use the token, the [broadcaster](BoundHelixClient::broadcaster_id) and the [user of the token](BoundHelixClient::user_id).
+/// The broadcaster is the user of the token, unless another channel is set with [`for_broadcaster`](BoundHelixClient::for_broadcaster).
+///
+/// With one bound client per managed broadcaster, the token of one broadcaster can't be used with the id of another by mistake.
+///
+/// # Examples
+///
+/// ```rust, no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+/// use twitch_api::helix;
+///
+/// let channel = client.on_behalf_of(&token)?.for_broadcaster("1234");
+/// channel.ban_user("5678", "spam", None).await?;
+/// channel.send_chat_message("no spam please").await?;
+/// # Ok(()) }
+/// ```
+pub struct BoundHelixClient<'a, C: 'a, T: ?Sized> {
+    client: &'a HelixClient<'a, C>,
+    token: &'a T,
+    ctx: ModerationContext<'a>,
+}
+
+impl<C, T: ?Sized> Clone for BoundHelixClient<'_, C, T> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            token: self.token,
+            ctx: self.ctx.clone(),
+        }
+    }
+}
+
+impl<'a, C: crate::HttpClient + Sync + 'a> HelixClient<'a, C> {
+    /// Bind this client to `token`, see [`BoundHelixClient`]
+    ///
+    /// Fails if the token has no user id, like an [app access token](twitch_oauth2::AppAccessToken).
+    pub fn on_behalf_of<T>(
+        &'a self,
+        token: &'a T,
+    ) -> Result<BoundHelixClient<'a, C, T>, NoUserIdOnToken>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let user_id = token.user_id().ok_or(NoUserIdOnToken)?;
+        Ok(BoundHelixClient {
+            client: self,
+            token,
+            ctx: ModerationContext::new(user_id, user_id),
+        })
+    }
+}
+
+impl<'a, C, T: ?Sized> BoundHelixClient<'a, C, T> {
+    /// Act in the chat of `broadcaster_id` instead, as a moderator or editor of that channel
+    pub fn for_broadcaster(
+        mut self,
+        broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+    ) -> Self {
+        self.ctx.broadcaster_id = broadcaster_id.into_cow();
+        self
+    }
+
+    /// The broadcaster whose channel the helpers act in
+    pub fn broadcaster_id(&self) -> &types::UserIdRef { &self.ctx.broadcaster_id }
+
+    /// The user of the token, who the helpers act as
+    pub fn user_id(&self) -> &types::UserIdRef { &self.ctx.moderator_id }
+
+    /// The broadcaster and the moderator as a [`ModerationContext`], for the `_ctx` helpers on [`HelixClient`]
+    pub fn ctx(&self) -> &ModerationContext<'a> { &self.ctx }
+
+    /// The token the helpers use
+    pub fn token(&self) -> &'a T { self.token }
+
+    /// The client the helpers use
+    pub fn client(&self) -> &'a HelixClient<'a, C> { self.client }
+}
+
+helix_helper! {
+    /// Ban a user in the chat of the broadcaster, see [`HelixClient::ban_user`]
+    pub async fn ban_user<'b>(
+        target_user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        reason: impl Into<&'b str> + Send,
+        duration: impl Into<Option<u32>> + Send,
+    ) -> Result<helix::moderation::BanUser, ClientExtError<C, ModerationActionError>>
+        = client.ban_user_ctx(ctx, target_user_id, reason, duration);
+}
+
+helix_helper! {
+    /// Put a user in a timeout in the chat of the broadcaster, see [`HelixClient::timeout_user`]
+    pub async fn timeout_user<'b>(
+        target_user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        reason: impl Into<&'b str> + Send,
+        duration: std::time::Duration,
+    ) -> Result<helix::moderation::BanUser, ClientExtError<C, helix::moderation::BanUserBodyError>>
+        = client.timeout_user_ctx(ctx, target_user_id, reason, duration);
+}
+
+helix_helper! {
+    /// Delete a chat message in the chat of the broadcaster, see [`HelixClient::delete_chat_message`]
+    pub async fn delete_chat_message<'b>(
+        message_id: impl types::IntoCow<'b, types::MsgIdRef> + Send + 'b,
+    ) -> Result<helix::moderation::DeleteChatMessagesResponse, ClientError<C>>
+        = client.delete_chat_message_ctx(ctx, message_id);
+}
+
+helix_helper! {
+    /// Delete all chat messages in the chat of the broadcaster, see [`HelixClient::delete_all_chat_message`]
+    pub async fn delete_all_chat_message<'b>(
+    ) -> Result<helix::moderation::DeleteChatMessagesResponse, ClientError<C>>
+        = client.delete_all_chat_message(broadcaster, user);
+}
+
+helix_helper! {
+    /// Get the chatters in the chat of the broadcaster, see [`HelixClient::get_chatters`]
+    pub fn get_chatters<'b>(
+        batch_size: impl Into<Option<usize>>,
+    ) -> helix::PaginatedStream<'a, helix::chat::Chatter, ClientExtError<C, helix::chat::ChattersError>>
+        = client.get_chatters_ctx(ctx, batch_size);
+}
+
+helix_helper! {
+    /// Get the chat settings of the broadcaster, including the settings only moderators can see, see [`HelixClient::get_chat_settings`]
+    pub async fn get_chat_settings<'b>(
+    ) -> Result<helix::chat::ChatSettings, ClientError<C>>
+        = client.get_chat_settings(broadcaster, user);
+}
+
+helix_helper! {
+    /// Send a message in the chat of the broadcaster, see [`HelixClient::send_chat_message`]
+    pub async fn send_chat_message<'b>(
+        message: impl Into<&'b str> + Send,
+    ) -> Result<helix::chat::SendChatMessageResponse, ClientExtError<C, helix::chat::MessageTooLongError>>
+        = client.send_chat_message(broadcaster, user, message);
+}
+
+helix_helper! {
+    /// Send an announcement in the chat of the broadcaster, see [`HelixClient::send_chat_announcement`]
+    pub async fn send_chat_announcement<'b, E>(
+        message: impl Into<&'b str> + Send,
+        color: impl std::convert::TryInto<helix::chat::AnnouncementColor, Error = E> + Send,
+    ) -> Result<helix::chat::SendChatAnnouncementResponse, ClientExtError<C, helix::chat::SendChatAnnouncementBodyError<E>>>
+        = client.send_chat_announcement(broadcaster, user, message, color);
+}
+
+helix_helper! {
+    /// Get the live streams followed by the user of the token, see [`HelixClient::get_followed_streams`]
+    pub fn get_followed_streams<'b>(
+    ) -> impl futures::Stream<Item = Result<helix::streams::Stream, ClientError<C>>> + Send + Unpin + 'a
+        = client.get_followed_streams();
+}
+
+helix_helper! {
+    /// Get the subscribers of the user of the token, see [`HelixClient::get_broadcaster_subscriptions`]
+    ///
+    /// These are always the subscribers of the user of the token, not of the broadcaster set with [`for_broadcaster`](BoundHelixClient::for_broadcaster).
+    pub fn get_broadcaster_subscriptions<'b>(
+    ) -> impl futures::Stream<
+        Item = Result<
+            helix::subscriptions::BroadcasterSubscription,
+            ClientExtError<C, helix::subscriptions::SubscriptionsError>,
+        >,
+    > + Send + Unpin + 'a
+        = client.get_broadcaster_subscriptions();
+}
+
+#[cfg(test)]
+mod tests {
+    const BAN: &str = r#"{"data":[{"broadcaster_id":"1234","moderator_id":"5678","user_id":"9876","created_at":"2021-09-28T19:27:31Z","end_time":null}]}"#;
+
+    #[tokio::test]
+    async fn ban_user_as_token_user() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("moderation/bans", 200, BAN)
+            .respond("moderation/bans", 200, BAN);
+        let token = crate::tests::user_token("moderator", "5678");
+        let client = mock.helix();
+
+        let bound = client.on_behalf_of(&token).unwrap();
+        assert_eq!(bound.broadcaster_id().as_str(), "5678");
+        assert_eq!(bound.user_id().as_str(), "5678");
+        bound.ban_user("9876", "spam", None).await.unwrap();
+
+        let channel = bound.for_broadcaster("1234");
+        assert_eq!(channel.broadcaster_id().as_str(), "1234");
+        let ban = channel.ban_user("9876", "spam", None).await.unwrap();
+        assert_eq!(ban.user_id.as_str(), "9876");
+
+        let requests = mock.requests();
+        assert_eq!(
+            requests[0].1.query(),
+            Some("broadcaster_id=5678&moderator_id=5678")
+        );
+        assert_eq!(
+            requests[1].1.query(),
+            Some("broadcaster_id=1234&moderator_id=5678")
+        );
+        assert_eq!(
+            &requests[1].2[..],
+            br#"{"data":{"user_id":"9876","reason":"spam"}}"#
+        );
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn token_user_helpers() {
+        use futures::TryStreamExt as _;
+
+        let mock = crate::tests::MockClient::new();
+        mock.respond("streams/followed", 200, r#"{"data":[],"pagination":{}}"#)
+            .respond(
+                "subscriptions",
+                200,
+                r#"{"data":[],"pagination":{},"total":0,"points":0}"#,
+            );
+        let token = crate::tests::user_token("moderator", "5678");
+        let client = mock.helix();
+
+        // the channel set with for_broadcaster is not used
+        let channel = client.on_behalf_of(&token).unwrap().for_broadcaster("1234");
+        let streams: Vec<_> = channel.get_followed_streams().try_collect().await.unwrap();
+        assert!(streams.is_empty());
+        let subs: Vec<_> = channel
+            .get_broadcaster_subscriptions()
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(subs.is_empty());
+
+        let requests = mock.requests();
+        assert_eq!(requests[0].1.path(), "/helix/streams/followed");
+        assert!(requests[0].1.query().unwrap().contains("user_id=5678"));
+        assert_eq!(requests[1].1.path(), "/helix/subscriptions");
+        assert!(requests[1]
+            .1
+            .query()
+            .unwrap()
+            .contains("broadcaster_id=5678"));
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[test]
+    fn needs_user_token() {
+        let client = crate::tests::MockClient::new().helix();
+        let token = crate::tests::app_token();
+        assert!(client.on_behalf_of(&token).is_err());
+    }
+}
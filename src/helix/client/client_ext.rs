@@ -9,11 +9,122 @@ use twitch_oauth2::TwitchToken;
 
 type ClientError<C> = ClientRequestError<<C as crate::HttpClient>::Error>;
 
-// TODO: Consider moving these into the specific modules where the request is defined. Preferably backed by a macro
+// TODO: Move the remaining helpers into the modules where the request is defined, see `helix_helper!`
 
 impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
+    /// Get multiple [User](helix::users::User)s from user ids and logins in one request, indexed by id and login.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// At most [100](helix::users::get_users::MAX_USERS) ids and logins can be given in total.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_secret");
+    /// let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+    /// use twitch_api::{helix, types};
+    ///
+    /// let users = client
+    ///     .get_users(&["1234"][..].into(), &["justintv"][..].into(), &token)
+    ///     .await?;
+    /// let justintv = users.by_login.get(&types::UserName::from("justintv"));
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_users<T>(
+        &'client self,
+        ids: &types::Collection<'_, types::UserId>,
+        logins: &types::Collection<'_, types::UserName>,
+        token: &T,
+    ) -> Result<helix::users::UserLookup, ClientExtError<C, helix::users::TooManyUsersError>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::users::GetUsersRequest::ids_and_logins(ids.clone(), logins.clone())?;
+        Ok(self
+            .req_get(req, token)
+            .await
+            .map_err(ClientExtError::ClientError)?
+            .data
+            .into_iter()
+            .collect())
+    }
+
     /// Get [User](helix::users::User) from user login
+    ///
+    /// The login is normalized with [`UserNameExt::normalize`](crate::extra::UserNameExt::normalize) first, so `@TwitchDev` finds `twitchdev`.
+    /// An invalid login is returned as an error without making a request, use [`get_user_from_login_raw`](HelixClient::get_user_from_login_raw) to send the login as is.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    ///
+    /// let user: Option<helix::users::User> = client.get_user_from_login("@TwitchDev", &token).await?;
+    /// # Ok(()) }
+    /// ```
     pub async fn get_user_from_login<T>(
+        &'client self,
+        login: impl AsRef<str> + Send,
+        token: &T,
+    ) -> Result<Option<helix::users::User>, ClientExtError<C, crate::extra::InvalidLoginError>>
+    where
+        T: TwitchToken + Sync + Send + ?Sized,
+    {
+        use crate::extra::UserNameExt as _;
+
+        let login = types::UserName::normalize(login.as_ref())?;
+        self.get_user_from_login_raw(&*login, token)
+            .await
+            .map_err(ClientExtError::ClientError)
+    }
+
+    /// Get [User](helix::users::User) from user login, failing with [`NotFoundError`] if there is no such user
+    ///
+    /// Like [`get_user_from_login`](HelixClient::get_user_from_login), for when a missing user is an error.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    ///
+    /// let user: helix::users::User = client.get_user_from_login_required("twitchdev", &token).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_user_from_login_required<T>(
+        &'client self,
+        login: impl AsRef<str> + Send,
+        token: &T,
+    ) -> Result<helix::users::User, ClientExtError<C, UserFromLoginError>>
+    where
+        T: TwitchToken + Sync + Send + ?Sized,
+    {
+        let login = login.as_ref();
+        required(self.get_user_from_login(login, token).await, "user", login)
+    }
+
+    /// Get [User](helix::users::User) from user login, without normalizing the login
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    pub async fn get_user_from_login_raw<T>(
         &'client self,
         login: impl Into<&types::UserNameRef> + Send,
         token: &T,
@@ -25,11 +136,48 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
             helix::users::GetUsersRequest::logins(&[login.into()][..]),
             token,
         )
-        .await
-        .map(|response| response.first())
+        .await?
+        .into_optional()
+        .map_err(unexpected_response)
+    }
+
+    /// Resolve a login to a user id, normalizing the login like in [`get_user_from_login`](HelixClient::get_user_from_login)
+    ///
+    /// All the `_from_login` helpers go through this or [`resolve_user_id_raw`](HelixClient::resolve_user_id_raw), so the lookup lives in one place.
+    pub(crate) async fn resolve_user_id<T>(
+        &'client self,
+        login: impl AsRef<str> + Send,
+        token: &T,
+    ) -> Result<Option<types::UserId>, ClientExtError<C, crate::extra::InvalidLoginError>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        use crate::extra::UserNameExt as _;
+
+        let login = types::UserName::normalize(login.as_ref())?;
+        self.resolve_user_id_raw(&*login, token)
+            .await
+            .map_err(ClientExtError::ClientError)
+    }
+
+    /// Resolve a login to a user id, without normalizing the login
+    async fn resolve_user_id_raw<T>(
+        &'client self,
+        login: &types::UserNameRef,
+        token: &T,
+    ) -> Result<Option<types::UserId>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        Ok(self
+            .get_user_from_login_raw(login, token)
+            .await?
+            .map(|user| user.id))
     }
 
     /// Get [User](helix::users::User) from user id
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
     pub async fn get_user_from_id<T>(
         &'client self,
         id: impl Into<&types::UserIdRef> + Send,
@@ -39,12 +187,17 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
         T: TwitchToken + Send + Sync + ?Sized,
     {
         self.req_get(helix::users::GetUsersRequest::ids(&[id.into()][..]), token)
-            .await
-            .map(|response| response.first())
+            .await?
+            .into_optional()
+            .map_err(unexpected_response)
     }
 
     /// Get multiple [User](helix::users::User)s from user ids.
     ///
+    /// Duplicated ids are only requested once, the users are returned in the order of `ids`.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
     /// # Examples
     ///
     /// ```rust, no_run
@@ -53,8 +206,7 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
     /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
     /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
-    /// use twitch_api::{helix, types};
-    /// use futures::TryStreamExt;
+    /// use twitch_api::prelude::*;
     ///
     /// let users: Vec<helix::users::User> = client
     ///     .get_users_from_ids(&["1234", "4321"][..].into(), &token).try_collect().await?;
@@ -68,18 +220,46 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        futures::stream::iter(ids.chunks(100).collect::<Vec<_>>())
-            .map(move |c| {
+        chunked_request(
+            ids.iter(),
+            100,
+            |user: &helix::users::User| &*user.id,
+            move |c| async move {
                 let req = helix::users::GetUsersRequest::ids(c);
-                futures::stream::once(self.req_get(req, token)).boxed()
-            })
-            .flatten_unordered(None)
-            .map_ok(|resp| futures::stream::iter(resp.data.into_iter().map(Ok)))
-            .try_flatten_unordered(None)
+                Ok(self.req_get(req, token).await?.data)
+            },
+        )
     }
 
     /// Get [ChannelInformation](helix::channels::ChannelInformation) from a broadcasters login
+    ///
+    /// The login is normalized like in [`get_user_from_login`](HelixClient::get_user_from_login), use [`get_channel_from_login_raw`](HelixClient::get_channel_from_login_raw) to send the login as is.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
     pub async fn get_channel_from_login<T>(
+        &'client self,
+        login: impl AsRef<str> + Send,
+        token: &T,
+    ) -> Result<
+        Option<helix::channels::ChannelInformation>,
+        ClientExtError<C, crate::extra::InvalidLoginError>,
+    >
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        if let Some(id) = self.resolve_user_id(login, token).await? {
+            self.get_channel_from_id(&id, token)
+                .await
+                .map_err(ClientExtError::ClientError)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get [ChannelInformation](helix::channels::ChannelInformation) from a broadcasters login, without normalizing the login
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    pub async fn get_channel_from_login_raw<T>(
         &'client self,
         login: impl Into<&types::UserNameRef> + Send,
         token: &T,
@@ -87,14 +267,16 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        if let Some(user) = self.get_user_from_login(login.into(), token).await? {
-            self.get_channel_from_id(&user.id, token).await
+        if let Some(id) = self.resolve_user_id_raw(login.into(), token).await? {
+            self.get_channel_from_id(&id, token).await
         } else {
             Ok(None)
         }
     }
 
     /// Get [ChannelInformation](helix::channels::ChannelInformation) from a broadcasters id
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
     pub async fn get_channel_from_id<T>(
         &'client self,
         id: impl Into<&types::UserIdRef> + Send,
@@ -108,12 +290,79 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
             helix::channels::GetChannelInformationRequest::broadcaster_ids(ids),
             token,
         )
-        .await
-        .map(|response| response.first())
+        .await?
+        .into_optional()
+        .map_err(unexpected_response)
+    }
+
+    /// Get [ChannelInformation](helix::channels::ChannelInformation) from a broadcasters id, failing with [`NotFoundError`] if there is no such channel
+    ///
+    /// Like [`get_channel_from_id`](HelixClient::get_channel_from_id), for when a missing channel is an error.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    pub async fn get_channel_from_id_required<T>(
+        &'client self,
+        id: impl Into<&types::UserIdRef> + Send,
+        token: &T,
+    ) -> Result<helix::channels::ChannelInformation, ClientExtError<C, NotFoundError>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let id = id.into();
+        required::<_, _, NotFoundError, _>(
+            self.get_channel_from_id(id, token)
+                .await
+                .map_err(ClientExtError::ClientError),
+            "channel",
+            id.as_str(),
+        )
+    }
+
+    /// Get [ChannelInformation](helix::channels::ChannelInformation) of the broadcaster an event happened in
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::{eventsub, helix};
+    ///
+    /// async fn on_event(
+    ///     client: &helix::HelixClient<'static, twitch_api::client::DummyHttpClient>,
+    ///     event: &impl eventsub::BroadcasterInfo,
+    ///     token: &twitch_oauth2::UserToken,
+    /// ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    ///     if let Some(channel) = client.get_channel_for_event(event, token).await? {
+    ///         println!("event in {}, playing {}", channel.broadcaster_name, channel.game_name);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "eventsub")]
+    pub async fn get_channel_for_event<T>(
+        &'client self,
+        event: &(impl crate::eventsub::BroadcasterInfo + Sync + ?Sized),
+        token: &T,
+    ) -> Result<Option<helix::channels::ChannelInformation>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        self.get_channel_from_id(event.broadcaster_id(), token)
+            .await
     }
 
     /// Get multiple [ChannelInformation](helix::channels::ChannelInformation) from broadcasters ids
     ///
+    /// Duplicated ids are only requested once, the channels are returned in the order of `ids`.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
     /// # Examples
     ///
     /// ```rust, no_run
@@ -122,8 +371,7 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
     /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
     /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
-    /// use twitch_api::{helix, types};
-    /// use futures::TryStreamExt;
+    /// use twitch_api::prelude::*;
     ///
     /// let chatters: Vec<helix::channels::ChannelInformation> = client
     ///     .get_channels_from_ids(&["1234", "4321"][..].into(), &token).try_collect().await?;
@@ -140,18 +388,23 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        futures::stream::iter(ids.chunks(100).collect::<Vec<_>>())
-            .map(move |c| {
+        chunked_request(
+            ids.iter(),
+            100,
+            |channel: &helix::channels::ChannelInformation| &*channel.broadcaster_id,
+            move |c| async move {
                 let req = helix::channels::GetChannelInformationRequest::broadcaster_ids(c);
-                futures::stream::once(self.req_get(req, token)).boxed()
-            })
-            .flatten_unordered(None)
-            .map_ok(|resp| futures::stream::iter(resp.data.into_iter().map(Ok)))
-            .try_flatten_unordered(None)
+                Ok(self.req_get(req, token).await?.data)
+            },
+        )
     }
 
     /// Get multiple [Stream](helix::streams::Stream)s from user ids.
     ///
+    /// Duplicated ids are only requested once, the streams are returned in the order of `ids`.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
     /// # Examples
     ///
     /// ```rust, no_run
@@ -180,16 +433,23 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let ids = ids.chunks(100).collect::<Vec<_>>();
-        futures::stream::iter(ids.into_iter().map(move |c| {
-            let req = helix::streams::GetStreamsRequest::user_ids(c).first(100);
-            make_stream(req, token, self, std::collections::VecDeque::from)
-        }))
-        .flatten_unordered(None)
+        chunked_request(
+            ids.iter(),
+            100,
+            |stream: &helix::streams::Stream| &*stream.user_id,
+            move |c| {
+                let req = helix::streams::GetStreamsRequest::user_ids(c).first(100);
+                make_list_stream(req, token, self).try_collect()
+            },
+        )
     }
 
     /// Get multiple [Stream](helix::streams::Stream)s from user logins.
     ///
+    /// Duplicated logins are only requested once, the streams are returned in the order of `logins`. Streams of logins that are not written like Twitch returns them, e.g. in uppercase, come after the other streams of their chunk of 100 logins.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
     /// # Examples
     ///
     /// ```rust, no_run
@@ -218,17 +478,22 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let logins = logins.chunks(100).collect::<Vec<_>>();
-        futures::stream::iter(logins.into_iter().map(move |c| {
-            let req = helix::streams::GetStreamsRequest::user_logins(c).first(100);
-            make_stream(req, token, self, std::collections::VecDeque::from)
-        }))
-        .flatten_unordered(None)
+        chunked_request(
+            logins.iter(),
+            100,
+            |stream: &helix::streams::Stream| &*stream.user_login,
+            move |c| {
+                let req = helix::streams::GetStreamsRequest::user_logins(c).first(100);
+                make_list_stream(req, token, self).try_collect()
+            },
+        )
     }
 
-    /// Get chatters in a stream [Chatter][helix::chat::Chatter]
+    /// Get multiple [Stream](helix::streams::Stream)s in categories, see [`get_games_by_id`](HelixClient::get_games_by_id) to get the categories.
     ///
-    /// `batch_size` sets the amount of chatters to retrieve per api call, max 1000, defaults to 100.
+    /// Duplicated ids are only requested once, the streams are returned in the order of `ids`, and by viewer count within a category.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
     ///
     /// # Examples
     ///
@@ -238,33 +503,43 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
     /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
     /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
-    /// use twitch_api::helix;
+    /// use twitch_api::{types, helix};
     /// use futures::TryStreamExt;
     ///
-    /// let chatters: Vec<helix::chat::Chatter> = client
-    ///    .get_chatters("1234", "4321", 1000, &token)
-    ///    .try_collect().await?;
+    /// let live: Vec<helix::streams::Stream> = client
+    ///     .get_streams_in_categories(&["509658", "27471"][..].into(), &token)
+    ///     .try_collect()
+    ///     .await?;
     /// # Ok(()) }
     /// ```
-    pub fn get_chatters<T>(
+    pub fn get_streams_in_categories<T>(
         &'client self,
-        broadcaster_id: impl Into<&'client types::UserIdRef>,
-        moderator_id: impl Into<&'client types::UserIdRef>,
-        batch_size: impl Into<Option<usize>>,
+        ids: &'client types::Collection<'client, types::CategoryId>,
         token: &'client T,
-    ) -> impl futures::Stream<Item = Result<helix::chat::Chatter, ClientError<C>>> + Send + Unpin + 'client
+    ) -> impl futures::Stream<Item = Result<helix::streams::Stream, ClientError<C>>>
+           + Send
+           + Unpin
+           + 'client
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::chat::GetChattersRequest {
-            first: batch_size.into(),
-            ..helix::chat::GetChattersRequest::new(broadcaster_id.into(), moderator_id.into())
-        };
-
-        make_stream(req, token, self, std::collections::VecDeque::from)
+        chunked_request(
+            ids.iter(),
+            100,
+            |stream: &helix::streams::Stream| &*stream.game_id,
+            move |c| {
+                let req = helix::streams::GetStreamsRequest::game_ids(c).first(100);
+                make_list_stream(req, token, self).try_collect()
+            },
+        )
     }
 
-    /// Search [Categories](helix::search::Category)
+    /// Get [Stream](helix::streams::Stream)s in a category by its name
+    ///
+    /// The category is resolved with [`search_categories_exact`](HelixClient::search_categories_exact),
+    /// if there is no category with this exact name, [`CategoryNotFound`](helix::streams::CategoryNotFound) is returned.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
     ///
     /// # Examples
     ///
@@ -277,27 +552,47 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// use twitch_api::helix;
     /// use futures::TryStreamExt;
     ///
-    /// let categories: Vec<helix::search::Category> = client
-    ///     .search_categories("Fortnite", &token)
-    ///     .try_collect().await?;
+    /// let live: Vec<helix::streams::Stream> = client
+    ///     .get_streams_in_category_by_name("just chatting", &token)
+    ///     .await?
+    ///     .try_collect()
+    ///     .await?;
     /// # Ok(()) }
     /// ```
-    pub fn search_categories<T>(
+    pub async fn get_streams_in_category_by_name<T>(
         &'client self,
-        query: impl Into<&'client str>,
+        name: impl Into<&str> + Send,
         token: &'client T,
-    ) -> impl futures::Stream<Item = Result<helix::search::Category, ClientError<C>>>
-           + Send
-           + Unpin
-           + 'client
+    ) -> Result<
+        helix::PaginatedStream<'client, helix::streams::Stream, ClientError<C>>,
+        ClientExtError<C, helix::streams::CategoryNotFound>,
+    >
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::search::SearchCategoriesRequest::query(query.into()).first(100);
-        make_stream(req, token, self, std::collections::VecDeque::from)
+        let name = name.into();
+        let Some(category) = self
+            .search_categories_exact(name, token)
+            .await
+            .map_err(ClientExtError::ClientError)?
+        else {
+            return Err(helix::streams::CategoryNotFound {
+                name: name.to_owned(),
+            }
+            .into());
+        };
+        let req = helix::streams::GetStreamsRequest::game_ids(vec![category.id]).first(100);
+        Ok(make_list_stream(req, token, self))
     }
 
-    /// Search [Channels](helix::search::Channel) via channel name or description
+    /// Get the `limit` streams with the most viewers across the categories in `category_ids`
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// The categories are requested concurrently and the streams are sorted by [viewer count](helix::streams::Stream::viewer_count), most viewers first.
+    /// A stream returned for more than one category is only included once.
+    /// Twitch returns the streams of a category sorted by viewers, so only as many pages are requested per category as `limit` needs,
+    /// a single page for a `limit` of up to 100.
     ///
     /// # Examples
     ///
@@ -308,33 +603,51 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
     /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
     /// use twitch_api::helix;
-    /// use futures::TryStreamExt;
     ///
-    /// let channel: Vec<helix::search::Channel> = client
-    ///     .search_channels("twitchdev", false, &token)
-    ///     .try_collect().await?;
+    /// let top: Vec<helix::streams::Stream> = client
+    ///     .get_top_streams_in_categories(&["509658", "27471"][..].into(), 10, &token)
+    ///     .await?;
     /// # Ok(()) }
     /// ```
-    pub fn search_channels<'b, T>(
+    pub async fn get_top_streams_in_categories<T>(
         &'client self,
-        query: impl Into<&'b str>,
-        live_only: bool,
+        category_ids: &'client types::Collection<'client, types::CategoryId>,
+        limit: usize,
         token: &'client T,
-    ) -> impl futures::Stream<Item = Result<helix::search::Channel, ClientError<C>>>
-           + Send
-           + Unpin
-           + 'client
+    ) -> Result<Vec<helix::streams::Stream>, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
-        'b: 'client,
     {
-        let req = helix::search::SearchChannelsRequest::query(query.into()).live_only(live_only);
-        make_stream(req, token, self, std::collections::VecDeque::from)
+        if limit == 0 {
+            return Ok(vec![]);
+        }
+        let categories: Vec<_> = {
+            let mut seen = std::collections::HashSet::new();
+            category_ids.iter().filter(|id| seen.insert(*id)).collect()
+        };
+        let mut streams: Vec<helix::streams::Stream> = futures::stream::iter(categories)
+            .map(|id| {
+                let req = helix::streams::GetStreamsRequest::game_ids(vec![id.to_owned()])
+                    .first(limit.min(100));
+                make_list_stream(req, token, self)
+                    .take(limit)
+                    .try_collect::<Vec<_>>()
+            })
+            .buffered(CHUNK_CONCURRENCY)
+            .try_concat()
+            .await?;
+        streams.sort_by(|a, b| b.viewer_count.cmp(&a.viewer_count));
+        let mut seen = std::collections::HashSet::new();
+        streams.retain(|stream| seen.insert(stream.id.clone()));
+        streams.truncate(limit);
+        Ok(streams)
     }
 
-    /// Get information on a [follow relationship](helix::users::FollowRelationship)
+    /// Get how long a broadcaster has been live, see [`Stream::uptime`](helix::streams::Stream::uptime)
     ///
-    /// Can be used to see if X follows Y
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// Returns [`None`] if the broadcaster is not live.
     ///
     /// # Examples
     ///
@@ -344,45 +657,39 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
     /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
     /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
-    /// use twitch_api::{types, helix};
-    /// use futures::TryStreamExt;
+    /// use twitch_api::helix;
     ///
-    /// // Get the followers of channel "1234"
-    /// let followers: Vec<helix::users::FollowRelationship> = client
-    ///     .get_follow_relationships(Some("1234".into()), None, &token)
-    ///     .try_collect().await?;
+    /// match client.get_stream_uptime("1234", &token).await? {
+    ///     Some(uptime) => println!("live for {} minutes", uptime.as_secs() / 60),
+    ///     None => println!("offline"),
+    /// }
     /// # Ok(()) }
     /// ```
-    #[deprecated(
-        note = "this method will not work anymore on 3 august, see https://discuss.dev.twitch.tv/t/follows-endpoints-and-eventsub-subscription-type-are-now-available-in-open-beta/43322"
-    )]
-    #[allow(deprecated)]
-    #[doc(hidden)]
-    pub fn get_follow_relationships<'b, T>(
+    #[cfg(feature = "time")]
+    pub async fn get_stream_uptime<T>(
         &'client self,
-        to_id: impl Into<Option<&'b types::UserIdRef>>,
-        from_id: impl Into<Option<&'b types::UserIdRef>>,
-        token: &'client T,
-    ) -> impl futures::Stream<Item = Result<helix::users::FollowRelationship, ClientError<C>>>
-           + Send
-           + Unpin
-           + 'client
+        user_id: impl Into<&types::UserIdRef> + Send,
+        token: &T,
+    ) -> Result<Option<std::time::Duration>, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
-        'b: 'client,
     {
-        let mut req = helix::users::GetUsersFollowsRequest::empty();
-        req.to_id = to_id.into().map(Cow::Borrowed);
-        req.from_id = from_id.into().map(Cow::Borrowed);
-
-        make_stream(req, token, self, |s| {
-            std::collections::VecDeque::from(s.follow_relationships)
-        })
+        Ok(self
+            .req_get(
+                helix::streams::GetStreamsRequest::user_ids(&[user_id.into()][..]),
+                token,
+            )
+            .await?
+            .first()
+            .and_then(|stream| stream.uptime()))
     }
 
-    /// Get authenticated users' followed [streams](helix::streams::Stream)
+    /// Search [Categories](helix::search::Category)
     ///
-    /// Requires token with scope [`user:read:follows`](twitch_oauth2::Scope::UserReadFollows).
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// `batch_size` sets the amount of categories to retrieve per api call, max 100, defaults to 100.
+    /// To read only a few categories, size the pages with [`expect_items`](helix::PaginatedStream::expect_items) instead.
     ///
     /// # Examples
     ///
@@ -390,38 +697,36 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
     /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
-    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_secret");
+    /// let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
     /// use twitch_api::helix;
     /// use futures::TryStreamExt;
     ///
-    /// let channels: Vec<helix::streams::Stream> = client
-    ///     .get_followed_streams(&token)
+    /// let categories: Vec<helix::search::Category> = client
+    ///     .search_categories("Fortnite", None, &token)
     ///     .try_collect().await?;
     /// # Ok(()) }
     /// ```
-    pub fn get_followed_streams<T>(
+    pub fn search_categories<T>(
         &'client self,
+        query: impl Into<&'client str>,
+        batch_size: impl Into<Option<usize>>,
         token: &'client T,
-    ) -> impl futures::Stream<Item = Result<helix::streams::Stream, ClientError<C>>>
-           + Send
-           + Unpin
-           + 'client
+    ) -> helix::PaginatedStream<'client, helix::search::Category, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let user_id = match token
-            .user_id()
-            .ok_or_else(|| ClientRequestError::Custom("no user_id found on token".into()))
-        {
-            Ok(t) => t,
-            Err(e) => return futures::stream::once(async { Err(e) }).boxed(),
-        };
-        let req = helix::streams::GetFollowedStreamsRequest::user_id(user_id);
-        make_stream(req, token, self, std::collections::VecDeque::from)
+        let req = helix::search::SearchCategoriesRequest::query(query.into())
+            .first(batch_size.into().unwrap_or(100));
+        make_list_stream(req, token, self)
     }
 
-    /// Get authenticated broadcasters' [subscribers](helix::subscriptions::BroadcasterSubscription)
+    /// Search for a [Category](helix::search::Category) with exactly this name, ignoring case
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// Only the first 100 results of the search are checked. Returns [`None`](Option::None) if none of them is named `name`.
     ///
     /// # Examples
     ///
@@ -432,37 +737,37 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
     /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
     /// use twitch_api::helix;
-    /// use futures::TryStreamExt;
     ///
-    /// let subs: Vec<helix::subscriptions::BroadcasterSubscription> = client
-    ///     .get_broadcaster_subscriptions(&token)
-    ///     .try_collect().await?;
+    /// let category: Option<helix::search::Category> = client
+    ///     .search_categories_exact("just chatting", &token)
+    ///     .await?;
     /// # Ok(()) }
     /// ```
-    pub fn get_broadcaster_subscriptions<T>(
+    pub async fn search_categories_exact<T>(
         &'client self,
-        token: &'client T,
-    ) -> impl futures::Stream<
-        Item = Result<helix::subscriptions::BroadcasterSubscription, ClientError<C>>,
-    > + Send
-           + Unpin
-           + 'client
+        name: impl Into<&str> + Send,
+        token: &T,
+    ) -> Result<Option<helix::search::Category>, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let user_id = match token
-            .user_id()
-            .ok_or_else(|| ClientRequestError::Custom("no user_id found on token".into()))
-        {
-            Ok(t) => t,
-            Err(e) => return futures::stream::once(async { Err(e) }).boxed(),
-        };
-        // If this fails to compile due to missing implementation, make sure this crate and `twitch_oauth2` use the same version of `twitch_types`
-        let req = helix::subscriptions::GetBroadcasterSubscriptionsRequest::broadcaster_id(user_id);
-        make_stream(req, token, self, std::collections::VecDeque::from)
+        let name = name.into();
+        let lowercase = name.to_lowercase();
+        let req = helix::search::SearchCategoriesRequest::query(name).first(100);
+        Ok(self
+            .req_get(req, token)
+            .await?
+            .data
+            .into_iter()
+            .find(|c| c.name.to_lowercase() == lowercase))
     }
 
-    /// Get all moderators in a channel [Get Moderators](helix::moderation::GetModeratorsRequest)
+    /// Search for the [Category](helix::search::Category) that best matches `query`
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// Only the first 10 results of the search are ranked, with [`rank_categories`](helix::search::rank_categories).
+    /// Returns [`None`](Option::None) if the search has no results.
     ///
     /// # Examples
     ///
@@ -473,30 +778,29 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
     /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
     /// use twitch_api::helix;
-    /// use futures::TryStreamExt;
     ///
-    /// let moderators: Vec<helix::moderation::Moderator> = client
-    ///     .get_moderators_in_channel_from_id("twitchdev", &token)
-    ///     .try_collect().await?;
+    /// let category: Option<helix::search::Category> = client
+    ///     .search_category_best_match("osu", &token)
+    ///     .await?;
     /// # Ok(()) }
     /// ```
-    pub fn get_moderators_in_channel_from_id<'b: 'client, T>(
+    pub async fn search_category_best_match<T>(
         &'client self,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + 'b,
-        token: &'client T,
-    ) -> impl futures::Stream<Item = Result<helix::moderation::Moderator, ClientError<C>>>
-           + Send
-           + Unpin
-           + 'client
+        query: impl Into<&str> + Send,
+        token: &T,
+    ) -> Result<Option<helix::search::Category>, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::moderation::GetModeratorsRequest::broadcaster_id(broadcaster_id);
-
-        make_stream(req, token, self, std::collections::VecDeque::from)
+        let query = query.into();
+        let req = helix::search::SearchCategoriesRequest::query(query).first(10);
+        let mut categories = self.req_get(req, token).await?.data;
+        Ok(helix::search::rank_categories(query, &categories).map(|i| categories.swap_remove(i)))
     }
 
-    /// Get all banned users in a channel [Get Banned Users](helix::moderation::GetBannedUsersRequest)
+    /// Search [Channels](helix::search::Channel) via channel name or description
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
     ///
     /// # Examples
     ///
@@ -509,72 +813,177 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// use twitch_api::helix;
     /// use futures::TryStreamExt;
     ///
-    /// let moderators: Vec<helix::moderation::BannedUser> = client.get_banned_users_in_channel_from_id("twitchdev", &token).try_collect().await?;
+    /// let channel: Vec<helix::search::Channel> = client
+    ///     .search_channels("twitchdev", false, &token)
+    ///     .try_collect().await?;
     /// # Ok(()) }
     /// ```
-    pub fn get_banned_users_in_channel_from_id<'b: 'client, T>(
+    pub fn search_channels<'b, T>(
         &'client self,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + 'b,
+        query: impl Into<&'b str>,
+        live_only: bool,
         token: &'client T,
-    ) -> impl futures::Stream<Item = Result<helix::moderation::BannedUser, ClientError<C>>>
-           + Send
-           + Unpin
-           + 'client
+    ) -> helix::PaginatedStream<'client, helix::search::Channel, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
+        'b: 'client,
     {
-        let req = helix::moderation::GetBannedUsersRequest::broadcaster_id(broadcaster_id);
-
-        make_stream(req, token, self, std::collections::VecDeque::from)
+        let req = helix::search::SearchChannelsRequest::query(query.into()).live_only(live_only);
+        make_list_stream(req, token, self)
     }
 
-    /// Get a users, with login, follow count
+    /// Get information on a [follow relationship](helix::users::FollowRelationship)
+    ///
+    /// Can be used to see if X follows Y
+    ///
+    /// Fails with [`GetUsersFollowsRequestError::MissingId`](helix::users::GetUsersFollowsRequestError::MissingId) before making a request if neither `to_id` nor `from_id` is set.
+    ///
+    /// Twitch has removed Get Users Follows and responds with `410 Gone`. When only `to_id` is set, the followers are then requested
+    /// with [Get Channel Followers](helix::channels::get_channel_followers), which needs a user token of the broadcaster or one of their moderators
+    /// with the [`moderator:read:followers`](twitch_oauth2::Scope::ModeratorReadFollowers) scope.
+    /// If that is not possible, the stream fails with [`FollowsEndpointGone`](helix::users::FollowsEndpointGone).
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::{types, helix};
+    /// use futures::TryStreamExt;
+    ///
+    /// // Get the followers of channel "1234"
+    /// let followers: Vec<helix::users::FollowRelationship> = client
+    ///     .get_follow_relationships(Some("1234".into()), None, &token)?
+    ///     .try_collect().await?;
+    /// # Ok(()) }
+    /// ```
     #[deprecated(
         note = "this method will not work anymore on 3 august, see https://discuss.dev.twitch.tv/t/follows-endpoints-and-eventsub-subscription-type-are-now-available-in-open-beta/43322"
     )]
     #[allow(deprecated)]
     #[doc(hidden)]
-    pub async fn get_total_followers_from_login<'b, T>(
+    pub fn get_follow_relationships<'b, T>(
         &'client self,
-        login: impl types::IntoCow<'b, types::UserNameRef> + Send + 'b,
-        token: &T,
-    ) -> Result<Option<i64>, ClientError<C>>
+        to_id: impl Into<Option<&'b types::UserIdRef>>,
+        from_id: impl Into<Option<&'b types::UserIdRef>>,
+        token: &'client T,
+    ) -> Result<
+        helix::PaginatedStream<
+            'client,
+            helix::users::FollowRelationship,
+            ClientExtError<C, helix::users::FollowsEndpointGone>,
+        >,
+        helix::users::GetUsersFollowsRequestError,
+    >
     where
         T: TwitchToken + Send + Sync + ?Sized,
+        'b: 'client,
     {
-        if let Some(user) = self.get_user_from_login(&*login.into_cow(), token).await? {
-            self.get_total_followers_from_id(&user.id, token)
-                .await
-                .map(Some)
-        } else {
-            Ok(None)
-        }
+        let (to_id, from_id) = (to_id.into(), from_id.into());
+        let req = helix::users::GetUsersFollowsRequest::try_new(to_id, from_id)?;
+        // only the followers of a channel can be requested from the new endpoint
+        let broadcaster_id = to_id.filter(|_| from_id.is_none()).map(ToOwned::to_owned);
+
+        let progress = PaginationProgress::default();
+        let mut stream = make_stream(req, token, self, |s| {
+            std::collections::VecDeque::from(s.follow_relationships)
+        });
+        let reported = progress.clone();
+        let inner = futures::stream::once(async move {
+            let first = stream.next().await;
+            match first {
+                Some(Err(ref e)) if is_gone(e) => match broadcaster_id {
+                    Some(id) => {
+                        self.get_followers_as_relationships(id, token, reported)
+                            .await
+                    }
+                    None => futures::stream::once(async {
+                        Err(ClientExtError::Other(helix::users::FollowsEndpointGone))
+                    })
+                    .boxed(),
+                },
+                first => {
+                    reported.mirror(&stream.progress);
+                    futures::stream::iter(first)
+                        .chain(stream.reporting_to(reported))
+                        .map_err(ClientExtError::ClientError)
+                        .boxed()
+                }
+            }
+        })
+        .flatten()
+        .boxed();
+        Ok(PaginatedStream { inner, progress })
     }
 
-    /// Get a broadcasters follow count
-    ///
-    /// # Notes
-    ///
-    /// You need to have the scope `moderator:read:followers` and be a moderator of the channel if the token is not the broadcasters own token
-    pub async fn get_total_channel_followers<'b, T>(
+    /// Get the followers of a channel from Get Channel Followers as [follow relationships](helix::users::FollowRelationship)
+    #[allow(deprecated)]
+    async fn get_followers_as_relationships<T>(
         &'client self,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        token: &T,
-    ) -> Result<i64, ClientError<C>>
+        broadcaster_id: types::UserId,
+        token: &'client T,
+        progress: PaginationProgress,
+    ) -> futures::stream::BoxStream<
+        'client,
+        Result<
+            helix::users::FollowRelationship,
+            ClientExtError<C, helix::users::FollowsEndpointGone>,
+        >,
+    >
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let resp = self
-            .req_get(
-                helix::channels::GetChannelFollowersRequest::broadcaster_id(broadcaster_id),
-                token,
-            )
-            .await?;
+        let gone = || {
+            futures::stream::once(async {
+                Err(ClientExtError::Other(helix::users::FollowsEndpointGone))
+            })
+            .boxed()
+        };
+        if !can_read_followers(token) {
+            return gone();
+        }
+        let broadcaster = match self.get_user_from_id(&broadcaster_id, token).await {
+            Ok(Some(broadcaster)) => broadcaster,
+            Ok(None) => return futures::stream::empty().boxed(),
+            Err(e) => {
+                return futures::stream::once(async { Err(ClientExtError::ClientError(e)) }).boxed()
+            }
+        };
 
-        Ok(resp.total.unwrap_or(0))
+        let req =
+            helix::channels::GetChannelFollowersRequest::broadcaster_id(broadcaster_id).first(100);
+        let mut stream = make_list_stream(req, token, self);
+        let first = stream.next().await;
+        match first {
+            Some(Err(ref e)) if is_unauthorized(e) => return gone(),
+            // only the total is returned if the token is not of the broadcaster or a moderator
+            None if stream.total().map_or(false, |total| total > 0) => return gone(),
+            _ => (),
+        }
+        progress.mirror(&stream.progress);
+        futures::stream::iter(first)
+            .chain(stream.reporting_to(progress))
+            .map_ok(move |follower| helix::users::FollowRelationship {
+                followed_at: follower.followed_at,
+                from_id: follower.user_id,
+                from_name: follower.user_name,
+                from_login: follower.user_login,
+                to_id: broadcaster.id.clone(),
+                to_name: broadcaster.display_name.clone(),
+                to_login: broadcaster.login.clone(),
+            })
+            .map_err(ClientExtError::ClientError)
+            .boxed()
     }
 
-    /// Get users followed channels
+    /// Get authenticated users' followed [streams](helix::streams::Stream)
+    ///
+    /// Requires token with scope [`user:read:follows`](twitch_oauth2::Scope::UserReadFollows).
+    ///
+    /// To skip reruns and watch parties, wrap the stream in [`filter_live_broadcasts`](helix::streams::filter_live_broadcasts).
     ///
     /// # Examples
     ///
@@ -587,57 +996,96 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// use twitch_api::helix;
     /// use futures::TryStreamExt;
     ///
-    /// let schedule: Vec<helix::channels::FollowedBroadcaster> = client
-    ///     .get_followed_channels("1234", &token)
-    ///     .try_collect()
-    ///     .await?;
+    /// let channels: Vec<helix::streams::Stream> = client
+    ///     .get_followed_streams(&token)
+    ///     .try_collect().await?;
     /// # Ok(()) }
     /// ```
-    pub fn get_followed_channels<'b: 'client, T>(
+    pub fn get_followed_streams<T>(
         &'client self,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + 'b,
         token: &'client T,
-    ) -> impl futures::Stream<Item = Result<helix::channels::FollowedBroadcaster, ClientError<C>>>
+    ) -> impl futures::Stream<Item = Result<helix::streams::Stream, ClientError<C>>>
            + Send
            + Unpin
            + 'client
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req =
-            helix::channels::get_followed_channels::GetFollowedChannels::user_id(broadcaster_id);
-        make_stream(req, token, self, |broadcasts| broadcasts.into())
+        let user_id = match token
+            .user_id()
+            .ok_or_else(|| ClientRequestError::Custom("no user_id found on token".into()))
+        {
+            Ok(t) => t,
+            Err(e) => return futures::stream::once(async { Err(e) }).boxed(),
+        };
+        let req = helix::streams::GetFollowedStreamsRequest::user_id(user_id);
+        make_list_stream(req, token, self).boxed()
     }
 
-    /// Get a users, with id, follow count
+    /// Create a [stream marker](helix::streams::StreamMarker) with a description, if the broadcaster is live
     ///
-    /// # Notes
+    /// Twitch responds with `404 Not Found` and a message saying the user is not live when the broadcaster is not live,
+    /// this is returned as [`MarkerOutcome::NotLive`](helix::streams::MarkerOutcome::NotLive). Any other error, including other `404 Not Found` responses, is returned as an error.
+    /// The description is limited to [140 characters](helix::streams::create_stream_marker::MAX_DESCRIPTION_LENGTH).
     ///
-    /// This returns zero if the user doesn't exist
-    #[allow(deprecated)]
-    #[doc(hidden)]
-    #[deprecated(
-        note = "this method will not work anymore on 3 august, see https://discuss.dev.twitch.tv/t/follows-endpoints-and-eventsub-subscription-type-are-now-available-in-open-beta/43322"
-    )]
-    pub async fn get_total_followers_from_id<'b, T>(
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    ///
+    /// match client.create_stream_marker_if_live("1234", "great play", &token).await? {
+    ///     helix::streams::MarkerOutcome::Created(marker) => println!("marked at {}s", marker.position_seconds),
+    ///     _ => println!("not live"),
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn create_stream_marker_if_live<'b, T>(
         &'client self,
-        to_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        description: impl Into<Cow<'b, str>> + Send,
         token: &T,
-    ) -> Result<i64, ClientError<C>>
+    ) -> Result<
+        helix::streams::MarkerOutcome,
+        ClientExtError<C, helix::streams::StreamMarkerDescriptionError>,
+    >
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let resp = self
-            .req_get(
-                helix::users::GetUsersFollowsRequest::followers(to_id),
+        let body =
+            helix::streams::CreateStreamMarkerBody::with_description(broadcaster_id, description)?;
+        match self
+            .req_post(
+                helix::streams::CreateStreamMarkerRequest::new(),
+                body,
                 token,
             )
-            .await?;
-
-        Ok(resp.data.total)
+            .await
+        {
+            Ok(response) => Ok(helix::streams::MarkerOutcome::Created(response.data)),
+            Err(ClientRequestError::HelixRequestPostError(
+                helix::HelixRequestPostError::Error {
+                    status: http::StatusCode::NOT_FOUND,
+                    message,
+                    ..
+                },
+            )) if message.to_lowercase().contains("not live") => {
+                Ok(helix::streams::MarkerOutcome::NotLive)
+            }
+            Err(e) => Err(ClientExtError::ClientError(e)),
+        }
     }
 
-    /// Get games by ID.
+    /// Create a [stream marker](helix::streams::StreamMarker) and send a chat announcement about it
+    ///
+    /// The announcement is `<description> (HH:MM:SS)`, with the [position](helix::streams::StreamMarker::position) of the marker in the stream.
+    /// The description and color are checked before the marker is created.
+    ///
+    /// If the announcement fails after the marker was created, [`MarkAndAnnounceError::Announcement`](helix::streams::MarkAndAnnounceError::Announcement) contains the created marker.
     ///
     /// # Examples
     ///
@@ -647,144 +1095,75 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
     /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
     /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
-    /// use twitch_api::{types, helix};
-    /// use futures::TryStreamExt;
+    /// use twitch_api::helix;
     ///
-    /// let games: Vec<helix::games::Game> = client
-    ///     .get_games_by_id(&["509658", "32982", "27471"][..].into(), &token).try_collect().await?;
+    /// let marked = client
+    ///     .mark_and_announce("1234", "5678", "great play", "purple", &token)
+    ///     .await?;
+    /// println!("marked at {}", marked.marker.position());
     /// # Ok(()) }
     /// ```
-    pub fn get_games_by_id<T>(
-        &'client self,
-        ids: &'client types::Collection<'client, types::CategoryId>,
-        token: &'client T,
-    ) -> impl futures::Stream<Item = Result<helix::games::Game, ClientError<C>>> + Send + Unpin + 'client
-    where
-        T: TwitchToken + Send + Sync + ?Sized,
-    {
-        futures::stream::iter(ids.chunks(100).collect::<Vec<_>>())
-            .map(move |c| {
-                let req = helix::games::GetGamesRequest::ids(c);
-                futures::stream::once(self.req_get(req, token)).boxed()
-            })
-            .flatten_unordered(None)
-            .map_ok(|resp| futures::stream::iter(resp.data.into_iter().map(Ok)))
-            .try_flatten_unordered(None)
-    }
-
-    /// Block a user
-    pub async fn block_user<'b, T>(
+    pub async fn mark_and_announce<'b, T, E>(
         &'client self,
-        target_user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        description: impl Into<Cow<'b, str>> + Send,
+        announcement_color: impl std::convert::TryInto<helix::chat::AnnouncementColor, Error = E> + Send,
         token: &T,
-    ) -> Result<helix::users::BlockUser, ClientError<C>>
+    ) -> Result<
+        helix::streams::MarkAndAnnounce,
+        ClientExtError<C, helix::streams::MarkAndAnnounceError<C, E>>,
+    >
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        Ok(self
-            .req_put(
-                helix::users::BlockUserRequest::block_user(target_user_id),
-                helix::EmptyBody,
+        use helix::streams::MarkAndAnnounceError;
+
+        let color = announcement_color
+            .try_into()
+            .map_err(MarkAndAnnounceError::<C, E>::Color)?;
+        let broadcaster_id = broadcaster_id.into_cow();
+        let description = description.into();
+        let body = helix::streams::CreateStreamMarkerBody::with_description(
+            &*broadcaster_id,
+            description.clone(),
+        )
+        .map_err(MarkAndAnnounceError::<C, E>::Description)?;
+        let marker = self
+            .req_post(
+                helix::streams::CreateStreamMarkerRequest::new(),
+                body,
                 token,
             )
-            .await?
-            .data)
-    }
+            .await
+            .map_err(ClientExtError::ClientError)?
+            .data;
 
-    /// Unblock a user
-    pub async fn unblock_user<'b, T>(
-        &'client self,
-        target_user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        token: &T,
-    ) -> Result<helix::users::UnblockUser, ClientError<C>>
-    where
-        T: TwitchToken + Send + Sync + ?Sized,
-    {
-        Ok(self
-            .req_delete(
-                helix::users::UnblockUserRequest::unblock_user(target_user_id),
+        let body = helix::chat::SendChatAnnouncementBody {
+            message: format!("{} ({})", description, marker.position()).into(),
+            color,
+        };
+        match self
+            .req_post(
+                helix::chat::SendChatAnnouncementRequest::new(&*broadcaster_id, moderator_id),
+                body,
                 token,
             )
-            .await?
-            .data)
-    }
-
-    /// Ban a user
-    pub async fn ban_user<'b, T>(
-        &'client self,
-        target_user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        reason: impl Into<&'b str> + Send,
-        duration: impl Into<Option<u32>> + Send,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        token: &T,
-    ) -> Result<helix::moderation::BanUser, ClientError<C>>
-    where
-        T: TwitchToken + Send + Sync + ?Sized,
-    {
-        Ok(self
-            .req_post(
-                helix::moderation::BanUserRequest::new(broadcaster_id, moderator_id),
-                helix::moderation::BanUserBody::new(target_user_id, reason.into(), duration),
-                token,
-            )
-            .await?
-            .data)
-    }
-
-    /// Unban a user
-    pub async fn unban_user<'b, T>(
-        &'client self,
-        target_user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        token: &T,
-    ) -> Result<helix::moderation::UnbanUserResponse, ClientError<C>>
-    where
-        T: TwitchToken + Send + Sync + ?Sized,
-    {
-        Ok(self
-            .req_delete(
-                helix::moderation::UnbanUserRequest::new(
-                    broadcaster_id,
-                    moderator_id,
-                    target_user_id,
-                ),
-                token,
-            )
-            .await?
-            .data)
-    }
-
-    #[cfg(feature = "beta")]
-    /// Warn a user
-    pub async fn warn_chat_user<'b, T>(
-        &'client self,
-        target_user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        reason: impl Into<&'b str> + Send,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        token: &T,
-    ) -> Result<helix::moderation::WarnChatUser, ClientError<C>>
-    where
-        T: TwitchToken + Send + Sync + ?Sized,
-    {
-        Ok(self
-            .req_post(
-                helix::moderation::WarnChatUserRequest::new(broadcaster_id, moderator_id),
-                helix::moderation::WarnChatUserBody::new(target_user_id, reason.into()),
-                token,
-            )
-            .await?
-            .data)
+            .await
+        {
+            Ok(response) => Ok(helix::streams::MarkAndAnnounce {
+                marker,
+                announcement: response.data,
+            }),
+            Err(error) => Err(MarkAndAnnounceError::<C, E>::Announcement { marker, error }.into()),
+        }
     }
 
-    // FIXME: Example should use https://github.com/twitch-rs/twitch_api/issues/162
-    /// Get all scheduled streams in a channel.
-    ///
-    /// # Notes
+    /// Get authenticated broadcasters' [subscribers](helix::subscriptions::BroadcasterSubscription)
     ///
-    /// Make sure to limit the data here using [`try_take_while`](futures::stream::TryStreamExt::try_take_while), otherwise this will never end on recurring scheduled streams.
+    /// Only affiliates and partners can have subscribers, for other broadcasters the stream fails with
+    /// [`SubscriptionsError::NotAffiliateOrPartner`](helix::subscriptions::SubscriptionsError::NotAffiliateOrPartner).
+    /// Check [`can_have_subscriptions`](HelixClient::can_have_subscriptions) to skip the request.
     ///
     /// # Examples
     ///
@@ -797,78 +1176,156 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// use twitch_api::helix;
     /// use futures::TryStreamExt;
     ///
-    /// let schedule: Vec<helix::schedule::Segment> = client
-    ///     .get_channel_schedule("twitchdev", &token)
-    ///     .try_take_while(|s| {
-    ///         futures::future::ready(Ok(!s.start_time.as_str().starts_with("2021-10")))
-    ///     })
-    ///     .try_collect()
-    ///     .await?;
+    /// let subs: Vec<helix::subscriptions::BroadcasterSubscription> = client
+    ///     .get_broadcaster_subscriptions(&token)
+    ///     .try_collect().await?;
     /// # Ok(()) }
     /// ```
-    pub fn get_channel_schedule<'b: 'client, T>(
+    pub fn get_broadcaster_subscriptions<T>(
         &'client self,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + 'b,
         token: &'client T,
-    ) -> impl futures::Stream<Item = Result<helix::schedule::Segment, ClientError<C>>>
-           + Send
+    ) -> impl futures::Stream<
+        Item = Result<
+            helix::subscriptions::BroadcasterSubscription,
+            ClientExtError<C, helix::subscriptions::SubscriptionsError>,
+        >,
+    > + Send
            + Unpin
            + 'client
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::schedule::GetChannelStreamScheduleRequest::broadcaster_id(broadcaster_id);
-
-        make_stream(req, token, self, |broadcasts| broadcasts.segments.into())
+        let user_id = match token
+            .user_id()
+            .ok_or_else(|| ClientRequestError::Custom("no user_id found on token".into()))
+        {
+            Ok(t) => t,
+            Err(e) => {
+                return futures::stream::once(async { Err(ClientExtError::ClientError(e)) }).boxed()
+            }
+        };
+        // If this fails to compile due to missing implementation, make sure this crate and `twitch_oauth2` use the same version of `twitch_types`
+        let req = helix::subscriptions::GetBroadcasterSubscriptionsRequest::broadcaster_id(user_id);
+        make_list_stream(req, token, self)
+            .map_err(
+                |e| match helix::subscriptions::SubscriptionsError::from_client_error(e) {
+                    Ok(e) => ClientExtError::Other(e),
+                    Err(e) => ClientExtError::ClientError(e),
+                },
+            )
+            .boxed()
     }
 
-    /// Get all global emotes
-    pub async fn get_global_emotes<T>(
-        &'client self,
-        token: &T,
-    ) -> Result<Vec<helix::chat::GlobalEmote>, ClientError<C>>
-    where
-        T: TwitchToken + Send + Sync + ?Sized,
-    {
-        let req = helix::chat::GetGlobalEmotesRequest::new();
-        Ok(self.req_get(req, token).await?.data)
+    /// Check if a user is an affiliate or partner, which is required to have subscriptions
+    ///
+    /// [`User::broadcaster_type`](helix::users::User::broadcaster_type) is used, no request is made.
+    /// Broadcaster types not known to this library are assumed to not have subscriptions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    /// use futures::TryStreamExt;
+    ///
+    /// let user = client.get_user_from_id("1234", &token).await?.expect("no user");
+    /// if client.can_have_subscriptions(&user) {
+    ///     let subs: Vec<helix::subscriptions::BroadcasterSubscription> = client
+    ///         .get_broadcaster_subscriptions(&token)
+    ///         .try_collect()
+    ///         .await?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn can_have_subscriptions(&self, user: &helix::users::User) -> bool {
+        matches!(
+            user.broadcaster_type,
+            Some(helix::users::BroadcasterType::Affiliate | helix::users::BroadcasterType::Partner)
+        )
     }
 
-    /// Get channel emotes in channel with user id
-    pub async fn get_channel_emotes_from_id<'b, T>(
+    /// Get a [breakdown](helix::subscriptions::SubscriptionBreakdown) of the authenticated broadcasters' subscriptions by tier and gifter
+    ///
+    /// All pages of [Get Broadcaster Subscriptions](helix::subscriptions::GetBroadcasterSubscriptionsRequest) are requested,
+    /// only the counters are kept in memory.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    ///
+    /// let breakdown = client.get_subscription_breakdown(&token).await?;
+    /// println!("{} of {} subs are gifted", breakdown.gifted, breakdown.total);
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_subscription_breakdown<T>(
         &'client self,
-        user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        token: &T,
-    ) -> Result<Vec<helix::chat::ChannelEmote>, ClientError<C>>
+        token: &'client T,
+    ) -> Result<helix::subscriptions::SubscriptionBreakdown, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::chat::GetChannelEmotesRequest::broadcaster_id(user_id);
-        Ok(self.req_get(req, token).await?.data)
-    }
+        let user_id = token
+            .user_id()
+            .ok_or_else(|| ClientRequestError::Custom("no user_id found on token".into()))?;
+        let req = helix::subscriptions::GetBroadcasterSubscriptionsRequest::broadcaster_id(user_id)
+            .first(100);
 
-    /// Get channel emotes in channel with user login
-    pub async fn get_channel_emotes_from_login<T>(
-        &'client self,
-        login: impl types::IntoCow<'client, types::UserNameRef> + Send + 'client,
-        token: &T,
-    ) -> Result<Option<Vec<helix::chat::ChannelEmote>>, ClientError<C>>
-    where
-        T: TwitchToken + Send + Sync + ?Sized,
-    {
-        if let Some(user) = self
-            .get_user_from_login(login.into_cow().as_ref(), token)
-            .await?
-        {
-            self.get_channel_emotes_from_id(&user.id, token)
-                .await
-                .map(Some)
-        } else {
-            Ok(None)
+        let mut breakdown = helix::subscriptions::SubscriptionBreakdown::default();
+        let mut gifters = std::collections::HashMap::<types::UserId, u64>::new();
+        let mut counted = 0;
+
+        let response = self.req_get(req, token).await?;
+        let total = response.total;
+        breakdown.points = response.points().ok();
+        let mut page = Some(response);
+        while let Some(response) = page {
+            for sub in &response.data {
+                counted += 1;
+                let tier = match &sub.tier {
+                    types::SubscriptionTier::Prime => types::SubscriptionTier::Tier1,
+                    tier => tier.clone(),
+                };
+                *breakdown.by_tier.entry(tier).or_default() += 1;
+                if sub.is_gift {
+                    breakdown.gifted += 1;
+                    if let Some(gifter_id) = &sub.gifter_id {
+                        *gifters.entry(gifter_id.clone()).or_default() += 1;
+                    }
+                }
+            }
+            page = response.get_next(self, token).await?;
         }
+
+        breakdown.total = total
+            .and_then(|total| std::convert::TryFrom::try_from(total).ok())
+            .unwrap_or(counted);
+        breakdown.top_gifters = gifters.into_iter().collect();
+        breakdown.top_gifters.sort_by(|(a_id, a), (b_id, b)| {
+            b.cmp(a).then_with(|| a_id.as_str().cmp(b_id.as_str()))
+        });
+        Ok(breakdown)
     }
 
-    /// Get emotes in emote sets
+    /// Get the [subscriptions](helix::subscriptions::BroadcasterSubscription) of specific users to the authenticated broadcaster
+    ///
+    /// Users that are not subscribed are not in the returned map. The user ids are requested in chunks of 100.
+    ///
+    /// Requires token with scope [`channel:read:subscriptions`](twitch_oauth2::Scope::ChannelReadSubscriptions).
+    ///
+    /// # Notes
+    ///
+    /// Twitch reports the total amount of subscriptions of the broadcaster in these responses, not the amount of requested users that are subscribed,
+    /// use [`get_subscription_breakdown`](HelixClient::get_subscription_breakdown) to get the total.
     ///
     /// # Examples
     ///
@@ -878,170 +1335,292 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
     /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
     /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
-    /// use twitch_api::{types, helix};
-    /// use futures::TryStreamExt;
+    /// use twitch_api::{helix, types};
     ///
-    /// let emotes: Vec<helix::chat::get_emote_sets::Emote> = client
-    ///     .get_emote_sets(&["0"][..].into(), &token).try_collect().await?;
+    /// let subs = client
+    ///     .get_subscribed_users(&["1234", "5678"][..].into(), &token)
+    ///     .await?;
+    /// if let Some(sub) = subs.get(types::UserIdRef::from_static("1234")) {
+    ///     println!("subscribed with {:?}", sub.tier);
+    /// }
     /// # Ok(()) }
     /// ```
-    pub fn get_emote_sets<T>(
+    pub async fn get_subscribed_users<T>(
         &'client self,
-        emote_sets: &'client types::Collection<'client, types::EmoteSetId>,
-        token: &'client T,
-    ) -> impl futures::Stream<Item = Result<helix::chat::get_emote_sets::Emote, ClientError<C>>>
-           + Send
-           + Unpin
-           + 'client
+        user_ids: &types::Collection<'_, types::UserId>,
+        token: &T,
+    ) -> Result<
+        std::collections::HashMap<types::UserId, helix::subscriptions::BroadcasterSubscription>,
+        ClientError<C>,
+    >
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        futures::stream::iter(emote_sets.chunks(25).collect::<Vec<_>>())
-            .map(move |c| {
-                let req = helix::chat::GetEmoteSetsRequest::emote_set_ids(c);
-                futures::stream::once(self.req_get(req, token)).boxed()
-            })
-            .flatten_unordered(None)
-            .map_ok(|r| futures::stream::iter(r.data.into_iter().map(Ok)))
-            .try_flatten_unordered(None)
+        let broadcaster_id = token
+            .user_id()
+            .ok_or_else(|| ClientRequestError::Custom("no user_id found on token".into()))?;
+        let mut stream = chunked_request(
+            user_ids.iter(),
+            helix::subscriptions::get_broadcaster_subscriptions::MAX_USER_IDS,
+            |sub: &helix::subscriptions::BroadcasterSubscription| &*sub.user_id,
+            move |chunk| async move {
+                let req = helix::subscriptions::GetBroadcasterSubscriptionsRequest::broadcaster_id(
+                    broadcaster_id,
+                )
+                .subscriber(chunk)
+                .first(100);
+                let mut subs = vec![];
+                let mut page = Some(self.req_get(req, token).await?);
+                while let Some(response) = page {
+                    subs.extend(response.data.iter().cloned());
+                    page = response.get_next(self, token).await?;
+                }
+                Ok(subs)
+            },
+        );
+        let mut subs = std::collections::HashMap::with_capacity(user_ids.len());
+        while let Some(sub) = stream.try_next().await? {
+            subs.entry(sub.user_id.clone()).or_insert(sub);
+        }
+        Ok(subs)
     }
 
-    /// Get a broadcaster's chat settings
-    pub async fn get_chat_settings<'b, T>(
+    /// Get a users, with login, follow count
+    ///
+    /// The login is normalized like in [`get_user_from_login`](HelixClient::get_user_from_login).
+    ///
+    /// Returns [`None`](Option::None) if the user doesn't exist, see [`get_total_followers_from_id`](HelixClient::get_total_followers_from_id).
+    pub async fn get_total_followers_from_login<T>(
         &'client self,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        moderator_id: impl Into<Option<&'b types::UserIdRef>> + Send + 'b,
+        login: impl AsRef<str> + Send,
         token: &T,
-    ) -> Result<helix::chat::ChatSettings, ClientError<C>>
+    ) -> Result<Option<i64>, ClientExtError<C, FollowersFromLoginError>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let mut req = helix::chat::GetChatSettingsRequest::broadcaster_id(broadcaster_id);
-        if let Some(moderator_id) = moderator_id.into() {
-            req = req.moderator_id(moderator_id);
+        if !can_read_follower_total(token) {
+            return Err(
+                FollowersFromLoginError::from(helix::channels::FollowerCountUnavailable).into(),
+            );
+        }
+        let id = match self.resolve_user_id(login, token).await {
+            Ok(Some(id)) => id,
+            Ok(None) => return Ok(None),
+            Err(ClientExtError::ClientError(e)) => return Err(ClientExtError::ClientError(e)),
+            Err(ClientExtError::Other(e)) => return Err(ClientExtError::Other(e.into())),
+        };
+        match self.get_total_followers_from_id(&id, token).await {
+            Ok(total) => Ok(total),
+            Err(ClientExtError::ClientError(e)) => Err(ClientExtError::ClientError(e)),
+            Err(ClientExtError::Other(e)) => Err(ClientExtError::Other(e.into())),
         }
-        Ok(self.req_get(req, token).await?.data)
     }
 
-    /// Send a chat announcement
-    pub async fn send_chat_announcement<'b, T, E>(
+    /// Get a users, with login, follow count, without normalizing the login
+    ///
+    /// Returns [`None`](Option::None) if the user doesn't exist, see [`get_total_followers_from_id`](HelixClient::get_total_followers_from_id).
+    pub async fn get_total_followers_from_login_raw<'b, T>(
         &'client self,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        message: impl Into<&'b str> + Send,
-        color: impl std::convert::TryInto<helix::chat::AnnouncementColor, Error = E> + Send,
+        login: impl types::IntoCow<'b, types::UserNameRef> + Send + 'b,
         token: &T,
-    ) -> Result<helix::chat::SendChatAnnouncementResponse, ClientExtError<C, E>>
+    ) -> Result<Option<i64>, ClientExtError<C, helix::channels::FollowerCountUnavailable>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::chat::SendChatAnnouncementRequest::new(broadcaster_id, moderator_id);
-        let body = helix::chat::SendChatAnnouncementBody::new(message.into(), color)?;
-        Ok(self
-            .req_post(req, body, token)
+        if !can_read_follower_total(token) {
+            return Err(helix::channels::FollowerCountUnavailable.into());
+        }
+        match self
+            .resolve_user_id_raw(&login.into_cow(), token)
             .await
             .map_err(ClientExtError::ClientError)?
-            .data)
+        {
+            Some(id) => self.get_total_followers_from_id(&id, token).await,
+            None => Ok(None),
+        }
     }
 
-    /// Delete a specific chat message
-    pub async fn delete_chat_message<'b, T>(
+    /// Get a broadcasters follow count
+    ///
+    /// # Notes
+    ///
+    /// Needs a user token, the total is returned without the scope `moderator:read:followers`.
+    pub async fn get_total_channel_followers<'b, T>(
         &'client self,
         broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        message_id: impl types::IntoCow<'b, types::MsgIdRef> + Send + 'b,
         token: &T,
-    ) -> Result<helix::moderation::DeleteChatMessagesResponse, ClientError<C>>
+    ) -> Result<i64, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::moderation::DeleteChatMessagesRequest::new(broadcaster_id, moderator_id)
-            .message_id(message_id);
-        Ok(self.req_delete(req, token).await?.data)
+        let resp = self
+            .req_get(
+                helix::channels::GetChannelFollowersRequest::broadcaster_id(broadcaster_id),
+                token,
+            )
+            .await?;
+
+        Ok(resp.total.unwrap_or(0))
     }
 
-    /// Delete all chat messages in a broadcasters chat room
-    pub async fn delete_all_chat_message<'b, T>(
+    /// Get when a user started following a broadcaster
+    ///
+    /// Returns [`None`](Option::None) if the user is not following the broadcaster.
+    ///
+    /// # Notes
+    ///
+    /// You need to have the scope `moderator:read:followers` and be a moderator of the channel if the token is not the broadcasters own token.
+    /// Tokens without the scope fail with [`FollowerListUnavailable`](helix::channels::FollowerListUnavailable), without making a request.
+    /// Twitch returns no followers to a user that isn't a moderator of the channel, so this returns [`None`](Option::None) for them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// if let Some(followed_at) = client.get_follower_since("1234", "4321", &token).await? {
+    ///     println!("following since {followed_at}");
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_follower_since<'b, T>(
         &'client self,
         broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
         token: &T,
-    ) -> Result<helix::moderation::DeleteChatMessagesResponse, ClientError<C>>
+    ) -> Result<Option<types::Timestamp>, ClientExtError<C, helix::channels::FollowerListUnavailable>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::moderation::DeleteChatMessagesRequest::new(broadcaster_id, moderator_id);
-        Ok(self.req_delete(req, token).await?.data)
+        if !can_read_followers(token) {
+            return Err(helix::channels::FollowerListUnavailable.into());
+        }
+        let resp = match self
+            .req_get(
+                helix::channels::GetChannelFollowersRequest::broadcaster_id(broadcaster_id)
+                    .user_id(user_id),
+                token,
+            )
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) if is_unauthorized(&e) => {
+                return Err(helix::channels::FollowerListUnavailable.into())
+            }
+            Err(e) => return Err(ClientExtError::ClientError(e)),
+        };
+
+        Ok(resp
+            .data
+            .into_iter()
+            .next()
+            .map(|follower| follower.followed_at))
     }
 
-    /// Start a raid
-    pub async fn start_a_raid<'b, T>(
+    /// Check if a user has been following a broadcaster for at least `min`
+    ///
+    /// A user that is not following the broadcaster is not a follower for any duration, so this returns `false` instead of an error.
+    /// See [`get_follower_since`](HelixClient::get_follower_since) for the required scope, a token without it fails with [`FollowerListUnavailable`](helix::channels::FollowerListUnavailable).
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// let week = std::time::Duration::from_secs(7 * 24 * 60 * 60);
+    /// if !client.is_follower_for("1234", "4321", week, &token).await? {
+    ///     println!("links are only allowed for followers of a week or more");
+    /// }
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "time")]
+    pub async fn is_follower_for<'b, T>(
         &'client self,
-        from_broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        to_broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        min: std::time::Duration,
         token: &T,
-    ) -> Result<helix::raids::StartARaidResponse, ClientError<C>>
+    ) -> Result<bool, ClientExtError<C, helix::channels::FollowerListUnavailable>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::raids::StartARaidRequest::new(from_broadcaster_id, to_broadcaster_id);
-        Ok(self.req_post(req, helix::EmptyBody, token).await?.data)
+        let Some(followed_at) = self
+            .get_follower_since(broadcaster_id, user_id, token)
+            .await?
+        else {
+            return Ok(false);
+        };
+        // a follow in the future, e.g. because of clock skew, has just happened
+        let following_for: std::time::Duration = std::convert::TryFrom::try_from(
+            types::Timestamp::now().to_utc() - followed_at.to_utc(),
+        )
+        .unwrap_or_default();
+        Ok(following_for >= min)
     }
 
-    /// Cancel a raid
-    pub async fn cancel_a_raid<'b, T>(
-        &'client self,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        token: &T,
-    ) -> Result<helix::raids::CancelARaidResponse, ClientError<C>>
-    where
-        T: TwitchToken + Send + Sync + ?Sized,
-    {
-        let req = helix::raids::CancelARaidRequest::broadcaster_id(broadcaster_id);
-        Ok(self.req_delete(req, token).await?.data)
-    }
-
-    /// Update a user's chat color
-    pub async fn update_user_chat_color<'b, T>(
-        &'client self,
-        user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        color: impl Into<types::NamedUserColor<'b>> + Send + 'b,
-        token: &T,
-    ) -> Result<helix::chat::UpdateUserChatColorResponse, ClientError<C>>
-    where
-        T: TwitchToken + Send + Sync + ?Sized,
-    {
-        let req = helix::chat::UpdateUserChatColorRequest {
-            user_id: user_id.into_cow(),
-            color: color.into(),
-        };
-
-        Ok(self.req_put(req, helix::EmptyBody, token).await?.data)
-    }
-
-    /// Get a user's chat color
+    /// Get the followers of a broadcaster that followed at or after `since`, newest first
+    ///
+    /// The stream relies on Twitch returning followers ordered by [`followed_at`](helix::channels::Follower::followed_at), newest first,
+    /// and ends at the first follower that followed before `since`, without requesting more pages.
+    ///
+    /// # Notes
+    ///
+    /// You need to have the scope `moderator:read:followers` and be a moderator of the channel if the token is not the broadcasters own token
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::prelude::*;
+    /// use std::convert::TryFrom;
     ///
-    /// [`None`](Option::None) is returned if the user never set their color in the settings.
-    pub async fn get_user_chat_color<T>(
+    /// let new_followers: Vec<helix::channels::FollowerSince> = client
+    ///     .get_followers_since("1234", types::Timestamp::try_from("2023-03-01T00:00:00Z")?, &token)
+    ///     .try_collect()
+    ///     .await?;
+    /// println!("{} new followers", new_followers.len());
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn get_followers_since<'b: 'client, T>(
         &'client self,
-        user_id: impl Into<&types::UserIdRef> + Send,
-        token: &T,
-    ) -> Result<Option<helix::chat::UserChatColor>, ClientError<C>>
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + 'b,
+        since: types::Timestamp,
+        token: &'client T,
+    ) -> impl futures::Stream<Item = Result<helix::channels::FollowerSince, ClientError<C>>>
+           + Send
+           + Unpin
+           + 'client
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        Ok(self
-            .req_get(
-                helix::chat::GetUserChatColorRequest::user_ids(&user_id.into()),
-                token,
-            )
-            .await?
-            .first())
+        let since = since.to_utc();
+        let req =
+            helix::channels::GetChannelFollowersRequest::broadcaster_id(broadcaster_id).first(100);
+        make_list_stream(req, token, self)
+            .try_take_while(move |follower| {
+                futures::future::ready(Ok(follower.followed_at.to_utc() >= since))
+            })
+            .enumerate()
+            .map(|(i, follower)| {
+                follower.map(|follower| helix::channels::FollowerSince {
+                    follower,
+                    new_followers: i + 1,
+                })
+            })
     }
 
-    /// Get multiple users' chat colors
-    ///
-    /// Users that never set their color in the settings are not returned.
+    /// Get users followed channels
     ///
     /// # Examples
     ///
@@ -1051,142 +1630,225 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
     /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
     /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
-    /// use twitch_api::{types, helix};
+    /// use twitch_api::helix;
     /// use futures::TryStreamExt;
     ///
-    /// let colors: Vec<helix::chat::UserChatColor> = client
-    ///     .get_users_chat_colors(&["1234"][..].into(), &token).try_collect().await?;
+    /// let schedule: Vec<helix::channels::FollowedBroadcaster> = client
+    ///     .get_followed_channels("1234", &token)
+    ///     .try_collect()
+    ///     .await?;
     /// # Ok(()) }
     /// ```
-    pub fn get_users_chat_colors<T>(
+    pub fn get_followed_channels<'b: 'client, T>(
         &'client self,
-        user_ids: &'client types::Collection<'client, types::UserId>,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + 'b,
         token: &'client T,
-    ) -> impl futures::Stream<Item = Result<helix::chat::UserChatColor, ClientError<C>>>
+    ) -> impl futures::Stream<Item = Result<helix::channels::FollowedBroadcaster, ClientError<C>>>
            + Send
            + Unpin
            + 'client
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        futures::stream::iter(user_ids.chunks(100))
-            .map(move |c| {
-                let req = helix::chat::GetUserChatColorRequest::user_ids(c);
-                futures::stream::once(self.req_get(req, token)).boxed()
-            })
-            .flatten_unordered(None)
-            .map_ok(move |o| futures::stream::iter(o.data.into_iter().map(Ok)))
-            .try_flatten_unordered(None)
+        let req =
+            helix::channels::get_followed_channels::GetFollowedChannels::user_id(broadcaster_id);
+        make_stream(req, token, self, |broadcasts| broadcasts.into())
     }
 
-    /// Add a channel moderator
-    pub async fn add_channel_moderator<'b, T>(
+    /// Get a users, with id, follow count
+    ///
+    /// Returns [`None`](Option::None) if the user doesn't exist.
+    ///
+    /// # Notes
+    ///
+    /// The total is read from the first page of [Get Channel Followers](helix::channels::get_channel_followers), which needs a user token.
+    /// No scope is needed for the total, the user doesn't have to have [`moderator:read:followers`](twitch_oauth2::Scope::ModeratorReadFollowers) or be the broadcaster or one of their moderators.
+    /// Other tokens fail with [`FollowerCountUnavailable`](helix::channels::FollowerCountUnavailable), without making a request for an app access token.
+    ///
+    /// Twitch responds the same for users without followers and users that don't exist, so a total of zero is checked with an extra request to [Get Users](helix::users::get_users).
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    ///
+    /// match client.get_total_followers_from_id("1234", &token).await {
+    ///     Ok(Some(total)) => println!("{total} followers"),
+    ///     Ok(None) => println!("no such user"),
+    ///     Err(helix::ClientExtError::Other(e)) => println!("{e}"),
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_total_followers_from_id<'b, T>(
         &'client self,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        to_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
         token: &T,
-    ) -> Result<helix::moderation::AddChannelModeratorResponse, ClientError<C>>
+    ) -> Result<Option<i64>, ClientExtError<C, helix::channels::FollowerCountUnavailable>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::moderation::AddChannelModeratorRequest {
-            broadcaster_id: broadcaster_id.into_cow(),
-            moderator_id: moderator_id.into_cow(),
+        if !can_read_follower_total(token) {
+            return Err(helix::channels::FollowerCountUnavailable.into());
+        }
+        let to_id = to_id.into_cow();
+        let total = match self
+            .req_get(
+                helix::channels::GetChannelFollowersRequest::broadcaster_id(&*to_id).first(1),
+                token,
+            )
+            .await
+        {
+            Ok(resp) => resp.total.unwrap_or(0),
+            Err(e) if is_unauthorized(&e) => {
+                return Err(helix::channels::FollowerCountUnavailable.into())
+            }
+            Err(e) => return Err(ClientExtError::ClientError(e)),
         };
-
-        Ok(self.req_post(req, helix::EmptyBody, token).await?.data)
+        if total == 0
+            && self
+                .get_user_from_id(&*to_id, token)
+                .await
+                .map_err(ClientExtError::ClientError)?
+                .is_none()
+        {
+            return Ok(None);
+        }
+        Ok(Some(total))
     }
 
-    /// Remove a channel moderator
-    pub async fn remove_channel_moderator<'b, T>(
+    /// Get a [Game](helix::games::Game) by ID
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    pub async fn get_game_by_id<T>(
         &'client self,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        id: impl Into<&types::CategoryIdRef> + Send,
         token: &T,
-    ) -> Result<helix::moderation::RemoveChannelModeratorResponse, ClientError<C>>
+    ) -> Result<Option<helix::games::Game>, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::moderation::RemoveChannelModeratorRequest {
-            broadcaster_id: broadcaster_id.into_cow(),
-            moderator_id: moderator_id.into_cow(),
-        };
-
-        Ok(self.req_delete(req, token).await?.data)
+        let ids: &[_] = &[id.into()];
+        self.req_get(helix::games::GetGamesRequest::ids(ids), token)
+            .await?
+            .into_optional()
+            .map_err(unexpected_response)
     }
 
-    /// Get channel VIPs
-    pub fn get_vips_in_channel<'b: 'client, T>(
+    /// Get a [Game](helix::games::Game) by ID, failing with [`NotFoundError`] if there is no such game
+    ///
+    /// Like [`get_game_by_id`](HelixClient::get_game_by_id), for when a missing game is an error.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    pub async fn get_game_by_id_required<T>(
         &'client self,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + 'b,
-        token: &'client T,
-    ) -> impl futures::Stream<Item = Result<helix::channels::Vip, ClientError<C>>> + Send + Unpin + 'client
+        id: impl Into<&types::CategoryIdRef> + Send,
+        token: &T,
+    ) -> Result<helix::games::Game, ClientExtError<C, NotFoundError>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::channels::GetVipsRequest::broadcaster_id(broadcaster_id);
-
-        make_stream(req, token, self, std::collections::VecDeque::from)
+        let id = id.into();
+        required::<_, _, NotFoundError, _>(
+            self.get_game_by_id(id, token)
+                .await
+                .map_err(ClientExtError::ClientError),
+            "game",
+            id.as_str(),
+        )
     }
 
-    /// Add a channel vip
-    pub async fn add_channel_vip<'b, T>(
+    /// Get games by ID.
+    ///
+    /// Duplicated ids are only requested once, the games are returned in the order of `ids`.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::{types, helix};
+    /// use futures::TryStreamExt;
+    ///
+    /// let games: Vec<helix::games::Game> = client
+    ///     .get_games_by_id(&["509658", "32982", "27471"][..].into(), &token).try_collect().await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_games_by_id<T>(
         &'client self,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        token: &T,
-    ) -> Result<helix::channels::AddChannelVipResponse, ClientError<C>>
+        ids: &'client types::Collection<'client, types::CategoryId>,
+        token: &'client T,
+    ) -> impl futures::Stream<Item = Result<helix::games::Game, ClientError<C>>> + Send + Unpin + 'client
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::channels::AddChannelVipRequest {
-            broadcaster_id: broadcaster_id.into_cow(),
-            user_id: user_id.into_cow(),
-        };
-
-        Ok(self.req_post(req, helix::EmptyBody, token).await?.data)
+        chunked_request(
+            ids.iter(),
+            100,
+            |game: &helix::games::Game| &*game.id,
+            move |c| async move {
+                let req = helix::games::GetGamesRequest::ids(c);
+                Ok(self.req_get(req, token).await?.data)
+            },
+        )
     }
 
-    /// Remove a channel vip
-    pub async fn remove_channel_vip<'b, T>(
+    /// Block a user
+    pub async fn block_user<'b, T>(
         &'client self,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        target_user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
         token: &T,
-    ) -> Result<helix::channels::RemoveChannelVipResponse, ClientError<C>>
+    ) -> Result<helix::users::BlockUser, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::channels::RemoveChannelVipRequest {
-            broadcaster_id: broadcaster_id.into_cow(),
-            user_id: user_id.into_cow(),
-        };
-
-        Ok(self.req_delete(req, token).await?.data)
+        Ok(self
+            .req_put(
+                helix::users::BlockUserRequest::block_user(target_user_id),
+                helix::EmptyBody,
+                token,
+            )
+            .await?
+            .data)
     }
 
-    /// Send a whisper
-    pub async fn send_whisper<'b, T>(
+    /// Unblock a user
+    pub async fn unblock_user<'b, T>(
         &'client self,
-        from: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        to: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        message: impl Into<&'b str> + Send,
+        target_user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
         token: &T,
-    ) -> Result<helix::whispers::SendWhisperResponse, ClientError<C>>
+    ) -> Result<helix::users::UnblockUser, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
         Ok(self
-            .req_post(
-                helix::whispers::SendWhisperRequest::new(from, to),
-                helix::whispers::SendWhisperBody::new(message.into()),
+            .req_delete(
+                helix::users::UnblockUserRequest::unblock_user(target_user_id),
                 token,
             )
             .await?
             .data)
     }
 
-    /// Get all custom rewards
+    // FIXME: Example should use https://github.com/twitch-rs/twitch_api/issues/162
+    /// Get all scheduled streams in a channel.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// # Notes
+    ///
+    /// Make sure to limit the data here using [`try_take_while`](futures::stream::TryStreamExt::try_take_while), otherwise this will never end on recurring scheduled streams.
+    /// [`get_channel_schedule_between`](HelixClient::get_channel_schedule_between) limits the segments to a time window.
     ///
     /// # Examples
     ///
@@ -1197,35 +1859,39 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
     /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
     /// use twitch_api::helix;
+    /// use futures::TryStreamExt;
     ///
-    /// let rewards: Vec<helix::points::CustomReward> = client
-    ///     .get_all_custom_rewards("1234", true, &token)
+    /// let schedule: Vec<helix::schedule::Segment> = client
+    ///     .get_channel_schedule("twitchdev", &token)
+    ///     .try_take_while(|s| {
+    ///         futures::future::ready(Ok(!s.start_time.as_str().starts_with("2021-10")))
+    ///     })
+    ///     .try_collect()
     ///     .await?;
     /// # Ok(()) }
     /// ```
-    pub async fn get_all_custom_rewards<'b, T>(
+    pub fn get_channel_schedule<'b: 'client, T>(
         &'client self,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        only_managable_rewards: bool,
-        token: &T,
-    ) -> Result<Vec<helix::points::CustomReward>, ClientError<C>>
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + 'b,
+        token: &'client T,
+    ) -> impl futures::Stream<Item = Result<helix::schedule::Segment, ClientError<C>>>
+           + Send
+           + Unpin
+           + 'client
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        self.get_custom_rewards(
-            broadcaster_id,
-            only_managable_rewards,
-            &types::Collection::EMPTY,
-            token,
-        )
-        .await
+        let req = helix::schedule::GetChannelStreamScheduleRequest::broadcaster_id(broadcaster_id);
+
+        make_stream(req, token, self, |broadcasts| broadcasts.segments.into())
     }
 
-    /// Get specific custom rewards, see [`get_all_custom_rewards`](HelixClient::get_all_custom_rewards) to get all rewards
+    /// Get the scheduled streams in a channel starting from `start`, up to but not including `end`
     ///
-    /// # Notes
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
     ///
-    /// Takes a max of 50 ids
+    /// Twitch keeps generating segments of recurring streams, so [`get_channel_schedule`](HelixClient::get_channel_schedule) never ends on its own.
+    /// Twitch returns segments ordered by their start time, the stream ends at the first segment starting at `end` or later, without fetching more pages.
     ///
     /// # Examples
     ///
@@ -1235,83 +1901,138 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
     /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
     /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
-    /// use twitch_api::helix;
+    /// use twitch_api::prelude::*;
+    /// use std::convert::TryFrom;
     ///
-    /// let rewards: Vec<helix::points::CustomReward> = client
-    ///     .get_custom_rewards("1234", true, &["8969ec47-55b6-4559-a8fe-3f1fc4e6fe58"][..].into(), &token)
+    /// let october: Vec<helix::schedule::Segment> = client
+    ///     .get_channel_schedule_between(
+    ///         "141981764",
+    ///         types::Timestamp::try_from("2021-10-01T00:00:00Z")?,
+    ///         types::Timestamp::try_from("2021-11-01T00:00:00Z")?,
+    ///         &token,
+    ///     )
+    ///     .try_collect()
     ///     .await?;
     /// # Ok(()) }
     /// ```
-    // XXX: This function is useless as a stream, since you can never have more than 50 rewards on a channel
-    pub async fn get_custom_rewards<'b, T>(
+    #[cfg(feature = "time")]
+    pub fn get_channel_schedule_between<'b: 'client, T>(
         &'client self,
-        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
-        only_managable_rewards: bool,
-        ids: &'b types::Collection<'b, types::RewardId>,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + 'b,
+        start: types::Timestamp,
+        end: types::Timestamp,
         token: &'client T,
-    ) -> Result<Vec<helix::points::CustomReward>, ClientError<C>>
+    ) -> impl futures::Stream<Item = Result<helix::schedule::Segment, ClientError<C>>>
+           + Send
+           + Unpin
+           + 'client
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        Ok(self
-            .req_get(
-                helix::points::GetCustomRewardRequest::broadcaster_id(broadcaster_id)
-                    .only_manageable_rewards(only_managable_rewards)
-                    .ids(ids.clone()),
-                token,
-            )
-            .await?
-            .data)
+        let (start_utc, end_utc) = (start.to_utc(), end.to_utc());
+        let req = helix::schedule::GetChannelStreamScheduleRequest::broadcaster_id(broadcaster_id)
+            .start_time(start);
+
+        make_stream(req, token, self, |broadcasts| broadcasts.segments.into())
+            .try_take_while(move |segment| {
+                futures::future::ready(Ok(segment.start_time.to_utc() < end_utc))
+            })
+            // a segment that is in progress at `start` can be returned
+            .try_filter(move |segment| {
+                futures::future::ready(segment.start_time.to_utc() >= start_utc)
+            })
     }
 
-    #[cfg(feature = "eventsub")]
-    /// Create an [EventSub](crate::eventsub) subscription
-    pub async fn create_eventsub_subscription<T, E: crate::eventsub::EventSubscription + Send>(
+    /// Get the broadcaster and vacation information of a broadcasters schedule
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// Only fetches a single segment, use [`get_channel_schedule`](HelixClient::get_channel_schedule) to get the segments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    ///
+    /// let meta = client.get_channel_schedule_meta("141981764", &token).await?;
+    /// if let Some(vacation) = meta.vacation {
+    ///     println!("{} is on vacation until {}", meta.broadcaster_name, vacation.end_time);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_channel_schedule_meta<'b, T>(
         &'client self,
-        subscription: E,
-        transport: crate::eventsub::Transport,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
         token: &T,
-    ) -> Result<helix::eventsub::CreateEventSubSubscription<E>, ClientError<C>>
+    ) -> Result<helix::schedule::ScheduleMeta, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        Ok(self
-            .req_post(
-                helix::eventsub::CreateEventSubSubscriptionRequest::new(),
-                helix::eventsub::CreateEventSubSubscriptionBody::new(subscription, transport),
-                token,
-            )
-            .await?
-            .data)
+        let req = helix::schedule::GetChannelStreamScheduleRequest::broadcaster_id(broadcaster_id)
+            .first(1);
+
+        Ok(self.req_get(req, token).await?.data.into())
     }
 
-    #[cfg(feature = "eventsub")]
-    /// Delete an [EventSub](crate::eventsub) subscription
-    pub async fn delete_eventsub_subscription<'b, T>(
+    /// Get the next scheduled stream of a broadcaster
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// Only fetches a single page of the schedule starting from now and returns the first segment that is not [canceled](helix::schedule::Segment::is_canceled).
+    /// Returns [`None`] if the broadcaster has no schedule.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    ///
+    /// if let Some(segment) = client.get_next_scheduled_stream("141981764", &token).await? {
+    ///     println!("next stream: {} at {}", segment.title, segment.start_time);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_next_scheduled_stream<'b, T>(
         &'client self,
-        id: impl types::IntoCow<'b, types::EventSubIdRef> + Send + 'b,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
         token: &T,
-    ) -> Result<helix::eventsub::DeleteEventSubSubscription, ClientError<C>>
+    ) -> Result<Option<helix::schedule::Segment>, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        Ok(self
-            .req_delete(
-                helix::eventsub::DeleteEventSubSubscriptionRequest::id(id),
-                token,
-            )
-            .await?
-            .data)
+        let req = helix::schedule::GetChannelStreamScheduleRequest::broadcaster_id(broadcaster_id)
+            .first(10);
+
+        match self.req_get(req, token).await {
+            Ok(response) => Ok(response
+                .data
+                .segments
+                .into_iter()
+                .find(|segment| !segment.is_canceled())),
+            Err(ClientRequestError::HelixRequestGetError(helix::HelixRequestGetError::Error {
+                status: http::StatusCode::NOT_FOUND,
+                ..
+            })) => Ok(None),
+            Err(e) => Err(e),
+        }
     }
 
-    #[cfg(feature = "eventsub")]
-    /// Get all [EventSub](crate::eventsub) subscriptions for this [Client](twitch_oauth2::TwitchToken)
+    /// Get the scheduled streams of several broadcasters starting from `start`, up to but not including `end`, ordered by their start time
     ///
-    /// # Notes
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
     ///
-    /// The return item is a struct [`EventSubSubscriptions`](helix::eventsub::EventSubSubscriptions)
-    /// which contains a field with all the subscriptions.
-    /// See the example for collecting all _specific_ subscriptions
+    /// The schedules are requested concurrently and limited to the window like in [`get_channel_schedule_between`](HelixClient::get_channel_schedule_between).
+    /// Each segment is returned with the id of its broadcaster, segments starting at the same time keep the order of `broadcaster_ids`.
+    /// Broadcasters without a schedule are skipped, any other error fails the call with the broadcaster it happened for.
     ///
     /// # Examples
     ///
@@ -1321,62 +2042,78 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
     /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
     /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
-    /// use twitch_api::{helix, eventsub};
-    /// use futures::{TryStreamExt, stream};
-    ///
-    /// let mut total_cost = None;
+    /// use twitch_api::prelude::*;
+    /// use std::convert::TryFrom;
     ///
-    /// let chatters: Vec<eventsub::EventSubSubscription> = client
-    ///     .get_eventsub_subscriptions(None, None, None, &token)
-    ///     .map_ok(|r| {
-    ///         total_cost = Some(r.total_cost);
-    ///         stream::iter(
-    ///             r.subscriptions
-    ///                 .into_iter()
-    ///                 .map(Ok::<_, twitch_api::helix::ClientRequestError<_>>),
-    ///         )
-    ///     })
-    ///     .try_flatten()
-    ///     .try_collect()
+    /// let week = client
+    ///     .get_merged_schedules(
+    ///         &["141981764", "12826"][..].into(),
+    ///         (
+    ///             types::Timestamp::try_from("2021-10-04T00:00:00Z")?,
+    ///             types::Timestamp::try_from("2021-10-11T00:00:00Z")?,
+    ///         ),
+    ///         &token,
+    ///     )
     ///     .await?;
+    /// for (broadcaster_id, segment) in week {
+    ///     println!("{}: {} by {}", segment.start_time, segment.title, broadcaster_id);
+    /// }
     /// # Ok(()) }
     /// ```
-    pub fn get_eventsub_subscriptions<'b: 'client, T>(
+    #[cfg(feature = "time")]
+    pub async fn get_merged_schedules<T>(
         &'client self,
-        status: impl Into<Option<crate::eventsub::Status>>,
-        event_type: impl Into<Option<crate::eventsub::EventType>>,
-        // FIXME: IntoOptionCow?
-        user_id: Option<&'b types::UserIdRef>,
+        broadcaster_ids: &'client types::Collection<'client, types::UserId>,
+        window: (types::Timestamp, types::Timestamp),
         token: &'client T,
-    ) -> impl futures::Stream<Item = Result<helix::eventsub::EventSubSubscriptions, ClientError<C>>>
-           + Send
-           + Unpin
-           + 'client
+    ) -> Result<Vec<(types::UserId, helix::schedule::Segment)>, MergedScheduleError<ClientError<C>>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::eventsub::GetEventSubSubscriptionsRequest {
-            status: status.into(),
-            type_: event_type.into(),
-            user_id: user_id.map(|c| c.as_cow()),
-            after: None,
-            first: None,
+        let (start, end) = window;
+        let broadcasters: Vec<_> = {
+            let mut seen = std::collections::HashSet::new();
+            broadcaster_ids
+                .iter()
+                .filter(|id| seen.insert(*id))
+                .collect()
         };
-
-        make_stream(req, token, self, |r| {
-            let mut vec = std::collections::VecDeque::new();
-            vec.push_front(r);
-            vec
-        })
+        let mut segments: Vec<_> = futures::stream::iter(broadcasters)
+            .map(|id| {
+                let schedule = self
+                    .get_channel_schedule_between(id, start.clone(), end.clone(), token)
+                    .try_collect::<Vec<_>>();
+                async move {
+                    match schedule.await {
+                        Ok(segments) => Ok(segments
+                            .into_iter()
+                            .map(|segment| (id.to_owned(), segment))
+                            .collect::<Vec<_>>()),
+                        Err(ClientRequestError::HelixRequestGetError(
+                            helix::HelixRequestGetError::Error {
+                                status: http::StatusCode::NOT_FOUND,
+                                ..
+                            },
+                        )) => Ok(vec![]),
+                        Err(source) => Err(MergedScheduleError {
+                            broadcaster_id: id.to_owned(),
+                            source,
+                        }),
+                    }
+                }
+            })
+            .buffered(CHUNK_CONCURRENCY)
+            .try_concat()
+            .await?;
+        segments.sort_by_key(|(_, segment)| segment.start_time.to_utc());
+        Ok(segments)
     }
 
-    #[cfg(feature = "eventsub")]
-    /// Get all [Conduits](crate::eventsub::Conduit) for the Twitch Developer Application
-    /// associated with this token
+    /// Get all global emotes, only if they changed since `validator` was returned
     ///
-    /// # Notes
+    /// See [`req_get_conditional`](HelixClient::req_get_conditional), store the emotes with the validator to not download them again on every start.
     ///
-    /// The token must be an App Access Token
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
     ///
     /// # Examples
     ///
@@ -1384,34 +2121,40 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
     /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
-    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
-    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
-    /// # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
-    /// use twitch_api::{helix, eventsub};
-    ///
-    /// let conduits = client.get_conduits(&token).await?;
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// # let (mut emotes, mut validator): (Vec<helix::chat::GlobalEmote>, helix::CacheValidator) = Default::default();
+    /// use twitch_api::helix;
     ///
+    /// if let helix::Conditional::Modified { data, validator: new } = client
+    ///     .get_global_emotes_if_modified(Some(&validator), &token)
+    ///     .await?
+    /// {
+    ///     emotes = data;
+    ///     validator = new;
+    /// }
     /// # Ok(()) }
     /// ```
-    pub async fn get_conduits<'b: 'client, T>(
+    pub async fn get_global_emotes_if_modified<T>(
         &'client self,
-        token: &'client T,
-    ) -> Result<Vec<crate::eventsub::Conduit>, ClientError<C>>
+        validator: Option<&helix::CacheValidator>,
+        token: &T,
+    ) -> Result<helix::Conditional<Vec<helix::chat::GlobalEmote>>, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        self.req_get(helix::eventsub::GetConduitsRequest {}, token)
-            .await
-            .map(|response| response.data)
+        let req = helix::chat::GetGlobalEmotesRequest::new();
+        Ok(self
+            .req_get_conditional(req, validator, token)
+            .await?
+            .map(|response| response.data))
     }
 
-    #[cfg(feature = "eventsub")]
-    /// Create a [Conduit](crate::eventsub) for the Twitch Developer Application
-    /// associated with this token
+    /// Get channel emotes in channel with user id, only if they changed since `validator` was returned
     ///
-    /// # Notes
+    /// See [`get_global_emotes_if_modified`](HelixClient::get_global_emotes_if_modified).
     ///
-    /// The token must be an App Access Token
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
     ///
     /// # Examples
     ///
@@ -1419,114 +2162,193 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
     /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
-    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
-    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
-    /// # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
-    /// use twitch_api::{helix, eventsub};
-    ///
-    /// let shard_count = 5;
-    /// let created_conduit = client.create_conduit(shard_count, &token).await?;
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// # let (mut emotes, mut validator): (Vec<helix::chat::ChannelEmote>, helix::CacheValidator) = Default::default();
+    /// use twitch_api::helix;
     ///
+    /// if let helix::Conditional::Modified { data, validator: new } = client
+    ///     .get_channel_emotes_from_id_if_modified("1234", Some(&validator), &token)
+    ///     .await?
+    /// {
+    ///     emotes = data;
+    ///     validator = new;
+    /// }
     /// # Ok(()) }
     /// ```
-    pub async fn create_conduit<'b: 'client, T>(
+    pub async fn get_channel_emotes_from_id_if_modified<'b, T>(
         &'client self,
-        shard_count: usize,
-        token: &'client T,
-    ) -> Result<crate::eventsub::Conduit, ClientError<C>>
+        user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        validator: Option<&helix::CacheValidator>,
+        token: &T,
+    ) -> Result<helix::Conditional<Vec<helix::chat::ChannelEmote>>, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::eventsub::CreateConduitRequest {};
-        let body = helix::eventsub::CreateConduitBody::new(shard_count);
-
-        self.req_post(req, body, token)
-            .await
-            .map(|response| response.data)
+        let req = helix::chat::GetChannelEmotesRequest::broadcaster_id(user_id);
+        Ok(self
+            .req_get_conditional(req, validator, token)
+            .await?
+            .map(|response| response.data))
     }
 
-    #[cfg(feature = "eventsub")]
-    /// Gets a list of all shards for a conduit.
+    /// Get channel emotes in channel with user id, grouped by what unlocks them
     ///
-    /// # Notes
+    /// "Which emotes does a Tier 2 subscriber get" are the emotes of [`Subscription(Tier2)`](helix::chat::EmoteUnlock::Subscription) and the tiers before it.
     ///
-    /// The token must be an App Access Token
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
     ///
     /// # Examples
     ///
-    /// ## Get all shards from the given conduit
-    ///
     /// ```rust, no_run
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
     /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
-    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
-    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
-    /// # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
-    /// use twitch_api::{helix, eventsub};
-    /// use futures::TryStreamExt;
-    ///
-    /// let conduit_id = "26b1c993-bfcf-44d9-b876-379dacafe75a";
-    /// let status = None;
-    /// let all_shards: Vec<eventsub::ShardResponse> = client
-    ///     .get_conduit_shards(conduit_id, status, &token)
-    ///     .try_collect().await?;
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::{helix, helix::chat::EmoteUnlock, types::SubscriptionTier};
     ///
-    /// # Ok(()) }
+    /// let emotes = client.get_channel_emotes_by_tier("1234", &token).await?;
+    /// let tier2: Vec<_> = emotes
+    ///     .range(..=EmoteUnlock::Subscription(SubscriptionTier::Tier2))
+    ///     .flat_map(|(_, emotes)| emotes)
+    ///     .map(|emote| &emote.name)
+    ///     .collect();
+    /// # Ok(())
+    /// # }
     /// ```
+    pub async fn get_channel_emotes_by_tier<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<
+        std::collections::BTreeMap<helix::chat::EmoteUnlock, Vec<helix::chat::ChannelEmote>>,
+        ClientError<C>,
+    >
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let mut by_tier = std::collections::BTreeMap::<_, Vec<_>>::new();
+        for emote in self
+            .get_channel_emotes_from_id(broadcaster_id, token)
+            .await?
+        {
+            by_tier.entry(emote.unlock()).or_default().push(emote);
+        }
+        Ok(by_tier)
+    }
+
+    /// Get the global and channel emotes and badges needed to render chat in a channel
     ///
-    /// ## Get all enabled shards from the given conduit
+    /// The four requests are made concurrently and fail together, use [`get_chat_render_assets_partial`](HelixClient::get_chat_render_assets_partial) to get the parts that succeeded.
+    /// A channel emote or badge replaces a global emote or badge with the same name or set id and version.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// # Examples
     ///
     /// ```rust, no_run
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
     /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
-    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
-    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
-    /// # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
-    /// use twitch_api::{helix, eventsub};
-    /// use futures::TryStreamExt;
-    ///
-    /// let conduit_id = "26b1c993-bfcf-44d9-b876-379dacafe75a";
-    /// let status = eventsub::ShardStatus::Enabled;
-    /// let enabled_shards: Vec<eventsub::ShardResponse> = client
-    ///     .get_conduit_shards(conduit_id, status, &token)
-    ///     .try_collect().await?;
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
     ///
+    /// let assets: helix::chat::ChatRenderAssets =
+    ///     client.get_chat_render_assets("141981764", &token).await?;
+    /// if let Some(emote) = assets.emote("Kappa") {
+    ///     println!("{}", emote.url().size_3x().render());
+    /// }
+    /// if let Some(badge) = assets.badge("subscriber", "12") {
+    ///     println!("{}", badge.image_url_4x);
+    /// }
     /// # Ok(()) }
     /// ```
-    pub fn get_conduit_shards<'b: 'client, T>(
+    pub async fn get_chat_render_assets<'b, T>(
         &'client self,
-        conduit_id: impl Into<Cow<'b, str>>,
-        status: impl Into<Option<crate::eventsub::ShardStatus>>,
-        token: &'client T,
-    ) -> impl futures::Stream<Item = Result<crate::eventsub::ShardResponse, ClientError<C>>>
-           + Send
-           + Unpin
-           + 'client
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<helix::chat::ChatRenderAssets, ClientError<C>>
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::eventsub::GetConduitShardsRequest {
-            conduit_id: conduit_id.into(),
-            status: status.into(),
-            after: None,
-        };
-
-        make_stream(req, token, self, std::collections::VecDeque::from)
+        let broadcaster_id = broadcaster_id.into_cow();
+        let (global_emotes, channel_emotes, global_badges, channel_badges) = futures::try_join!(
+            self.req_get(helix::chat::GetGlobalEmotesRequest::new(), token),
+            self.req_get(
+                helix::chat::GetChannelEmotesRequest::broadcaster_id(&*broadcaster_id),
+                token
+            ),
+            self.req_get(helix::chat::GetGlobalChatBadgesRequest::new(), token),
+            self.req_get(
+                helix::chat::GetChannelChatBadgesRequest::broadcaster_id(&*broadcaster_id),
+                token
+            ),
+        )?;
+        let template = channel_emotes
+            .template()
+            .ok()
+            .flatten()
+            .or_else(|| global_emotes.template().ok().flatten());
+        Ok(helix::chat::ChatRenderAssets::new(
+            global_emotes.data,
+            channel_emotes.data,
+            global_badges.data,
+            channel_badges.data,
+            template,
+        ))
     }
 
-    #[cfg(feature = "eventsub")]
-    /// Updates the [Shard](crate::eventsub) for the given [Conduit](crate::eventsub).
+    /// Get the global and channel emotes and badges needed to render chat in a channel, keeping the parts that succeeded
     ///
-    /// This is used to connect a Webhook or Websocket transport to a conduit, which you can read
-    /// more about [here](https://dev.twitch.tv/docs/eventsub/handling-conduit-events/)
+    /// Like [`get_chat_render_assets`](HelixClient::get_chat_render_assets), but a failed request doesn't fail the others.
+    /// Use [`ChatRenderAssetsParts::assets`](helix::chat::ChatRenderAssetsParts::assets) to combine the parts that succeeded.
     ///
-    /// # Notes
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    pub async fn get_chat_render_assets_partial<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        token: &T,
+    ) -> helix::chat::ChatRenderAssetsParts<ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let broadcaster_id = broadcaster_id.into_cow();
+        let (global_emotes, channel_emotes, global_badges, channel_badges) = futures::join!(
+            self.req_get(helix::chat::GetGlobalEmotesRequest::new(), token),
+            self.req_get(
+                helix::chat::GetChannelEmotesRequest::broadcaster_id(&*broadcaster_id),
+                token
+            ),
+            self.req_get(helix::chat::GetGlobalChatBadgesRequest::new(), token),
+            self.req_get(
+                helix::chat::GetChannelChatBadgesRequest::broadcaster_id(&*broadcaster_id),
+                token
+            ),
+        );
+        let template = [
+            channel_emotes.as_ref().ok().and_then(|r| r.template().ok()),
+            global_emotes.as_ref().ok().and_then(|r| r.template().ok()),
+        ]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .next();
+        helix::chat::ChatRenderAssetsParts {
+            global_emotes: global_emotes.map(|r| r.data),
+            channel_emotes: channel_emotes.map(|r| r.data),
+            global_badges: global_badges.map(|r| r.data),
+            channel_badges: channel_badges.map(|r| r.data),
+            template,
+        }
+    }
+
+    /// Get emotes in emote sets
     ///
-    /// Shard IDs are indexed starting at 0, so a conduit with a shard_count of 5 will have shards with IDs 0 through 4.
+    /// Duplicated emote sets are only requested once, the emotes are returned in the order of `emote_sets`.
     ///
-    /// The token must be an App Access Token
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
     ///
     /// # Examples
     ///
@@ -1534,224 +2356,3549 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
     /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
-    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
-    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
-    /// # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
-    /// use twitch_api::{helix, eventsub};
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::{types, helix};
+    /// use futures::TryStreamExt;
     ///
-    /// // The conduit ID of a previously created Conduit
-    /// let conduit_id = "bb7a1803-eb03-41ef-a1ab-e9242e72053e";
-    /// // The session ID of your WebSocket EventSub connection
-    /// let session_id = "AgoQMpdhHZ-dSoyv7NLALgOGHhIGY2VsbC1j";
-    /// let shard = twitch_api::eventsub::Shard::new(
-    ///     "0",
-    ///     twitch_api::eventsub::Transport::websocket(session_id),
-    /// );
-
-    /// let response = client
-    ///     .update_conduit_shards(conduit_id, vec![shard], &token)
-    ///     .await;
+    /// let emotes: Vec<helix::chat::get_emote_sets::Emote> = client
+    ///     .get_emote_sets(&["0"][..].into(), &token).try_collect().await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_emote_sets<T>(
+        &'client self,
+        emote_sets: &'client types::Collection<'client, types::EmoteSetId>,
+        token: &'client T,
+    ) -> impl futures::Stream<Item = Result<helix::chat::get_emote_sets::Emote, ClientError<C>>>
+           + Send
+           + Unpin
+           + 'client
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        chunked_request(
+            emote_sets.iter(),
+            25,
+            |emote: &helix::chat::get_emote_sets::Emote| &*emote.emote_set_id,
+            move |c| async move {
+                let req = helix::chat::GetEmoteSetsRequest::emote_set_ids(c);
+                Ok(self.req_get(req, token).await?.data)
+            },
+        )
+    }
+
+    /// Start a raid
+    ///
+    /// Transient failures are retried, see [`with_retries`](HelixClient::with_retries).
+    pub async fn start_a_raid<'b, T>(
+        &'client self,
+        from_broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        to_broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<helix::raids::StartARaidResponse, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::raids::StartARaidRequest::new(from_broadcaster_id, to_broadcaster_id);
+        Ok(self
+            .req_post_idempotent(req, helix::EmptyBody, token)
+            .await?
+            .data)
+    }
+
+    /// Start a raid, checking that it can succeed first
+    ///
+    /// Unlike [`start_a_raid`](HelixClient::start_a_raid), this rejects raiding yourself with [`RaidError::SelfRaid`](helix::raids::RaidError::SelfRaid)
+    /// and makes an extra request to check that the channel to raid is live,
+    /// returning [`RaidError::TargetOffline`](helix::raids::RaidError::TargetOffline) without starting the raid if it isn't.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
     ///
+    /// match client.start_raid_checked("1234", "5678", &token).await {
+    ///     Ok(_) => println!("raid started"),
+    ///     Err(helix::ClientExtError::Other(helix::raids::RaidError::TargetOffline)) => {
+    ///         println!("channel is offline")
+    ///     }
+    ///     Err(e) => return Err(e.into()),
+    /// }
     /// # Ok(()) }
     /// ```
-    pub async fn update_conduit_shards<'b: 'client, T>(
+    pub async fn start_raid_checked<'b, T>(
         &'client self,
-        conduit_id: impl Into<String> + Send,
-        shards: Vec<crate::eventsub::Shard>,
+        from_broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        to_broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<helix::raids::StartARaidResponse, ClientExtError<C, helix::raids::RaidError>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let from_broadcaster_id = from_broadcaster_id.into_cow();
+        let to_broadcaster_id = to_broadcaster_id.into_cow();
+        let req =
+            helix::raids::StartARaidRequest::try_new(&*from_broadcaster_id, &*to_broadcaster_id)?;
+        let live = self
+            .req_get(
+                helix::streams::GetStreamsRequest::user_ids(&[&*to_broadcaster_id][..]),
+                token,
+            )
+            .await
+            .map_err(ClientExtError::ClientError)?
+            .data
+            .iter()
+            .any(|stream| stream.user_id == *to_broadcaster_id);
+        if !live {
+            return Err(helix::raids::RaidError::TargetOffline.into());
+        }
+        Ok(self
+            .req_post_idempotent(req, helix::EmptyBody, token)
+            .await
+            .map_err(ClientExtError::ClientError)?
+            .data)
+    }
+
+    /// Cancel a raid
+    pub async fn cancel_a_raid<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<helix::raids::CancelARaidResponse, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::raids::CancelARaidRequest::broadcaster_id(broadcaster_id);
+        Ok(self.req_delete(req, token).await?.data)
+    }
+
+    /// Check messages against the AutoMod settings of a channel
+    ///
+    /// Takes pairs of message ids and texts and returns whether each message is [permitted](helix::moderation::CheckAutoModStatus::is_permitted), keyed by message id.
+    /// The messages are checked in chunks of 100, one request at a time. If a message id is given more than once, the last result for it is kept.
+    ///
+    /// The token has to belong to the broadcaster and needs the [`ModerationRead`](twitch_oauth2::Scope::ModerationRead) scope.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    ///
+    /// let permitted = client
+    ///     .check_messages_automod("1234", [("123", "Hello World!"), ("393", "Boooooo!")], &token)
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn check_messages_automod<'b, T, Id, Text>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        pairs: impl IntoIterator<Item = (Id, Text)> + Send,
+        token: &T,
+    ) -> Result<std::collections::HashMap<types::MsgId, bool>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+        Id: types::IntoCow<'b, types::MsgIdRef> + 'b,
+        Text: Into<Cow<'b, str>>,
+    {
+        let broadcaster_id = broadcaster_id.into_cow();
+        let bodies = helix::moderation::CheckAutoModStatusBody::from_pairs(pairs);
+        let mut permitted = std::collections::HashMap::with_capacity(bodies.len());
+        for chunk in bodies.chunks(100) {
+            let chunk: Vec<_> = chunk.iter().collect();
+            let req =
+                helix::moderation::CheckAutoModStatusRequest::broadcaster_id(&*broadcaster_id);
+            let statuses = self.req_post_idempotent(req, &chunk[..], token).await?.data;
+            permitted.extend(statuses.into_iter().map(|s| (s.msg_id, s.is_permitted)));
+        }
+        Ok(permitted)
+    }
+
+    /// Check a message with AutoMod and delete it, optionally putting its sender in a timeout, if it isn't permitted
+    ///
+    /// Makes a [Check AutoMod Status](helix::moderation::CheckAutoModStatusRequest) request and returns without doing anything else if the message is permitted.
+    /// Otherwise the message is deleted with [Delete Chat Messages](helix::moderation::DeleteChatMessagesRequest),
+    /// and for [`EnforcementAction::DeleteAndTimeout`](helix::moderation::EnforcementAction::DeleteAndTimeout) the sender is put in a timeout with [Ban User](helix::moderation::BanUserRequest).
+    ///
+    /// The timeout is checked with [`BanUserBody::try_new`](helix::moderation::BanUserBody::try_new) before any request is made.
+    /// Checking the message requires a token of the broadcaster, so `moderator_id` is usually the broadcaster's id.
+    /// The token needs the [`ModerationRead`](twitch_oauth2::Scope::ModerationRead) and [`ModeratorManageChatMessages`](twitch_oauth2::Scope::ModeratorManageChatMessages) scopes,
+    /// and [`ModeratorManageBannedUsers`](twitch_oauth2::Scope::ModeratorManageBannedUsers) for timeouts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix::{self, moderation::EnforcementAction};
+    ///
+    /// let outcome = client
+    ///     .enforce_automod(
+    ///         "1234",
+    ///         "1234",
+    ///         "abc-123",
+    ///         "some message",
+    ///         "5678",
+    ///         EnforcementAction::DeleteAndTimeout {
+    ///             seconds: 60,
+    ///             reason: "AutoMod".into(),
+    ///         },
+    ///         &token,
+    ///     )
+    ///     .await?;
+    /// if !outcome.verdict.is_permitted {
+    ///     println!("deleted message, timeout: {:?}", outcome.timeout);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub async fn enforce_automod<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        msg_id: impl types::IntoCow<'b, types::MsgIdRef> + Send + 'b,
+        msg_text: impl Into<Cow<'b, str>> + Send,
+        user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        action: helix::moderation::EnforcementAction<'b>,
+        token: &T,
+    ) -> Result<
+        helix::moderation::AutoModEnforcement,
+        ClientExtError<C, helix::moderation::BanUserBodyError>,
+    >
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let broadcaster_id = broadcaster_id.into_cow();
+        let moderator_id = moderator_id.into_cow();
+        let msg_id = msg_id.into_cow();
+        let timeout = match action {
+            helix::moderation::EnforcementAction::DeleteOnly => None,
+            helix::moderation::EnforcementAction::DeleteAndTimeout { seconds, reason } => Some(
+                helix::moderation::BanUserBody::try_new(user_id, reason, seconds)?,
+            ),
+        };
+
+        let body = helix::moderation::CheckAutoModStatusBody::new(&*msg_id, msg_text);
+        let verdict = self
+            .req_post_idempotent(
+                helix::moderation::CheckAutoModStatusRequest::broadcaster_id(&*broadcaster_id),
+                &[&body][..],
+                token,
+            )
+            .await
+            .map_err(ClientExtError::ClientError)?
+            .into_single()
+            .map_err(|e| ClientExtError::ClientError(unexpected_response(e)))?;
+        if verdict.is_permitted {
+            return Ok(helix::moderation::AutoModEnforcement {
+                verdict,
+                deleted: false,
+                timeout: None,
+            });
+        }
+
+        self.delete_chat_message(&*broadcaster_id, &*moderator_id, &*msg_id, token)
+            .await
+            .map_err(ClientExtError::ClientError)?;
+        let timeout = match timeout {
+            Some(body) => Some(
+                self.req_post(
+                    helix::moderation::BanUserRequest::new(&*broadcaster_id, &*moderator_id),
+                    body,
+                    token,
+                )
+                .await
+                .map_err(ClientExtError::ClientError)?
+                .data,
+            ),
+            None => None,
+        };
+        Ok(helix::moderation::AutoModEnforcement {
+            verdict,
+            deleted: true,
+            timeout,
+        })
+    }
+
+    /// Get chatters in a stream like [`get_chatters`](HelixClient::get_chatters), timing out every page after `timeout`
+    ///
+    /// Pages of big channels can take multiple seconds, so by default this endpoint uses [`SLOW_ENDPOINT_TIMEOUT`](helix::SLOW_ENDPOINT_TIMEOUT)
+    /// instead of the [client timeout](HelixClient::with_timeout). `timeout` is used over both.
+    pub fn get_chatters_with_timeout<T>(
+        &'client self,
+        broadcaster_id: impl Into<&'client types::UserIdRef>,
+        moderator_id: impl Into<&'client types::UserIdRef>,
+        batch_size: impl Into<Option<usize>>,
+        timeout: std::time::Duration,
         token: &'client T,
-    ) -> Result<helix::eventsub::UpdateConduitShardsResponse, ClientError<C>>
+    ) -> helix::PaginatedStream<'client, helix::chat::Chatter, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::chat::GetChattersRequest::new(broadcaster_id.into(), moderator_id.into())
+            .first(batch_size);
+        make_list_stream_with_timeout(req, token, self, Some(timeout))
+    }
+
+    /// Get chatters in a stream like [`get_chatters`](HelixClient::get_chatters), skipping chatters that were already returned
+    ///
+    /// Twitch warns that the list of chatters can change between pages, returning some chatters on more than one page.
+    /// The ids of all returned chatters are kept in memory until the stream is dropped, about as much as collecting the logins of the chatters would take.
+    /// Chatters that moved to a page that was already fetched are still missed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    /// use futures::TryStreamExt;
+    ///
+    /// let chatters: Vec<helix::chat::Chatter> = client
+    ///    .get_chatters_deduped("1234", "4321", 1000, &token)
+    ///    .try_collect().await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_chatters_deduped<T>(
+        &'client self,
+        broadcaster_id: impl Into<&'client types::UserIdRef>,
+        moderator_id: impl Into<&'client types::UserIdRef>,
+        batch_size: impl Into<Option<usize>>,
+        token: &'client T,
+    ) -> helix::PaginatedStream<
+        'client,
+        helix::chat::Chatter,
+        ClientExtError<C, helix::chat::ChattersError>,
+    >
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let mut seen = std::collections::HashSet::new();
+        self.get_chatters(broadcaster_id, moderator_id, batch_size, token)
+            .map_stream(move |stream| {
+                stream.try_filter(move |chatter| {
+                    futures::future::ready(seen.insert(chatter.user_id.clone()))
+                })
+            })
+    }
+
+    /// Get all banned users in a channel like [`get_banned_users_in_channel_from_id`](HelixClient::get_banned_users_in_channel_from_id), timing out every page after `timeout`
+    ///
+    /// By default this endpoint uses [`SLOW_ENDPOINT_TIMEOUT`](helix::SLOW_ENDPOINT_TIMEOUT)
+    /// instead of the [client timeout](HelixClient::with_timeout). `timeout` is used over both.
+    pub fn get_banned_users_in_channel_from_id_with_timeout<'b: 'client, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + 'b,
+        timeout: std::time::Duration,
+        token: &'client T,
+    ) -> helix::PaginatedStream<'client, helix::moderation::BannedUser, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        make_list_stream_with_timeout(
+            helix::moderation::GetBannedUsersRequest::broadcaster_id(broadcaster_id),
+            token,
+            self,
+            Some(timeout),
+        )
+    }
+
+    /// Get the logins of the chatters in a stream
+    ///
+    /// Like [`get_chatters`](HelixClient::get_chatters), but only keeps the [login](helix::chat::Chatter::user_login) of each chatter,
+    /// dropping the rest of every page as soon as it's received.
+    ///
+    /// `batch_size` sets the amount of chatters to retrieve per api call, max 1000, defaults to 100.
+    ///
+    /// If `moderator_id` is [`None`](Option::None), the user id of the token is used, falling back to the broadcaster if the token has no user id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::prelude::*;
+    ///
+    /// let logins: Vec<types::UserName> = client
+    ///    .get_chatter_logins("1234", None, 1000, &token)
+    ///    .try_collect().await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_chatter_logins<T>(
+        &'client self,
+        broadcaster_id: impl Into<&'client types::UserIdRef>,
+        moderator_id: impl Into<Option<&'client types::UserIdRef>>,
+        batch_size: impl Into<Option<usize>>,
+        token: &'client T,
+    ) -> helix::PaginatedStream<'client, types::UserName, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let broadcaster_id = broadcaster_id.into();
+        let moderator_id = moderator_id
+            .into()
+            .or_else(|| token.user_id())
+            .unwrap_or(broadcaster_id);
+        let req =
+            helix::chat::GetChattersRequest::new(broadcaster_id, moderator_id).first(batch_size);
+        make_stream(req, token, self, |chatters| {
+            chatters.into_iter().map(|c| c.user_login).collect()
+        })
+    }
+
+    /// Update the chat color of the user of the token
+    ///
+    /// `color` is normalized with [`normalize_user_color`](helix::chat::normalize_user_color), so hex colors can be given with or without a leading `#`.
+    /// Hex colors can only be used by users with Turbo or Prime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    ///
+    /// client.set_own_chat_color("9146FF", &token).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn set_own_chat_color<T>(
+        &'client self,
+        color: &str,
+        token: &T,
+    ) -> Result<
+        helix::chat::UpdateUserChatColorResponse,
+        ClientExtError<C, helix::chat::SetOwnChatColorError>,
+    >
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
-        let req = helix::eventsub::UpdateConduitShardsRequest {};
-        let body = helix::eventsub::UpdateConduitShardsBody::new(conduit_id.into(), shards);
+        let user_id = token
+            .user_id()
+            .ok_or(helix::chat::SetOwnChatColorError::NoUserIdOnToken)?;
+        let normalized = helix::chat::normalize_user_color(color);
+        let color = <types::NamedUserColor as std::convert::TryFrom<&str>>::try_from(&*normalized)
+            .map_err(|_| helix::chat::SetOwnChatColorError::InvalidColor {
+                color: color.to_owned(),
+            })?;
+        self.update_user_chat_color(user_id, color, token)
+            .await
+            .map_err(ClientExtError::ClientError)
+    }
+
+    /// Get the chat colors of multiple users, indexed by user id
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// Every requested id is a key in the map. The color is [`None`](Option::None) if the user never set their color in the settings,
+    /// or if Twitch didn't return the user, e.g. because they don't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::{types, helix};
+    ///
+    /// let colors = client
+    ///     .get_users_chat_colors_map(&["1234", "4321"][..].into(), &token)
+    ///     .await?;
+    /// let color: Option<&types::HexColor> = colors[&types::UserId::from("1234")].as_ref();
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_users_chat_colors_map<T>(
+        &'client self,
+        user_ids: &types::Collection<'_, types::UserId>,
+        token: &T,
+    ) -> Result<std::collections::HashMap<types::UserId, Option<types::HexColor>>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let mut colors: std::collections::HashMap<_, _> =
+            user_ids.iter().map(|id| (id.to_owned(), None)).collect();
+        let mut stream = chunked_request(
+            user_ids.iter(),
+            100,
+            |color: &helix::chat::UserChatColor| &*color.user_id,
+            move |c| async move {
+                let req = helix::chat::GetUserChatColorRequest::user_ids(c);
+                Ok(self.req_get(req, token).await?.data)
+            },
+        );
+        while let Some(color) = stream.try_next().await? {
+            colors.insert(color.user_id, color.color);
+        }
+        Ok(colors)
+    }
+
+    /// Send a chat message, splitting it into multiple messages if it's too long
+    ///
+    /// The message is split with [`split_message`](helix::chat::split_message) and the parts are sent one after another.
+    /// Returns the IDs of the sent messages. If a part fails, the error contains the IDs of the parts that were sent before it.
+    pub async fn send_chat_message_chunked<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        sender_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        message: impl Into<&'b str> + Send,
+        token: &T,
+    ) -> Result<Vec<types::MsgId>, helix::chat::ChunkedMessageError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        use helix::chat::{ChunkedMessageError, ChunkedMessageFailure};
+
+        let broadcaster_id = broadcaster_id.into_cow();
+        let sender_id = sender_id.into_cow();
+        let mut sent = vec![];
+        for part in helix::chat::split_message(message.into()) {
+            let body = helix::chat::SendChatMessageBody::new(&*broadcaster_id, &*sender_id, part);
+            let reason = match self
+                .req_post(helix::chat::SendChatMessageRequest::new(), body, token)
+                .await
+            {
+                Ok(response) => match response.data {
+                    helix::chat::SendChatMessageResponse {
+                        is_sent: true,
+                        message_id: Some(id),
+                        ..
+                    } => {
+                        sent.push(id);
+                        continue;
+                    }
+                    response => ChunkedMessageFailure::Dropped(response.drop_reason),
+                },
+                Err(e) => ChunkedMessageFailure::Request(e),
+            };
+            return Err(ChunkedMessageError { sent, reason });
+        }
+        Ok(sent)
+    }
+
+    /// Send a chat message, sending it once more if it was dropped for a reason that can pass
+    ///
+    /// Which drops are retried is set with `retry`, see [`ChatMessageRetry`](helix::chat::ChatMessageRetry):
+    ///
+    /// - [duplicates](helix::chat::ChatMessageDropCode::MsgDuplicate) are sent again with an [invisible character](helix::chat::ChatMessageRetry::INVISIBLE_SUFFIX) appended
+    /// - messages dropped because of [slow mode](helix::chat::ChatMessageDropCode::MsgSlowmode) are sent again after the wait in the [drop reason](helix::chat::ChatMessageDropReason::slow_mode_wait),
+    ///   waiting with `sleep`, e.g. `tokio::time::sleep`
+    ///
+    /// A message is sent again at most once. Other drops, like [bans](helix::chat::ChatMessageDropCode::MsgBanned) or [followers-only mode](helix::chat::ChatMessageDropCode::MsgFollowersonly),
+    /// fail with [`SendChatMessageError::Dropped`](helix::chat::SendChatMessageError::Dropped) without sending the message again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix::{self, chat::{ChatMessageRetry, SendChatMessageError}};
+    ///
+    /// match client
+    ///     .send_chat_message_with_retry(
+    ///         "1234",
+    ///         "4321",
+    ///         "Hello, world!",
+    ///         ChatMessageRetry::default(),
+    ///         tokio::time::sleep,
+    ///         &token,
+    ///     )
+    ///     .await
+    /// {
+    ///     Ok(response) => println!("sent {:?}", response.message_id),
+    ///     Err(helix::ClientExtError::Other(SendChatMessageError::Dropped(Some(reason)))) => {
+    ///         println!("not sent: {}", reason.message)
+    ///     }
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn send_chat_message_with_retry<'b, T, F>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        sender_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        message: impl Into<&'b str> + Send,
+        retry: helix::chat::ChatMessageRetry,
+        sleep: impl Fn(std::time::Duration) -> F + Send,
+        token: &T,
+    ) -> Result<
+        helix::chat::SendChatMessageResponse,
+        ClientExtError<C, helix::chat::SendChatMessageError>,
+    >
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+        F: std::future::Future<Output = ()> + Send,
+    {
+        use helix::chat::{ChatMessageDropCode, ChatMessageRetry, SendChatMessageError};
+
+        let broadcaster_id = broadcaster_id.into_cow();
+        let sender_id = sender_id.into_cow();
+        let mut message = Cow::Borrowed(message.into());
+        let mut retried = false;
+        loop {
+            let body =
+                helix::chat::SendChatMessageBody::try_new(&*broadcaster_id, &*sender_id, &*message)
+                    .map_err(SendChatMessageError::from)?;
+            let response = self
+                .req_post(helix::chat::SendChatMessageRequest::new(), body, token)
+                .await
+                .map_err(ClientExtError::ClientError)?
+                .data;
+            if response.is_sent {
+                return Ok(response);
+            }
+            let Some(reason) = response.drop_reason else {
+                return Err(SendChatMessageError::Dropped(None).into());
+            };
+            if !retried {
+                retried = true;
+                match reason.code {
+                    ChatMessageDropCode::MsgDuplicate if retry.duplicate => {
+                        let varied = format!("{message}{}", ChatMessageRetry::INVISIBLE_SUFFIX);
+                        if varied.chars().count() <= helix::chat::MAX_MESSAGE_LENGTH {
+                            message = Cow::Owned(varied);
+                            continue;
+                        }
+                    }
+                    ChatMessageDropCode::MsgSlowmode if retry.slow_mode => {
+                        if let Some(wait) = reason
+                            .slow_mode_wait()
+                            .filter(|wait| *wait <= retry.max_slow_mode_wait)
+                        {
+                            sleep(wait).await;
+                            continue;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            return Err(SendChatMessageError::Dropped(Some(reason)).into());
+        }
+    }
+
+    /// Get channel VIPs
+    ///
+    /// `batch_size` sets the amount of VIPs to retrieve per api call, max 100, defaults to 100.
+    pub fn get_vips_in_channel<'b: 'client, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + 'b,
+        batch_size: impl Into<Option<usize>>,
+        token: &'client T,
+    ) -> helix::PaginatedStream<'client, helix::channels::Vip, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::channels::GetVipsRequest::broadcaster_id(broadcaster_id).first(
+            batch_size
+                .into()
+                .unwrap_or(helix::channels::get_vips::MAX_PAGE_SIZE),
+        );
+
+        make_list_stream(req, token, self)
+    }
+
+    /// Send a whisper
+    pub async fn send_whisper<'b, T>(
+        &'client self,
+        from: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        to: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        message: impl Into<&'b str> + Send,
+        token: &T,
+    ) -> Result<helix::whispers::SendWhisperResponse, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        Ok(self
+            .req_post(
+                helix::whispers::SendWhisperRequest::new(from, to),
+                helix::whispers::SendWhisperBody::new(message.into()),
+                token,
+            )
+            .await?
+            .data)
+    }
+
+    /// Send whispers to multiple users, one at a time
+    ///
+    /// Waits `delay` between whispers with `sleep`, e.g. `tokio::time::sleep`, to stay under the per-second limit.
+    /// When a whisper is [rate limited](helix::whispers::WhisperOutcome::RateLimited), the next whisper is sent after the `Retry-After` of the response, or `delay` if there is none.
+    /// When the [daily limit is reached](helix::whispers::WhisperOutcome::DailyLimitReached), no more whispers are sent and the remaining recipients are returned as [`WhisperOutcome::NotSent`](helix::whispers::WhisperOutcome::NotSent).
+    /// Twitch also limits the amount of unique recipients per day, sending to more is reported as the daily limit.
+    ///
+    /// Requires a user token with scope [`user:manage:whispers`](twitch_oauth2::Scope::UserManageWhispers).
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::{helix, types};
+    ///
+    /// let recipients = vec![
+    ///     (types::UserId::from("1234"), "hello!".to_owned()),
+    ///     (types::UserId::from("5678"), "hi!".to_owned()),
+    /// ];
+    /// let outcomes = client
+    ///     .send_whispers(
+    ///         "4321",
+    ///         recipients,
+    ///         std::time::Duration::from_secs(1),
+    ///         tokio::time::sleep,
+    ///         &token,
+    ///     )
+    ///     .await;
+    /// for (user_id, outcome) in outcomes {
+    ///     if !outcome.is_sent() {
+    ///         println!("could not whisper {user_id}: {outcome:?}");
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn send_whispers<'b, T, F>(
+        &'client self,
+        from: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        recipients: impl IntoIterator<Item = (types::UserId, String)> + Send,
+        delay: std::time::Duration,
+        sleep: impl Fn(std::time::Duration) -> F + Send,
+        token: &T,
+    ) -> Vec<(
+        types::UserId,
+        helix::whispers::WhisperOutcome<ClientError<C>>,
+    )>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+        F: std::future::Future<Output = ()> + Send,
+    {
+        use helix::whispers::WhisperOutcome;
+
+        let from = from.into_cow();
+        let mut outcomes = vec![];
+        let mut wait = None;
+        let mut recipients = recipients.into_iter().collect::<Vec<_>>().into_iter();
+        while let Some((to, message)) = recipients.next() {
+            if let Some(wait) = wait.take() {
+                sleep(wait).await;
+            }
+            let outcome = match self.send_whisper(&*from, &*to, &*message, token).await {
+                Ok(_) => WhisperOutcome::Sent,
+                Err(ClientRequestError::HelixRequestPostError(
+                    helix::HelixRequestPostError::RateLimited { ref message, .. },
+                )) if helix::whispers::send_whisper::is_daily_limit(message.as_deref()) => {
+                    outcomes.push((to, WhisperOutcome::DailyLimitReached));
+                    outcomes.extend(recipients.map(|(to, _)| (to, WhisperOutcome::NotSent)));
+                    break;
+                }
+                Err(ClientRequestError::HelixRequestPostError(
+                    helix::HelixRequestPostError::RateLimited { retry_after, .. },
+                )) => {
+                    wait = retry_after;
+                    WhisperOutcome::RateLimited { retry_after }
+                }
+                Err(ClientRequestError::HelixRequestPostError(
+                    helix::HelixRequestPostError::Error {
+                        status,
+                        ref message,
+                        ..
+                    },
+                )) if helix::whispers::send_whisper::is_recipient_blocked(status, message) => {
+                    WhisperOutcome::RecipientBlocked
+                }
+                Err(e) => WhisperOutcome::Failed(e),
+            };
+            outcomes.push((to, outcome));
+            wait.get_or_insert(delay);
+        }
+        outcomes
+    }
+
+    /// Get all custom rewards
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    ///
+    /// let rewards: Vec<helix::points::CustomReward> = client
+    ///     .get_all_custom_rewards("1234", true, &token)
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_all_custom_rewards<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        only_managable_rewards: bool,
+        token: &T,
+    ) -> Result<Vec<helix::points::CustomReward>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        self.get_custom_rewards(
+            broadcaster_id,
+            only_managable_rewards,
+            &types::Collection::EMPTY,
+            token,
+        )
+        .await
+    }
+
+    /// Get specific custom rewards, see [`get_all_custom_rewards`](HelixClient::get_all_custom_rewards) to get all rewards
+    ///
+    /// # Notes
+    ///
+    /// Takes a max of 50 ids
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    ///
+    /// let rewards: Vec<helix::points::CustomReward> = client
+    ///     .get_custom_rewards("1234", true, &["8969ec47-55b6-4559-a8fe-3f1fc4e6fe58"][..].into(), &token)
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    // XXX: This function is useless as a stream, since you can never have more than 50 rewards on a channel
+    pub async fn get_custom_rewards<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        only_managable_rewards: bool,
+        ids: &'b types::Collection<'b, types::RewardId>,
+        token: &'client T,
+    ) -> Result<Vec<helix::points::CustomReward>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        Ok(self
+            .req_get(
+                helix::points::GetCustomRewardRequest::broadcaster_id(broadcaster_id)
+                    .only_manageable_rewards(only_managable_rewards)
+                    .ids(ids.clone()),
+                token,
+            )
+            .await?
+            .data)
+    }
+
+    #[cfg(feature = "eventsub")]
+    /// Create an [EventSub](crate::eventsub) subscription
+    ///
+    /// Transient failures are retried, see [`with_retries`](HelixClient::with_retries).
+    /// If a retry finds the subscription already exists, [`ClientRequestError::AlreadyExists`] is returned.
+    pub async fn create_eventsub_subscription<T, E: crate::eventsub::EventSubscription + Send>(
+        &'client self,
+        subscription: E,
+        transport: crate::eventsub::Transport,
+        token: &T,
+    ) -> Result<helix::eventsub::CreateEventSubSubscription<E>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        Ok(self
+            .req_post_idempotent(
+                helix::eventsub::CreateEventSubSubscriptionRequest::new(),
+                helix::eventsub::CreateEventSubSubscriptionBody::new(subscription, transport),
+                token,
+            )
+            .await?
+            .data)
+    }
+
+    #[cfg(feature = "eventsub")]
+    /// Delete an [EventSub](crate::eventsub) subscription
+    pub async fn delete_eventsub_subscription<'b, T>(
+        &'client self,
+        id: impl types::IntoCow<'b, types::EventSubIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<helix::eventsub::DeleteEventSubSubscription, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        Ok(self
+            .req_delete(
+                helix::eventsub::DeleteEventSubSubscriptionRequest::id(id),
+                token,
+            )
+            .await?
+            .data)
+    }
+
+    #[cfg(feature = "eventsub")]
+    /// Get all [EventSub](crate::eventsub) subscriptions for this [Client](twitch_oauth2::TwitchToken)
+    ///
+    /// # Notes
+    ///
+    /// The return item is a struct [`EventSubSubscriptions`](helix::eventsub::EventSubSubscriptions)
+    /// which contains a field with all the subscriptions.
+    /// See the example for collecting all _specific_ subscriptions
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::{helix, eventsub};
+    /// use futures::{TryStreamExt, stream};
+    ///
+    /// let mut total_cost = None;
+    ///
+    /// let chatters: Vec<eventsub::EventSubSubscription> = client
+    ///     .get_eventsub_subscriptions(None, None, None, &token)
+    ///     .map_ok(|r| {
+    ///         total_cost = Some(r.total_cost);
+    ///         stream::iter(
+    ///             r.subscriptions
+    ///                 .into_iter()
+    ///                 .map(Ok::<_, twitch_api::helix::ClientRequestError<_>>),
+    ///         )
+    ///     })
+    ///     .try_flatten()
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_eventsub_subscriptions<'b: 'client, T>(
+        &'client self,
+        status: impl Into<Option<crate::eventsub::SubscriptionStatus>>,
+        event_type: impl Into<Option<crate::eventsub::EventType>>,
+        // FIXME: IntoOptionCow?
+        user_id: Option<&'b types::UserIdRef>,
+        token: &'client T,
+    ) -> impl futures::Stream<Item = Result<helix::eventsub::EventSubSubscriptions, ClientError<C>>>
+           + Send
+           + Unpin
+           + 'client
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::eventsub::GetEventSubSubscriptionsRequest {
+            status: status.into(),
+            type_: event_type.into(),
+            user_id: user_id.map(|c| c.as_cow()),
+            after: None,
+            first: None,
+        };
+
+        make_stream(req, token, self, |r| {
+            let mut vec = std::collections::VecDeque::new();
+            vec.push_front(r);
+            vec
+        })
+    }
+
+    #[cfg(feature = "eventsub")]
+    /// Get all [EventSub](crate::eventsub) subscriptions for this [Client](twitch_oauth2::TwitchToken) that have [failed](crate::eventsub::SubscriptionStatus::is_failed)
+    ///
+    /// These are the subscriptions that Twitch won't send notifications for anymore, which should be deleted and possibly recreated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_secret");
+    /// # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+    /// use twitch_api::{helix, eventsub};
+    /// use futures::TryStreamExt;
+    ///
+    /// let failed: Vec<eventsub::EventSubSubscription> = client
+    ///     .get_failed_eventsub_subscriptions(&token)
+    ///     .try_collect()
+    ///     .await?;
+    /// for subscription in failed {
+    ///     client.delete_eventsub_subscription(subscription.id, &token).await?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn get_failed_eventsub_subscriptions<T>(
+        &'client self,
+        token: &'client T,
+    ) -> impl futures::Stream<Item = Result<crate::eventsub::EventSubSubscription, ClientError<C>>>
+           + Send
+           + Unpin
+           + 'client
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::eventsub::GetEventSubSubscriptionsRequest::new();
+
+        // an empty page ends the stream, so filter the subscriptions after paginating
+        make_stream(req, token, self, |r| r.subscriptions.into())
+            .try_filter(|s| futures::future::ready(s.status.is_failed()))
+    }
+
+    #[cfg(feature = "eventsub")]
+    /// Get all [Conduits](crate::eventsub::Conduit) for the Twitch Developer Application
+    /// associated with this token
+    ///
+    /// # Notes
+    ///
+    /// The token must be an App Access Token
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
+    /// # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+    /// use twitch_api::{helix, eventsub};
+    ///
+    /// let conduits = client.get_conduits(&token).await?;
+    ///
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_conduits<'b: 'client, T>(
+        &'client self,
+        token: &'client T,
+    ) -> Result<Vec<crate::eventsub::Conduit>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        self.req_get(helix::eventsub::GetConduitsRequest::new(), token)
+            .await
+            .map(|response| response.data)
+    }
+
+    #[cfg(feature = "eventsub")]
+    /// Create a [Conduit](crate::eventsub) for the Twitch Developer Application
+    /// associated with this token
+    ///
+    /// # Notes
+    ///
+    /// The token must be an App Access Token
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
+    /// # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+    /// use twitch_api::{helix, eventsub};
+    ///
+    /// let shard_count = 5;
+    /// let created_conduit = client.create_conduit(shard_count, &token).await?;
+    ///
+    /// # Ok(()) }
+    /// ```
+    pub async fn create_conduit<'b: 'client, T>(
+        &'client self,
+        shard_count: usize,
+        token: &'client T,
+    ) -> Result<crate::eventsub::Conduit, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::eventsub::CreateConduitRequest::new();
+        let body = helix::eventsub::CreateConduitBody::new(shard_count);
+
+        self.req_post(req, body, token)
+            .await
+            .map(|response| response.data)
+    }
+
+    #[cfg(feature = "eventsub")]
+    /// Gets a list of all shards for a conduit.
+    ///
+    /// # Notes
+    ///
+    /// The token must be an App Access Token
+    ///
+    /// # Examples
+    ///
+    /// ## Get all shards from the given conduit
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
+    /// # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+    /// use twitch_api::prelude::*;
+    ///
+    /// let conduit_id = eventsub::ConduitIdRef::from_static("26b1c993-bfcf-44d9-b876-379dacafe75a");
+    /// let status = None;
+    /// let all_shards: Vec<eventsub::ShardResponse> = client
+    ///     .get_conduit_shards(conduit_id, status, &token)
+    ///     .try_collect().await?;
+    ///
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// ## Get all enabled shards from the given conduit
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
+    /// # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+    /// use twitch_api::prelude::*;
+    ///
+    /// let conduit_id = eventsub::ConduitIdRef::from_static("26b1c993-bfcf-44d9-b876-379dacafe75a");
+    /// let status = eventsub::ShardStatus::Enabled;
+    /// let enabled_shards: Vec<eventsub::ShardResponse> = client
+    ///     .get_conduit_shards(conduit_id, status, &token)
+    ///     .try_collect().await?;
+    ///
+    /// # Ok(()) }
+    /// ```
+    pub fn get_conduit_shards<'b: 'client, T>(
+        &'client self,
+        conduit_id: impl Into<Cow<'b, crate::eventsub::ConduitIdRef>>,
+        status: impl Into<Option<crate::eventsub::ShardStatus>>,
+        token: &'client T,
+    ) -> impl futures::Stream<Item = Result<crate::eventsub::ShardResponse, ClientError<C>>>
+           + Send
+           + Unpin
+           + 'client
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::eventsub::GetConduitShardsRequest::conduit_id(conduit_id).status(status);
+
+        make_list_stream(req, token, self)
+    }
+
+    #[cfg(feature = "eventsub")]
+    /// Updates the [Shard](crate::eventsub) for the given [Conduit](crate::eventsub).
+    ///
+    /// This is used to connect a Webhook or Websocket transport to a conduit, which you can read
+    /// more about [here](https://dev.twitch.tv/docs/eventsub/handling-conduit-events/)
+    ///
+    /// # Notes
+    ///
+    /// Shard IDs are indexed starting at 0, so a conduit with a shard_count of 5 will have shards with IDs 0 through 4.
+    ///
+    /// The token must be an App Access Token
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
+    /// # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+    /// use twitch_api::{helix, eventsub};
+    ///
+    /// // The conduit ID of a previously created Conduit
+    /// let conduit_id = "bb7a1803-eb03-41ef-a1ab-e9242e72053e";
+    /// // The session ID of your WebSocket EventSub connection
+    /// let session_id = "AgoQMpdhHZ-dSoyv7NLALgOGHhIGY2VsbC1j";
+    /// let shard = twitch_api::eventsub::Shard::new(
+    ///     "0",
+    ///     twitch_api::eventsub::Transport::websocket(session_id),
+    /// );
+
+    /// let response = client
+    ///     .update_conduit_shards(conduit_id, vec![shard], &token)
+    ///     .await;
+    ///
+    /// # Ok(()) }
+    /// ```
+    pub async fn update_conduit_shards<'b: 'client, T>(
+        &'client self,
+        conduit_id: impl Into<crate::eventsub::ConduitId> + Send,
+        shards: Vec<crate::eventsub::Shard>,
+        token: &'client T,
+    ) -> Result<helix::eventsub::UpdateConduitShardsResponse, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::eventsub::UpdateConduitShardsRequest::new();
+        let body = helix::eventsub::UpdateConduitShardsBody::new(conduit_id, shards);
+
+        self.req_patch(req, body, token)
+            .await
+            .map(|response| response.data)
+    }
+
+    /// Validate a token and check that it satisfies the `required` scopes
+    ///
+    /// The scopes of requests can be passed with [`Request::SCOPE`](helix::Request::SCOPE).
+    /// An expired or revoked token is returned as [`TokenHealth::Invalid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix::{self, Request};
+    ///
+    /// let health = client
+    ///     .validate_token_health(
+    ///         &token,
+    ///         &[
+    ///             helix::chat::GetChattersRequest::SCOPE,
+    ///             helix::moderation::BanUserRequest::SCOPE,
+    ///         ],
+    ///     )
+    ///     .await?;
+    /// if !health.is_healthy() {
+    ///     println!("token needs to be renewed: {health:?}");
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn validate_token_health<T>(
+        &'client self,
+        token: &T,
+        required: &[twitch_oauth2::Validator],
+    ) -> Result<
+        TokenHealth,
+        twitch_oauth2::tokens::errors::ValidationError<
+            crate::client::CompatError<<C as crate::HttpClient>::Error>,
+        >,
+    >
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let validated = match twitch_oauth2::validate_token(self, token.token()).await {
+            Ok(validated) => validated,
+            Err(twitch_oauth2::tokens::errors::ValidationError::NotAuthorized) => {
+                return Ok(TokenHealth::Invalid)
+            }
+            Err(e) => return Err(e),
+        };
+        let scopes = validated.scopes.as_deref().unwrap_or_default();
+        let missing: Vec<_> = required
+            .iter()
+            .filter(|validator| !validator.matches(scopes))
+            .cloned()
+            .collect();
+        if missing.is_empty() {
+            Ok(TokenHealth::Healthy(validated))
+        } else {
+            Ok(TokenHealth::MissingScopes {
+                token: validated,
+                missing,
+            })
+        }
+    }
+
+    /// Periodically check the health of a token with [`validate_token_health`](HelixClient::validate_token_health)
+    ///
+    /// `on_warning` is called when a check finds the token unhealthy or expiring within `warn_before`.
+    /// Checks are done every `interval`, or earlier to warn in time, waiting with `sleep`, e.g. `tokio::time::sleep`.
+    /// Returns when the token is no longer valid, the token is not refreshed.
+    pub async fn watch_token_health<T, F>(
+        &'client self,
+        token: &T,
+        required: &[twitch_oauth2::Validator],
+        interval: std::time::Duration,
+        warn_before: std::time::Duration,
+        sleep: impl Fn(std::time::Duration) -> F + Send,
+        mut on_warning: impl FnMut(&TokenHealth) + Send,
+    ) -> Result<
+        (),
+        twitch_oauth2::tokens::errors::ValidationError<
+            crate::client::CompatError<<C as crate::HttpClient>::Error>,
+        >,
+    >
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+        F: std::future::Future<Output = ()> + Send,
+    {
+        loop {
+            let health = self.validate_token_health(token, required).await?;
+            let until_warning = health
+                .expires_in()
+                .map(|expires_in| expires_in.saturating_sub(warn_before));
+            if !health.is_healthy() || until_warning == Some(std::time::Duration::ZERO) {
+                on_warning(&health);
+            }
+            if let TokenHealth::Invalid = health {
+                return Ok(());
+            }
+            let wait = match until_warning {
+                Some(until_warning) if !until_warning.is_zero() => until_warning.min(interval),
+                _ => interval,
+            };
+            sleep(wait).await;
+        }
+    }
+}
+
+/// Health of a token, returned by [`HelixClient::validate_token_health`]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum TokenHealth {
+    /// The token is valid and has all required scopes
+    Healthy(twitch_oauth2::ValidatedToken),
+    /// The token is valid, but doesn't have all required scopes
+    MissingScopes {
+        /// The validated token
+        token: twitch_oauth2::ValidatedToken,
+        /// The required scopes that are not satisfied by the token
+        missing: Vec<twitch_oauth2::Validator>,
+    },
+    /// The token is expired or was revoked
+    Invalid,
+}
+
+impl TokenHealth {
+    /// The token is valid and has all required scopes
+    pub fn is_healthy(&self) -> bool { matches!(self, TokenHealth::Healthy(_)) }
+
+    /// The validated token, if it is valid
+    pub fn token(&self) -> Option<&twitch_oauth2::ValidatedToken> {
+        match self {
+            TokenHealth::Healthy(token) | TokenHealth::MissingScopes { token, .. } => Some(token),
+            TokenHealth::Invalid => None,
+        }
+    }
+
+    /// Time until the token expires, if it is valid and expires
+    pub fn expires_in(&self) -> Option<std::time::Duration> {
+        self.token().and_then(|token| token.expires_in)
+    }
+
+    /// Login of the user the token belongs to, if it is a valid user token
+    pub fn login(&self) -> Option<&types::UserNameRef> {
+        self.token().and_then(|token| token.login.as_deref())
+    }
+
+    /// ID of the user the token belongs to, if it is a valid user token
+    pub fn user_id(&self) -> Option<&types::UserIdRef> {
+        self.token().and_then(|token| token.user_id.as_deref())
+    }
+}
+
+/// Maximum amount of chunks requested at the same time by [`chunked_request`], and of requests made at the same time by other helpers
+const CHUNK_CONCURRENCY: usize = 4;
+
+/// Make a request for every chunk of at most `chunk_size` unique `items`, for helpers that take more items than one request allows
+///
+/// Duplicated items are only requested once, at their first occurrence. No request is made if there are no items.
+/// At most [`CHUNK_CONCURRENCY`] chunks are requested at a time and the results are yielded in the order of the chunks.
+/// Within a chunk, the results are sorted by the position in `items` of their `key`, results with the same key,
+/// or with a key that wasn't requested, keep the order Twitch returned them in.
+pub(crate) fn chunked_request<'a, R, Item, E, F, Fut>(
+    items: impl IntoIterator<Item = &'a R>,
+    chunk_size: usize,
+    key: fn(&Item) -> &R,
+    mut request: F,
+) -> futures::stream::BoxStream<'a, Result<Item, E>>
+where
+    R: ?Sized + std::hash::Hash + Eq + ToOwned + 'a,
+    R::Owned: std::hash::Hash + Eq + Clone + Send,
+    Item: Send + 'a,
+    E: Send + 'a,
+    F: FnMut(Vec<R::Owned>) -> Fut + Send + 'a,
+    Fut: std::future::Future<Output = Result<Vec<Item>, E>> + Send + 'a,
+{
+    let mut seen = std::collections::HashSet::new();
+    let mut unique = items
+        .into_iter()
+        .filter(|item| seen.insert(*item))
+        .map(ToOwned::to_owned)
+        .peekable();
+    let mut chunks = vec![];
+    while unique.peek().is_some() {
+        chunks.push(unique.by_ref().take(chunk_size).collect::<Vec<_>>());
+    }
+
+    futures::stream::iter(chunks)
+        .map(move |chunk| {
+            let order: std::collections::HashMap<R::Owned, usize> =
+                chunk.iter().cloned().zip(0..).collect();
+            let response = request(chunk);
+            async move {
+                let mut items = response.await?;
+                items.sort_by_key(|item| order.get(key(item)).copied().unwrap_or(usize::MAX));
+                Ok(items)
+            }
+        })
+        .buffered(CHUNK_CONCURRENCY)
+        .map_ok(|items| futures::stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+}
+
+/// Error for a response with more results than the helper expects
+pub(crate) fn unexpected_response<C: crate::HttpClient>(
+    e: helix::SingleItemError,
+) -> ClientError<C> {
+    ClientRequestError::UnexpectedResponse(e)
+}
+
+fn status_of<C: crate::HttpClient>(e: &ClientError<C>) -> Option<http::StatusCode> {
+    match e {
+        ClientRequestError::HelixRequestGetError(helix::HelixRequestGetError::Error {
+            status,
+            ..
+        }) => Some(*status),
+        _ => None,
+    }
+}
+
+/// Twitch responds with `410 Gone` to endpoints that have been removed
+fn is_gone<C: crate::HttpClient>(e: &ClientError<C>) -> bool {
+    status_of(e) == Some(http::StatusCode::GONE)
+}
+
+fn is_unauthorized<C: crate::HttpClient>(e: &ClientError<C>) -> bool {
+    matches!(
+        status_of(e),
+        Some(http::StatusCode::UNAUTHORIZED | http::StatusCode::FORBIDDEN)
+    )
+}
+
+/// Get Channel Followers returns the total follower count to any user token
+fn can_read_follower_total<T: TwitchToken + ?Sized>(token: &T) -> bool { token.user_id().is_some() }
+
+/// Get Channel Followers needs a user token with `moderator:read:followers` to list followers
+fn can_read_followers<T: TwitchToken + ?Sized>(token: &T) -> bool {
+    token.user_id().is_some()
+        && token
+            .scopes()
+            .contains(&twitch_oauth2::Scope::ModeratorReadFollowers)
+}
+
+/// Error type to combine a http client error with a other error
+#[derive(Debug, thiserror::Error)]
+pub enum ClientExtError<C: crate::HttpClient, E> {
+    /// Http client error
+    #[error(transparent)]
+    ClientError(ClientError<C>),
+    /// Other error
+    #[error(transparent)]
+    Other(#[from] E),
+}
+
+impl<C: crate::HttpClient, E> ClientExtError<C, E> {
+    /// The [phase](helix::Phase) of the request the [client error](ClientExtError::ClientError) happened in
+    ///
+    /// Returns `None` for [other](ClientExtError::Other) errors, these are made by the helper from a successful request or response.
+    pub fn phase(&self) -> Option<helix::Phase> {
+        match self {
+            Self::ClientError(e) => Some(e.phase()),
+            Self::Other(_) => None,
+        }
+    }
+
+    /// Twitch's id for the request the [client error](ClientExtError::ClientError) happened in, see [`ClientRequestError::request_id`]
+    ///
+    /// Also returned by the errors of the [paginated streams](helix::PaginatedStream) of the helpers.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::ClientError(e) => e.request_id(),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+/// Error returned by the `_required` helpers when Twitch has nothing for the identifier, like [`get_channel_from_id_required`](HelixClient::get_channel_from_id_required)
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{resource} '{identifier}' not found")]
+#[non_exhaustive]
+pub struct NotFoundError {
+    /// What was looked up, for example `"user"` or `"game"`
+    pub resource: &'static str,
+    /// The login, id or name that was looked up
+    pub identifier: String,
+}
+
+/// Error returned by [`HelixClient::get_merged_schedules`] when the schedule of a broadcaster could not be fetched
+#[derive(Debug, thiserror::Error)]
+#[error("could not get the schedule of broadcaster {broadcaster_id}")]
+#[non_exhaustive]
+pub struct MergedScheduleError<E> {
+    /// The broadcaster whose schedule failed
+    pub broadcaster_id: types::UserId,
+    /// The error of the request
+    #[source]
+    pub source: E,
+}
+
+/// Error returned by [`HelixClient::get_user_from_login_required`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum UserFromLoginError {
+    /// The login is not valid, no request was made
+    #[error(transparent)]
+    InvalidLogin(#[from] crate::extra::InvalidLoginError),
+    /// There is no user with this login
+    #[error(transparent)]
+    NotFound(#[from] NotFoundError),
+}
+
+/// Error returned by [`HelixClient::get_total_followers_from_login`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum FollowersFromLoginError {
+    /// The login is not valid, no request was made
+    #[error(transparent)]
+    InvalidLogin(#[from] crate::extra::InvalidLoginError),
+    /// The follower count can't be requested with the token
+    #[error(transparent)]
+    Unavailable(#[from] helix::channels::FollowerCountUnavailable),
+}
+
+/// Turn the `None` of an optional helper into a [`NotFoundError`]
+///
+/// All the `_required` helpers go through this, so they fail the same way.
+fn required<C: crate::HttpClient, T, E, F>(
+    found: Result<Option<T>, ClientExtError<C, E>>,
+    resource: &'static str,
+    identifier: &str,
+) -> Result<T, ClientExtError<C, F>>
+where
+    F: From<E> + From<NotFoundError>,
+{
+    match found {
+        Ok(Some(found)) => Ok(found),
+        Ok(None) => Err(ClientExtError::Other(
+            NotFoundError {
+                resource,
+                identifier: identifier.to_owned(),
+            }
+            .into(),
+        )),
+        Err(ClientExtError::ClientError(e)) => Err(ClientExtError::ClientError(e)),
+        Err(ClientExtError::Other(e)) => Err(ClientExtError::Other(e.into())),
+    }
+}
+
+/// Make a paginate-able request into a stream
+///
+/// # Examples
+///
+/// ```rust, no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+/// use twitch_api::helix;
+/// use futures::TryStreamExt;
+///
+/// let req = helix::moderation::GetModeratorsRequest::broadcaster_id("1234");
+///
+/// helix::make_stream(req, &token, &client, std::collections::VecDeque::from).try_collect::<Vec<_>>().await?
+/// # ;
+/// # Ok(())
+/// # }
+/// ```
+pub fn make_stream<
+    'a,
+    C: crate::HttpClient + Send + Sync,
+    T: TwitchToken + Send + Sync + ?Sized + Send + Sync,
+    // FIXME: Why does this have to be clone and debug?
+    Req: super::Request
+        + super::RequestGet
+        + super::Paginated
+        + Clone
+        + std::fmt::Debug
+        + Send
+        + Sync
+        + 'a,
+    // FIXME: this 'a seems suspicious
+    Item: Send + 'a,
+>(
+    req: Req,
+    token: &'a T,
+    client: &'a super::HelixClient<'a, C>,
+    fun: impl Fn(<Req as super::Request>::Response) -> std::collections::VecDeque<Item>
+        + Send
+        + Sync
+        + Copy
+        + 'static,
+) -> PaginatedStream<'a, Item, ClientError<C>>
+where
+    // FIXME: This clone is bad. I want to be able to return the data, but not in a way that limits the response to be Default
+    // I also want to keep allocations low, so std::mem::take is perfect, but that makes get_next not work optimally.
+    <Req as super::Request>::Response: Send + Sync + std::fmt::Debug + Clone,
+{
+    make_stream_with_timeout(req, token, client, None, fun)
+}
+
+/// Make a paginatable request into a stream like [`make_stream`], timing out every request after `timeout` if given
+pub(crate) fn make_stream_with_timeout<
+    'a,
+    C: crate::HttpClient + Send + Sync,
+    T: TwitchToken + Send + Sync + ?Sized + Send + Sync,
+    // FIXME: Why does this have to be clone and debug?
+    Req: super::Request
+        + super::RequestGet
+        + super::Paginated
+        + Clone
+        + std::fmt::Debug
+        + Send
+        + Sync
+        + 'a,
+    // FIXME: this 'a seems suspicious
+    Item: Send + 'a,
+>(
+    req: Req,
+    token: &'a T,
+    client: &'a super::HelixClient<'a, C>,
+    timeout: Option<std::time::Duration>,
+    fun: impl Fn(<Req as super::Request>::Response) -> std::collections::VecDeque<Item>
+        + Send
+        + Sync
+        + Copy
+        + 'static,
+) -> PaginatedStream<'a, Item, ClientError<C>>
+where
+    // FIXME: This clone is bad. I want to be able to return the data, but not in a way that limits the response to be Default
+    // I also want to keep allocations low, so std::mem::take is perfect, but that makes get_next not work optimally.
+    <Req as super::Request>::Response: Send + Sync + std::fmt::Debug + Clone,
+{
+    enum StateMode<Req: super::Request + super::RequestGet, Item> {
+        /// A request needs to be done.
+        Req(Option<Req>),
+        /// We have made a request, now working through the data
+        Cont(
+            super::Response<Req, <Req as super::Request>::Response>,
+            std::collections::VecDeque<Item>,
+        ),
+        Next(Option<super::Response<Req, <Req as super::Request>::Response>>),
+        /// The operation failed, allowing no further processing
+        Failed,
+    }
+
+    impl<Req: super::Request + super::RequestGet, Item> StateMode<Req, Item> {
+        fn take_initial(&mut self) -> Req {
+            match self {
+                StateMode::Req(ref mut r) if r.is_some() => std::mem::take(r).expect("oops"),
+                _ => todo!("hmmm"),
+            }
+        }
+
+        fn take_next(&mut self) -> super::Response<Req, <Req as super::Request>::Response> {
+            match self {
+                StateMode::Next(ref mut r) if r.is_some() => std::mem::take(r).expect("oops"),
+                _ => todo!("hmmm"),
+            }
+        }
+    }
+
+    struct State<
+        'a,
+        C: crate::HttpClient,
+        T: TwitchToken + Send + Sync + ?Sized,
+        Req: super::Request + super::RequestGet,
+        Item,
+    > {
+        mode: StateMode<Req, Item>,
+        client: &'a HelixClient<'a, C>,
+        token: &'a T,
+        timeout: Option<std::time::Duration>,
+        progress: PaginationProgress,
+        /// Amount of items requested for the last page, if it was sized for the expected amount of items
+        page_size: Option<usize>,
+    }
+
+    impl<
+            'a,
+            C: crate::HttpClient,
+            T: TwitchToken + Send + Sync + ?Sized,
+            Req: super::Request + super::RequestGet + super::Paginated,
+            Item,
+        > State<'a, C, T, Req, Item>
+    {
+        /// Process a request, with a given deq
+        fn process(
+            mut self,
+            r: super::Response<Req, <Req as super::Request>::Response>,
+            d: std::collections::VecDeque<Item>,
+        ) -> Self {
+            self.mode = StateMode::Cont(r, d);
+            self
+        }
+
+        fn failed(mut self) -> Self {
+            self.mode = StateMode::Failed;
+            self
+        }
+
+        /// get the next
+        fn get_next(mut self) -> Self {
+            match self.mode {
+                StateMode::Cont(r, d) => {
+                    assert!(d.is_empty());
+                    self.mode = StateMode::Next(Some(r));
+                    self
+                }
+                _ => panic!("oops"),
+            }
+        }
+    }
+    let progress = PaginationProgress::default();
+    let statemode = StateMode::Req(Some(req));
+    let state = State {
+        mode: statemode,
+        client,
+        token,
+        timeout,
+        progress: progress.clone(),
+        page_size: None,
+    };
+    let inner = futures::stream::unfold(state, move |mut state: State<_, _, _, _>| async move {
+        match state.mode {
+            StateMode::Req(Some(_)) => {
+                let mut req = state.mode.take_initial();
+                state.page_size = state.progress.size_page(&mut req, state.page_size);
+                let f = state
+                    .client
+                    .req_get_with_timeout(req, state.token, state.timeout);
+                let resp = match f.await {
+                    Ok(resp) => resp,
+                    Err(e) => return Some((Err(e), state.failed())),
+                };
+                state.progress.fetched(&resp);
+                let mut deq = fun(resp.data.clone());
+                deq.pop_front().map(|d| (Ok(d), state.process(resp, deq)))
+            }
+            StateMode::Cont(_, ref mut deq) => {
+                if let Some(d) = deq.pop_front() {
+                    if deq.is_empty() {
+                        Some((Ok(d), state.get_next()))
+                    } else {
+                        Some((Ok(d), state))
+                    }
+                } else {
+                    // New request returned empty.
+                    None
+                }
+            }
+            StateMode::Next(Some(_)) => {
+                let mut resp = state.mode.take_next();
+                if let Some(req) = resp.request.as_mut() {
+                    state.page_size = state.progress.size_page(req, state.page_size);
+                }
+                let f = resp.get_next_with_timeout(state.client, state.token, state.timeout);
+                let resp = match f.await {
+                    Ok(Some(resp)) => resp,
+                    Ok(None) => return None,
+                    Err(e) => return Some((Err(e), state.failed())),
+                };
+                state.progress.fetched(&resp);
+                let mut deq = fun(resp.data.clone());
+                deq.pop_front().map(|d| (Ok(d), state.process(resp, deq)))
+            }
+            _ => todo!("failed to process request"),
+        }
+    })
+    .boxed();
+    PaginatedStream { inner, progress }
+}
+
+/// Make a paginatable request with a list of items into a stream, like [`make_stream`] with [`VecDeque::from`](std::collections::VecDeque::from)
+pub(crate) fn make_list_stream<
+    'a,
+    C: crate::HttpClient + Send + Sync,
+    T: TwitchToken + Send + Sync + ?Sized,
+    Req: super::Request<Response = Vec<Item>>
+        + super::RequestGet
+        + super::Paginated
+        + Clone
+        + std::fmt::Debug
+        + Send
+        + Sync
+        + 'a,
+    Item: serde::de::DeserializeOwned + PartialEq + Clone + std::fmt::Debug + Send + Sync + 'a,
+>(
+    req: Req,
+    token: &'a T,
+    client: &'a super::HelixClient<'a, C>,
+) -> PaginatedStream<'a, Item, ClientError<C>> {
+    make_list_stream_with_timeout(req, token, client, None)
+}
+
+/// Make a paginatable request with a list of items into a stream like [`make_list_stream`], timing out every request after `timeout` if given
+///
+/// With the `stream-parse` feature, every page is parsed item by item straight into the queue the stream yields from.
+/// This skips the intermediate [`Response`](super::Response) and the copy of its data that [`make_stream_with_timeout`] keeps to request the next page,
+/// which adds up for endpoints like [Get Chatters](super::chat::GetChattersRequest) that return about a megabyte per page.
+pub(crate) fn make_list_stream_with_timeout<
+    'a,
+    C: crate::HttpClient + Send + Sync,
+    T: TwitchToken + Send + Sync + ?Sized,
+    Req: super::Request<Response = Vec<Item>>
+        + super::RequestGet
+        + super::Paginated
+        + Clone
+        + std::fmt::Debug
+        + Send
+        + Sync
+        + 'a,
+    Item: serde::de::DeserializeOwned + PartialEq + Clone + std::fmt::Debug + Send + Sync + 'a,
+>(
+    req: Req,
+    token: &'a T,
+    client: &'a super::HelixClient<'a, C>,
+    timeout: Option<std::time::Duration>,
+) -> PaginatedStream<'a, Item, ClientError<C>> {
+    #[cfg(not(feature = "stream-parse"))]
+    {
+        make_stream_with_timeout(
+            req,
+            token,
+            client,
+            timeout,
+            std::collections::VecDeque::from,
+        )
+    }
+    #[cfg(feature = "stream-parse")]
+    {
+        struct State<'a, C: crate::HttpClient, T: ?Sized, Req, Item> {
+            client: &'a HelixClient<'a, C>,
+            token: &'a T,
+            timeout: Option<std::time::Duration>,
+            /// The request for the next page, [`None`] once there are no more pages
+            next: Option<Req>,
+            /// Cursor and digest of the last page
+            last: Option<(Option<helix::Cursor>, u64)>,
+            total: Option<i64>,
+            items: std::collections::VecDeque<Item>,
+            progress: PaginationProgress,
+            page_size: Option<usize>,
+        }
+
+        let progress = PaginationProgress::default();
+        let state = State {
+            client,
+            token,
+            timeout,
+            next: Some(req),
+            last: None,
+            total: None,
+            items: std::collections::VecDeque::new(),
+            progress: progress.clone(),
+            page_size: None,
+        };
+        let inner = futures::stream::unfold(state, |mut state| async move {
+            if let Some(item) = state.items.pop_front() {
+                return Some((Ok(item), state));
+            }
+            let mut req = state.next.take()?;
+            state.page_size = state.progress.size_page(&mut req, state.page_size);
+            let page: Result<super::request::ListPage<Item>, ClientError<C>> = async {
+                use super::HelixAuth as _;
+
+                state.client.validate(&req, state.token)?;
+                let request = req.create_request(
+                    state.token.auth_bearer().unwrap_or_default(),
+                    state.token.auth_client_id(),
+                )?;
+                let uri = request.uri().clone();
+                let response = state
+                    .client
+                    .send_get::<Req, T>(request, state.timeout, state.token)
+                    .await?;
+                super::request::parse_list_page::<Item, _>(&uri, response)
+                    .map_err(ClientRequestError::from)
+            }
+            .await;
+            let page = match page {
+                Ok(page) => page,
+                Err(e) => return Some((Err(e), state)),
+            };
+            // Same workarounds as in `Response::get_next`, see there
+            if matches!(state.last, Some((_, digest)) if digest == page.digest) {
+                return None;
+            }
+            let mut cursor = page.pagination;
+            if matches!(&state.last, Some((last, _)) if *last == cursor) {
+                cursor = None;
+            }
+            state.total = page.total.or(state.total);
+            state.progress.record(state.total, &cursor);
+            if let Some(cursor) = &cursor {
+                req.set_pagination(Some(cursor.clone()));
+                state.next = Some(req);
+            }
+            state.last = Some((cursor, page.digest));
+            state.items = page.data;
+            state.items.pop_front().map(|item| (Ok(item), state))
+        })
+        .boxed();
+        PaginatedStream { inner, progress }
+    }
+}
+
+/// Write the items of `stream` into `sink`, in batches of `batch_size` items
+///
+/// Every batch is [sent](futures::SinkExt::send) and flushed before the stream is polled again,
+/// so while the sink is busy, no more pages are fetched. An incomplete last batch is sent at the end.
+/// A `batch_size` of `0` is treated as `1`.
+///
+/// Returns the amount of items written. Errors from the stream and from the sink are returned as [`DrainError::Api`] and [`DrainError::Sink`],
+/// both stop the stream, the items of an incomplete batch are dropped on an error.
+///
+/// # Examples
+///
+/// ```rust, no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+/// use twitch_api::helix;
+///
+/// let req = helix::moderation::GetBannedUsersRequest::broadcaster_id("1234");
+/// let stream = helix::make_stream(req, &token, &client, std::collections::VecDeque::from);
+/// // a sink from an async closure, e.g. writing into a database
+/// let sink = futures::sink::unfold((), |(), batch: Vec<helix::moderation::BannedUser>| async move {
+///     println!("writing {} banned users", batch.len());
+///     Ok::<_, std::io::Error>(())
+/// });
+/// let written = helix::drain_to_sink(stream, sink, 100).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn drain_to_sink<S, Si, Item, E>(
+    stream: S,
+    sink: Si,
+    batch_size: usize,
+) -> Result<usize, DrainError<E, Si::Error>>
+where
+    S: futures::Stream<Item = Result<Item, E>>,
+    Si: futures::Sink<Vec<Item>>,
+{
+    use futures::SinkExt as _;
+
+    let batch_size = batch_size.max(1);
+    futures::pin_mut!(stream, sink);
+    let mut written = 0;
+    let mut batch = Vec::with_capacity(batch_size);
+    while let Some(item) = stream.next().await {
+        batch.push(item.map_err(DrainError::Api)?);
+        if batch.len() == batch_size {
+            let full = std::mem::replace(&mut batch, Vec::with_capacity(batch_size));
+            sink.send(full).await.map_err(DrainError::Sink)?;
+            written += batch_size;
+        }
+    }
+    if !batch.is_empty() {
+        written += batch.len();
+        sink.send(batch).await.map_err(DrainError::Sink)?;
+    }
+    Ok(written)
+}
+
+/// Error returned by [`drain_to_sink`]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum DrainError<E, SE> {
+    /// The stream failed, e.g. a page could not be fetched
+    #[error("could not get the next item")]
+    Api(#[source] E),
+    /// The sink failed to take a batch
+    #[error("could not write the batch")]
+    Sink(#[source] SE),
+}
+
+/// A stream of items from a paginated request, returned by [`make_stream`]
+///
+/// If the stream yields an error, no more items are returned. The progress made before the error is still available
+/// with [`pages_fetched`](PaginatedStream::pages_fetched) and [`last_cursor`](PaginatedStream::last_cursor),
+/// or with a [`PaginationProgress`] from [`progress`](PaginatedStream::progress) if the stream is consumed.
+pub struct PaginatedStream<'a, Item, E> {
+    inner: std::pin::Pin<Box<dyn futures::Stream<Item = Result<Item, E>> + 'a + Send>>,
+    progress: PaginationProgress,
+}
+
+impl<Item, E> PaginatedStream<'_, Item, E> {
+    /// Total amount of items the request would return if fully paginated, as reported by the first page.
+    ///
+    /// [`None`](Option::None) if no page has been fetched yet or the endpoint doesn't return a total.
+    pub fn total(&self) -> Option<i64> { self.progress.total() }
+
+    /// Amount of pages that have been fetched successfully, see [`PaginationProgress::pages_fetched`]
+    pub fn pages_fetched(&self) -> usize { self.progress.pages_fetched() }
+
+    /// Cursor returned by the last fetched page, see [`PaginationProgress::last_cursor`]
+    pub fn last_cursor(&self) -> Option<helix::Cursor> { self.progress.last_cursor() }
+
+    /// Hint that only about `items` items will be read from this stream, set this before the stream is polled.
+    ///
+    /// The first page then requests `items` items, and every following page twice as many as the page before,
+    /// so taking a few items doesn't fetch a full page while reading on still needs few requests.
+    /// Requests that can't change their page size with [`Paginated::set_first`](helix::Paginated::set_first) ignore this.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    /// use futures::{StreamExt, TryStreamExt};
+    ///
+    /// // requests 5 categories instead of 100
+    /// let categories: Vec<helix::search::Category> = client
+    ///     .search_categories("Fortnite", None, &token)
+    ///     .expect_items(5)
+    ///     .take(5)
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn expect_items(self, items: usize) -> Self {
+        self.progress
+            .inner
+            .lock()
+            .expect("lock is poisoned")
+            .expected_items = Some(items);
+        self
+    }
+
+    /// Get a handle to the progress of this stream, which stays readable after the stream is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix::{self, Paginated as _};
+    /// use futures::TryStreamExt;
+    ///
+    /// let req = helix::moderation::GetModeratorsRequest::broadcaster_id("1234");
+    /// let stream = helix::make_stream(req.clone(), &token, &client, std::collections::VecDeque::from);
+    /// let progress = stream.progress();
+    /// if let Err(e) = stream.try_collect::<Vec<_>>().await {
+    ///     eprintln!("failed after {} pages: {e}", progress.pages_fetched());
+    ///     // continue where the stream stopped
+    ///     let mut req = req;
+    ///     req.set_pagination(progress.last_cursor());
+    ///     let rest: Vec<_> = helix::make_stream(req, &token, &client, std::collections::VecDeque::from)
+    ///         .try_collect()
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn progress(&self) -> PaginationProgress { self.progress.clone() }
+}
+
+/// Progress of a [`PaginatedStream`]
+///
+/// Cloning this gives a handle to the same progress.
+#[derive(Clone, Default)]
+pub struct PaginationProgress {
+    inner: std::sync::Arc<std::sync::Mutex<PaginationProgressInner>>,
+}
+
+#[derive(Default)]
+struct PaginationProgressInner {
+    total: Option<i64>,
+    pages_fetched: usize,
+    last_cursor: Option<helix::Cursor>,
+    expected_items: Option<usize>,
+}
+
+impl PaginationProgress {
+    fn fetched<R: super::Request, D: serde::de::DeserializeOwned + PartialEq>(
+        &self,
+        response: &super::Response<R, D>,
+    ) {
+        self.record(response.total, &response.pagination);
+    }
+
+    /// Record a fetched page with `total` and `cursor`
+    fn record(&self, total: Option<i64>, cursor: &Option<helix::Cursor>) {
+        let mut inner = self.inner.lock().expect("lock is poisoned");
+        inner.total = total;
+        inner.pages_fetched += 1;
+        inner.last_cursor.clone_from(cursor);
+    }
+
+    /// Size the page `req` requests for the [expected amount of items](PaginatedStream::expect_items), returning the new page size
+    ///
+    /// The first page requests the expected amount, every following page twice as many as `last`.
+    /// Does nothing if no amount of items is expected.
+    fn size_page<Req: super::Paginated>(
+        &self,
+        req: &mut Req,
+        last: Option<usize>,
+    ) -> Option<usize> {
+        let expected = self
+            .inner
+            .lock()
+            .expect("lock is poisoned")
+            .expected_items?;
+        let first = last.map_or(expected.max(1), |last| last.saturating_mul(2));
+        req.set_first(first);
+        Some(first)
+    }
+
+    /// Copy the progress of `other` into this progress
+    fn mirror(&self, other: &PaginationProgress) {
+        let other = other.inner.lock().expect("lock is poisoned");
+        let mut inner = self.inner.lock().expect("lock is poisoned");
+        inner.total = other.total;
+        inner.pages_fetched = other.pages_fetched;
+        inner.last_cursor.clone_from(&other.last_cursor);
+    }
+
+    /// Total amount of items the request would return if fully paginated, see [`PaginatedStream::total`]
+    pub fn total(&self) -> Option<i64> { self.inner.lock().expect("lock is poisoned").total }
+
+    /// Amount of pages that have been fetched successfully.
+    ///
+    /// If the stream failed with this being `0`, the first request failed. Otherwise, fetching the next page failed.
+    pub fn pages_fetched(&self) -> usize {
+        self.inner.lock().expect("lock is poisoned").pages_fetched
+    }
+
+    /// Cursor returned by the last fetched page.
+    ///
+    /// Set this on the request with [`Paginated::set_pagination`](helix::Paginated::set_pagination) to continue after the last fetched page.
+    /// [`None`](Option::None) if no page has been fetched yet or the last page had no cursor.
+    pub fn last_cursor(&self) -> Option<helix::Cursor> {
+        self.inner
+            .lock()
+            .expect("lock is poisoned")
+            .last_cursor
+            .clone()
+    }
+}
+
+impl std::fmt::Debug for PaginationProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let inner = self.inner.lock().expect("lock is poisoned");
+        f.debug_struct("PaginationProgress")
+            .field("total", &inner.total)
+            .field("pages_fetched", &inner.pages_fetched)
+            .field("last_cursor", &inner.last_cursor)
+            .finish()
+    }
+}
+
+impl<'a, Item: 'a, E: 'a> PaginatedStream<'a, Item, E> {
+    /// Transform the items of this stream, keeping its progress
+    pub(crate) fn map_stream<I2, E2, S>(
+        self,
+        f: impl FnOnce(std::pin::Pin<Box<dyn futures::Stream<Item = Result<Item, E>> + 'a + Send>>) -> S,
+    ) -> PaginatedStream<'a, I2, E2>
+    where
+        S: futures::Stream<Item = Result<I2, E2>> + Send + 'a,
+    {
+        PaginatedStream {
+            inner: f(self.inner).boxed(),
+            progress: self.progress,
+        }
+    }
+
+    /// Keep `progress` up to date with the progress of this stream, for streams that continue another stream
+    fn reporting_to(
+        mut self,
+        progress: PaginationProgress,
+    ) -> impl futures::Stream<Item = Result<Item, E>> + Send + 'a {
+        futures::stream::poll_fn(move |cx| {
+            let poll = self.poll_next_unpin(cx);
+            if poll.is_ready() {
+                progress.mirror(&self.progress);
+            }
+            poll
+        })
+    }
+}
+
+impl<Item, E> futures::Stream for PaginatedStream<'_, Item, E> {
+    type Item = Result<Item, E>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl<Item, E> std::fmt::Debug for PaginatedStream<'_, Item, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PaginatedStream")
+            .field("progress", &self.progress)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Checks that the helpers defined with `helix_helper!` keep their signatures
+#[cfg(test)]
+fn _helix_helper_signatures<'c, C, T>(
+    client: &'c HelixClient<'c, C>,
+    ids: &'c types::Collection<'c, types::UserId>,
+    token: &'c T,
+) where
+    C: crate::HttpClient + Sync,
+    T: TwitchToken + Send + Sync,
+{
+    fn stream<I, E, S: futures::Stream<Item = Result<I, E>> + Send + Unpin>(_: S) {}
+    fn future<O, F: std::future::Future<Output = O> + Send>(_: F) {}
+
+    stream::<helix::chat::Chatter, ClientExtError<C, helix::chat::ChattersError>, _>(
+        client.get_chatters("1234", "4321", 1000, token),
+    );
+    let ctx = helix::moderation::ModerationContext::new("1234", "4321");
+    stream::<helix::chat::Chatter, ClientExtError<C, helix::chat::ChattersError>, _>(
+        client.get_chatters_ctx(&ctx, 1000, token),
+    );
+    future::<
+        Result<
+            helix::moderation::BanUser,
+            ClientExtError<C, helix::moderation::ModerationActionError>,
+        >,
+        _,
+    >(client.ban_user_ctx(&ctx, "5678", "spam", None, token));
+    future::<Result<helix::moderation::DeleteChatMessagesResponse, ClientError<C>>, _>(
+        client.delete_chat_message_ctx(&ctx, "abc-123", token),
+    );
+    future::<
+        Result<helix::moderation::BanUser, ClientExtError<C, helix::moderation::BanUserBodyError>>,
+        _,
+    >(client.timeout_user_ctx(
+        &ctx,
+        "5678",
+        "spam",
+        std::time::Duration::from_secs(10),
+        token,
+    ));
+    stream::<types::UserName, ClientError<C>, _>(
+        client.get_chatter_logins("1234", None, 1000, token),
+    );
+    future::<Result<helix::users::User, ClientExtError<C, UserFromLoginError>>, _>(
+        client.get_user_from_login_required("twitchdev", token),
+    );
+    future::<Result<helix::channels::ChannelInformation, ClientExtError<C, NotFoundError>>, _>(
+        client.get_channel_from_id_required("1234", token),
+    );
+    future::<Result<helix::games::Game, ClientExtError<C, NotFoundError>>, _>(
+        client.get_game_by_id_required("509658", token),
+    );
+    future::<Result<Vec<helix::chat::GlobalEmote>, ClientError<C>>, _>(
+        client.get_global_emotes(token),
+    );
+    future::<Result<Vec<helix::chat::ChannelEmote>, ClientError<C>>, _>(
+        client.get_channel_emotes_from_id("1234", token),
+    );
+    future::<Result<helix::Conditional<Vec<helix::chat::GlobalEmote>>, ClientError<C>>, _>(
+        client.get_global_emotes_if_modified(None, token),
+    );
+    future::<
+        Result<
+            Option<Vec<helix::chat::ChannelEmote>>,
+            ClientExtError<C, crate::extra::InvalidLoginError>,
+        >,
+        _,
+    >(client.get_channel_emotes_from_login("twitchdev", token));
+    future::<Result<Option<helix::chat::UserChatColor>, ClientError<C>>, _>(
+        client.get_user_chat_color("1234", token),
+    );
+    stream::<helix::chat::UserChatColor, ClientError<C>, _>(
+        client.get_users_chat_colors(ids, token),
+    );
+    future::<Result<helix::Conditional<Vec<helix::chat::ChannelEmote>>, ClientError<C>>, _>(
+        client.get_channel_emotes_from_id_if_modified("1234", None, token),
+    );
+    future::<
+        Result<
+            std::collections::BTreeMap<helix::chat::EmoteUnlock, Vec<helix::chat::ChannelEmote>>,
+            ClientError<C>,
+        >,
+        _,
+    >(client.get_channel_emotes_by_tier("1234", token));
+    future::<Result<helix::chat::ChatSettings, ClientError<C>>, _>(client.get_chat_settings(
+        "1234",
+        Some::<&types::UserIdRef>("4321".into()),
+        token,
+    ));
+    future::<
+        Result<
+            helix::chat::SendChatAnnouncementResponse,
+            ClientExtError<C, helix::chat::SendChatAnnouncementBodyError<std::convert::Infallible>>,
+        >,
+        _,
+    >(client.send_chat_announcement(
+        "1234",
+        "4321",
+        "hello",
+        helix::chat::AnnouncementColor::Blue,
+        token,
+    ));
+    future::<
+        Result<
+            helix::chat::SendChatMessageResponse,
+            ClientExtError<C, helix::chat::MessageTooLongError>,
+        >,
+        _,
+    >(client.send_chat_message("1234", "4321", "hello", token));
+    future::<
+        Result<
+            helix::chat::SendChatMessageResponse,
+            ClientExtError<C, helix::chat::SendChatMessageError>,
+        >,
+        _,
+    >(client.send_chat_message_with_retry(
+        "1234",
+        "4321",
+        "hello",
+        helix::chat::ChatMessageRetry::default(),
+        |_| async {},
+        token,
+    ));
+    future::<Result<helix::chat::UpdateUserChatColorResponse, ClientError<C>>, _>(
+        client.update_user_chat_color("1234", types::NamedUserColor::Blue, token),
+    );
+
+    stream::<helix::moderation::Moderator, ClientError<C>, _>(
+        client.get_moderators_in_channel_from_id("1234", None, token),
+    );
+    stream::<helix::moderation::BannedUser, ClientError<C>, _>(
+        client.get_banned_users_in_channel_from_id("1234", token),
+    );
+    future::<
+        Result<
+            helix::moderation::BanUser,
+            ClientExtError<C, helix::moderation::ModerationActionError>,
+        >,
+        _,
+    >(client.ban_user("1", "reason", 10, "1234", "4321", token));
+    future::<
+        Result<helix::moderation::BanUser, ClientExtError<C, helix::moderation::BanUserBodyError>>,
+        _,
+    >(client.timeout_user(
+        "1",
+        "reason",
+        std::time::Duration::from_secs(10),
+        "1234",
+        "4321",
+        token,
+    ));
+    future::<
+        Result<
+            helix::moderation::UnbanUserResponse,
+            ClientExtError<C, helix::moderation::ModerationActionError>,
+        >,
+        _,
+    >(client.unban_user("1", "1234", "4321", token));
+    #[cfg(feature = "beta")]
+    future::<Result<helix::moderation::WarnChatUser, ClientError<C>>, _>(
+        client.warn_chat_user("1", "reason", "1234", "4321", token),
+    );
+    future::<Result<helix::moderation::DeleteChatMessagesResponse, ClientError<C>>, _>(
+        client.delete_chat_message("1234", "4321", "abc-123", token),
+    );
+    future::<Result<helix::moderation::DeleteChatMessagesResponse, ClientError<C>>, _>(
+        client.delete_all_chat_message("1234", "4321", token),
+    );
+    future::<Result<std::collections::HashMap<types::MsgId, bool>, ClientError<C>>, _>(
+        client.check_messages_automod("1234", [("abc-123", "hello")], token),
+    );
+    future::<
+        Result<
+            helix::moderation::AutoModEnforcement,
+            ClientExtError<C, helix::moderation::BanUserBodyError>,
+        >,
+        _,
+    >(client.enforce_automod(
+        "1234",
+        "4321",
+        "abc-123",
+        "hello",
+        "1",
+        helix::moderation::EnforcementAction::DeleteOnly,
+        token,
+    ));
+    future::<
+        Result<
+            helix::moderation::AddChannelModeratorResponse,
+            ClientExtError<C, helix::moderation::ModerationActionError>,
+        >,
+        _,
+    >(client.add_channel_moderator("1234", "1", token));
+    future::<
+        Result<
+            helix::moderation::RemoveChannelModeratorResponse,
+            ClientExtError<C, helix::moderation::ModerationActionError>,
+        >,
+        _,
+    >(client.remove_channel_moderator("1234", "1", token));
+    #[cfg(feature = "time")]
+    {
+        let ids = Box::leak(Box::new(types::Collection::from(&["1234"][..])));
+        let now = types::Timestamp::now();
+        future::<
+            Result<
+                Vec<(types::UserId, helix::schedule::Segment)>,
+                MergedScheduleError<ClientError<C>>,
+            >,
+            _,
+        >(client.get_merged_schedules(ids, (now.clone(), now), token));
+    }
+    #[cfg(feature = "eventsub")]
+    stream::<crate::eventsub::EventSubSubscription, ClientError<C>, _>(
+        client.get_failed_eventsub_subscriptions(token),
+    );
+}
+
+#[cfg(all(test, feature = "client"))]
+mod token_health_tests {
+    use super::*;
+    use helix::Request;
+
+    const REQUIRED: &[twitch_oauth2::Validator] = &[
+        helix::chat::GetChattersRequest::SCOPE,
+        helix::moderation::BanUserRequest::SCOPE,
+    ];
+
+    fn validated(scopes: &str, expires_in: u64) -> String {
+        format!(
+            r#"{{"client_id":"wbmytr93xzw8zbg0p1izqyzzc5mbiz","login":"twitchdev","scopes":[{scopes}],"user_id":"141981764","expires_in":{expires_in}}}"#
+        )
+    }
+
+    const INVALID: &str = r#"{"status":401,"message":"invalid access token"}"#;
+
+    #[tokio::test]
+    async fn healthy() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "/oauth2/validate",
+            200,
+            &validated(
+                r#""moderator:read:chatters","moderator:manage:banned_users""#,
+                5520838,
+            ),
+        );
+        let token = crate::tests::user_token("twitchdev", "141981764");
+
+        let health = mock
+            .helix()
+            .validate_token_health(&token, REQUIRED)
+            .await
+            .unwrap();
+        assert!(health.is_healthy(), "{health:?}");
+        assert_eq!(health.login().map(|l| l.as_str()), Some("twitchdev"));
+        assert_eq!(health.user_id().map(|id| id.as_str()), Some("141981764"));
+        assert_eq!(
+            health.expires_in(),
+            Some(std::time::Duration::from_secs(5520838))
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_scopes() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "/oauth2/validate",
+            200,
+            &validated(r#""moderator:read:chatters""#, 5520838),
+        );
+        let token = crate::tests::user_token("twitchdev", "141981764");
+
+        let health = mock
+            .helix()
+            .validate_token_health(&token, REQUIRED)
+            .await
+            .unwrap();
+        let TokenHealth::MissingScopes { missing, .. } = &health else {
+            panic!("expected missing scopes, got {health:?}")
+        };
+        assert_eq!(missing.len(), 1);
+        assert!(missing[0].matches(&[twitch_oauth2::Scope::ModeratorManageBannedUsers]));
+        assert!(health.token().is_some());
+    }
+
+    #[tokio::test]
+    async fn expired() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("/oauth2/validate", 401, INVALID);
+        let token = crate::tests::user_token("twitchdev", "141981764");
+
+        let health = mock
+            .helix()
+            .validate_token_health(&token, REQUIRED)
+            .await
+            .unwrap();
+        assert!(matches!(health, TokenHealth::Invalid));
+        assert_eq!(health.expires_in(), None);
+    }
+
+    #[tokio::test]
+    async fn watch() {
+        let mock = crate::tests::MockClient::new();
+        let scopes = r#""moderator:read:chatters","moderator:manage:banned_users""#;
+        mock.respond("/oauth2/validate", 200, &validated(scopes, 3900))
+            .respond("/oauth2/validate", 200, &validated(scopes, 60))
+            .respond("/oauth2/validate", 401, INVALID);
+        let token = crate::tests::user_token("twitchdev", "141981764");
+
+        let slept = std::sync::Mutex::new(vec![]);
+        let mut warnings = vec![];
+        mock.helix()
+            .watch_token_health(
+                &token,
+                REQUIRED,
+                std::time::Duration::from_secs(3600),
+                std::time::Duration::from_secs(600),
+                |d| {
+                    slept.lock().unwrap().push(d);
+                    futures::future::ready(())
+                },
+                |health| warnings.push(health.expires_in()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            warnings,
+            vec![Some(std::time::Duration::from_secs(60)), None]
+        );
+        // waits until the warning is due, then the full interval
+        assert_eq!(
+            *slept.lock().unwrap(),
+            vec![
+                std::time::Duration::from_secs(3300),
+                std::time::Duration::from_secs(3600)
+            ]
+        );
+        assert_eq!(mock.pending(), 0);
+    }
+}
+
+#[cfg(all(test, feature = "client"))]
+mod login_tests {
+    use super::*;
+
+    const USER: &str = r#"{"data":[{"id":"141981764","login":"twitchdev","display_name":"TwitchDev","type":"","broadcaster_type":"partner","description":"","profile_image_url":"","offline_image_url":"","view_count":0,"created_at":"2016-12-14T20:32:28.894263Z"}]}"#;
+    const NO_USER: &str = r#"{"data":[]}"#;
+
+    fn assert_user_lookup(mock: &crate::tests::MockClient, login: &str) {
+        let requests = mock.requests();
+        assert_eq!(requests[0].1.path(), "/helix/users");
+        assert_eq!(requests[0].1.query(), Some(&*format!("login={login}")));
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn user_from_login() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("users", 200, USER)
+            .respond("users", 200, NO_USER);
+        let token = crate::tests::user_token("twitchdev", "141981764");
+        let client = mock.helix();
+
+        let user = client.get_user_from_login("@TwitchDev", &token).await;
+        assert_eq!(user.unwrap().unwrap().id.as_str(), "141981764");
+        assert!(client
+            .get_user_from_login("nobody", &token)
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(mock.requests()[1].1.query(), Some("login=nobody"));
+        assert_user_lookup(&mock, "twitchdev");
+    }
+
+    #[tokio::test]
+    async fn channel_from_login() {
+        let channel = r#"{"data":[{"broadcaster_id":"141981764","broadcaster_login":"twitchdev","broadcaster_name":"TwitchDev","broadcaster_language":"en","game_id":"509670","game_name":"Science & Technology","title":"TwitchDev Monthly Update","delay":0,"tags":[],"content_classification_labels":[],"is_branded_content":false}]}"#;
+        let mock = crate::tests::MockClient::new();
+        mock.respond("users", 200, USER)
+            .respond("channels", 200, channel)
+            .respond("users", 200, USER)
+            .respond("channels", 200, channel)
+            .respond("users", 200, NO_USER)
+            .respond("users", 200, NO_USER);
+        let token = crate::tests::user_token("twitchdev", "141981764");
+        let client = mock.helix();
+
+        let info = client
+            .get_channel_from_login("@TwitchDev", &token)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.broadcaster_id.as_str(), "141981764");
+        let info = client
+            .get_channel_from_login_raw("twitchdev", &token)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.broadcaster_id.as_str(), "141981764");
+        assert!(client
+            .get_channel_from_login("nobody", &token)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(client
+            .get_channel_from_login_raw("nobody", &token)
+            .await
+            .unwrap()
+            .is_none());
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 6);
+        assert_eq!(requests[1].1.query(), Some("broadcaster_id=141981764"));
+        assert_user_lookup(&mock, "twitchdev");
+    }
+
+    #[test]
+    fn not_found_display() {
+        let e = NotFoundError {
+            resource: "user",
+            identifier: "xyz".to_owned(),
+        };
+        assert_eq!(e.to_string(), "user 'xyz' not found");
+        assert_eq!(
+            UserFromLoginError::from(e).to_string(),
+            "user 'xyz' not found"
+        );
+    }
+
+    #[tokio::test]
+    async fn required_makes_the_same_request() {
+        let channel = r#"{"data":[{"broadcaster_id":"141981764","broadcaster_login":"twitchdev","broadcaster_name":"TwitchDev","broadcaster_language":"en","game_id":"509670","game_name":"Science & Technology","title":"TwitchDev Monthly Update","delay":0,"tags":[],"content_classification_labels":[],"is_branded_content":false}]}"#;
+        let game =
+            r#"{"data":[{"id":"509658","name":"Just Chatting","box_art_url":"","igdb_id":""}]}"#;
+        let mock = crate::tests::MockClient::new();
+        mock.respond("users", 200, USER)
+            .respond("users", 200, USER)
+            .respond("users", 200, NO_USER)
+            .respond("channels", 200, channel)
+            .respond("channels", 200, channel)
+            .respond("channels", 200, NO_USER)
+            .respond("games", 200, game)
+            .respond("games", 200, game)
+            .respond("games", 200, NO_USER);
+        let token = crate::tests::user_token("twitchdev", "141981764");
+        let client = mock.helix();
+
+        let user = client.get_user_from_login("@TwitchDev", &token).await;
+        let required = client
+            .get_user_from_login_required("@TwitchDev", &token)
+            .await;
+        assert_eq!(user.unwrap().unwrap(), required.unwrap());
+        assert!(matches!(
+            client.get_user_from_login_required("nobody", &token).await,
+            Err(ClientExtError::Other(UserFromLoginError::NotFound(NotFoundError { resource: "user", identifier }))) if identifier == "nobody"
+        ));
+        assert!(matches!(
+            client
+                .get_user_from_login_required("not a login", &token)
+                .await,
+            Err(ClientExtError::Other(UserFromLoginError::InvalidLogin(_)))
+        ));
+
+        let info = client.get_channel_from_id("141981764", &token).await;
+        let required = client
+            .get_channel_from_id_required("141981764", &token)
+            .await;
+        assert_eq!(info.unwrap().unwrap(), required.unwrap());
+        let Err(ClientExtError::Other(e)) = client.get_channel_from_id_required("1", &token).await
+        else {
+            panic!("expected a not found error");
+        };
+        assert_eq!(e.to_string(), "channel '1' not found");
+
+        let found = client.get_game_by_id("509658", &token).await;
+        let required = client.get_game_by_id_required("509658", &token).await;
+        assert_eq!(found.unwrap().unwrap(), required.unwrap());
+        let Err(ClientExtError::Other(e)) = client.get_game_by_id_required("1", &token).await
+        else {
+            panic!("expected a not found error");
+        };
+        assert_eq!(e.to_string(), "game '1' not found");
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 9);
+        for (optional, required) in [(0, 1), (3, 4), (6, 7)] {
+            assert_eq!(requests[optional].0, requests[required].0);
+            assert_eq!(requests[optional].1, requests[required].1);
+        }
+        assert_eq!(requests[5].1.query(), Some("broadcaster_id=1"));
+        assert_eq!(requests[8].1.query(), Some("id=1"));
+        assert_user_lookup(&mock, "twitchdev");
+    }
+
+    #[tokio::test]
+    async fn channel_emotes_from_login() {
+        let emotes = r#"{"data":[{"id":"304456832","name":"twitchdevPitchfork","images":{"url_1x":"","url_2x":"","url_4x":""},"tier":"1000","emote_type":"subscriptions","emote_set_id":"301590448","format":["static"],"scale":["1.0"],"theme_mode":["light"]}],"template":"https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}"}"#;
+        let mock = crate::tests::MockClient::new();
+        mock.respond("users", 200, USER)
+            .respond("chat/emotes", 200, emotes)
+            .respond("users", 200, NO_USER);
+        let token = crate::tests::user_token("twitchdev", "141981764");
+        let client = mock.helix();
+
+        let emotes = client
+            .get_channel_emotes_from_login("TwitchDev", &token)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(emotes[0].id.as_str(), "304456832");
+        assert!(client
+            .get_channel_emotes_from_login("nobody", &token)
+            .await
+            .unwrap()
+            .is_none());
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[1].1.query(), Some("broadcaster_id=141981764"));
+        assert_user_lookup(&mock, "twitchdev");
+    }
+
+    #[tokio::test]
+    async fn total_followers_from_login() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("users", 200, USER)
+            .respond(
+                "channels/followers",
+                200,
+                r#"{"total":12345,"data":[],"pagination":{}}"#,
+            )
+            .respond("users", 200, NO_USER);
+        let token = crate::tests::user_token_with_scopes(
+            "twitchdev",
+            "141981764",
+            vec![twitch_oauth2::Scope::ModeratorReadFollowers],
+        );
+        let client = mock.helix();
+
+        let total = client
+            .get_total_followers_from_login("twitchdev", &token)
+            .await
+            .unwrap();
+        assert_eq!(total, Some(12345));
+        assert!(client
+            .get_total_followers_from_login("nobody", &token)
+            .await
+            .unwrap()
+            .is_none());
+        // invalid logins fail without a request
+        assert!(matches!(
+            client.get_total_followers_from_login("@a", &token).await,
+            Err(ClientExtError::Other(
+                FollowersFromLoginError::InvalidLogin(_)
+            ))
+        ));
+        assert_eq!(mock.requests().len(), 3);
+        assert_user_lookup(&mock, "twitchdev");
+    }
+
+    #[tokio::test]
+    async fn total_followers_from_login_raw() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("users", 200, NO_USER);
+        let token = crate::tests::user_token_with_scopes(
+            "twitchdev",
+            "141981764",
+            vec![twitch_oauth2::Scope::ModeratorReadFollowers],
+        );
+
+        assert!(mock
+            .helix()
+            .get_total_followers_from_login_raw("TwitchDev", &token)
+            .await
+            .unwrap()
+            .is_none());
+        assert_user_lookup(&mock, "TwitchDev");
+    }
+}
+
+#[cfg(all(test, feature = "client"))]
+mod chunked_request_tests {
+    use super::*;
+
+    /// Returns the requested chunks and the merged results of `chunked_request` with chunks of 100
+    async fn request(items: &[&str]) -> (Vec<Vec<String>>, Vec<String>) {
+        let requested = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+        let chunks = requested.clone();
+        let results = chunked_request(
+            items.iter().copied(),
+            100,
+            |item: &String| item.as_str(),
+            move |chunk: Vec<String>| {
+                chunks.lock().unwrap().push(chunk.clone());
+                // answer in a different order than requested
+                async move { Ok::<_, std::convert::Infallible>(chunk.into_iter().rev().collect()) }
+            },
+        )
+        .try_collect()
+        .await
+        .unwrap();
+        let requested = requested.lock().unwrap().clone();
+        (requested, results)
+    }
+
+    fn ids(n: usize) -> Vec<String> { (0..n).map(|i| i.to_string()).collect() }
+
+    #[tokio::test]
+    async fn empty() {
+        let (requested, results) = request(&[]).await;
+        assert!(requested.is_empty());
+        assert!(results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn duplicates() {
+        let (requested, results) = request(&["a", "b", "a", "c", "b"]).await;
+        assert_eq!(requested, vec![vec!["a", "b", "c"]]);
+        assert_eq!(results, vec!["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn chunk_boundaries() {
+        for (n, sizes) in [(100, vec![100]), (101, vec![100, 1]), (200, vec![100, 100])] {
+            let ids = ids(n);
+            let items: Vec<&str> = ids.iter().map(String::as_str).collect();
+            let (requested, results) = request(&items).await;
+            assert_eq!(
+                requested.iter().map(Vec::len).collect::<Vec<_>>(),
+                sizes,
+                "{n} items"
+            );
+            assert_eq!(results, ids, "{n} items");
+        }
+    }
+
+    #[tokio::test]
+    async fn duplicates_across_chunks() {
+        let mut ids = ids(150);
+        ids.extend(ids.clone());
+        let items: Vec<&str> = ids.iter().map(String::as_str).collect();
+        let (requested, results) = request(&items).await;
+        assert_eq!(
+            requested.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![100, 50]
+        );
+        assert_eq!(results, ids[..150]);
+    }
+
+    #[tokio::test]
+    async fn users_from_ids() {
+        let mock = crate::tests::MockClient::new();
+        let token = crate::tests::user_token("user", "1");
+        let client = mock.helix();
+
+        let none: Vec<helix::users::User> = client
+            .get_users_from_ids(&types::Collection::EMPTY, &token)
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(none.is_empty());
+        assert!(mock.requests().is_empty());
+
+        let user = |id: &str| {
+            format!(
+                r#"{{"id":"{id}","login":"user{id}","display_name":"User{id}","type":"","broadcaster_type":"","description":"","profile_image_url":"","offline_image_url":"","view_count":0,"created_at":"2016-12-14T20:32:28.894263Z"}}"#
+            )
+        };
+        mock.respond(
+            "users",
+            200,
+            &format!(r#"{{"data":[{},{}]}}"#, user("2"), user("1")),
+        );
+        let ids: types::Collection<types::UserId> = ["1", "2", "1"][..].into();
+        let users: Vec<helix::users::User> = client
+            .get_users_from_ids(&ids, &token)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            users.iter().map(|u| u.id.as_str()).collect::<Vec<_>>(),
+            vec!["1", "2"]
+        );
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].1.query(), Some("id=1&id=2"));
+    }
+}
+
+#[cfg(all(test, feature = "client"))]
+mod enforce_automod_tests {
+    use super::*;
+    use helix::moderation::EnforcementAction;
 
-        self.req_patch(req, body, token)
+    const PERMITTED: &str = r#"{"data":[{"msg_id":"abc-123","is_permitted":true}]}"#;
+    const NOT_PERMITTED: &str = r#"{"data":[{"msg_id":"abc-123","is_permitted":false}]}"#;
+    const BAN: &str = r#"{"data":[{"broadcaster_id":"1234","moderator_id":"1234","user_id":"9876","created_at":"2021-09-28T19:27:31Z","end_time":"2021-09-28T19:28:31Z"}]}"#;
+
+    fn requests(mock: &crate::tests::MockClient) -> Vec<(http::Method, String)> {
+        mock.requests()
+            .into_iter()
+            .map(|(method, uri, _)| (method, uri.to_string()))
+            .collect()
+    }
+
+    async fn enforce(
+        mock: &crate::tests::MockClient,
+        action: EnforcementAction<'static>,
+    ) -> Result<
+        helix::moderation::AutoModEnforcement,
+        ClientExtError<crate::tests::MockClient, helix::moderation::BanUserBodyError>,
+    > {
+        let token = crate::tests::user_token("twitchdev", "1234");
+        mock.helix()
+            .enforce_automod("1234", "1234", "abc-123", "hello", "9876", action, &token)
             .await
-            .map(|response| response.data)
     }
-}
 
-/// Error type to combine a http client error with a other error
-#[derive(Debug, thiserror::Error)]
-pub enum ClientExtError<C: crate::HttpClient, E> {
-    /// Http client error
-    #[error(transparent)]
-    ClientError(ClientError<C>),
-    /// Other error
-    #[error(transparent)]
-    Other(#[from] E),
+    #[tokio::test]
+    async fn permitted() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("moderation/enforcements/status", 200, PERMITTED);
+
+        let outcome = enforce(
+            &mock,
+            EnforcementAction::DeleteAndTimeout {
+                seconds: 60,
+                reason: "AutoMod".into(),
+            },
+        )
+        .await
+        .unwrap();
+        assert!(outcome.verdict.is_permitted);
+        assert!(!outcome.deleted);
+        assert_eq!(outcome.timeout, None);
+        assert_eq!(
+            requests(&mock),
+            vec![(
+                http::Method::POST,
+                "https://api.twitch.tv/helix/moderation/enforcements/status?broadcaster_id=1234"
+                    .to_string()
+            )]
+        );
+        assert_eq!(
+            &*mock.requests()[0].2,
+            br#"{"data":[{"msg_id":"abc-123","msg_text":"hello"}]}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_only() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("moderation/enforcements/status", 200, NOT_PERMITTED)
+            .respond("moderation/chat", 204, "");
+
+        let outcome = enforce(&mock, EnforcementAction::DeleteOnly).await.unwrap();
+        assert!(!outcome.verdict.is_permitted);
+        assert!(outcome.deleted);
+        assert_eq!(outcome.timeout, None);
+        assert_eq!(requests(&mock), vec![
+            (
+                http::Method::POST,
+                "https://api.twitch.tv/helix/moderation/enforcements/status?broadcaster_id=1234"
+                    .to_string()
+            ),
+            (
+                http::Method::DELETE,
+                "https://api.twitch.tv/helix/moderation/chat?broadcaster_id=1234&moderator_id=1234&message_id=abc-123"
+                    .to_string()
+            ),
+        ]);
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn delete_and_timeout() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("moderation/enforcements/status", 200, NOT_PERMITTED)
+            .respond("moderation/chat", 204, "")
+            .respond("moderation/bans", 200, BAN);
+
+        let outcome = enforce(
+            &mock,
+            EnforcementAction::DeleteAndTimeout {
+                seconds: 60,
+                reason: "AutoMod".into(),
+            },
+        )
+        .await
+        .unwrap();
+        assert!(outcome.deleted);
+        assert_eq!(outcome.timeout.unwrap().user_id.as_str(), "9876");
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[1].0, http::Method::DELETE);
+        assert_eq!(requests[2].0, http::Method::POST);
+        assert_eq!(
+            requests[2].1.to_string(),
+            "https://api.twitch.tv/helix/moderation/bans?broadcaster_id=1234&moderator_id=1234"
+        );
+        assert_eq!(
+            &*requests[2].2,
+            br#"{"data":{"user_id":"9876","duration":60,"reason":"AutoMod"}}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_timeout() {
+        let mock = crate::tests::MockClient::new();
+
+        let err = enforce(
+            &mock,
+            EnforcementAction::DeleteAndTimeout {
+                seconds: 0,
+                reason: "AutoMod".into(),
+            },
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ClientExtError::Other(helix::moderation::BanUserBodyError::DurationOutOfRange(0))
+        ));
+        assert!(mock.requests().is_empty());
+    }
 }
 
-/// Make a paginate-able request into a stream
-///
-/// # Examples
-///
-/// ```rust, no_run
-/// # #[tokio::main]
-/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-/// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
-/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
-/// use twitch_api::helix;
-/// use futures::TryStreamExt;
-///
-/// let req = helix::moderation::GetModeratorsRequest::broadcaster_id("1234");
-///
-/// helix::make_stream(req, &token, &client, std::collections::VecDeque::from).try_collect::<Vec<_>>().await?
-/// # ;
-/// # Ok(())
-/// # }
-/// ```
-pub fn make_stream<
-    'a,
-    C: crate::HttpClient + Send + Sync,
-    T: TwitchToken + Send + Sync + ?Sized + Send + Sync,
-    // FIXME: Why does this have to be clone and debug?
-    Req: super::Request
-        + super::RequestGet
-        + super::Paginated
-        + Clone
-        + std::fmt::Debug
-        + Send
-        + Sync
-        + 'a,
-    // FIXME: this 'a seems suspicious
-    Item: Send + 'a,
->(
-    req: Req,
-    token: &'a T,
-    client: &'a super::HelixClient<'a, C>,
-    fun: impl Fn(<Req as super::Request>::Response) -> std::collections::VecDeque<Item>
-        + Send
-        + Sync
-        + Copy
-        + 'static,
-) -> std::pin::Pin<Box<dyn futures::Stream<Item = Result<Item, ClientError<C>>> + 'a + Send>>
-where
-    // FIXME: This clone is bad. I want to be able to return the data, but not in a way that limits the response to be Default
-    // I also want to keep allocations low, so std::mem::take is perfect, but that makes get_next not work optimally.
-    <Req as super::Request>::Response: Send + Sync + std::fmt::Debug + Clone,
-{
-    enum StateMode<Req: super::Request + super::RequestGet, Item> {
-        /// A request needs to be done.
-        Req(Option<Req>),
-        /// We have made a request, now working through the data
-        Cont(
-            super::Response<Req, <Req as super::Request>::Response>,
-            std::collections::VecDeque<Item>,
-        ),
-        Next(Option<super::Response<Req, <Req as super::Request>::Response>>),
-        /// The operation failed, allowing no further processing
-        Failed,
+#[cfg(all(test, feature = "client"))]
+#[deny(deprecated)]
+mod check_messages_automod_tests {
+    use super::*;
+
+    fn statuses(range: std::ops::Range<usize>) -> String {
+        let statuses: Vec<_> = range
+            .map(|i| format!(r#"{{"msg_id":"{i}","is_permitted":{}}}"#, i % 2 == 0))
+            .collect();
+        format!(r#"{{"data":[{}]}}"#, statuses.join(","))
     }
 
-    impl<Req: super::Request + super::RequestGet, Item> StateMode<Req, Item> {
-        fn take_initial(&mut self) -> Req {
-            match self {
-                StateMode::Req(ref mut r) if r.is_some() => std::mem::take(r).expect("oops"),
-                _ => todo!("hmmm"),
-            }
+    #[tokio::test]
+    async fn chunks() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("moderation/enforcements/status", 200, &statuses(0..100))
+            .respond("moderation/enforcements/status", 200, &statuses(100..150));
+        let token = crate::tests::user_token("twitchdev", "1234");
+        let messages: Vec<(String, String)> = (0..150)
+            .map(|i| (i.to_string(), format!("message {i}")))
+            .collect();
+
+        let permitted = mock
+            .helix()
+            .check_messages_automod(
+                "1234",
+                messages
+                    .iter()
+                    .map(|(id, text)| (id.as_str(), text.as_str())),
+                &token,
+            )
+            .await
+            .unwrap();
+        assert_eq!(permitted.len(), 150);
+        assert_eq!(
+            permitted.get(types::MsgIdRef::from_static("42")),
+            Some(&true)
+        );
+        assert_eq!(
+            permitted.get(types::MsgIdRef::from_static("149")),
+            Some(&false)
+        );
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        for (request, count) in requests.iter().zip([100, 50]) {
+            let body: serde_json::Value = serde_json::from_slice(&request.2).unwrap();
+            assert_eq!(body["data"].as_array().unwrap().len(), count);
         }
+        assert_eq!(mock.pending(), 0);
+    }
+}
 
-        fn take_next(&mut self) -> super::Response<Req, <Req as super::Request>::Response> {
-            match self {
-                StateMode::Next(ref mut r) if r.is_some() => std::mem::take(r).expect("oops"),
-                _ => todo!("hmmm"),
-            }
+#[cfg(all(test, feature = "client"))]
+mod list_stream_tests {
+    use super::*;
+
+    fn page(range: std::ops::Range<usize>, cursor: Option<&str>, total: Option<i64>) -> String {
+        let chatters: Vec<_> = range
+            .map(|i| {
+                format!(
+                    r#"{{"user_id":"{}","user_login":"chatter{i}","user_name":"Chatter{i}"}}"#,
+                    100_000_000 + i
+                )
+            })
+            .collect();
+        let pagination =
+            cursor.map_or_else(|| "{}".to_owned(), |c| format!(r#"{{"cursor":"{c}"}}"#));
+        let total = total.map_or_else(String::new, |t| format!(r#","total":{t}"#));
+        format!(
+            r#"{{"data":[{}],"pagination":{pagination}{total}}}"#,
+            chatters.join(",")
+        )
+    }
+
+    /// Stream chatters from `pages`, returning the chatters and the pages fetched, total and last cursor of the stream
+    async fn collect(
+        pages: &[String],
+        list: bool,
+    ) -> (
+        Vec<helix::chat::Chatter>,
+        usize,
+        Option<i64>,
+        Option<helix::Cursor>,
+    ) {
+        let mock = crate::tests::MockClient::new();
+        for page in pages {
+            mock.respond("chat/chatters", 200, page);
         }
+        let client = mock.helix();
+        let token = crate::tests::user_token("twitchdev", "1234");
+        let req = helix::chat::GetChattersRequest::new("1234", "1234").first(1000);
+        let stream = if list {
+            make_list_stream(req, &token, &client)
+        } else {
+            make_stream(req, &token, &client, std::collections::VecDeque::from)
+        };
+        let progress = stream.progress();
+        let chatters = stream.try_collect().await.unwrap();
+        (
+            chatters,
+            progress.pages_fetched(),
+            progress.total(),
+            progress.last_cursor(),
+        )
     }
 
-    struct State<
-        'a,
-        C: crate::HttpClient,
-        T: TwitchToken + Send + Sync + ?Sized,
-        Req: super::Request + super::RequestGet,
-        Item,
-    > {
-        mode: StateMode<Req, Item>,
-        client: &'a HelixClient<'a, C>,
-        token: &'a T,
+    async fn assert_equivalent(pages: &[String]) -> (usize, usize) {
+        let list = collect(pages, true).await;
+        let default = collect(pages, false).await;
+        assert_eq!(list, default);
+        (list.0.len(), list.1)
     }
 
-    impl<
-            'a,
-            C: crate::HttpClient,
-            T: TwitchToken + Send + Sync + ?Sized,
-            Req: super::Request + super::RequestGet + super::Paginated,
-            Item,
-        > State<'a, C, T, Req, Item>
-    {
-        /// Process a request, with a given deq
-        fn process(
-            mut self,
-            r: super::Response<Req, <Req as super::Request>::Response>,
-            d: std::collections::VecDeque<Item>,
-        ) -> Self {
-            self.mode = StateMode::Cont(r, d);
-            self
+    #[tokio::test]
+    async fn same_as_make_stream() {
+        let pages = [
+            page(0..1000, Some("a"), Some(2500)),
+            page(1000..2000, Some("b"), None),
+            page(2000..2500, None, None),
+        ];
+        assert_eq!(assert_equivalent(&pages).await, (2500, 3));
+        let (chatters, _, total, cursor) = collect(&pages, true).await;
+        assert_eq!(chatters[1234].user_login.as_str(), "chatter1234");
+        assert_eq!(total, Some(2500));
+        assert_eq!(cursor, None);
+    }
+
+    #[tokio::test]
+    async fn same_as_make_stream_on_workarounds() {
+        // the cursor that was just used
+        let pages = [
+            page(0..1000, Some("a"), None),
+            page(1000..2000, Some("a"), None),
+        ];
+        assert_eq!(assert_equivalent(&pages).await, (2000, 2));
+        // the page that was just returned
+        let pages = [
+            page(0..1000, Some("a"), None),
+            page(0..1000, Some("b"), None),
+        ];
+        assert_eq!(assert_equivalent(&pages).await, (1000, 1));
+        // an empty page
+        let pages = [page(0..1000, Some("a"), None), page(0..0, Some("b"), None)];
+        assert_eq!(assert_equivalent(&pages).await, (1000, 2));
+    }
+
+    /// Stream chatters from `pages` expecting `expected` items, returning the chatters read and the `first` of every request
+    async fn collect_expecting(
+        pages: &[String],
+        list: bool,
+        expected: usize,
+        take: usize,
+    ) -> (Vec<helix::chat::Chatter>, Vec<Option<String>>) {
+        let mock = crate::tests::MockClient::new();
+        for page in pages {
+            mock.respond("chat/chatters", 200, page);
         }
+        let client = mock.helix();
+        let token = crate::tests::user_token("twitchdev", "1234");
+        let req = helix::chat::GetChattersRequest::new("1234", "1234").first(100);
+        let stream = if list {
+            make_list_stream(req, &token, &client)
+        } else {
+            make_stream(req, &token, &client, std::collections::VecDeque::from)
+        };
+        let chatters = stream
+            .expect_items(expected)
+            .take(take)
+            .try_collect()
+            .await
+            .unwrap();
+        let firsts = mock
+            .requests()
+            .iter()
+            .map(|(_, uri, _)| {
+                uri.query()?
+                    .split('&')
+                    .find_map(|pair| pair.strip_prefix("first="))
+                    .map(str::to_owned)
+            })
+            .collect();
+        (chatters, firsts)
+    }
 
-        fn failed(mut self) -> Self {
-            self.mode = StateMode::Failed;
-            self
+    #[tokio::test]
+    async fn expect_items() {
+        let pages = [
+            page(0..5, Some("a"), None),
+            page(5..15, Some("b"), None),
+            page(15..35, Some("c"), None),
+        ];
+        for list in [true, false] {
+            let (chatters, firsts) = collect_expecting(&pages, list, 5, 7).await;
+            assert_eq!(chatters.len(), 7);
+            // the second page is only requested because more than 5 chatters were read, the third not at all
+            assert_eq!(firsts, [Some("5".to_owned()), Some("10".to_owned())]);
         }
+    }
 
-        /// get the next
-        fn get_next(mut self) -> Self {
-            match self.mode {
-                StateMode::Cont(r, d) => {
-                    assert!(d.is_empty());
-                    self.mode = StateMode::Next(Some(r));
-                    self
-                }
-                _ => panic!("oops"),
-            }
+    #[tokio::test]
+    async fn expect_items_clamped() {
+        let pages = [
+            page(0..600, Some("a"), None),
+            page(600..1600, Some("b"), None),
+            page(1600..1700, None, None),
+        ];
+        for list in [true, false] {
+            let (chatters, firsts) = collect_expecting(&pages, list, 600, usize::MAX).await;
+            assert_eq!(chatters.len(), 1700);
+            assert_eq!(
+                firsts,
+                [
+                    Some("600".to_owned()),
+                    Some("1000".to_owned()),
+                    Some("1000".to_owned())
+                ]
+            );
         }
     }
-    let statemode = StateMode::Req(Some(req));
-    let state = State {
-        mode: statemode,
-        client,
-        token,
-    };
-    futures::stream::unfold(state, move |mut state: State<_, _, _, _>| async move {
-        match state.mode {
-            StateMode::Req(Some(_)) => {
-                let req = state.mode.take_initial();
-                let f = state.client.req_get(req, state.token);
-                let resp = match f.await {
-                    Ok(resp) => resp,
-                    Err(e) => return Some((Err(e), state.failed())),
-                };
-                let mut deq = fun(resp.data.clone());
-                deq.pop_front().map(|d| (Ok(d), state.process(resp, deq)))
-            }
-            StateMode::Cont(_, ref mut deq) => {
-                if let Some(d) = deq.pop_front() {
-                    if deq.is_empty() {
-                        Some((Ok(d), state.get_next()))
-                    } else {
-                        Some((Ok(d), state))
-                    }
-                } else {
-                    // New request returned empty.
-                    None
+}
+
+#[cfg(all(test, feature = "client"))]
+mod drain_tests {
+    use super::*;
+
+    fn page(range: std::ops::Range<usize>, cursor: Option<&str>) -> String {
+        let chatters: Vec<_> = range
+            .map(|i| {
+                format!(r#"{{"user_id":"{i}","user_login":"chatter{i}","user_name":"Chatter{i}"}}"#)
+            })
+            .collect();
+        let pagination =
+            cursor.map_or_else(|| "{}".to_owned(), |c| format!(r#"{{"cursor":"{c}"}}"#));
+        format!(
+            r#"{{"data":[{}],"pagination":{pagination}}}"#,
+            chatters.join(",")
+        )
+    }
+
+    fn chatters<'a>(
+        client: &'a HelixClient<'a, crate::tests::MockClient>,
+        token: &'a twitch_oauth2::UserToken,
+    ) -> helix::PaginatedStream<'a, helix::chat::Chatter, ClientError<crate::tests::MockClient>>
+    {
+        let req = helix::chat::GetChattersRequest::new("1234", "1234");
+        make_stream(req, token, client, std::collections::VecDeque::from)
+    }
+
+    #[tokio::test]
+    async fn batches() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("chat/chatters", 200, &page(0..2, Some("a")))
+            .respond("chat/chatters", 200, &page(2..5, None));
+        let client = mock.helix();
+        let token = crate::tests::user_token("twitchdev", "1234");
+
+        let mut batches: Vec<Vec<helix::chat::Chatter>> = vec![];
+        let written = drain_to_sink(chatters(&client, &token), &mut batches, 2)
+            .await
+            .unwrap();
+        assert_eq!(written, 5);
+        // the last batch is sent even if it's not full
+        let lengths: Vec<_> = batches.iter().map(Vec::len).collect();
+        assert_eq!(lengths, [2, 2, 1]);
+        assert_eq!(batches[2][0].user_login.as_str(), "chatter4");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn waits_for_the_sink() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("chat/chatters", 200, &page(0..1, Some("a")))
+            .respond("chat/chatters", 200, &page(1..3, None));
+        let client = mock.helix();
+        let token = crate::tests::user_token("twitchdev", "1234");
+        // a sink that takes a batch only when it's received
+        let (sender, mut receiver) = futures::channel::mpsc::channel(0);
+
+        let drain = drain_to_sink(chatters(&client, &token), sender, 1);
+        let slow_receiver = async {
+            let mut received = vec![];
+            loop {
+                tokio::time::sleep(std::time::Duration::from_secs(10)).await;
+                let requests = mock.requests().len();
+                match receiver.next().await {
+                    Some(batch) => received.push((batch, requests)),
+                    None => break received,
                 }
             }
-            StateMode::Next(Some(_)) => {
-                let resp = state.mode.take_next();
-                let f = resp.get_next(state.client, state.token);
-                let resp = match f.await {
-                    Ok(Some(resp)) => resp,
-                    Ok(None) => return None,
-                    Err(e) => return Some((Err(e), state.failed())),
-                };
-                let mut deq = fun(resp.data.clone());
-                deq.pop_front().map(|d| (Ok(d), state.process(resp, deq)))
+        };
+        let (written, received) = futures::join!(drain, slow_receiver);
+        assert_eq!(written.unwrap(), 3);
+        // the second page is only fetched after the first batch was taken
+        let requests: Vec<_> = received.iter().map(|(_, requests)| *requests).collect();
+        assert_eq!(requests, [1, 2, 2]);
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn errors() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("chat/chatters", 200, &page(0..1, Some("a")))
+            .respond("chat/chatters", 200, &page(1..2, Some("b")))
+            .respond("chat/chatters", 200, &page(2..3, None));
+        let client = mock.helix();
+        let token = crate::tests::user_token("twitchdev", "1234");
+
+        // the sink fails on the second batch
+        let sink = futures::sink::unfold(0, |taken, _: Vec<helix::chat::Chatter>| async move {
+            if taken == 1 {
+                Err(std::io::Error::other("disk full"))
+            } else {
+                Ok(taken + 1)
             }
-            _ => todo!("failed to process request"),
+        });
+        match drain_to_sink(chatters(&client, &token), sink, 1).await {
+            Err(DrainError::Sink(e)) => assert_eq!(e.to_string(), "disk full"),
+            e => panic!("unexpected result: {e:?}"),
         }
-    })
-    .boxed()
+        // no more pages are fetched
+        assert_eq!(mock.requests().len(), 2);
+        assert_eq!(mock.pending(), 1);
+
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "chat/chatters",
+            401,
+            r#"{"error":"Unauthorized","status":401,"message":"Missing scope: moderator:read:chatters"}"#,
+        );
+        let client = mock.helix();
+        let mut batches: Vec<Vec<helix::chat::Chatter>> = vec![];
+        match drain_to_sink(chatters(&client, &token), &mut batches, 1).await {
+            Err(DrainError::Api(ClientRequestError::HelixRequestGetError(_))) => {}
+            e => panic!("unexpected result: {e:?}"),
+        }
+        assert!(batches.is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "client", feature = "time"))]
+mod merged_schedule_tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    fn schedule(broadcaster_id: &str, starts: &[&str]) -> String {
+        let segments: Vec<_> = starts
+            .iter()
+            .map(|start| {
+                format!(
+                    r#"{{"id":"{broadcaster_id}-{start}","start_time":"{start}","end_time":"{start}","title":"stream","canceled_until":null,"category":null,"is_recurring":true}}"#
+                )
+            })
+            .collect();
+        format!(
+            r#"{{"data":{{"segments":[{}],"broadcaster_id":"{broadcaster_id}","broadcaster_name":"b","broadcaster_login":"b","vacation":null}},"pagination":{{}}}}"#,
+            segments.join(",")
+        )
+    }
+
+    const NOT_FOUND: &str =
+        r#"{"error":"Not Found","status":404,"message":"Segments were not found."}"#;
+
+    fn window() -> (types::Timestamp, types::Timestamp) {
+        (
+            types::Timestamp::try_from("2021-07-01T00:00:00Z").unwrap(),
+            types::Timestamp::try_from("2021-07-08T00:00:00Z").unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn merges_schedules() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "schedule",
+            200,
+            &schedule(
+                "1",
+                &[
+                    "2021-07-01T18:00:00Z",
+                    "2021-07-03T18:00:00Z",
+                    "2021-07-09T18:00:00Z",
+                ],
+            ),
+        )
+        .respond("schedule", 404, NOT_FOUND)
+        .respond(
+            "schedule",
+            200,
+            &schedule(
+                "3",
+                &[
+                    "2021-06-30T23:00:00Z",
+                    "2021-07-02T12:00:00Z",
+                    "2021-07-10T12:00:00Z",
+                ],
+            ),
+        );
+        let client = mock.helix();
+        let token = crate::tests::app_token();
+
+        let ids = types::Collection::from(&["1", "2", "3"][..]);
+        let segments = client
+            .get_merged_schedules(&ids, window(), &token)
+            .await
+            .unwrap();
+        let segments: Vec<_> = segments
+            .iter()
+            .map(|(id, segment)| (id.as_str(), segment.start_time.as_str()))
+            .collect();
+        assert_eq!(
+            segments,
+            [
+                ("1", "2021-07-01T18:00:00Z"),
+                ("3", "2021-07-02T12:00:00Z"),
+                ("1", "2021-07-03T18:00:00Z"),
+            ]
+        );
+        for ((_, uri, _), id) in mock.requests().iter().zip(["1", "2", "3"]) {
+            let query = crate::tests::query_pairs(uri);
+            assert!(query.contains(&("broadcaster_id".to_owned(), id.to_owned())));
+            assert!(query.contains(&("start_time".to_owned(), "2021-07-01T00:00:00Z".to_owned())));
+        }
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn names_the_failing_broadcaster() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("schedule", 200, &schedule("1", &["2021-07-01T18:00:00Z"]))
+            .respond(
+                "schedule",
+                500,
+                r#"{"error":"Internal Server Error","status":500,"message":""}"#,
+            );
+        let client = mock.helix();
+        let token = crate::tests::app_token();
+
+        let ids = types::Collection::from(&["1", "2"][..]);
+        let error = client
+            .get_merged_schedules(&ids, window(), &token)
+            .await
+            .unwrap_err();
+        assert_eq!(error.broadcaster_id.as_str(), "2");
+        assert!(matches!(
+            error.source,
+            ClientRequestError::HelixRequestGetError(helix::HelixRequestGetError::Error {
+                status: http::StatusCode::INTERNAL_SERVER_ERROR,
+                ..
+            })
+        ));
+    }
+}
+
+#[cfg(all(test, feature = "client", feature = "eventsub"))]
+mod failed_eventsub_tests {
+    use futures::TryStreamExt;
+
+    fn subscription(id: &str, status: &str) -> String {
+        format!(
+            r#"{{"id":"{id}","status":"{status}","type":"stream.online","version":"1","condition":{{"broadcaster_user_id":"1234"}},"created_at":"2020-11-10T20:08:33.12345678Z","transport":{{"method":"webhook","callback":"https://this-is-a-callback.com"}},"cost":1}}"#
+        )
+    }
+
+    #[tokio::test]
+    async fn only_failed() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "eventsub/subscriptions",
+            200,
+            &format!(
+                r#"{{"total":4,"data":[{},{}],"total_cost":2,"max_total_cost":10000,"pagination":{{"cursor":"abc"}}}}"#,
+                subscription("1", "enabled"),
+                subscription("2", "notification_failures_exceeded"),
+            ),
+        )
+        .respond(
+            "eventsub/subscriptions",
+            200,
+            &format!(
+                r#"{{"total":4,"data":[{},{}],"total_cost":2,"max_total_cost":10000,"pagination":{{}}}}"#,
+                subscription("3", "webhook_callback_verification_pending"),
+                subscription("4", "user_removed"),
+            ),
+        );
+        let client = mock.helix();
+        let token = crate::tests::app_token();
+
+        let failed: Vec<_> = client
+            .get_failed_eventsub_subscriptions(&token)
+            .try_collect()
+            .await
+            .unwrap();
+        let ids: Vec<_> = failed.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, ["2", "4"]);
+        assert_eq!(mock.requests().len(), 2);
+        assert_eq!(mock.pending(), 0);
+    }
 }
@@ -0,0 +1,183 @@
+//! Sharing one response between identical GET requests in flight, see [`HelixClient::with_coalescing`](super::HelixClient::with_coalescing)
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use futures::channel::oneshot;
+
+use crate::types;
+
+/// Most distinct requests that are coalesced at the same time, others are sent as is
+pub(crate) const MAX_IN_FLIGHT: usize = 128;
+
+/// What makes two requests identical
+///
+/// The uri includes the query. The token is identified by its client id and user, not its secret,
+/// so a refreshed token of the same user shares requests with the old one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Key {
+    pub(crate) uri: http::Uri,
+    pub(crate) client_id: String,
+    pub(crate) user_id: Option<types::UserId>,
+}
+
+/// Requests in flight, with the waiters for their response
+#[derive(Debug, Default)]
+pub(crate) struct InFlight {
+    requests: Mutex<HashMap<Key, Vec<oneshot::Sender<crate::client::Response>>>>,
+}
+
+/// How to get the response of a request, see [`InFlight::join`]
+pub(crate) enum Join<'a> {
+    /// No identical request is in flight, send it and [finish](Leader::finish) with the response
+    Leader(Leader<'a>),
+    /// An identical request is in flight, wait for its response
+    ///
+    /// The sender is dropped if that request failed, send the request yourself in that case.
+    Waiter(oneshot::Receiver<crate::client::Response>),
+    /// Too many requests are in flight, send it without sharing the response
+    Full,
+}
+
+impl InFlight {
+    /// Join the identical request in flight, or become the one sending it
+    pub(crate) fn join(&self, key: Key) -> Join<'_> {
+        let mut requests = self.requests.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(waiters) = requests.get_mut(&key) {
+            let (tx, rx) = oneshot::channel();
+            waiters.push(tx);
+            return Join::Waiter(rx);
+        }
+        if requests.len() >= MAX_IN_FLIGHT {
+            return Join::Full;
+        }
+        requests.insert(key.clone(), Vec::new());
+        Join::Leader(Leader {
+            in_flight: self,
+            key,
+            response: None,
+        })
+    }
+
+    /// Number of distinct requests in flight
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .len()
+    }
+}
+
+/// The request that is sent for its identical requests
+///
+/// Removes the request from the [`InFlight`] map when dropped,
+/// giving the waiters the response if [finished](Leader::finish), or nothing if it failed or was cancelled.
+pub(crate) struct Leader<'a> {
+    in_flight: &'a InFlight,
+    key: Key,
+    response: Option<crate::client::Response>,
+}
+
+impl Leader<'_> {
+    /// Give `response` to all waiters
+    pub(crate) fn finish(mut self, response: &crate::client::Response) {
+        self.response = Some(clone_response(response));
+    }
+}
+
+impl Drop for Leader<'_> {
+    fn drop(&mut self) {
+        let waiters = self
+            .in_flight
+            .requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&self.key);
+        if let (Some(waiters), Some(response)) = (waiters, &self.response) {
+            for waiter in waiters {
+                let _ = waiter.send(clone_response(response));
+            }
+        }
+    }
+}
+
+/// Copy a response for another waiter, [`http::Response`] isn't [`Clone`]
+fn clone_response(response: &crate::client::Response) -> crate::client::Response {
+    let mut clone = http::Response::new(response.body().clone());
+    *clone.status_mut() = response.status();
+    *clone.version_mut() = response.version();
+    *clone.headers_mut() = response.headers().clone();
+    clone
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::helix::users::GetUsersRequest;
+
+    const USERS: &str = r#"{"data":[{"id":"141981764","login":"twitchdev","display_name":"TwitchDev","type":"","broadcaster_type":"partner","description":"","profile_image_url":"","offline_image_url":"","created_at":"2016-12-14T20:32:28Z"}]}"#;
+
+    #[tokio::test(start_paused = true)]
+    async fn identical_gets() {
+        let mock = crate::tests::MockClient::new();
+        mock.delay(std::time::Duration::from_secs(1))
+            .respond("users", 200, USERS);
+        let client = mock.helix().with_coalescing(true);
+        let token = crate::tests::user_token("twitchdev", "141981764");
+
+        let responses = futures::future::join_all(
+            (0..10).map(|_| client.req_get(GetUsersRequest::logins(&["twitchdev"][..]), &token)),
+        )
+        .await;
+        assert_eq!(mock.requests().len(), 1);
+        let responses: Vec<_> = responses.into_iter().map(Result::unwrap).collect();
+        assert_eq!(responses[0].data[0].login.as_str(), "twitchdev");
+        assert!(responses.iter().all(|r| *r == responses[0]));
+        assert_eq!(client.coalescing.as_ref().unwrap().len(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn different_gets() {
+        let mock = crate::tests::MockClient::new();
+        mock.delay(std::time::Duration::from_secs(1));
+        for _ in 0..4 {
+            mock.respond("users", 200, USERS);
+        }
+        let client = mock.helix().with_coalescing(true);
+        let user = crate::tests::user_token("twitchdev", "141981764");
+        let other_user = crate::tests::user_token("justintv", "1234");
+        let app = crate::tests::app_token();
+
+        let (a, b, c, d) = futures::join!(
+            client.req_get(GetUsersRequest::logins(&["twitchdev"][..]), &user),
+            client.req_get(GetUsersRequest::logins(&["justintv"][..]), &user),
+            client.req_get(GetUsersRequest::logins(&["twitchdev"][..]), &other_user),
+            client.req_get(GetUsersRequest::logins(&["twitchdev"][..]), &app),
+        );
+        for response in [a, b, c, d] {
+            response.unwrap();
+        }
+        assert_eq!(mock.requests().len(), 4);
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cancelled_leader() {
+        let mock = crate::tests::MockClient::new();
+        mock.delay(std::time::Duration::from_secs(1))
+            .respond("users", 200, USERS)
+            .respond("users", 200, USERS);
+        let client = mock.helix().with_coalescing(true);
+        let token = crate::tests::user_token("twitchdev", "141981764");
+
+        let request = || client.req_get(GetUsersRequest::logins(&["twitchdev"][..]), &token);
+        let (leader, waiter) = futures::join!(
+            tokio::time::timeout(std::time::Duration::from_millis(500), request()),
+            request(),
+        );
+        assert!(leader.is_err());
+        // the waiter sends the request itself once the leader is gone
+        waiter.unwrap();
+        assert_eq!(mock.requests().len(), 2);
+        assert_eq!(client.coalescing.as_ref().unwrap().len(), 0);
+    }
+}
@@ -43,21 +43,27 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
     where
         R: Request + RequestGet,
         D: serde::de::Deserialize<'d> + 'd,
-        T: TwitchToken + ?Sized,
+        T: HelixAuth + ?Sized,
         C: Send,
     {
-        let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        self.validate(&request, token)?;
+        let req = request.create_request(
+            token.auth_bearer().unwrap_or_default(),
+            token.auth_client_id(),
+        )?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send::<R>(req, None).await?;
         {
             let request = Some(request);
             let uri = &uri;
+            let request_id = crate::helix::request::request_id(response.headers());
             let text = std::str::from_utf8(response.body()).map_err(|e| {
-                HelixRequestGetError::Utf8Error(response.body().clone(), e, uri.clone())
+                HelixRequestGetError::Utf8Error(
+                    response.body().clone(),
+                    e,
+                    uri.clone(),
+                    request_id.clone(),
+                )
             })?;
             //eprintln!("\n\nmessage is ------------ {} ------------", text);
             if let Ok(HelixRequestError {
@@ -71,6 +77,7 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
                     status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                     message,
                     uri: uri.clone(),
+                    request_id: request_id.clone(),
                 }
                 .into());
             }
@@ -80,6 +87,7 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
                     e,
                     uri.clone(),
                     response.status(),
+                    request_id.clone(),
                 )
             })?;
             Ok(CustomResponse {
@@ -104,22 +112,28 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
         R: Request + RequestPost + RequestPost<Body = B>,
         B: HelixRequestBody,
         D: serde::de::Deserialize<'d> + 'd,
-        T: TwitchToken + ?Sized,
+        T: HelixAuth + ?Sized,
         C: Send,
     {
-        let req =
-            request.create_request(body, token.token().secret(), token.client_id().as_str())?;
+        self.validate(&request, token)?;
+        let req = request.create_request(
+            body,
+            token.auth_bearer().unwrap_or_default(),
+            token.auth_client_id(),
+        )?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send::<R>(req, None).await?;
         {
             let request = Some(request);
             let uri = &uri;
+            let request_id = crate::helix::request::request_id(response.headers());
             let text = std::str::from_utf8(response.body()).map_err(|e| {
-                HelixRequestPostError::Utf8Error(response.body().clone(), e, uri.clone())
+                HelixRequestPostError::Utf8Error(
+                    response.body().clone(),
+                    e,
+                    uri.clone(),
+                    request_id.clone(),
+                )
             })?;
             //eprintln!("\n\nmessage is ------------ {} ------------", text);
             if let Ok(HelixRequestError {
@@ -134,6 +148,7 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
                     message,
                     uri: uri.clone(),
                     body: response.body().clone(),
+                    request_id: request_id.clone(),
                 }
                 .into());
             }
@@ -143,6 +158,7 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
                     e,
                     uri.clone(),
                     response.status(),
+                    request_id.clone(),
                 )
             })?;
             Ok(CustomResponse {
@@ -172,22 +188,28 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
         R: Request + RequestPatch + RequestPatch<Body = B>,
         B: HelixRequestBody,
         D: serde::de::Deserialize<'d> + 'd,
-        T: TwitchToken + ?Sized,
+        T: HelixAuth + ?Sized,
         C: Send,
         F: Fn(&R, &http::Uri, &str, http::StatusCode) -> Result<(), HelixRequestPatchError>,
     {
-        let req =
-            request.create_request(body, token.token().secret(), token.client_id().as_str())?;
+        self.validate(&request, token)?;
+        let req = request.create_request(
+            body,
+            token.auth_bearer().unwrap_or_default(),
+            token.auth_client_id(),
+        )?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send::<R>(req, None).await?;
         {
             let uri = &uri;
+            let request_id = crate::helix::request::request_id(response.headers());
             let text = std::str::from_utf8(response.body()).map_err(|e| {
-                HelixRequestPatchError::Utf8Error(response.body().clone(), e, uri.clone())
+                HelixRequestPatchError::Utf8Error(
+                    response.body().clone(),
+                    e,
+                    uri.clone(),
+                    request_id.clone(),
+                )
             })?;
             if let Ok(HelixRequestError {
                 error,
@@ -201,6 +223,7 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
                     message,
                     uri: uri.clone(),
                     body: response.body().clone(),
+                    request_id: request_id.clone(),
                 }
                 .into());
             }
@@ -211,6 +234,7 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
                     e,
                     uri.clone(),
                     response.status(),
+                    request_id.clone(),
                 )
             })?;
             Ok(CustomResponse {
@@ -238,21 +262,27 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
     where
         R: Request + RequestDelete,
         D: serde::de::Deserialize<'d> + 'd,
-        T: TwitchToken + ?Sized,
+        T: HelixAuth + ?Sized,
         C: Send,
         F: Fn(&R, &http::Uri, &str, http::StatusCode) -> Result<(), HelixRequestDeleteError>,
     {
-        let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        self.validate(&request, token)?;
+        let req = request.create_request(
+            token.auth_bearer().unwrap_or_default(),
+            token.auth_client_id(),
+        )?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send::<R>(req, None).await?;
         {
             let uri = &uri;
+            let request_id = crate::helix::request::request_id(response.headers());
             let text = std::str::from_utf8(response.body()).map_err(|e| {
-                HelixRequestDeleteError::Utf8Error(response.body().clone(), e, uri.clone())
+                HelixRequestDeleteError::Utf8Error(
+                    response.body().clone(),
+                    e,
+                    uri.clone(),
+                    request_id.clone(),
+                )
             })?;
             if let Ok(HelixRequestError {
                 error,
@@ -266,6 +296,7 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
                     message,
                     uri: uri.clone(),
                     body: response.body().clone(),
+                    request_id: request_id.clone(),
                 }
                 .into());
             }
@@ -276,6 +307,7 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
                     e,
                     uri.clone(),
                     response.status(),
+                    request_id.clone(),
                 )
             })?;
             Ok(CustomResponse {
@@ -305,22 +337,28 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
         R: Request + RequestPut + RequestPut<Body = B>,
         B: HelixRequestBody,
         D: serde::de::Deserialize<'d> + 'd,
-        T: TwitchToken + ?Sized,
+        T: HelixAuth + ?Sized,
         C: Send,
         F: Fn(&R, &http::Uri, &str, http::StatusCode) -> Result<(), HelixRequestDeleteError>,
     {
-        let req =
-            request.create_request(body, token.token().secret(), token.client_id().as_str())?;
+        self.validate(&request, token)?;
+        let req = request.create_request(
+            body,
+            token.auth_bearer().unwrap_or_default(),
+            token.auth_client_id(),
+        )?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send::<R>(req, None).await?;
         {
             let uri = &uri;
+            let request_id = crate::helix::request::request_id(response.headers());
             let text = std::str::from_utf8(response.body()).map_err(|e| {
-                HelixRequestPutError::Utf8Error(response.body().clone(), e, uri.clone())
+                HelixRequestPutError::Utf8Error(
+                    response.body().clone(),
+                    e,
+                    uri.clone(),
+                    request_id.clone(),
+                )
             })?;
             if let Ok(HelixRequestError {
                 error,
@@ -334,6 +372,7 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
                     message,
                     uri: uri.clone(),
                     body: response.body().clone(),
+                    request_id: request_id.clone(),
                 }
                 .into());
             }
@@ -344,6 +383,7 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
                     e,
                     uri.clone(),
                     response.status(),
+                    request_id.clone(),
                 )
             })?;
             Ok(CustomResponse {
@@ -184,6 +184,8 @@ impl Request for GetBitsLeaderboardRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::BitsRead];
 }
 
+endpoint_meta!(GET GetBitsLeaderboardRequest<'static>);
+
 impl RequestGet for GetBitsLeaderboardRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -207,6 +209,7 @@ impl RequestGet for GetBitsLeaderboardRequest<'_> {
                 e,
                 uri.clone(),
                 status,
+                None,
             )
         })?;
         Ok(helix::Response::new(
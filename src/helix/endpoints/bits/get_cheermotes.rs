@@ -193,6 +193,8 @@ impl Request for GetCheermotesRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetCheermotesRequest<'static>);
+
 impl RequestGet for GetCheermotesRequest<'_> {}
 
 #[cfg(test)]
@@ -434,3 +436,19 @@ fn test_request() {
 
     dbg!(GetCheermotesRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_fixture() {
+    #[derive(Deserialize, Serialize)]
+    struct Data {
+        data: Vec<Cheermote>,
+    }
+
+    let cheermotes: Data =
+        crate::tests::roundtrip_fixture(include_str!("fixtures/get_cheermotes.json"), &[]);
+    assert_eq!(
+        cheermotes.data[0].tiers[1].images.dark.static_.url_1_5x,
+        "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/dark/static/100/1.5.png"
+    );
+}
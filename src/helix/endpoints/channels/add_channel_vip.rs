@@ -93,6 +93,8 @@ impl Request for AddChannelVipRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageVips];
 }
 
+endpoint_meta!(POST AddChannelVipRequest<'static>);
+
 impl RequestPost for AddChannelVipRequest<'_> {
     type Body = helix::EmptyBody;
 
@@ -115,11 +117,29 @@ impl RequestPost for AddChannelVipRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
 }
 
+helix_helper! {
+    /// Add a channel vip
+    ///
+    /// Known errors returned by Twitch are turned into a [`ModerationActionError`](helix::moderation::ModerationActionError).
+    pub async fn add_channel_vip<'b, T>(
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+    ) -> helix::channels::AddChannelVipResponse, helix::moderation::ModerationActionError = req_post(helix::moderation::ModerationActionError::from_client_error) {
+        AddChannelVipRequest {
+            broadcaster_id: broadcaster_id.into_cow(),
+            user_id: user_id.into_cow(),
+        }
+    } {
+        helix::EmptyBody
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -142,3 +162,9 @@ fn test_request() {
 
     dbg!(AddChannelVipRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: AddChannelVipRequest,
+    204 => b"",
+}
@@ -107,6 +107,8 @@ impl Request for GetAdScheduleRequest<'_> {
     )];
 }
 
+endpoint_meta!(GET GetAdScheduleRequest<'static>);
+
 impl RequestGet for GetAdScheduleRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -124,6 +126,7 @@ impl RequestGet for GetAdScheduleRequest<'_> {
                     e,
                     uri.clone(),
                     status,
+                    None,
                 )
             });
         let response = match response {
@@ -166,15 +169,10 @@ impl RequestGet for GetAdScheduleRequest<'_> {
     }
 }
 
+// From twitch docs
+// FIXME: twitch docs sucks https://github.com/twitchdev/issues/issues/857, also trailing comma
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetAdScheduleRequest::broadcaster_id("123");
-
-    // From twitch docs
-    // FIXME: twitch docs sucks https://github.com/twitchdev/issues/issues/857, also trailing comma
-    let data = br#"
+const FIXTURE: &[u8] = br#"
     {
         "data": [
           {
@@ -187,8 +185,15 @@ fn test_request() {
           }
         ]
       }
-        "#
-    .to_vec();
+        "#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetAdScheduleRequest::broadcaster_id("123");
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -237,3 +242,9 @@ fn test_request_empty_wrong() {
         None
     );
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetAdScheduleRequest,
+    200 => FIXTURE,
+}
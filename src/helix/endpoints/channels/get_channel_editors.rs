@@ -92,16 +92,13 @@ impl Request for GetChannelEditorsRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelReadEditors];
 }
 
+endpoint_meta!(GET GetChannelEditorsRequest<'static>);
+
 impl RequestGet for GetChannelEditorsRequest<'_> {}
 
+// From twitch docs
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetChannelEditorsRequest::broadcaster_id("44445592");
-
-    // From twitch docs
-    let data = br#"
+const FIXTURE: &[u8] = br#"
     {
         "data": [
           {
@@ -116,8 +113,15 @@ fn test_request() {
           }
         ]
       }
-        "#
-    .to_vec();
+        "#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetChannelEditorsRequest::broadcaster_id("44445592");
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -129,3 +133,9 @@ fn test_request() {
 
     dbg!(GetChannelEditorsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetChannelEditorsRequest,
+    200 => FIXTURE,
+}
@@ -117,6 +117,38 @@ pub struct Follower {
     pub user_name: types::DisplayName,
 }
 
+/// A [`Follower`] returned by `HelixClient::get_followers_since`
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub struct FollowerSince {
+    /// The follower
+    pub follower: Follower,
+    /// Amount of followers since the cutoff so far, including this one
+    pub new_followers: usize,
+}
+
+/// The follower count of a channel can't be requested with the token
+///
+/// Returned by [`HelixClient::get_total_followers_from_id`](helix::HelixClient::get_total_followers_from_id) when the token
+/// is not a user token, or Twitch rejects it.
+#[cfg(feature = "client")]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("the follower count of a channel is only returned for a user token")]
+#[non_exhaustive]
+pub struct FollowerCountUnavailable;
+
+/// The followers of a channel can't be listed with the token
+///
+/// Returned by [`HelixClient::get_follower_since`](helix::HelixClient::get_follower_since) and the helpers built on it when the token
+/// is not a user token with the `moderator:read:followers` scope, or Twitch rejects it.
+#[cfg(feature = "client")]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "the followers of a channel are only listed for a user token with the `moderator:read:followers` scope"
+)]
+#[non_exhaustive]
+pub struct FollowerListUnavailable;
+
 impl Request for GetChannelFollowersRequest<'_> {
     type Response = Vec<Follower>;
 
@@ -124,8 +156,11 @@ impl Request for GetChannelFollowersRequest<'_> {
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator =
         twitch_oauth2::validator![twitch_oauth2::Scope::ModeratorReadFollowers];
+    const TIMEOUT_HINT: Option<std::time::Duration> = Some(helix::SLOW_ENDPOINT_TIMEOUT);
 }
 
+endpoint_meta!(GET GetChannelFollowersRequest<'static>);
+
 impl RequestGet for GetChannelFollowersRequest<'_> {}
 
 impl helix::Paginated for GetChannelFollowersRequest<'_> {
@@ -134,15 +169,10 @@ impl helix::Paginated for GetChannelFollowersRequest<'_> {
     }
 }
 
+// From twitch docs
+// FIXME: example has trailing `,`, is missing a `,` and has ...
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetChannelFollowersRequest::broadcaster_id("123456");
-
-    // From twitch docs
-    // FIXME: example has trailing `,`, is missing a `,` and has ...
-    let data = br#"
+const FIXTURE: &[u8] = br#"
     {
         "total": 8,
         "data": [
@@ -163,8 +193,15 @@ fn test_request() {
           "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6NX19"
         }
       }
-        "#
-    .to_vec();
+        "#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetChannelFollowersRequest::broadcaster_id("123456");
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -176,3 +213,309 @@ fn test_request() {
 
     dbg!(GetChannelFollowersRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(all(test, feature = "client", feature = "time"))]
+#[tokio::test]
+async fn test_get_followers_since() {
+    use futures::TryStreamExt;
+    use std::convert::TryFrom;
+
+    fn follower(id: &str, followed_at: &str) -> String {
+        format!(
+            r#"{{"user_id":"{id}","user_name":"User{id}","user_login":"user{id}","followed_at":"{followed_at}"}}"#
+        )
+    }
+
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "channels/followers",
+        200,
+        &format!(
+            r#"{{"total":8,"data":[{},{}],"pagination":{{"cursor":"page2"}}}}"#,
+            follower("1", "2023-03-05T12:00:00Z"),
+            follower("2", "2023-03-04T12:00:00.5Z"),
+        ),
+    )
+    .respond(
+        "channels/followers",
+        200,
+        &format!(
+            r#"{{"total":8,"data":[{},{},{}],"pagination":{{"cursor":"page3"}}}}"#,
+            follower("3", "2023-03-01T00:00:00Z"),
+            follower("4", "2023-02-28T23:59:59Z"),
+            follower("5", "2023-02-27T12:00:00Z"),
+        ),
+    )
+    .respond(
+        "channels/followers",
+        200,
+        &format!(
+            r#"{{"total":8,"data":[{}],"pagination":{{}}}}"#,
+            follower("6", "2023-02-01T12:00:00Z"),
+        ),
+    );
+    let token = crate::tests::user_token("broadcaster", "1234");
+
+    let followers: Vec<FollowerSince> = mock
+        .helix()
+        .get_followers_since(
+            "1234",
+            types::Timestamp::try_from("2023-03-01T00:00:00Z").unwrap(),
+            &token,
+        )
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        followers
+            .iter()
+            .map(|f| (f.follower.user_id.as_str(), f.new_followers))
+            .collect::<Vec<_>>(),
+        vec![("1", 1), ("2", 2), ("3", 3)]
+    );
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].1.query(), Some("broadcaster_id=1234&first=100"));
+    assert_eq!(
+        requests[1].1.query(),
+        Some("broadcaster_id=1234&after=page2&first=100")
+    );
+    assert_eq!(mock.pending(), 1);
+}
+
+#[cfg(all(test, feature = "client", feature = "time"))]
+#[tokio::test]
+async fn test_is_follower_for() {
+    let week = std::time::Duration::from_secs(7 * 24 * 60 * 60);
+    let just_now = types::Timestamp::now();
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "channels/followers",
+        200,
+        r#"{"total":8,"data":[{"user_id":"5678","user_name":"User","user_login":"user","followed_at":"2022-05-24T22:22:08Z"}],"pagination":{}}"#,
+    )
+    .respond(
+        "channels/followers",
+        200,
+        &format!(
+            r#"{{"total":8,"data":[{{"user_id":"5678","user_name":"User","user_login":"user","followed_at":"{just_now}"}}],"pagination":{{}}}}"#
+        ),
+    )
+    .respond(
+        "channels/followers",
+        200,
+        r#"{"total":8,"data":[],"pagination":{}}"#,
+    )
+    .respond(
+        "channels/followers",
+        200,
+        r#"{"total":8,"data":[],"pagination":{}}"#,
+    );
+    let token = crate::tests::user_token_with_scopes(
+        "broadcaster",
+        "1234",
+        vec![twitch_oauth2::Scope::ModeratorReadFollowers],
+    );
+    let client = mock.helix();
+
+    assert!(client
+        .is_follower_for("1234", "5678", week, &token)
+        .await
+        .unwrap());
+    assert!(!client
+        .is_follower_for("1234", "5678", week, &token)
+        .await
+        .unwrap());
+    assert!(!client
+        .is_follower_for("1234", "5678", week, &token)
+        .await
+        .unwrap());
+    assert_eq!(
+        client
+            .get_follower_since("1234", "5678", &token)
+            .await
+            .unwrap(),
+        None
+    );
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 4);
+    assert!(requests
+        .iter()
+        .all(|(_, uri, _)| uri.query() == Some("user_id=5678&broadcaster_id=1234")));
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_follower_since_not_permitted() {
+    let mock = crate::tests::MockClient::new();
+    let client = mock.helix();
+
+    // the token is missing `moderator:read:followers`, no request is made
+    let err = client
+        .get_follower_since("1234", "5678", &crate::tests::user_token("user", "1"))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        helix::ClientExtError::Other(FollowerListUnavailable)
+    ));
+    assert!(mock.requests().is_empty());
+
+    mock.respond(
+        "channels/followers",
+        401,
+        r#"{"error":"Unauthorized","status":401,"message":"Missing scope: moderator:read:followers"}"#,
+    );
+    let token = crate::tests::user_token_with_scopes(
+        "user",
+        "1",
+        vec![twitch_oauth2::Scope::ModeratorReadFollowers],
+    );
+    let err = client
+        .get_follower_since("1234", "5678", &token)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        helix::ClientExtError::Other(FollowerListUnavailable)
+    ));
+    assert_eq!(mock.requests().len(), 1);
+    assert_eq!(mock.pending(), 0);
+}
+
+#[cfg(all(test, feature = "client"))]
+mod total_followers_tests {
+    use super::*;
+
+    fn token() -> twitch_oauth2::UserToken {
+        crate::tests::user_token_with_scopes(
+            "user",
+            "1",
+            vec![twitch_oauth2::Scope::ModeratorReadFollowers],
+        )
+    }
+
+    #[tokio::test]
+    async fn permitted() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "channels/followers",
+            200,
+            r#"{"total":8,"data":[],"pagination":{}}"#,
+        );
+        let client = mock.helix();
+
+        let total = client
+            .get_total_followers_from_id("1234", &token())
+            .await
+            .unwrap();
+        assert_eq!(total, Some(8));
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].1.path(), "/helix/channels/followers");
+        assert_eq!(requests[0].1.query(), Some("broadcaster_id=1234&first=1"));
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn without_scope() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "channels/followers",
+            200,
+            r#"{"total":8,"data":[],"pagination":{}}"#,
+        );
+
+        // the total is returned to user tokens without `moderator:read:followers`
+        let total = mock
+            .helix()
+            .get_total_followers_from_id("1234", &crate::tests::user_token("user", "1"))
+            .await
+            .unwrap();
+        assert_eq!(total, Some(8));
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn not_permitted() {
+        let mock = crate::tests::MockClient::new();
+        let client = mock.helix();
+
+        // app access tokens can't read the total, no request is made
+        let err = client
+            .get_total_followers_from_id("1234", &crate::tests::app_token())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            helix::ClientExtError::Other(FollowerCountUnavailable)
+        ));
+        assert!(mock.requests().is_empty());
+
+        mock.respond(
+            "channels/followers",
+            401,
+            r#"{"error":"Unauthorized","status":401,"message":"Invalid OAuth token"}"#,
+        );
+        let err = client
+            .get_total_followers_from_id("1234", &token())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            helix::ClientExtError::Other(FollowerCountUnavailable)
+        ));
+        assert_eq!(mock.requests().len(), 1);
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn nonexistent_user() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "channels/followers",
+            200,
+            r#"{"total":0,"data":[],"pagination":{}}"#,
+        )
+        .respond("users", 200, r#"{"data":[]}"#)
+        .respond(
+            "channels/followers",
+            200,
+            r#"{"total":0,"data":[],"pagination":{}}"#,
+        )
+        .respond(
+            "users",
+            200,
+            r#"{"data":[{"id":"1234","login":"broadcaster","display_name":"Broadcaster","type":"","broadcaster_type":"","description":"","profile_image_url":"","offline_image_url":"","view_count":0,"created_at":"2016-12-14T20:32:28.894263Z"}]}"#,
+        );
+        let client = mock.helix();
+
+        let total = client
+            .get_total_followers_from_id("1234", &token())
+            .await
+            .unwrap();
+        assert_eq!(total, None);
+
+        // a user without followers
+        let total = client
+            .get_total_followers_from_id("1234", &token())
+            .await
+            .unwrap();
+        assert_eq!(total, Some(0));
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[1].1.path(), "/helix/users");
+        assert_eq!(requests[1].1.query(), Some("id=1234"));
+        assert_eq!(mock.pending(), 0);
+    }
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetChannelFollowersRequest,
+    200 => FIXTURE,
+}
@@ -97,7 +97,7 @@ pub struct ChannelInformation {
     /// Name of the game being played on the channel
     pub game_name: types::CategoryId,
     /// Language of the channel
-    pub broadcaster_language: String,
+    pub broadcaster_language: types::LanguageCode,
     /// Title of the stream
     pub title: String,
     /// Description of the stream
@@ -118,6 +118,12 @@ pub struct ChannelInformation {
     pub is_branded_content: bool,
 }
 
+impl helix::games::CategoryLike for ChannelInformation {
+    fn category_id(&self) -> &types::CategoryIdRef { &self.game_id }
+
+    fn category_name(&self) -> &str { self.game_name.as_str() }
+}
+
 impl Request for GetChannelInformationRequest<'_> {
     type Response = Vec<ChannelInformation>;
 
@@ -126,17 +132,13 @@ impl Request for GetChannelInformationRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetChannelInformationRequest<'static>);
+
 impl RequestGet for GetChannelInformationRequest<'_> {}
 
+// From twitch docs
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let ids: &[&types::UserIdRef] = &["141981764".into()];
-    let req = GetChannelInformationRequest::broadcaster_ids(ids);
-
-    // From twitch docs
-    let data = br#"
+const FIXTURE: &[u8] = br#"
         {
           "data": [
             {
@@ -154,8 +156,16 @@ fn test_request() {
             }
           ]
         }
-        "#
-    .to_vec();
+        "#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let ids: &[&types::UserIdRef] = &["141981764".into()];
+    let req = GetChannelInformationRequest::broadcaster_ids(ids);
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -167,3 +177,9 @@ fn test_request() {
 
     dbg!(GetChannelInformationRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetChannelInformationRequest,
+    200 => FIXTURE,
+}
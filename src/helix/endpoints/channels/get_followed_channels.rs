@@ -139,6 +139,8 @@ impl Request for GetFollowedChannels<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::UserReadFollows];
 }
 
+endpoint_meta!(GET GetFollowedChannels<'static>);
+
 impl RequestGet for GetFollowedChannels<'_> {}
 
 impl helix::Paginated for GetFollowedChannels<'_> {
@@ -147,15 +149,10 @@ impl helix::Paginated for GetFollowedChannels<'_> {
     }
 }
 
+// From twitch docs
+// FIXME: example has trailing `,`, is missing a `,` and has ...
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetFollowedChannels::user_id("123456");
-
-    // From twitch docs
-    // FIXME: example has trailing `,`, is missing a `,` and has ...
-    let data = br#"
+const FIXTURE: &[u8] = br#"
     {
         "total": 8,
         "data": [
@@ -176,8 +173,15 @@ fn test_request() {
             "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6NX19"
         }
     }
-      "#
-    .to_vec();
+      "#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetFollowedChannels::user_id("123456");
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -189,3 +193,9 @@ fn test_request() {
 
     dbg!(GetFollowedChannels::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetFollowedChannels,
+    200 => FIXTURE,
+}
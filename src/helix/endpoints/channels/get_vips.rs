@@ -37,6 +37,9 @@
 use super::*;
 use helix::RequestGet;
 
+/// Maximum amount of VIPs returned per page
+pub const MAX_PAGE_SIZE: usize = 100;
+
 /// Query Parameters for [Get VIPs](super::get_vips)
 ///
 /// [`get-vips`](https://dev.twitch.tv/docs/api/reference#get-vips)
@@ -57,7 +60,7 @@ pub struct GetVipsRequest<'a> {
     pub user_id: types::Collection<'a, types::UserId>,
     /// The maximum number of items to return per page in the response. The minimum page size is 1 item per page and the maximum is 100. The default is 20.
     #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
-    pub first: Option<usize>,
+    pub first: Option<helix::PageSize>,
     /// The cursor used to get the next page of results. The Pagination object in the response contains the cursor’s value. Read more.
     #[cfg_attr(feature = "typed-builder", builder(default))]
     #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
@@ -82,7 +85,7 @@ impl<'a> GetVipsRequest<'a> {
         }
     }
 
-    /// Set amount of results returned per page.
+    /// Set amount of results returned per page, values above the limit of [100](MAX_PAGE_SIZE) are clamped.
     ///
     /// # Examples
     ///
@@ -90,8 +93,8 @@ impl<'a> GetVipsRequest<'a> {
     /// use twitch_api::helix::channels::GetVipsRequest;
     /// let request = GetVipsRequest::broadcaster_id("1234").first(100);
     /// ```
-    pub fn first(mut self, first: usize) -> Self {
-        self.first = Some(first);
+    pub fn first(mut self, first: impl Into<Option<usize>>) -> Self {
+        self.first = first.into().map(helix::PageSize::clamped::<Self>);
         self
     }
 
@@ -131,6 +134,7 @@ pub struct Vip {
 impl Request for GetVipsRequest<'_> {
     type Response = Vec<Vip>;
 
+    const MAX_PAGE_SIZE: usize = MAX_PAGE_SIZE;
     const PATH: &'static str = "channels/vips";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![any(
@@ -139,23 +143,24 @@ impl Request for GetVipsRequest<'_> {
     )];
 }
 
+endpoint_meta!(GET GetVipsRequest<'static>);
+
 impl helix::Paginated for GetVipsRequest<'_> {
     fn set_pagination(&mut self, cursor: Option<helix::Cursor>) {
         self.after = cursor.map(|c| c.into_cow())
     }
+
+    fn set_first(&mut self, first: usize) {
+        self.first = Some(helix::PageSize::clamped::<Self>(first))
+    }
 }
 
 impl RequestGet for GetVipsRequest<'_> {}
 
+// From twitch docs
+// FIXME: Example has ...
 #[cfg(test)]
-#[test]
-fn test_request_all() {
-    use helix::*;
-    let req = GetVipsRequest::broadcaster_id("123");
-
-    // From twitch docs
-    // FIXME: Example has ...
-    let data = br#"
+const FIXTURE_ALL: &[u8] = br#"
       {
         "data": [
           {
@@ -167,8 +172,15 @@ fn test_request_all() {
         "pagination": {
           "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6NX19"
         }
-      }"#
-    .to_vec();
+      }"#;
+
+#[cfg(test)]
+#[test]
+fn test_request_all() {
+    use helix::*;
+    let req = GetVipsRequest::broadcaster_id("123");
+
+    let data = FIXTURE_ALL.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -181,17 +193,10 @@ fn test_request_all() {
     dbg!(GetVipsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
 
+// From twitch docs
+// FIXME: Example has ...
 #[cfg(test)]
-#[test]
-fn test_request_multiple() {
-    use helix::*;
-
-    let ids: &[&types::UserIdRef] = &["456".into(), "678".into()];
-    let req = GetVipsRequest::broadcaster_id("123").user_ids(ids);
-
-    // From twitch docs
-    // FIXME: Example has ...
-    let data = br#"
+const FIXTURE_MULTIPLE: &[u8] = br#"
       {
         "data": [
           {
@@ -203,8 +208,17 @@ fn test_request_multiple() {
         "pagination": {
           "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6NX19"
         }
-      }"#
-    .to_vec();
+      }"#;
+
+#[cfg(test)]
+#[test]
+fn test_request_multiple() {
+    use helix::*;
+
+    let ids: &[&types::UserIdRef] = &["456".into(), "678".into()];
+    let req = GetVipsRequest::broadcaster_id("123").user_ids(ids);
+
+    let data = FIXTURE_MULTIPLE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -216,3 +230,88 @@ fn test_request_multiple() {
 
     dbg!(GetVipsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_first() {
+    use helix::*;
+    let req = GetVipsRequest::broadcaster_id("123").first(250);
+    assert_eq!(
+        req.get_uri().unwrap().to_string(),
+        "https://api.twitch.tv/helix/channels/vips?broadcaster_id=123&first=100"
+    );
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_get_vips_in_channel_batch_size() {
+    use futures::TryStreamExt;
+
+    fn vip(id: &str) -> String {
+        format!(r#"{{"user_id":"{id}","user_name":"User{id}","user_login":"user{id}"}}"#)
+    }
+
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "channels/vips",
+        200,
+        &format!(
+            r#"{{"data":[{}],"pagination":{{"cursor":"c1"}}}}"#,
+            vip("1")
+        ),
+    )
+    .respond(
+        "channels/vips",
+        200,
+        &format!(
+            r#"{{"data":[{}],"pagination":{{"cursor":"c2"}}}}"#,
+            vip("2")
+        ),
+    )
+    .respond(
+        "channels/vips",
+        200,
+        &format!(r#"{{"data":[{}],"pagination":{{}}}}"#, vip("3")),
+    )
+    .respond(
+        "channels/vips",
+        200,
+        &format!(
+            r#"{{"data":[{},{},{}],"pagination":{{}}}}"#,
+            vip("1"),
+            vip("2"),
+            vip("3")
+        ),
+    );
+    let token = crate::tests::user_token("broadcaster", "123");
+    let client = mock.helix();
+
+    let small: Vec<Vip> = client
+        .get_vips_in_channel("123", 1, &token)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(mock.requests().len(), 3);
+    let default: Vec<Vip> = client
+        .get_vips_in_channel("123", None, &token)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(small, default);
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 4);
+    assert_eq!(requests[0].1.query(), Some("broadcaster_id=123&first=1"));
+    assert_eq!(
+        requests[2].1.query(),
+        Some("broadcaster_id=123&first=1&after=c2")
+    );
+    assert_eq!(requests[3].1.query(), Some("broadcaster_id=123&first=100"));
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetVipsRequest,
+    200 => FIXTURE_ALL,
+    200 => FIXTURE_MULTIPLE,
+}
@@ -46,7 +46,10 @@ pub use get_ad_schedule::{AdSchedule, GetAdScheduleRequest};
 #[doc(inline)]
 pub use get_channel_editors::{Editor, GetChannelEditorsRequest};
 #[doc(inline)]
-pub use get_channel_followers::{Follower, GetChannelFollowersRequest};
+pub use get_channel_followers::{Follower, FollowerSince, GetChannelFollowersRequest};
+#[cfg(feature = "client")]
+#[doc(inline)]
+pub use get_channel_followers::{FollowerCountUnavailable, FollowerListUnavailable};
 #[doc(inline)]
 pub use get_channel_information::{ChannelInformation, GetChannelInformationRequest};
 #[doc(inline)]
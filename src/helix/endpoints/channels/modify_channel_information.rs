@@ -259,6 +259,8 @@ impl Request for ModifyChannelInformationRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageBroadcast];
 }
 
+endpoint_meta!(PATCH ModifyChannelInformationRequest<'static>);
+
 impl<'a> RequestPatch for ModifyChannelInformationRequest<'a> {
     type Body = ModifyChannelInformationBody<'a>;
 
@@ -281,6 +283,7 @@ impl<'a> RequestPatch for ModifyChannelInformationRequest<'a> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
@@ -299,7 +302,7 @@ fn test_request() {
     body.tags(&["LevelingUp"]);
 
     assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        body.body_json().unwrap(),
         r#"{"game_id":"33214","title":"there are helicopters in the game? REASON TO PLAY FORTNITE found","broadcaster_language":"en","tags":["LevelingUp"]}"#
     );
 
@@ -318,3 +321,9 @@ fn test_request() {
 
     dbg!(ModifyChannelInformationRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: ModifyChannelInformationRequest,
+    204 => b"",
+}
@@ -95,6 +95,8 @@ impl Request for RemoveChannelVipRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageVips];
 }
 
+endpoint_meta!(DELETE RemoveChannelVipRequest<'static>);
+
 impl RequestDelete for RemoveChannelVipRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -115,11 +117,27 @@ impl RequestDelete for RemoveChannelVipRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
 }
 
+helix_helper! {
+    /// Remove a channel vip
+    ///
+    /// Known errors returned by Twitch are turned into a [`ModerationActionError`](helix::moderation::ModerationActionError).
+    pub async fn remove_channel_vip<'b, T>(
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+    ) -> helix::channels::RemoveChannelVipResponse, helix::moderation::ModerationActionError = req_delete(helix::moderation::ModerationActionError::from_client_error) {
+        RemoveChannelVipRequest {
+            broadcaster_id: broadcaster_id.into_cow(),
+            user_id: user_id.into_cow(),
+        }
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -141,3 +159,9 @@ fn test_request() {
 
     dbg!(RemoveChannelVipRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: RemoveChannelVipRequest,
+    204 => b"",
+}
@@ -92,6 +92,8 @@ impl Request for SnoozeNextAdRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageAds];
 }
 
+endpoint_meta!(POST SnoozeNextAdRequest<'static>);
+
 impl<'a> RequestPost for SnoozeNextAdRequest<'a> {
     type Body = helix::EmptyBody;
 
@@ -111,6 +113,7 @@ impl<'a> RequestPost for SnoozeNextAdRequest<'a> {
                     e,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         Ok(helix::Response::new(
@@ -122,6 +125,7 @@ impl<'a> RequestPost for SnoozeNextAdRequest<'a> {
                     response: response.to_string(),
                     status,
                     uri: uri.clone(),
+                    request_id: None,
                 })?,
             resp.pagination.cursor,
             request,
@@ -131,15 +135,10 @@ impl<'a> RequestPost for SnoozeNextAdRequest<'a> {
     }
 }
 
+// From twitch docs
+// FIXME: WRONG!!!!!!!1 https://github.com/twitchdev/issues/issues/857#issuecomment-1793777950 also trailing comma
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = SnoozeNextAdRequest::broadcaster_id("123");
-
-    // From twitch docs
-    // FIXME: WRONG!!!!!!!1 https://github.com/twitchdev/issues/issues/857#issuecomment-1793777950 also trailing comma
-    let data = r#"
+const FIXTURE: &str = r#"
     {
         "data": [
           {
@@ -151,6 +150,14 @@ fn test_request() {
       }
       "#;
 
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = SnoozeNextAdRequest::broadcaster_id("123");
+
+    let data = FIXTURE;
+
     let http_response = http::Response::builder().status(204).body(data).unwrap();
 
     let uri = req.get_uri().unwrap();
@@ -161,3 +168,9 @@ fn test_request() {
 
     dbg!(SnoozeNextAdRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: SnoozeNextAdRequest,
+    204 => FIXTURE,
+}
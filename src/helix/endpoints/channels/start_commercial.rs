@@ -128,12 +128,26 @@ impl Request for StartCommercialRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelEditCommercial];
 }
 
+endpoint_meta!(POST StartCommercialRequest<'static>);
+
 impl<'a> RequestPost for StartCommercialRequest<'a> {
     type Body = StartCommercialBody<'a>;
 }
 
 impl helix::private::SealedSerialize for StartCommercialBody<'_> {}
 
+// From twitch docs
+#[cfg(test)]
+const FIXTURE: &[u8] = br#"
+{
+    "data": [{
+      "length" : 60,
+      "message" : "",
+      "retry_after" : 480
+    }]
+}
+"#;
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -143,23 +157,13 @@ fn test_request() {
     let body = StartCommercialBody::new("41245072", types::CommercialLength::Length60);
 
     assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        body.body_json().unwrap(),
         r#"{"broadcaster_id":"41245072","length":60}"#
     );
 
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
-    // From twitch docs
-    let data = br#"
-{
-    "data": [{
-      "length" : 60,
-      "message" : "",
-      "retry_after" : 480
-    }]
-}
-"#
-    .to_vec();
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -171,3 +175,9 @@ fn test_request() {
 
     dbg!(StartCommercialRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: StartCommercialRequest,
+    200 => FIXTURE,
+}
@@ -101,6 +101,8 @@ impl Request for GetCharityCampaignRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::scopes::Scope::ChannelReadCharity];
 }
 
+endpoint_meta!(GET GetCharityCampaignRequest<'static>);
+
 impl RequestGet for GetCharityCampaignRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -118,6 +120,7 @@ impl RequestGet for GetCharityCampaignRequest<'_> {
                     e,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         Ok(helix::Response::new(
@@ -110,6 +110,8 @@ impl Request for GetCharityCampaignDonationsRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::scopes::Scope::ChannelReadCharity];
 }
 
+endpoint_meta!(GET GetCharityCampaignDonationsRequest<'static>);
+
 impl RequestGet for GetCharityCampaignDonationsRequest<'_> {}
 
 #[cfg(test)]
@@ -55,6 +55,11 @@ pub struct GetChannelChatBadgesRequest<'a> {
 
 impl<'a> GetChannelChatBadgesRequest<'a> {
     /// Get chat badges for the specified broadcaster.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::chat::GetChannelChatBadgesRequest;
+    /// let request = GetChannelChatBadgesRequest::broadcaster_id("1234");
+    /// ```
     pub fn broadcaster_id(broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
         Self {
             broadcaster_id: broadcaster_id.into_cow(),
@@ -75,17 +80,14 @@ impl Request for GetChannelChatBadgesRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetChannelChatBadgesRequest<'static>);
+
 impl RequestGet for GetChannelChatBadgesRequest<'_> {}
 
+// From twitch docs
+// FIXME: Example has ...
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetChannelChatBadgesRequest::broadcaster_id("135093069");
-
-    // From twitch docs
-    // FIXME: Example has ...
-    let data = br#"
+const FIXTURE: &[u8] = br#"
     {
         "data": [
           {
@@ -116,8 +118,15 @@ fn test_request() {
           }
         ]
       }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetChannelChatBadgesRequest::broadcaster_id("135093069");
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -129,3 +138,9 @@ fn test_request() {
 
     dbg!(GetChannelChatBadgesRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetChannelChatBadgesRequest,
+    200 => FIXTURE,
+}
@@ -53,6 +53,11 @@ pub struct GetChannelEmotesRequest<'a> {
 
 impl<'a> GetChannelEmotesRequest<'a> {
     /// Get emotes in a specific broadcasters channel.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::chat::GetChannelEmotesRequest;
+    /// let request = GetChannelEmotesRequest::broadcaster_id("1234");
+    /// ```
     pub fn broadcaster_id(broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
         Self {
             broadcaster_id: broadcaster_id.into_cow(),
@@ -73,17 +78,52 @@ impl Request for GetChannelEmotesRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetChannelEmotesRequest<'static>);
+
 impl RequestGet for GetChannelEmotesRequest<'_> {}
 
-#[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetChannelEmotesRequest::broadcaster_id("304456832");
+impl helix::private::SealedEmoteTemplate for GetChannelEmotesRequest<'_> {}
 
-    // From twitch docs
-    // FIXME: Example has ... and is malformed, uses [] in images
-    let data = br#"
+helix_helper! {
+    /// Get channel emotes in channel with user id
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    pub async fn get_channel_emotes_from_id<'b, T>(
+        user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+    ) -> Vec<helix::chat::ChannelEmote> = req_get {
+        GetChannelEmotesRequest::broadcaster_id(user_id)
+    }
+}
+
+helix_helper! {
+    /// Get channel emotes in channel with user login
+    ///
+    /// The login is normalized like in [`get_user_from_login`](helix::HelixClient::get_user_from_login).
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    pub async fn get_channel_emotes_from_login<T>(
+        &self,
+        login: impl AsRef<str> + Send,
+        token: &T,
+    ) -> Result<
+        Option<Vec<helix::chat::ChannelEmote>>,
+        helix::ClientExtError<C, crate::extra::InvalidLoginError>,
+    > {
+        if let Some(id) = self.resolve_user_id(login, token).await? {
+            self.get_channel_emotes_from_id(&id, token)
+                .await
+                .map(Some)
+                .map_err(helix::ClientExtError::ClientError)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+// From twitch docs
+// FIXME: Example has ... and is malformed, uses [] in images
+#[cfg(test)]
+const FIXTURE: &[u8] = br#"
     {
       "data": [
         {
@@ -113,8 +153,15 @@ fn test_request() {
       ],
       "template": "https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}"
     }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetChannelEmotesRequest::broadcaster_id("304456832");
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -124,5 +171,128 @@ fn test_request() {
         "https://api.twitch.tv/helix/chat/emotes?broadcaster_id=304456832"
     );
 
-    dbg!(GetChannelEmotesRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let response = GetChannelEmotesRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(
+        response.template().unwrap().as_deref(),
+        Some(
+            "https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}"
+        )
+    );
+    dbg!(response);
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_channel_emotes_by_tier() {
+    let emote = |id: &str, tier: &str, emote_type: &str| {
+        format!(
+            r#"{{"id":"{id}","name":"emote{id}","images":{{"url_1x":"","url_2x":"","url_4x":""}},"tier":"{tier}","emote_type":"{emote_type}","emote_set_id":"1","format":["static"],"scale":["1.0"],"theme_mode":["light"]}}"#
+        )
+    };
+    let emotes = [
+        emote("1", "2000", "subscriptions"),
+        emote("2", "1000", "subscriptions"),
+        emote("3", "", "follower"),
+        emote("4", "", "bitstier"),
+        emote("5", "1000", "subscriptions"),
+        emote("6", "", "limitedtime"),
+    ];
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "chat/emotes",
+        200,
+        &format!(r#"{{"data":[{}],"template":""}}"#, emotes.join(",")),
+    );
+    let client = mock.helix();
+    let token = crate::tests::app_token();
+
+    let by_tier = client
+        .get_channel_emotes_by_tier("1234", &token)
+        .await
+        .unwrap();
+    let grouped: Vec<(EmoteUnlock, Vec<&str>)> = by_tier
+        .iter()
+        .map(|(unlock, emotes)| {
+            (
+                unlock.clone(),
+                emotes.iter().map(|e| e.id.as_str()).collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        grouped,
+        [
+            (
+                EmoteUnlock::Subscription(types::SubscriptionTier::Tier1),
+                vec!["2", "5"]
+            ),
+            (
+                EmoteUnlock::Subscription(types::SubscriptionTier::Tier2),
+                vec!["1"]
+            ),
+            (EmoteUnlock::Follower, vec!["3"]),
+            (EmoteUnlock::Bits, vec!["4"]),
+            (
+                EmoteUnlock::Other(EmoteType::Unknown("limitedtime".to_owned())),
+                vec!["6"]
+            ),
+        ]
+    );
+    assert_eq!(
+        by_tier[&EmoteUnlock::Bits][0].emote_type,
+        EmoteType::BitsTier
+    );
+    assert_eq!(by_tier[&EmoteUnlock::Follower][0].tier, None);
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_channel_emotes_if_modified() {
+    let emotes = r#"{"data":[{"id":"304456832","name":"twitchdevPitchfork","images":{"url_1x":"","url_2x":"","url_4x":""},"tier":"1000","emote_type":"subscriptions","emote_set_id":"301590448","format":["static"],"scale":["1.0"],"theme_mode":["light"]}],"template":""}"#;
+    let mock = crate::tests::MockClient::new();
+    mock.respond_with_headers("chat/emotes", 200, &[("ETag", "W/\"1\"")], emotes)
+        .respond("chat/emotes", 304, "")
+        .respond("chat/emotes", 200, emotes);
+    let client = mock.helix();
+    let token = crate::tests::app_token();
+
+    let helix::Conditional::Modified { data, validator } = client
+        .get_channel_emotes_from_id_if_modified("141981764", None, &token)
+        .await
+        .unwrap()
+    else {
+        panic!("expected modified emotes");
+    };
+    assert_eq!(data[0].id.as_str(), "304456832");
+    assert_eq!(validator.etag.as_deref(), Some("W/\"1\""));
+    assert_eq!(validator.last_modified, None);
+
+    let outcome = client
+        .get_channel_emotes_from_id_if_modified("141981764", Some(&validator), &token)
+        .await
+        .unwrap();
+    assert_eq!(outcome, helix::Conditional::NotModified);
+
+    // without ETag, the response can't be validated again
+    let outcome = client
+        .get_channel_emotes_from_id_if_modified("141981764", Some(&validator), &token)
+        .await
+        .unwrap();
+    let helix::Conditional::Modified { validator, .. } = outcome else {
+        panic!("expected modified emotes");
+    };
+    assert!(validator.is_empty());
+
+    let requests = mock.requests();
+    assert_eq!(requests[1].1.query(), Some("broadcaster_id=141981764"));
+    assert_eq!(
+        mock.request_headers()[1][http::header::IF_NONE_MATCH],
+        "W/\"1\""
+    );
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetChannelEmotesRequest,
+    200 => FIXTURE,
 }
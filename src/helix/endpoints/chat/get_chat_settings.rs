@@ -82,6 +82,11 @@ pub struct GetChatSettingsRequest<'a> {
 
 impl<'a> GetChatSettingsRequest<'a> {
     /// Get chat settings for broadcasters channel
+    ///
+    /// ```rust
+    /// use twitch_api::helix::chat::GetChatSettingsRequest;
+    /// let request = GetChatSettingsRequest::broadcaster_id("1234567");
+    /// ```
     pub fn broadcaster_id(broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
         Self {
             broadcaster_id: broadcaster_id.into_cow(),
@@ -111,8 +116,19 @@ impl Request for GetChatSettingsRequest<'_> {
     const PATH: &'static str = "chat/settings";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+
+    #[cfg(feature = "twitch_oauth2")]
+    fn opt_scopes(&self) -> &'static [twitch_oauth2::Scope] {
+        if self.moderator_id.is_some() {
+            Self::OPT_SCOPE
+        } else {
+            &[]
+        }
+    }
 }
 
+endpoint_meta!(GET GetChatSettingsRequest<'static>);
+
 impl RequestGet for GetChatSettingsRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -132,6 +148,7 @@ impl RequestGet for GetChatSettingsRequest<'_> {
                             e,
                             uri.clone(),
                             status,
+                            None,
                         )
                     })?;
                 let [s] = resp.data;
@@ -143,6 +160,7 @@ impl RequestGet for GetChatSettingsRequest<'_> {
                     response: response.to_string(),
                     status,
                     uri: uri.clone(),
+                    request_id: None,
                 })
             }
         };
@@ -150,17 +168,28 @@ impl RequestGet for GetChatSettingsRequest<'_> {
     }
 }
 
-#[cfg(test)]
-#[test]
-fn test_request_as_mod() {
-    use helix::*;
-    let req = GetChatSettingsRequest::broadcaster_id("1234").moderator_id("713936733");
+helix_helper! {
+    /// Get a broadcaster's chat settings
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken) if `moderator_id` is [`None`](Option::None).
+    pub async fn get_chat_settings<'b, T>(
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl Into<Option<&'b types::UserIdRef>> + Send + 'b,
+    ) -> helix::chat::ChatSettings = req_get {
+        let req = GetChatSettingsRequest::broadcaster_id(broadcaster_id);
+        match moderator_id.into() {
+            Some(moderator_id) => req.moderator_id(moderator_id),
+            None => req,
+        }
+    }
+}
 
-    // Twitch's example is wrong,
-    // they didn't include a moderator id in the request
-    // but received `non_moderator_chat_delay`.
-    // From twitch docs
-    let data = br#"
+// Twitch's example is wrong,
+// they didn't include a moderator id in the request
+// but received `non_moderator_chat_delay`.
+// From twitch docs
+#[cfg(test)]
+const FIXTURE_AS_MOD: &[u8] = br#"
     {
       "data": [
         {
@@ -177,8 +206,15 @@ fn test_request_as_mod() {
         }
       ]
     }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request_as_mod() {
+    use helix::*;
+    let req = GetChatSettingsRequest::broadcaster_id("1234").moderator_id("713936733");
+
+    let data = FIXTURE_AS_MOD.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -191,14 +227,9 @@ fn test_request_as_mod() {
     dbg!(GetChatSettingsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
 
+// From twitch docs
 #[cfg(test)]
-#[test]
-fn test_request_as_user() {
-    use helix::*;
-    let req = GetChatSettingsRequest::broadcaster_id("11148817");
-
-    // From twitch docs
-    let data = br#"
+const FIXTURE_AS_USER: &[u8] = br#"
     {
       "data": [
         {
@@ -213,8 +244,15 @@ fn test_request_as_user() {
         }
       ]
     }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request_as_user() {
+    use helix::*;
+    let req = GetChatSettingsRequest::broadcaster_id("11148817");
+
+    let data = FIXTURE_AS_USER.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -226,3 +264,10 @@ fn test_request_as_user() {
 
     dbg!(GetChatSettingsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetChatSettingsRequest,
+    200 => FIXTURE_AS_MOD,
+    200 => FIXTURE_AS_USER,
+}
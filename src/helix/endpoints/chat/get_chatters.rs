@@ -60,7 +60,7 @@ pub struct GetChattersRequest<'a> {
     pub moderator_id: Cow<'a, types::UserIdRef>,
     /// The maximum number of items to return per page in the response. The minimum page size is 1 item per page and the maximum is 1,000. The default is 100.
     #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
-    pub first: Option<usize>,
+    pub first: Option<helix::PageSize>,
     /// The cursor used to get the next page of results. The Pagination object in the response contains the cursor’s value.
     #[cfg_attr(feature = "typed-builder", builder(default))]
     #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
@@ -73,6 +73,11 @@ impl<'a> GetChattersRequest<'a> {
     /// # Notes
     ///
     /// The moderator has to be the token owner and can moderate the chat
+    ///
+    /// ```rust
+    /// use twitch_api::helix::chat::GetChattersRequest;
+    /// let request = GetChattersRequest::new("1234", "4321");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -85,9 +90,9 @@ impl<'a> GetChattersRequest<'a> {
         }
     }
 
-    /// Set amount of results returned per page.
-    pub fn first(mut self, first: usize) -> Self {
-        self.first = Some(first);
+    /// Set amount of results returned per page, values above the limit of 1000 are clamped.
+    pub fn first(mut self, first: impl Into<Option<usize>>) -> Self {
+        self.first = first.into().map(helix::PageSize::clamped::<Self>);
         self
     }
 }
@@ -96,6 +101,10 @@ impl helix::Paginated for GetChattersRequest<'_> {
     fn set_pagination(&mut self, cursor: Option<helix::Cursor>) {
         self.after = cursor.map(|c| c.into_cow())
     }
+
+    fn set_first(&mut self, first: usize) {
+        self.first = Some(helix::PageSize::clamped::<Self>(first))
+    }
 }
 
 /// Return Values for [Get Chatters](super::get_chatters)
@@ -113,26 +122,109 @@ pub struct Chatter {
     pub user_name: types::DisplayName,
 }
 
+/// Borrowed [`Chatter`], returned by [`BorrowedResponse::data`](helix::BorrowedResponse::data)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct ChatterRef<'a> {
+    /// The ID of a user that’s connected to the broadcaster’s chat room.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub user_id: Cow<'a, types::UserIdRef>,
+    /// The user’s login name.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub user_login: Cow<'a, types::UserNameRef>,
+    /// The user’s display name.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub user_name: Cow<'a, types::DisplayNameRef>,
+}
+
+impl ChatterRef<'_> {
+    /// Convert into an owned [`Chatter`]
+    pub fn into_owned(self) -> Chatter {
+        Chatter {
+            user_id: self.user_id.into_owned(),
+            user_login: self.user_login.into_owned(),
+            user_name: self.user_name.into_owned(),
+        }
+    }
+}
+
 impl Request for GetChattersRequest<'_> {
     type Response = Vec<Chatter>;
 
+    const MAX_PAGE_SIZE: usize = 1000;
     const PATH: &'static str = "chat/chatters";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator =
         twitch_oauth2::validator![twitch_oauth2::Scope::ModeratorReadChatters];
+    const TIMEOUT_HINT: Option<std::time::Duration> = Some(helix::SLOW_ENDPOINT_TIMEOUT);
+
+    /// All chatters are returned with the required scope, whether the token is for the broadcaster or a moderator
+    #[cfg(feature = "twitch_oauth2")]
+    fn opt_scopes(&self) -> &'static [twitch_oauth2::Scope] { &[] }
 }
 
+endpoint_meta!(GET GetChattersRequest<'static>);
+
 impl RequestGet for GetChattersRequest<'_> {}
 
-#[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetChattersRequest::new("123456", "654321");
+impl helix::RequestGetBorrowed for GetChattersRequest<'_> {
+    type BorrowedResponse<'a> = Vec<ChatterRef<'a>>;
+}
 
-    // From twitch docs
-    // FIXME: Example has ...
-    let data = br#"
+helix_helper! {
+    /// Get chatters in a stream [Chatter][helix::chat::Chatter]
+    ///
+    /// `batch_size` sets the amount of chatters to retrieve per api call, max 1000, defaults to 100.
+    ///
+    /// `moderator_id` has to be the user of the token and a moderator of the broadcaster, or the broadcaster themselves,
+    /// otherwise the stream fails with a [`ChattersError`](helix::chat::ChattersError).
+    ///
+    /// The list can change while it's paginated, so a chatter can be returned on two pages or not at all.
+    /// Use [`get_chatters_deduped`](helix::HelixClient::get_chatters_deduped) to skip chatters that were already returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    /// use futures::TryStreamExt;
+    ///
+    /// let chatters: Vec<helix::chat::Chatter> = client
+    ///    .get_chatters("1234", "4321", 1000, &token)
+    ///    .try_collect().await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_chatters<T>(
+        broadcaster_id: impl Into<&'client types::UserIdRef>,
+        moderator_id: impl Into<&'client types::UserIdRef>,
+        batch_size: impl Into<Option<usize>>,
+    ) -> Stream<helix::chat::Chatter>, helix::chat::ChattersError = (helix::chat::ChattersError::from_client_error) {
+        GetChattersRequest::new(broadcaster_id.into(), moderator_id.into()).first(batch_size)
+    }
+}
+
+helix_helper! {
+    /// Get chatters in a stream like [`get_chatters`](helix::HelixClient::get_chatters), in the chat and as the moderator of `ctx`
+    pub fn get_chatters_ctx<T>(
+        ctx: &helix::moderation::ModerationContext<'_>,
+        batch_size: impl Into<Option<usize>>,
+    ) -> Stream<helix::chat::Chatter>, helix::chat::ChattersError = (helix::chat::ChattersError::from_client_error) {
+        GetChattersRequest::new(
+            ctx.broadcaster_id.as_ref().to_owned(),
+            ctx.moderator_id.as_ref().to_owned(),
+        )
+        .first(batch_size)
+    }
+}
+
+// From twitch docs
+// FIXME: Example has ...
+#[cfg(test)]
+const FIXTURE: &[u8] = br#"
     {
         "data": [{
                 "user_id": "128393656",
@@ -144,8 +236,15 @@ fn test_request() {
         },
         "total": 8
     }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetChattersRequest::new("123456", "654321");
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -155,5 +254,216 @@ fn test_request() {
         "https://api.twitch.tv/helix/chat/chatters?broadcaster_id=123456&moderator_id=654321"
     );
 
-    dbg!(GetChattersRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let response = GetChattersRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(response.total, Some(8));
+    dbg!(response);
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_get_chatter_logins() {
+    use futures::TryStreamExt;
+
+    fn page(n: usize, cursor: Option<&str>) -> String {
+        let chatters = (n * 1000..(n + 1) * 1000)
+            .map(|i| {
+                format!(r#"{{"user_id":"{i}","user_login":"chatter{i}","user_name":"Chatter{i}"}}"#)
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let pagination =
+            cursor.map_or_else(|| "{}".to_owned(), |c| format!(r#"{{"cursor":"{c}"}}"#));
+        format!(r#"{{"data":[{chatters}],"pagination":{pagination},"total":3000}}"#)
+    }
+
+    let mock = crate::tests::MockClient::new();
+    mock.respond("chat/chatters", 200, &page(0, Some("a")))
+        .respond("chat/chatters", 200, &page(1, Some("b")))
+        .respond("chat/chatters", 200, &page(2, None));
+    let token = crate::tests::user_token("moderator", "4321");
+    let client = mock.helix();
+
+    let mut stream = client.get_chatter_logins("1234", None, 1000, &token);
+    let mut count = 0;
+    while let Some(login) = stream.try_next().await.unwrap() {
+        assert_eq!(login.as_str(), format!("chatter{count}"));
+        count += 1;
+    }
+    assert_eq!(count, 3000);
+    assert_eq!(stream.total(), Some(3000));
+    assert_eq!(mock.pending(), 0);
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(
+        requests[0].1.query(),
+        Some("broadcaster_id=1234&moderator_id=4321&first=1000")
+    );
+    assert_eq!(
+        requests[2].1.query(),
+        Some("broadcaster_id=1234&moderator_id=4321&first=1000&after=b")
+    );
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_get_chatters_not_moderator() {
+    use futures::TryStreamExt;
+
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "chat/chatters",
+        403,
+        r#"{"error":"Forbidden","status":403,"message":"The user in moderator_id is not one of the broadcaster's moderators."}"#,
+    )
+    .respond(
+        "chat/chatters",
+        401,
+        r#"{"error":"Unauthorized","status":401,"message":"The ID in moderator_id must match the user ID found in the request's OAuth token."}"#,
+    );
+    let token = crate::tests::user_token("moderator", "4321");
+    let client = mock.helix();
+
+    let err = client
+        .get_chatters("1234", "4321", None, &token)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap_err();
+    let helix::ClientExtError::Other(helix::chat::ChattersError::NotModerator(e)) = err else {
+        panic!("expected NotModerator, got {err:?}")
+    };
+    assert_eq!(e.status, http::StatusCode::FORBIDDEN);
+
+    let err = client
+        .get_chatters("1234", "1111", None, &token)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        helix::ClientExtError::Other(helix::chat::ChattersError::ModeratorMismatch(_))
+    ));
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_get_chatters_deduped() {
+    use futures::TryStreamExt;
+
+    let mock = crate::tests::MockClient::new();
+    for _ in 0..2 {
+        mock.respond(
+            "chat/chatters",
+            200,
+            r#"{"data":[{"user_id":"1","user_login":"one","user_name":"One"},{"user_id":"2","user_login":"two","user_name":"Two"}],"pagination":{"cursor":"a"},"total":3}"#,
+        )
+        .respond(
+            "chat/chatters",
+            200,
+            r#"{"data":[{"user_id":"2","user_login":"two","user_name":"Two"},{"user_id":"3","user_login":"three","user_name":"Three"}],"pagination":{},"total":3}"#,
+        );
+    }
+    let token = crate::tests::user_token("moderator", "4321");
+    let client = mock.helix();
+
+    let ids = |chatters: Vec<Chatter>| {
+        chatters
+            .into_iter()
+            .map(|c| c.user_id.as_str().to_owned())
+            .collect::<Vec<_>>()
+    };
+    let raw = client
+        .get_chatters("1234", "4321", 2, &token)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(ids(raw), ["1", "2", "2", "3"]);
+
+    let mut stream = client.get_chatters_deduped("1234", "4321", 2, &token);
+    let mut deduped = vec![];
+    while let Some(chatter) = stream.try_next().await.unwrap() {
+        deduped.push(chatter);
+    }
+    assert_eq!(ids(deduped), ["1", "2", "3"]);
+    assert_eq!(stream.pages_fetched(), 2);
+    assert_eq!(stream.total(), Some(3));
+    assert_eq!(mock.pending(), 0);
+}
+
+#[cfg(test)]
+#[test]
+fn test_borrowed() {
+    use helix::*;
+    let req = GetChattersRequest::new("123456", "654321");
+
+    let data = br#"
+    {
+        "data": [
+            {"user_id": "128393656", "user_login": "smittysmithers", "user_name": "smittysmithers"},
+            {"user_id": "128393657", "user_login": "jose", "user_name": "Jos\u00e9"}
+        ],
+        "pagination": {
+            "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6NX19"
+        },
+        "total": 8
+    }
+"#
+    .to_vec();
+
+    let uri = req.get_uri().unwrap();
+    let owned = GetChattersRequest::parse_response(
+        Some(req.clone()),
+        &uri,
+        http::Response::builder().body(data.clone()).unwrap(),
+    )
+    .unwrap();
+    let response = BorrowedResponse::parse_response(
+        Some(req),
+        &uri,
+        http::Response::builder().body(data).unwrap(),
+    )
+    .unwrap();
+    let borrowed = response.data().unwrap();
+
+    assert_eq!(borrowed.total, Some(8));
+    assert_eq!(borrowed.pagination, owned.pagination);
+    assert!(matches!(borrowed.data[0].user_login, Cow::Borrowed(_)));
+    // escaped strings can't be borrowed
+    assert!(matches!(borrowed.data[1].user_name, Cow::Owned(_)));
+    assert_eq!(borrowed.data[1].user_name.as_str(), "José");
+    assert_eq!(
+        borrowed
+            .data
+            .into_iter()
+            .map(ChatterRef::into_owned)
+            .collect::<Vec<_>>(),
+        owned.data
+    );
+
+    let error = BorrowedResponse::<GetChattersRequest>::parse_response(
+        None,
+        &uri,
+        http::Response::builder()
+            .status(401)
+            .body(br#"{"error":"Unauthorized","status":401,"message":"Missing scope"}"#.to_vec())
+            .unwrap(),
+    );
+    assert!(matches!(error, Err(HelixRequestGetError::Error { .. })));
+}
+
+#[cfg(test)]
+#[test]
+fn test_first() {
+    use helix::*;
+    let req = GetChattersRequest::new("1234", "4321").first(5000);
+    assert_eq!(
+        req.get_uri().unwrap().to_string(),
+        "https://api.twitch.tv/helix/chat/chatters?broadcaster_id=1234&moderator_id=4321&first=1000"
+    );
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetChattersRequest,
+    200 => FIXTURE,
 }
@@ -57,6 +57,11 @@ pub struct GetEmoteSetsRequest<'a> {
 
 impl<'a> GetEmoteSetsRequest<'a> {
     /// Get emotes in these sets
+    ///
+    /// ```rust
+    /// use twitch_api::helix::chat::GetEmoteSetsRequest;
+    /// let request = GetEmoteSetsRequest::emote_set_ids(&"1234");
+    /// ```
     pub fn emote_set_ids(
         emote_set_ids: impl Into<types::Collection<'a, types::EmoteSetId>>,
     ) -> Self {
@@ -124,19 +129,16 @@ impl Request for GetEmoteSetsRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
-impl RequestGet for GetEmoteSetsRequest<'_> {}
+endpoint_meta!(GET GetEmoteSetsRequest<'static>);
 
-#[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
+impl RequestGet for GetEmoteSetsRequest<'_> {}
 
-    let ids: &[&types::EmoteSetIdRef] = &["301590448".into()];
-    let req = GetEmoteSetsRequest::emote_set_ids(ids);
+impl helix::private::SealedEmoteTemplate for GetEmoteSetsRequest<'_> {}
 
-    // From twitch docs
-    // FIXME: Example has ... and is malformed, uses [] in images
-    let data = br#"
+// From twitch docs
+// FIXME: Example has ... and is malformed, uses [] in images
+#[cfg(test)]
+const FIXTURE: &[u8] = br#"
     {
       "data": [
         {
@@ -166,8 +168,17 @@ fn test_request() {
       ],
       "template": "https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}"
     }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+
+    let ids: &[&types::EmoteSetIdRef] = &["301590448".into()];
+    let req = GetEmoteSetsRequest::emote_set_ids(ids);
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -177,5 +188,18 @@ fn test_request() {
         "https://api.twitch.tv/helix/chat/emotes/set?emote_set_id=301590448"
     );
 
-    dbg!(GetEmoteSetsRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let response = GetEmoteSetsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(
+        response.template().unwrap().as_deref(),
+        Some(
+            "https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}"
+        )
+    );
+    dbg!(response);
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetEmoteSetsRequest,
+    200 => FIXTURE,
 }
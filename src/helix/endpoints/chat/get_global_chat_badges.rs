@@ -47,6 +47,11 @@ pub struct GetGlobalChatBadgesRequest {}
 
 impl GetGlobalChatBadgesRequest {
     /// Get global chat badges
+    ///
+    /// ```rust
+    /// use twitch_api::helix::chat::GetGlobalChatBadgesRequest;
+    /// let request = GetGlobalChatBadgesRequest::new();
+    /// ```
     pub fn new() -> Self { Self::default() }
 }
 
@@ -63,17 +68,14 @@ impl Request for GetGlobalChatBadgesRequest {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetGlobalChatBadgesRequest);
+
 impl RequestGet for GetGlobalChatBadgesRequest {}
 
+// From twitch docs
+// FIXME: Example has ...
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetGlobalChatBadgesRequest::new();
-
-    // From twitch docs
-    // FIXME: Example has ...
-    let data = br#"
+const FIXTURE: &[u8] = br#"
     {
         "data": [
           {
@@ -91,8 +93,15 @@ fn test_request() {
           }
         ]
     }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetGlobalChatBadgesRequest::new();
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -104,3 +113,9 @@ fn test_request() {
 
     dbg!(GetGlobalChatBadgesRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetGlobalChatBadgesRequest,
+    200 => FIXTURE,
+}
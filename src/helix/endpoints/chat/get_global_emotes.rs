@@ -5,11 +5,11 @@
 //!
 //! ## Request: [GetGlobalEmotesRequest]
 //!
-//! To use this endpoint, construct a [`GetGlobalEmotesRequest`] with the [`GetGlobalEmotesRequest::default()`] method.
+//! To use this endpoint, construct a [`GetGlobalEmotesRequest`] with the [`GetGlobalEmotesRequest::new()`] method.
 //!
 //! ```rust
 //! use twitch_api::helix::chat::get_global_emotes;
-//! let request = get_global_emotes::GetGlobalEmotesRequest::default();
+//! let request = get_global_emotes::GetGlobalEmotesRequest::new();
 //! ```
 //!
 //! ## Response: [GlobalEmote]
@@ -24,7 +24,7 @@
 //! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
-//! let request = get_global_emotes::GetGlobalEmotesRequest::default();
+//! let request = get_global_emotes::GetGlobalEmotesRequest::new();
 //! let response: Vec<helix::chat::GlobalEmote> = client.req_get(request, &token).await?.data;
 //! # Ok(())
 //! # }
@@ -47,6 +47,11 @@ pub struct GetGlobalEmotesRequest {}
 
 impl GetGlobalEmotesRequest {
     /// Get global emotes
+    ///
+    /// ```rust
+    /// use twitch_api::helix::chat::GetGlobalEmotesRequest;
+    /// let request = GetGlobalEmotesRequest::new();
+    /// ```
     pub fn new() -> Self { Self {} }
 }
 
@@ -63,17 +68,40 @@ impl Request for GetGlobalEmotesRequest {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetGlobalEmotesRequest);
+
 impl RequestGet for GetGlobalEmotesRequest {}
 
-#[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetGlobalEmotesRequest::default();
+impl helix::private::SealedEmoteTemplate for GetGlobalEmotesRequest {}
 
-    // From twitch docs
-    // FIXME: Example has ...
-    let data = br#"
+helix_helper! {
+    /// Get all global emotes
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_secret");
+    /// let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+    /// use twitch_api::helix;
+    ///
+    /// let emotes: Vec<helix::chat::GlobalEmote> = client.get_global_emotes(&token).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_global_emotes<T>() -> Vec<helix::chat::GlobalEmote> = req_get {
+        GetGlobalEmotesRequest::new()
+    }
+}
+
+// From twitch docs
+// FIXME: Example has ...
+#[cfg(test)]
+const FIXTURE: &[u8] = br#"
 
     {
       "data": [
@@ -101,8 +129,15 @@ fn test_request() {
       ],
       "template": "https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}"
     }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetGlobalEmotesRequest::new();
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -112,5 +147,67 @@ fn test_request() {
         "https://api.twitch.tv/helix/chat/emotes/global?"
     );
 
-    dbg!(GetGlobalEmotesRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let response = GetGlobalEmotesRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(
+        response.template().unwrap().as_deref(),
+        Some(
+            "https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}"
+        )
+    );
+    dbg!(response);
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_global_emotes_if_modified() {
+    let emotes = r#"{"data":[{"id":"196892","name":"TwitchUnity","images":{"url_1x":"","url_2x":"","url_4x":""},"format":["static"],"scale":["1.0"],"theme_mode":["light"]}],"template":""}"#;
+    let mock = crate::tests::MockClient::new();
+    mock.respond_with_headers(
+        "chat/emotes/global",
+        200,
+        &[
+            ("ETag", r#""abc123""#),
+            ("Last-Modified", "Wed, 21 Oct 2015 07:28:00 GMT"),
+        ],
+        emotes,
+    )
+    // not json, a 304 must not be parsed
+    .respond("chat/emotes/global", 304, "not modified");
+    let client = mock.helix();
+    let token = crate::tests::app_token();
+
+    let helix::Conditional::Modified { data, validator } = client
+        .get_global_emotes_if_modified(None, &token)
+        .await
+        .unwrap()
+    else {
+        panic!("expected modified emotes");
+    };
+    assert_eq!(data[0].id.as_str(), "196892");
+    assert_eq!(validator.etag.as_deref(), Some(r#""abc123""#));
+    assert_eq!(
+        validator.last_modified.as_deref(),
+        Some("Wed, 21 Oct 2015 07:28:00 GMT")
+    );
+
+    let outcome = client
+        .get_global_emotes_if_modified(Some(&validator), &token)
+        .await
+        .unwrap();
+    assert_eq!(outcome, helix::Conditional::NotModified);
+
+    let headers = mock.request_headers();
+    assert!(!headers[0].contains_key(http::header::IF_NONE_MATCH));
+    assert_eq!(headers[1][http::header::IF_NONE_MATCH], r#""abc123""#);
+    assert_eq!(
+        headers[1][http::header::IF_MODIFIED_SINCE],
+        "Wed, 21 Oct 2015 07:28:00 GMT"
+    );
+    assert_eq!(mock.pending(), 0);
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetGlobalEmotesRequest,
+    200 => FIXTURE,
 }
@@ -106,18 +106,77 @@ impl Request for GetUserChatColorRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetUserChatColorRequest<'static>);
+
 impl RequestGet for GetUserChatColorRequest<'_> {}
 
-#[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let ids: &[&types::UserIdRef] = &["11111".into(), "44444".into()];
-    let req = GetUserChatColorRequest::user_ids(ids);
+helix_helper! {
+    /// Get a user's chat color
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// [`None`](Option::None) is returned if the user never set their color in the settings.
+    pub async fn get_user_chat_color<T>(
+        &self,
+        user_id: impl Into<&types::UserIdRef> + Send,
+        token: &T,
+    ) -> Result<Option<UserChatColor>, helix::ClientRequestError<<C as crate::HttpClient>::Error>> {
+        self.req_get(GetUserChatColorRequest::user_ids(&user_id.into()), token)
+            .await?
+            .into_optional()
+            .map_err(helix::client::client_ext::unexpected_response)
+    }
+}
+
+helix_helper! {
+    /// Get multiple users' chat colors
+    ///
+    /// Duplicated ids are only requested once, the colors are returned in the order of `user_ids`.
+    ///
+    /// Works with an [app access token](twitch_oauth2::AppAccessToken), no scopes are required.
+    ///
+    /// Users that never set their color in the settings are not returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::{types, helix};
+    /// use futures::TryStreamExt;
+    ///
+    /// let colors: Vec<helix::chat::UserChatColor> = client
+    ///     .get_users_chat_colors(&["1234"][..].into(), &token).try_collect().await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_users_chat_colors<T>(
+        &self,
+        user_ids: &'client types::Collection<'client, types::UserId>,
+        token: &'client T,
+    ) -> impl futures::Stream<
+        Item = Result<UserChatColor, helix::ClientRequestError<<C as crate::HttpClient>::Error>>,
+    > + Send
+        + Unpin
+        + 'client {
+        helix::client::client_ext::chunked_request(
+            user_ids.iter(),
+            100,
+            |color: &UserChatColor| &*color.user_id,
+            move |c| async move {
+                let req = GetUserChatColorRequest::user_ids(c);
+                Ok(self.req_get(req, token).await?.data)
+            },
+        )
+    }
+}
 
-    // From twitch docs
-    // FIXME: Example has ...
-    let data = br##"
+// From twitch docs
+// FIXME: Example has ...
+#[cfg(test)]
+const FIXTURE: &[u8] = br##"
     {
         "data": [
           {
@@ -134,8 +193,16 @@ fn test_request() {
           }
         ]
       }
-"##
-    .to_vec();
+"##;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let ids: &[&types::UserIdRef] = &["11111".into(), "44444".into()];
+    let req = GetUserChatColorRequest::user_ids(ids);
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -149,3 +216,70 @@ fn test_request() {
         dbg!(GetUserChatColorRequest::parse_response(Some(req), &uri, http_response).unwrap());
     assert!(resp.data.get(1).unwrap().color.is_none())
 }
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_get_user_chat_color() {
+    let mock = crate::tests::MockClient::new();
+    let entry = r##"{"user_id":"11111","user_name":"SpeedySpeedster1","user_login":"speedyspeedster1","color":"#9146FF"}"##;
+    mock.respond("chat/color", 200, r#"{"data":[]}"#)
+        .respond("chat/color", 200, &format!(r#"{{"data":[{entry}]}}"#))
+        .respond(
+            "chat/color",
+            200,
+            &format!(r#"{{"data":[{entry},{entry}]}}"#),
+        );
+    let token = crate::tests::user_token("user", "1234");
+    let client = mock.helix();
+
+    assert_eq!(
+        client.get_user_chat_color("11111", &token).await.unwrap(),
+        None
+    );
+    assert!(client
+        .get_user_chat_color("11111", &token)
+        .await
+        .unwrap()
+        .is_some());
+    assert!(matches!(
+        client.get_user_chat_color("11111", &token).await,
+        Err(helix::ClientRequestError::UnexpectedResponse(
+            helix::SingleItemError::Multiple { count: 2 }
+        ))
+    ));
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_get_users_chat_colors_map() {
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "chat/color",
+        200,
+        r##"{"data":[{"user_id":"0","user_name":"User0","user_login":"user0","color":"#9146FF"},{"user_id":"1","user_name":"User1","user_login":"user1","color":""}]}"##,
+    )
+    .respond("chat/color", 200, r#"{"data":[]}"#);
+    let token = crate::tests::user_token("user", "1234");
+
+    let ids: Vec<types::UserId> = (0..101).map(|i| i.to_string().into()).collect();
+    let colors = mock
+        .helix()
+        .get_users_chat_colors_map(&ids[..].into(), &token)
+        .await
+        .unwrap();
+    assert_eq!(colors.len(), 101);
+    assert_eq!(
+        colors[&types::UserId::from_static("0")],
+        Some(types::HexColor::from_static("#9146FF"))
+    );
+    assert_eq!(colors[&types::UserId::from_static("1")], None);
+    assert_eq!(colors[&types::UserId::from_static("2")], None);
+    assert_eq!(colors[&types::UserId::from_static("100")], None);
+    assert_eq!(mock.requests().len(), 2);
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetUserChatColorRequest,
+    200 => FIXTURE,
+}
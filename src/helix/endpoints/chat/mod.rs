@@ -28,7 +28,7 @@ pub use get_channel_emotes::GetChannelEmotesRequest;
 #[doc(inline)]
 pub use get_chat_settings::GetChatSettingsRequest;
 #[doc(inline)]
-pub use get_chatters::{Chatter, GetChattersRequest};
+pub use get_chatters::{Chatter, ChatterRef, GetChattersRequest};
 #[doc(inline)]
 pub use get_emote_sets::GetEmoteSetsRequest;
 #[doc(inline)]
@@ -41,13 +41,19 @@ pub use get_user_chat_color::{GetUserChatColorRequest, UserChatColor};
 pub use send_a_shoutout::{SendAShoutoutRequest, SendAShoutoutResponse};
 #[doc(inline)]
 pub use send_chat_announcement::{
-    SendChatAnnouncementBody, SendChatAnnouncementRequest, SendChatAnnouncementResponse,
+    SendChatAnnouncementBody, SendChatAnnouncementBodyError, SendChatAnnouncementRequest,
+    SendChatAnnouncementResponse,
 };
 #[doc(inline)]
 pub use send_chat_message::{
     ChatMessageDropCode, ChatMessageDropReason, SendChatMessageBody, SendChatMessageRequest,
     SendChatMessageResponse,
 };
+#[cfg(feature = "client")]
+#[doc(inline)]
+pub use send_chat_message::{
+    ChatMessageRetry, ChunkedMessageError, ChunkedMessageFailure, SendChatMessageError,
+};
 #[doc(inline)]
 pub use update_chat_settings::{UpdateChatSettingsBody, UpdateChatSettingsRequest};
 #[doc(inline)]
@@ -56,6 +62,153 @@ pub use update_user_chat_color::{UpdateUserChatColorRequest, UpdateUserChatColor
 #[doc(inline)]
 pub use crate::extra::AnnouncementColor;
 
+/// Maximum length of a [chat message](SendChatMessageBody::message) or [announcement](SendChatAnnouncementBody::message), in characters.
+pub const MAX_MESSAGE_LENGTH: usize = 500;
+
+/// A chat message or announcement is longer than [`MAX_MESSAGE_LENGTH`] characters
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("message is {length} characters long, the maximum is 500")]
+#[non_exhaustive]
+pub struct MessageTooLongError {
+    /// Length of the rejected message, in characters
+    pub length: usize,
+}
+
+fn check_message_length(message: &str) -> Result<(), MessageTooLongError> {
+    let length = message.chars().count();
+    if length > MAX_MESSAGE_LENGTH {
+        return Err(MessageTooLongError { length });
+    }
+    Ok(())
+}
+
+impl<R, D> helix::Response<R, D>
+where
+    R: Request + helix::private::SealedEmoteTemplate,
+    D: serde::de::DeserializeOwned + PartialEq,
+{
+    /// The template to build emote image urls with, as returned by Twitch.
+    ///
+    /// Available on the responses of [Get Channel Emotes](get_channel_emotes), [Get Emote Sets](get_emote_sets) and [Get Global Emotes](get_global_emotes).
+    /// Contains the placeholders `{{id}}`, `{{format}}`, `{{scale}}` and `{{theme_mode}}`.
+    pub fn template(&self) -> Result<Option<String>, serde_json::Error> {
+        self.get_other("template")
+    }
+}
+
+/// Normalize user input into a color for [Update User Chat Color](update_user_chat_color)
+///
+/// Surrounding whitespace is removed, a hex color without a leading `#` gets one and names are lowercased.
+/// The result can be parsed into a [`NamedUserColor`](types::NamedUserColor).
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api::helix::chat;
+///
+/// assert_eq!(chat::normalize_user_color("9146FF"), "#9146FF");
+/// assert_eq!(chat::normalize_user_color("#9146FF"), "#9146FF");
+/// assert_eq!(chat::normalize_user_color(" Blue_Violet "), "blue_violet");
+/// ```
+pub fn normalize_user_color(input: &str) -> Cow<'_, str> {
+    let input = input.trim();
+    if input.len() == 6 && input.chars().all(|c| c.is_ascii_hexdigit()) {
+        Cow::Owned(format!("#{input}"))
+    } else if input.starts_with('#') || !input.chars().any(|c| c.is_ascii_uppercase()) {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(input.to_ascii_lowercase())
+    }
+}
+
+/// Error returned by [`HelixClient::set_own_chat_color`](helix::HelixClient::set_own_chat_color)
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SetOwnChatColorError {
+    /// The token has no user id, like an app access token
+    #[error("no user id found on token")]
+    NoUserIdOnToken,
+    /// The color is not a named or hex color
+    #[error("{color:?} is not a named color or hex color")]
+    InvalidColor {
+        /// The rejected color
+        color: String,
+    },
+}
+
+/// Known errors of [Get Chatters](get_chatters), returned by [`HelixClient::get_chatters`](helix::HelixClient::get_chatters)
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[non_exhaustive]
+pub enum ChattersError {
+    /// moderator_id is not the user of the token: {0}
+    ModeratorMismatch(helix::HelixApiError),
+    /// moderator_id is not a moderator of the broadcaster: {0}
+    NotModerator(helix::HelixApiError),
+}
+
+impl ChattersError {
+    /// The error returned by Twitch
+    pub fn api_error(&self) -> &helix::HelixApiError {
+        match self {
+            Self::ModeratorMismatch(e) | Self::NotModerator(e) => e,
+        }
+    }
+
+    /// Map a known error returned by Twitch into a [`ChattersError`], other errors are kept as is
+    #[cfg(feature = "client")]
+    pub(crate) fn from_client_error<RE: std::error::Error + Send + Sync + 'static>(
+        error: helix::ClientRequestError<RE>,
+    ) -> Result<Self, helix::ClientRequestError<RE>> {
+        let variant: fn(helix::HelixApiError) -> Self = match &error {
+            helix::ClientRequestError::HelixRequestGetError(
+                helix::HelixRequestGetError::Error { message, .. },
+            ) if message.to_lowercase().contains("must match") => Self::ModeratorMismatch,
+            helix::ClientRequestError::HelixRequestGetError(
+                helix::HelixRequestGetError::Error { status, .. },
+            ) if *status == http::StatusCode::FORBIDDEN => Self::NotModerator,
+            _ => return Err(error),
+        };
+        error.into_api_error().map(variant)
+    }
+}
+
+/// Split a message into parts of at most [`MAX_MESSAGE_LENGTH`] characters
+///
+/// Parts are split at the last whitespace that fits, or between two characters if there is none.
+/// Whitespace around the parts is removed. Characters that display as one, like some emoji, can be split apart.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api::helix::chat;
+///
+/// let message = "word ".repeat(150);
+/// let parts = chat::split_message(&message);
+/// assert_eq!(parts.len(), 2);
+/// assert!(parts
+///     .iter()
+///     .all(|p| p.chars().count() <= chat::MAX_MESSAGE_LENGTH));
+/// ```
+pub fn split_message(message: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut rest = message.trim();
+    while !rest.is_empty() {
+        // byte index of the first character that doesn't fit
+        let Some((end, _)) = rest.char_indices().nth(MAX_MESSAGE_LENGTH) else {
+            parts.push(rest);
+            break;
+        };
+        let split = if rest[end..].starts_with(char::is_whitespace) {
+            end
+        } else {
+            rest[..end].rfind(char::is_whitespace).unwrap_or(end)
+        };
+        parts.push(rest[..split].trim_end());
+        rest = rest[split..].trim_start();
+    }
+    parts
+}
+
 /// A set of badges
 #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
@@ -104,17 +257,8 @@ pub struct ChannelEmote {
         deserialize_with = "crate::deserialize_none_from_empty_string"
     )]
     pub tier: Option<types::SubscriptionTier>,
-    // FIXME: Enumify?
     /// The type of emote.
-    ///
-    /// The most common values for custom channel emotes are
-    ///
-    /// `subscriptions`: Indicates a custom subscriber emote.
-    ///
-    /// `bitstier`: Indicates a custom Bits tier emote.
-    ///
-    /// `follower`: Indicates a custom follower emote.
-    pub emote_type: String,
+    pub emote_type: EmoteType,
     /// ID of the emote set the emote belongs to.
     pub emote_set_id: types::EmoteSetId,
     /// The formats that the emote is available in.
@@ -143,6 +287,77 @@ impl ChannelEmote {
     /// # }
     /// ```
     pub fn url(&self) -> types::EmoteUrlBuilder<'_> { EmoteUrlBuilder::new(&self.id) }
+
+    /// What unlocks this emote for viewers of the channel
+    ///
+    /// Subscriber emotes without a [tier](ChannelEmote::tier) are [`EmoteUnlock::Other`].
+    pub fn unlock(&self) -> EmoteUnlock {
+        match (&self.emote_type, &self.tier) {
+            (EmoteType::Subscriptions, Some(tier)) => EmoteUnlock::Subscription(tier.clone()),
+            (EmoteType::Follower, _) => EmoteUnlock::Follower,
+            (EmoteType::BitsTier, _) => EmoteUnlock::Bits,
+            (emote_type, _) => EmoteUnlock::Other(emote_type.clone()),
+        }
+    }
+}
+
+/// Type of a [channel emote](ChannelEmote::emote_type)
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum EmoteType {
+    /// A custom subscriber emote
+    #[serde(rename = "subscriptions")]
+    Subscriptions,
+    /// A custom Bits tier emote
+    #[serde(rename = "bitstier")]
+    BitsTier,
+    /// A custom follower emote
+    #[serde(rename = "follower")]
+    Follower,
+    /// An emote type not known to this crate
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// What unlocks a [channel emote](ChannelEmote::unlock), used as key in [`HelixClient::get_channel_emotes_by_tier`](crate::helix::HelixClient::get_channel_emotes_by_tier)
+///
+/// Subscriptions are ordered by tier, before follower, Bits and other emotes.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+#[non_exhaustive]
+pub enum EmoteUnlock {
+    /// Subscribing to the channel at this tier, or a higher one
+    Subscription(types::SubscriptionTier),
+    /// Following the channel
+    Follower,
+    /// Cheering Bits in the channel
+    Bits,
+    /// Any other way
+    Other(EmoteType),
+}
+
+impl EmoteUnlock {
+    fn sort_key(&self) -> (u8, u8, Option<&str>, Option<&EmoteType>) {
+        match self {
+            EmoteUnlock::Subscription(tier) => match tier {
+                types::SubscriptionTier::Prime => (0, 0, None, None),
+                types::SubscriptionTier::Tier1 => (0, 1, None, None),
+                types::SubscriptionTier::Tier2 => (0, 2, None, None),
+                types::SubscriptionTier::Tier3 => (0, 3, None, None),
+                types::SubscriptionTier::Other(tier) => (0, 4, Some(tier.as_str()), None),
+            },
+            EmoteUnlock::Follower => (1, 0, None, None),
+            EmoteUnlock::Bits => (2, 0, None, None),
+            EmoteUnlock::Other(emote_type) => (3, 0, None, Some(emote_type)),
+        }
+    }
+}
+
+impl PartialOrd for EmoteUnlock {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for EmoteUnlock {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering { self.sort_key().cmp(&other.sort_key()) }
 }
 
 /// A chat emote
@@ -164,6 +379,148 @@ pub struct GlobalEmote {
     pub theme_mode: Vec<types::EmoteThemeMode>,
 }
 
+/// An emote in [`ChatRenderAssets`]
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub enum RenderEmote {
+    /// An emote of the channel
+    Channel(ChannelEmote),
+    /// A global emote
+    Global(GlobalEmote),
+}
+
+impl RenderEmote {
+    /// ID of the emote.
+    pub fn id(&self) -> &types::EmoteIdRef {
+        match self {
+            RenderEmote::Channel(emote) => &emote.id,
+            RenderEmote::Global(emote) => &emote.id,
+        }
+    }
+
+    /// Name of the emote a viewer types into Twitch chat for the image to appear.
+    pub fn name(&self) -> &str {
+        match self {
+            RenderEmote::Channel(emote) => &emote.name,
+            RenderEmote::Global(emote) => &emote.name,
+        }
+    }
+
+    /// Object of image URLs for the emote.
+    pub fn images(&self) -> &types::Image {
+        match self {
+            RenderEmote::Channel(emote) => &emote.images,
+            RenderEmote::Global(emote) => &emote.images,
+        }
+    }
+
+    /// Create an emote builder for this emote.
+    pub fn url(&self) -> types::EmoteUrlBuilder<'_> { EmoteUrlBuilder::new(self.id()) }
+}
+
+/// Emotes and badges needed to render chat in a channel
+///
+/// Returned by [`HelixClient::get_chat_render_assets`](helix::HelixClient::get_chat_render_assets).
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct ChatRenderAssets {
+    /// Emotes by name, a channel emote replaces a global emote with the same name.
+    pub emotes: std::collections::HashMap<String, RenderEmote>,
+    /// The template to build emote image urls with, as returned by Twitch.
+    ///
+    /// Contains the placeholders `{{id}}`, `{{format}}`, `{{scale}}` and `{{theme_mode}}`.
+    pub template: Option<String>,
+    /// Badges by set id and version, a channel badge replaces a global badge with the same set id and version.
+    pub badges: std::collections::HashMap<(types::BadgeSetId, types::ChatBadgeId), ChatBadge>,
+}
+
+impl ChatRenderAssets {
+    /// Combine global and channel emotes and badges
+    pub fn new(
+        global_emotes: impl IntoIterator<Item = GlobalEmote>,
+        channel_emotes: impl IntoIterator<Item = ChannelEmote>,
+        global_badges: impl IntoIterator<Item = BadgeSet>,
+        channel_badges: impl IntoIterator<Item = BadgeSet>,
+        template: Option<String>,
+    ) -> Self {
+        let mut assets = Self {
+            template,
+            ..Self::default()
+        };
+        assets.emotes.extend(
+            global_emotes
+                .into_iter()
+                .map(|e| (e.name.clone(), RenderEmote::Global(e))),
+        );
+        assets.emotes.extend(
+            channel_emotes
+                .into_iter()
+                .map(|e| (e.name.clone(), RenderEmote::Channel(e))),
+        );
+        for set in global_badges.into_iter().chain(channel_badges) {
+            for badge in set.versions {
+                assets
+                    .badges
+                    .insert((set.set_id.clone(), badge.id.clone()), badge);
+            }
+        }
+        assets
+    }
+
+    /// Get an emote by name
+    pub fn emote(&self, name: &str) -> Option<&RenderEmote> { self.emotes.get(name) }
+
+    /// Get a badge by set id and version, e.g. `("subscriber", "12")`
+    pub fn badge(&self, set_id: &str, version: &str) -> Option<&ChatBadge> {
+        self.badges.get(&(set_id.into(), version.into()))
+    }
+}
+
+/// The responses that make up [`ChatRenderAssets`], each of which may have failed
+///
+/// Returned by [`HelixClient::get_chat_render_assets_partial`](helix::HelixClient::get_chat_render_assets_partial).
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ChatRenderAssetsParts<E> {
+    /// Global emotes
+    pub global_emotes: Result<Vec<GlobalEmote>, E>,
+    /// Emotes of the channel
+    pub channel_emotes: Result<Vec<ChannelEmote>, E>,
+    /// Global badges
+    pub global_badges: Result<Vec<BadgeSet>, E>,
+    /// Badges of the channel
+    pub channel_badges: Result<Vec<BadgeSet>, E>,
+    /// The template to build emote image urls with, if any emote request succeeded
+    pub template: Option<String>,
+}
+
+impl<E> ChatRenderAssetsParts<E> {
+    /// Combine the parts that succeeded, skipping the ones that failed
+    pub fn assets(&self) -> ChatRenderAssets {
+        fn ok<T: Clone, E>(part: &Result<Vec<T>, E>) -> Vec<T> {
+            part.as_ref().map_or_else(|_| vec![], Clone::clone)
+        }
+        ChatRenderAssets::new(
+            ok(&self.global_emotes),
+            ok(&self.channel_emotes),
+            ok(&self.global_badges),
+            ok(&self.channel_badges),
+            self.template.clone(),
+        )
+    }
+
+    /// Combine all parts, failing with the first error
+    pub fn into_assets(self) -> Result<ChatRenderAssets, E> {
+        Ok(ChatRenderAssets::new(
+            self.global_emotes?,
+            self.channel_emotes?,
+            self.global_badges?,
+            self.channel_badges?,
+            self.template,
+        ))
+    }
+}
+
 /// Chat settings
 #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
@@ -215,3 +572,164 @@ pub struct ChatSettings {
     /// Is true, if the broadcaster requires unique messages only; otherwise, false.
     pub unique_chat_mode: bool,
 }
+
+#[cfg(test)]
+#[test]
+fn test_split_message() {
+    assert!(split_message("").is_empty());
+    assert!(split_message("   ").is_empty());
+    assert_eq!(split_message(" hello chat "), vec!["hello chat"]);
+
+    // exactly at the limit
+    let message = "a".repeat(MAX_MESSAGE_LENGTH);
+    assert_eq!(split_message(&message), vec![message.as_str()]);
+
+    // prefer whitespace
+    let message = format!("{} {}", "a".repeat(300), "b".repeat(300));
+    assert_eq!(
+        split_message(&message),
+        vec!["a".repeat(300).as_str(), "b".repeat(300).as_str()]
+    );
+    let message = format!("{} {}", "a".repeat(MAX_MESSAGE_LENGTH), "b");
+    assert_eq!(
+        split_message(&message),
+        vec!["a".repeat(MAX_MESSAGE_LENGTH).as_str(), "b"]
+    );
+
+    // emoji at the boundary
+    let message = format!("{}😀😀", "a".repeat(MAX_MESSAGE_LENGTH - 1));
+    let parts = split_message(&message);
+    assert_eq!(parts.len(), 2);
+    assert!(parts[0].ends_with('😀'));
+    assert_eq!(parts[0].chars().count(), MAX_MESSAGE_LENGTH);
+    assert_eq!(parts[1], "😀");
+
+    // CJK without whitespace
+    let message = "字".repeat(MAX_MESSAGE_LENGTH * 2 + 1);
+    let parts = split_message(&message);
+    assert_eq!(
+        parts.iter().map(|p| p.chars().count()).collect::<Vec<_>>(),
+        vec![MAX_MESSAGE_LENGTH, MAX_MESSAGE_LENGTH, 1]
+    );
+    assert_eq!(parts.concat(), message);
+}
+
+#[cfg(all(test, feature = "client"))]
+mod client_tests {
+    const IMAGES: &str = r#"{"url_1x":"","url_2x":"","url_4x":""}"#;
+    const TEMPLATE: &str =
+        "https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}";
+
+    fn emotes(emotes: &[(&str, &str)], channel: bool) -> String {
+        let emotes: Vec<_> = emotes
+            .iter()
+            .map(|(id, name)| {
+                let channel = if channel {
+                    r#""tier":"1000","emote_type":"subscriptions","emote_set_id":"1","#
+                } else {
+                    ""
+                };
+                format!(
+                    r#"{{"id":"{id}","name":"{name}","images":{IMAGES},{channel}"format":["static"],"scale":["1.0"],"theme_mode":["light"]}}"#
+                )
+            })
+            .collect();
+        format!(
+            r#"{{"data":[{}],"template":"{TEMPLATE}"}}"#,
+            emotes.join(",")
+        )
+    }
+
+    fn badges(set_id: &str, title: &str) -> String {
+        format!(
+            r#"{{"data":[{{"set_id":"{set_id}","versions":[{{"id":"1","image_url_1x":"","image_url_2x":"","image_url_4x":"","title":"{title}","description":""}}]}}]}}"#
+        )
+    }
+
+    fn mock() -> crate::tests::MockClient {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "chat/emotes/global",
+            200,
+            &emotes(&[("25", "Kappa"), ("88", "PogChamp")], false),
+        )
+        .respond(
+            "chat/emotes",
+            200,
+            &emotes(&[("emotesv2_1", "PogChamp")], true),
+        )
+        .respond("chat/badges/global", 200, &badges("subscriber", "Global"))
+        .respond("chat/badges", 200, &badges("subscriber", "Channel"));
+        mock
+    }
+
+    #[tokio::test]
+    async fn chat_render_assets() {
+        let mock = mock();
+        let token = crate::tests::user_token("justinfan", "1337");
+        let assets = mock
+            .helix()
+            .get_chat_render_assets("141981764", &token)
+            .await
+            .unwrap();
+
+        let mut paths: Vec<_> = mock
+            .requests()
+            .iter()
+            .map(|(_, uri, _)| uri.path_and_query().unwrap().to_string())
+            .collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [
+                "/helix/chat/badges/global",
+                "/helix/chat/badges?broadcaster_id=141981764",
+                "/helix/chat/emotes/global",
+                "/helix/chat/emotes?broadcaster_id=141981764",
+            ]
+        );
+
+        assert_eq!(assets.emotes.len(), 2);
+        assert!(matches!(
+            assets.emote("Kappa"),
+            Some(super::RenderEmote::Global(_))
+        ));
+        let pogchamp = assets.emote("PogChamp").unwrap();
+        assert!(matches!(pogchamp, super::RenderEmote::Channel(_)));
+        assert_eq!(pogchamp.id().as_str(), "emotesv2_1");
+        assert_eq!(assets.template.as_deref(), Some(TEMPLATE));
+        assert_eq!(assets.badge("subscriber", "1").unwrap().title, "Channel");
+        assert!(assets.badge("subscriber", "2").is_none());
+    }
+
+    #[tokio::test]
+    async fn chat_render_assets_partial() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "chat/emotes/global",
+            200,
+            &emotes(&[("25", "Kappa")], false),
+        )
+        .respond("chat/badges/global", 200, &badges("subscriber", "Global"))
+        .respond("chat/badges", 503, "");
+        let token = crate::tests::user_token("justinfan", "1337");
+        let client = mock.helix();
+
+        let parts = client
+            .get_chat_render_assets_partial("141981764", &token)
+            .await;
+        assert_eq!(mock.requests().len(), 4);
+        assert!(parts.channel_emotes.is_err());
+        assert!(parts.channel_badges.is_err());
+        let assets = parts.assets();
+        assert!(assets.emote("Kappa").is_some());
+        assert_eq!(assets.badge("subscriber", "1").unwrap().title, "Global");
+        assert_eq!(assets.template.as_deref(), Some(TEMPLATE));
+        assert!(parts.into_assets().is_err());
+
+        assert!(client
+            .get_chat_render_assets("141981764", &token)
+            .await
+            .is_err());
+    }
+}
@@ -61,6 +61,11 @@ pub struct SendAShoutoutRequest<'a> {
 
 impl<'a> SendAShoutoutRequest<'a> {
     /// Create a new [`SendAShoutoutRequest`]
+    ///
+    /// ```rust
+    /// use twitch_api::helix::chat::SendAShoutoutRequest;
+    /// let request = SendAShoutoutRequest::new("1234", "5678", "9123");
+    /// ```
     pub fn new(
         from_broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         to_broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -77,7 +82,7 @@ impl<'a> SendAShoutoutRequest<'a> {
 /// Return Values for [Send A Shoutout](super::send_a_shoutout)
 ///
 /// [`send-a-shoutout`](https://dev.twitch.tv/docs/api/reference#send-a-shoutout)
-#[derive(PartialEq, Eq, Deserialize, Debug, Clone)]
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub enum SendAShoutoutResponse {
@@ -93,6 +98,8 @@ impl Request for SendAShoutoutRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ModeratorManageShoutouts];
 }
 
+endpoint_meta!(POST SendAShoutoutRequest<'static>);
+
 impl RequestPost for SendAShoutoutRequest<'_> {
     type Body = helix::EmptyBody;
 
@@ -115,6 +122,7 @@ impl RequestPost for SendAShoutoutRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
@@ -143,3 +151,9 @@ fn test_request() {
 
     dbg!(SendAShoutoutRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: SendAShoutoutRequest,
+    204 => b"",
+}
@@ -77,6 +77,11 @@ pub struct SendChatAnnouncementRequest<'a> {
 
 impl<'a> SendChatAnnouncementRequest<'a> {
     /// Send announcement in channel as this moderator
+    ///
+    /// ```rust
+    /// use twitch_api::helix::chat::SendChatAnnouncementRequest;
+    /// let request = SendChatAnnouncementRequest::new("1234", "5678");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -123,6 +128,35 @@ impl<'a> SendChatAnnouncementBody<'a> {
             color: color.try_into()?,
         })
     }
+
+    /// Create a new announcement with specified color
+    ///
+    /// Fails if the message is longer than [`MAX_MESSAGE_LENGTH`] characters or the color is invalid.
+    pub fn try_new<E>(
+        message: impl Into<Cow<'a, str>>,
+        color: impl std::convert::TryInto<AnnouncementColor, Error = E>,
+    ) -> Result<Self, SendChatAnnouncementBodyError<E>> {
+        let message = message.into();
+        check_message_length(&message)?;
+        Ok(Self {
+            message,
+            color: color
+                .try_into()
+                .map_err(SendChatAnnouncementBodyError::Color)?,
+        })
+    }
+}
+
+/// Error returned by [`SendChatAnnouncementBody::try_new`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SendChatAnnouncementBodyError<E> {
+    /// The message is too long
+    #[error(transparent)]
+    MessageTooLong(#[from] MessageTooLongError),
+    /// The color is invalid
+    #[error("invalid announcement color")]
+    Color(#[source] E),
 }
 
 impl helix::private::SealedSerialize for SendChatAnnouncementBody<'_> {}
@@ -161,6 +195,8 @@ impl Request for SendChatAnnouncementRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ModeratorManageAnnouncements];
 }
 
+endpoint_meta!(POST SendChatAnnouncementRequest<'static>);
+
 impl<'a> RequestPost for SendChatAnnouncementRequest<'a> {
     type Body = SendChatAnnouncementBody<'a>;
 
@@ -183,11 +219,28 @@ impl<'a> RequestPost for SendChatAnnouncementRequest<'a> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
 }
 
+helix_helper! {
+    /// Send a chat announcement
+    ///
+    /// The message and color are checked with [`SendChatAnnouncementBody::try_new`](helix::chat::SendChatAnnouncementBody::try_new) before sending the request.
+    pub async fn send_chat_announcement<'b, T, E>(
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        message: impl Into<&'b str> + Send,
+        color: impl std::convert::TryInto<helix::chat::AnnouncementColor, Error = E> + Send,
+    ) -> helix::chat::SendChatAnnouncementResponse, SendChatAnnouncementBodyError<E> = req_post {
+        SendChatAnnouncementRequest::new(broadcaster_id, moderator_id)
+    } {
+        SendChatAnnouncementBody::try_new(message.into(), color)?
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -197,7 +250,7 @@ fn test_request() {
     let body = SendChatAnnouncementBody::new("Hello chat!", "purple").unwrap();
 
     assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        body.body_json().unwrap(),
         r#"{"message":"Hello chat!","color":"purple"}"#
     );
 
@@ -216,3 +269,41 @@ fn test_request() {
 
     dbg!(SendChatAnnouncementRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_message_length() {
+    assert!(SendChatAnnouncementBody::try_new("a".repeat(MAX_MESSAGE_LENGTH), "blue").is_ok());
+    assert!(matches!(
+        SendChatAnnouncementBody::try_new("a".repeat(MAX_MESSAGE_LENGTH + 1), "blue"),
+        Err(SendChatAnnouncementBodyError::MessageTooLong(
+            MessageTooLongError { length: 501 }
+        ))
+    ));
+    assert!(matches!(
+        SendChatAnnouncementBody::try_new("hello", "pink"),
+        Err(SendChatAnnouncementBodyError::Color(_))
+    ));
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_body_roundtrip(
+        message in "(?s).*",
+        color in proptest::sample::select(&["blue", "green", "orange", "purple", "primary"][..]),
+    ) {
+        use helix::*;
+        let body = SendChatAnnouncementBody::new(message.as_str(), color).unwrap();
+
+        let json: serde_json::Value = serde_json::from_slice(&body.try_to_body().unwrap()).unwrap();
+        proptest::prop_assert_eq!(&json["message"], &*message);
+        proptest::prop_assert_eq!(&json["color"], color);
+    }
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: SendChatAnnouncementRequest,
+    204 => b"",
+}
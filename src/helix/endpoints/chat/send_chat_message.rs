@@ -73,6 +73,11 @@ pub struct SendChatMessageRequest<'a> {
 
 impl SendChatMessageRequest<'_> {
     /// Create a new [`SendChatMessageRequest`]
+    ///
+    /// ```rust
+    /// use twitch_api::helix::chat::SendChatMessageRequest;
+    /// let request = SendChatMessageRequest::new();
+    /// ```
     pub fn new() -> Self { SendChatMessageRequest::default() }
 }
 
@@ -125,6 +130,24 @@ impl<'a> SendChatMessageBody<'a> {
         }
     }
 
+    /// Send a message in the broadcaster's channel
+    ///
+    /// Fails if the message is longer than [`MAX_MESSAGE_LENGTH`] characters, see [`split_message`] for sending longer messages.
+    pub fn try_new(
+        broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        sender_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        message: impl types::IntoCow<'a, str> + 'a,
+    ) -> Result<Self, MessageTooLongError> {
+        let message = message.into_cow();
+        check_message_length(&message)?;
+        Ok(Self {
+            broadcaster_id: broadcaster_id.into_cow(),
+            sender_id: sender_id.into_cow(),
+            message,
+            reply_parent_message_id: None,
+        })
+    }
+
     /// Set the reply parent message-id
     pub fn reply_parent_message_id(
         mut self,
@@ -231,6 +254,30 @@ pub struct ChatMessageDropReason {
     pub message: String,
 }
 
+impl ChatMessageDropReason {
+    /// How long to wait before the message can be sent, if it was dropped because of [slow mode](ChatMessageDropCode::MsgSlowmode)
+    ///
+    /// Read from the [message](Self::message), e.g. "You will be able to talk again in 5 seconds."
+    /// [`None`](Option::None) for other drop codes or if the message doesn't say how long.
+    pub fn slow_mode_wait(&self) -> Option<std::time::Duration> {
+        if self.code != ChatMessageDropCode::MsgSlowmode {
+            return None;
+        }
+        let mut words = self.message.split_whitespace().peekable();
+        while let Some(word) = words.next() {
+            if let Ok(seconds) = word.parse() {
+                if words
+                    .peek()
+                    .map_or(false, |next| next.starts_with("second"))
+                {
+                    return Some(std::time::Duration::from_secs(seconds));
+                }
+            }
+        }
+        None
+    }
+}
+
 /// Return Values for [Send Chat message](super::send_chat_message)
 ///
 /// [`send-chat-message`](https://dev.twitch.tv/docs/api/reference#send-chat-message)
@@ -259,6 +306,8 @@ impl Request for SendChatMessageRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::UserWriteChat];
 }
 
+endpoint_meta!(POST SendChatMessageRequest<'static>);
+
 impl<'a> RequestPost for SendChatMessageRequest<'a> {
     type Body = SendChatMessageBody<'a>;
 
@@ -280,6 +329,7 @@ impl<'a> RequestPost for SendChatMessageRequest<'a> {
                             e,
                             uri.clone(),
                             status,
+                            None,
                         )
                     })?;
                 let [s] = resp.data;
@@ -291,6 +341,7 @@ impl<'a> RequestPost for SendChatMessageRequest<'a> {
                     response: response.to_string(),
                     status,
                     uri: uri.clone(),
+                    request_id: None,
                 })
             }
         };
@@ -298,6 +349,148 @@ impl<'a> RequestPost for SendChatMessageRequest<'a> {
     }
 }
 
+/// Error returned by [`HelixClient::send_chat_message_chunked`](crate::helix::HelixClient::send_chat_message_chunked)
+#[cfg(feature = "client")]
+#[derive(Debug, thiserror::Error)]
+#[error("could not send part {} of the message", .sent.len() + 1)]
+#[non_exhaustive]
+pub struct ChunkedMessageError<C: crate::HttpClient> {
+    /// IDs of the parts that were sent before the failure
+    pub sent: Vec<types::MsgId>,
+    /// Why the part could not be sent
+    #[source]
+    pub reason: ChunkedMessageFailure<C>,
+}
+
+/// Why a part in [`HelixClient::send_chat_message_chunked`](crate::helix::HelixClient::send_chat_message_chunked) could not be sent
+#[cfg(feature = "client")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ChunkedMessageFailure<C: crate::HttpClient> {
+    /// The request failed
+    #[error("request failed")]
+    Request(#[source] helix::ClientRequestError<<C as crate::HttpClient>::Error>),
+    /// Twitch did not send the message
+    #[error("message was dropped")]
+    Dropped(Option<ChatMessageDropReason>),
+}
+
+#[cfg(feature = "client")]
+/// Error returned by [`HelixClient::send_chat_message_with_retry`](crate::helix::HelixClient::send_chat_message_with_retry)
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SendChatMessageError {
+    /// The message is too long
+    #[error(transparent)]
+    TooLong(#[from] MessageTooLongError),
+    /// Twitch did not send the message, and it was not retried
+    #[error("message was dropped")]
+    Dropped(Option<ChatMessageDropReason>),
+}
+
+#[cfg(feature = "client")]
+/// Which dropped messages [`HelixClient::send_chat_message_with_retry`](crate::helix::HelixClient::send_chat_message_with_retry) sends again
+///
+/// Every retry is on by default. A message is sent again at most once.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api::helix::chat::ChatMessageRetry;
+///
+/// let retry = ChatMessageRetry::default()
+///     .duplicate(false)
+///     .max_slow_mode_wait(std::time::Duration::from_secs(5));
+/// assert!(retry.slow_mode);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ChatMessageRetry {
+    /// Send a message dropped as a [duplicate](ChatMessageDropCode::MsgDuplicate) again, with an invisible character appended so it's not identical.
+    ///
+    /// Not done if the message would become longer than [`MAX_MESSAGE_LENGTH`].
+    pub duplicate: bool,
+    /// Send a message dropped because of [slow mode](ChatMessageDropCode::MsgSlowmode) again, after waiting as long as the [drop reason says](ChatMessageDropReason::slow_mode_wait).
+    ///
+    /// Not done if the drop reason doesn't say how long to wait, or it's longer than [`max_slow_mode_wait`](Self::max_slow_mode_wait).
+    pub slow_mode: bool,
+    /// Longest wait for slow mode before sending again. Default: 30 seconds
+    pub max_slow_mode_wait: std::time::Duration,
+}
+
+#[cfg(feature = "client")]
+impl ChatMessageRetry {
+    /// Appended to duplicate messages, a space and `U+E0000`, a code point of the Unicode tags block which Twitch doesn't show.
+    pub const INVISIBLE_SUFFIX: &'static str = " \u{E0000}";
+
+    /// Don't send dropped messages again
+    pub fn none() -> Self {
+        Self {
+            duplicate: false,
+            slow_mode: false,
+            ..Self::default()
+        }
+    }
+
+    /// Set if [duplicates](Self::duplicate) are sent again
+    pub fn duplicate(mut self, duplicate: bool) -> Self {
+        self.duplicate = duplicate;
+        self
+    }
+
+    /// Set if messages dropped because of [slow mode](Self::slow_mode) are sent again
+    pub fn slow_mode(mut self, slow_mode: bool) -> Self {
+        self.slow_mode = slow_mode;
+        self
+    }
+
+    /// Set the [longest wait](Self::max_slow_mode_wait) for slow mode
+    pub fn max_slow_mode_wait(mut self, max_slow_mode_wait: std::time::Duration) -> Self {
+        self.max_slow_mode_wait = max_slow_mode_wait;
+        self
+    }
+}
+
+#[cfg(feature = "client")]
+impl Default for ChatMessageRetry {
+    fn default() -> Self {
+        Self {
+            duplicate: true,
+            slow_mode: true,
+            max_slow_mode_wait: std::time::Duration::from_secs(30),
+        }
+    }
+}
+
+helix_helper! {
+    /// Send a chat message
+    ///
+    /// The message is checked with [`SendChatMessageBody::try_new`](helix::chat::SendChatMessageBody::try_new) before sending the request,
+    /// use [`send_chat_message_chunked`](helix::HelixClient::send_chat_message_chunked) for messages longer than [`MAX_MESSAGE_LENGTH`](helix::chat::MAX_MESSAGE_LENGTH).
+    pub async fn send_chat_message<'b, T>(
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        sender_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        message: impl Into<&'b str> + Send,
+    ) -> helix::chat::SendChatMessageResponse, MessageTooLongError = req_post {
+        SendChatMessageRequest::new()
+    } {
+        SendChatMessageBody::try_new(broadcaster_id, sender_id, message.into())?
+    }
+}
+
+// From twitch docs
+#[cfg(test)]
+const FIXTURE_SUCCESS: &[u8] = br#"
+    {
+      "data": [
+          {
+            "message_id": "abc-123-def",
+            "is_sent": true
+          }
+      ]
+    }
+    "#;
+
 #[cfg(test)]
 #[test]
 fn test_success() {
@@ -307,24 +500,13 @@ fn test_success() {
     let body = SendChatMessageBody::new("12826", "141981764", "Hello, world! twitchdevHype");
 
     assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        body.body_json().unwrap(),
         r#"{"broadcaster_id":"12826","sender_id":"141981764","message":"Hello, world! twitchdevHype"}"#
     );
 
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
-    // From twitch docs
-    let data = br#"
-    {
-      "data": [
-          {
-            "message_id": "abc-123-def",
-            "is_sent": true
-          }
-      ]
-    }
-    "#
-    .to_vec();
+    let data = FIXTURE_SUCCESS.to_vec();
 
     let http_response = http::Response::builder().status(200).body(data).unwrap();
 
@@ -393,3 +575,245 @@ fn test_reject() {
         ChatMessageDropCode::Unknown("Foo".to_string())
     );
 }
+
+#[cfg(test)]
+#[test]
+fn test_message_length() {
+    assert!(SendChatMessageBody::try_new("1234", "4321", "a".repeat(MAX_MESSAGE_LENGTH)).is_ok());
+    assert_eq!(
+        SendChatMessageBody::try_new("1234", "4321", "é".repeat(MAX_MESSAGE_LENGTH + 1))
+            .unwrap_err(),
+        MessageTooLongError { length: 501 }
+    );
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_send_chat_message_chunked() {
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "chat/messages",
+        200,
+        r#"{"data":[{"message_id":"a","is_sent":true}]}"#,
+    )
+    .respond(
+        "chat/messages",
+        200,
+        r#"{"data":[{"message_id":"","is_sent":false,"drop_reason":{"code":"msg_ratelimit","message":"Your message was not sent because you are sending messages too quickly."}}]}"#,
+    );
+    let token = crate::tests::user_token("sender", "4321");
+
+    let message = format!("{} {}", "a".repeat(300), "b".repeat(300));
+    let err = mock
+        .helix()
+        .send_chat_message_chunked("1234", "4321", message.as_str(), &token)
+        .await
+        .unwrap_err();
+    assert_eq!(err.sent, vec![types::MsgId::from_static("a")]);
+    assert!(matches!(
+        err.reason,
+        ChunkedMessageFailure::Dropped(Some(ChatMessageDropReason {
+            code: ChatMessageDropCode::MsgRatelimit,
+            ..
+        }))
+    ));
+    assert_eq!(err.to_string(), "could not send part 2 of the message");
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(std::str::from_utf8(&requests[1].2)
+        .unwrap()
+        .contains(&"b".repeat(300)));
+}
+
+#[cfg(test)]
+#[test]
+fn test_slow_mode_wait() {
+    let reason = |code, message: &str| ChatMessageDropReason {
+        code,
+        message: message.to_owned(),
+    };
+    assert_eq!(
+        reason(
+            ChatMessageDropCode::MsgSlowmode,
+            "This room is in slow mode and you are sending messages too quickly. You will be able to talk again in 27 seconds."
+        )
+        .slow_mode_wait(),
+        Some(std::time::Duration::from_secs(27))
+    );
+    assert_eq!(
+        reason(
+            ChatMessageDropCode::MsgSlowmode,
+            "You will be able to talk again in 1 second."
+        )
+        .slow_mode_wait(),
+        Some(std::time::Duration::from_secs(1))
+    );
+    assert_eq!(
+        reason(
+            ChatMessageDropCode::MsgSlowmode,
+            "This room is in slow mode."
+        )
+        .slow_mode_wait(),
+        None
+    );
+    assert_eq!(
+        reason(
+            ChatMessageDropCode::MsgTimedout,
+            "You are timed out for 30 more seconds."
+        )
+        .slow_mode_wait(),
+        None
+    );
+}
+
+#[cfg(all(test, feature = "client"))]
+mod retry_tests {
+    use super::*;
+
+    fn dropped(code: &str, message: &str) -> String {
+        format!(
+            r#"{{"data":[{{"message_id":"","is_sent":false,"drop_reason":{{"code":"{code}","message":"{message}"}}}}]}}"#
+        )
+    }
+
+    const SENT: &str = r#"{"data":[{"message_id":"abc-123-def","is_sent":true}]}"#;
+
+    /// Send "hello" with `retry`, answering with `responses`, returning the result, the waits and the sent messages
+    async fn send(
+        responses: &[&str],
+        retry: ChatMessageRetry,
+    ) -> (
+        Result<SendChatMessageResponse, SendChatMessageError>,
+        Vec<std::time::Duration>,
+        Vec<String>,
+    ) {
+        let mock = crate::tests::MockClient::new();
+        for response in responses {
+            mock.respond("chat/messages", 200, response);
+        }
+        let token = crate::tests::user_token("sender", "4321");
+        let waits = std::sync::Mutex::new(vec![]);
+        let result = mock
+            .helix()
+            .send_chat_message_with_retry(
+                "1234",
+                "4321",
+                "hello",
+                retry,
+                |wait| {
+                    waits.lock().unwrap().push(wait);
+                    async {}
+                },
+                &token,
+            )
+            .await
+            .map_err(|e| match e {
+                helix::ClientExtError::Other(e) => e,
+                helix::ClientExtError::ClientError(e) => panic!("request failed: {e}"),
+            });
+        let messages = mock
+            .requests()
+            .iter()
+            .map(|(_, _, body)| {
+                let body: serde_json::Value = serde_json::from_slice(body).unwrap();
+                body["message"].as_str().unwrap().to_owned()
+            })
+            .collect();
+        (result, waits.into_inner().unwrap(), messages)
+    }
+
+    fn drop_code(
+        result: Result<SendChatMessageResponse, SendChatMessageError>,
+    ) -> ChatMessageDropCode {
+        match result {
+            Err(SendChatMessageError::Dropped(Some(reason))) => reason.code,
+            other => panic!("expected a drop, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn duplicate() {
+        let duplicate = dropped(
+            "msg_duplicate",
+            "Your message was not sent because it is identical to the previous one you sent, less than 30 seconds ago.",
+        );
+        let (result, waits, messages) =
+            send(&[&duplicate, SENT], ChatMessageRetry::default()).await;
+        assert!(result.unwrap().is_sent);
+        assert!(waits.is_empty());
+        assert_eq!(messages, ["hello".to_owned(), "hello \u{E0000}".to_owned()]);
+
+        // only retried once
+        let (result, _, messages) =
+            send(&[&duplicate, &duplicate, SENT], ChatMessageRetry::default()).await;
+        assert_eq!(drop_code(result), ChatMessageDropCode::MsgDuplicate);
+        assert_eq!(messages.len(), 2);
+
+        let (result, _, messages) = send(
+            &[&duplicate, SENT],
+            ChatMessageRetry::default().duplicate(false),
+        )
+        .await;
+        assert_eq!(drop_code(result), ChatMessageDropCode::MsgDuplicate);
+        assert_eq!(messages.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn slow_mode() {
+        let slow_mode = dropped(
+            "msg_slowmode",
+            "This room is in slow mode and you are sending messages too quickly. You will be able to talk again in 3 seconds.",
+        );
+        let (result, waits, messages) =
+            send(&[&slow_mode, SENT], ChatMessageRetry::default()).await;
+        assert!(result.unwrap().is_sent);
+        assert_eq!(waits, [std::time::Duration::from_secs(3)]);
+        assert_eq!(messages, ["hello".to_owned(), "hello".to_owned()]);
+
+        let retry =
+            ChatMessageRetry::default().max_slow_mode_wait(std::time::Duration::from_secs(2));
+        let (result, waits, messages) = send(&[&slow_mode, SENT], retry).await;
+        assert_eq!(drop_code(result), ChatMessageDropCode::MsgSlowmode);
+        assert!(waits.is_empty());
+        assert_eq!(messages.len(), 1);
+
+        let (result, waits, messages) = send(
+            &[&slow_mode, SENT],
+            ChatMessageRetry::default().slow_mode(false),
+        )
+        .await;
+        assert_eq!(drop_code(result), ChatMessageDropCode::MsgSlowmode);
+        assert!(waits.is_empty());
+        assert_eq!(messages.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn permanent() {
+        for (code, expected) in [
+            ("msg_banned", ChatMessageDropCode::MsgBanned),
+            ("msg_followersonly", ChatMessageDropCode::MsgFollowersonly),
+            ("msg_rejected", ChatMessageDropCode::MsgRejected),
+            ("msg_subsonly", ChatMessageDropCode::MsgSubsonly),
+            (
+                "msg_somethingnew",
+                ChatMessageDropCode::Unknown("msg_somethingnew".to_owned()),
+            ),
+        ] {
+            let (result, waits, messages) = send(
+                &[&dropped(code, "not sent"), SENT],
+                ChatMessageRetry::default(),
+            )
+            .await;
+            assert_eq!(drop_code(result), expected);
+            assert!(waits.is_empty());
+            assert_eq!(messages.len(), 1, "{code}");
+        }
+    }
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: SendChatMessageRequest,
+    200 => FIXTURE_SUCCESS,
+}
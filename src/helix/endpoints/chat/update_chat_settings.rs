@@ -78,6 +78,11 @@ pub struct UpdateChatSettingsRequest<'a> {
 ///FIXME: The moderator_id parameter is redundant, we should make this a client ext function
 impl<'a> UpdateChatSettingsRequest<'a> {
     /// Update the chat settings for the specified broadcaster as the specified moderator
+    ///
+    /// ```rust
+    /// use twitch_api::helix::chat::UpdateChatSettingsRequest;
+    /// let request = UpdateChatSettingsRequest::new("1234", "5678");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -180,6 +185,8 @@ impl Request for UpdateChatSettingsRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ModeratorManageChatSettings];
 }
 
+endpoint_meta!(PATCH UpdateChatSettingsRequest<'static>);
+
 impl RequestPatch for UpdateChatSettingsRequest<'_> {
     type Body = UpdateChatSettingsBody;
 
@@ -201,6 +208,7 @@ impl RequestPatch for UpdateChatSettingsRequest<'_> {
                             e,
                             uri.clone(),
                             status,
+                            None,
                         )
                     })?;
                 resp.data.into_iter().next().ok_or(
@@ -209,6 +217,7 @@ impl RequestPatch for UpdateChatSettingsRequest<'_> {
                         response: response.to_string(),
                         status,
                         uri: uri.clone(),
+                        request_id: None,
                     },
                 )?
             }
@@ -218,6 +227,7 @@ impl RequestPatch for UpdateChatSettingsRequest<'_> {
                     response: response.to_string(),
                     status,
                     uri: uri.clone(),
+                    request_id: None,
                 })
             }
         };
@@ -225,26 +235,9 @@ impl RequestPatch for UpdateChatSettingsRequest<'_> {
     }
 }
 
+// From twitch docs
 #[cfg(test)]
-#[test]
-#[allow(clippy::field_reassign_with_default)]
-fn test_request() {
-    use helix::*;
-    let req = UpdateChatSettingsRequest::new("1234", "5678");
-
-    let mut body = UpdateChatSettingsBody::default();
-    // FIXME: Setters
-    body.slow_mode = Some(true);
-    body.slow_mode_wait_time = Some(10);
-
-    assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
-        r#"{"slow_mode":true,"slow_mode_wait_time":10}"#
-    );
-    dbg!(req.create_request(body, "token", "clientid").unwrap());
-
-    // From twitch docs
-    let data = br##"
+const FIXTURE: &[u8] = br##"
     {
         "data": [
           {
@@ -261,8 +254,27 @@ fn test_request() {
             "non_moderator_chat_delay_duration": null
           }
         ]
-    }"##
-    .to_vec();
+    }"##;
+
+#[cfg(test)]
+#[test]
+#[allow(clippy::field_reassign_with_default)]
+fn test_request() {
+    use helix::*;
+    let req = UpdateChatSettingsRequest::new("1234", "5678");
+
+    let mut body = UpdateChatSettingsBody::default();
+    // FIXME: Setters
+    body.slow_mode = Some(true);
+    body.slow_mode_wait_time = Some(10);
+
+    assert_eq!(
+        body.body_json().unwrap(),
+        r#"{"slow_mode":true,"slow_mode_wait_time":10}"#
+    );
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -274,3 +286,9 @@ fn test_request() {
 
     dbg!(UpdateChatSettingsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: UpdateChatSettingsRequest,
+    200 => FIXTURE,
+}
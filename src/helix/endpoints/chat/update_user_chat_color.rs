@@ -64,6 +64,11 @@ pub struct UpdateUserChatColorRequest<'a> {
 
 impl<'a> UpdateUserChatColorRequest<'a> {
     /// Update the users chat color
+    ///
+    /// ```rust
+    /// use twitch_api::helix::chat::UpdateUserChatColorRequest;
+    /// let request = UpdateUserChatColorRequest::new("123", twitch_types::NamedUserColor::Blue);
+    /// ```
     pub fn new(
         user_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         color: types::NamedUserColor<'static>,
@@ -95,6 +100,8 @@ impl Request for UpdateUserChatColorRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::UserManageChatColor];
 }
 
+endpoint_meta!(PUT UpdateUserChatColorRequest<'static>);
+
 impl RequestPut for UpdateUserChatColorRequest<'_> {
     type Body = helix::EmptyBody;
 
@@ -117,11 +124,27 @@ impl RequestPut for UpdateUserChatColorRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
 }
 
+helix_helper! {
+    /// Update a user's chat color
+    pub async fn update_user_chat_color<'b, T>(
+        user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        color: impl Into<types::NamedUserColor<'b>> + Send + 'b,
+    ) -> helix::chat::UpdateUserChatColorResponse = req_put {
+        UpdateUserChatColorRequest {
+            user_id: user_id.into_cow(),
+            color: color.into(),
+        }
+    } {
+        helix::EmptyBody
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_request_named() {
@@ -167,3 +190,78 @@ fn test_request_hex() {
 
     dbg!(UpdateUserChatColorRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_request_encoding() {
+    use std::convert::TryFrom;
+
+    use helix::*;
+    for input in ["#9146FF", "9146FF", " 9146FF "] {
+        let color = normalize_user_color(input);
+        let req = UpdateUserChatColorRequest {
+            user_id: Cow::Borrowed("123".into()),
+            color: types::NamedUserColor::try_from(&*color).unwrap(),
+        };
+        let uri = req.get_uri().unwrap().to_string();
+        assert_eq!(uri.matches("%23").count(), 1, "{uri}");
+        assert!(!uri.contains('#'), "{uri}");
+        assert!(uri.ends_with("color=%239146FF"), "{uri}");
+    }
+
+    for input in ["blue", "Blue_Violet", "hot_pink"] {
+        let color = normalize_user_color(input);
+        let req = UpdateUserChatColorRequest {
+            user_id: Cow::Borrowed("123".into()),
+            color: types::NamedUserColor::try_from(&*color).unwrap(),
+        };
+        let uri = req.get_uri().unwrap().to_string();
+        assert!(!uri.contains('%'), "{uri}");
+        assert!(
+            uri.ends_with(&format!("color={}", input.to_lowercase())),
+            "{uri}"
+        );
+    }
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_set_own_chat_color() {
+    let mock = crate::tests::MockClient::new();
+    mock.respond("chat/color", 204, "");
+    let client = mock.helix();
+
+    client
+        .set_own_chat_color("9146FF", &crate::tests::user_token("user", "123"))
+        .await
+        .unwrap();
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].0, http::Method::PUT);
+    assert_eq!(requests[0].1.query(), Some("user_id=123&color=%239146FF"));
+
+    let err = client
+        .set_own_chat_color("not a color", &crate::tests::user_token("user", "123"))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        helix::ClientExtError::Other(SetOwnChatColorError::InvalidColor { .. })
+    ));
+
+    let err = client
+        .set_own_chat_color("blue", &crate::tests::app_token())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        helix::ClientExtError::Other(SetOwnChatColorError::NoUserIdOnToken)
+    ));
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: UpdateUserChatColorRequest,
+    204 => b"",
+}
@@ -14,7 +14,7 @@
 //!
 //! ## Response: [CreatedClip]
 //!
-//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
 //!
 //! ```rust, no_run
 //! use twitch_api::helix::{self, clips::create_clip};
@@ -25,16 +25,16 @@
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
 //! let request = create_clip::CreateClipRequest::broadcaster_id("1234");
-//! let response: Vec<create_clip::CreatedClip> = client.req_get(request, &token).await?.data;
+//! let response: Vec<create_clip::CreatedClip> = client.req_post(request, Default::default(), &token).await?.data;
 //! # Ok(())
 //! # }
 //! ```
 //!
-//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
 //! and parse the [`http::Response`] with [`CreateClipRequest::parse_response(None, &request.get_uri(), response)`](CreateClipRequest::parse_response)
 
 use super::*;
-use helix::RequestGet;
+use helix::RequestPost;
 
 /// Query Parameters for [Create Clip](super::create_clip)
 ///
@@ -91,7 +91,11 @@ impl Request for CreateClipRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ClipsEdit];
 }
 
-impl RequestGet for CreateClipRequest<'_> {}
+endpoint_meta!(POST CreateClipRequest<'static>);
+
+impl RequestPost for CreateClipRequest<'_> {
+    type Body = helix::EmptyBody;
+}
 
 #[cfg(test)]
 #[test]
@@ -110,7 +114,7 @@ fn test_request() {
     "#
     .to_vec();
 
-    let http_response = http::Response::builder().body(data).unwrap();
+    let http_response = http::Response::builder().status(202).body(data).unwrap();
 
     let uri = req.get_uri().unwrap();
 
@@ -119,5 +123,19 @@ fn test_request() {
         "https://api.twitch.tv/helix/clips?broadcaster_id=44322889"
     );
 
-    dbg!(CreateClipRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    // a GET to the same uri is Get Clips, which lists clips instead of creating one
+    let request = req
+        .create_request(helix::EmptyBody, "token", "clientid")
+        .unwrap();
+    assert_eq!(request.method(), http::Method::POST);
+    assert_eq!(request.uri(), &uri);
+
+    let response = CreateClipRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(
+        response.data,
+        vec![CreatedClip {
+            id: "FiveWordsForClipSlug".into(),
+            edit_url: "http://clips.twitch.tv/FiveWordsForClipSlug/edit".into(),
+        }]
+    );
 }
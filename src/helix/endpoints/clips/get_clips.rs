@@ -5,7 +5,7 @@
 //!
 //! ## Request: [GetClipsRequest]
 //!
-//! To use this endpoint, construct a [`GetClipsRequest`] with the [`GetClipsRequest::broadcaster_id()`] method.
+//! To use this endpoint, construct a [`GetClipsRequest`] with the [`GetClipsRequest::broadcaster_id()`], [`GetClipsRequest::game_id()`] or [`GetClipsRequest::clip_ids()`] method.
 //!
 //! ```rust
 //! use twitch_api::helix::clips::get_clips;
@@ -90,7 +90,12 @@ impl<'a> GetClipsRequest<'a> {
     /// # Notes
     ///
     /// This is not a valid request and needs to be filled out with other fields
-    pub fn empty() -> Self {
+    #[deprecated(
+        note = "this is not a valid request, use `GetClipsRequest::broadcaster_id`, `GetClipsRequest::game_id` or `GetClipsRequest::clip_ids` instead"
+    )]
+    pub fn empty() -> Self { Self::unset() }
+
+    fn unset() -> Self {
         Self {
             broadcaster_id: Default::default(),
             game_id: Default::default(),
@@ -105,26 +110,42 @@ impl<'a> GetClipsRequest<'a> {
     }
 
     /// Broadcaster for whom clips are returned.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::clips::GetClipsRequest;
+    /// let request = GetClipsRequest::broadcaster_id("1234");
+    /// ```
     pub fn broadcaster_id(broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
         Self {
             broadcaster_id: Some(broadcaster_id.into_cow()),
-            ..Self::empty()
+            ..Self::unset()
         }
     }
 
     /// Game for which clips are returned.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::clips::GetClipsRequest;
+    /// let request = GetClipsRequest::game_id("509658");
+    /// ```
     pub fn game_id(game_id: impl types::IntoCow<'a, types::CategoryIdRef> + 'a) -> Self {
         Self {
             game_id: Some(game_id.into_cow()),
-            ..Self::empty()
+            ..Self::unset()
         }
     }
 
     /// IDs of clips being queried
+    ///
+    /// ```rust
+    /// use twitch_api::helix::clips::GetClipsRequest;
+    /// let request =
+    ///     GetClipsRequest::clip_ids(&["AwkwardHelplessSalamanderSwiftRage"]);
+    /// ```
     pub fn clip_ids(clip_ids: impl Into<types::Collection<'a, types::ClipId>>) -> Self {
         Self {
             id: clip_ids.into(),
-            ..Self::empty()
+            ..Self::unset()
         }
     }
 
@@ -206,6 +227,8 @@ impl Request for GetClipsRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetClipsRequest<'static>);
+
 impl RequestGet for GetClipsRequest<'_> {}
 
 impl helix::Paginated for GetClipsRequest<'_> {
@@ -1,5 +1,50 @@
 //! Creates a new conduit for your Client.
 //! [`create-conduit`](https://dev.twitch.tv/docs/api/reference/#create-conduits)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [CreateConduitRequest]
+//!
+//! To use this endpoint, construct a [`CreateConduitRequest`] with the [`CreateConduitRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::eventsub::create_conduit;
+//! let request = create_conduit::CreateConduitRequest::new();
+//! ```
+//!
+//! ## Body: [CreateConduitBody]
+//!
+//! We also need to provide a body to the request containing the amount of shards we want.
+//!
+//! ```
+//! # use twitch_api::helix::eventsub::create_conduit;
+//! let body = create_conduit::CreateConduitBody::new(5);
+//! ```
+//!
+//! ## Response: [Conduit](crate::eventsub::Conduit)
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! The token must be an [app access token](twitch_oauth2::AppAccessToken).
+//!
+//! ```rust, no_run
+//! use twitch_api::{eventsub, helix::{self, eventsub::create_conduit}};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+//! # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_secret");
+//! # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+//! let request = create_conduit::CreateConduitRequest::new();
+//! let body = create_conduit::CreateConduitBody::new(5);
+//! let response: eventsub::Conduit = client.req_post(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`CreateConduitRequest::parse_response(None, &request.get_uri(), response)`](CreateConduitRequest::parse_response)
 
 use super::*;
 use crate::eventsub;
@@ -14,6 +59,11 @@ use helix::RequestPost;
 #[non_exhaustive]
 pub struct CreateConduitRequest {}
 
+impl CreateConduitRequest {
+    /// Create a new conduit
+    pub fn new() -> Self { Self::default() }
+}
+
 impl Request for CreateConduitRequest {
     type Response = eventsub::Conduit;
 
@@ -22,6 +72,8 @@ impl Request for CreateConduitRequest {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(POST CreateConduitRequest);
+
 /// Body Parameters for [Create Conduit](super::create_conduit)
 ///
 /// [`create-conduit`](https://dev.twitch.tv/docs/api/reference/#create-conduits)
@@ -64,6 +116,7 @@ impl RequestPost for CreateConduitRequest {
                 e,
                 uri.clone(),
                 status,
+                None,
             )
         })?;
 
@@ -110,7 +163,7 @@ fn test_successful_response() {
     assert_eq!(
         response.data,
         crate::eventsub::Conduit {
-            id: "bfcfc993-26b1-b876-44d9-afe75a379dac".to_string(),
+            id: "bfcfc993-26b1-b876-44d9-afe75a379dac".into(),
             shard_count: 5,
         },
     );
@@ -1,8 +1,79 @@
 //! Creates an EventSub subscription.
+//! [`create-eventsub-subscription`](https://dev.twitch.tv/docs/api/reference#create-eventsub-subscription)
 //!
 //! See also [`HelixClient::create_eventsub_subscription`](crate::helix::HelixClient::create_eventsub_subscription)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [CreateEventSubSubscriptionRequest]
+//!
+//! To use this endpoint, construct a [`CreateEventSubSubscriptionRequest`] for the [subscription type](EventSubscription) with the [`CreateEventSubSubscriptionRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::{
+//!     eventsub::channel::ChannelFollowV2,
+//!     helix::eventsub::create_eventsub_subscription,
+//! };
+//! let request =
+//!     create_eventsub_subscription::CreateEventSubSubscriptionRequest::<
+//!         ChannelFollowV2,
+//!     >::new();
+//! ```
+//!
+//! ## Body: [CreateEventSubSubscriptionBody]
+//!
+//! We also need to provide a body to the request containing the subscription and how to deliver it.
+//!
+//! ```
+//! # use twitch_api::helix::eventsub::create_eventsub_subscription;
+//! use twitch_api::eventsub::{channel::ChannelFollowV2, Transport};
+//! let body =
+//!     create_eventsub_subscription::CreateEventSubSubscriptionBody::new(
+//!         ChannelFollowV2::new("1234", "5678"),
+//!         Transport::webhook(
+//!             "https://example.org/eventsub/channelfollow",
+//!             String::from("secretabcd"),
+//!         ),
+//!     );
+//! ```
+//!
+//! ## Response: [CreateEventSubSubscription]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api::{
+//!     eventsub::{channel::ChannelFollowV2, Transport},
+//!     helix::{self, eventsub::create_eventsub_subscription},
+//! };
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+//! # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_secret");
+//! # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+//! let request = create_eventsub_subscription::CreateEventSubSubscriptionRequest::new();
+//! let body = create_eventsub_subscription::CreateEventSubSubscriptionBody::new(
+//!     ChannelFollowV2::new("1234", "5678"),
+//!     Transport::webhook(
+//!         "https://example.org/eventsub/channelfollow",
+//!         String::from("secretabcd"),
+//!     ),
+//! );
+//! let response: create_eventsub_subscription::CreateEventSubSubscription<ChannelFollowV2> =
+//!     client.req_post(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`CreateEventSubSubscriptionRequest::parse_response(None, &request.get_uri(), response)`](helix::RequestPost::parse_response)
+
 use super::*;
-use crate::eventsub::{EventSubscription, EventType, Status, Transport, TransportResponse};
+use crate::eventsub::{
+    EventSubscription, EventType, SubscriptionStatus, Transport, TransportResponse,
+};
 
 /// Query Parameters for [Create EventSub Subscription](super::create_eventsub_subscription)
 ///
@@ -40,6 +111,12 @@ impl<E: EventSubscription> helix::Request for CreateEventSubSubscriptionRequest<
     const SCOPE: twitch_oauth2::Validator = E::SCOPE;
 }
 
+// the path doesn't depend on the subscription, but the scopes do
+endpoint_meta!(
+    POST CreateEventSubSubscriptionRequest<crate::eventsub::channel::ChannelUpdateV2>,
+    scopes = twitch_oauth2::validator![]
+);
+
 /// Body Parameters for [Create EventSub Subscription](super::create_eventsub_subscription)
 ///
 /// [`create-eventsub-subscription`](https://dev.twitch.tv/docs/api/reference#create-eventsub-subscription)
@@ -98,7 +175,7 @@ pub struct CreateEventSubSubscription<E: EventSubscription> {
     /// ID of the subscription created.
     pub id: types::EventSubId,
     /// Status of the subscription.
-    pub status: Status,
+    pub status: SubscriptionStatus,
     /// The category of the subscription that was created.
     #[serde(rename = "type")]
     pub type_: EventType,
@@ -141,7 +218,7 @@ impl<E: EventSubscription> helix::RequestPost for CreateEventSubSubscriptionRequ
             condition: E,
             created_at: types::Timestamp,
             id: types::EventSubId,
-            status: Status,
+            status: SubscriptionStatus,
             transport: TransportResponse,
             #[serde(rename = "type")]
             type_: EventType,
@@ -158,7 +235,13 @@ impl<E: EventSubscription> helix::RequestPost for CreateEventSubSubscriptionRequ
             max_total_cost: usize,
         }
         let response: InnerResponse<E> = helix::parse_json(text, true).map_err(|e| {
-            helix::HelixRequestPostError::DeserializeError(text.to_string(), e, uri.clone(), status)
+            helix::HelixRequestPostError::DeserializeError(
+                text.to_string(),
+                e,
+                uri.clone(),
+                status,
+                None,
+            )
         })?;
         let data = response.data.into_iter().next().ok_or_else(|| {
             helix::HelixRequestPostError::InvalidResponse {
@@ -166,6 +249,7 @@ impl<E: EventSubscription> helix::RequestPost for CreateEventSubSubscriptionRequ
                 response: text.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }
         })?;
         Ok(helix::Response::with_data(
@@ -188,6 +272,8 @@ impl<E: EventSubscription> helix::RequestPost for CreateEventSubSubscriptionRequ
     }
 }
 
+impl<E: EventSubscription> helix::IdempotentPost for CreateEventSubSubscriptionRequest<E> {}
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -203,7 +289,7 @@ fn test_request() {
     let body = CreateEventSubSubscriptionBody::new(sub, transport);
 
     assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        body.body_json().unwrap(),
         r#"{"type":"user.update","version":"1","condition":{"user_id":"1234"},"transport":{"method":"webhook","callback":"https://this-is-a-callback.com","secret":"s3cre7"}}"#
     );
 
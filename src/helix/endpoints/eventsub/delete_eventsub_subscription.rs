@@ -1,4 +1,46 @@
 //! Delete an EventSub subscription.
+//! [`delete-eventsub-subscription`](https://dev.twitch.tv/docs/api/reference#delete-eventsub-subscription)
+//!
+//! See also [`HelixClient::delete_eventsub_subscription`](crate::helix::HelixClient::delete_eventsub_subscription)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [DeleteEventSubSubscriptionRequest]
+//!
+//! To use this endpoint, construct a [`DeleteEventSubSubscriptionRequest`] with the [`DeleteEventSubSubscriptionRequest::id()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::eventsub::delete_eventsub_subscription;
+//! let request =
+//!     delete_eventsub_subscription::DeleteEventSubSubscriptionRequest::id(
+//!         "26b1c993-bfcf-44d9-b876-379dacafe75a",
+//!     );
+//! ```
+//!
+//! ## Response: [DeleteEventSubSubscription]
+//!
+//! Send the request to receive the response with [`HelixClient::req_delete()`](helix::HelixClient::req_delete).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, eventsub::delete_eventsub_subscription};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+//! # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_secret");
+//! # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+//! let request = delete_eventsub_subscription::DeleteEventSubSubscriptionRequest::id(
+//!     "26b1c993-bfcf-44d9-b876-379dacafe75a",
+//! );
+//! let response: delete_eventsub_subscription::DeleteEventSubSubscription =
+//!     client.req_delete(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestDelete::create_request)
+//! and parse the [`http::Response`] with [`DeleteEventSubSubscriptionRequest::parse_response(None, &request.get_uri(), response)`](DeleteEventSubSubscriptionRequest::parse_response)
 
 use super::*;
 use helix::RequestDelete;
@@ -32,6 +74,8 @@ impl Request for DeleteEventSubSubscriptionRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(DELETE DeleteEventSubSubscriptionRequest<'static>);
+
 /// Return Values for [Delete EventSub Subscriptions](super::delete_eventsub_subscription)
 ///
 /// [`delete-eventsub-subscriptions`](https://dev.twitch.tv/docs/api/reference#delete-eventsub-subscription)
@@ -62,6 +106,7 @@ impl RequestDelete for DeleteEventSubSubscriptionRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
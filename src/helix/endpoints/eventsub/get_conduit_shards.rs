@@ -1,5 +1,46 @@
 //! Gets a list of all shards for a conduit.
 //! [`get-conduit-shards`](https://dev.twitch.tv/docs/api/reference/#get-conduit-shards)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetConduitShardsRequest]
+//!
+//! To use this endpoint, construct a [`GetConduitShardsRequest`] with the [`GetConduitShardsRequest::conduit_id()`] method.
+//!
+//! ```rust
+//! use twitch_api::{eventsub, helix::eventsub::get_conduit_shards};
+//! let request = get_conduit_shards::GetConduitShardsRequest::conduit_id(
+//!     eventsub::ConduitIdRef::from_static(
+//!         "bfcfc993-26b1-b876-44d9-afe75a379dac",
+//!     ),
+//! );
+//! ```
+//!
+//! ## Response: [ShardResponse](crate::eventsub::ShardResponse)
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! The token must be an [app access token](twitch_oauth2::AppAccessToken).
+//!
+//! ```rust, no_run
+//! use twitch_api::{eventsub, helix::{self, eventsub::get_conduit_shards}};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+//! # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_secret");
+//! # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+//! let request = get_conduit_shards::GetConduitShardsRequest::conduit_id(
+//!     eventsub::ConduitIdRef::from_static("bfcfc993-26b1-b876-44d9-afe75a379dac"),
+//! );
+//! let response: Vec<eventsub::ShardResponse> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetConduitShardsRequest::parse_response(None, &request.get_uri(), response)`](GetConduitShardsRequest::parse_response)
 
 use super::*;
 use crate::eventsub;
@@ -8,15 +49,15 @@ use helix::RequestGet;
 /// Query Parameters for [Get Conduit Shards](super::get_conduit_shards)
 ///
 /// [`get-conduit-shards`](https://dev.twitch.tv/docs/api/reference/#get-conduit-shards)
-#[derive(PartialEq, Eq, Serialize, Clone, Debug, Default)]
+#[derive(PartialEq, Eq, Serialize, Clone, Debug)]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
 #[must_use]
 #[non_exhaustive]
 pub struct GetConduitShardsRequest<'a> {
     /// Conduit ID.
-    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
     #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
-    pub conduit_id: Cow<'a, str>,
+    pub conduit_id: Cow<'a, eventsub::ConduitIdRef>,
 
     /// Status to filter by.
     #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
@@ -28,6 +69,32 @@ pub struct GetConduitShardsRequest<'a> {
     pub after: Option<Cow<'a, helix::CursorRef>>,
 }
 
+impl Default for GetConduitShardsRequest<'_> {
+    fn default() -> Self {
+        Self {
+            conduit_id: Cow::Borrowed(eventsub::ConduitIdRef::from_static("")),
+            status: None,
+            after: None,
+        }
+    }
+}
+
+impl<'a> GetConduitShardsRequest<'a> {
+    /// Get the shards of this conduit
+    pub fn conduit_id(conduit_id: impl Into<Cow<'a, eventsub::ConduitIdRef>>) -> Self {
+        Self {
+            conduit_id: conduit_id.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Filter the shards by this status
+    pub fn status(mut self, status: impl Into<Option<eventsub::ShardStatus>>) -> Self {
+        self.status = status.into();
+        self
+    }
+}
+
 /// Return Values for [Get Conduit Shards](super::get_conduit_shards)
 ///
 /// [`get-conduit-shards`](https://dev.twitch.tv/docs/api/reference/#get-conduit-shards)
@@ -47,6 +114,8 @@ impl Request for GetConduitShardsRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetConduitShardsRequest<'static>);
+
 impl RequestGet for GetConduitShardsRequest<'_> {}
 
 impl helix::Paginated for GetConduitShardsRequest<'_> {
@@ -138,42 +207,42 @@ fn test_request() {
         response.data,
         vec![
             crate::eventsub::ShardResponse {
-                id: "0".to_string(),
+                id: "0".into(),
                 status: ShardStatus::Enabled,
                 transport: TransportResponse::Webhook(WebhookTransportResponse {
                     callback: "https://this-is-a-callback.com".to_string(),
                 }),
             },
             crate::eventsub::ShardResponse {
-                id: "1".to_string(),
+                id: "1".into(),
                 status: ShardStatus::WebhookCallbackVerificationPending,
                 transport: TransportResponse::Webhook(WebhookTransportResponse {
                     callback: "https://this-is-a-callback-2.com".to_string(),
                 }),
             },
             crate::eventsub::ShardResponse {
-                id: "2".to_string(),
+                id: "2".into(),
                 status: ShardStatus::Enabled,
                 transport: TransportResponse::Websocket(WebsocketTransportResponse {
-                    session_id: "9fd5164a-a958-4c60-b7f4-6a7202506ca0".to_string(),
+                    session_id: "9fd5164a-a958-4c60-b7f4-6a7202506ca0".into(),
                     connected_at: Some(Timestamp::from_static("2020-11-10T14:32:18.730260295Z")),
                     disconnected_at: None,
                 }),
             },
             crate::eventsub::ShardResponse {
-                id: "3".to_string(),
+                id: "3".into(),
                 status: ShardStatus::Enabled,
                 transport: TransportResponse::Websocket(WebsocketTransportResponse {
-                    session_id: "238b4b08-13f1-4b8f-8d31-56665a7a9d9f".to_string(),
+                    session_id: "238b4b08-13f1-4b8f-8d31-56665a7a9d9f".into(),
                     connected_at: Some(Timestamp::from_static("2020-11-10T14:32:18.730260295Z")),
                     disconnected_at: None,
                 }),
             },
             crate::eventsub::ShardResponse {
-                id: "4".to_string(),
+                id: "4".into(),
                 status: ShardStatus::WebsocketDisconnected,
                 transport: TransportResponse::Websocket(WebsocketTransportResponse {
-                    session_id: "ad1c9fc3-0d99-4eb7-8a04-8608e8ff9ec9".to_string(),
+                    session_id: "ad1c9fc3-0d99-4eb7-8a04-8608e8ff9ec9".into(),
                     connected_at: Some(Timestamp::from_static("2020-11-10T14:32:18.730260295Z")),
                     disconnected_at: Some(Timestamp::from_static("2020-11-11T14:32:18.730260295Z")),
                 }),
@@ -1,5 +1,40 @@
 //! Get the conduits for your Client.
 //! [`get-conduits`](https://dev.twitch.tv/docs/api/reference/#get-conduits)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetConduitsRequest]
+//!
+//! To use this endpoint, construct a [`GetConduitsRequest`] with the [`GetConduitsRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::eventsub::get_conduits;
+//! let request = get_conduits::GetConduitsRequest::new();
+//! ```
+//!
+//! ## Response: [Conduit](crate::eventsub::Conduit)
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! The token must be an [app access token](twitch_oauth2::AppAccessToken).
+//!
+//! ```rust, no_run
+//! use twitch_api::{eventsub, helix::{self, eventsub::get_conduits}};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+//! # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_secret");
+//! # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+//! let request = get_conduits::GetConduitsRequest::new();
+//! let response: Vec<eventsub::Conduit> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetConduitsRequest::parse_response(None, &request.get_uri(), response)`](GetConduitsRequest::parse_response)
 
 use super::*;
 use crate::eventsub;
@@ -14,6 +49,11 @@ use helix::RequestGet;
 #[non_exhaustive]
 pub struct GetConduitsRequest {}
 
+impl GetConduitsRequest {
+    /// Get the conduits of the client
+    pub fn new() -> Self { Self::default() }
+}
+
 impl Request for GetConduitsRequest {
     type Response = Vec<eventsub::Conduit>;
 
@@ -22,6 +62,8 @@ impl Request for GetConduitsRequest {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetConduitsRequest);
+
 impl RequestGet for GetConduitsRequest {
     fn parse_inner_response(
         request: Option<Self>,
@@ -44,6 +86,7 @@ impl RequestGet for GetConduitsRequest {
                 e,
                 uri.clone(),
                 status,
+                None,
             )
         })?;
         Ok(helix::Response::new(
@@ -98,11 +141,11 @@ fn test_request() {
         response.data,
         vec![
             crate::eventsub::Conduit {
-                id: "26b1c993-bfcf-44d9-b876-379dacafe75a".to_string(),
+                id: "26b1c993-bfcf-44d9-b876-379dacafe75a".into(),
                 shard_count: 15,
             },
             crate::eventsub::Conduit {
-                id: "bfcfc993-26b1-b876-44d9-afe75a379dac".to_string(),
+                id: "bfcfc993-26b1-b876-44d9-afe75a379dac".into(),
                 shard_count: 5,
             },
         ]
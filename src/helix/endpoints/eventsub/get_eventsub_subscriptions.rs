@@ -1,4 +1,47 @@
 //! Get a list of your EventSub subscriptions.
+//! [`get-eventsub-subscriptions`](https://dev.twitch.tv/docs/api/reference#get-eventsub-subscriptions)
+//!
+//! See also [`HelixClient::get_eventsub_subscriptions`](crate::helix::HelixClient::get_eventsub_subscriptions)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetEventSubSubscriptionsRequest]
+//!
+//! To use this endpoint, construct a [`GetEventSubSubscriptionsRequest`] with the [`GetEventSubSubscriptionsRequest::status()`] or [`GetEventSubSubscriptionsRequest::eventsub_type()`] methods,
+//! or [`GetEventSubSubscriptionsRequest::new()`] to get all subscriptions.
+//!
+//! ```rust
+//! use twitch_api::{eventsub, helix::eventsub::get_eventsub_subscriptions};
+//! let request =
+//!     get_eventsub_subscriptions::GetEventSubSubscriptionsRequest::status(
+//!         eventsub::SubscriptionStatus::Enabled,
+//!     );
+//! ```
+//!
+//! ## Response: [EventSubSubscriptions]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api::{eventsub, helix::{self, eventsub::get_eventsub_subscriptions}};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+//! # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_secret");
+//! # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+//! let request = get_eventsub_subscriptions::GetEventSubSubscriptionsRequest::status(
+//!     eventsub::SubscriptionStatus::Enabled,
+//! );
+//! let response: get_eventsub_subscriptions::EventSubSubscriptions =
+//!     client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetEventSubSubscriptionsRequest::parse_response(None, &request.get_uri(), response)`](GetEventSubSubscriptionsRequest::parse_response)
 
 use super::*;
 use crate::eventsub;
@@ -14,7 +57,7 @@ use helix::RequestGet;
 pub struct GetEventSubSubscriptionsRequest<'a> {
     /// Include this parameter to filter subscriptions by their status.
     #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
-    pub status: Option<eventsub::Status>,
+    pub status: Option<eventsub::SubscriptionStatus>,
     /// Filter subscriptions by [subscription type](eventsub::EventType) (e.g., [channel.update](eventsub::EventType::ChannelUpdate)).
     #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
     pub type_: Option<eventsub::EventType>,
@@ -37,8 +80,16 @@ pub struct GetEventSubSubscriptionsRequest<'a> {
 }
 
 impl GetEventSubSubscriptionsRequest<'_> {
+    /// Get all eventsub subscriptions
+    ///
+    /// ```rust
+    /// use twitch_api::helix::eventsub::GetEventSubSubscriptionsRequest;
+    /// let request = GetEventSubSubscriptionsRequest::new();
+    /// ```
+    pub fn new() -> Self { Self::default() }
+
     /// Get eventsub subscriptions by this status
-    pub fn status(status: impl Into<eventsub::Status>) -> Self {
+    pub fn status(status: impl Into<eventsub::SubscriptionStatus>) -> Self {
         Self {
             status: Some(status.into()),
             ..Self::default()
@@ -62,6 +113,8 @@ impl Request for GetEventSubSubscriptionsRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetEventSubSubscriptionsRequest<'static>);
+
 /// Return Values for [Get EventSub Subscriptions](super::get_eventsub_subscriptions)
 ///
 /// [`get-eventsub-subscriptions`](https://dev.twitch.tv/docs/api/reference#get-eventsub-subscriptions)
@@ -107,6 +160,7 @@ impl RequestGet for GetEventSubSubscriptionsRequest<'_> {
                 e,
                 uri.clone(),
                 status,
+                None,
             )
         })?;
         Ok(helix::Response::new(
@@ -192,3 +246,24 @@ fn test_request() {
         GetEventSubSubscriptionsRequest::parse_response(Some(req), &uri, http_response).unwrap()
     );
 }
+
+#[cfg(test)]
+#[test]
+fn test_status_query() {
+    use helix::*;
+    let req = GetEventSubSubscriptionsRequest::status(
+        eventsub::SubscriptionStatus::WebhookCallbackVerificationFailed,
+    );
+    assert_eq!(
+        req.get_uri().unwrap().to_string(),
+        "https://api.twitch.tv/helix/eventsub/subscriptions?status=webhook_callback_verification_failed"
+    );
+
+    let req = GetEventSubSubscriptionsRequest::status(eventsub::SubscriptionStatus::Other(
+        "some_new_status".to_owned(),
+    ));
+    assert_eq!(
+        req.get_uri().unwrap().to_string(),
+        "https://api.twitch.tv/helix/eventsub/subscriptions?status=some_new_status"
+    );
+}
@@ -35,3 +35,34 @@ pub use get_eventsub_subscriptions::{EventSubSubscriptions, GetEventSubSubscript
 pub use update_conduit_shards::{
     UpdateConduitShardsBody, UpdateConduitShardsRequest, UpdateConduitShardsResponse,
 };
+
+#[cfg(test)]
+#[test]
+fn test_paths() {
+    use crate::eventsub::channel::ChannelFollowV2;
+    #[allow(unused_imports)]
+    use crate::helix::eventsub::{
+        CreateConduitBody, CreateEventSubSubscription, CreateEventSubSubscriptionBody,
+        DeleteEventSubSubscription, EventSubSubscriptions, UpdateConduitShardsBody,
+        UpdateConduitShardsResponse,
+    };
+    use crate::helix::eventsub::{
+        CreateConduitRequest, CreateEventSubSubscriptionRequest, DeleteEventSubSubscriptionRequest,
+        GetConduitShardsRequest, GetConduitsRequest, GetEventSubSubscriptionsRequest,
+        UpdateConduitShardsRequest,
+    };
+
+    // the re-exports are the same types as in the endpoint modules
+    let _: CreateEventSubSubscriptionRequest<ChannelFollowV2> =
+        create_eventsub_subscription::CreateEventSubSubscriptionRequest::new();
+    let _: DeleteEventSubSubscriptionRequest =
+        delete_eventsub_subscription::DeleteEventSubSubscriptionRequest::id("1");
+    let _: GetEventSubSubscriptionsRequest =
+        get_eventsub_subscriptions::GetEventSubSubscriptionsRequest::default();
+    let _: CreateConduitRequest = create_conduit::CreateConduitRequest::new();
+    let _: GetConduitsRequest = get_conduits::GetConduitsRequest::new();
+    let _: GetConduitShardsRequest = get_conduit_shards::GetConduitShardsRequest::conduit_id(
+        crate::eventsub::ConduitIdRef::from_static("1"),
+    );
+    let _: UpdateConduitShardsRequest = update_conduit_shards::UpdateConduitShardsRequest::new();
+}
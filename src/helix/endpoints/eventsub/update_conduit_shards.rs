@@ -1,5 +1,66 @@
 //! Updates shard(s) for a [conduit](https://dev.twitch.tv/docs/eventsub/handling-conduit-events).
 //! [`update-conduit-shards`](https://dev.twitch.tv/docs/api/reference/#update-conduit-shards)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [UpdateConduitShardsRequest]
+//!
+//! To use this endpoint, construct a [`UpdateConduitShardsRequest`] with the [`UpdateConduitShardsRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::eventsub::update_conduit_shards;
+//! let request = update_conduit_shards::UpdateConduitShardsRequest::new();
+//! ```
+//!
+//! ## Body: [UpdateConduitShardsBody]
+//!
+//! We also need to provide a body to the request containing the shards we want to update.
+//!
+//! ```
+//! # use twitch_api::helix::eventsub::update_conduit_shards;
+//! use twitch_api::eventsub;
+//! let body = update_conduit_shards::UpdateConduitShardsBody::new(
+//!     "bfcfc993-26b1-b876-44d9-afe75a379dac",
+//!     vec![eventsub::Shard::new(
+//!         "0",
+//!         eventsub::Transport::websocket(
+//!             "AgoQMpdhHZ-dSoyv7NLALgOGHhIGY2VsbC1j",
+//!         ),
+//!     )],
+//! );
+//! ```
+//!
+//! ## Response: [UpdateConduitShardsResponse]
+//!
+//! Send the request to receive the response with [`HelixClient::req_patch()`](helix::HelixClient::req_patch).
+//!
+//! The token must be an [app access token](twitch_oauth2::AppAccessToken).
+//!
+//! ```rust, no_run
+//! use twitch_api::{eventsub, helix::{self, eventsub::update_conduit_shards}};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+//! # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_secret");
+//! # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+//! let request = update_conduit_shards::UpdateConduitShardsRequest::new();
+//! let body = update_conduit_shards::UpdateConduitShardsBody::new(
+//!     "bfcfc993-26b1-b876-44d9-afe75a379dac",
+//!     vec![eventsub::Shard::new(
+//!         "0",
+//!         eventsub::Transport::websocket("AgoQMpdhHZ-dSoyv7NLALgOGHhIGY2VsbC1j"),
+//!     )],
+//! );
+//! let response: update_conduit_shards::UpdateConduitShardsResponse =
+//!     client.req_patch(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPatch::create_request)
+//! and parse the [`http::Response`] with [`UpdateConduitShardsRequest::parse_response(None, &request.get_uri(), response)`](UpdateConduitShardsRequest::parse_response)
 
 use super::*;
 use crate::eventsub;
@@ -14,6 +75,11 @@ use helix::RequestPatch;
 #[non_exhaustive]
 pub struct UpdateConduitShardsRequest {}
 
+impl UpdateConduitShardsRequest {
+    /// Update shards of a conduit
+    pub fn new() -> Self { Self::default() }
+}
+
 impl Request for UpdateConduitShardsRequest {
     type Response = UpdateConduitShardsResponse;
 
@@ -22,6 +88,8 @@ impl Request for UpdateConduitShardsRequest {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(PATCH UpdateConduitShardsRequest);
+
 /// The structured response for [Update Conduit Shards](super::update_conduit_shards)
 ///
 /// [`update-conduit-shards`](https://dev.twitch.tv/docs/api/reference/#update-conduit-shards)
@@ -43,7 +111,7 @@ pub struct UpdateConduitShardsResponse {
 #[non_exhaustive]
 pub struct UpdateConduitShardsBody {
     /// Conduit ID.
-    pub conduit_id: String,
+    pub conduit_id: eventsub::ConduitId,
 
     /// List of shards to update.
     pub shards: Vec<eventsub::Shard>,
@@ -51,8 +119,11 @@ pub struct UpdateConduitShardsBody {
 
 impl UpdateConduitShardsBody {
     /// Conduit body settings
-    pub fn new(conduit_id: String, shards: Vec<eventsub::Shard>) -> Self {
-        Self { conduit_id, shards }
+    pub fn new(conduit_id: impl Into<eventsub::ConduitId>, shards: Vec<eventsub::Shard>) -> Self {
+        Self {
+            conduit_id: conduit_id.into(),
+            shards,
+        }
     }
 }
 
@@ -83,6 +154,7 @@ impl RequestPatch for UpdateConduitShardsRequest {
                 e,
                 uri.clone(),
                 status,
+                None,
             )
         })?;
 
@@ -156,7 +228,7 @@ fn test_successful_response() {
         response.data.shards,
         vec![
             crate::eventsub::ShardResponse {
-                id: "0".to_string(),
+                id: "0".into(),
                 status: crate::eventsub::ShardStatus::Enabled,
                 transport: crate::eventsub::TransportResponse::Webhook(
                     crate::eventsub::WebhookTransportResponse {
@@ -165,7 +237,7 @@ fn test_successful_response() {
                 ),
             },
             crate::eventsub::ShardResponse {
-                id: "1".to_string(),
+                id: "1".into(),
                 status: crate::eventsub::ShardStatus::WebhookCallbackVerificationPending,
                 transport: crate::eventsub::TransportResponse::Webhook(
                     crate::eventsub::WebhookTransportResponse {
@@ -179,7 +251,7 @@ fn test_successful_response() {
     assert_eq!(
         response.data.errors,
         vec![crate::eventsub::ShardError {
-            id: "3".to_string(),
+            id: "3".into(),
             message: "The shard id is outside the conduit's range".to_string(),
             code: "invalid_parameter".to_string(),
         },]
@@ -5,7 +5,7 @@
 //!
 //! ## Request: [GetGamesRequest]
 //!
-//! To use this endpoint, construct a [`GetGamesRequest`] with the [`GetGamesRequest::names()`], [`GetGamesRequest::ids()`] or [`GetGamesRequest::empty()`] method.
+//! To use this endpoint, construct a [`GetGamesRequest`] with the [`GetGamesRequest::names()`] or [`GetGamesRequest::ids()`] method.
 //!
 //! ```rust
 //! use twitch_api::helix::games::get_games;
@@ -101,6 +101,8 @@ impl Request for GetGamesRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetGamesRequest<'static>);
+
 impl RequestGet for GetGamesRequest<'_> {}
 
 #[cfg(test)]
@@ -5,11 +5,11 @@
 //!
 //! ## Request: [GetTopGamesRequest]
 //!
-//! To use this endpoint, construct a [`GetTopGamesRequest`] with the [`GetTopGamesRequest::default()`] method.
+//! To use this endpoint, construct a [`GetTopGamesRequest`] with the [`GetTopGamesRequest::new()`] method.
 //!
 //! ```rust
 //! use twitch_api::helix::games::get_top_games;
-//! let request = get_top_games::GetTopGamesRequest::default().first(100);
+//! let request = get_top_games::GetTopGamesRequest::new().first(100);
 //! ```
 //!
 //! ## Response: [Game](types::TwitchCategory)
@@ -24,7 +24,7 @@
 //! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
-//! let request = get_top_games::GetTopGamesRequest::default();
+//! let request = get_top_games::GetTopGamesRequest::new();
 //! let response: Vec<get_top_games::Game> = client.req_get(request, &token).await?.data;
 //! # Ok(())
 //! # }
@@ -58,6 +58,14 @@ pub struct GetTopGamesRequest<'a> {
 }
 
 impl GetTopGamesRequest<'_> {
+    /// Get the most popular games
+    ///
+    /// ```rust
+    /// use twitch_api::helix::games::GetTopGamesRequest;
+    /// let request = GetTopGamesRequest::new().first(100);
+    /// ```
+    pub fn new() -> Self { Self::default() }
+
     /// Set amount of results returned per page.
     pub fn first(mut self, first: usize) -> Self {
         self.first = Some(first);
@@ -78,6 +86,8 @@ impl Request for GetTopGamesRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetTopGamesRequest<'static>);
+
 impl RequestGet for GetTopGamesRequest<'_> {}
 
 impl helix::Paginated for GetTopGamesRequest<'_> {
@@ -90,7 +100,7 @@ impl helix::Paginated for GetTopGamesRequest<'_> {
 #[test]
 fn test_request() {
     use helix::*;
-    let req = GetTopGamesRequest::default();
+    let req = GetTopGamesRequest::new();
 
     // From twitch docs
     let data = br#"
@@ -14,3 +14,28 @@ pub use get_games::GetGamesRequest;
 #[doc(inline)]
 pub use get_top_games::GetTopGamesRequest;
 pub use types::TwitchCategory as Game;
+
+/// A category, or a reference to one, e.g. the game being played on a [stream](helix::streams::Stream)
+///
+/// Implemented for [`Game`] (which is the same type as [`search::Category`](helix::search::Category))
+/// and for the `game_id` and `game_name` of streams and channels.
+///
+/// ```rust
+/// use twitch_api::helix::games::CategoryLike;
+///
+/// fn same_category(a: &impl CategoryLike, b: &impl CategoryLike) -> bool {
+///     a.category_id() == b.category_id()
+/// }
+/// ```
+pub trait CategoryLike {
+    /// ID of the category
+    fn category_id(&self) -> &types::CategoryIdRef;
+    /// Name of the category
+    fn category_name(&self) -> &str;
+}
+
+impl CategoryLike for Game {
+    fn category_id(&self) -> &types::CategoryIdRef { &self.id }
+
+    fn category_name(&self) -> &str { &self.name }
+}
@@ -116,6 +116,8 @@ impl Request for GetCreatorGoalsRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelReadGoals];
 }
 
+endpoint_meta!(GET GetCreatorGoalsRequest<'static>);
+
 impl RequestGet for GetCreatorGoalsRequest<'_> {}
 
 #[cfg(test)]
@@ -143,6 +143,8 @@ impl Request for GetHypeTrainEventsRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelReadHypeTrain];
 }
 
+endpoint_meta!(GET GetHypeTrainEventsRequest<'static>);
+
 impl RequestGet for GetHypeTrainEventsRequest<'_> {}
 
 impl helix::Paginated for GetHypeTrainEventsRequest<'_> {
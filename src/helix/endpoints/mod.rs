@@ -23,3 +23,188 @@ pub mod teams;
 pub mod users;
 pub mod videos;
 pub mod whispers;
+
+/// Every endpoint this crate has a [`Request`](crate::helix::Request) for
+///
+/// Useful to compare against the [Twitch API reference](https://dev.twitch.tv/docs/api/reference) to find endpoints that are not wrapped yet.
+pub static ENDPOINTS: &[crate::helix::EndpointMeta] = &[
+    bits::get_bits_leaderboard::ENDPOINT,
+    bits::get_cheermotes::ENDPOINT,
+    channels::add_channel_vip::ENDPOINT,
+    channels::get_ad_schedule::ENDPOINT,
+    channels::get_channel_editors::ENDPOINT,
+    channels::get_channel_followers::ENDPOINT,
+    channels::get_channel_information::ENDPOINT,
+    channels::get_followed_channels::ENDPOINT,
+    channels::get_vips::ENDPOINT,
+    channels::modify_channel_information::ENDPOINT,
+    channels::remove_channel_vip::ENDPOINT,
+    channels::snooze_next_ad::ENDPOINT,
+    channels::start_commercial::ENDPOINT,
+    charity::get_charity_campaign::ENDPOINT,
+    charity::get_charity_campaign_donations::ENDPOINT,
+    chat::get_channel_chat_badges::ENDPOINT,
+    chat::get_channel_emotes::ENDPOINT,
+    chat::get_chat_settings::ENDPOINT,
+    chat::get_chatters::ENDPOINT,
+    chat::get_emote_sets::ENDPOINT,
+    chat::get_global_chat_badges::ENDPOINT,
+    chat::get_global_emotes::ENDPOINT,
+    chat::get_user_chat_color::ENDPOINT,
+    chat::send_a_shoutout::ENDPOINT,
+    chat::send_chat_announcement::ENDPOINT,
+    chat::send_chat_message::ENDPOINT,
+    chat::update_chat_settings::ENDPOINT,
+    chat::update_user_chat_color::ENDPOINT,
+    clips::create_clip::ENDPOINT,
+    clips::get_clips::ENDPOINT,
+    #[cfg(feature = "eventsub")]
+    eventsub::create_conduit::ENDPOINT,
+    #[cfg(feature = "eventsub")]
+    eventsub::create_eventsub_subscription::ENDPOINT,
+    #[cfg(feature = "eventsub")]
+    eventsub::delete_eventsub_subscription::ENDPOINT,
+    #[cfg(feature = "eventsub")]
+    eventsub::get_conduit_shards::ENDPOINT,
+    #[cfg(feature = "eventsub")]
+    eventsub::get_conduits::ENDPOINT,
+    #[cfg(feature = "eventsub")]
+    eventsub::get_eventsub_subscriptions::ENDPOINT,
+    #[cfg(feature = "eventsub")]
+    eventsub::update_conduit_shards::ENDPOINT,
+    games::get_games::ENDPOINT,
+    games::get_top_games::ENDPOINT,
+    goals::get_creator_goals::ENDPOINT,
+    hypetrain::get_hypetrain_events::ENDPOINT,
+    moderation::add_blocked_term::ENDPOINT,
+    moderation::add_channel_moderator::ENDPOINT,
+    moderation::ban_user::ENDPOINT,
+    moderation::check_automod_status::ENDPOINT,
+    moderation::delete_chat_messages::ENDPOINT,
+    moderation::get_automod_settings::ENDPOINT,
+    moderation::get_banned_users::ENDPOINT,
+    moderation::get_blocked_terms::ENDPOINT,
+    moderation::get_moderators::ENDPOINT,
+    moderation::get_shield_mode_status::ENDPOINT,
+    moderation::manage_held_automod_messages::ENDPOINT,
+    moderation::remove_blocked_term::ENDPOINT,
+    moderation::remove_channel_moderator::ENDPOINT,
+    moderation::unban_user::ENDPOINT,
+    moderation::update_automod_settings::ENDPOINT,
+    moderation::update_shield_mode_status::ENDPOINT,
+    #[cfg(feature = "beta")]
+    moderation::warn_chat_user::ENDPOINT,
+    points::create_custom_rewards::ENDPOINT,
+    points::delete_custom_reward::ENDPOINT,
+    points::get_custom_reward::ENDPOINT,
+    points::get_custom_reward_redemption::ENDPOINT,
+    points::update_custom_reward::ENDPOINT,
+    points::update_redemption_status::ENDPOINT,
+    polls::create_poll::ENDPOINT,
+    polls::end_poll::ENDPOINT,
+    polls::get_polls::ENDPOINT,
+    predictions::create_prediction::ENDPOINT,
+    predictions::end_prediction::ENDPOINT,
+    predictions::get_predictions::ENDPOINT,
+    raids::cancel_a_raid::ENDPOINT,
+    raids::start_a_raid::ENDPOINT,
+    schedule::create_channel_stream_schedule_segment::ENDPOINT,
+    schedule::delete_channel_stream_schedule_segment::ENDPOINT,
+    schedule::get_channel_stream_schedule::ENDPOINT,
+    schedule::update_channel_stream_schedule::ENDPOINT,
+    schedule::update_channel_stream_schedule_segment::ENDPOINT,
+    search::search_categories::ENDPOINT,
+    search::search_channels::ENDPOINT,
+    streams::create_stream_marker::ENDPOINT,
+    streams::get_followed_streams::ENDPOINT,
+    streams::get_stream_tags::ENDPOINT,
+    streams::get_streams::ENDPOINT,
+    streams::replace_stream_tags::ENDPOINT,
+    subscriptions::check_user_subscription::ENDPOINT,
+    subscriptions::get_broadcaster_subscriptions::ENDPOINT,
+    subscriptions::get_broadcaster_subscriptions_events::ENDPOINT,
+    tags::get_all_stream_tags::ENDPOINT,
+    teams::get_channel_teams::ENDPOINT,
+    teams::get_teams::ENDPOINT,
+    users::block_user::ENDPOINT,
+    users::get_user_block_list::ENDPOINT,
+    users::get_users::ENDPOINT,
+    users::get_users_follows::ENDPOINT,
+    users::unblock_user::ENDPOINT,
+    videos::delete_videos::ENDPOINT,
+    videos::get_videos::ENDPOINT,
+    whispers::send_whisper::ENDPOINT,
+];
+
+#[cfg(test)]
+#[test]
+fn endpoints_are_unique() {
+    let mut seen = std::collections::HashSet::new();
+    for endpoint in ENDPOINTS {
+        assert!(
+            seen.insert((endpoint.path, endpoint.method.clone())),
+            "{} {} is registered twice, last by {}",
+            endpoint.method,
+            endpoint.path,
+            endpoint.module_path
+        );
+    }
+}
+
+#[cfg(all(test, feature = "beta", feature = "eventsub"))]
+#[test]
+fn every_request_is_registered() {
+    fn visit(dir: &std::path::Path, found: &mut Vec<(String, usize)>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                visit(&path, found);
+                continue;
+            }
+            let source = std::fs::read_to_string(&path).unwrap();
+            let impls = source
+                .lines()
+                .filter(|l| {
+                    // `impl Request for`, `impl<'a> Request for` or `impl helix::Request for`, not other traits ending in `Request`
+                    l.starts_with("impl")
+                        && l.match_indices("Request for ").any(|(i, _)| {
+                            matches!(l[..i].chars().next_back(), Some(' ' | '>' | ':'))
+                        })
+                })
+                .count();
+            if impls > 0 {
+                let module = path
+                    .strip_prefix(env!("CARGO_MANIFEST_DIR"))
+                    .unwrap()
+                    .with_extension("")
+                    .components()
+                    .skip(1)
+                    .map(|c| c.as_os_str().to_str().unwrap())
+                    .collect::<Vec<_>>()
+                    .join("::");
+                found.push((format!("twitch_api::{module}"), impls));
+            }
+        }
+    }
+
+    let mut found = vec![];
+    visit(
+        &std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src/helix/endpoints"),
+        &mut found,
+    );
+    assert!(!found.is_empty());
+    for (module, impls) in &found {
+        let registered = ENDPOINTS
+            .iter()
+            .filter(|e| e.module_path == module.as_str())
+            .count();
+        assert_eq!(
+            registered, *impls,
+            "{module} has {impls} requests but {registered} registered endpoints"
+        );
+    }
+    assert_eq!(
+        found.iter().map(|(_, impls)| impls).sum::<usize>(),
+        ENDPOINTS.len()
+    );
+}
@@ -70,6 +70,11 @@ pub struct AddBlockedTermRequest<'a> {
 
 impl<'a> AddBlockedTermRequest<'a> {
     /// Where to add blocked term
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::AddBlockedTermRequest;
+    /// let request = AddBlockedTermRequest::new("1234", "5678");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -131,27 +136,15 @@ impl Request for AddBlockedTermRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ModeratorManageBlockedTerms];
 }
 
+endpoint_meta!(POST AddBlockedTermRequest<'static>);
+
 impl<'a> RequestPost for AddBlockedTermRequest<'a> {
     type Body = AddBlockedTermBody<'a>;
 }
 
+// From twitch docs
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = AddBlockedTermRequest::new("1234", "5678");
-
-    let body = AddBlockedTermBody::new("A phrase I’m not fond of");
-
-    assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
-        r#"{"text":"A phrase I’m not fond of"}"#
-    );
-
-    dbg!(req.create_request(body, "token", "clientid").unwrap());
-
-    // From twitch docs
-    let data = br#"
+const FIXTURE: &[u8] = br#"
     {
         "data": [
           {
@@ -165,8 +158,24 @@ fn test_request() {
           }
         ]
       }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = AddBlockedTermRequest::new("1234", "5678");
+
+    let body = AddBlockedTermBody::new("A phrase I’m not fond of");
+
+    assert_eq!(
+        body.body_json().unwrap(),
+        r#"{"text":"A phrase I’m not fond of"}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -178,3 +187,9 @@ fn test_request() {
 
     dbg!(AddBlockedTermRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: AddBlockedTermRequest,
+    200 => FIXTURE,
+}
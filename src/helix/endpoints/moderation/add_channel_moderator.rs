@@ -58,6 +58,11 @@ pub struct AddChannelModeratorRequest<'a> {
 
 impl<'a> AddChannelModeratorRequest<'a> {
     /// Add moderator on channel
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::AddChannelModeratorRequest;
+    /// let request = AddChannelModeratorRequest::new("1234", "5678");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -89,6 +94,8 @@ impl Request for AddChannelModeratorRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageModerators];
 }
 
+endpoint_meta!(POST AddChannelModeratorRequest<'static>);
+
 impl RequestPost for AddChannelModeratorRequest<'_> {
     type Body = helix::EmptyBody;
 
@@ -111,11 +118,29 @@ impl RequestPost for AddChannelModeratorRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
 }
 
+helix_helper! {
+    /// Add a channel moderator
+    ///
+    /// Known errors returned by Twitch are turned into a [`ModerationActionError`].
+    pub async fn add_channel_moderator<'b, T>(
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+    ) -> helix::moderation::AddChannelModeratorResponse, ModerationActionError = req_post(ModerationActionError::from_client_error) {
+        AddChannelModeratorRequest {
+            broadcaster_id: broadcaster_id.into_cow(),
+            moderator_id: moderator_id.into_cow(),
+        }
+    } {
+        helix::EmptyBody
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -139,3 +164,9 @@ fn test_request() {
 
     dbg!(AddChannelModeratorRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: AddChannelModeratorRequest,
+    204 => b"",
+}
@@ -0,0 +1,258 @@
+//! Track bans and moderators of a channel between polls.
+//!
+//! Twitch no longer offers endpoints for moderation events, so the changes are derived by
+//! comparing [Get Banned Users](super::get_banned_users) and [Get Moderators](super::get_moderators)
+//! to the state seen on the previous poll.
+//!
+//! For realtime events, use the `channel.ban`, `channel.unban`, `channel.moderator.add` and
+//! `channel.moderator.remove` EventSub subscriptions instead.
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, moderation::ModerationAuditStream};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! # let stored: Option<String> = None;
+//! let mut audit = match stored {
+//!     Some(json) => ModerationAuditStream::from_snapshot(serde_json::from_str(&json)?),
+//!     None => ModerationAuditStream::new("1234"),
+//! };
+//! for change in audit.poll(&client, &token).await? {
+//!     println!("{change:?}");
+//! }
+//! let stored = serde_json::to_string(audit.snapshot())?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::*;
+use futures::TryStreamExt;
+use twitch_oauth2::TwitchToken;
+
+/// A change in the moderation state of a channel, emitted by [`ModerationAuditStream::poll`]
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ModerationChange {
+    /// A user was banned or put in a timeout.
+    ///
+    /// Also emitted when the ban of an already banned user changed, e.g. a timeout was extended.
+    Banned(BannedUser),
+    /// A user is no longer banned, either by an unban or an expired timeout.
+    Unbanned(types::UserId),
+    /// A user was made moderator.
+    ModAdded(Moderator),
+    /// A user is no longer a moderator.
+    ModRemoved(types::UserId),
+}
+
+/// The moderation state of a channel as seen on the last poll of a [`ModerationAuditStream`]
+///
+/// Serialize this to persist the state between runs.
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct ModerationSnapshot {
+    /// The broadcaster whose channel is tracked.
+    pub broadcaster_id: types::UserId,
+    /// Users banned or in a timeout.
+    pub banned: Vec<BannedUser>,
+    /// Moderators of the channel.
+    pub moderators: Vec<Moderator>,
+}
+
+impl ModerationSnapshot {
+    /// An empty snapshot for this broadcaster
+    pub fn new(broadcaster_id: impl Into<types::UserId>) -> Self {
+        Self {
+            broadcaster_id: broadcaster_id.into(),
+            banned: vec![],
+            moderators: vec![],
+        }
+    }
+}
+
+/// Emit [changes](ModerationChange) to the bans and moderators of a channel since the last poll
+///
+/// The first poll on a new stream reports all current bans and moderators.
+/// Start from a stored [`ModerationSnapshot`] with [`ModerationAuditStream::from_snapshot`] to only get what changed since.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ModerationAuditStream {
+    snapshot: ModerationSnapshot,
+}
+
+impl ModerationAuditStream {
+    /// Track the channel of this broadcaster, starting from an empty snapshot
+    pub fn new(broadcaster_id: impl Into<types::UserId>) -> Self {
+        Self::from_snapshot(ModerationSnapshot::new(broadcaster_id))
+    }
+
+    /// Continue tracking from a stored snapshot
+    pub fn from_snapshot(snapshot: ModerationSnapshot) -> Self { Self { snapshot } }
+
+    /// The state seen on the last poll
+    pub fn snapshot(&self) -> &ModerationSnapshot { &self.snapshot }
+
+    /// Consume the stream, returning the state seen on the last poll
+    pub fn into_snapshot(self) -> ModerationSnapshot { self.snapshot }
+
+    /// Fetch the current bans and moderators and return what changed since the last poll.
+    ///
+    /// The snapshot is only updated if both lists could be fetched.
+    pub async fn poll<'client, C, T>(
+        &mut self,
+        client: &'client helix::HelixClient<'client, C>,
+        token: &'client T,
+    ) -> Result<Vec<ModerationChange>, helix::ClientRequestError<<C as crate::HttpClient>::Error>>
+    where
+        C: crate::HttpClient + Sync + 'client,
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let broadcaster_id = self.snapshot.broadcaster_id.clone();
+        let banned: Vec<BannedUser> = client
+            .get_banned_users_in_channel_from_id(broadcaster_id.clone(), token)
+            .try_collect()
+            .await?;
+        let moderators: Vec<Moderator> = client
+            .get_moderators_in_channel_from_id(broadcaster_id, None, token)
+            .try_collect()
+            .await?;
+        Ok(self.update(banned, moderators))
+    }
+
+    /// Replace the snapshot with the given state, returning what changed
+    fn update(
+        &mut self,
+        banned: Vec<BannedUser>,
+        moderators: Vec<Moderator>,
+    ) -> Vec<ModerationChange> {
+        use std::collections::HashMap;
+
+        let mut changes = vec![];
+
+        let previous: HashMap<&types::UserIdRef, &BannedUser> = self
+            .snapshot
+            .banned
+            .iter()
+            .map(|b| (&*b.user_id, b))
+            .collect();
+        let current: HashMap<&types::UserIdRef, &BannedUser> =
+            banned.iter().map(|b| (&*b.user_id, b)).collect();
+        changes.extend(
+            banned
+                .iter()
+                .filter(|b| previous.get(&*b.user_id).copied() != Some(*b))
+                .cloned()
+                .map(ModerationChange::Banned),
+        );
+        changes.extend(
+            self.snapshot
+                .banned
+                .iter()
+                .filter(|b| !current.contains_key(&*b.user_id))
+                .map(|b| ModerationChange::Unbanned(b.user_id.clone())),
+        );
+
+        let previous: HashMap<&types::UserIdRef, &Moderator> = self
+            .snapshot
+            .moderators
+            .iter()
+            .map(|m| (&*m.user_id, m))
+            .collect();
+        let current: HashMap<&types::UserIdRef, &Moderator> =
+            moderators.iter().map(|m| (&*m.user_id, m)).collect();
+        changes.extend(
+            moderators
+                .iter()
+                .filter(|m| !previous.contains_key(&*m.user_id))
+                .cloned()
+                .map(ModerationChange::ModAdded),
+        );
+        changes.extend(
+            self.snapshot
+                .moderators
+                .iter()
+                .filter(|m| !current.contains_key(&*m.user_id))
+                .map(|m| ModerationChange::ModRemoved(m.user_id.clone())),
+        );
+
+        self.snapshot.banned = banned;
+        self.snapshot.moderators = moderators;
+        changes
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_poll() {
+    let mock = crate::tests::MockClient::new();
+    let client = mock.helix();
+    let token = crate::tests::user_token("broadcaster", "1234");
+
+    fn ban(id: &str, expires_at: Option<&str>) -> String {
+        format!(
+            r#"{{"user_id":"{id}","user_name":"User{id}","user_login":"user{id}","expires_at":"{}","reason":"","moderator_id":"1234","moderator_login":"broadcaster","moderator_name":"Broadcaster"}}"#,
+            expires_at.unwrap_or_default()
+        )
+    }
+    fn moderator(id: &str) -> String {
+        format!(r#"{{"user_id":"{id}","user_name":"Mod{id}","user_login":"mod{id}"}}"#)
+    }
+    fn page(items: &[String]) -> String {
+        format!(r#"{{"data":[{}],"pagination":{{}}}}"#, items.join(","))
+    }
+
+    mock.respond(
+        "moderation/banned",
+        200,
+        &page(&[ban("1", None), ban("2", Some("2022-01-01T00:00:00Z"))]),
+    )
+    .respond("moderation/moderators", 200, &page(&[moderator("10")]));
+
+    let mut audit = ModerationAuditStream::new("1234");
+    let changes = audit.poll(&client, &token).await.unwrap();
+    assert_eq!(changes.len(), 3);
+    assert!(
+        matches!(&changes[0], ModerationChange::Banned(b) if b.user_id.as_str() == "1" && b.expires_at.is_none())
+    );
+    assert!(matches!(&changes[1], ModerationChange::Banned(b) if b.user_id.as_str() == "2"));
+    assert!(matches!(&changes[2], ModerationChange::ModAdded(m) if m.user_id.as_str() == "10"));
+
+    // persist and restore between polls
+    let stored = serde_json::to_string(audit.snapshot()).unwrap();
+    let mut audit = ModerationAuditStream::from_snapshot(serde_json::from_str(&stored).unwrap());
+
+    // user 1 stays banned, user 2 got a longer timeout, user 3 is new, mod 10 was replaced by 11
+    mock.respond(
+        "moderation/banned",
+        200,
+        &page(&[
+            ban("1", None),
+            ban("2", Some("2022-01-02T00:00:00Z")),
+            ban("3", None),
+        ]),
+    )
+    .respond("moderation/moderators", 200, &page(&[moderator("11")]));
+
+    let changes = audit.poll(&client, &token).await.unwrap();
+    assert_eq!(changes.len(), 4, "{changes:?}");
+    assert!(matches!(&changes[0], ModerationChange::Banned(b) if b.user_id.as_str() == "2"));
+    assert!(matches!(&changes[1], ModerationChange::Banned(b) if b.user_id.as_str() == "3"));
+    assert!(matches!(&changes[2], ModerationChange::ModAdded(m) if m.user_id.as_str() == "11"));
+    assert_eq!(changes[3], ModerationChange::ModRemoved("10".into()));
+
+    // user 1 was unbanned
+    mock.respond(
+        "moderation/banned",
+        200,
+        &page(&[ban("2", Some("2022-01-02T00:00:00Z")), ban("3", None)]),
+    )
+    .respond("moderation/moderators", 200, &page(&[moderator("11")]));
+
+    let changes = audit.poll(&client, &token).await.unwrap();
+    assert_eq!(changes, vec![ModerationChange::Unbanned("1".into())]);
+    assert_eq!(mock.pending(), 0);
+    assert_eq!(audit.into_snapshot().banned.len(), 2);
+}
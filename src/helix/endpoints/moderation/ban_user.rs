@@ -47,6 +47,12 @@
 
 use super::*;
 use helix::RequestPost;
+
+/// Maximum duration of a timeout, in seconds (2 weeks).
+pub const MAX_TIMEOUT_DURATION: u32 = 1_209_600;
+/// Maximum length of a [ban reason](BanUserBody::reason), in characters.
+pub const MAX_REASON_LENGTH: usize = 500;
+
 /// Query Parameters for [Ban User](super::ban_user)
 ///
 /// [`ban-user`](https://dev.twitch.tv/docs/api/reference#ban-user)
@@ -71,6 +77,11 @@ pub struct BanUserRequest<'a> {
 
 impl<'a> BanUserRequest<'a> {
     /// Ban a user on this channel
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::BanUserRequest;
+    /// let request = BanUserRequest::new("1234", "5678");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -111,6 +122,8 @@ pub struct BanUserBody<'a> {
 
 impl<'a> BanUserBody<'a> {
     /// Create a new [`BanUserBody`]
+    ///
+    /// See [`BanUserBody::try_new`] for a version that checks the duration and reason.
     pub fn new(
         user_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         reason: impl Into<Cow<'a, str>>,
@@ -122,6 +135,68 @@ impl<'a> BanUserBody<'a> {
             duration: duration.into(),
         }
     }
+
+    /// Create a new [`BanUserBody`], checking that the duration and reason are accepted by Twitch
+    ///
+    /// The duration must be between 1 and [`MAX_TIMEOUT_DURATION`] seconds,
+    /// and the reason can be at most [`MAX_REASON_LENGTH`] characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::{
+    ///     ban_user::BanUserBodyError, BanUserBody,
+    /// };
+    ///
+    /// assert!(BanUserBody::try_new("9876", "no reason", 120).is_ok());
+    /// assert_eq!(
+    ///     BanUserBody::try_new("9876", "no reason", 0).unwrap_err(),
+    ///     BanUserBodyError::DurationOutOfRange(0)
+    /// );
+    /// ```
+    pub fn try_new(
+        user_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        reason: impl Into<Cow<'a, str>>,
+        duration: impl Into<Option<u32>>,
+    ) -> Result<Self, BanUserBodyError> {
+        let body = Self::new(user_id, reason, duration);
+        if let Some(duration) = body.duration {
+            if !(1..=MAX_TIMEOUT_DURATION).contains(&duration) {
+                return Err(BanUserBodyError::DurationOutOfRange(duration.into()));
+            }
+        }
+        let length = body.reason.chars().count();
+        if length > MAX_REASON_LENGTH {
+            return Err(BanUserBodyError::ReasonTooLong(length));
+        }
+        Ok(body)
+    }
+
+    /// Create a new [`BanUserBody`] for a timeout, checking that the duration and reason are accepted by Twitch
+    ///
+    /// The duration is truncated to whole seconds, see [`BanUserBody::try_new`].
+    pub fn timeout(
+        user_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        reason: impl Into<Cow<'a, str>>,
+        duration: std::time::Duration,
+    ) -> Result<Self, BanUserBodyError> {
+        let seconds = duration.as_secs();
+        let seconds: u32 = std::convert::TryFrom::try_from(seconds)
+            .map_err(|_| BanUserBodyError::DurationOutOfRange(seconds))?;
+        Self::try_new(user_id, reason, seconds)
+    }
+}
+
+/// Invalid [`BanUserBody`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum BanUserBodyError {
+    /// The duration is not between 1 and [`MAX_TIMEOUT_DURATION`] seconds
+    #[error("duration of {0} seconds is not between 1 and 1209600 seconds")]
+    DurationOutOfRange(u64),
+    /// The reason is longer than [`MAX_REASON_LENGTH`] characters
+    #[error("reason is {0} characters long, the maximum is 500")]
+    ReasonTooLong(usize),
 }
 
 impl helix::HelixRequestBody for BanUserBody<'_> {
@@ -148,6 +223,10 @@ pub struct BanUser {
     /// The UTC date and time (in RFC3999 format) when the ban was created.
     pub created_at: types::Timestamp,
     /// The UTC date and time (in RFC3339 format) that the timeout will end. Is null if the user was banned instead of put in a timeout.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
     pub end_time: Option<types::Timestamp>,
     /// The moderator that banned or put the user in the timeout.
     pub moderator_id: types::UserId,
@@ -164,6 +243,8 @@ impl Request for BanUserRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ModeratorManageBannedUsers];
 }
 
+endpoint_meta!(POST BanUserRequest<'static>);
+
 impl<'a> RequestPost for BanUserRequest<'a> {
     type Body = BanUserBody<'a>;
 
@@ -186,6 +267,7 @@ impl<'a> RequestPost for BanUserRequest<'a> {
                 e,
                 uri.clone(),
                 status,
+                None,
             )
         })?;
         Ok(helix::Response::with_data(
@@ -195,6 +277,7 @@ impl<'a> RequestPost for BanUserRequest<'a> {
                     response: response.to_string(),
                     status,
                     uri: uri.clone(),
+                    request_id: None,
                 }
             })?,
             request,
@@ -202,23 +285,73 @@ impl<'a> RequestPost for BanUserRequest<'a> {
     }
 }
 
-#[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = BanUserRequest::new("1234", "5678");
+helix_helper! {
+    /// Ban a user, or put them in a timeout if a duration in seconds is given
+    ///
+    /// The duration and reason are checked with [`BanUserBody::try_new`](helix::moderation::BanUserBody::try_new) before sending the request.
+    /// Banning a user that is already banned fails with [`ModerationActionError::AlreadyBanned`].
+    pub async fn ban_user<'b, T>(
+        target_user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        reason: impl Into<&'b str> + Send,
+        duration: impl Into<Option<u32>> + Send,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+    ) -> helix::moderation::BanUser, ModerationActionError = req_post(ModerationActionError::from_client_error) {
+        BanUserRequest::new(broadcaster_id, moderator_id)
+    } {
+        BanUserBody::try_new(target_user_id, reason.into(), duration).map_err(ModerationActionError::from)?
+    }
+}
 
-    let body = BanUserBody::new("9876", "no reason", 300);
+helix_helper! {
+    /// Ban a user like [`ban_user`](helix::HelixClient::ban_user), in the chat and as the moderator of `ctx`
+    pub async fn ban_user_ctx<'b, T>(
+        ctx: &'b helix::moderation::ModerationContext<'b>,
+        target_user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        reason: impl Into<&'b str> + Send,
+        duration: impl Into<Option<u32>> + Send,
+    ) -> helix::moderation::BanUser, ModerationActionError = req_post(ModerationActionError::from_client_error) {
+        BanUserRequest::new(&*ctx.broadcaster_id, &*ctx.moderator_id)
+    } {
+        BanUserBody::try_new(target_user_id, reason.into(), duration).map_err(ModerationActionError::from)?
+    }
+}
 
-    assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
-        r#"{"data":{"user_id":"9876","duration":300,"reason":"no reason"}}"#
-    );
+helix_helper! {
+    /// Put a user in a timeout
+    ///
+    /// The duration is truncated to whole seconds and must be between 1 second and 2 weeks,
+    /// see [`BanUserBody::timeout`](helix::moderation::BanUserBody::timeout).
+    pub async fn timeout_user<'b, T>(
+        target_user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        reason: impl Into<&'b str> + Send,
+        duration: std::time::Duration,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+    ) -> helix::moderation::BanUser, BanUserBodyError = req_post {
+        BanUserRequest::new(broadcaster_id, moderator_id)
+    } {
+        BanUserBody::timeout(target_user_id, reason.into(), duration)?
+    }
+}
 
-    dbg!(req.create_request(body, "token", "clientid").unwrap());
+helix_helper! {
+    /// Put a user in a timeout like [`timeout_user`](helix::HelixClient::timeout_user), in the chat and as the moderator of `ctx`
+    pub async fn timeout_user_ctx<'b, T>(
+        ctx: &'b helix::moderation::ModerationContext<'b>,
+        target_user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        reason: impl Into<&'b str> + Send,
+        duration: std::time::Duration,
+    ) -> helix::moderation::BanUser, BanUserBodyError = req_post {
+        BanUserRequest::new(&*ctx.broadcaster_id, &*ctx.moderator_id)
+    } {
+        BanUserBody::timeout(target_user_id, reason.into(), duration)?
+    }
+}
 
-    // From twitch docs
-    let data = br#"
+// From twitch docs
+#[cfg(test)]
+const FIXTURE: &[u8] = br#"
     {
         "data": [
           {
@@ -230,8 +363,26 @@ fn test_request() {
           }
         ]
       }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = BanUserRequest::new("1234", "5678");
+
+    let body = BanUserBody::new("9876", "no reason", 300);
+
+    let json = body.body_json().unwrap();
+    assert_eq!(
+        json,
+        r#"{"data":{"user_id":"9876","duration":300,"reason":"no reason"}}"#
+    );
+
+    let request = req.create_request(body, "token", "clientid").unwrap();
+    assert_eq!(request.body().as_ref(), json.as_bytes());
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -274,3 +425,71 @@ fn test_request_error() {
 
     dbg!(BanUserRequest::parse_response(Some(req), &uri, http_response).unwrap_err());
 }
+
+#[cfg(test)]
+#[test]
+fn test_body_validation() {
+    assert_eq!(
+        BanUserBody::try_new("9876", "", 0).unwrap_err(),
+        BanUserBodyError::DurationOutOfRange(0)
+    );
+    assert!(BanUserBody::try_new("9876", "", 1).is_ok());
+    assert!(BanUserBody::try_new("9876", "", 1_209_600).is_ok());
+    assert_eq!(
+        BanUserBody::try_new("9876", "", 1_209_601).unwrap_err(),
+        BanUserBodyError::DurationOutOfRange(1_209_601)
+    );
+    assert!(BanUserBody::try_new("9876", "", None).is_ok());
+
+    assert!(BanUserBody::try_new("9876", "a".repeat(500), None).is_ok());
+    assert_eq!(
+        BanUserBody::try_new("9876", "a".repeat(501), None).unwrap_err(),
+        BanUserBodyError::ReasonTooLong(501)
+    );
+
+    let timeout = |secs| BanUserBody::timeout("9876", "", std::time::Duration::from_secs(secs));
+    assert_eq!(
+        timeout(0).unwrap_err(),
+        BanUserBodyError::DurationOutOfRange(0)
+    );
+    assert_eq!(timeout(1).unwrap().duration, Some(1));
+    assert_eq!(timeout(1_209_600).unwrap().duration, Some(1_209_600));
+    assert_eq!(
+        timeout(1_209_601).unwrap_err(),
+        BanUserBodyError::DurationOutOfRange(1_209_601)
+    );
+    assert_eq!(
+        timeout(u64::MAX).unwrap_err(),
+        BanUserBodyError::DurationOutOfRange(u64::MAX)
+    );
+    assert_eq!(
+        BanUserBody::timeout("9876", "", std::time::Duration::from_millis(1500))
+            .unwrap()
+            .duration,
+        Some(1)
+    );
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_body_roundtrip(
+        user_id in "(?s).*",
+        reason in "(?s).*",
+        duration in proptest::option::of(proptest::num::u32::ANY),
+    ) {
+        use helix::*;
+        let body = BanUserBody::new(user_id.as_str(), reason.as_str(), duration);
+
+        let json: serde_json::Value = serde_json::from_slice(&body.try_to_body().unwrap()).unwrap();
+        proptest::prop_assert_eq!(&json["data"]["user_id"], &*user_id);
+        proptest::prop_assert_eq!(&json["data"]["reason"], &*reason);
+        proptest::prop_assert_eq!(json["data"].get("duration").and_then(|d| d.as_u64()), duration.map(u64::from));
+    }
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: BanUserRequest,
+    200 => FIXTURE,
+}
@@ -1,4 +1,3 @@
-#![allow(deprecated_in_future, deprecated)]
 //! Determines whether a string message meets the channel’s AutoMod requirements.
 //! [`check-automod-status`](https://dev.twitch.tv/docs/api/reference#check-automod-status)
 //!
@@ -70,6 +69,11 @@ pub struct CheckAutoModStatusRequest<'a> {
 
 impl<'a> CheckAutoModStatusRequest<'a> {
     /// Check automod status in this broadcasters channel.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::CheckAutoModStatusRequest;
+    /// let request = CheckAutoModStatusRequest::broadcaster_id("1234");
+    /// ```
     pub fn broadcaster_id(broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
         Self {
             broadcaster_id: broadcaster_id.into_cow(),
@@ -80,8 +84,11 @@ impl<'a> CheckAutoModStatusRequest<'a> {
 /// Body Parameters for [Check AutoMod Status](super::check_automod_status)
 ///
 /// [`check-automod-status`](https://dev.twitch.tv/docs/api/reference#check-automod-status)
+///
+/// The deprecated `user_id` is only available with the `automod_user_id` feature and will be removed.
 #[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
 #[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[cfg_attr(feature = "automod_user_id", allow(deprecated))]
 #[non_exhaustive]
 pub struct CheckAutoModStatusBody<'a> {
     /// Developer-generated identifier for mapping messages to results.
@@ -93,6 +100,7 @@ pub struct CheckAutoModStatusBody<'a> {
     #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
     pub msg_text: Cow<'a, str>,
     /// User ID of the sender.
+    #[cfg(feature = "automod_user_id")]
     #[deprecated(since = "0.7.0", note = "user_id in automod check is no longer read")]
     #[cfg_attr(
         feature = "typed-builder",
@@ -105,6 +113,7 @@ pub struct CheckAutoModStatusBody<'a> {
 
 impl<'a> CheckAutoModStatusBody<'a> {
     /// Create a new [`CheckAutoModStatusBody`]
+    #[cfg_attr(feature = "automod_user_id", allow(deprecated))]
     pub fn new(
         msg_id: impl types::IntoCow<'a, types::MsgIdRef> + 'a,
         msg_text: impl Into<Cow<'a, str>>,
@@ -112,9 +121,36 @@ impl<'a> CheckAutoModStatusBody<'a> {
         Self {
             msg_id: msg_id.into_cow(),
             msg_text: msg_text.into(),
+            #[cfg(feature = "automod_user_id")]
             user_id: None,
         }
     }
+
+    /// Create a [`CheckAutoModStatusBody`] for every message id and text pair
+    ///
+    /// Twitch checks at most 100 messages per request, see [`HelixClient::check_messages_automod`](crate::helix::HelixClient::check_messages_automod) to check more.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::CheckAutoModStatusBody;
+    ///
+    /// let bodies = CheckAutoModStatusBody::from_pairs([
+    ///     ("123", "Hello World!"),
+    ///     ("393", "Boooooo!"),
+    /// ]);
+    /// let body: Vec<&CheckAutoModStatusBody> = bodies.iter().collect();
+    /// # let _ = body.as_slice();
+    /// ```
+    pub fn from_pairs<Id, Text>(pairs: impl IntoIterator<Item = (Id, Text)>) -> Vec<Self>
+    where
+        Id: types::IntoCow<'a, types::MsgIdRef> + 'a,
+        Text: Into<Cow<'a, str>>, {
+        pairs
+            .into_iter()
+            .map(|(msg_id, msg_text)| Self::new(msg_id, msg_text))
+            .collect()
+    }
 }
 
 impl<'a> helix::HelixRequestBody for &'a [&'a CheckAutoModStatusBody<'a>] {
@@ -143,6 +179,35 @@ pub struct CheckAutoModStatus {
     pub is_permitted: bool,
 }
 
+/// What [`HelixClient::enforce_automod`](crate::helix::HelixClient::enforce_automod) does with a message AutoMod doesn't permit
+#[cfg(feature = "client")]
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub enum EnforcementAction<'a> {
+    /// Delete the message.
+    DeleteOnly,
+    /// Delete the message and put its sender in a timeout.
+    DeleteAndTimeout {
+        /// Duration of the timeout in seconds, between 1 and [`MAX_TIMEOUT_DURATION`](super::ban_user::MAX_TIMEOUT_DURATION).
+        seconds: u32,
+        /// The reason for the timeout, at most [`MAX_REASON_LENGTH`](super::ban_user::MAX_REASON_LENGTH) characters.
+        reason: Cow<'a, str>,
+    },
+}
+
+/// What [`HelixClient::enforce_automod`](crate::helix::HelixClient::enforce_automod) did with a message
+#[cfg(feature = "client")]
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub struct AutoModEnforcement {
+    /// The AutoMod verdict for the message.
+    pub verdict: CheckAutoModStatus,
+    /// Whether the message was deleted.
+    pub deleted: bool,
+    /// The timeout of the sender, if they were put in one.
+    pub timeout: Option<super::BanUser>,
+}
+
 impl Request for CheckAutoModStatusRequest<'_> {
     type Response = Vec<CheckAutoModStatus>;
 
@@ -150,12 +215,39 @@ impl Request for CheckAutoModStatusRequest<'_> {
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator =
         twitch_oauth2::validator![twitch_oauth2::Scope::ModerationRead];
+
+    fn as_token_bound(&self) -> Option<&dyn helix::TokenBoundRequest> { Some(self) }
+}
+
+impl helix::TokenBoundRequest for CheckAutoModStatusRequest<'_> {
+    fn token_bound_user_id(&self) -> Option<&types::UserIdRef> { Some(&self.broadcaster_id) }
 }
 
+endpoint_meta!(POST CheckAutoModStatusRequest<'static>);
+
 impl<'a> RequestPost for CheckAutoModStatusRequest<'a> {
     type Body = &'a [&'a CheckAutoModStatusBody<'a>];
 }
 
+impl helix::IdempotentPost for CheckAutoModStatusRequest<'_> {}
+
+// From twitch docs
+#[cfg(test)]
+const FIXTURE: &[u8] = br#"
+{
+   "data": [
+     {
+       "msg_id": "123",
+       "is_permitted": true
+     },
+     {
+       "msg_id": "393",
+       "is_permitted": false
+     }
+   ]
+}
+"#;
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -166,28 +258,15 @@ fn test_request() {
         &CheckAutoModStatusBody::new("393", "Boooooo!"),
     ];
 
+    let json = body.as_slice().body_json().unwrap();
     assert_eq!(
-        std::str::from_utf8(&body.as_slice().try_to_body().unwrap()).unwrap(),
+        json,
         r#"{"data":[{"msg_id":"123","msg_text":"Hello World!"},{"msg_id":"393","msg_text":"Boooooo!"}]}"#
     );
 
-    dbg!(req.create_request(body, "token", "clientid").unwrap());
-    // From twitch docs
-    let data = br#"
-{
-   "data": [
-     {
-       "msg_id": "123",
-       "is_permitted": true
-     },
-     {
-       "msg_id": "393",
-       "is_permitted": false
-     }
-   ]
-}
-"#
-    .to_vec();
+    let request = req.create_request(body, "token", "clientid").unwrap();
+    assert_eq!(request.body().as_ref(), json.as_bytes());
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -199,3 +278,34 @@ fn test_request() {
 
     dbg!(CheckAutoModStatusRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[deny(deprecated)]
+mod from_pairs_tests {
+    use super::*;
+    use helix::HelixRequestBody;
+
+    #[test]
+    fn from_pairs() {
+        let bodies =
+            CheckAutoModStatusBody::from_pairs([("123", "Hello World!"), ("393", "Boooooo!")]);
+        assert_eq!(
+            bodies,
+            vec![
+                CheckAutoModStatusBody::new("123", "Hello World!"),
+                CheckAutoModStatusBody::new("393", "Boooooo!"),
+            ]
+        );
+        let body: Vec<_> = bodies.iter().collect();
+        assert_eq!(
+            body.as_slice().body_json().unwrap(),
+            r#"{"data":[{"msg_id":"123","msg_text":"Hello World!"},{"msg_id":"393","msg_text":"Boooooo!"}]}"#
+        );
+    }
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: CheckAutoModStatusRequest,
+    200 => FIXTURE,
+}
@@ -74,6 +74,11 @@ pub struct DeleteChatMessagesRequest<'a> {
 
 impl<'a> DeleteChatMessagesRequest<'a> {
     /// Remove chat message(s)
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::DeleteChatMessagesRequest;
+    /// let request = DeleteChatMessagesRequest::new("1234", "5678").message_id("abc-123-def");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -111,8 +116,16 @@ impl Request for DeleteChatMessagesRequest<'_> {
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator =
         twitch_oauth2::validator![twitch_oauth2::Scope::ModeratorManageChatMessages];
+
+    fn as_token_bound(&self) -> Option<&dyn helix::TokenBoundRequest> { Some(self) }
 }
 
+impl helix::TokenBoundRequest for DeleteChatMessagesRequest<'_> {
+    fn token_bound_user_id(&self) -> Option<&types::UserIdRef> { Some(&self.moderator_id) }
+}
+
+endpoint_meta!(DELETE DeleteChatMessagesRequest<'static>);
+
 impl RequestDelete for DeleteChatMessagesRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -133,11 +146,43 @@ impl RequestDelete for DeleteChatMessagesRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
 }
 
+helix_helper! {
+    /// Delete a specific chat message
+    pub async fn delete_chat_message<'b, T>(
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        message_id: impl types::IntoCow<'b, types::MsgIdRef> + Send + 'b,
+    ) -> helix::moderation::DeleteChatMessagesResponse = req_delete {
+        DeleteChatMessagesRequest::new(broadcaster_id, moderator_id).message_id(message_id)
+    }
+}
+
+helix_helper! {
+    /// Delete a specific chat message like [`delete_chat_message`](helix::HelixClient::delete_chat_message), in the chat and as the moderator of `ctx`
+    pub async fn delete_chat_message_ctx<'b, T>(
+        ctx: &'b helix::moderation::ModerationContext<'b>,
+        message_id: impl types::IntoCow<'b, types::MsgIdRef> + Send + 'b,
+    ) -> helix::moderation::DeleteChatMessagesResponse = req_delete {
+        DeleteChatMessagesRequest::new(&*ctx.broadcaster_id, &*ctx.moderator_id).message_id(message_id)
+    }
+}
+
+helix_helper! {
+    /// Delete all chat messages in a broadcasters chat room
+    pub async fn delete_all_chat_message<'b, T>(
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+    ) -> helix::moderation::DeleteChatMessagesResponse = req_delete {
+        DeleteChatMessagesRequest::new(broadcaster_id, moderator_id)
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_request_all() {
@@ -177,3 +222,9 @@ fn test_request_specific() {
 
     dbg!(DeleteChatMessagesRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: DeleteChatMessagesRequest,
+    204 => b"",
+}
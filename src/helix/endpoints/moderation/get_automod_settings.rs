@@ -57,6 +57,11 @@ pub struct GetAutoModSettingsRequest<'a> {
 
 impl<'a> GetAutoModSettingsRequest<'a> {
     /// Get AutoMod settings in a broadcasters channel as specified moderator
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::GetAutoModSettingsRequest;
+    /// let request = GetAutoModSettingsRequest::new("1234", "5678");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -110,6 +115,8 @@ impl Request for GetAutoModSettingsRequest<'_> {
     )];
 }
 
+endpoint_meta!(GET GetAutoModSettingsRequest<'static>);
+
 impl RequestGet for GetAutoModSettingsRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -125,12 +132,7 @@ impl RequestGet for GetAutoModSettingsRequest<'_> {
 }
 
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetAutoModSettingsRequest::new("1234", "5678");
-
-    let data = br#"
+const FIXTURE: &[u8] = br#"
     {
         "data": [
             {
@@ -148,8 +150,15 @@ fn test_request() {
             }
         ]
     }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetAutoModSettingsRequest::new("1234", "5678");
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -169,12 +178,7 @@ fn test_request() {
 }
 
 #[cfg(test)]
-#[test]
-fn test_request_with_overall() {
-    use helix::*;
-    let req = GetAutoModSettingsRequest::new("1234", "5678");
-
-    let data = br#"
+const FIXTURE_WITH_OVERALL: &[u8] = br#"
     {
         "data": [
             {
@@ -192,8 +196,15 @@ fn test_request_with_overall() {
             }
         ]
     }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request_with_overall() {
+    use helix::*;
+    let req = GetAutoModSettingsRequest::new("1234", "5678");
+
+    let data = FIXTURE_WITH_OVERALL.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -212,3 +223,10 @@ fn test_request_with_overall() {
     assert_eq!(res.broadcaster_id.as_str(), "1234");
     assert_eq!(res.moderator_id.as_str(), "5678");
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetAutoModSettingsRequest,
+    200 => FIXTURE,
+    200 => FIXTURE_WITH_OVERALL,
+}
@@ -72,6 +72,11 @@ pub struct GetBannedUsersRequest<'a> {
 
 impl<'a> GetBannedUsersRequest<'a> {
     /// Get banned users in a broadcasters channel.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::GetBannedUsersRequest;
+    /// let request = GetBannedUsersRequest::broadcaster_id("1234");
+    /// ```
     pub fn broadcaster_id(broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
         Self {
             broadcaster_id: broadcaster_id.into_cow(),
@@ -108,11 +113,19 @@ pub struct BannedUser {
     pub user_name: types::DisplayName,
     /// Login of a user who has been banned.
     pub user_login: types::UserName,
-    #[serde(deserialize_with = "crate::deserialize_none_from_empty_string")]
-    /// RFC3339 formatted timestamp for timeouts; empty string for bans.
+    /// When the timeout expires, [`None`] for permanent bans.
+    ///
+    /// See [`BannedUser::ban_kind`] to tell timeouts and bans apart.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
     pub expires_at: Option<types::Timestamp>,
     /// The reason for the ban if provided by the moderator.
-    #[serde(deserialize_with = "crate::deserialize_none_from_empty_string")]
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
     pub reason: Option<String>,
     /// User ID of the moderator who initiated the ban.
     pub moderator_id: types::UserId,
@@ -122,6 +135,135 @@ pub struct BannedUser {
     pub moderator_name: types::DisplayName,
 }
 
+/// Whether a [`BannedUser`] is banned permanently or timed out
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub enum BanKind {
+    /// The user is banned until unbanned by a moderator.
+    Permanent,
+    /// The user is timed out.
+    Timeout {
+        /// When the timeout expires.
+        expires_at: types::Timestamp,
+        /// Time left until the timeout expires.
+        ///
+        /// [`None`] if the timeout has already expired but Twitch still lists it, or if the `time` feature is not enabled.
+        remaining: Option<std::time::Duration>,
+    },
+}
+
+impl BanKind {
+    /// Whether this is a permanent ban.
+    pub fn is_permanent(&self) -> bool { matches!(self, BanKind::Permanent) }
+
+    #[cfg_attr(not(feature = "time"), allow(unused_variables))]
+    fn new(expires_at: Option<&types::Timestamp>, now: Option<&types::TimestampRef>) -> Self {
+        let Some(expires_at) = expires_at else {
+            return BanKind::Permanent;
+        };
+        #[cfg(feature = "time")]
+        let remaining = now.and_then(|now| {
+            std::convert::TryFrom::try_from(expires_at.to_utc() - now.to_utc())
+                .ok()
+                .filter(|remaining: &std::time::Duration| !remaining.is_zero())
+        });
+        #[cfg(not(feature = "time"))]
+        let remaining = None;
+        BanKind::Timeout {
+            expires_at: expires_at.clone(),
+            remaining,
+        }
+    }
+}
+
+impl BannedUser {
+    /// Whether the user is banned permanently or timed out, and for how much longer if timed out.
+    pub fn ban_kind(&self) -> BanKind {
+        #[cfg(feature = "time")]
+        let now = Some(types::Timestamp::now());
+        #[cfg(not(feature = "time"))]
+        let now: Option<types::Timestamp> = None;
+        BanKind::new(self.expires_at.as_ref(), now.as_deref())
+    }
+
+    /// Whether the user is banned permanently or timed out, with the time left on a timeout counted from `now`.
+    #[cfg(feature = "time")]
+    pub fn ban_kind_at(&self, now: &types::TimestampRef) -> BanKind {
+        BanKind::new(self.expires_at.as_ref(), Some(now))
+    }
+}
+
+/// Borrowed [`BannedUser`], returned by [`BorrowedResponse::data`](helix::BorrowedResponse::data)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct BannedUserRef<'a> {
+    /// User ID of a user who has been banned.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub user_id: Cow<'a, types::UserIdRef>,
+    /// Display name of a user who has been banned.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub user_name: Cow<'a, types::DisplayNameRef>,
+    /// Login of a user who has been banned.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub user_login: Cow<'a, types::UserNameRef>,
+    /// When the timeout expires, [`None`] for permanent bans.
+    ///
+    /// See [`BannedUserRef::ban_kind`] to tell timeouts and bans apart.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
+    pub expires_at: Option<types::Timestamp>,
+    /// The reason for the ban if provided by the moderator.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
+    pub reason: Option<String>,
+    /// User ID of the moderator who initiated the ban.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub moderator_id: Cow<'a, types::UserIdRef>,
+    /// Login of the moderator who initiated the ban.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub moderator_login: Cow<'a, types::UserNameRef>,
+    /// Display name of the moderator who initiated the ban.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub moderator_name: Cow<'a, types::DisplayNameRef>,
+}
+
+impl BannedUserRef<'_> {
+    /// Convert into an owned [`BannedUser`]
+    pub fn into_owned(self) -> BannedUser {
+        BannedUser {
+            user_id: self.user_id.into_owned(),
+            user_name: self.user_name.into_owned(),
+            user_login: self.user_login.into_owned(),
+            expires_at: self.expires_at,
+            reason: self.reason,
+            moderator_id: self.moderator_id.into_owned(),
+            moderator_login: self.moderator_login.into_owned(),
+            moderator_name: self.moderator_name.into_owned(),
+        }
+    }
+}
+
+impl BannedUserRef<'_> {
+    /// Whether the user is banned permanently or timed out, and for how much longer if timed out.
+    pub fn ban_kind(&self) -> BanKind {
+        #[cfg(feature = "time")]
+        let now = Some(types::Timestamp::now());
+        #[cfg(not(feature = "time"))]
+        let now: Option<types::Timestamp> = None;
+        BanKind::new(self.expires_at.as_ref(), now.as_deref())
+    }
+
+    /// Whether the user is banned permanently or timed out, with the time left on a timeout counted from `now`.
+    #[cfg(feature = "time")]
+    pub fn ban_kind_at(&self, now: &types::TimestampRef) -> BanKind {
+        BanKind::new(self.expires_at.as_ref(), Some(now))
+    }
+}
+
 impl Request for GetBannedUsersRequest<'_> {
     type Response = Vec<BannedUser>;
 
@@ -131,16 +273,50 @@ impl Request for GetBannedUsersRequest<'_> {
         twitch_oauth2::Scope::ModerationRead,
         twitch_oauth2::Scope::ModeratorManageBannedUsers
     )];
+    const TIMEOUT_HINT: Option<std::time::Duration> = Some(helix::SLOW_ENDPOINT_TIMEOUT);
 }
 
+endpoint_meta!(GET GetBannedUsersRequest<'static>);
+
 impl RequestGet for GetBannedUsersRequest<'_> {}
 
+impl helix::RequestGetBorrowed for GetBannedUsersRequest<'_> {
+    type BorrowedResponse<'a> = Vec<BannedUserRef<'a>>;
+}
+
 impl helix::Paginated for GetBannedUsersRequest<'_> {
     fn set_pagination(&mut self, cursor: Option<helix::Cursor>) {
         self.after = cursor.map(|c| c.into_cow())
     }
 }
 
+helix_helper! {
+    /// Get all banned users in a channel [Get Banned Users](helix::moderation::GetBannedUsersRequest)
+    ///
+    /// Timed out users are included, use [`BannedUser::ban_kind`](helix::moderation::BannedUser::ban_kind) to tell them apart from permanent bans.
+    /// Twitch may still list a timeout shortly after it has expired.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    /// use futures::TryStreamExt;
+    ///
+    /// let moderators: Vec<helix::moderation::BannedUser> = client.get_banned_users_in_channel_from_id("twitchdev", &token).try_collect().await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_banned_users_in_channel_from_id<'b: 'client, T>(
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + 'b,
+    ) -> Stream<helix::moderation::BannedUser> {
+        GetBannedUsersRequest::broadcaster_id(broadcaster_id)
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -189,7 +365,7 @@ fn test_request() {
 "#
         .to_vec();
 
-    let http_response = http::Response::builder().body(data).unwrap();
+    let http_response = http::Response::builder().body(data.clone()).unwrap();
 
     let uri = req.get_uri().unwrap();
     assert_eq!(
@@ -197,5 +373,107 @@ fn test_request() {
         "https://api.twitch.tv/helix/moderation/banned?broadcaster_id=198704263"
     );
 
-    dbg!(GetBannedUsersRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let owned = dbg!(
+        GetBannedUsersRequest::parse_response(Some(req.clone()), &uri, http_response).unwrap()
+    );
+
+    let borrowed = BorrowedResponse::parse_response(
+        Some(req),
+        &uri,
+        http::Response::builder().body(data).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        borrowed
+            .data()
+            .unwrap()
+            .data
+            .into_iter()
+            .map(BannedUserRef::into_owned)
+            .collect::<Vec<_>>(),
+        owned.data
+    );
+}
+
+#[cfg(test)]
+const FIXTURE_BAN_KIND: &[u8] = br#"
+{
+  "data": [
+    {
+      "user_id": "423374343",
+      "user_login": "glowillig",
+      "user_name": "glowillig",
+      "expires_at": "",
+      "reason": "",
+      "moderator_id": "141981764",
+      "moderator_login": "twitchdev",
+      "moderator_name": "TwitchDev"
+    },
+    {
+      "user_id": "424596340",
+      "user_login": "quotrok",
+      "user_name": "quotrok",
+      "expires_at": "2022-08-07T02:07:55Z",
+      "reason": "Inappropriate words.",
+      "moderator_id": "141981764",
+      "moderator_login": "twitchdev",
+      "moderator_name": "TwitchDev"
+    },
+    {
+      "user_id": "424596341",
+      "user_login": "quotrak",
+      "user_name": "quotrak",
+      "expires_at": "2022-08-07T01:00:00Z",
+      "moderator_id": "141981764",
+      "moderator_login": "twitchdev",
+      "moderator_name": "TwitchDev"
+    }
+  ],
+  "pagination": {}
+}
+"#;
+
+#[cfg(all(test, feature = "time"))]
+#[test]
+fn test_ban_kind() {
+    use helix::*;
+    let req = GetBannedUsersRequest::broadcaster_id("198704263");
+
+    let data = FIXTURE_BAN_KIND.to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    let users = GetBannedUsersRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    let now = types::Timestamp::new("2022-08-07T02:00:00Z").unwrap();
+
+    assert_eq!(users[0].reason, None);
+    assert_eq!(users[0].ban_kind_at(&now), BanKind::Permanent);
+    assert!(users[0].ban_kind().is_permanent());
+
+    assert_eq!(users[1].reason.as_deref(), Some("Inappropriate words."));
+    assert_eq!(
+        users[1].ban_kind_at(&now),
+        BanKind::Timeout {
+            expires_at: types::Timestamp::new("2022-08-07T02:07:55Z").unwrap(),
+            remaining: Some(std::time::Duration::from_secs(7 * 60 + 55)),
+        }
+    );
+
+    assert_eq!(users[2].reason, None);
+    assert_eq!(
+        users[2].ban_kind_at(&now),
+        BanKind::Timeout {
+            expires_at: types::Timestamp::new("2022-08-07T01:00:00Z").unwrap(),
+            remaining: None,
+        }
+    );
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetBannedUsersRequest,
+    200 => FIXTURE_BAN_KIND,
 }
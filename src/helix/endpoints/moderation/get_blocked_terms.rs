@@ -65,6 +65,11 @@ pub struct GetBlockedTermsRequest<'a> {
 
 impl<'a> GetBlockedTermsRequest<'a> {
     /// Get blocked terms in a broadcasters channel as specified moderator
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::get_blocked_terms::GetBlockedTermsRequest;
+    /// let request = GetBlockedTermsRequest::new("1234", "5678");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -100,6 +105,8 @@ impl Request for GetBlockedTermsRequest<'_> {
     )];
 }
 
+endpoint_meta!(GET GetBlockedTermsRequest<'static>);
+
 impl RequestGet for GetBlockedTermsRequest<'_> {}
 
 impl helix::Paginated for GetBlockedTermsRequest<'_> {
@@ -108,14 +115,9 @@ impl helix::Paginated for GetBlockedTermsRequest<'_> {
     }
 }
 
+// From twitch docs, FIXME: has ... and a "bad" comma
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetBlockedTermsRequest::new("1234", "5678").first(10);
-
-    // From twitch docs, FIXME: has ... and a "bad" comma
-    let data = br#"
+const FIXTURE: &[u8] = br#"
     {
       "data": [
         {
@@ -132,8 +134,15 @@ fn test_request() {
         "cursor": "eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6I..."
       }
     }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetBlockedTermsRequest::new("1234", "5678").first(10);
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -145,3 +154,9 @@ fn test_request() {
 
     dbg!(GetBlockedTermsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetBlockedTermsRequest,
+    200 => FIXTURE,
+}
@@ -37,6 +37,9 @@ use helix::RequestGet;
 
 // Format: Repeated Query Parameter, eg. /moderation/banned?broadcaster_id=1&user_id=2&user_id=3
 // Maximum: 100
+/// Maximum amount of moderators returned per page
+pub const MAX_PAGE_SIZE: usize = 100;
+
 /// Query Parameters for [Get Moderators](super::get_moderators)
 ///
 /// [`get-moderators`](https://dev.twitch.tv/docs/api/reference#get-moderators)
@@ -61,11 +64,16 @@ pub struct GetModeratorsRequest<'a> {
     pub after: Option<Cow<'a, helix::CursorRef>>,
     /// Number of values to be returned per page. Limit: 100. Default: 20.
     #[cfg_attr(feature = "typed-builder", builder(setter(into), default))]
-    pub first: Option<usize>,
+    pub first: Option<helix::PageSize>,
 }
 
 impl<'a> GetModeratorsRequest<'a> {
     /// Get moderators in a broadcasters channel.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::GetModeratorsRequest;
+    /// let request = GetModeratorsRequest::broadcaster_id("1234");
+    /// ```
     pub fn broadcaster_id(broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
         Self {
             broadcaster_id: broadcaster_id.into_cow(),
@@ -81,9 +89,16 @@ impl<'a> GetModeratorsRequest<'a> {
         self
     }
 
-    /// Set amount of results returned per page.
-    pub fn first(mut self, first: usize) -> Self {
-        self.first = Some(first);
+    /// Set amount of results returned per page, values above the limit of [100](MAX_PAGE_SIZE) are clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::GetModeratorsRequest;
+    /// let request = GetModeratorsRequest::broadcaster_id("1234").first(100);
+    /// ```
+    pub fn first(mut self, first: impl Into<Option<usize>>) -> Self {
+        self.first = first.into().map(helix::PageSize::clamped::<Self>);
         self
     }
 }
@@ -106,6 +121,7 @@ pub struct Moderator {
 impl Request for GetModeratorsRequest<'_> {
     type Response = Vec<Moderator>;
 
+    const MAX_PAGE_SIZE: usize = MAX_PAGE_SIZE;
     const PATH: &'static str = "moderation/moderators";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![any(
@@ -114,22 +130,53 @@ impl Request for GetModeratorsRequest<'_> {
     )];
 }
 
+endpoint_meta!(GET GetModeratorsRequest<'static>);
+
 impl RequestGet for GetModeratorsRequest<'_> {}
 
 impl helix::Paginated for GetModeratorsRequest<'_> {
     fn set_pagination(&mut self, cursor: Option<helix::Cursor>) {
         self.after = cursor.map(|c| c.into_cow())
     }
+
+    fn set_first(&mut self, first: usize) {
+        self.first = Some(helix::PageSize::clamped::<Self>(first))
+    }
 }
 
-#[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetModeratorsRequest::broadcaster_id("198704263");
+helix_helper! {
+    /// Get all moderators in a channel [Get Moderators](helix::moderation::GetModeratorsRequest)
+    ///
+    /// `batch_size` sets the amount of moderators to retrieve per api call, max 100, defaults to 100.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    /// use futures::TryStreamExt;
+    ///
+    /// let moderators: Vec<helix::moderation::Moderator> = client
+    ///     .get_moderators_in_channel_from_id("twitchdev", None, &token)
+    ///     .try_collect().await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_moderators_in_channel_from_id<'b: 'client, T>(
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + 'b,
+        batch_size: impl Into<Option<usize>>,
+    ) -> Stream<helix::moderation::Moderator> {
+        GetModeratorsRequest::broadcaster_id(broadcaster_id)
+            .first(batch_size.into().unwrap_or(MAX_PAGE_SIZE))
+    }
+}
 
-    // From twitch docs
-    let data = br#"
+// From twitch docs
+#[cfg(test)]
+const FIXTURE: &[u8] = br#"
 {
     "data": [
         {
@@ -147,8 +194,15 @@ fn test_request() {
         "cursor": "eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6IjEwMDQ3MzA2NDo4NjQwNjU3MToxSVZCVDFKMnY5M1BTOXh3d1E0dUdXMkJOMFcifX0"
     }
 }
-"#
-        .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetModeratorsRequest::broadcaster_id("198704263");
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -160,3 +214,20 @@ fn test_request() {
 
     dbg!(GetModeratorsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_first() {
+    use helix::*;
+    let req = GetModeratorsRequest::broadcaster_id("198704263").first(250);
+    assert_eq!(
+        req.get_uri().unwrap().to_string(),
+        "https://api.twitch.tv/helix/moderation/moderators?broadcaster_id=198704263&first=100"
+    );
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetModeratorsRequest,
+    200 => FIXTURE,
+}
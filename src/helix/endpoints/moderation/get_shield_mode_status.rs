@@ -57,6 +57,11 @@ pub struct GetShieldModeStatusRequest<'a> {
 
 impl<'a> GetShieldModeStatusRequest<'a> {
     /// Get shield mode status in a broadcasters channel as specified moderator
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::GetShieldModeStatusRequest;
+    /// let request = GetShieldModeStatusRequest::new("1234", "5678");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -89,13 +94,26 @@ impl<'de> serde::Deserialize<'de> for ShieldModeStatus {
         #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
         struct InnerShieldModeStatus {
             is_active: bool,
-            #[serde(deserialize_with = "crate::deserialize_none_from_empty_string")]
+            // missing when serialized without a `last_shield_mode`
+            #[serde(
+                default,
+                deserialize_with = "crate::deserialize_none_from_empty_string"
+            )]
             moderator_id: Option<types::UserId>,
-            #[serde(deserialize_with = "crate::deserialize_none_from_empty_string")]
+            #[serde(
+                default,
+                deserialize_with = "crate::deserialize_none_from_empty_string"
+            )]
             moderator_login: Option<types::UserName>,
-            #[serde(deserialize_with = "crate::deserialize_none_from_empty_string")]
+            #[serde(
+                default,
+                deserialize_with = "crate::deserialize_none_from_empty_string"
+            )]
             moderator_name: Option<types::DisplayName>,
-            #[serde(deserialize_with = "crate::deserialize_none_from_empty_string")]
+            #[serde(
+                default,
+                deserialize_with = "crate::deserialize_none_from_empty_string"
+            )]
             last_activated_at: Option<types::Timestamp>,
         }
 
@@ -153,6 +171,8 @@ impl Request for GetShieldModeStatusRequest<'_> {
     )];
 }
 
+endpoint_meta!(GET GetShieldModeStatusRequest<'static>);
+
 impl RequestGet for GetShieldModeStatusRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -170,6 +190,7 @@ impl RequestGet for GetShieldModeStatusRequest<'_> {
                     e,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         Ok(helix::Response::new(
@@ -179,6 +200,7 @@ impl RequestGet for GetShieldModeStatusRequest<'_> {
                     response: response.to_string(),
                     status,
                     uri: uri.clone(),
+                    request_id: None,
                 },
             )?,
             inner_response.pagination.cursor,
@@ -189,14 +211,9 @@ impl RequestGet for GetShieldModeStatusRequest<'_> {
     }
 }
 
+// From twitch docs, FIXME: has ... and a "bad" comma
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetShieldModeStatusRequest::new("12345", "98765");
-
-    // From twitch docs, FIXME: has ... and a "bad" comma
-    let data = br#"
+const FIXTURE: &[u8] = br#"
     {
         "data": [
           {
@@ -208,8 +225,15 @@ fn test_request() {
           }
         ]
       }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetShieldModeStatusRequest::new("12345", "98765");
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -222,14 +246,9 @@ fn test_request() {
     dbg!(GetShieldModeStatusRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
 
+// From twitch docs, FIXME: has ... and a "bad" comma
 #[cfg(test)]
-#[test]
-fn test_request_empty() {
-    use helix::*;
-    let req = GetShieldModeStatusRequest::new("12345", "98765");
-
-    // From twitch docs, FIXME: has ... and a "bad" comma
-    let data = br#"
+const FIXTURE_EMPTY: &[u8] = br#"
     {
         "data": [
           {
@@ -241,8 +260,15 @@ fn test_request_empty() {
           }
         ]
       }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request_empty() {
+    use helix::*;
+    let req = GetShieldModeStatusRequest::new("12345", "98765");
+
+    let data = FIXTURE_EMPTY.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -256,3 +282,10 @@ fn test_request_empty() {
     dbg!(&status);
     assert!(status.data.last_shield_mode.is_none());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetShieldModeStatusRequest,
+    200 => FIXTURE,
+    200 => FIXTURE_EMPTY,
+}
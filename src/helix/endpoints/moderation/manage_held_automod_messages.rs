@@ -72,6 +72,11 @@ pub struct ManageHeldAutoModMessagesRequest<'a> {
 
 impl ManageHeldAutoModMessagesRequest<'_> {
     /// Create a new [`ManageHeldAutoModMessagesRequest`]
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::ManageHeldAutoModMessagesRequest;
+    /// let request = ManageHeldAutoModMessagesRequest::new();
+    /// ```
     pub fn new() -> Self { Self::default() }
 }
 
@@ -160,6 +165,8 @@ impl Request for ManageHeldAutoModMessagesRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ModeratorManageAutoMod];
 }
 
+endpoint_meta!(POST ManageHeldAutoModMessagesRequest<'static>);
+
 impl<'a> RequestPost for ManageHeldAutoModMessagesRequest<'a> {
     type Body = ManageHeldAutoModMessagesBody<'a>;
 
@@ -182,6 +189,7 @@ impl<'a> RequestPost for ManageHeldAutoModMessagesRequest<'a> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
@@ -196,7 +204,7 @@ fn test_request() {
     let body = ManageHeldAutoModMessagesBody::new("9327994", "836013710", true);
 
     assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        body.body_json().unwrap(),
         r#"{"user_id":"9327994","msg_id":"836013710","action":"ALLOW"}"#
     );
 
@@ -215,3 +223,9 @@ fn test_request() {
 
     dbg!(ManageHeldAutoModMessagesRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: ManageHeldAutoModMessagesRequest,
+    204 => b"",
+}
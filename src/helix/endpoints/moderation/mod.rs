@@ -10,6 +10,8 @@ use std::borrow::Cow;
 
 pub mod add_blocked_term;
 pub mod add_channel_moderator;
+#[cfg(feature = "client")]
+pub mod audit_stream;
 pub mod ban_user;
 pub mod check_automod_status;
 pub mod delete_chat_messages;
@@ -31,8 +33,14 @@ pub mod warn_chat_user;
 pub use add_blocked_term::{AddBlockedTermBody, AddBlockedTermRequest};
 #[doc(inline)]
 pub use add_channel_moderator::{AddChannelModeratorRequest, AddChannelModeratorResponse};
+#[cfg(feature = "client")]
 #[doc(inline)]
-pub use ban_user::{BanUser, BanUserBody, BanUserRequest};
+pub use audit_stream::{ModerationAuditStream, ModerationChange, ModerationSnapshot};
+#[doc(inline)]
+pub use ban_user::{BanUser, BanUserBody, BanUserBodyError, BanUserRequest};
+#[cfg(feature = "client")]
+#[doc(inline)]
+pub use check_automod_status::{AutoModEnforcement, EnforcementAction};
 #[doc(inline)]
 pub use check_automod_status::{
     CheckAutoModStatus, CheckAutoModStatusBody, CheckAutoModStatusRequest,
@@ -42,7 +50,7 @@ pub use delete_chat_messages::{DeleteChatMessagesRequest, DeleteChatMessagesResp
 #[doc(inline)]
 pub use get_automod_settings::{AutoModSettings, GetAutoModSettingsRequest};
 #[doc(inline)]
-pub use get_banned_users::{BannedUser, GetBannedUsersRequest};
+pub use get_banned_users::{BanKind, BannedUser, BannedUserRef, GetBannedUsersRequest};
 #[doc(inline)]
 pub use get_moderators::{GetModeratorsRequest, Moderator};
 #[doc(inline)]
@@ -78,6 +86,10 @@ pub struct BlockedTerm {
     /// The UTC date and time (in RFC3339 format) of when the blocked term is set to expire. After the block expires, user’s will be able to use the term in the broadcaster’s chat room.
     ///
     /// This field is null if the term was added manually or was permanently blocked by AutoMod.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
     pub expires_at: Option<types::Timestamp>,
     /// An ID that uniquely identifies this blocked term.
     pub id: types::BlockedTermId,
@@ -90,3 +102,320 @@ pub struct BlockedTerm {
     /// When the term is added, this timestamp is the same as created_at. The timestamp changes as AutoMod continues to deny the term.
     pub updated_at: types::Timestamp,
 }
+
+/// The broadcaster whose chat is moderated and the moderator doing it
+///
+/// Most moderation requests take both ids, which are easy to pass in the wrong order as they're both [`UserId`](types::UserId)s.
+/// Helpers ending in `_ctx`, like [`ban_user_ctx`](helix::HelixClient::ban_user_ctx), take this instead.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ModerationContext<'a> {
+    /// The broadcaster whose chat is moderated
+    pub broadcaster_id: Cow<'a, types::UserIdRef>,
+    /// The moderator doing it, this must be the user of the token
+    pub moderator_id: Cow<'a, types::UserIdRef>,
+}
+
+impl<'a> ModerationContext<'a> {
+    /// Moderate the chat of `broadcaster_id` as `moderator_id`
+    pub fn new(
+        broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+    ) -> Self {
+        Self {
+            broadcaster_id: broadcaster_id.into_cow(),
+            moderator_id: moderator_id.into_cow(),
+        }
+    }
+
+    /// Moderate the chat of `broadcaster_id` as the user of `token`
+    ///
+    /// Fails if the token has no user id, like an [app access token](twitch_oauth2::AppAccessToken).
+    #[cfg(feature = "twitch_oauth2")]
+    pub fn as_self(
+        broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        token: &'a (impl twitch_oauth2::TwitchToken + ?Sized),
+    ) -> Result<Self, NoUserIdOnToken> {
+        let moderator_id = token.user_id().ok_or(NoUserIdOnToken)?;
+        Ok(Self::new(broadcaster_id, moderator_id))
+    }
+}
+
+/// The token has no user id to moderate as, see [`ModerationContext::as_self`]
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("no user id found on token")]
+pub struct NoUserIdOnToken;
+
+/// Known errors of moderation actions like [banning](ban_user) a user or [adding a moderator](add_channel_moderator)
+///
+/// Errors returned by Twitch are matched on their status and message, the [raw error](helix::HelixApiError) is kept in every variant, see [`ModerationActionError::api_error`].
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[non_exhaustive]
+pub enum ModerationActionError {
+    /// user is already banned: {0}
+    AlreadyBanned(helix::HelixApiError),
+    /// user is not banned: {0}
+    NotBanned(helix::HelixApiError),
+    /// user is a moderator: {0}
+    TargetIsModerator(helix::HelixApiError),
+    /// moderator id does not match the user id of the token: {0}
+    ModeratorIdMismatch(helix::HelixApiError),
+    /// {0}
+    Other(helix::HelixApiError),
+    /// invalid ban
+    InvalidBan(#[from] BanUserBodyError),
+}
+
+impl ModerationActionError {
+    /// The error returned by Twitch, [`None`] if the request was never sent
+    pub fn api_error(&self) -> Option<&helix::HelixApiError> {
+        match self {
+            Self::AlreadyBanned(e)
+            | Self::NotBanned(e)
+            | Self::TargetIsModerator(e)
+            | Self::ModeratorIdMismatch(e)
+            | Self::Other(e) => Some(e),
+            Self::InvalidBan(_) => None,
+        }
+    }
+
+    /// Map an error returned by Twitch into a [`ModerationActionError`], other errors are kept as is
+    #[cfg(feature = "client")]
+    pub(crate) fn from_client_error<RE: std::error::Error + Send + Sync + 'static>(
+        error: helix::ClientRequestError<RE>,
+    ) -> Result<Self, helix::ClientRequestError<RE>> {
+        error.into_api_error().map(Self::from)
+    }
+}
+
+impl From<helix::HelixApiError> for ModerationActionError {
+    fn from(error: helix::HelixApiError) -> Self {
+        if !error.status.is_client_error() {
+            return Self::Other(error);
+        }
+        let message = error.message.to_lowercase();
+        if message.contains("already banned") {
+            Self::AlreadyBanned(error)
+        } else if message.contains("is not banned") {
+            Self::NotBanned(error)
+        } else if message.contains("is a moderator") {
+            Self::TargetIsModerator(error)
+        } else if message.contains("moderator_id must match") {
+            Self::ModeratorIdMismatch(error)
+        } else {
+            Self::Other(error)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "client"))]
+mod moderation_action_error_tests {
+    use super::*;
+    use crate::helix::ClientExtError;
+
+    fn error(status: u16, error: &str, message: &str) -> String {
+        format!(r#"{{"error":"{error}","status":{status},"message":"{message}"}}"#)
+    }
+
+    #[tokio::test]
+    async fn already_banned() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "moderation/bans",
+            400,
+            &error(
+                400,
+                "Bad Request",
+                "The user specified in the user_id field is already banned.",
+            ),
+        );
+        let token = crate::tests::user_token("twitchdev", "4321");
+
+        let err = mock
+            .helix()
+            .ban_user("1", "reason", None, "1234", "4321", &token)
+            .await
+            .unwrap_err();
+        let ClientExtError::Other(ModerationActionError::AlreadyBanned(e)) = err else {
+            panic!("expected AlreadyBanned, got {err:?}")
+        };
+        assert_eq!(e.status, http::StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn not_banned() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "moderation/bans",
+            400,
+            &error(
+                400,
+                "Bad Request",
+                "The user specified in the user_id field is not banned.",
+            ),
+        );
+        let token = crate::tests::user_token("twitchdev", "4321");
+
+        let err = mock
+            .helix()
+            .unban_user("1", "1234", "4321", &token)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ClientExtError::Other(ModerationActionError::NotBanned(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn target_is_moderator() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "channels/vips",
+            422,
+            &error(
+                422,
+                "Unprocessable Entity",
+                "The user in the user_id query parameter is a moderator. To make them a VIP, you must first remove them as a moderator.",
+            ),
+        );
+        let token = crate::tests::user_token("twitchdev", "1234");
+
+        let err = mock
+            .helix()
+            .add_channel_vip("1234", "1", &token)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ClientExtError::Other(ModerationActionError::TargetIsModerator(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn moderator_id_mismatch() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "moderation/bans",
+            401,
+            &error(
+                401,
+                "Unauthorized",
+                "The ID in moderator_id must match the user ID in the access token.",
+            ),
+        );
+        let token = crate::tests::user_token("twitchdev", "4321");
+
+        let err = mock
+            .helix()
+            .ban_user("1", "reason", None, "1234", "1337", &token)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ClientExtError::Other(ModerationActionError::ModeratorIdMismatch(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn unknown_message() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "moderation/moderators",
+            400,
+            &error(
+                400,
+                "Bad Request",
+                "The user in the user_id query parameter is not a moderator.",
+            ),
+        );
+        let token = crate::tests::user_token("twitchdev", "1234");
+
+        let err = mock
+            .helix()
+            .remove_channel_moderator("1234", "1", &token)
+            .await
+            .unwrap_err();
+        let ClientExtError::Other(err) = err else {
+            panic!("expected a helix error, got {err:?}")
+        };
+        assert!(matches!(err, ModerationActionError::Other(_)));
+        assert_eq!(
+            err.api_error().map(|e| e.message.as_str()),
+            Some("The user in the user_id query parameter is not a moderator.")
+        );
+    }
+}
+
+#[cfg(all(test, feature = "client"))]
+mod moderation_context_tests {
+    use super::*;
+    use futures::TryStreamExt;
+
+    #[test]
+    fn as_self() {
+        let token = crate::tests::user_token("twitchdev", "1337");
+        let ctx = ModerationContext::as_self("1234", &token).unwrap();
+        assert_eq!(ctx, ModerationContext::new("1234", "1337"));
+
+        let token = crate::tests::app_token();
+        assert_eq!(
+            ModerationContext::as_self("1234", &token),
+            Err(NoUserIdOnToken)
+        );
+    }
+
+    #[tokio::test]
+    async fn same_requests() {
+        let ban = r#"{"data":[{"broadcaster_id":"1234","moderator_id":"1337","user_id":"9876","created_at":"2021-09-28T19:27:31Z","end_time":null}]}"#;
+        let chatters = r#"{"data":[{"user_id":"128393656","user_login":"smittysmithers","user_name":"smittysmithers"}],"pagination":{},"total":1}"#;
+        let mock = crate::tests::MockClient::new();
+        mock.respond("moderation/bans", 200, ban)
+            .respond("moderation/bans", 200, ban)
+            .respond("moderation/chat", 204, "")
+            .respond("moderation/chat", 204, "")
+            .respond("chat/chatters", 200, chatters)
+            .respond("chat/chatters", 200, chatters);
+        let token = crate::tests::user_token("twitchdev", "1337");
+        let client = mock.helix();
+        let ctx = ModerationContext::as_self("1234", &token).unwrap();
+
+        client
+            .ban_user("9876", "spam", 300, "1234", "1337", &token)
+            .await
+            .unwrap();
+        client
+            .ban_user_ctx(&ctx, "9876", "spam", 300, &token)
+            .await
+            .unwrap();
+        client
+            .delete_chat_message("1234", "1337", "abc-123", &token)
+            .await
+            .unwrap();
+        client
+            .delete_chat_message_ctx(&ctx, "abc-123", &token)
+            .await
+            .unwrap();
+        let positional: Vec<_> = client
+            .get_chatters("1234", "1337", 1000, &token)
+            .try_collect()
+            .await
+            .unwrap();
+        let with_ctx: Vec<_> = client
+            .get_chatters_ctx(&ctx, 1000, &token)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(positional, with_ctx);
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 6);
+        for pair in requests.chunks(2) {
+            assert_eq!(pair[0], pair[1]);
+        }
+        assert_eq!(
+            requests[0].1.query(),
+            Some("broadcaster_id=1234&moderator_id=1337")
+        );
+        assert_eq!(mock.pending(), 0);
+    }
+}
@@ -62,6 +62,11 @@ pub struct RemoveBlockedTermRequest<'a> {
 
 impl<'a> RemoveBlockedTermRequest<'a> {
     /// Remove blocked term
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::RemoveBlockedTermRequest;
+    /// let request = RemoveBlockedTermRequest::new("1234", "5678", "DEADBEEF");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -96,6 +101,8 @@ impl Request for RemoveBlockedTermRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ModeratorManageBlockedTerms];
 }
 
+endpoint_meta!(DELETE RemoveBlockedTermRequest<'static>);
+
 impl RequestDelete for RemoveBlockedTermRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -116,6 +123,7 @@ impl RequestDelete for RemoveBlockedTermRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
@@ -140,3 +148,9 @@ fn test_request() {
 
     dbg!(RemoveBlockedTermRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: RemoveBlockedTermRequest,
+    204 => b"",
+}
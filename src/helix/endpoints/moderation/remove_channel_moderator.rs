@@ -59,6 +59,11 @@ pub struct RemoveChannelModeratorRequest<'a> {
 
 impl<'a> RemoveChannelModeratorRequest<'a> {
     /// Remove moderator
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::RemoveChannelModeratorRequest;
+    /// let request = RemoveChannelModeratorRequest::new("1234", "5678");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -90,6 +95,8 @@ impl Request for RemoveChannelModeratorRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageModerators];
 }
 
+endpoint_meta!(DELETE RemoveChannelModeratorRequest<'static>);
+
 impl RequestDelete for RemoveChannelModeratorRequest<'_> {
     fn parse_inner_response<'d>(
         request: Option<Self>,
@@ -110,11 +117,27 @@ impl RequestDelete for RemoveChannelModeratorRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
 }
 
+helix_helper! {
+    /// Remove a channel moderator
+    ///
+    /// Known errors returned by Twitch are turned into a [`ModerationActionError`].
+    pub async fn remove_channel_moderator<'b, T>(
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+    ) -> helix::moderation::RemoveChannelModeratorResponse, ModerationActionError = req_delete(ModerationActionError::from_client_error) {
+        RemoveChannelModeratorRequest {
+            broadcaster_id: broadcaster_id.into_cow(),
+            moderator_id: moderator_id.into_cow(),
+        }
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -136,3 +159,9 @@ fn test_request() {
 
     dbg!(RemoveChannelModeratorRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: RemoveChannelModeratorRequest,
+    204 => b"",
+}
@@ -61,6 +61,11 @@ pub struct UnbanUserRequest<'a> {
 
 impl<'a> UnbanUserRequest<'a> {
     /// Remove the ban or timeout that was placed on the specified user.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::UnbanUserRequest;
+    /// let request = UnbanUserRequest::new("1234", "5678", "1337");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -94,6 +99,8 @@ impl Request for UnbanUserRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ModeratorManageBannedUsers];
 }
 
+endpoint_meta!(DELETE UnbanUserRequest<'static>);
+
 impl RequestDelete for UnbanUserRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -114,11 +121,25 @@ impl RequestDelete for UnbanUserRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
 }
 
+helix_helper! {
+    /// Unban a user
+    ///
+    /// Unbanning a user that is not banned fails with [`ModerationActionError::NotBanned`].
+    pub async fn unban_user<'b, T>(
+        target_user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+    ) -> helix::moderation::UnbanUserResponse, ModerationActionError = req_delete(ModerationActionError::from_client_error) {
+        UnbanUserRequest::new(broadcaster_id, moderator_id, target_user_id)
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -142,3 +163,9 @@ fn test_request() {
 
     dbg!(UnbanUserRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: UnbanUserRequest,
+    204 => b"",
+}
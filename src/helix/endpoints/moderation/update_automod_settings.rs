@@ -79,6 +79,11 @@ pub struct UpdateAutoModSettingsRequest<'a> {
 
 impl<'a> UpdateAutoModSettingsRequest<'a> {
     /// Update the AutoMod settings on the specified channel as the specified moderator
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::UpdateAutoModSettingsRequest;
+    /// let request = UpdateAutoModSettingsRequest::new("123", "456");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -195,6 +200,8 @@ impl Request for UpdateAutoModSettingsRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ModeratorManageAutomodSettings];
 }
 
+endpoint_meta!(PUT UpdateAutoModSettingsRequest<'static>);
+
 impl RequestPut for UpdateAutoModSettingsRequest<'_> {
     type Body = UpdateAutoModSettingsBody;
 
@@ -211,22 +218,9 @@ impl RequestPut for UpdateAutoModSettingsRequest<'_> {
     }
 }
 
+// From twitch docs
 #[cfg(test)]
-#[test]
-fn test_request_overall() {
-    use helix::*;
-    let req = UpdateAutoModSettingsRequest::new("1234", "5678");
-    let body = UpdateAutoModSettingsBody::overall(3);
-
-    assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
-        r#"{"overall_level":3}"#
-    );
-
-    req.create_request(body, "token", "clientid").unwrap();
-
-    // From twitch docs
-    let data = br#"
+const FIXTURE_OVERALL: &[u8] = br#"
     {
         "data": [
             {
@@ -244,8 +238,20 @@ fn test_request_overall() {
             }
         ]
     }
-    "#
-    .to_vec();
+    "#;
+
+#[cfg(test)]
+#[test]
+fn test_request_overall() {
+    use helix::*;
+    let req = UpdateAutoModSettingsRequest::new("1234", "5678");
+    let body = UpdateAutoModSettingsBody::overall(3);
+
+    assert_eq!(body.body_json().unwrap(), r#"{"overall_level":3}"#);
+
+    req.create_request(body, "token", "clientid").unwrap();
+
+    let data = FIXTURE_OVERALL.to_vec();
 
     let http_response = http::Response::builder().status(200).body(data).unwrap();
 
@@ -262,6 +268,27 @@ fn test_request_overall() {
     assert_eq!(res.disability, 3);
 }
 
+#[cfg(test)]
+const FIXTURE_INDIVIDUAL: &[u8] = br#"
+    {
+        "data": [
+            {
+                "aggression": 0,
+                "broadcaster_id": "1234",
+                "bullying": 1,
+                "disability": 0,
+                "misogyny": 0,
+                "moderator_id": "5678",
+                "overall_level": null,
+                "race_ethnicity_or_religion": 0,
+                "sex_based_terms": 0,
+                "sexuality_sex_or_gender": 0,
+                "swearing": 2
+            }
+        ]
+    }
+    "#;
+
 #[cfg(test)]
 #[test]
 fn test_request_individual() {
@@ -279,32 +306,13 @@ fn test_request_individual() {
     });
 
     assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        body.body_json().unwrap(),
         r#"{"aggression":0,"bullying":1,"swearing":2}"#
     );
 
     req.create_request(body, "token", "clientid").unwrap();
 
-    let data = br#"
-    {
-        "data": [
-            {
-                "aggression": 0,
-                "broadcaster_id": "1234",
-                "bullying": 1,
-                "disability": 0,
-                "misogyny": 0,
-                "moderator_id": "5678",
-                "overall_level": null,
-                "race_ethnicity_or_religion": 0,
-                "sex_based_terms": 0,
-                "sexuality_sex_or_gender": 0,
-                "swearing": 2
-            }
-        ]
-    }
-    "#
-    .to_vec();
+    let data = FIXTURE_INDIVIDUAL.to_vec();
 
     let http_response = http::Response::builder().status(200).body(data).unwrap();
 
@@ -320,3 +328,10 @@ fn test_request_individual() {
     assert_eq!(res.overall_level, None);
     assert_eq!(res.swearing, 2);
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: UpdateAutoModSettingsRequest,
+    200 => FIXTURE_OVERALL,
+    200 => FIXTURE_INDIVIDUAL,
+}
@@ -78,6 +78,11 @@ pub struct UpdateShieldModeStatusRequest<'a> {
 
 impl<'a> UpdateShieldModeStatusRequest<'a> {
     /// Set the shield mode status on specified channel as the specified moderator
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::update_shield_mode_status::UpdateShieldModeStatusRequest;
+    /// let request = UpdateShieldModeStatusRequest::new("123", "456");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -123,6 +128,8 @@ impl Request for UpdateShieldModeStatusRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ModeratorManageShieldMode];
 }
 
+endpoint_meta!(PUT UpdateShieldModeStatusRequest<'static>);
+
 impl<'a> RequestPut for UpdateShieldModeStatusRequest<'a> {
     type Body = UpdateShieldModeStatusBody<'a>;
 
@@ -142,6 +149,7 @@ impl<'a> RequestPut for UpdateShieldModeStatusRequest<'a> {
                     e,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         Ok(helix::Response::new(
@@ -151,6 +159,7 @@ impl<'a> RequestPut for UpdateShieldModeStatusRequest<'a> {
                     response: response.to_string(),
                     status,
                     uri: uri.clone(),
+                    request_id: None,
                 },
             )?,
             inner_response.pagination.cursor,
@@ -161,22 +170,9 @@ impl<'a> RequestPut for UpdateShieldModeStatusRequest<'a> {
     }
 }
 
+// From twitch docs
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = UpdateShieldModeStatusRequest::new("12345", "98765");
-    let body = UpdateShieldModeStatusBody::is_active(false);
-
-    assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
-        r#"{"is_active":false}"#
-    );
-
-    dbg!(req.create_request(body, "token", "clientid").unwrap());
-
-    // From twitch docs
-    let data = br#"
+const FIXTURE: &[u8] = br#"
     {
         "data": [
           {
@@ -188,8 +184,20 @@ fn test_request() {
           }
         ]
     }
-    "#
-    .to_vec();
+    "#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateShieldModeStatusRequest::new("12345", "98765");
+    let body = UpdateShieldModeStatusBody::is_active(false);
+
+    assert_eq!(body.body_json().unwrap(), r#"{"is_active":false}"#);
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().status(200).body(data).unwrap();
 
@@ -201,3 +209,9 @@ fn test_request() {
 
     dbg!(UpdateShieldModeStatusRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: UpdateShieldModeStatusRequest,
+    200 => FIXTURE,
+}
@@ -74,6 +74,11 @@ pub struct WarnChatUserRequest<'a> {
 
 impl<'a> WarnChatUserRequest<'a> {
     /// Warn a user on this channel
+    ///
+    /// ```rust
+    /// use twitch_api::helix::moderation::WarnChatUserRequest;
+    /// let request = WarnChatUserRequest::new("404040", "404041");
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
@@ -155,6 +160,8 @@ impl Request for WarnChatUserRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ModeratorManageWarnings];
 }
 
+endpoint_meta!(POST WarnChatUserRequest<'static>);
+
 impl<'a> RequestPost for WarnChatUserRequest<'a> {
     type Body = WarnChatUserBody<'a>;
 
@@ -177,6 +184,7 @@ impl<'a> RequestPost for WarnChatUserRequest<'a> {
                 e,
                 uri.clone(),
                 status,
+                None,
             )
         })?;
         Ok(helix::Response::with_data(
@@ -186,6 +194,7 @@ impl<'a> RequestPost for WarnChatUserRequest<'a> {
                     response: response.to_string(),
                     status,
                     uri: uri.clone(),
+                    request_id: None,
                 }
             })?,
             request,
@@ -193,6 +202,35 @@ impl<'a> RequestPost for WarnChatUserRequest<'a> {
     }
 }
 
+helix_helper! {
+    /// Warn a user
+    pub async fn warn_chat_user<'b, T>(
+        target_user_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        reason: impl Into<&'b str> + Send,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+    ) -> helix::moderation::WarnChatUser = req_post {
+        WarnChatUserRequest::new(broadcaster_id, moderator_id)
+    } {
+        WarnChatUserBody::new(target_user_id, reason.into())
+    }
+}
+
+// From twitch docs
+#[cfg(test)]
+const FIXTURE: &[u8] = br#"
+    {
+      "data": [
+        {
+          "broadcaster_id": "404040",
+          "user_id": "9876",
+          "moderator_id": "404041",
+          "reason": "stop doing that!"
+        }
+      ]
+    }
+"#;
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -202,26 +240,13 @@ fn test_request() {
     let body = WarnChatUserBody::new("9876", "stop doing that!");
 
     assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        body.body_json().unwrap(),
         r#"{"data":{"user_id":"9876","reason":"stop doing that!"}}"#
     );
 
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
-    // From twitch docs
-    let data = br#"
-    {
-      "data": [
-        {
-          "broadcaster_id": "404040",
-          "user_id": "9876",
-          "moderator_id": "404041",
-          "reason": "stop doing that!"
-        }
-      ]
-    }
-"#
-    .to_vec();
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -263,3 +288,9 @@ fn test_request_error() {
 
     dbg!(WarnChatUserRequest::parse_response(Some(req), &uri, http_response).unwrap_err());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: WarnChatUserRequest,
+    200 => FIXTURE,
+}
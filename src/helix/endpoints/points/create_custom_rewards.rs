@@ -173,6 +173,8 @@ impl Request for CreateCustomRewardRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageRedemptions];
 }
 
+endpoint_meta!(POST CreateCustomRewardRequest<'static>);
+
 impl<'a> RequestPost for CreateCustomRewardRequest<'a> {
     type Body = CreateCustomRewardBody<'a>;
 
@@ -192,6 +194,7 @@ impl<'a> RequestPost for CreateCustomRewardRequest<'a> {
                     e,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         let data = response.data.into_iter().next().ok_or_else(|| {
@@ -200,6 +203,7 @@ impl<'a> RequestPost for CreateCustomRewardRequest<'a> {
                 response: response_str.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }
         })?;
         Ok(helix::Response {
@@ -208,6 +212,7 @@ impl<'a> RequestPost for CreateCustomRewardRequest<'a> {
             request,
             total: response.total,
             other: None,
+            request_id: None,
         })
     }
 }
@@ -221,7 +226,7 @@ fn test_request() {
     let body = CreateCustomRewardBody::new("game analysis 1v1", 50000);
 
     assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        body.body_json().unwrap(),
         r#"{"title":"game analysis 1v1","cost":50000}"#
     );
 
@@ -91,6 +91,8 @@ impl Request for DeleteCustomRewardRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageRedemptions];
 }
 
+endpoint_meta!(DELETE DeleteCustomRewardRequest<'static>);
+
 impl RequestDelete for DeleteCustomRewardRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -111,6 +113,7 @@ impl RequestDelete for DeleteCustomRewardRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
@@ -131,6 +131,10 @@ pub struct CustomReward {
     /// The number of redemptions redeemed during the current live stream. Counts against the max_per_stream_setting limit. Null if the broadcasters stream isn’t live or max_per_stream_setting isn’t enabled.
     pub redemptions_redeemed_current_stream: Option<usize>,
     /// Timestamp of the cooldown expiration. Null if the reward isn’t on cooldown.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
     pub cooldown_expires_at: Option<types::Timestamp>,
 }
 
@@ -145,6 +149,8 @@ impl Request for GetCustomRewardRequest<'_> {
     )];
 }
 
+endpoint_meta!(GET GetCustomRewardRequest<'static>);
+
 impl RequestGet for GetCustomRewardRequest<'_> {}
 
 #[cfg(test)]
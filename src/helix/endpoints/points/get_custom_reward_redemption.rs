@@ -211,6 +211,8 @@ impl Request for GetCustomRewardRedemptionRequest<'_> {
     )];
 }
 
+endpoint_meta!(GET GetCustomRewardRedemptionRequest<'static>);
+
 impl RequestGet for GetCustomRewardRedemptionRequest<'_> {}
 
 impl helix::Paginated for GetCustomRewardRedemptionRequest<'_> {
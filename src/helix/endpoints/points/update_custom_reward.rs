@@ -179,6 +179,8 @@ impl Request for UpdateCustomRewardRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageRedemptions];
 }
 
+endpoint_meta!(PATCH UpdateCustomRewardRequest<'static>);
+
 impl<'a> RequestPatch for UpdateCustomRewardRequest<'a> {
     type Body = UpdateCustomRewardBody<'a>;
 
@@ -200,6 +202,7 @@ impl<'a> RequestPatch for UpdateCustomRewardRequest<'a> {
                             e,
                             uri.clone(),
                             status,
+                            None,
                         )
                     })?;
                 UpdateCustomReward::Success(resp.data.into_iter().next().ok_or(
@@ -208,6 +211,7 @@ impl<'a> RequestPatch for UpdateCustomRewardRequest<'a> {
                         response: response.to_string(),
                         status,
                         uri: uri.clone(),
+                        request_id: None,
                     },
                 )?)
             }
@@ -217,6 +221,7 @@ impl<'a> RequestPatch for UpdateCustomRewardRequest<'a> {
                     response: response.to_string(),
                     status,
                     uri: uri.clone(),
+                    request_id: None,
                 })
             }
         };
@@ -235,10 +240,7 @@ fn test_request() {
         ..Default::default()
     };
 
-    assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
-        r#"{"is_enabled":false}"#
-    );
+    assert_eq!(body.body_json().unwrap(), r#"{"is_enabled":false}"#);
 
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
@@ -145,6 +145,8 @@ impl Request for UpdateRedemptionStatusRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::scopes::Scope::ChannelManageRedemptions];
 }
 
+endpoint_meta!(PATCH UpdateRedemptionStatusRequest<'static>);
+
 impl RequestPatch for UpdateRedemptionStatusRequest<'_> {
     type Body = UpdateRedemptionStatusBody;
 
@@ -166,6 +168,7 @@ impl RequestPatch for UpdateRedemptionStatusRequest<'_> {
                             e,
                             uri.clone(),
                             status,
+                            None,
                         )
                     })?;
                 UpdateRedemptionStatusInformation::Success(resp.data.into_iter().next().ok_or(
@@ -174,6 +177,7 @@ impl RequestPatch for UpdateRedemptionStatusRequest<'_> {
                         response: response.to_string(),
                         status,
                         uri: uri.clone(),
+                        request_id: None,
                     },
                 )?)
             }
@@ -183,6 +187,7 @@ impl RequestPatch for UpdateRedemptionStatusRequest<'_> {
                     response: response.to_string(),
                     status,
                     uri: uri.clone(),
+                    request_id: None,
                 })
             }
         };
@@ -204,10 +209,7 @@ fn test_request() {
 
     let body = UpdateRedemptionStatusBody::status(CustomRewardRedemptionStatus::Canceled);
 
-    assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
-        r#"{"status":"CANCELED"}"#
-    );
+    assert_eq!(body.body_json().unwrap(), r#"{"status":"CANCELED"}"#);
 
     dbg!(req.create_request(body, "abcd", "client").unwrap());
     // From twitch docs
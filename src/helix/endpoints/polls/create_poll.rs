@@ -182,6 +182,8 @@ impl Request for CreatePollRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManagePolls];
 }
 
+endpoint_meta!(POST CreatePollRequest<'static>);
+
 impl<'a> RequestPost for CreatePollRequest<'a> {
     type Body = CreatePollBody<'a>;
 
@@ -201,6 +203,7 @@ impl<'a> RequestPost for CreatePollRequest<'a> {
                     e,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         let data = response.data.into_iter().next().ok_or_else(|| {
@@ -209,6 +212,7 @@ impl<'a> RequestPost for CreatePollRequest<'a> {
                 response: response_str.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }
         })?;
         Ok(helix::Response {
@@ -217,6 +221,7 @@ impl<'a> RequestPost for CreatePollRequest<'a> {
             request,
             total: None,
             other: None,
+            request_id: None,
         })
     }
 }
@@ -233,7 +238,7 @@ fn test_request() {
         .channel_points_voting_enabled(true);
 
     assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        body.body_json().unwrap(),
         r#"{"broadcaster_id":"141981764","title":"Heads or Tails?","choices":[{"title":"Heads"},{"title":"Tails"}],"channel_points_voting_enabled":true,"channel_points_per_vote":100,"duration":1800}"#
     );
 
@@ -143,6 +143,8 @@ impl Request for EndPollRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManagePolls];
 }
 
+endpoint_meta!(PATCH EndPollRequest<'static>);
+
 impl<'a> RequestPatch for EndPollRequest<'a> {
     type Body = EndPollBody<'a>;
 
@@ -164,6 +166,7 @@ impl<'a> RequestPatch for EndPollRequest<'a> {
                             e,
                             uri.clone(),
                             status,
+                            None,
                         )
                     })?;
                 EndPoll::Success(resp.data.into_iter().next().ok_or(
@@ -172,6 +175,7 @@ impl<'a> RequestPatch for EndPollRequest<'a> {
                         response: response.to_string(),
                         status,
                         uri: uri.clone(),
+                        request_id: None,
                     },
                 )?)
             }
@@ -183,6 +187,7 @@ impl<'a> RequestPatch for EndPollRequest<'a> {
                     response: response.to_string(),
                     status,
                     uri: uri.clone(),
+                    request_id: None,
                 })
             }
         };
@@ -203,7 +208,7 @@ fn test_request() {
     );
 
     assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        body.body_json().unwrap(),
         r#"{"broadcaster_id":"141981764","id":"ed961efd-8a3f-4cf5-a9d0-e616c590cd2a","status":"TERMINATED"}"#
     );
 
@@ -112,6 +112,10 @@ pub struct Poll {
     /// UTC timestamp for the poll’s start time.
     pub started_at: types::Timestamp,
     /// UTC timestamp for the poll’s end time. Set to null if the poll is active.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
     pub ended_at: Option<types::Timestamp>,
 }
 
@@ -126,6 +130,8 @@ impl Request for GetPollsRequest<'_> {
     )];
 }
 
+endpoint_meta!(GET GetPollsRequest<'static>);
+
 impl RequestGet for GetPollsRequest<'_> {}
 
 impl helix::Paginated for GetPollsRequest<'_> {
@@ -162,6 +162,8 @@ impl Request for CreatePredictionRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManagePredictions];
 }
 
+endpoint_meta!(POST CreatePredictionRequest<'static>);
+
 impl<'a> RequestPost for CreatePredictionRequest<'a> {
     type Body = CreatePredictionBody<'a>;
 
@@ -181,6 +183,7 @@ impl<'a> RequestPost for CreatePredictionRequest<'a> {
                     e,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         let data = response.data.into_iter().next().ok_or_else(|| {
@@ -189,6 +192,7 @@ impl<'a> RequestPost for CreatePredictionRequest<'a> {
                 response: response_str.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }
         })?;
         Ok(helix::Response {
@@ -197,6 +201,7 @@ impl<'a> RequestPost for CreatePredictionRequest<'a> {
             request,
             total: None,
             other: None,
+            request_id: None,
         })
     }
 }
@@ -213,7 +218,7 @@ fn test_request() {
     let body = CreatePredictionBody::new("141981764", "Any leeks in the stream?", outcomes, 120);
 
     assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        body.body_json().unwrap(),
         r#"{"broadcaster_id":"141981764","title":"Any leeks in the stream?","outcomes":[{"title":"Yes, give it time."},{"title":"Definitely not."}],"prediction_window":120}"#
     );
 
@@ -158,6 +158,8 @@ impl Request for EndPredictionRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManagePredictions];
 }
 
+endpoint_meta!(PATCH EndPredictionRequest<'static>);
+
 impl<'a> RequestPatch for EndPredictionRequest<'a> {
     type Body = EndPredictionBody<'a>;
 
@@ -179,6 +181,7 @@ impl<'a> RequestPatch for EndPredictionRequest<'a> {
                             e,
                             uri.clone(),
                             status,
+                            None,
                         )
                     })?;
                 EndPrediction::Success(resp.data.into_iter().next().ok_or(
@@ -187,6 +190,7 @@ impl<'a> RequestPatch for EndPredictionRequest<'a> {
                         response: response.to_string(),
                         status,
                         uri: uri.clone(),
+                        request_id: None,
                     },
                 )?)
             }
@@ -198,6 +202,7 @@ impl<'a> RequestPatch for EndPredictionRequest<'a> {
                     response: response.to_string(),
                     status,
                     uri: uri.clone(),
+                    request_id: None,
                 })
             }
         };
@@ -219,7 +224,7 @@ fn test_request() {
     .winning_outcome_id("73085848-a94d-4040-9d21-2cb7a89374b7");
 
     assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        body.body_json().unwrap(),
         r#"{"broadcaster_id":"141981764","id":"bc637af0-7766-4525-9308-4112f4cbf178","status":"RESOLVED","winning_outcome_id":"73085848-a94d-4040-9d21-2cb7a89374b7"}"#
     );
 
@@ -114,8 +114,16 @@ pub struct Prediction {
     /// UTC timestamp for the Prediction’s start time.
     pub created_at: types::Timestamp,
     /// UTC timestamp for when the Prediction ended. If the status is ACTIVE, this is set to null.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
     pub ended_at: Option<types::Timestamp>,
     /// UTC timestamp for when the Prediction was locked. If the status is not LOCKED, this is set to null.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
     pub locked_at: Option<types::Timestamp>,
 }
 
@@ -128,6 +136,8 @@ impl Request for GetPredictionsRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelReadPredictions];
 }
 
+endpoint_meta!(GET GetPredictionsRequest<'static>);
+
 impl RequestGet for GetPredictionsRequest<'_> {}
 
 impl helix::Paginated for GetPredictionsRequest<'_> {
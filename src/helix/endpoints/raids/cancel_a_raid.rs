@@ -79,6 +79,8 @@ impl Request for CancelARaidRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageRaids];
 }
 
+endpoint_meta!(DELETE CancelARaidRequest<'static>);
+
 impl RequestDelete for CancelARaidRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -99,6 +101,7 @@ impl RequestDelete for CancelARaidRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
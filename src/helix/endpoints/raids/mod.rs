@@ -13,4 +13,4 @@ pub mod start_a_raid;
 #[doc(inline)]
 pub use cancel_a_raid::{CancelARaidRequest, CancelARaidResponse};
 #[doc(inline)]
-pub use start_a_raid::{StartARaidRequest, StartARaidResponse};
+pub use start_a_raid::{RaidError, StartARaidRequest, StartARaidResponse};
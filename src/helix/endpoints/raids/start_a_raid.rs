@@ -66,6 +66,32 @@ impl<'a> StartARaidRequest<'a> {
             to_broadcaster_id: to_broadcaster_id.into_cow(),
         }
     }
+
+    /// Create a new [`StartARaidRequest`]
+    ///
+    /// Fails with [`RaidError::SelfRaid`] if the broadcaster tries to raid themselves, which Twitch always rejects.
+    pub fn try_new(
+        from_broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        to_broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+    ) -> Result<Self, RaidError> {
+        let req = Self::new(from_broadcaster_id, to_broadcaster_id);
+        if req.from_broadcaster_id == req.to_broadcaster_id {
+            return Err(RaidError::SelfRaid);
+        }
+        Ok(req)
+    }
+}
+
+/// Error returned by [`StartARaidRequest::try_new`] and [`HelixClient::start_raid_checked`](crate::helix::HelixClient::start_raid_checked)
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum RaidError {
+    /// The broadcaster tried to raid themselves
+    #[error("a broadcaster can not raid themselves")]
+    SelfRaid,
+    /// The channel to raid is not live
+    #[error("the channel to raid is not live")]
+    TargetOffline,
 }
 
 /// Return Values for [Start A Raid](super::start_a_raid)
@@ -89,6 +115,8 @@ impl Request for StartARaidRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageRaids];
 }
 
+endpoint_meta!(POST StartARaidRequest<'static>);
+
 impl RequestPost for StartARaidRequest<'_> {
     type Body = helix::EmptyBody;
 
@@ -108,6 +136,7 @@ impl RequestPost for StartARaidRequest<'_> {
                     e,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         let data = response.data.into_iter().next().ok_or_else(|| {
@@ -116,6 +145,7 @@ impl RequestPost for StartARaidRequest<'_> {
                 response: response_str.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }
         })?;
         Ok(helix::Response {
@@ -124,10 +154,13 @@ impl RequestPost for StartARaidRequest<'_> {
             request,
             total: None,
             other: None,
+            request_id: None,
         })
     }
 }
 
+impl helix::IdempotentPost for StartARaidRequest<'_> {}
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -161,3 +194,84 @@ fn test_request() {
 
     dbg!(StartARaidRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_self_raid() {
+    assert_eq!(
+        StartARaidRequest::try_new("12345678", "12345678"),
+        Err(RaidError::SelfRaid)
+    );
+    assert!(StartARaidRequest::try_new("12345678", "87654321").is_ok());
+}
+
+#[cfg(all(test, feature = "client"))]
+mod client_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn self_raid() {
+        let mock = crate::tests::MockClient::new();
+        let token = crate::tests::user_token("broadcaster", "1234");
+
+        let err = mock
+            .helix()
+            .start_raid_checked("1234", "1234", &token)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            helix::ClientExtError::Other(RaidError::SelfRaid)
+        ));
+        assert!(mock.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn target_offline() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("streams", 200, r#"{"data":[],"pagination":{}}"#);
+        let token = crate::tests::user_token("broadcaster", "1234");
+
+        let err = mock
+            .helix()
+            .start_raid_checked("1234", "5678", &token)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            helix::ClientExtError::Other(RaidError::TargetOffline)
+        ));
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].0, http::Method::GET);
+        assert_eq!(requests[0].1.query(), Some("user_id=5678"));
+    }
+
+    #[tokio::test]
+    async fn target_live() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "streams",
+            200,
+            r#"{"data":[{"id":"40952121085","user_id":"5678","user_login":"target","user_name":"Target","game_id":"509658","game_name":"Just Chatting","type":"live","title":"hello","tags":[],"viewer_count":78365,"started_at":"2021-03-10T15:04:21Z","language":"en","thumbnail_url":"","tag_ids":[],"is_mature":false}],"pagination":{}}"#,
+        );
+        mock.respond(
+            "raids",
+            200,
+            r#"{"data":[{"created_at":"2022-02-18T07:20:50.52Z","is_mature":false}]}"#,
+        );
+        let token = crate::tests::user_token("broadcaster", "1234");
+
+        mock.helix()
+            .start_raid_checked("1234", "5678", &token)
+            .await
+            .unwrap();
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].0, http::Method::POST);
+        assert_eq!(
+            requests[1].1.query(),
+            Some("from_broadcaster_id=1234&to_broadcaster_id=5678")
+        );
+    }
+}
@@ -84,6 +84,11 @@ pub struct CreateChannelStreamScheduleSegmentRequest<'a> {
 
 impl<'a> CreateChannelStreamScheduleSegmentRequest<'a> {
     /// Create a single scheduled broadcast or a recurring scheduled broadcast for a channel’s [stream schedule](https://help.twitch.tv/s/article/channel-page-setup#Schedule).
+    ///
+    /// ```rust
+    /// use twitch_api::helix::schedule::CreateChannelStreamScheduleSegmentRequest;
+    /// let request = CreateChannelStreamScheduleSegmentRequest::broadcaster_id("141981764");
+    /// ```
     pub fn broadcaster_id(broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
         Self {
             broadcaster_id: broadcaster_id.into_cow(),
@@ -158,8 +163,16 @@ impl Request for CreateChannelStreamScheduleSegmentRequest<'_> {
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator =
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageSchedule];
+
+    fn as_token_bound(&self) -> Option<&dyn helix::TokenBoundRequest> { Some(self) }
+}
+
+impl helix::TokenBoundRequest for CreateChannelStreamScheduleSegmentRequest<'_> {
+    fn token_bound_user_id(&self) -> Option<&types::UserIdRef> { Some(&self.broadcaster_id) }
 }
 
+endpoint_meta!(POST CreateChannelStreamScheduleSegmentRequest<'static>);
+
 impl<'a> RequestPost for CreateChannelStreamScheduleSegmentRequest<'a> {
     type Body = CreateChannelStreamScheduleSegmentBody<'a>;
 }
@@ -181,7 +194,7 @@ fn test_request() {
     };
 
     assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        body.body_json().unwrap(),
         r#"{"start_time":"2021-07-01T18:00:00Z","timezone":"America/New_York","is_recurring":false,"duration":"60","category_id":"509670","title":"TwitchDev Monthly Update // July 1, 2021"}"#
     );
 
@@ -221,8 +234,11 @@ fn test_request() {
         "https://api.twitch.tv/helix/schedule/segment?broadcaster_id=141981764"
     );
 
-    dbg!(
+    let schedule: ScheduledBroadcasts =
         CreateChannelStreamScheduleSegmentRequest::parse_response(Some(req), &uri, http_response)
             .unwrap()
-    );
+            .data;
+    assert_eq!(schedule.vacation, None);
+    assert_eq!(schedule.broadcaster_login.as_str(), "twitchdev");
+    dbg!(schedule);
 }
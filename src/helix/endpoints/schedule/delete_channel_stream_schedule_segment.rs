@@ -64,6 +64,14 @@ pub struct DeleteChannelStreamScheduleSegmentRequest<'a> {
 
 impl<'a> DeleteChannelStreamScheduleSegmentRequest<'a> {
     /// Delete a single scheduled broadcast or a recurring scheduled broadcast for a channel’s [stream schedule](https://help.twitch.tv/s/article/channel-page-setup#Schedule).
+    ///
+    /// ```rust
+    /// use twitch_api::helix::schedule::DeleteChannelStreamScheduleSegmentRequest;
+    /// let request = DeleteChannelStreamScheduleSegmentRequest::new(
+    ///     "1234",
+    ///     "eyJzZWdtZW50SUQiOiI4Y2EwN2E2NC0xYTZkLTRjYWItYWE5Ni0xNjIyYzNjYWUzZDkiLCJpc29ZZWFyIjoyMDIxLCJpc29XZWVrIjoyMX0=",
+    /// );
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         id: impl types::IntoCow<'a, types::StreamSegmentIdRef> + 'a,
@@ -94,6 +102,8 @@ impl Request for DeleteChannelStreamScheduleSegmentRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageSchedule];
 }
 
+endpoint_meta!(DELETE DeleteChannelStreamScheduleSegmentRequest<'static>);
+
 impl RequestDelete for DeleteChannelStreamScheduleSegmentRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -114,6 +124,7 @@ impl RequestDelete for DeleteChannelStreamScheduleSegmentRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
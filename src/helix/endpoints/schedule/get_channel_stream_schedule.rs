@@ -74,6 +74,11 @@ pub struct GetChannelStreamScheduleRequest<'a> {
 
 impl<'a> GetChannelStreamScheduleRequest<'a> {
     /// Get a broadcasters schedule
+    ///
+    /// ```rust
+    /// use twitch_api::helix::schedule::GetChannelStreamScheduleRequest;
+    /// let request = GetChannelStreamScheduleRequest::broadcaster_id("1234");
+    /// ```
     pub fn broadcaster_id(broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
         Self {
             broadcaster_id: broadcaster_id.into_cow(),
@@ -126,6 +131,8 @@ impl Request for GetChannelStreamScheduleRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetChannelStreamScheduleRequest<'static>);
+
 impl RequestGet for GetChannelStreamScheduleRequest<'_> {}
 
 impl helix::Paginated for GetChannelStreamScheduleRequest<'_> {
@@ -178,3 +185,304 @@ fn test_request() {
 
     dbg!(GetChannelStreamScheduleRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_request_vacation() {
+    use helix::*;
+    let req = GetChannelStreamScheduleRequest::broadcaster_id("141981764");
+
+    let data = br#"
+    {
+        "data": {
+          "segments": [],
+          "broadcaster_id": "141981764",
+          "broadcaster_name": "TwitchDev",
+          "broadcaster_login": "twitchdev",
+          "vacation": {
+            "start_time": "2021-07-05T00:00:00Z",
+            "end_time": "2021-07-12T00:00:00Z"
+          }
+        },
+        "pagination": {}
+      }
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    let schedule = GetChannelStreamScheduleRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    let vacation = schedule.vacation.clone().unwrap();
+    assert_eq!(vacation.start_time.as_str(), "2021-07-05T00:00:00Z");
+    assert_eq!(vacation.end_time.as_str(), "2021-07-12T00:00:00Z");
+    assert_eq!(schedule.meta().vacation, Some(vacation));
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_no_vacation() {
+    use helix::*;
+    let req = GetChannelStreamScheduleRequest::broadcaster_id("141981764");
+
+    let data = br#"
+    {
+        "data": {
+          "segments": [],
+          "broadcaster_id": "141981764",
+          "broadcaster_name": "TwitchDev",
+          "broadcaster_login": "twitchdev",
+          "vacation": null
+        },
+        "pagination": {}
+      }
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    let schedule = GetChannelStreamScheduleRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(schedule.vacation, None);
+    let meta = ScheduleMeta::from(schedule);
+    assert_eq!(meta.broadcaster_login.as_str(), "twitchdev");
+    assert_eq!(meta.vacation, None);
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_schedule_meta() {
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "schedule",
+        200,
+        r#"{"data":{"segments":[],"broadcaster_id":"141981764","broadcaster_name":"TwitchDev","broadcaster_login":"twitchdev","vacation":{"start_time":"2021-07-05T00:00:00Z","end_time":"2021-07-12T00:00:00Z"}},"pagination":{}}"#,
+    );
+    let token = crate::tests::user_token("twitchdev", "141981764");
+
+    let meta = mock
+        .helix()
+        .get_channel_schedule_meta("141981764", &token)
+        .await
+        .unwrap();
+    assert_eq!(meta.broadcaster_id.as_str(), "141981764");
+    assert_eq!(
+        meta.vacation.as_ref().map(|v| v.end_time.as_str()),
+        Some("2021-07-12T00:00:00Z")
+    );
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].1.to_string(),
+        "https://api.twitch.tv/helix/schedule?broadcaster_id=141981764&first=1"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_canceled() {
+    use helix::*;
+    let req = GetChannelStreamScheduleRequest::broadcaster_id("141981764");
+
+    let data = br#"
+    {
+        "data": {
+          "segments": [
+            {
+              "id": "eyJzZWdtZW50SUQiOiI4Y2EwN2E2NC0xYTZkLTRjYWItYWE5Ni0xNjIyYzNjYWUzZDkiLCJpc29ZZWFyIjoyMDIxLCJpc29XZWVrIjoyMX0=",
+              "start_time": "2021-05-28T17:00:00Z",
+              "end_time": "2021-05-28T18:00:00Z",
+              "title": "Going to the moon",
+              "canceled_until": "2021-06-04T17:00:00Z",
+              "category": null,
+              "is_recurring": true
+            },
+            {
+              "id": "eyJzZWdtZW50SUQiOiI4Y2EwN2E2NC0xYTZkLTRjYWItYWE5Ni0xNjIyYzNjYWUzZDkiLCJpc29ZZWFyIjoyMDIxLCJpc29XZWVrIjoyMn0=",
+              "start_time": "2021-06-04T17:00:00Z",
+              "end_time": "2021-06-04T18:00:00Z",
+              "title": "Going to the moon",
+              "canceled_until": null,
+              "is_recurring": true
+            }
+          ],
+          "broadcaster_id": "141981764",
+          "broadcaster_name": "TwitchDev",
+          "broadcaster_login": "twitchdev",
+          "vacation": null
+        },
+        "pagination": {}
+      }
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    let schedule = GetChannelStreamScheduleRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    let [canceled, next] = &schedule.segments[..] else {
+        panic!("expected two segments")
+    };
+    assert!(canceled.is_canceled());
+    assert!(canceled.is_recurring);
+    assert_eq!(
+        canceled.canceled_until.as_ref().map(|t| t.as_str()),
+        Some("2021-06-04T17:00:00Z")
+    );
+    assert_eq!(canceled.category, None);
+    assert!(!next.is_canceled());
+    assert_eq!(next.category, None);
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_no_schedule() {
+    use helix::*;
+    let req = GetChannelStreamScheduleRequest::broadcaster_id("141981764");
+
+    let data = br#"{"error":"Not Found","status":404,"message":"segments were either not found or are not available yet"}"#
+        .to_vec();
+
+    let http_response = http::Response::builder().status(404).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    let err = GetChannelStreamScheduleRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        HelixRequestGetError::Error {
+            status: http::StatusCode::NOT_FOUND,
+            ..
+        }
+    ));
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_next_scheduled_stream() {
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "schedule",
+        200,
+        r#"{"data":{"segments":[{"id":"a","start_time":"2021-05-28T17:00:00Z","end_time":"2021-05-28T18:00:00Z","title":"Canceled","canceled_until":"2021-06-04T17:00:00Z","category":null,"is_recurring":true},{"id":"b","start_time":"2021-06-04T17:00:00Z","end_time":"2021-06-04T18:00:00Z","title":"Next","canceled_until":null,"category":{"id":"509670","name":"Science & Technology"},"is_recurring":true}],"broadcaster_id":"141981764","broadcaster_name":"TwitchDev","broadcaster_login":"twitchdev","vacation":null},"pagination":{}}"#,
+    )
+    .respond(
+        "schedule",
+        404,
+        r#"{"error":"Not Found","status":404,"message":"segments were either not found or are not available yet"}"#,
+    );
+    let token = crate::tests::user_token("twitchdev", "141981764");
+    let client = mock.helix();
+
+    let next = client
+        .get_next_scheduled_stream("141981764", &token)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(next.id.as_str(), "b");
+    assert_eq!(
+        next.category.map(|c| c.name),
+        Some("Science & Technology".to_owned())
+    );
+
+    assert_eq!(
+        client
+            .get_next_scheduled_stream("141981764", &token)
+            .await
+            .unwrap(),
+        None
+    );
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[0].1.to_string(),
+        "https://api.twitch.tv/helix/schedule?broadcaster_id=141981764&first=10"
+    );
+}
+
+#[cfg(all(test, feature = "client", feature = "time"))]
+#[tokio::test]
+async fn test_schedule_between() {
+    use futures::TryStreamExt;
+    use std::convert::TryFrom;
+
+    let page = |starts: &[&str], cursor: Option<&str>| {
+        let segments: Vec<String> = starts
+            .iter()
+            .map(|start| {
+                format!(
+                    r#"{{"id":"{start}","start_time":"{start}","end_time":"{start}","title":"","canceled_until":null,"category":null,"is_recurring":true}}"#
+                )
+            })
+            .collect();
+        let pagination = cursor.map_or_else(String::new, |c| format!(r#""cursor":"{c}""#));
+        format!(
+            r#"{{"data":{{"segments":[{}],"broadcaster_id":"141981764","broadcaster_name":"TwitchDev","broadcaster_login":"twitchdev","vacation":null}},"pagination":{{{pagination}}}}}"#,
+            segments.join(",")
+        )
+    };
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "schedule",
+        200,
+        // the first segment is in progress at the start
+        &page(
+            &[
+                "2021-07-01T09:00:00Z",
+                "2021-07-01T10:00:00Z",
+                "2021-07-01T11:00:00Z",
+            ],
+            Some("page2"),
+        ),
+    )
+    .respond(
+        "schedule",
+        200,
+        &page(
+            &[
+                "2021-07-01T12:00:00Z",
+                "2021-07-01T13:00:00Z",
+                "2021-07-01T14:00:00Z",
+            ],
+            Some("page3"),
+        ),
+    )
+    .respond("schedule", 200, &page(&["2021-07-01T15:00:00Z"], None));
+    let token = crate::tests::app_token();
+    let client = mock.helix();
+
+    let segments: Vec<Segment> = client
+        .get_channel_schedule_between(
+            "141981764",
+            types::Timestamp::try_from("2021-07-01T10:00:00Z").unwrap(),
+            types::Timestamp::try_from("2021-07-01T13:00:00Z").unwrap(),
+            &token,
+        )
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        segments.iter().map(|s| s.id.as_str()).collect::<Vec<_>>(),
+        [
+            "2021-07-01T10:00:00Z",
+            "2021-07-01T11:00:00Z",
+            "2021-07-01T12:00:00Z"
+        ]
+    );
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[0].1.query(),
+        Some("broadcaster_id=141981764&start_time=2021-07-01T10%3A00%3A00Z")
+    );
+    assert_eq!(mock.pending(), 1);
+}
@@ -41,9 +41,65 @@ pub struct ScheduledBroadcasts {
     /// Login of the broadcaster.
     pub broadcaster_login: types::UserName,
     /// If Vacation Mode is enabled, this includes start and end dates for the vacation. If Vacation Mode is disabled, value is set to null.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
     pub vacation: Option<Vacation>,
 }
 
+impl ScheduledBroadcasts {
+    /// The broadcaster and vacation information of this schedule, without the segments
+    pub fn meta(&self) -> ScheduleMeta {
+        ScheduleMeta {
+            broadcaster_id: self.broadcaster_id.clone(),
+            broadcaster_name: self.broadcaster_name.clone(),
+            broadcaster_login: self.broadcaster_login.clone(),
+            vacation: self.vacation.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl ScheduledBroadcasts {
+    /// Whether the broadcaster is on [vacation](ScheduledBroadcasts::vacation) right now
+    pub fn is_on_vacation_now(&self) -> bool { self.is_on_vacation_at(&types::Timestamp::now()) }
+
+    /// Whether the broadcaster is on [vacation](ScheduledBroadcasts::vacation) at `now`, see [`Vacation::is_active_at`]
+    pub fn is_on_vacation_at(&self, now: &types::TimestampRef) -> bool {
+        self.vacation
+            .as_ref()
+            .map_or(false, |vacation| vacation.is_active_at(now))
+    }
+}
+
+/// Broadcaster and vacation information of a [schedule](ScheduledBroadcasts)
+///
+/// Returned by [`HelixClient::get_channel_schedule_meta`](crate::helix::HelixClient::get_channel_schedule_meta)
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub struct ScheduleMeta {
+    /// User ID of the broadcaster.
+    pub broadcaster_id: types::UserId,
+    /// Display name of the broadcaster.
+    pub broadcaster_name: types::DisplayName,
+    /// Login of the broadcaster.
+    pub broadcaster_login: types::UserName,
+    /// Start and end dates of the vacation if Vacation Mode is enabled, [`None`] otherwise.
+    pub vacation: Option<Vacation>,
+}
+
+impl From<ScheduledBroadcasts> for ScheduleMeta {
+    fn from(schedule: ScheduledBroadcasts) -> Self {
+        Self {
+            broadcaster_id: schedule.broadcaster_id,
+            broadcaster_name: schedule.broadcaster_name,
+            broadcaster_login: schedule.broadcaster_login,
+            vacation: schedule.vacation,
+        }
+    }
+}
+
 /// Scheduled broadcast for a stream schedule.
 #[derive(PartialEq, Eq, Deserialize, Debug, Clone)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
@@ -58,13 +114,76 @@ pub struct Segment {
     /// Title for the scheduled broadcast.
     pub title: String,
     /// Used with recurring scheduled broadcasts. Specifies the date of the next recurring broadcast in RFC3339 format if one or more specific broadcasts have been deleted in the series. Set to null otherwise.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
     pub canceled_until: Option<types::Timestamp>,
     /// The category for the scheduled broadcast. Set to null if no category has been specified.
+    #[serde(default)]
     pub category: Option<Category>,
     /// Indicates if the scheduled broadcast is recurring weekly.
     pub is_recurring: bool,
 }
 
+impl Segment {
+    /// Whether this broadcast has been canceled, see [`canceled_until`](Segment::canceled_until)
+    pub fn is_canceled(&self) -> bool { self.canceled_until.is_some() }
+}
+
+/// Parts of a [`StreamSegmentId`](types::StreamSegmentId), returned by [`StreamSegmentIdExt::decode`]
+#[cfg(feature = "base64")]
+#[derive(PartialEq, Eq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct SegmentIdParts {
+    /// The ID of the segment, shared by all broadcasts of a recurring segment
+    #[serde(rename = "segmentID")]
+    pub segment_id: String,
+    /// The ISO 8601 year of the broadcast
+    #[serde(rename = "isoYear")]
+    pub iso_year: i32,
+    /// The ISO 8601 week of the broadcast
+    #[serde(rename = "isoWeek")]
+    pub iso_week: u8,
+}
+
+/// Decode a [`StreamSegmentId`](types::StreamSegmentId)
+///
+/// Segment ids are base64 encoded JSON with the id of the segment and the ISO year and week of the broadcast.
+/// This format is not documented by Twitch and can change at any time, so only use the parts for display.
+/// Always send the id itself to Twitch, ids that don't decode are still valid.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api::{helix::schedule::StreamSegmentIdExt as _, types};
+///
+/// let id = types::StreamSegmentId::from(
+///     "eyJzZWdtZW50SUQiOiJlNGFjYzcyNC0zNzFmLTQwMmMtODFjYS0yM2FkYTc5NzU5ZDQiLCJpc29ZZWFyIjoyMDIxLCJpc29XZWVrIjoyNn0=",
+/// );
+/// let parts = id.decode().unwrap();
+/// assert_eq!((parts.iso_year, parts.iso_week), (2021, 26));
+/// ```
+#[cfg(feature = "base64")]
+pub trait StreamSegmentIdExt {
+    /// Decode the parts of this id, [`None`](Option::None) if it's not in the expected format
+    fn decode(&self) -> Option<SegmentIdParts>;
+}
+
+#[cfg(feature = "base64")]
+impl StreamSegmentIdExt for types::StreamSegmentIdRef {
+    fn decode(&self) -> Option<SegmentIdParts> {
+        use base64::Engine as _;
+
+        let id = self.as_str().trim_end_matches('=');
+        let json = base64::engine::general_purpose::STANDARD_NO_PAD
+            .decode(id)
+            .or_else(|_| base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(id))
+            .ok()?;
+        serde_json::from_slice(&json).ok()
+    }
+}
+
 // FIXME: Similar to types::TwitchCategory, missing box_art
 /// The category for a scheduled broadcast.
 #[derive(PartialEq, Eq, Deserialize, Debug, Clone)]
@@ -87,3 +206,86 @@ pub struct Vacation {
     /// End time for vacation specified in RFC3339 format.
     pub end_time: types::Timestamp,
 }
+
+#[cfg(feature = "time")]
+impl Vacation {
+    /// Whether the vacation is going on at `now`
+    ///
+    /// The vacation starts at [`start_time`](Vacation::start_time) and is over at [`end_time`](Vacation::end_time).
+    /// The times are compared as instants, so timestamps with different offsets compare correctly.
+    pub fn is_active_at(&self, now: &types::TimestampRef) -> bool {
+        let now = now.to_utc();
+        self.start_time.to_utc() <= now && now < self.end_time.to_utc()
+    }
+}
+
+#[cfg(all(test, feature = "base64"))]
+#[test]
+fn test_decode_segment_id() {
+    use helix::RequestGet as _;
+
+    let id = types::StreamSegmentId::from(
+        "eyJzZWdtZW50SUQiOiJlNGFjYzcyNC0zNzFmLTQwMmMtODFjYS0yM2FkYTc5NzU5ZDQiLCJpc29ZZWFyIjoyMDIxLCJpc29XZWVrIjoyNn0=",
+    );
+    assert_eq!(
+        id.decode(),
+        Some(SegmentIdParts {
+            segment_id: "e4acc724-371f-402c-81ca-23ada79759d4".to_owned(),
+            iso_year: 2021,
+            iso_week: 26,
+        })
+    );
+
+    // ids that don't decode are still usable as ids
+    let opaque = types::StreamSegmentId::from("opaque-segment_id");
+    assert_eq!(opaque.decode(), None);
+    assert_eq!(types::StreamSegmentId::from("YWJj").decode(), None);
+    let json = serde_json::to_string(&opaque).unwrap();
+    assert_eq!(json, r#""opaque-segment_id""#);
+    assert_eq!(
+        serde_json::from_str::<types::StreamSegmentId>(&json).unwrap(),
+        opaque
+    );
+    let req =
+        get_channel_stream_schedule::GetChannelStreamScheduleRequest::broadcaster_id("141981764")
+            .id(&*opaque);
+    assert_eq!(
+        req.get_uri().unwrap().to_string(),
+        "https://api.twitch.tv/helix/schedule?broadcaster_id=141981764&id=opaque-segment_id"
+    );
+}
+
+#[cfg(all(test, feature = "time"))]
+#[test]
+fn test_vacation_is_active_at() {
+    let vacation = Vacation {
+        start_time: types::Timestamp::new("2021-07-05T00:00:00Z").unwrap(),
+        end_time: types::Timestamp::new("2021-07-12T00:00:00Z").unwrap(),
+    };
+    for (now, active) in [
+        ("2021-07-04T23:59:59Z", false),
+        ("2021-07-05T00:00:00Z", true),
+        ("2021-07-05T02:00:00+02:00", true),
+        ("2021-07-08T12:00:00Z", true),
+        ("2021-07-11T23:59:59Z", true),
+        ("2021-07-12T00:00:00Z", false),
+        ("2021-07-11T20:00:00-04:00", false),
+    ] {
+        let now = types::Timestamp::new(now).unwrap();
+        assert_eq!(vacation.is_active_at(&now), active, "{now}");
+    }
+
+    let mut schedule = ScheduledBroadcasts {
+        segments: vec![],
+        broadcaster_id: "141981764".into(),
+        broadcaster_name: "TwitchDev".into(),
+        broadcaster_login: "twitchdev".into(),
+        vacation: None,
+    };
+    let now = types::Timestamp::new("2021-07-08T12:00:00Z").unwrap();
+    assert!(!schedule.is_on_vacation_at(&now));
+    assert!(!schedule.is_on_vacation_now());
+    schedule.vacation = Some(vacation);
+    assert!(schedule.is_on_vacation_at(&now));
+    assert!(!schedule.is_on_vacation_now());
+}
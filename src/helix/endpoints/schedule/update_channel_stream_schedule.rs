@@ -74,6 +74,11 @@ pub struct UpdateChannelStreamScheduleRequest<'a> {
 
 impl<'a> UpdateChannelStreamScheduleRequest<'a> {
     /// Update the settings for a channel’s stream schedule.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::schedule::UpdateChannelStreamScheduleRequest;
+    /// let request = UpdateChannelStreamScheduleRequest::broadcaster_id("274637212");
+    /// ```
     pub fn broadcaster_id(broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
         Self {
             broadcaster_id: broadcaster_id.into_cow(),
@@ -105,6 +110,8 @@ impl Request for UpdateChannelStreamScheduleRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageSchedule];
 }
 
+endpoint_meta!(PATCH UpdateChannelStreamScheduleRequest<'static>);
+
 impl RequestPatch for UpdateChannelStreamScheduleRequest<'_> {
     type Body = helix::EmptyBody;
 
@@ -127,6 +134,7 @@ impl RequestPatch for UpdateChannelStreamScheduleRequest<'_> {
                     response: response.to_string(),
                     status,
                     uri: uri.clone(),
+                    request_id: None,
                 })
             }
         };
@@ -75,6 +75,14 @@ pub struct UpdateChannelStreamScheduleSegmentRequest<'a> {
 
 impl<'a> UpdateChannelStreamScheduleSegmentRequest<'a> {
     /// Update a single scheduled broadcast or a recurring scheduled broadcast for a channel’s [stream schedule](https://help.twitch.tv/s/article/channel-page-setup#Schedule).
+    ///
+    /// ```rust
+    /// use twitch_api::helix::schedule::update_channel_stream_schedule_segment::UpdateChannelStreamScheduleSegmentRequest;
+    /// let request = UpdateChannelStreamScheduleSegmentRequest::new(
+    ///     "141981764",
+    ///     "eyJzZWdtZW50SUQiOiJlNGFjYzcyNC0zNzFmLTQwMmMtODFjYS0yM2FkYTc5NzU5ZDQiLCJpc29ZZWFyIjoyMDIxLCJpc29XZWVrIjoyNn0=",
+    /// );
+    /// ```
     pub fn new(
         broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
         id: impl types::IntoCow<'a, types::StreamSegmentIdRef> + 'a,
@@ -141,6 +149,8 @@ impl Request for UpdateChannelStreamScheduleSegmentRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageSchedule];
 }
 
+endpoint_meta!(PATCH UpdateChannelStreamScheduleSegmentRequest<'static>);
+
 impl<'a> RequestPatch for UpdateChannelStreamScheduleSegmentRequest<'a> {
     type Body = UpdateChannelStreamScheduleSegmentBody<'a>;
 
@@ -160,6 +170,7 @@ impl<'a> RequestPatch for UpdateChannelStreamScheduleSegmentRequest<'a> {
                     e,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         Ok(helix::Response::new(
@@ -185,10 +196,7 @@ fn test_request() {
         ..<_>::default()
     };
 
-    assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
-        r#"{"duration":"120"}"#
-    );
+    assert_eq!(body.body_json().unwrap(), r#"{"duration":"120"}"#);
 
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
@@ -226,8 +234,11 @@ fn test_request() {
         "https://api.twitch.tv/helix/schedule/segment?broadcaster_id=141981764&id=eyJzZWdtZW50SUQiOiJlNGFjYzcyNC0zNzFmLTQwMmMtODFjYS0yM2FkYTc5NzU5ZDQiLCJpc29ZZWFyIjoyMDIxLCJpc29XZWVrIjoyNn0%3D"
     );
 
-    dbg!(
+    let schedule: ScheduledBroadcasts =
         UpdateChannelStreamScheduleSegmentRequest::parse_response(Some(req), &uri, http_response)
             .unwrap()
-    );
+            .data;
+    assert_eq!(schedule.vacation, None);
+    assert_eq!(schedule.broadcaster_login.as_str(), "twitchdev");
+    dbg!(schedule);
 }
@@ -28,7 +28,7 @@ pub mod search_categories;
 pub mod search_channels;
 
 #[doc(inline)]
-pub use search_categories::SearchCategoriesRequest;
+pub use search_categories::{rank_categories, SearchCategoriesRequest};
 #[doc(inline)]
 pub use search_channels::{Channel, SearchChannelsRequest};
 pub use types::TwitchCategory as Category;
@@ -58,11 +58,16 @@ pub struct SearchCategoriesRequest<'a> {
     pub before: Option<Cow<'a, helix::CursorRef>>,
     /// Number of values to be returned per page. Limit: 100. Default: 20.
     #[cfg_attr(feature = "typed-builder", builder(setter(into), default))]
-    pub first: Option<usize>,
+    pub first: Option<helix::PageSize>,
 }
 
 impl<'a> SearchCategoriesRequest<'a> {
     /// Search categories with the following query.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::search::SearchCategoriesRequest;
+    /// let request = SearchCategoriesRequest::query("hello");
+    /// ```
     pub fn query(query: impl Into<Cow<'a, str>>) -> Self {
         Self {
             query: query.into(),
@@ -72,9 +77,9 @@ impl<'a> SearchCategoriesRequest<'a> {
         }
     }
 
-    /// Set amount of results returned per page.
-    pub fn first(mut self, first: usize) -> Self {
-        self.first = Some(first);
+    /// Set amount of results returned per page, values above the limit of 100 are clamped.
+    pub fn first(mut self, first: impl Into<Option<usize>>) -> Self {
+        self.first = first.into().map(helix::PageSize::clamped::<Self>);
         self
     }
 }
@@ -84,14 +89,60 @@ impl<'a> SearchCategoriesRequest<'a> {
 /// [`search-categories`](https://dev.twitch.tv/docs/api/reference#search-categories)
 pub type Category = types::TwitchCategory;
 
+/// Pick the category in `categories` that best matches `query`, returning its index
+///
+/// Categories are ranked by
+///
+/// 1. a name equal to `query`, ignoring case
+/// 2. the longest common prefix of the name and `query`, ignoring case
+/// 3. their position in `categories`, which is the order Twitch returned them in
+///
+/// Returns [`None`] if `categories` is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api::helix::search::{rank_categories, Category};
+///
+/// # let category = |name: &str| -> Category { serde_json::from_value(serde_json::json!({"id": "1", "name": name, "box_art_url": ""})).unwrap() };
+/// let categories = [category("osu!mania"), category("osu!")];
+/// assert_eq!(rank_categories("OSU!", &categories), Some(1));
+/// ```
+pub fn rank_categories(query: &str, categories: &[Category]) -> Option<usize> {
+    let query = query.to_lowercase();
+    let names = || {
+        categories
+            .iter()
+            .map(|category| category.name.to_lowercase())
+            .enumerate()
+    };
+    if let Some((i, _)) = names().find(|(_, name)| *name == query) {
+        return Some(i);
+    }
+    let common_prefix = |name: &str| {
+        name.chars()
+            .zip(query.chars())
+            .take_while(|(a, b)| a == b)
+            .count()
+    };
+    // `max_by_key` returns the last of equal elements, iterating in reverse makes the first category win ties
+    names()
+        .rev()
+        .max_by_key(|(_, name)| common_prefix(name))
+        .map(|(i, _)| i)
+}
+
 impl Request for SearchCategoriesRequest<'_> {
     type Response = Vec<Category>;
 
+    const MAX_PAGE_SIZE: usize = 100;
     const PATH: &'static str = "search/categories";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET SearchCategoriesRequest<'static>);
+
 impl RequestGet for SearchCategoriesRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -109,6 +160,7 @@ impl RequestGet for SearchCategoriesRequest<'_> {
                     e,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         Ok(helix::Response::new(
@@ -125,16 +177,15 @@ impl helix::Paginated for SearchCategoriesRequest<'_> {
     fn set_pagination(&mut self, cursor: Option<helix::Cursor>) {
         self.after = cursor.map(|c| c.into_cow())
     }
+
+    fn set_first(&mut self, first: usize) {
+        self.first = Some(helix::PageSize::clamped::<Self>(first))
+    }
 }
 
+// From twitch docs
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = SearchCategoriesRequest::query("fort");
-
-    // From twitch docs
-    let data = br#"
+const FIXTURE: &[u8] = br#"
 {
     "data": [
         {
@@ -152,8 +203,15 @@ fn test_request() {
         "cursor": "eyJiIjpudWxsLCJhIjp7IkN"
     }
 }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = SearchCategoriesRequest::query("fort");
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -166,20 +224,22 @@ fn test_request() {
     dbg!(SearchCategoriesRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
 
+// From twitch docs
+#[cfg(test)]
+const FIXTURE_NULL: &[u8] = br#"
+{
+    "data": null,
+    "pagination": {}
+}
+"#;
+
 #[cfg(test)]
 #[test]
 fn test_request_null() {
     use helix::*;
     let req = SearchCategoriesRequest::query("aaaaaaaaaaaaaaaaaaaaaaaaaaa");
 
-    // From twitch docs
-    let data = br#"
-{
-    "data": null,
-    "pagination": {}
-}
-"#
-    .to_vec();
+    let data = FIXTURE_NULL.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -191,3 +251,147 @@ fn test_request_null() {
 
     dbg!(SearchCategoriesRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_search_categories_exact() {
+    let mock = crate::tests::MockClient::new();
+    let data = r#"
+{
+    "data": [
+        {
+            "id": "509658",
+            "name": "Just Chatting Extended",
+            "box_art_url": "https://static-cdn.jtvnw.net/ttv-boxart/509658-{width}x{height}.jpg"
+        },
+        {
+            "id": "1",
+            "name": "Just Chat",
+            "box_art_url": "https://static-cdn.jtvnw.net/ttv-boxart/1-{width}x{height}.jpg"
+        },
+        {
+            "id": "509658",
+            "name": "Just Chatting",
+            "box_art_url": "https://static-cdn.jtvnw.net/ttv-boxart/509658-{width}x{height}.jpg"
+        }
+    ],
+    "pagination": {}
+}
+"#;
+    mock.respond("search/categories", 200, data)
+        .respond("search/categories", 200, data);
+    let token = crate::tests::user_token("user", "1234");
+    let client = mock.helix();
+
+    let category = client
+        .search_categories_exact("just chatting", &token)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(category.id.as_str(), "509658");
+    assert_eq!(category.name, "Just Chatting");
+    assert_eq!(
+        mock.requests()[0].1.query(),
+        Some("query=just+chatting&first=100")
+    );
+
+    assert_eq!(
+        client
+            .search_categories_exact("just chatting e", &token)
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_rank_categories() {
+    fn categories(names: &[&str]) -> Vec<Category> {
+        names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                serde_json::from_value(serde_json::json!({
+                    "id": i.to_string(),
+                    "name": name,
+                    "box_art_url": "",
+                }))
+                .unwrap()
+            })
+            .collect()
+    }
+
+    let osu = categories(&["osu!mania", "Osu!", "osu"]);
+    // exact matches win over longer prefixes
+    assert_eq!(rank_categories("osu!", &osu), Some(1));
+    assert_eq!(rank_categories("OSU!", &osu), Some(1));
+    assert_eq!(rank_categories("osu", &osu), Some(2));
+    // longest common prefix, the first of equally long prefixes wins
+    assert_eq!(rank_categories("osu!!", &osu), Some(0));
+    assert_eq!(
+        rank_categories("osu", &categories(&["Music", "osu!", "Osu! Mania"])),
+        Some(1)
+    );
+    assert_eq!(
+        rank_categories("OSU! m", &categories(&["osu!", "Osu! Mania"])),
+        Some(1)
+    );
+    // nothing in common, Twitch knows best
+    assert_eq!(
+        rank_categories("xyz", &categories(&["Music", "osu!"])),
+        Some(0)
+    );
+    assert_eq!(rank_categories("osu", &[]), None);
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_search_category_best_match() {
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "search/categories",
+        200,
+        r#"{"data":[
+            {"id":"1","name":"Music","box_art_url":""},
+            {"id":"21465","name":"osu!","box_art_url":""},
+            {"id":"3","name":"Osu! Mania","box_art_url":""}
+        ],"pagination":{}}"#,
+    )
+    .respond("search/categories", 200, r#"{"data":null,"pagination":{}}"#);
+    let token = crate::tests::user_token("user", "1234");
+    let client = mock.helix();
+
+    let category = client
+        .search_category_best_match("OSU", &token)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(category.id.as_str(), "21465");
+    assert_eq!(mock.requests()[0].1.query(), Some("query=OSU&first=10"));
+    assert_eq!(
+        client
+            .search_category_best_match("nothing", &token)
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_first() {
+    use helix::*;
+    let req = SearchCategoriesRequest::query("fort").first(250);
+    assert_eq!(
+        req.get_uri().unwrap().to_string(),
+        "https://api.twitch.tv/helix/search/categories?query=fort&first=100"
+    );
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: SearchCategoriesRequest,
+    200 => FIXTURE,
+    200 => FIXTURE_NULL,
+}
@@ -55,7 +55,7 @@ pub struct SearchChannelsRequest<'a> {
     /// Maximum number of objects to return. Maximum: 100 Default: 20
     #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
     // FIXME: No setter because int
-    pub first: Option<usize>,
+    pub first: Option<helix::PageSize>,
     /// Filter results for live streams only. Default: false
     #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
     pub live_only: Option<bool>,
@@ -63,6 +63,11 @@ pub struct SearchChannelsRequest<'a> {
 
 impl<'a> SearchChannelsRequest<'a> {
     /// Search channels with the following query.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::search::SearchChannelsRequest;
+    /// let request = SearchChannelsRequest::query("hello");
+    /// ```
     pub fn query(query: impl Into<Cow<'a, str>>) -> Self {
         Self {
             query: query.into(),
@@ -78,9 +83,9 @@ impl<'a> SearchChannelsRequest<'a> {
         self
     }
 
-    /// Set amount of results returned per page.
-    pub fn first(mut self, first: usize) -> Self {
-        self.first = Some(first);
+    /// Set amount of results returned per page, values above the limit of 100 are clamped.
+    pub fn first(mut self, first: impl Into<Option<usize>>) -> Self {
+        self.first = first.into().map(helix::PageSize::clamped::<Self>);
         self
     }
 }
@@ -101,7 +106,7 @@ pub struct Channel {
     /// Display name corresponding to user_id
     pub display_name: types::DisplayName,
     /// Channel language (Broadcaster Language field from the [Channels service][crate::helix::channels])
-    pub broadcaster_language: String,
+    pub broadcaster_language: types::LanguageCode,
     /// Login of the broadcaster.
     pub broadcaster_login: types::UserName,
     /// channel title
@@ -123,30 +128,38 @@ pub struct Channel {
     pub tags: Vec<String>,
 }
 
+impl helix::games::CategoryLike for Channel {
+    fn category_id(&self) -> &types::CategoryIdRef { &self.game_id }
+
+    fn category_name(&self) -> &str { &self.game_name }
+}
+
 impl Request for SearchChannelsRequest<'_> {
     type Response = Vec<Channel>;
 
+    const MAX_PAGE_SIZE: usize = 100;
     const PATH: &'static str = "search/channels";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET SearchChannelsRequest<'static>);
+
 impl RequestGet for SearchChannelsRequest<'_> {}
 
 impl helix::Paginated for SearchChannelsRequest<'_> {
     fn set_pagination(&mut self, cursor: Option<helix::Cursor>) {
         self.after = cursor.map(|c| c.into_cow())
     }
+
+    fn set_first(&mut self, first: usize) {
+        self.first = Some(helix::PageSize::clamped::<Self>(first))
+    }
 }
 
+// From twitch docs
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = SearchChannelsRequest::query("fort");
-
-    // From twitch docs
-    let data = br#"
+const FIXTURE: &[u8] = br#"
     {
         "data": [
             {
@@ -180,8 +193,15 @@ fn test_request() {
         ],
         "pagination": {}
       }
-"#
-        .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = SearchChannelsRequest::query("fort");
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -193,3 +213,44 @@ fn test_request() {
 
     dbg!(SearchChannelsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_uri_roundtrip(
+        query in "(?s).*",
+        after in proptest::option::of("(?s).*"),
+        first in proptest::option::of(proptest::num::usize::ANY),
+        live_only in proptest::option::of(proptest::bool::ANY),
+    ) {
+        use helix::*;
+        let mut req = SearchChannelsRequest::query(query.clone());
+        req.after = after.clone().map(|after| Cursor::from(after).into());
+        req.first = first.map(helix::PageSize::unchecked);
+        req.live_only = live_only;
+
+        let uri = req.get_uri().unwrap();
+        let mut expected = vec![("query".to_owned(), query)];
+        expected.extend(after.map(|after| ("after".to_owned(), after)));
+        expected.extend(first.map(|first| ("first".to_owned(), first.to_string())));
+        expected.extend(live_only.map(|live_only| ("live_only".to_owned(), live_only.to_string())));
+        proptest::prop_assert_eq!(crate::tests::query_pairs(&uri), expected);
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_first() {
+    use helix::*;
+    let req = SearchChannelsRequest::query("fort").first(250);
+    assert_eq!(
+        req.get_uri().unwrap().to_string(),
+        "https://api.twitch.tv/helix/search/channels?query=fort&first=100"
+    );
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: SearchChannelsRequest,
+    200 => FIXTURE,
+}
@@ -0,0 +1,466 @@
+//! Adds a marker to a live stream.
+//! [`create-stream-marker`](https://dev.twitch.tv/docs/api/reference#create-stream-marker)
+//!
+//! A marker is an arbitrary point in a live stream that the broadcaster or editor wants to mark, so they can return to that spot later to create video highlights.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [CreateStreamMarkerRequest]
+//!
+//! To use this endpoint, construct a [`CreateStreamMarkerRequest`] with the [`CreateStreamMarkerRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::streams::create_stream_marker;
+//! let request = create_stream_marker::CreateStreamMarkerRequest::new();
+//! ```
+//!
+//! ## Body: [CreateStreamMarkerBody]
+//!
+//! We also need to provide a body to the request containing the broadcaster and an optional description.
+//!
+//! ```
+//! # use twitch_api::helix::streams::create_stream_marker;
+//! let body = create_stream_marker::CreateStreamMarkerBody::with_description(
+//!     "123",
+//!     "hello, this is a marker!",
+//! )
+//! .unwrap();
+//! ```
+//!
+//! ## Response: [StreamMarker]
+//!
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, streams::create_stream_marker};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = create_stream_marker::CreateStreamMarkerRequest::new();
+//! let body = create_stream_marker::CreateStreamMarkerBody::new("123");
+//! let response: create_stream_marker::StreamMarker = client.req_post(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`CreateStreamMarkerRequest::parse_response(None, &request.get_uri(), response)`](CreateStreamMarkerRequest::parse_response)
+
+use std::marker::PhantomData;
+
+use super::*;
+use helix::RequestPost;
+
+/// Maximum length of a [stream marker description](CreateStreamMarkerBody::description), in characters.
+pub const MAX_DESCRIPTION_LENGTH: usize = 140;
+
+/// Query Parameters for [Create Stream Marker](super::create_stream_marker)
+///
+/// [`create-stream-marker`](https://dev.twitch.tv/docs/api/reference#create-stream-marker)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct CreateStreamMarkerRequest<'a> {
+    #[serde(skip)]
+    _marker: PhantomData<&'a ()>,
+}
+
+impl CreateStreamMarkerRequest<'_> {
+    /// Create a new [`CreateStreamMarkerRequest`]
+    pub fn new() -> Self { Self::default() }
+}
+
+/// Body Parameters for [Create Stream Marker](super::create_stream_marker)
+///
+/// [`create-stream-marker`](https://dev.twitch.tv/docs/api/reference#create-stream-marker)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[non_exhaustive]
+pub struct CreateStreamMarkerBody<'a> {
+    /// The ID of the broadcaster that’s streaming content. This ID must match the user ID in the access token or the user in the access token must be one of the broadcaster’s editors.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub user_id: Cow<'a, types::UserIdRef>,
+    /// A short description of the marker to help the user remember why they marked the location. The maximum length of the description is 140 characters.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreateStreamMarkerBody<'a> {
+    /// Create a marker without a description
+    pub fn new(user_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
+        Self {
+            user_id: user_id.into_cow(),
+            description: None,
+        }
+    }
+
+    /// Create a marker with a description
+    ///
+    /// Fails if the description is longer than [`MAX_DESCRIPTION_LENGTH`] characters.
+    pub fn with_description(
+        user_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        description: impl Into<Cow<'a, str>>,
+    ) -> Result<Self, StreamMarkerDescriptionError> {
+        let description = description.into();
+        let length = description.chars().count();
+        if length > MAX_DESCRIPTION_LENGTH {
+            return Err(StreamMarkerDescriptionError { length });
+        }
+        Ok(Self {
+            user_id: user_id.into_cow(),
+            description: Some(description),
+        })
+    }
+}
+
+/// The description for a [stream marker](CreateStreamMarkerBody) is too long
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("description is {length} characters long, the maximum is 140")]
+#[non_exhaustive]
+pub struct StreamMarkerDescriptionError {
+    /// Length of the rejected description, in characters
+    pub length: usize,
+}
+
+impl helix::private::SealedSerialize for CreateStreamMarkerBody<'_> {}
+
+/// Return Values for [Create Stream Marker](super::create_stream_marker)
+///
+/// [`create-stream-marker`](https://dev.twitch.tv/docs/api/reference#create-stream-marker)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct StreamMarker {
+    /// An ID that identifies this marker.
+    pub id: String,
+    /// The UTC date and time (in RFC3339 format) of when the user created the marker.
+    pub created_at: types::Timestamp,
+    /// The relative offset (in seconds) of the marker from the beginning of the stream.
+    pub position_seconds: i64,
+    /// A description that the user gave the marker to help them remember why they marked the location.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
+    pub description: Option<String>,
+}
+
+impl StreamMarker {
+    /// The [position](StreamMarker::position_seconds) of the marker, formatted as `HH:MM:SS`
+    ///
+    /// ```rust
+    /// # use twitch_api::helix::streams::StreamMarker;
+    /// # let marker: StreamMarker = serde_json::from_str(r#"{"id":"123","created_at":"2018-08-20T20:10:03Z","description":"","position_seconds":3909}"#).unwrap();
+    /// assert_eq!(marker.position(), "01:05:09");
+    /// ```
+    pub fn position(&self) -> String {
+        let seconds = self.position_seconds.max(0);
+        format!(
+            "{:02}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    }
+}
+
+/// Result of [`HelixClient::create_stream_marker_if_live`](crate::helix::HelixClient::create_stream_marker_if_live)
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub enum MarkerOutcome {
+    /// The marker was created
+    Created(StreamMarker),
+    /// The broadcaster is not live, no marker was created
+    NotLive,
+}
+
+/// Result of [`HelixClient::mark_and_announce`](crate::helix::HelixClient::mark_and_announce)
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub struct MarkAndAnnounce {
+    /// The created marker
+    pub marker: StreamMarker,
+    /// The response to the announcement
+    pub announcement: helix::chat::SendChatAnnouncementResponse,
+}
+
+/// Error returned by [`HelixClient::mark_and_announce`](crate::helix::HelixClient::mark_and_announce)
+#[cfg(feature = "client")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum MarkAndAnnounceError<C: crate::HttpClient, E> {
+    /// The description is too long
+    #[error(transparent)]
+    Description(#[from] StreamMarkerDescriptionError),
+    /// The color is invalid
+    #[error("invalid announcement color")]
+    Color(#[source] E),
+    /// The marker was created, but the announcement could not be sent
+    #[error("marker was created, but the announcement could not be sent")]
+    Announcement {
+        /// The created marker
+        marker: StreamMarker,
+        /// Why the announcement could not be sent
+        #[source]
+        error: helix::ClientRequestError<<C as crate::HttpClient>::Error>,
+    },
+}
+
+impl Request for CreateStreamMarkerRequest<'_> {
+    type Response = StreamMarker;
+
+    const PATH: &'static str = "streams/markers";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator =
+        twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageBroadcast];
+}
+
+endpoint_meta!(POST CreateStreamMarkerRequest<'static>);
+
+impl<'a> RequestPost for CreateStreamMarkerRequest<'a> {
+    type Body = CreateStreamMarkerBody<'a>;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        helix::parse_single_return(request, uri, response, status)
+    }
+}
+
+// From twitch docs
+#[cfg(test)]
+const FIXTURE: &[u8] = br#"
+    {
+        "data": [
+           {
+              "id": "123",
+              "created_at": "2018-08-20T20:10:03Z",
+              "description": "hello, this is a marker!",
+              "position_seconds": 244
+           }
+        ]
+     }
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = CreateStreamMarkerRequest::new();
+
+    let body = CreateStreamMarkerBody::with_description("123", "hello, this is a marker!").unwrap();
+
+    assert_eq!(
+        body.body_json().unwrap(),
+        r#"{"user_id":"123","description":"hello, this is a marker!"}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    let data = FIXTURE.to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/streams/markers?"
+    );
+
+    dbg!(CreateStreamMarkerRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
+
+#[cfg(test)]
+#[test]
+fn test_description_length() {
+    let description = "a".repeat(MAX_DESCRIPTION_LENGTH);
+    assert!(CreateStreamMarkerBody::with_description("123", description.as_str()).is_ok());
+    // characters, not bytes
+    assert!(CreateStreamMarkerBody::with_description("123", "é".repeat(140)).is_ok());
+    assert_eq!(
+        CreateStreamMarkerBody::with_description("123", "a".repeat(141)).unwrap_err(),
+        StreamMarkerDescriptionError { length: 141 }
+    );
+}
+
+#[cfg(all(test, feature = "client"))]
+mod client_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn created() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "streams/markers",
+            200,
+            r#"{"data":[{"id":"123","created_at":"2018-08-20T20:10:03Z","description":"clip this","position_seconds":244}]}"#,
+        );
+        let token = crate::tests::user_token("broadcaster", "1234");
+
+        let outcome = mock
+            .helix()
+            .create_stream_marker_if_live("1234", "clip this", &token)
+            .await
+            .unwrap();
+        let MarkerOutcome::Created(marker) = outcome else {
+            panic!("expected a marker, got {outcome:?}")
+        };
+        assert_eq!(marker.position_seconds, 244);
+        assert_eq!(marker.description.as_deref(), Some("clip this"));
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].0, http::Method::POST);
+        assert_eq!(
+            &requests[0].2[..],
+            br#"{"user_id":"1234","description":"clip this"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn not_live() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "streams/markers",
+            404,
+            r#"{"error":"Not Found","status":404,"message":"user is not live"}"#,
+        );
+        let token = crate::tests::user_token("broadcaster", "1234");
+
+        let outcome = mock
+            .helix()
+            .create_stream_marker_if_live("1234", "clip this", &token)
+            .await
+            .unwrap();
+        assert_eq!(outcome, MarkerOutcome::NotLive);
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn other_not_found() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond(
+            "streams/markers",
+            404,
+            r#"{"error":"Not Found","status":404,"message":"user does not have VODs enabled"}"#,
+        );
+        let token = crate::tests::user_token("broadcaster", "1234");
+
+        let err = mock
+            .helix()
+            .create_stream_marker_if_live("1234", "clip this", &token)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            helix::ClientExtError::ClientError(helix::ClientRequestError::HelixRequestPostError(
+                helix::HelixRequestPostError::Error {
+                    status: http::StatusCode::NOT_FOUND,
+                    ..
+                }
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn description_too_long() {
+        let mock = crate::tests::MockClient::new();
+        let token = crate::tests::user_token("broadcaster", "1234");
+
+        let err = mock
+            .helix()
+            .create_stream_marker_if_live("1234", "a".repeat(141), &token)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            helix::ClientExtError::Other(StreamMarkerDescriptionError { length: 141 })
+        ));
+        assert!(mock.requests().is_empty());
+    }
+
+    const MARKER: &str = r#"{"data":[{"id":"123","created_at":"2018-08-20T20:10:03Z","description":"clip this","position_seconds":3909}]}"#;
+
+    #[tokio::test]
+    async fn mark_and_announce() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("streams/markers", 200, MARKER)
+            .respond("chat/announcements", 204, "");
+        let token = crate::tests::user_token("moderator", "5678");
+
+        let marked = mock
+            .helix()
+            .mark_and_announce("1234", "5678", "clip this", "purple", &token)
+            .await
+            .unwrap();
+        assert_eq!(marked.marker.position_seconds, 3909);
+        assert_eq!(
+            marked.announcement,
+            helix::chat::SendChatAnnouncementResponse::Success
+        );
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests[1].1.query(),
+            Some("broadcaster_id=1234&moderator_id=5678")
+        );
+        assert_eq!(
+            &requests[1].2[..],
+            br#"{"message":"clip this (01:05:09)","color":"purple"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn mark_and_announce_partial() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("streams/markers", 200, MARKER).respond(
+            "chat/announcements",
+            403,
+            r#"{"error":"Forbidden","status":403,"message":"user is not a moderator"}"#,
+        );
+        let token = crate::tests::user_token("moderator", "5678");
+
+        let err = mock
+            .helix()
+            .mark_and_announce("1234", "5678", "clip this", "purple", &token)
+            .await
+            .unwrap_err();
+        let helix::ClientExtError::Other(MarkAndAnnounceError::Announcement { marker, .. }) = err
+        else {
+            panic!("expected a partial success, got {err:?}")
+        };
+        assert_eq!(marker.id, "123");
+        assert_eq!(mock.requests().len(), 2);
+
+        // an invalid color is rejected before creating the marker
+        assert!(matches!(
+            mock.helix()
+                .mark_and_announce("1234", "5678", "clip this", "pink", &token)
+                .await,
+            Err(helix::ClientExtError::Other(MarkAndAnnounceError::Color(_)))
+        ));
+        assert_eq!(mock.requests().len(), 2);
+    }
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: CreateStreamMarkerRequest,
+    200 => FIXTURE,
+}
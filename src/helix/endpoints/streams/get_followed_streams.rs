@@ -102,6 +102,8 @@ impl Request for GetFollowedStreamsRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::UserReadFollows];
 }
 
+endpoint_meta!(GET GetFollowedStreamsRequest<'static>);
+
 impl RequestGet for GetFollowedStreamsRequest<'_> {}
 
 impl helix::Paginated for GetFollowedStreamsRequest<'_> {
@@ -110,14 +112,9 @@ impl helix::Paginated for GetFollowedStreamsRequest<'_> {
     }
 }
 
+// From twitch docs.
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetFollowedStreamsRequest::user_id("141981764");
-
-    // From twitch docs.
-    let data = br#"
+const FIXTURE: &[u8] = br#"
 {
     "data": [
           {
@@ -159,8 +156,15 @@ fn test_request() {
         "cursor": "eyJiIjp7IkN1cnNvciI6ImV5SnpJam8zT0RNMk5TNDBORFF4TlRjMU1UY3hOU3dpWkNJNlptRnNjMlVzSW5RaU9uUnlkV1Y5In0sImEiOnsiQ3Vyc29yIjoiZXlKeklqb3hOVGs0TkM0MU56RXhNekExTVRZNU1ESXNJbVFpT21aaGJITmxMQ0owSWpwMGNuVmxmUT09In19=="
     }
 }
-"#
-        .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetFollowedStreamsRequest::user_id("141981764");
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -172,3 +176,9 @@ fn test_request() {
 
     dbg!(GetFollowedStreamsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetFollowedStreamsRequest,
+    200 => FIXTURE,
+}
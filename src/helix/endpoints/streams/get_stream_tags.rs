@@ -69,21 +69,19 @@ pub type Tag = helix::tags::TwitchTag;
 impl Request for GetStreamTagsRequest<'_> {
     type Response = Vec<Tag>;
 
+    const DEPRECATED: Option<&'static str> = Some(helix::tags::TWITCH_TAGS_DEPRECATED);
     const PATH: &'static str = "streams/tags";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetStreamTagsRequest<'static>);
+
 impl RequestGet for GetStreamTagsRequest<'_> {}
 
+// From twitch docs
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetStreamTagsRequest::broadcaster_id("198704263");
-
-    // From twitch docs
-    let data = "\
+const FIXTURE: &str = "\
 {\n\
     \"data\": [\n\
         {\n\
@@ -115,8 +113,15 @@ fn test_request() {
         }\n\
     ]\n\
 }\n\
-"
-        .as_bytes().to_vec();
+";
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetStreamTagsRequest::broadcaster_id("198704263");
+
+    let data = FIXTURE.as_bytes().to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -128,3 +133,9 @@ fn test_request() {
 
     dbg!(GetStreamTagsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetStreamTagsRequest,
+    200 => FIXTURE,
+}
@@ -5,8 +5,10 @@
 //!
 //! ## Notes
 //!
-//! See also [`HelixClient::get_streams_from_logins`](crate::helix::HelixClient::get_streams_from_logins) and
-//! [`HelixClient::get_streams_from_ids`](crate::helix::HelixClient::get_streams_from_ids)
+//! See also [`HelixClient::get_streams_from_logins`](crate::helix::HelixClient::get_streams_from_logins),
+//! [`HelixClient::get_streams_from_ids`](crate::helix::HelixClient::get_streams_from_ids),
+//! [`HelixClient::get_streams_in_categories`](crate::helix::HelixClient::get_streams_in_categories) and
+//! [`HelixClient::get_streams_in_category_by_name`](crate::helix::HelixClient::get_streams_in_category_by_name)
 //!
 //!
 //! ## Request: [GetStreamsRequest]
@@ -61,7 +63,7 @@ pub struct GetStreamsRequest<'a> {
     pub before: Option<Cow<'a, helix::CursorRef>>,
     /// Maximum number of objects to return. Maximum: 100. Default: 20.
     #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
-    pub first: Option<usize>,
+    pub first: Option<helix::PageSize>,
     /// Returns streams broadcasting a specified game ID. You can specify up to 10 IDs.
     #[cfg_attr(
         feature = "typed-builder",
@@ -72,9 +74,8 @@ pub struct GetStreamsRequest<'a> {
     #[cfg_attr(not(feature = "deser_borrow"), serde(bound(deserialize = "'de: 'a")))]
     pub game_id: types::Collection<'a, types::CategoryId>,
     /// Stream language. You can specify up to 100 languages.
-    #[cfg_attr(feature = "typed-builder", builder(default))]
-    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
-    pub language: Option<Cow<'a, str>>,
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    pub language: Option<types::LanguageCode>,
     /// Returns streams broadcast by one or more specified user IDs. You can specify up to 100 IDs.
     #[cfg_attr(
         feature = "typed-builder",
@@ -124,9 +125,15 @@ impl<'a> GetStreamsRequest<'a> {
         }
     }
 
-    /// Set amount of results returned per page.
-    pub fn first(mut self, first: usize) -> Self {
-        self.first = Some(first);
+    /// Set amount of results returned per page, values above the limit of 100 are clamped.
+    pub fn first(mut self, first: impl Into<Option<usize>>) -> Self {
+        self.first = first.into().map(helix::PageSize::clamped::<Self>);
+        self
+    }
+
+    /// Only return streams in this language
+    pub fn language(mut self, language: impl Into<types::LanguageCode>) -> Self {
+        self.language = Some(language.into());
         self
     }
 
@@ -153,7 +160,7 @@ pub struct Stream {
     /// Stream ID.
     pub id: types::StreamId,
     /// Stream language.
-    pub language: String,
+    pub language: types::LanguageCode,
     /// Indicates if the broadcaster has specified their channel contains mature content that may be inappropriate for younger audiences.
     pub is_mature: bool,
     /// UTC timestamp.
@@ -182,30 +189,227 @@ pub struct Stream {
     pub viewer_count: usize,
 }
 
+/// Borrowed [`Stream`], returned by [`BorrowedResponse::data`](helix::BorrowedResponse::data)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct StreamRef<'a> {
+    /// ID of the game being played on the stream.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub game_id: Cow<'a, types::CategoryIdRef>,
+    /// Name of the game being played.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub game_name: Cow<'a, str>,
+    /// Stream ID.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub id: Cow<'a, types::StreamIdRef>,
+    /// Stream language.
+    pub language: types::LanguageCode,
+    /// Indicates if the broadcaster has specified their channel contains mature content that may be inappropriate for younger audiences.
+    pub is_mature: bool,
+    /// UTC timestamp.
+    pub started_at: types::Timestamp,
+    /// The tags applied to the stream.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
+    pub tags: Vec<String>,
+    /// Thumbnail URL of the stream. All image URLs have variable width and height. You can replace {width} and {height} with any values to get that size image
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub thumbnail_url: Cow<'a, str>,
+    /// Stream title.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub title: Cow<'a, str>,
+    /// Stream type: "live" or "" (in case of error).
+    #[serde(rename = "type")]
+    pub type_: StreamType,
+    /// ID of the user who is streaming.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub user_id: Cow<'a, types::UserIdRef>,
+    /// Display name corresponding to user_id.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub user_name: Cow<'a, types::DisplayNameRef>,
+    /// Login of the user who is streaming.
+    #[serde(borrow, deserialize_with = "crate::deserialize_borrowed_cow")]
+    pub user_login: Cow<'a, types::UserNameRef>,
+    /// Number of viewers watching the stream at the time of the query.
+    pub viewer_count: usize,
+}
+
+impl StreamRef<'_> {
+    /// Convert into an owned [`Stream`]
+    ///
+    /// The deprecated [`tag_ids`](Stream::tag_ids) are not kept and will be empty.
+    pub fn into_owned(self) -> Stream {
+        #[allow(deprecated)]
+        Stream {
+            game_id: self.game_id.into_owned(),
+            game_name: self.game_name.into_owned(),
+            id: self.id.into_owned(),
+            language: self.language,
+            is_mature: self.is_mature,
+            started_at: self.started_at,
+            tag_ids: vec![],
+            tags: self.tags,
+            thumbnail_url: self.thumbnail_url.into_owned(),
+            title: self.title.into_owned(),
+            type_: self.type_,
+            user_id: self.user_id.into_owned(),
+            user_name: self.user_name.into_owned(),
+            user_login: self.user_login.into_owned(),
+            viewer_count: self.viewer_count,
+        }
+    }
+}
+
+impl Stream {
+    /// Check if the stream has `tag` in its [tags](Stream::tags), ignoring case
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # let stream: twitch_api::helix::streams::Stream = serde_json::from_str(r#"{"id":"1","user_id":"2","user_login":"a","user_name":"a","game_id":"3","game_name":"a","type":"live","title":"a","tags":["Música"],"viewer_count":1,"started_at":"2021-03-10T15:04:21Z","language":"es","thumbnail_url":"","tag_ids":[],"is_mature":false}"#).unwrap();
+    /// assert!(stream.has_tag("MÚSICA"));
+    /// assert!(!stream.has_tag("musica"));
+    /// ```
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| {
+            t.chars()
+                .flat_map(char::to_lowercase)
+                .eq(tag.chars().flat_map(char::to_lowercase))
+        })
+    }
+
+    /// Check if this is a live broadcast, and not e.g. a rerun, a watch party or a stream with an empty [type](Stream::type_)
+    pub fn is_live_broadcast(&self) -> bool { self.type_.is_live() }
+}
+
+/// Filter a stream of [streams](Stream) to the ones that [have `tag`](Stream::has_tag)
+///
+/// Works with any of the helpers returning streams, e.g. [`HelixClient::get_followed_streams`](helix::HelixClient::get_followed_streams), without collecting them first.
+/// Errors are passed through.
+///
+/// # Examples
+///
+/// ```rust, no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+/// use futures::TryStreamExt;
+/// use twitch_api::helix;
+///
+/// let vtubers: Vec<helix::streams::Stream> = helix::streams::filter_streams_by_tag(
+///     client.get_followed_streams(&token),
+///     "VTuber",
+/// )
+/// .try_collect()
+/// .await?;
+/// # Ok(()) }
+/// ```
+#[cfg(feature = "client")]
+pub fn filter_streams_by_tag<S, E>(
+    streams: S,
+    tag: impl Into<String>,
+) -> impl futures::Stream<Item = Result<Stream, E>>
+where
+    S: futures::Stream<Item = Result<Stream, E>>,
+{
+    use futures::TryStreamExt as _;
+
+    let tag = tag.into();
+    streams.try_filter(move |stream| futures::future::ready(stream.has_tag(&tag)))
+}
+
+/// Filter a stream of [streams](Stream) to the [live broadcasts](Stream::is_live_broadcast), skipping reruns and watch parties
+///
+/// Works with any of the helpers returning streams, e.g. [`HelixClient::get_followed_streams`](helix::HelixClient::get_followed_streams), without collecting them first.
+/// Errors are passed through.
+///
+/// # Examples
+///
+/// ```rust, no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+/// use futures::TryStreamExt;
+/// use twitch_api::helix;
+///
+/// let live: Vec<helix::streams::Stream> =
+///     helix::streams::filter_live_broadcasts(client.get_followed_streams(&token))
+///         .try_collect()
+///         .await?;
+/// # Ok(()) }
+/// ```
+#[cfg(feature = "client")]
+pub fn filter_live_broadcasts<S, E>(streams: S) -> impl futures::Stream<Item = Result<Stream, E>>
+where S: futures::Stream<Item = Result<Stream, E>> {
+    use futures::TryStreamExt as _;
+
+    streams.try_filter(|stream| futures::future::ready(stream.is_live_broadcast()))
+}
+
+/// Error returned by [`HelixClient::get_streams_in_category_by_name`](crate::helix::HelixClient::get_streams_in_category_by_name)
+#[cfg(feature = "client")]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("no category named {name:?}")]
+#[non_exhaustive]
+pub struct CategoryNotFound {
+    /// The name that was searched for
+    pub name: String,
+}
+
+impl helix::games::CategoryLike for Stream {
+    fn category_id(&self) -> &types::CategoryIdRef { &self.game_id }
+
+    fn category_name(&self) -> &str { &self.game_name }
+}
+
+#[cfg(feature = "time")]
+impl Stream {
+    /// How long the stream has been live.
+    ///
+    /// Returns [`None`] if [`started_at`](Stream::started_at) is not a valid timestamp.
+    pub fn uptime(&self) -> Option<std::time::Duration> { self.uptime_at(&types::Timestamp::now()) }
+
+    /// How long the stream had been live at `now`.
+    ///
+    /// If `now` is before [`started_at`](Stream::started_at), e.g. because of clock skew, the uptime is zero.
+    /// Returns [`None`] if [`started_at`](Stream::started_at) is not a valid timestamp.
+    pub fn uptime_at(&self, now: &types::TimestampRef) -> Option<std::time::Duration> {
+        let started_at = types::Timestamp::new(self.started_at.to_string()).ok()?;
+        let uptime: Result<std::time::Duration, _> =
+            std::convert::TryFrom::try_from(now.to_utc() - started_at.to_utc());
+        Some(uptime.unwrap_or_default())
+    }
+}
+
 impl Request for GetStreamsRequest<'_> {
     type Response = Vec<Stream>;
 
+    const MAX_PAGE_SIZE: usize = 100;
     const PATH: &'static str = "streams";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetStreamsRequest<'static>);
+
 impl RequestGet for GetStreamsRequest<'_> {}
 
+impl helix::RequestGetBorrowed for GetStreamsRequest<'_> {
+    type BorrowedResponse<'a> = Vec<StreamRef<'a>>;
+}
+
 impl helix::Paginated for GetStreamsRequest<'_> {
     fn set_pagination(&mut self, cursor: Option<helix::Cursor>) {
         self.after = cursor.map(|c| c.into_cow())
     }
 }
 
+// From twitch docs, kinda. example 1 in https://dev.twitch.tv/docs/api/reference#get-streams is malformed
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetStreamsRequest::default();
-
-    // From twitch docs, kinda. example 1 in https://dev.twitch.tv/docs/api/reference#get-streams is malformed
-    let data = r#"
+const FIXTURE: &str = r#"
     {
         "data": [
           {
@@ -247,25 +451,61 @@ fn test_request() {
           "cursor": "eyJiIjp7IkN1cnNvciI6ImV5SnpJam8zT0RNMk5TNDBORFF4TlRjMU1UY3hOU3dpWkNJNlptRnNjMlVzSW5RaU9uUnlkV1Y5In0sImEiOnsiQ3Vyc29yIjoiZXlKeklqb3hOVGs0TkM0MU56RXhNekExTVRZNU1ESXNJbVFpT21aaGJITmxMQ0owSWpwMGNuVmxmUT09In19"
         }
       }
-"#
-        .as_bytes().to_vec();
+"#;
 
-    let http_response = http::Response::builder().body(data).unwrap();
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetStreamsRequest::default();
+
+    let data = FIXTURE.as_bytes().to_vec();
+
+    let http_response = http::Response::builder().body(data.clone()).unwrap();
 
     let uri = req.get_uri().unwrap();
     assert_eq!(uri.to_string(), "https://api.twitch.tv/helix/streams?");
 
-    dbg!(GetStreamsRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let res = GetStreamsRequest::parse_response(Some(req.clone()), &uri, http_response).unwrap();
+
+    let borrowed = BorrowedResponse::parse_response(
+        Some(req),
+        &uri,
+        http::Response::builder().body(data).unwrap(),
+    )
+    .unwrap();
+    let borrowed = borrowed.data().unwrap();
+    assert_eq!(borrowed.pagination, res.pagination);
+    // borrowed streams serialize like owned ones
+    assert_eq!(
+        serde_json::from_value::<Vec<Stream>>(serde_json::to_value(&borrowed.data).unwrap())
+            .unwrap(),
+        res.data
+    );
+    assert_eq!(
+        borrowed
+            .data
+            .into_iter()
+            .map(StreamRef::into_owned)
+            .collect::<Vec<_>>(),
+        res.data
+    );
 }
 
 #[cfg(test)]
 #[test]
-fn test_request_null_tags_issue184() {
+fn test_request_language() {
     use helix::*;
-    let req = GetStreamsRequest::default();
+    let req = GetStreamsRequest::default().language("EN");
+    assert_eq!(
+        req.get_uri().unwrap().to_string(),
+        "https://api.twitch.tv/helix/streams?language=en"
+    );
+}
 
-    // From twitch docs, kinda. example 1 in https://dev.twitch.tv/docs/api/reference#get-streams is malformed
-    let data = br#"
+// From twitch docs, kinda. example 1 in https://dev.twitch.tv/docs/api/reference#get-streams is malformed
+#[cfg(test)]
+const FIXTURE_NULL_TAGS_ISSUE184: &[u8] = br#"
 {
     "data": [
         {
@@ -290,8 +530,15 @@ fn test_request_null_tags_issue184() {
         "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6MjB9fQ=="
     }
 }
-"#
-        .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request_null_tags_issue184() {
+    use helix::*;
+    let req = GetStreamsRequest::default();
+
+    let data = FIXTURE_NULL_TAGS_ISSUE184.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -300,3 +547,397 @@ fn test_request_null_tags_issue184() {
 
     dbg!(GetStreamsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(all(test, feature = "time"))]
+#[test]
+fn test_uptime() {
+    let stream = |started_at: &str| -> Stream {
+        serde_json::from_value(serde_json::json!({
+            "id": "40952121085",
+            "user_id": "101051819",
+            "user_login": "afro",
+            "user_name": "Afro",
+            "game_id": "32982",
+            "game_name": "Grand Theft Auto V",
+            "type": "live",
+            "title": "Jacob: Digital Den Laptops & Routers",
+            "viewer_count": 1490,
+            "started_at": started_at,
+            "language": "en",
+            "thumbnail_url": "https://static-cdn.jtvnw.net/previews-ttv/live_user_afro-{width}x{height}.jpg",
+            "tag_ids": [],
+            "tags": ["English"],
+            "is_mature": false
+        }))
+        .unwrap()
+    };
+    let now = types::Timestamp::new("2021-03-10T17:34:21Z".to_owned()).unwrap();
+
+    assert_eq!(
+        stream("2021-03-10T15:04:21Z").uptime_at(&now),
+        Some(std::time::Duration::from_secs(2 * 60 * 60 + 30 * 60))
+    );
+    // clock skew, the stream started after now
+    assert_eq!(
+        stream("2021-03-10T17:34:51Z").uptime_at(&now),
+        Some(std::time::Duration::ZERO)
+    );
+    assert!(stream("2021-03-10T15:04:21Z").uptime().is_some());
+}
+
+#[cfg(all(test, feature = "client", feature = "time"))]
+#[tokio::test]
+async fn test_get_stream_uptime() {
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "streams",
+        200,
+        r#"{"data":[{"id":"40952121085","user_id":"101051819","user_login":"afro","user_name":"Afro","game_id":"32982","game_name":"Grand Theft Auto V","type":"live","title":"Jacob: Digital Den Laptops & Routers","viewer_count":1490,"started_at":"2021-03-10T15:04:21Z","language":"en","thumbnail_url":"https://static-cdn.jtvnw.net/previews-ttv/live_user_afro-{width}x{height}.jpg","tag_ids":[],"tags":["English"],"is_mature":false}],"pagination":{}}"#,
+    )
+    .respond("streams", 200, r#"{"data":[],"pagination":{}}"#);
+    let token = crate::tests::user_token("afro", "101051819");
+    let client = mock.helix();
+
+    let uptime = client
+        .get_stream_uptime("101051819", &token)
+        .await
+        .unwrap()
+        .expect("stream should be live");
+    assert!(uptime > std::time::Duration::from_secs(365 * 24 * 60 * 60));
+
+    assert_eq!(
+        client.get_stream_uptime("101051819", &token).await.unwrap(),
+        None
+    );
+    assert_eq!(
+        mock.requests()[0].1.to_string(),
+        "https://api.twitch.tv/helix/streams?user_id=101051819"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_has_tag() {
+    let stream: Stream = serde_json::from_str(
+        r#"{"id":"1","user_id":"2","user_login":"a","user_name":"a","game_id":"3","game_name":"a","type":"live","title":"a","tags":["VTuber","Música","Straße"],"viewer_count":1,"started_at":"2021-03-10T15:04:21Z","language":"es","thumbnail_url":"","tag_ids":[],"is_mature":false}"#,
+    )
+    .unwrap();
+
+    for tag in [
+        "VTuber", "vtuber", "VTUBER", "música", "MÚSICA", "straße", "STRAßE",
+    ] {
+        assert!(stream.has_tag(tag), "{tag}");
+    }
+    // no case folding beyond lowercasing, and no accent folding
+    assert!(!stream.has_tag("STRASSE"));
+    assert!(!stream.has_tag("musica"));
+    assert!(!stream.has_tag("VTube"));
+    assert!(!stream.has_tag(""));
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_filter_streams_by_tag() {
+    use futures::TryStreamExt;
+
+    let stream = |login: &str, tags: &str| -> Result<Stream, &'static str> {
+        Ok(serde_json::from_str(&format!(
+            r#"{{"id":"1","user_id":"2","user_login":"{login}","user_name":"a","game_id":"3","game_name":"a","type":"live","title":"a","tags":{tags},"viewer_count":1,"started_at":"2021-03-10T15:04:21Z","language":"es","thumbnail_url":"","tag_ids":[],"is_mature":false}}"#
+        ))
+        .unwrap())
+    };
+    let streams = futures::stream::iter(vec![
+        stream("a", r#"["vtuber"]"#),
+        stream("b", r#"["English"]"#),
+        stream("c", "null"),
+        Err("failed"),
+        stream("d", r#"["English","VTuber"]"#),
+    ]);
+
+    let mut filtered = std::pin::pin!(filter_streams_by_tag(streams, "VTuber"));
+    assert_eq!(
+        filtered
+            .try_next()
+            .await
+            .unwrap()
+            .unwrap()
+            .user_login
+            .as_str(),
+        "a"
+    );
+    assert_eq!(filtered.try_next().await, Err("failed"));
+    assert_eq!(
+        filtered
+            .try_next()
+            .await
+            .unwrap()
+            .unwrap()
+            .user_login
+            .as_str(),
+        "d"
+    );
+    assert_eq!(filtered.try_next().await, Ok(None));
+}
+
+#[cfg(test)]
+#[test]
+fn test_stream_type() {
+    let stream = |type_: &str| -> Stream {
+        serde_json::from_str(&format!(
+            r#"{{"id":"1","user_id":"2","user_login":"a","user_name":"a","game_id":"3","game_name":"a","type":"{type_}","title":"a","tags":[],"viewer_count":1,"started_at":"2021-03-10T15:04:21Z","language":"es","thumbnail_url":"","tag_ids":[],"is_mature":false}}"#
+        ))
+        .unwrap()
+    };
+
+    let live = stream("live");
+    assert_eq!(live.type_, StreamType::Live);
+    assert!(live.is_live_broadcast());
+
+    let error = stream("");
+    assert_eq!(error.type_, StreamType::NotLive);
+    assert!(!error.is_live_broadcast());
+
+    assert_eq!(stream("rerun").type_, StreamType::Rerun);
+    assert_eq!(stream("watch_party").type_, StreamType::WatchParty);
+    let unknown = stream("premiere");
+    assert_eq!(unknown.type_, StreamType::Unknown("premiere".to_owned()));
+    assert!(!unknown.is_live_broadcast());
+    assert_eq!(
+        serde_json::to_string(&unknown.type_).unwrap(),
+        r#""premiere""#
+    );
+    assert_eq!(serde_json::to_string(&error.type_).unwrap(), r#""""#);
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_filter_live_broadcasts() {
+    use futures::TryStreamExt;
+
+    let stream = |login: &str, type_: &str| {
+        format!(
+            r#"{{"id":"1","user_id":"2","user_login":"{login}","user_name":"a","game_id":"3","game_name":"a","type":"{type_}","title":"a","tags":[],"viewer_count":1,"started_at":"2021-03-10T15:04:21Z","language":"es","thumbnail_url":"","tag_ids":[],"is_mature":false}}"#
+        )
+    };
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "streams/followed",
+        200,
+        &format!(
+            r#"{{"data":[{},{},{}],"pagination":{{"cursor":"abc"}}}}"#,
+            stream("a", "live"),
+            stream("b", "rerun"),
+            stream("c", "")
+        ),
+    )
+    .respond(
+        "streams/followed",
+        200,
+        &format!(
+            r#"{{"data":[{},{}],"pagination":{{}}}}"#,
+            stream("d", "watch_party"),
+            stream("e", "live")
+        ),
+    );
+    let token = crate::tests::user_token("follower", "1234");
+    let client = mock.helix();
+
+    let live: Vec<_> = filter_live_broadcasts(client.get_followed_streams(&token))
+        .map_ok(|stream| stream.user_login.take())
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(live, ["a", "e"]);
+    assert_eq!(mock.requests().len(), 2);
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_get_streams_in_category_by_name() {
+    use futures::TryStreamExt;
+
+    let mock = crate::tests::MockClient::new();
+    let categories = r#"{"data":[{"id":"1","name":"Just Chatting Extended","box_art_url":""},{"id":"509658","name":"Just Chatting","box_art_url":""}],"pagination":{}}"#;
+    mock.respond("search/categories", 200, categories)
+        .respond(
+            "streams",
+            200,
+            r#"{"data":[{"id":"1","user_id":"2","user_login":"a","user_name":"a","game_id":"509658","game_name":"Just Chatting","type":"live","title":"a","tags":[],"viewer_count":1,"started_at":"2021-03-10T15:04:21Z","language":"es","thumbnail_url":"","tag_ids":[],"is_mature":false}],"pagination":{}}"#,
+        )
+        .respond("search/categories", 200, categories);
+    let token = crate::tests::user_token("user", "1234");
+    let client = mock.helix();
+
+    let streams: Vec<Stream> = client
+        .get_streams_in_category_by_name("just chatting", &token)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(streams.len(), 1);
+    assert_eq!(streams[0].game_id.as_str(), "509658");
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].1.path(), "/helix/search/categories");
+    assert_eq!(requests[1].1.path(), "/helix/streams");
+    assert_eq!(requests[1].1.query(), Some("first=100&game_id=509658"));
+
+    let err = client
+        .get_streams_in_category_by_name("just chat", &token)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        helix::ClientExtError::Other(CategoryNotFound { ref name }) if name == "just chat"
+    ));
+    assert_eq!(mock.requests().len(), 3);
+    assert_eq!(mock.pending(), 0);
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_get_top_streams_in_categories() {
+    fn page(streams: &[(&str, &str, usize)], cursor: Option<&str>) -> String {
+        let streams: Vec<_> = streams
+            .iter()
+            .map(|(id, game_id, viewers)| {
+                format!(
+                    r#"{{"id":"{id}","user_id":"{id}","user_login":"user{id}","user_name":"user{id}","game_id":"{game_id}","game_name":"","type":"live","title":"","tags":[],"viewer_count":{viewers},"started_at":"2021-03-10T15:04:21Z","language":"en","thumbnail_url":"","tag_ids":[],"is_mature":false}}"#
+                )
+            })
+            .collect();
+        let pagination =
+            cursor.map_or_else(|| "{}".to_owned(), |c| format!(r#"{{"cursor":"{c}"}}"#));
+        format!(
+            r#"{{"data":[{}],"pagination":{pagination}}}"#,
+            streams.join(",")
+        )
+    }
+
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "streams",
+        200,
+        &page(
+            &[("a", "1", 100), ("b", "1", 50), ("c", "1", 10)],
+            Some("next"),
+        ),
+    )
+    // "a" changed category between the requests
+    .respond(
+        "streams",
+        200,
+        &page(&[("d", "2", 80), ("a", "2", 100)], None),
+    )
+    .respond("streams", 200, &page(&[("e", "3", 60)], None));
+    let token = crate::tests::app_token();
+    let client = mock.helix();
+
+    let top = client
+        .get_top_streams_in_categories(&["1", "2", "3", "1"][..].into(), 3, &token)
+        .await
+        .unwrap();
+    let ids: Vec<_> = top.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(ids, ["a", "d", "e"]);
+    let requests = mock.requests();
+    // one page per category, the first category had enough streams
+    assert_eq!(requests.len(), 3);
+    for (request, game_id) in requests.iter().zip(["1", "2", "3"]) {
+        assert_eq!(
+            request.1.query(),
+            Some(format!("first=3&game_id={game_id}").as_str())
+        );
+    }
+    assert_eq!(mock.pending(), 0);
+
+    // a limit over 100 needs a second page
+    let first: Vec<_> = (0..100).map(|i| (i.to_string(), 1000 - i)).collect();
+    let second: Vec<_> = (100..200).map(|i| (i.to_string(), 1000 - i)).collect();
+    fn streams(page_streams: &[(String, usize)]) -> Vec<(&str, &str, usize)> {
+        page_streams
+            .iter()
+            .map(|(id, viewers)| (id.as_str(), "1", *viewers))
+            .collect()
+    }
+    mock.respond("streams", 200, &page(&streams(&first), Some("next")))
+        .respond("streams", 200, &page(&streams(&second), Some("last")));
+    let top = client
+        .get_top_streams_in_categories(&["1"][..].into(), 150, &token)
+        .await
+        .unwrap();
+    assert_eq!(top.len(), 150);
+    assert_eq!(top[149].id.as_str(), "149");
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 5);
+    assert_eq!(requests[3].1.query(), Some("first=100&game_id=1"));
+    assert_eq!(
+        requests[4].1.query(),
+        Some("after=next&first=100&game_id=1")
+    );
+    assert_eq!(mock.pending(), 0);
+
+    assert!(client
+        .get_top_streams_in_categories(&["1"][..].into(), 0, &token)
+        .await
+        .unwrap()
+        .is_empty());
+    assert_eq!(mock.requests().len(), 5);
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_uri_roundtrip(
+        after in proptest::option::of("(?s).*"),
+        before in proptest::option::of("(?s).*"),
+        first in proptest::option::of(proptest::num::usize::ANY),
+        game_id in proptest::collection::vec("(?s).*", 0..10),
+        language in proptest::option::of("(?s).*"),
+        user_id in proptest::collection::vec("(?s).*", 0..10),
+        user_login in proptest::collection::vec("(?s).*", 0..10),
+    ) {
+        use helix::*;
+        let mut req = GetStreamsRequest::default();
+        req.after = after.clone().map(|after| Cursor::from(after).into());
+        req.before = before.clone().map(|before| Cursor::from(before).into());
+        req.first = first.map(helix::PageSize::unchecked);
+        req.game_id = game_id.iter().cloned().map(types::CategoryId::from).collect::<Vec<_>>().into();
+        req.language = language.as_deref().map(types::LanguageCode::from);
+        req.user_id = user_id.iter().cloned().map(types::UserId::from).collect::<Vec<_>>().into();
+        req.user_login = user_login.iter().cloned().map(types::UserName::from).collect::<Vec<_>>().into();
+
+        let uri = req.get_uri().unwrap();
+        let pairs = |key: &str, values: Vec<String>| {
+            values.into_iter().map(move |value| (key.to_owned(), value)).collect::<Vec<_>>()
+        };
+        let mut expected = vec![];
+        expected.extend(pairs("after", after.into_iter().collect()));
+        expected.extend(pairs("before", before.into_iter().collect()));
+        expected.extend(pairs("first", first.iter().map(usize::to_string).collect()));
+        expected.extend(pairs("game_id", game_id));
+        // unknown codes are kept in lowercase
+        expected.extend(pairs("language", language.iter().map(|l| l.to_lowercase()).collect()));
+        expected.extend(pairs("user_id", user_id));
+        expected.extend(pairs("user_login", user_login));
+        proptest::prop_assert_eq!(crate::tests::query_pairs(&uri), expected);
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_first() {
+    use helix::*;
+    let req = GetStreamsRequest::user_logins(&["justintv"]).first(250);
+    assert_eq!(
+        req.get_uri().unwrap().to_string(),
+        "https://api.twitch.tv/helix/streams?first=100&user_login=justintv"
+    );
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetStreamsRequest,
+    200 => FIXTURE,
+    200 => FIXTURE_NULL_TAGS_ISSUE184,
+}
@@ -30,42 +30,61 @@ use crate::{
 use serde_derive::{Deserialize, Serialize};
 use std::borrow::Cow;
 
+#[cfg(feature = "client")]
+#[doc(inline)]
+pub use create_stream_marker::MarkAndAnnounceError;
+#[doc(inline)]
+pub use create_stream_marker::{
+    CreateStreamMarkerBody, CreateStreamMarkerRequest, MarkAndAnnounce, MarkerOutcome,
+    StreamMarker, StreamMarkerDescriptionError,
+};
 #[doc(inline)]
 pub use get_followed_streams::GetFollowedStreamsRequest;
 #[doc(inline)]
 #[allow(deprecated)]
 pub use get_stream_tags::{GetStreamTagsRequest, Tag};
+#[cfg(feature = "client")]
 #[doc(inline)]
-pub use get_streams::{GetStreamsRequest, Stream};
+pub use get_streams::{filter_live_broadcasts, filter_streams_by_tag, CategoryNotFound};
+#[doc(inline)]
+pub use get_streams::{GetStreamsRequest, Stream, StreamRef};
 #[doc(inline)]
 #[allow(deprecated)]
 pub use replace_stream_tags::{ReplaceStreamTags, ReplaceStreamTagsBody, ReplaceStreamTagsRequest};
 
+pub mod create_stream_marker;
 pub mod get_followed_streams;
 pub mod get_stream_tags;
 pub mod get_streams;
 pub mod replace_stream_tags;
 
 /// Gotten from [`Stream.type_`](get_streams::Stream#structfield.type_)
+///
+/// Twitch only documents `"live"`, and an empty string in case of an error.
+/// The other known values were returned by older versions of the API, unknown values are kept in [`Unknown`](StreamType::Unknown).
 #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
 #[non_exhaustive]
 pub enum StreamType {
     /// Stream is live.
     #[serde(rename = "live")]
     Live,
-
-    // Got error from endpoint
-    //Error, TODO: Should this be here?
-
-    //
-    /// Stream not live
+    /// Stream is a rerun of a past broadcast.
+    #[serde(rename = "rerun")]
+    Rerun,
+    /// Stream is a watch party.
+    #[serde(rename = "watch_party")]
+    WatchParty,
+    /// Stream not live, the type is an empty string
     ///
     /// # Notes
-    /// This is never returned from twitch endpoints. To get this
-    /// Just do a [`GetStreamsRequest`] and if there is no response for your user_id/user_login, you can be
+    /// Twitch returns an empty string in case of an error. To check if a channel is live,
+    /// just do a [`GetStreamsRequest`] and if there is no response for your user_id/user_login, you can be
     /// sure that the channel is not live
-    #[serde(other)]
+    #[serde(rename = "")]
     NotLive,
+    /// A stream type not known to this crate
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl StreamType {
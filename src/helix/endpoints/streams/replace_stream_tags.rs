@@ -130,12 +130,15 @@ impl helix::private::SealedSerialize for ReplaceStreamTagsBody<'_> {}
 impl Request for ReplaceStreamTagsRequest<'_> {
     type Response = ReplaceStreamTags;
 
+    const DEPRECATED: Option<&'static str> = Some(helix::tags::TWITCH_TAGS_DEPRECATED);
     const PATH: &'static str = "streams/tags";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator =
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageBroadcast];
 }
 
+endpoint_meta!(PUT ReplaceStreamTagsRequest<'static>);
+
 impl<'a> RequestPut for ReplaceStreamTagsRequest<'a> {
     type Body = ReplaceStreamTagsBody<'a>;
 
@@ -161,6 +164,7 @@ impl<'a> RequestPut for ReplaceStreamTagsRequest<'a> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
@@ -192,3 +196,9 @@ fn test_request() {
 
     dbg!(ReplaceStreamTagsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: ReplaceStreamTagsRequest,
+    204 => b"",
+}
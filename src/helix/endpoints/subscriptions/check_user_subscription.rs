@@ -106,6 +106,8 @@ impl Request for CheckUserSubscriptionRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::UserReadSubscriptions];
 }
 
+endpoint_meta!(GET CheckUserSubscriptionRequest<'static>);
+
 impl RequestGet for CheckUserSubscriptionRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -123,6 +125,7 @@ impl RequestGet for CheckUserSubscriptionRequest<'_> {
                     e,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         Ok(helix::Response::new(
@@ -132,6 +135,7 @@ impl RequestGet for CheckUserSubscriptionRequest<'_> {
                     response: text.to_string(),
                     status,
                     uri: uri.clone(),
+                    request_id: None,
                 },
             )?,
             inner_response.pagination.cursor,
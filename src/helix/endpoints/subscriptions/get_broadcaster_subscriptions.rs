@@ -38,6 +38,18 @@
 use super::*;
 use helix::RequestGet;
 
+/// Maximum number of user ids in a [`GetBroadcasterSubscriptionsRequest`]
+pub const MAX_USER_IDS: usize = 100;
+
+/// More than [`MAX_USER_IDS`] user ids were given to [`GetBroadcasterSubscriptionsRequest::user_ids`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{count} user ids were given, the maximum is 100")]
+#[non_exhaustive]
+pub struct TooManyUserIdsError {
+    /// Number of user ids given
+    pub count: usize,
+}
+
 /// Query Parameters for [Get Broadcaster Subscriptions](super::get_broadcaster_subscriptions)
 ///
 /// [`get-broadcaster-subscriptions`](https://dev.twitch.tv/docs/api/reference#get-broadcaster-subscriptions)
@@ -62,7 +74,7 @@ pub struct GetBroadcasterSubscriptionsRequest<'a> {
     pub after: Option<Cow<'a, helix::CursorRef>>,
     /// Number of values to be returned per page. Limit: 100. Default: 20.
     #[cfg_attr(feature = "typed-builder", builder(setter(into), default))]
-    pub first: Option<usize>,
+    pub first: Option<helix::PageSize>,
 }
 
 impl<'a> GetBroadcasterSubscriptionsRequest<'a> {
@@ -82,9 +94,35 @@ impl<'a> GetBroadcasterSubscriptionsRequest<'a> {
         self
     }
 
-    /// Set amount of results returned per page.
-    pub fn first(mut self, first: usize) -> Self {
-        self.first = Some(first);
+    /// Only return the subscriptions of these users
+    ///
+    /// Fails if more than [`MAX_USER_IDS`] user ids are given.
+    ///
+    /// The [`total`](helix::Response::total) of the response is still the total amount of subscriptions of the broadcaster, not the amount of subscribed users that were requested.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::subscriptions::GetBroadcasterSubscriptionsRequest;
+    /// GetBroadcasterSubscriptionsRequest::broadcaster_id("1234")
+    ///     .user_ids(&["5678", "9012"])
+    ///     .unwrap();
+    /// ```
+    pub fn user_ids(
+        mut self,
+        user_ids: impl Into<types::Collection<'a, types::UserId>>,
+    ) -> Result<Self, TooManyUserIdsError> {
+        let user_ids = user_ids.into();
+        if user_ids.len() > MAX_USER_IDS {
+            return Err(TooManyUserIdsError {
+                count: user_ids.len(),
+            });
+        }
+        self.user_id = user_ids;
+        Ok(self)
+    }
+
+    /// Set amount of results returned per page, values above the limit of 100 are clamped.
+    pub fn first(mut self, first: impl Into<Option<usize>>) -> Self {
+        self.first = first.into().map(helix::PageSize::clamped::<Self>);
         self
     }
 }
@@ -137,12 +175,19 @@ pub struct BroadcasterSubscription {
 impl Request for GetBroadcasterSubscriptionsRequest<'_> {
     type Response = Vec<BroadcasterSubscription>;
 
+    const MAX_PAGE_SIZE: usize = 100;
     const PATH: &'static str = "subscriptions";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator =
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelReadSubscriptions];
+
+    /// Filtering by [`user_id`](Self::user_id) returns the same data, so no scopes are needed on top of the required one
+    #[cfg(feature = "twitch_oauth2")]
+    fn opt_scopes(&self) -> &'static [twitch_oauth2::Scope] { &[] }
 }
 
+endpoint_meta!(GET GetBroadcasterSubscriptionsRequest<'static>);
+
 impl RequestGet for GetBroadcasterSubscriptionsRequest<'_> {}
 
 impl helix::Paginated for GetBroadcasterSubscriptionsRequest<'_> {
@@ -163,6 +208,22 @@ impl helix::Response<GetBroadcasterSubscriptionsRequest<'_>, Vec<BroadcasterSubs
     }
 }
 
+/// Aggregated subscriptions of a broadcaster, see [`HelixClient::get_subscription_breakdown`](crate::helix::HelixClient::get_subscription_breakdown)
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct SubscriptionBreakdown {
+    /// Total number of subscriptions, as reported by Twitch.
+    pub total: u64,
+    /// The current number of subscriber points earned by this broadcaster, if reported by Twitch.
+    pub points: Option<i64>,
+    /// Number of subscriptions per tier. Prime subscriptions are counted as [Tier 1](types::SubscriptionTier::Tier1).
+    pub by_tier: std::collections::HashMap<types::SubscriptionTier, u64>,
+    /// Number of gifted subscriptions.
+    pub gifted: u64,
+    /// Gifters with the amount of currently active subscriptions they gifted, most gifts first.
+    pub top_gifters: Vec<(types::UserId, u64)>,
+}
+
 /// Errors when retrieving `points` in [Get Broadcaster Subscriptions](self)
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
@@ -224,4 +285,212 @@ fn test_request() {
         );
     assert_eq!(resp.total, Some(13));
     assert_eq!(resp.points().unwrap(), 13);
+    assert_eq!(resp.get_other::<_, i64>("points").unwrap(), Some(13));
+    assert!(resp.get_other::<_, String>("points").is_err());
+    assert_eq!(resp.get_other::<_, i64>("date_range").unwrap(), None);
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_user_ids() {
+    use helix::*;
+    let req = GetBroadcasterSubscriptionsRequest::broadcaster_id("123")
+        .user_ids(&["1", "2", "3"])
+        .unwrap();
+    assert_eq!(
+        req.get_uri().unwrap().to_string(),
+        "https://api.twitch.tv/helix/subscriptions?broadcaster_id=123&user_id=1&user_id=2&user_id=3"
+    );
+
+    let ids: Vec<types::UserId> = (0..=MAX_USER_IDS).map(|i| i.to_string().into()).collect();
+    assert_eq!(
+        GetBroadcasterSubscriptionsRequest::broadcaster_id("123")
+            .user_ids(&ids[..])
+            .unwrap_err(),
+        TooManyUserIdsError { count: 101 }
+    );
+    assert!(GetBroadcasterSubscriptionsRequest::broadcaster_id("123")
+        .user_ids(&ids[1..])
+        .is_ok());
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_subscription_breakdown() {
+    fn sub(user: &str, tier: &str, plan: &str, gifter: Option<&str>) -> String {
+        format!(
+            r#"{{"broadcaster_id":"123","broadcaster_login":"broadcaster","broadcaster_name":"Broadcaster","gifter_id":"{0}","gifter_login":"{0}","gifter_name":"{0}","is_gift":{1},"tier":"{tier}","plan_name":"{plan}","user_id":"{user}","user_login":"user{user}","user_name":"User{user}"}}"#,
+            gifter.unwrap_or_default(),
+            gifter.is_some(),
+        )
+    }
+
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "subscriptions",
+        200,
+        &format!(
+            r#"{{"data":[{},{},{}],"pagination":{{"cursor":"page2"}},"total":5,"points":9}}"#,
+            sub("1", "1000", "Channel Subscription", None),
+            sub("2", "1000", "Channel Subscription", Some("20")),
+            sub("3", "2000", "Channel Subscription (Tier 2)", Some("21")),
+        ),
+    )
+    .respond(
+        "subscriptions",
+        200,
+        &format!(
+            r#"{{"data":[{},{}],"pagination":{{}},"total":5,"points":9}}"#,
+            sub("4", "3000", "Channel Subscription (Tier 3)", Some("20")),
+            sub("5", "Prime", "Prime", None),
+        ),
+    );
+    let token = crate::tests::user_token("broadcaster", "123");
+
+    let breakdown = mock
+        .helix()
+        .get_subscription_breakdown(&token)
+        .await
+        .unwrap();
+
+    assert_eq!(breakdown.total, 5);
+    assert_eq!(breakdown.points, Some(9));
+    assert_eq!(
+        breakdown.by_tier.get(&types::SubscriptionTier::Tier1),
+        Some(&3)
+    );
+    assert_eq!(
+        breakdown.by_tier.get(&types::SubscriptionTier::Tier2),
+        Some(&1)
+    );
+    assert_eq!(
+        breakdown.by_tier.get(&types::SubscriptionTier::Tier3),
+        Some(&1)
+    );
+    assert_eq!(breakdown.by_tier.get(&types::SubscriptionTier::Prime), None);
+    assert_eq!(breakdown.gifted, 3);
+    assert_eq!(
+        breakdown.top_gifters,
+        vec![("20".into(), 2), ("21".into(), 1)]
+    );
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[1].1.query(),
+        Some("broadcaster_id=123&after=page2&first=100")
+    );
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_get_subscribed_users() {
+    let sub = |user: &str| {
+        format!(
+            r#"{{"broadcaster_id":"123","broadcaster_login":"broadcaster","broadcaster_name":"Broadcaster","gifter_id":"","gifter_login":"","gifter_name":"","is_gift":false,"tier":"1000","plan_name":"Channel Subscription","user_id":"{user}","user_login":"user{user}","user_name":"User{user}"}}"#
+        )
+    };
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "subscriptions",
+        200,
+        &format!(
+            r#"{{"data":[{},{}],"pagination":{{}},"total":500,"points":600}}"#,
+            sub("1"),
+            sub("3")
+        ),
+    );
+    let token = crate::tests::user_token("broadcaster", "123");
+
+    let subs = mock
+        .helix()
+        .get_subscribed_users(&["1", "2", "3"][..].into(), &token)
+        .await
+        .unwrap();
+    let id = types::UserIdRef::from_static;
+    assert_eq!(subs.len(), 2);
+    assert_eq!(subs[id("1")].user_login.as_str(), "user1");
+    assert!(!subs.contains_key(id("2")));
+    assert_eq!(subs[id("3")].user_login.as_str(), "user3");
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].1.query(),
+        Some("broadcaster_id=123&user_id=1&user_id=2&user_id=3&first=100")
+    );
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_get_broadcaster_subscriptions_not_affiliate() {
+    use futures::TryStreamExt;
+
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "subscriptions",
+        400,
+        r#"{"error":"Bad Request","status":400,"message":"broadcaster_id must be partnered or affiliated"}"#,
+    )
+    .respond(
+        "subscriptions",
+        200,
+        r#"{"data":[{"broadcaster_id":"123","broadcaster_login":"broadcaster","broadcaster_name":"Broadcaster","gifter_id":"","gifter_login":"","gifter_name":"","is_gift":false,"tier":"1000","plan_name":"Channel Subscription","user_id":"1","user_login":"user1","user_name":"User1"}],"pagination":{},"total":1,"points":1}"#,
+    );
+    let token = crate::tests::user_token("broadcaster", "123");
+    let client = mock.helix();
+
+    let err = client
+        .get_broadcaster_subscriptions(&token)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap_err();
+    let helix::ClientExtError::Other(SubscriptionsError::NotAffiliateOrPartner(e)) = err else {
+        panic!("expected NotAffiliateOrPartner, got {err:?}")
+    };
+    assert_eq!(e.status, http::StatusCode::BAD_REQUEST);
+
+    let subs: Vec<BroadcasterSubscription> = client
+        .get_broadcaster_subscriptions(&token)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(subs.len(), 1);
+    assert_eq!(subs[0].user_id.as_str(), "1");
+}
+
+#[cfg(all(test, feature = "client"))]
+#[test]
+fn test_can_have_subscriptions() {
+    fn user(broadcaster_type: &str) -> helix::users::User {
+        serde_json::from_value(serde_json::json!({
+            "id": "141981764",
+            "login": "twitchdev",
+            "display_name": "TwitchDev",
+            "type": "",
+            "broadcaster_type": broadcaster_type,
+            "description": "",
+            "profile_image_url": "",
+            "offline_image_url": "",
+            "created_at": "2016-12-14T20:32:28.894263Z"
+        }))
+        .unwrap()
+    }
+
+    let client = crate::tests::MockClient::new().helix();
+    assert!(client.can_have_subscriptions(&user("partner")));
+    assert!(client.can_have_subscriptions(&user("affiliate")));
+    assert!(!client.can_have_subscriptions(&user("")));
+    assert!(!client.can_have_subscriptions(&user("something_new")));
+}
+
+#[cfg(test)]
+#[test]
+fn test_first() {
+    use helix::*;
+    let req = GetBroadcasterSubscriptionsRequest::broadcaster_id("1234").first(250);
+    assert_eq!(
+        req.get_uri().unwrap().to_string(),
+        "https://api.twitch.tv/helix/subscriptions?broadcaster_id=1234&first=100"
+    );
 }
@@ -187,6 +187,8 @@ impl Request for GetBroadcasterSubscriptionsEventsRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelReadSubscriptions];
 }
 
+endpoint_meta!(GET GetBroadcasterSubscriptionsEventsRequest<'static>);
+
 impl RequestGet for GetBroadcasterSubscriptionsEventsRequest<'_> {}
 
 impl helix::Paginated for GetBroadcasterSubscriptionsEventsRequest<'_> {
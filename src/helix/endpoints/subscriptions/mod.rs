@@ -31,7 +31,8 @@ pub mod get_broadcaster_subscriptions_events;
 
 #[doc(inline)]
 pub use get_broadcaster_subscriptions::{
-    BroadcasterSubscription, GetBroadcasterSubscriptionsRequest,
+    BroadcasterSubscription, GetBroadcasterSubscriptionsRequest, SubscriptionBreakdown,
+    TooManyUserIdsError,
 };
 #[doc(inline)]
 pub use get_broadcaster_subscriptions_events::{
@@ -40,3 +41,49 @@ pub use get_broadcaster_subscriptions_events::{
 
 #[doc(inline)]
 pub use check_user_subscription::{CheckUserSubscriptionRequest, UserSubscription};
+
+/// Known errors of [Get Broadcaster Subscriptions](get_broadcaster_subscriptions), returned by [`HelixClient::get_broadcaster_subscriptions`](helix::HelixClient::get_broadcaster_subscriptions)
+///
+/// Only broadcasters that are affiliates or partners can have subscriptions, see [`HelixClient::can_have_subscriptions`](helix::HelixClient::can_have_subscriptions).
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[non_exhaustive]
+pub enum SubscriptionsError {
+    /// broadcaster is not an affiliate or partner: {0}
+    NotAffiliateOrPartner(helix::HelixApiError),
+}
+
+impl SubscriptionsError {
+    /// The error returned by Twitch
+    pub fn api_error(&self) -> &helix::HelixApiError {
+        match self {
+            Self::NotAffiliateOrPartner(e) => e,
+        }
+    }
+
+    /// Map a known error returned by Twitch into a [`SubscriptionsError`], other errors are kept as is
+    #[cfg(feature = "client")]
+    pub(crate) fn from_client_error<RE: std::error::Error + Send + Sync + 'static>(
+        error: helix::ClientRequestError<RE>,
+    ) -> Result<Self, helix::ClientRequestError<RE>> {
+        match &error {
+            helix::ClientRequestError::HelixRequestGetError(
+                helix::HelixRequestGetError::Error {
+                    status, message, ..
+                },
+            ) if (*status == http::StatusCode::BAD_REQUEST
+                || *status == http::StatusCode::FORBIDDEN)
+                && is_not_qualified(message) =>
+            {
+                error.into_api_error().map(Self::NotAffiliateOrPartner)
+            }
+            _ => Err(error),
+        }
+    }
+}
+
+/// Whether the message of an error says that the broadcaster is not an affiliate or partner
+#[cfg(feature = "client")]
+fn is_not_qualified(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("partner") || message.contains("affiliate")
+}
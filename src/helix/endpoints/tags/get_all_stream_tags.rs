@@ -85,11 +85,14 @@ pub type Tag = helix::tags::TwitchTag;
 impl Request for GetAllStreamTagsRequest<'_> {
     type Response = Vec<Tag>;
 
+    const DEPRECATED: Option<&'static str> = Some(helix::tags::TWITCH_TAGS_DEPRECATED);
     const PATH: &'static str = "tags/streams";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetAllStreamTagsRequest<'static>);
+
 impl RequestGet for GetAllStreamTagsRequest<'_> {}
 
 impl helix::Paginated for GetAllStreamTagsRequest<'_> {
@@ -32,6 +32,9 @@ pub mod get_all_stream_tags;
 #[allow(deprecated)]
 pub use get_all_stream_tags::{GetAllStreamTagsRequest, Tag};
 
+/// [Deprecation](helix::Request::DEPRECATED) of the endpoints for Twitch-defined tags
+pub(crate) const TWITCH_TAGS_DEPRECATED: &str = "Twitch-defined tags have been deprecated, use the `tags` of Get Channel Information and Modify Channel Information instead. See https://discuss.dev.twitch.tv/t/adding-customizable-tags-to-the-twitch-api/42921";
+
 /// Language code, formatted as 2 letter language by ISO 639-1, a dash (`-`) and 2 letter region by ISO 3166-1
 ///
 /// i.e
@@ -76,7 +79,7 @@ impl From<AutoGenerated> for bool {
     }
 }
 /// A stream tag as defined by Twitch.
-#[derive(PartialEq, Eq, Deserialize, Debug, Clone)]
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
 #[deprecated(
     note = "Twitch-defined tags have been deprecated. See https://discuss.dev.twitch.tv/t/adding-customizable-tags-to-the-twitch-api/42921"
 )]
@@ -86,6 +86,8 @@ impl Request for GetChannelTeamsRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetChannelTeamsRequest<'static>);
+
 impl RequestGet for GetChannelTeamsRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -103,6 +105,7 @@ impl RequestGet for GetChannelTeamsRequest<'_> {
                     e,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         Ok(helix::Response::new(
@@ -110,7 +113,7 @@ impl RequestGet for GetChannelTeamsRequest<'_> {
             response.pagination.cursor,
             request,
             response.total,
-            response.other,
+            response.other.filter(|other| !other.is_empty()),
         ))
     }
 }
@@ -68,6 +68,52 @@ impl<'a> GetTeamsRequest<'a> {
             name: Some(name.into()),
         }
     }
+
+    /// Get team with this [`TeamId`](types::TeamId) or name
+    ///
+    /// Fails unless exactly one of `id` or `name` is set.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::teams::get_teams::{
+    ///     GetTeamsRequest, GetTeamsRequestError,
+    /// };
+    /// let request = GetTeamsRequest::try_new(None::<&str>, Some("coolteam"));
+    /// assert!(request.is_ok());
+    /// let request = GetTeamsRequest::try_new(Some("6358"), Some("coolteam"));
+    /// assert_eq!(request, Err(GetTeamsRequestError::BothIdAndName));
+    /// ```
+    pub fn try_new(
+        id: Option<impl types::IntoCow<'a, types::TeamIdRef> + 'a>,
+        name: Option<impl Into<Cow<'a, str>>>,
+    ) -> Result<Self, GetTeamsRequestError> {
+        let req = Self {
+            id: id.map(|id| id.into_cow()),
+            name: name.map(Into::into),
+        };
+        req.validate()?;
+        Ok(req)
+    }
+
+    /// Exactly one of `id` or `name` must be set.
+    fn validate(&self) -> Result<(), GetTeamsRequestError> {
+        match (&self.id, &self.name) {
+            (None, None) => Err(GetTeamsRequestError::MissingIdOrName),
+            (Some(_), Some(_)) => Err(GetTeamsRequestError::BothIdAndName),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Invalid [`GetTeamsRequest`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum GetTeamsRequestError {
+    /// Neither `id` nor `name` is set
+    #[error("either `id` or `name` must be set")]
+    MissingIdOrName,
+    /// Both `id` and `name` are set
+    #[error("only one of `id` or `name` can be set")]
+    BothIdAndName,
 }
 
 /// Return Values for [Get Teams](super::get_teams)
@@ -94,6 +140,8 @@ impl Request for GetTeamsRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetTeamsRequest<'static>);
+
 impl RequestGet for GetTeamsRequest<'_> {}
 
 #[cfg(test)]
@@ -190,3 +238,23 @@ fn test_no_thumbnail() {
 
     dbg!(GetTeamsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_validate() {
+    assert!(GetTeamsRequest::id("6358").validate().is_ok());
+    assert!(GetTeamsRequest::name("weightedblanket").validate().is_ok());
+
+    assert_eq!(
+        GetTeamsRequest::try_new(Some("6358"), Some("weightedblanket")),
+        Err(GetTeamsRequestError::BothIdAndName)
+    );
+    assert_eq!(
+        GetTeamsRequest::try_new(None::<&str>, None::<&str>),
+        Err(GetTeamsRequestError::MissingIdOrName)
+    );
+    assert_eq!(
+        GetTeamsRequest::try_new(Some("6358"), None::<&str>),
+        Ok(GetTeamsRequest::id("6358"))
+    );
+}
@@ -128,6 +128,8 @@ impl Request for BlockUserRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::UserManageBlockedUsers];
 }
 
+endpoint_meta!(PUT BlockUserRequest<'static>);
+
 impl RequestPut for BlockUserRequest<'_> {
     type Body = helix::EmptyBody;
 
@@ -149,6 +151,7 @@ impl RequestPut for BlockUserRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
@@ -176,3 +179,9 @@ fn test_request() {
 
     dbg!(BlockUserRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: BlockUserRequest,
+    204 => b"",
+}
@@ -94,6 +94,8 @@ impl Request for GetUserBlockListRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::UserReadBlockedUsers];
 }
 
+endpoint_meta!(GET GetUserBlockListRequest<'static>);
+
 impl RequestGet for GetUserBlockListRequest<'_> {}
 
 impl helix::Paginated for GetUserBlockListRequest<'_> {
@@ -102,14 +104,9 @@ impl helix::Paginated for GetUserBlockListRequest<'_> {
     }
 }
 
+// From twitch docs // FIXME: twitch docs say id, not user_id
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetUserBlockListRequest::broadcaster_id("23161357");
-
-    // From twitch docs // FIXME: twitch docs say id, not user_id
-    let data = br#"
+const FIXTURE: &[u8] = br#"
 {
     "data": [
         {
@@ -124,8 +121,15 @@ fn test_request() {
         }
     ]
     }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetUserBlockListRequest::broadcaster_id("23161357");
+
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -137,3 +141,9 @@ fn test_request() {
 
     dbg!(GetUserBlockListRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetUserBlockListRequest,
+    200 => FIXTURE,
+}
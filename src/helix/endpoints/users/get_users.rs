@@ -3,11 +3,11 @@
 //!
 //! ## Request: [GetUsersRequest]
 //!
-//! To use this endpoint, construct a [`GetUsersRequest`] with the [`GetUsersRequest::logins()`], [`GetUsersRequest::ids()`] or [`GetUsersRequest::new()`] method.
+//! To use this endpoint, construct a [`GetUsersRequest`] with the [`GetUsersRequest::logins()`], [`GetUsersRequest::ids()`] or [`GetUsersRequest::ids_and_logins()`] method.
 //!
 //! ```rust
 //! use twitch_api::helix::users::get_users;
-//! let mut request = get_users::GetUsersRequest::new();
+//! let request = get_users::GetUsersRequest::logins(&["justintvfan"]);
 //! ```
 //!
 //! ## Response: [User]
@@ -24,9 +24,7 @@
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
 //! let ids: &[&types::UserIdRef] = &["1234".into()];
 //! let logins: &[&types::UserNameRef] = &["justintvfan".into()];
-//! let mut request = get_users::GetUsersRequest::new();
-//! request.login = logins.into();
-//! request.id = ids.into();
+//! let request = get_users::GetUsersRequest::ids_and_logins(ids, logins)?;
 //!
 //! let response: Vec<get_users::User> = client.req_get(request, &token).await?.data;
 //! # Ok(())
@@ -39,6 +37,18 @@
 use super::*;
 use helix::RequestGet;
 
+/// Maximum number of ids and logins combined in a [`GetUsersRequest`]
+pub const MAX_USERS: usize = 100;
+
+/// More than [`MAX_USERS`] ids and logins were given to [`GetUsersRequest::ids_and_logins`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{count} ids and logins were given, the maximum is 100")]
+#[non_exhaustive]
+pub struct TooManyUsersError {
+    /// Number of ids and logins given
+    pub count: usize,
+}
+
 /// Query Parameters for [Get Users](super::get_users)
 ///
 /// [`get-users`](https://dev.twitch.tv/docs/api/reference#get-users)
@@ -87,6 +97,28 @@ impl<'a> GetUsersRequest<'a> {
         }
     }
 
+    /// Get multiple users by their [`UserId`](types::UserId) and [`UserName`](types::UserName) in one request
+    ///
+    /// Fails if more than [`MAX_USERS`] ids and logins are given in total.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::users::get_users::GetUsersRequest;
+    /// GetUsersRequest::ids_and_logins(&["1234"], &["twitch", "justintv"])
+    ///     .unwrap();
+    /// ```
+    pub fn ids_and_logins(
+        ids: impl Into<types::Collection<'a, types::UserId>>,
+        logins: impl Into<types::Collection<'a, types::UserName>>,
+    ) -> Result<Self, TooManyUsersError> {
+        let id = ids.into();
+        let login = logins.into();
+        let count = id.len() + login.len();
+        if count > MAX_USERS {
+            return Err(TooManyUsersError { count });
+        }
+        Ok(Self { id, login })
+    }
+
     /// Returns an empty [`GetUsersRequest`]
     pub fn new() -> Self {
         Self {
@@ -103,8 +135,12 @@ impl<'a> GetUsersRequest<'a> {
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct User {
-    /// User’s broadcaster type: "partner", "affiliate", or "".
-    pub broadcaster_type: Option<types::BroadcasterType>,
+    /// User’s broadcaster type: "partner", "affiliate", or "" (returned as [`None`]).
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
+    pub broadcaster_type: Option<BroadcasterType>,
     /// Date when the user was created.
     pub created_at: types::Timestamp,
     /// User’s channel description.
@@ -112,6 +148,7 @@ pub struct User {
     /// User’s display name.
     pub display_name: types::DisplayName,
     /// User’s email address. Returned if the request includes the [`user:read:email` scope](twitch_oauth2::Scope::UserReadEmail).
+    #[serde(default)]
     pub email: Option<String>,
     /// User’s ID.
     pub id: types::UserId,
@@ -121,9 +158,13 @@ pub struct User {
     pub offline_image_url: Option<String>,
     /// URL of the user’s profile image.
     pub profile_image_url: Option<String>,
-    /// User’s type: "staff", "admin", "global_mod", or "".
-    #[serde(rename = "type")]
-    pub type_: Option<types::UserType>,
+    /// User’s type: "staff", "admin", "global_mod", or "" (returned as [`None`]).
+    #[serde(
+        rename = "type",
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
+    pub type_: Option<UserType>,
     #[deprecated(
         since = "0.7.0",
         note = "removed, see https://discuss.dev.twitch.tv/t/get-users-api-endpoint-view-count-deprecation/37777"
@@ -133,6 +174,98 @@ pub struct User {
     pub view_count: usize,
 }
 
+/// [Users](User) indexed by id and login, see [`HelixClient::get_users`](crate::helix::HelixClient::get_users)
+///
+/// Users that were not found are missing from both maps.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct UserLookup {
+    /// Users by their id
+    pub by_id: std::collections::HashMap<types::UserId, User>,
+    /// Users by their login, as returned by Twitch (lowercase)
+    pub by_login: std::collections::HashMap<types::UserName, User>,
+}
+
+impl std::iter::FromIterator<User> for UserLookup {
+    fn from_iter<I: IntoIterator<Item = User>>(iter: I) -> Self {
+        let mut lookup = Self::default();
+        for user in iter {
+            lookup.by_login.insert(user.login.clone(), user.clone());
+            lookup.by_id.insert(user.id.clone(), user);
+        }
+        lookup
+    }
+}
+
+/// Broadcaster type of a [`User`]
+///
+/// A user that is neither an affiliate nor a partner has no broadcaster type, see [`User::broadcaster_type`].
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum BroadcasterType {
+    /// An affiliate broadcaster
+    Affiliate,
+    /// A partner broadcaster
+    Partner,
+    /// A broadcaster type not known to this library
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl BroadcasterType {
+    /// Get the broadcaster type as it is returned by Twitch
+    pub fn as_str(&self) -> &str {
+        match self {
+            BroadcasterType::Affiliate => "affiliate",
+            BroadcasterType::Partner => "partner",
+            BroadcasterType::Other(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for BroadcasterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Type of a [`User`]
+///
+/// A normal user has no type, see [`User::type_`].
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum UserType {
+    /// A Twitch administrator
+    Admin,
+    /// A global moderator
+    GlobalMod,
+    /// A Twitch employee
+    Staff,
+    /// A user type not known to this library
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl UserType {
+    /// Get the user type as it is returned by Twitch
+    pub fn as_str(&self) -> &str {
+        match self {
+            UserType::Admin => "admin",
+            UserType::GlobalMod => "global_mod",
+            UserType::Staff => "staff",
+            UserType::Other(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for UserType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 impl Request for GetUsersRequest<'_> {
     type Response = Vec<User>;
 
@@ -141,10 +274,45 @@ impl Request for GetUsersRequest<'_> {
     const PATH: &'static str = "users";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+
+    /// The email is only returned for the user of the token, which is returned if no ids or logins are given
+    #[cfg(feature = "twitch_oauth2")]
+    fn opt_scopes(&self) -> &'static [twitch_oauth2::Scope] {
+        if self.id.len() + self.login.len() == 0 {
+            Self::OPT_SCOPE
+        } else {
+            &[]
+        }
+    }
 }
 
+endpoint_meta!(GET GetUsersRequest<'static>);
+
 impl RequestGet for GetUsersRequest<'_> {}
 
+// From twitch docs
+// FIXME: This is not valid anymore. Twitch....
+#[cfg(test)]
+const FIXTURE: &[u8] = br#"
+{
+    "data": [
+        {
+        "id": "141981764",
+        "login": "twitchdev",
+        "display_name": "TwitchDev",
+        "type": "",
+        "broadcaster_type": "partner",
+        "description": "Supporting third-party developers building Twitch integrations from chatbots to game integrations.",
+        "profile_image_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/8a6381c7-d0c0-4576-b179-38bd5ce1d6af-profile_image-300x300.png",
+        "offline_image_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/3f13ab61-ec78-4fe6-8481-8682cb3b0ac2-channel_offline_image-1920x1080.png",
+        "view_count": 5980557,
+        "email": "not-real@email.com",
+        "created_at": "2016-12-14T20:32:28.894263Z"
+        }
+    ]
+    }
+"#;
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -153,36 +321,207 @@ fn test_request() {
     let ids: &[&types::UserIdRef] = &["44322889".into()];
     let req = GetUsersRequest::ids(ids);
 
-    // From twitch docs
-    // FIXME: This is not valid anymore. Twitch....
-    let data = br#"
+    let data = FIXTURE.to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/users?id=44322889"
+    );
+
+    dbg!(GetUsersRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
+
+#[cfg(test)]
+const FIXTURE_USER_TYPES: &[u8] = br#"
 {
     "data": [
         {
         "id": "141981764",
         "login": "twitchdev",
         "display_name": "TwitchDev",
-        "type": "",
+        "type": "staff",
         "broadcaster_type": "partner",
         "description": "Supporting third-party developers building Twitch integrations from chatbots to game integrations.",
         "profile_image_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/8a6381c7-d0c0-4576-b179-38bd5ce1d6af-profile_image-300x300.png",
         "offline_image_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/3f13ab61-ec78-4fe6-8481-8682cb3b0ac2-channel_offline_image-1920x1080.png",
-        "view_count": 5980557,
         "email": "not-real@email.com",
-        "created_at": "2016-12-14T20:32:28.894263Z"
+        "created_at": "2016-12-14T20:32:28Z"
+        },
+        {
+        "id": "1234",
+        "login": "justinfan",
+        "display_name": "justinfan",
+        "type": "",
+        "broadcaster_type": "",
+        "description": "",
+        "profile_image_url": "",
+        "offline_image_url": "",
+        "created_at": "2016-12-14T20:32:28Z"
+        },
+        {
+        "id": "5678",
+        "login": "newtype",
+        "display_name": "NewType",
+        "type": "moderator_plus",
+        "broadcaster_type": "superstar",
+        "description": "",
+        "profile_image_url": "",
+        "offline_image_url": "",
+        "created_at": "2016-12-14T20:32:28Z"
         }
     ]
-    }
-"#
-        .to_vec();
+}
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request_user_types() {
+    use helix::*;
+
+    let ids: &[&types::UserIdRef] = &["141981764".into(), "1234".into(), "5678".into()];
+    let req = GetUsersRequest::ids(ids);
+
+    let data = FIXTURE_USER_TYPES.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
+    let uri = req.get_uri().unwrap();
+    let users = GetUsersRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+
+    assert_eq!(users[0].broadcaster_type, Some(BroadcasterType::Partner));
+    assert_eq!(users[0].type_, Some(UserType::Staff));
+    assert_eq!(users[0].email.as_deref(), Some("not-real@email.com"));
+    assert_eq!(users[0].created_at.as_str(), "2016-12-14T20:32:28Z");
+
+    assert_eq!(users[1].broadcaster_type, None);
+    assert_eq!(users[1].type_, None);
+    assert_eq!(users[1].email, None);
+
+    assert_eq!(
+        users[2].broadcaster_type,
+        Some(BroadcasterType::Other("superstar".to_owned()))
+    );
+    assert_eq!(
+        users[2].type_.as_ref().map(|t| t.to_string()).as_deref(),
+        Some("moderator_plus")
+    );
+    assert_eq!(BroadcasterType::Partner.to_string(), "partner");
+    assert_eq!(UserType::GlobalMod.as_str(), "global_mod");
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_ids_and_logins() {
+    use helix::*;
+    let req =
+        GetUsersRequest::ids_and_logins(&["44322889", "12826"], &["twitch", "justintv"]).unwrap();
+
     let uri = req.get_uri().unwrap();
     assert_eq!(
         uri.to_string(),
-        "https://api.twitch.tv/helix/users?id=44322889"
+        "https://api.twitch.tv/helix/users?id=44322889&id=12826&login=twitch&login=justintv"
     );
 
-    dbg!(GetUsersRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let ids = vec!["1"; 60];
+    let logins = vec!["a"; 41];
+    assert_eq!(
+        GetUsersRequest::ids_and_logins(&ids[..], &logins[..]).unwrap_err(),
+        TooManyUsersError { count: 101 }
+    );
+    assert!(GetUsersRequest::ids_and_logins(&ids[..], &logins[..40]).is_ok());
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_get_users_lookup() {
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "users",
+        200,
+        r#"{"data":[
+            {"id":"12826","login":"twitch","display_name":"Twitch","type":"","broadcaster_type":"partner","description":"","profile_image_url":"","offline_image_url":"","created_at":"2007-05-22T10:39:54Z"},
+            {"id":"44322889","login":"dallas","display_name":"dallas","type":"staff","broadcaster_type":"","description":"","profile_image_url":"","offline_image_url":"","created_at":"2013-06-03T19:12:02Z"}
+        ]}"#,
+    );
+    let token = crate::tests::user_token("justinfan", "1337");
+
+    let lookup = mock
+        .helix()
+        .get_users(
+            &["44322889", "404"][..].into(),
+            &["twitch", "missing"][..].into(),
+            &token,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        mock.requests()[0].1.query(),
+        Some("id=44322889&id=404&login=twitch&login=missing")
+    );
+    assert_eq!(lookup.by_id.len(), 2);
+    assert_eq!(lookup.by_login.len(), 2);
+    assert_eq!(
+        lookup.by_id[&types::UserId::from("44322889")]
+            .login
+            .as_str(),
+        "dallas"
+    );
+    assert_eq!(
+        lookup.by_login[&types::UserName::from("twitch")]
+            .id
+            .as_str(),
+        "12826"
+    );
+    assert!(!lookup.by_id.contains_key(&types::UserId::from("404")));
+    assert!(!lookup
+        .by_login
+        .contains_key(&types::UserName::from("missing")));
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_get_user_from_login_normalized() {
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "users",
+        200,
+        r#"{"data":[
+            {"id":"141981764","login":"twitchdev","display_name":"TwitchDev","type":"","broadcaster_type":"partner","description":"","profile_image_url":"","offline_image_url":"","created_at":"2016-12-14T20:32:28Z"}
+        ]}"#,
+    );
+    let token = crate::tests::user_token("justinfan", "1337");
+    let client = mock.helix();
+
+    let user = client
+        .get_user_from_login("@TwitchDev ", &token)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(mock.requests()[0].1.query(), Some("login=twitchdev"));
+    assert_eq!(user.id.as_str(), "141981764");
+    assert!(crate::extra::eq_ignore_case(
+        &user.login,
+        &user.display_name
+    ));
+
+    assert!(matches!(
+        client.get_user_from_login("twitch dev", &token).await,
+        Err(crate::helix::ClientExtError::Other(
+            crate::extra::InvalidLoginError::Character { character: ' ' }
+        ))
+    ));
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetUsersRequest,
+    200 => FIXTURE,
+    200 => FIXTURE_USER_TYPES,
 }
@@ -62,18 +62,28 @@ pub struct GetUsersFollowsRequest<'a> {
 
 impl<'a> GetUsersFollowsRequest<'a> {
     /// Get the broadcasters that `from_id` is following
+    ///
+    /// ```rust
+    /// use twitch_api::helix::users::GetUsersFollowsRequest;
+    /// let request = GetUsersFollowsRequest::following("1234");
+    /// ```
     pub fn following(from_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
         Self {
             from_id: Some(from_id.into_cow()),
-            ..Self::empty()
+            ..Self::unset()
         }
     }
 
     /// Get the followers of `to_id`
+    ///
+    /// ```rust
+    /// use twitch_api::helix::users::GetUsersFollowsRequest;
+    /// let request = GetUsersFollowsRequest::followers("1234");
+    /// ```
     pub fn followers(to_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
         Self {
             to_id: Some(to_id.into_cow()),
-            ..Self::empty()
+            ..Self::unset()
         }
     }
 
@@ -85,8 +95,32 @@ impl<'a> GetUsersFollowsRequest<'a> {
         Self {
             from_id: Some(user_id.into_cow()),
             to_id: Some(broadcaster_id.into_cow()),
-            ..Self::empty()
+            ..Self::unset()
+        }
+    }
+
+    /// Get the follow relationships between `to_id` and `from_id`
+    ///
+    /// Fails if neither `to_id` nor `from_id` is set.
+    pub fn try_new(
+        to_id: Option<impl types::IntoCow<'a, types::UserIdRef> + 'a>,
+        from_id: Option<impl types::IntoCow<'a, types::UserIdRef> + 'a>,
+    ) -> Result<Self, GetUsersFollowsRequestError> {
+        let req = Self {
+            to_id: to_id.map(|id| id.into_cow()),
+            from_id: from_id.map(|id| id.into_cow()),
+            ..Self::unset()
+        };
+        req.validate()?;
+        Ok(req)
+    }
+
+    /// At least one of `from_id` or `to_id` must be set.
+    fn validate(&self) -> Result<(), GetUsersFollowsRequestError> {
+        if self.from_id.is_none() && self.to_id.is_none() {
+            return Err(GetUsersFollowsRequestError::MissingId);
         }
+        Ok(())
     }
 
     /// Returns an empty [`GetUsersFollowsRequest`]
@@ -94,7 +128,12 @@ impl<'a> GetUsersFollowsRequest<'a> {
     /// # Notes
     ///
     /// This is not a valid request, it needs to be filled with other fields
-    pub fn empty() -> Self {
+    #[deprecated(
+        note = "this is not a valid request, use `GetUsersFollowsRequest::followers` or `GetUsersFollowsRequest::following` instead"
+    )]
+    pub fn empty() -> Self { Self::unset() }
+
+    fn unset() -> Self {
         Self {
             after: None,
             first: None,
@@ -104,6 +143,15 @@ impl<'a> GetUsersFollowsRequest<'a> {
     }
 }
 
+/// Invalid [`GetUsersFollowsRequest`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum GetUsersFollowsRequestError {
+    /// Neither `from_id` nor `to_id` is set
+    #[error("either `from_id` or `to_id` must be set")]
+    MissingId,
+}
+
 /// Return Values for [Get Users Follows](super::get_users_follows)
 ///
 /// [`get-users-follows`](https://dev.twitch.tv/docs/api/reference#get-users-follows)
@@ -146,9 +194,24 @@ pub struct FollowRelationship {
     pub to_login: types::UserName,
 }
 
+/// Twitch has removed [Get Users Follows](self) and the request could not be made with [Get Channel Followers](helix::channels::get_channel_followers) instead
+///
+/// Returned by the helpers that used Get Users Follows when Twitch responds with `410 Gone`
+/// and the request was not for the followers of a channel, or the token can't read them.
+#[cfg(feature = "client")]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "`users/follows` has been removed by Twitch: get the followers of a channel from `channels/followers` with a user token of the broadcaster or one of their moderators with the `moderator:read:followers` scope, and the channels a user follows from `channels/followed`"
+)]
+#[non_exhaustive]
+pub struct FollowsEndpointGone;
+
 impl Request for GetUsersFollowsRequest<'_> {
     type Response = UsersFollows;
 
+    const DEPRECATED: Option<&'static str> = Some(
+        "Get Users Follows has been removed, use Get Followed Channels or Get Channel Followers instead. See https://discuss.dev.twitch.tv/t/follows-endpoints-and-eventsub-subscription-type-are-now-available-in-open-beta/43322",
+    );
     #[cfg(feature = "twitch_oauth2")]
     const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[];
     const PATH: &'static str = "users/follows";
@@ -156,6 +219,8 @@ impl Request for GetUsersFollowsRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetUsersFollowsRequest<'static>);
+
 impl RequestGet for GetUsersFollowsRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -180,6 +245,7 @@ impl RequestGet for GetUsersFollowsRequest<'_> {
                 e,
                 uri.clone(),
                 status,
+                None,
             )
         })?;
         Ok(helix::Response::new(
@@ -201,13 +267,9 @@ impl helix::Paginated for GetUsersFollowsRequest<'_> {
     }
 }
 
+// From twitch docs
 #[cfg(test)]
-#[test]
-fn test_request() {
-    use helix::*;
-    let req = GetUsersFollowsRequest::followers("23161357");
-    // From twitch docs
-    let data = br#"
+const FIXTURE: &[u8] = br#"
 {
     "total": 12345,
     "data":
@@ -235,8 +297,14 @@ fn test_request() {
         "cursor": "eyJiIjpudWxsLCJhIjoiMTUwMzQ0MTc3NjQyNDQyMjAwMCJ9"
     }
 }
-"#
-    .to_vec();
+"#;
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetUsersFollowsRequest::followers("23161357");
+    let data = FIXTURE.to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -248,3 +316,141 @@ fn test_request() {
 
     dbg!(GetUsersFollowsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_validate() {
+    assert_eq!(
+        GetUsersFollowsRequest::unset().validate(),
+        Err(GetUsersFollowsRequestError::MissingId)
+    );
+    assert_eq!(
+        GetUsersFollowsRequest::try_new(None::<&str>, None::<&str>).unwrap_err(),
+        GetUsersFollowsRequestError::MissingId
+    );
+    assert!(GetUsersFollowsRequest::try_new(Some("1234"), None::<&str>).is_ok());
+    assert!(GetUsersFollowsRequest::try_new(None::<&str>, Some("1234")).is_ok());
+    assert!(GetUsersFollowsRequest::follows("1234", "4321")
+        .validate()
+        .is_ok());
+}
+
+#[cfg(all(test, feature = "client"))]
+#[test]
+fn test_get_follow_relationships_missing_id() {
+    let mock = crate::tests::MockClient::new();
+    let token = crate::tests::user_token("user", "1234");
+
+    let client = mock.helix();
+    let res = client.get_follow_relationships(
+        None::<&types::UserIdRef>,
+        None::<&types::UserIdRef>,
+        &token,
+    );
+    assert_eq!(res.err(), Some(GetUsersFollowsRequestError::MissingId));
+    assert!(mock.requests().is_empty());
+}
+
+#[cfg(all(test, feature = "client"))]
+mod gone_tests {
+    use super::*;
+    use futures::TryStreamExt;
+
+    const GONE: &str =
+        r#"{"error":"Gone","status":410,"message":"The API is no longer available"}"#;
+    const USER: &str = r#"{"data":[{"id":"1234","login":"broadcaster","display_name":"Broadcaster","type":"","broadcaster_type":"","description":"","profile_image_url":"","offline_image_url":"","view_count":0,"created_at":"2016-12-14T20:32:28.894263Z"}]}"#;
+
+    fn broadcaster_token() -> twitch_oauth2::UserToken {
+        crate::tests::user_token_with_scopes(
+            "broadcaster",
+            "1234",
+            vec![twitch_oauth2::Scope::ModeratorReadFollowers],
+        )
+    }
+
+    #[tokio::test]
+    async fn followers_fall_back_to_channel_followers() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("users/follows", 410, GONE)
+            .respond("users", 200, USER)
+            .respond(
+                "channels/followers",
+                200,
+                r#"{"total":1,"data":[{"user_id":"11111","user_name":"UserDisplayName","user_login":"userloginname","followed_at":"2022-05-24T22:22:08Z"}],"pagination":{}}"#,
+            );
+        let token = broadcaster_token();
+        let client = mock.helix();
+
+        let stream = client
+            .get_follow_relationships(Some("1234".into()), None, &token)
+            .unwrap();
+        let progress = stream.progress();
+        let follows: Vec<FollowRelationship> = stream.try_collect().await.unwrap();
+        assert_eq!(follows.len(), 1);
+        assert_eq!(follows[0].from_id.as_str(), "11111");
+        assert_eq!(follows[0].from_login.as_str(), "userloginname");
+        assert_eq!(follows[0].to_id.as_str(), "1234");
+        assert_eq!(follows[0].to_login.as_str(), "broadcaster");
+        assert_eq!(progress.total(), Some(1));
+        assert_eq!(progress.pages_fetched(), 1);
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[2].1.path(), "/helix/channels/followers");
+        assert_eq!(requests[2].1.query(), Some("broadcaster_id=1234&first=100"));
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn followers_gone_without_followers_access() {
+        let mock = crate::tests::MockClient::new();
+        let client = mock.helix();
+        let is_gone = |res: Result<Vec<FollowRelationship>, _>| {
+            matches!(res, Err(helix::ClientExtError::Other(FollowsEndpointGone)))
+        };
+
+        // the token is missing `moderator:read:followers`
+        mock.respond("users/follows", 410, GONE);
+        let token = crate::tests::user_token("broadcaster", "1234");
+        let res = client
+            .get_follow_relationships(Some("1234".into()), None, &token)
+            .unwrap()
+            .try_collect()
+            .await;
+        assert!(is_gone(res));
+        assert_eq!(mock.requests().len(), 1);
+
+        // the token is not of the broadcaster or a moderator, so only the total is returned
+        mock.respond("users/follows", 410, GONE)
+            .respond("users", 200, USER)
+            .respond(
+                "channels/followers",
+                200,
+                r#"{"total":8,"data":[],"pagination":{}}"#,
+            );
+        let token = broadcaster_token();
+        let res = client
+            .get_follow_relationships(Some("1234".into()), None, &token)
+            .unwrap()
+            .try_collect()
+            .await;
+        assert!(is_gone(res));
+
+        // the channels a user follows can't be requested from channels/followers
+        mock.respond("users/follows", 410, GONE);
+        let res = client
+            .get_follow_relationships(None, Some("1234".into()), &token)
+            .unwrap()
+            .try_collect()
+            .await;
+        assert!(is_gone(res));
+        assert_eq!(mock.requests().len(), 5);
+        assert_eq!(mock.pending(), 0);
+    }
+}
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: GetUsersFollowsRequest,
+    200 => FIXTURE,
+}
@@ -36,8 +36,15 @@ pub use block_user::{BlockUser, BlockUserRequest};
 #[doc(inline)]
 pub use get_user_block_list::{GetUserBlockListRequest, UserBlock};
 #[doc(inline)]
-pub use get_users::{GetUsersRequest, User};
+pub use get_users::{
+    BroadcasterType, GetUsersRequest, TooManyUsersError, User, UserLookup, UserType,
+};
+#[cfg(feature = "client")]
+#[doc(inline)]
+pub use get_users_follows::FollowsEndpointGone;
 #[doc(inline)]
-pub use get_users_follows::{FollowRelationship, GetUsersFollowsRequest, UsersFollows};
+pub use get_users_follows::{
+    FollowRelationship, GetUsersFollowsRequest, GetUsersFollowsRequestError, UsersFollows,
+};
 #[doc(inline)]
 pub use unblock_user::{UnblockUser, UnblockUserRequest};
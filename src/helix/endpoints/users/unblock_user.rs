@@ -79,6 +79,8 @@ impl Request for UnblockUserRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::UserManageBlockedUsers];
 }
 
+endpoint_meta!(DELETE UnblockUserRequest<'static>);
+
 impl RequestDelete for UnblockUserRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -98,6 +100,7 @@ impl RequestDelete for UnblockUserRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
@@ -123,3 +126,9 @@ fn test_request() {
 
     dbg!(UnblockUserRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+crate::tests::roundtrip_test! {
+    test_roundtrip: UnblockUserRequest,
+    204 => b"",
+}
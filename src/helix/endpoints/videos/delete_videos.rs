@@ -82,6 +82,8 @@ impl Request for DeleteVideosRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageVideos];
 }
 
+endpoint_meta!(DELETE DeleteVideosRequest<'static>);
+
 impl RequestDelete for DeleteVideosRequest<'_> {
     fn parse_inner_response(
         request: Option<Self>,
@@ -101,6 +103,7 @@ impl RequestDelete for DeleteVideosRequest<'_> {
                 response: response.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
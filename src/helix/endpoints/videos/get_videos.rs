@@ -113,6 +113,63 @@ impl<'a> GetVideosRequest<'a> {
             ..Self::default()
         }
     }
+
+    /// Get videos by IDs, by the user who owns them or by the game they are of
+    ///
+    /// Fails unless exactly one of `ids`, `user_id` or `game_id` is set.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::videos::{GetVideosRequest, GetVideosRequestError};
+    /// let request =
+    ///     GetVideosRequest::try_new(&[] as &[&str], Some("1234"), None::<&str>);
+    /// assert!(request.is_ok());
+    /// let request =
+    ///     GetVideosRequest::try_new(&[] as &[&str], None::<&str>, None::<&str>);
+    /// assert_eq!(request, Err(GetVideosRequestError::MissingFilter));
+    /// ```
+    pub fn try_new(
+        ids: impl Into<types::Collection<'a, types::VideoId>>,
+        user_id: Option<impl types::IntoCow<'a, types::UserIdRef> + 'a>,
+        game_id: Option<impl types::IntoCow<'a, types::CategoryIdRef> + 'a>,
+    ) -> Result<Self, GetVideosRequestError> {
+        let req = Self {
+            id: ids.into(),
+            user_id: user_id.map(|id| id.into_cow()),
+            game_id: game_id.map(|id| id.into_cow()),
+            ..Self::default()
+        };
+        req.validate()?;
+        Ok(req)
+    }
+
+    /// Exactly one of `id`, `user_id` or `game_id` must be set.
+    fn validate(&self) -> Result<(), GetVideosRequestError> {
+        let set = [
+            !self.id.is_empty(),
+            self.user_id.is_some(),
+            self.game_id.is_some(),
+        ]
+        .iter()
+        .filter(|set| **set)
+        .count();
+        match set {
+            0 => Err(GetVideosRequestError::MissingFilter),
+            1 => Ok(()),
+            _ => Err(GetVideosRequestError::MultipleFilters),
+        }
+    }
+}
+
+/// Invalid [`GetVideosRequest`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum GetVideosRequestError {
+    /// None of `id`, `user_id` or `game_id` is set
+    #[error("one of `id`, `user_id` or `game_id` must be set")]
+    MissingFilter,
+    /// More than one of `id`, `user_id` or `game_id` is set
+    #[error("only one of `id`, `user_id` or `game_id` can be set")]
+    MultipleFilters,
 }
 
 /// Return Values for [Get Videos](super::get_videos)
@@ -179,6 +236,8 @@ impl Request for GetVideosRequest<'_> {
     const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
 }
 
+endpoint_meta!(GET GetVideosRequest<'static>);
+
 impl RequestGet for GetVideosRequest<'_> {}
 
 impl helix::Paginated for GetVideosRequest<'_> {
@@ -237,3 +296,28 @@ fn test_request() {
 
     dbg!(GetVideosRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_validate() {
+    assert!(GetVideosRequest::ids(vec!["234482848"]).validate().is_ok());
+    assert!(GetVideosRequest::user_id("1234").validate().is_ok());
+    assert!(GetVideosRequest::game_id("1234").validate().is_ok());
+    assert_eq!(
+        GetVideosRequest::default().validate(),
+        Err(GetVideosRequestError::MissingFilter)
+    );
+
+    assert_eq!(
+        GetVideosRequest::try_new(&[] as &[&str], Some("1234"), Some("4321")),
+        Err(GetVideosRequestError::MultipleFilters)
+    );
+    assert_eq!(
+        GetVideosRequest::try_new(&["234482848"][..], None::<&str>, Some("4321")),
+        Err(GetVideosRequestError::MultipleFilters)
+    );
+    assert_eq!(
+        GetVideosRequest::try_new(&[] as &[&str], None::<&str>, Some("4321")),
+        Ok(GetVideosRequest::game_id("4321"))
+    );
+}
@@ -28,7 +28,7 @@ pub mod delete_videos;
 pub mod get_videos;
 
 #[doc(inline)]
-pub use get_videos::{GetVideosRequest, Video};
+pub use get_videos::{GetVideosRequest, GetVideosRequestError, Video};
 
 /// Sort order of the videos
 #[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
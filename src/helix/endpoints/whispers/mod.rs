@@ -11,4 +11,4 @@ use std::borrow::Cow;
 pub mod send_whisper;
 
 #[doc(inline)]
-pub use send_whisper::{SendWhisperBody, SendWhisperRequest, SendWhisperResponse};
+pub use send_whisper::{SendWhisperBody, SendWhisperRequest, SendWhisperResponse, WhisperOutcome};
@@ -122,6 +122,48 @@ pub enum SendWhisperResponse {
     Success,
 }
 
+/// Outcome of sending a whisper to a recipient with [`HelixClient::send_whispers`](helix::HelixClient::send_whispers)
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum WhisperOutcome<E> {
+    /// The whisper was sent, or silently dropped by Twitch
+    Sent,
+    /// The recipient's settings prevent the sender from whispering them
+    RecipientBlocked,
+    /// The sender hit a per-second or per-minute whisper rate limit, the next whisper is sent after `retry_after`
+    RateLimited {
+        /// Time to wait before retrying, from the `Retry-After` header
+        retry_after: Option<std::time::Duration>,
+    },
+    /// The sender reached the daily whisper limit or the daily limit of unique recipients, no more whispers were sent
+    DailyLimitReached,
+    /// The whisper was not sent because the [daily limit was reached](WhisperOutcome::DailyLimitReached) on an earlier whisper
+    NotSent,
+    /// Sending the whisper failed
+    Failed(E),
+}
+
+impl<E> WhisperOutcome<E> {
+    /// The whisper was sent
+    pub fn is_sent(&self) -> bool { matches!(self, WhisperOutcome::Sent) }
+}
+
+/// Twitch answers `403 Forbidden` with this message when the recipient doesn't accept whispers from the sender
+pub(crate) fn is_recipient_blocked(status: http::StatusCode, message: &str) -> bool {
+    status == http::StatusCode::FORBIDDEN
+        && message
+            .to_lowercase()
+            .starts_with("the recipient's settings prevent this sender from whispering them")
+}
+
+/// Twitch answers `429 Too Many Requests` with a message mentioning the day when a daily limit is reached
+pub(crate) fn is_daily_limit(message: Option<&str>) -> bool {
+    message.map_or(false, |message| {
+        let message = message.to_lowercase();
+        message.contains("per day") || message.contains("daily")
+    })
+}
+
 impl Request for SendWhisperRequest<'_> {
     type Response = SendWhisperResponse;
 
@@ -131,6 +173,8 @@ impl Request for SendWhisperRequest<'_> {
         twitch_oauth2::validator![twitch_oauth2::Scope::UserManageWhispers];
 }
 
+endpoint_meta!(POST SendWhisperRequest<'static>);
+
 impl<'a> RequestPost for SendWhisperRequest<'a> {
     type Body = SendWhisperBody<'a>;
 
@@ -153,6 +197,7 @@ impl<'a> RequestPost for SendWhisperRequest<'a> {
                 response: response_str.to_string(),
                 status,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
@@ -166,10 +211,7 @@ fn test_request() {
 
     let body = SendWhisperBody::new("hello");
 
-    assert_eq!(
-        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
-        r#"{"message":"hello"}"#
-    );
+    assert_eq!(body.body_json().unwrap(), r#"{"message":"hello"}"#);
 
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
@@ -186,3 +228,75 @@ fn test_request() {
 
     dbg!(SendWhisperRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_send_whispers() {
+    let mock = crate::tests::MockClient::new();
+    mock.respond("whispers", 204, "")
+        .respond(
+            "whispers",
+            403,
+            r#"{"error":"Forbidden","status":403,"message":"The recipient's settings prevent this sender from whispering them."}"#,
+        )
+        .respond(
+            "whispers",
+            403,
+            r#"{"error":"Forbidden","status":403,"message":"The sender does not have a verified phone number."}"#,
+        )
+        .respond_with_headers(
+            "whispers",
+            429,
+            &[("Retry-After", "5")],
+            r#"{"error":"Too Many Requests","status":429,"message":"You have exceeded the number of whispers per minute"}"#,
+        )
+        .respond("whispers", 204, "")
+        .respond(
+            "whispers",
+            429,
+            r#"{"error":"Too Many Requests","status":429,"message":"You have exceeded the number of whispers per day"}"#,
+        )
+        .respond("whispers", 204, "");
+    let token = crate::tests::user_token("sender", "1");
+    let sleeps = std::sync::Mutex::new(vec![]);
+
+    let outcomes = mock
+        .helix()
+        .send_whispers(
+            "1",
+            ["2", "3", "4", "5", "6", "7", "8", "9"]
+                .iter()
+                .map(|id| (types::UserId::from(*id), format!("hello {id}"))),
+            std::time::Duration::from_secs(1),
+            |delay| {
+                sleeps.lock().unwrap().push(delay.as_secs());
+                futures::future::ready(())
+            },
+            &token,
+        )
+        .await;
+
+    let outcomes: Vec<_> = outcomes
+        .iter()
+        .map(|(id, outcome)| (id.as_str(), outcome))
+        .collect();
+    assert!(matches!(
+        outcomes[..],
+        [
+            ("2", WhisperOutcome::Sent),
+            ("3", WhisperOutcome::RecipientBlocked),
+            ("4", WhisperOutcome::Failed(_)),
+            ("5", WhisperOutcome::RateLimited { retry_after: Some(d) }),
+            ("6", WhisperOutcome::Sent),
+            ("7", WhisperOutcome::DailyLimitReached),
+            ("8", WhisperOutcome::NotSent),
+            ("9", WhisperOutcome::NotSent),
+        ] if d == std::time::Duration::from_secs(5)
+    ));
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 6);
+    assert_eq!(requests[5].1.query(), Some("from_user_id=1&to_user_id=7"));
+    assert_eq!(&requests[5].2[..], br#"{"message":"hello 7"}"#);
+    assert_eq!(*sleeps.lock().unwrap(), [1, 1, 1, 5, 1]);
+    assert_eq!(mock.pending(), 1);
+}
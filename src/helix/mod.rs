@@ -30,6 +30,40 @@
 //! # }
 //! ```
 //!
+//! ### Manual Pagination
+//!
+//! The helpers on [`HelixClient`] return a stream that gets the next page when it's read.
+//! To get pages one at a time instead, for example when a user asks for the next page, send the request from [`Response::next_request`].
+//! It doesn't consume the response, so the same page can be requested again if sending it fails.
+//!
+//! ```rust, no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! use twitch_api::helix::{self, streams::GetStreamsRequest};
+//! # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! # fn next_page_clicked() -> bool { true }
+//!
+//! let mut request = GetStreamsRequest::default().first(20);
+//! loop {
+//!     let page = match client.req_get(request.clone(), &token).await {
+//!         Ok(page) => page,
+//!         // sending the same request gets the same page
+//!         Err(e) if e.phase() == helix::Phase::Transport => continue,
+//!         Err(e) => return Err(e.into()),
+//!     };
+//!     for stream in &page.data {
+//!         println!("{} is live with {} viewers", stream.user_name, stream.viewer_count);
+//!     }
+//!     match page.next_request() {
+//!         Some(next) if next_page_clicked() => request = next,
+//!         _ => break,
+//!     }
+//! }
+//! # Ok(()) }
+//! ```
+//!
 //! ## Implemented Endpoints
 //!
 //! <!-- generate with "cargo xtask overview" (with a nightly toolchain) -->
@@ -109,7 +143,7 @@
 //!
 //! | Endpoint | Helper | Module |
 //! |---|---|---|
-//! | [Get Chatters](https://dev.twitch.tv/docs/api/reference#get-chatters) | [`HelixClient::get_chatters`] | [`chat::get_chatters`] |
+//! | [Get Chatters](https://dev.twitch.tv/docs/api/reference#get-chatters) | [`HelixClient::get_chatters`], [`HelixClient::get_chatter_logins`] | [`chat::get_chatters`] |
 //! | [Get Channel Emotes](https://dev.twitch.tv/docs/api/reference#get-channel-emotes) | - | [`chat::get_channel_emotes`] |
 //! | [Get Global Emotes](https://dev.twitch.tv/docs/api/reference#get-global-emotes) | [`HelixClient::get_global_emotes`] | [`chat::get_global_emotes`] |
 //! | [Get Emote Sets](https://dev.twitch.tv/docs/api/reference#get-emote-sets) | [`HelixClient::get_emote_sets`] | [`chat::get_emote_sets`] |
@@ -121,8 +155,8 @@
 //! | [Update Chat Settings](https://dev.twitch.tv/docs/api/reference#update-chat-settings) | - | [`chat::update_chat_settings`] |
 //! | [Send Chat Announcement](https://dev.twitch.tv/docs/api/reference#send-chat-announcement) | [`HelixClient::send_chat_announcement`] | [`chat::send_chat_announcement`] |
 //! | [Send a Shoutout](https://dev.twitch.tv/docs/api/reference#send-a-shoutout) | - | [`chat::send_a_shoutout`] |
-//! | [Send Chat Message](https://dev.twitch.tv/docs/api/reference#send-chat-message) | - | [`chat::send_chat_message`] |
-//! | [Get User Chat Color](https://dev.twitch.tv/docs/api/reference#get-user-chat-color) | [`HelixClient::get_user_chat_color`] | [`chat::get_user_chat_color`] |
+//! | [Send Chat Message](https://dev.twitch.tv/docs/api/reference#send-chat-message) | [`HelixClient::send_chat_message`] | [`chat::send_chat_message`] |
+//! | [Get User Chat Color](https://dev.twitch.tv/docs/api/reference#get-user-chat-color) | [`HelixClient::get_user_chat_color`], [`HelixClient::get_users_chat_colors_map`] | [`chat::get_user_chat_color`] |
 //! | [Update User Chat Color](https://dev.twitch.tv/docs/api/reference#update-user-chat-color) | [`HelixClient::update_user_chat_color`] | [`chat::update_user_chat_color`] |
 //!
 //! </details>
@@ -285,7 +319,7 @@
 //!
 //! | Endpoint | Helper | Module |
 //! |---|---|---|
-//! | [Start a raid](https://dev.twitch.tv/docs/api/reference#start-a-raid) | [`HelixClient::start_a_raid`] | [`raids::start_a_raid`] |
+//! | [Start a raid](https://dev.twitch.tv/docs/api/reference#start-a-raid) | [`HelixClient::start_a_raid`], [`HelixClient::start_raid_checked`] | [`raids::start_a_raid`] |
 //! | [Cancel a raid](https://dev.twitch.tv/docs/api/reference#cancel-a-raid) | [`HelixClient::cancel_a_raid`] | [`raids::cancel_a_raid`] |
 //!
 //! </details>
@@ -312,14 +346,14 @@
 //!
 //! </details>
 //!
-//! <details><summary style="cursor: pointer">Streams 🟡 2/5</summary>
+//! <details><summary style="cursor: pointer">Streams 🟡 3/5</summary>
 //!
 //! | Endpoint | Helper | Module |
 //! |---|---|---|
 //! | [Get Stream Key](https://dev.twitch.tv/docs/api/reference#get-stream-key) | - | - |
 //! | [Get Streams](https://dev.twitch.tv/docs/api/reference#get-streams) | - | [`streams::get_streams`] |
 //! | [Get Followed Streams](https://dev.twitch.tv/docs/api/reference#get-followed-streams) | [`HelixClient::get_followed_streams`] | [`streams::get_followed_streams`] |
-//! | [Create Stream Marker](https://dev.twitch.tv/docs/api/reference#create-stream-marker) | - | - |
+//! | [Create Stream Marker](https://dev.twitch.tv/docs/api/reference#create-stream-marker) | [`HelixClient::create_stream_marker_if_live`], [`HelixClient::mark_and_announce`] | [`streams::create_stream_marker`] |
 //! | [Get Stream Markers](https://dev.twitch.tv/docs/api/reference#get-stream-markers) | - | - |
 //!
 //! </details>
@@ -392,28 +426,389 @@ pub use twitch_oauth2::Scope;
 #[cfg(feature = "twitch_oauth2")]
 use twitch_oauth2::TwitchToken;
 
+/// Define a [`HelixClient`] helper next to the endpoint it uses.
+///
+/// The helper gets `&'client self` as the first and `token` as the last argument, with `T` being the token type.
+/// The generated method is only available with the `client` feature.
+///
+/// A paginated request is made into a [`PaginatedStream`] with [`make_stream`].
+/// The block evaluates to the request.
+/// `-> Stream<Item>, E = (map)` turns errors of the stream into a `ClientExtError<C, E>`, with `map` like below.
+///
+/// ```ignore
+/// helix_helper! {
+///     /// Get all moderators in a channel
+///     pub fn get_moderators_in_channel_from_id<'b: 'client, T>(
+///         broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + 'b,
+///         batch_size: impl Into<Option<usize>>,
+///     ) -> Stream<helix::moderation::Moderator> {
+///         helix::moderation::GetModeratorsRequest::broadcaster_id(broadcaster_id)
+///             .first(batch_size.into().unwrap_or(100))
+///     }
+/// }
+/// ```
+///
+/// A single request is sent with the given `req_*` method of [`HelixClient`], returning the data of the response.
+/// The first block evaluates to the request, the second block to the body if the method takes one.
+///
+/// `-> Data` returns a `Result<Data, ClientRequestError<_>>`.
+/// `-> Data, E` returns a `Result<Data, ClientExtError<C, E>>`, allowing the blocks to return an `E` with `?`.
+/// `-> Data, E = req_*(map)` additionally turns errors of the request into an `E` where `map` returns `Ok`,
+/// with `map` being a `fn(ClientRequestError<_>) -> Result<E, ClientRequestError<_>>`.
+///
+/// ```ignore
+/// helix_helper! {
+///     /// Send a whisper
+///     pub async fn send_whisper<'b, T>(
+///         from: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+///         to: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+///         message: impl Into<&'b str> + Send,
+///     ) -> helix::whispers::SendWhisperResponse = req_post {
+///         helix::whispers::SendWhisperRequest::new(from, to)
+///     } {
+///         helix::whispers::SendWhisperBody::new(message.into())
+///     }
+/// }
+/// ```
+///
+/// An `# Errors` section describing the error type is appended to the doc comment of these helpers.
+///
+/// A helper that doesn't fit these shapes takes `&self` like a method and spells out its arguments, including `token`,
+/// and its return type. The block is the body of the method.
+///
+/// ```ignore
+/// helix_helper! {
+///     /// Get a user's chat color
+///     pub async fn get_user_chat_color<T>(
+///         &self,
+///         user_id: impl Into<&types::UserIdRef> + Send,
+///         token: &T,
+///     ) -> Result<Option<helix::chat::UserChatColor>, helix::ClientRequestError<<C as crate::HttpClient>::Error>> {
+///         // ...
+///     }
+/// }
+/// ```
+///
+/// A helper of [`BoundHelixClient`] forwards to the [`HelixClient`] helper after `client.`.
+/// In the arguments of the call, `ctx`, `broadcaster` and `user` are the [`ModerationContext`](moderation::ModerationContext)
+/// and the ids of the bound client, the token is added as the last argument.
+/// These are defined in `helix/client/bound.rs`, where the fields of the bound client are visible.
+///
+/// ```ignore
+/// helix_helper! {
+///     /// Send a message in the chat of the broadcaster
+///     pub async fn send_chat_message<'b>(
+///         message: impl Into<&'b str> + Send,
+///     ) -> Result<helix::chat::SendChatMessageResponse, ClientExtError<C, helix::chat::MessageTooLongError>>
+///         = client.send_chat_message(broadcaster, user, message);
+/// }
+/// ```
+macro_rules! helix_helper {
+    (
+        $(#[$meta:meta])*
+        $vis:vis async fn $name:ident<$($lt:lifetime $(: $bound:lifetime)?,)* T $(, $gen:ident)*>(
+            $($arg:ident: $arg_ty:ty),* $(,)?
+        ) -> $ret:ty, $err:ty = $method:ident($map:path) $request:block $($body:block)?
+    ) => {
+        #[cfg(feature = "client")]
+        #[warn(clippy::future_not_send)]
+        impl<'client, C: $crate::HttpClient + Sync + 'client> $crate::helix::HelixClient<'client, C> {
+            $(#[$meta])*
+            #[doc = ""]
+            #[doc = "# Errors"]
+            #[doc = ""]
+            #[doc = helix_helper!(@errors mapped $err)]
+            $vis async fn $name<$($lt $(: $bound)?,)* T $(, $gen)*>(
+                &'client self,
+                $($arg: $arg_ty,)*
+                token: &T,
+            ) -> helix_helper!(@result $ret, $err)
+            where
+                T: twitch_oauth2::TwitchToken + Send + Sync + ?Sized,
+            {
+                Ok(self
+                    .$method($request, $($body,)? token)
+                    .await
+                    .map_err(|e| match $map(e) {
+                        Ok(e) => $crate::helix::ClientExtError::Other(e),
+                        Err(e) => $crate::helix::ClientExtError::ClientError(e),
+                    })?
+                    .data)
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis async fn $name:ident<$($lt:lifetime $(: $bound:lifetime)?,)* T $(, $gen:ident)*>(
+            $($arg:ident: $arg_ty:ty),* $(,)?
+        ) -> $ret:ty $(, $err:ty)? = $method:ident $request:block $($body:block)?
+    ) => {
+        #[cfg(feature = "client")]
+        #[warn(clippy::future_not_send)]
+        impl<'client, C: $crate::HttpClient + Sync + 'client> $crate::helix::HelixClient<'client, C> {
+            $(#[$meta])*
+            #[doc = ""]
+            #[doc = "# Errors"]
+            #[doc = ""]
+            #[doc = helix_helper!(@errors $($err)?)]
+            $vis async fn $name<$($lt $(: $bound)?,)* T $(, $gen)*>(
+                &'client self,
+                $($arg: $arg_ty,)*
+                token: &T,
+            ) -> helix_helper!(@result $ret $(, $err)?)
+            where
+                T: twitch_oauth2::TwitchToken + Send + Sync + ?Sized,
+            {
+                Ok(helix_helper!(@send self.$method($request, $($body,)? token) $(, $err)?).data)
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident<$($lt:lifetime $(: $bound:lifetime)?,)* T>(
+            $($arg:ident: $arg_ty:ty),* $(,)?
+        ) -> Stream<$item:ty>, $err:ty = ($map:path) $request:block
+    ) => {
+        #[cfg(feature = "client")]
+        impl<'client, C: $crate::HttpClient + Sync + 'client> $crate::helix::HelixClient<'client, C> {
+            $(#[$meta])*
+            #[doc = ""]
+            #[doc = "# Errors"]
+            #[doc = ""]
+            #[doc = helix_helper!(@errors mapped $err)]
+            $vis fn $name<$($lt $(: $bound)?,)* T>(
+                &'client self,
+                $($arg: $arg_ty,)*
+                token: &'client T,
+            ) -> $crate::helix::PaginatedStream<'client, $item, $crate::helix::ClientExtError<C, $err>>
+            where
+                T: twitch_oauth2::TwitchToken + Send + Sync + ?Sized,
+            {
+                $crate::helix::make_list_stream($request, token, self)
+                    .map_stream(|stream| {
+                        futures::TryStreamExt::map_err(stream, |e| match $map(e) {
+                            Ok(e) => $crate::helix::ClientExtError::Other(e),
+                            Err(e) => $crate::helix::ClientExtError::ClientError(e),
+                        })
+                    })
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident<$($lt:lifetime $(: $bound:lifetime)?,)* T>(
+            $($arg:ident: $arg_ty:ty),* $(,)?
+        ) -> Stream<$item:ty> $request:block
+    ) => {
+        #[cfg(feature = "client")]
+        impl<'client, C: $crate::HttpClient + Sync + 'client> $crate::helix::HelixClient<'client, C> {
+            $(#[$meta])*
+            #[doc = ""]
+            #[doc = "# Errors"]
+            #[doc = ""]
+            #[doc = helix_helper!(@errors)]
+            $vis fn $name<$($lt $(: $bound)?,)* T>(
+                &'client self,
+                $($arg: $arg_ty,)*
+                token: &'client T,
+            ) -> $crate::helix::PaginatedStream<'client, $item, $crate::helix::ClientRequestError<<C as $crate::HttpClient>::Error>>
+            where
+                T: twitch_oauth2::TwitchToken + Send + Sync + ?Sized,
+            {
+                $crate::helix::make_list_stream($request, token, self)
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis async fn $name:ident<$($lt:lifetime $(: $bound:lifetime)?,)* T $(, $gen:ident)*>(
+            &$this:ident,
+            $($arg:ident: $arg_ty:ty),* $(,)?
+        ) -> $ret:ty $body:block
+    ) => {
+        #[cfg(feature = "client")]
+        #[warn(clippy::future_not_send)]
+        impl<'client, C: $crate::HttpClient + Sync + 'client> $crate::helix::HelixClient<'client, C> {
+            $(#[$meta])*
+            $vis async fn $name<$($lt $(: $bound)?,)* T $(, $gen)*>(
+                &'client $this,
+                $($arg: $arg_ty,)*
+            ) -> $ret
+            where
+                T: twitch_oauth2::TwitchToken + Send + Sync + ?Sized,
+            $body
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident<$($lt:lifetime $(: $bound:lifetime)?,)* T>(
+            &$this:ident,
+            $($arg:ident: $arg_ty:ty),* $(,)?
+        ) -> $ret:ty $body:block
+    ) => {
+        #[cfg(feature = "client")]
+        impl<'client, C: $crate::HttpClient + Sync + 'client> $crate::helix::HelixClient<'client, C> {
+            $(#[$meta])*
+            $vis fn $name<$($lt $(: $bound)?,)* T>(
+                &'client $this,
+                $($arg: $arg_ty,)*
+            ) -> $ret
+            where
+                T: twitch_oauth2::TwitchToken + Send + Sync + ?Sized,
+            $body
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis async fn $name:ident<$lt:lifetime $(, $gen:ident)*>(
+            $($arg:ident: $arg_ty:ty),* $(,)?
+        ) -> $ret:ty = client.$target:ident($($call:tt)*);
+    ) => {
+        impl<'a, C, T> $crate::helix::BoundHelixClient<'a, C, T>
+        where
+            C: $crate::HttpClient + Sync + 'a,
+            T: twitch_oauth2::TwitchToken + Send + Sync + ?Sized,
+        {
+            $(#[$meta])*
+            $vis async fn $name<$lt $(, $gen)*>(&$lt self, $($arg: $arg_ty),*) -> $ret {
+                helix_helper!(@bound self $target [] $($call)*).await
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident<$lt:lifetime $(, $gen:ident)*>(
+            $($arg:ident: $arg_ty:ty),* $(,)?
+        ) -> $ret:ty = client.$target:ident($($call:tt)*);
+    ) => {
+        impl<'a, C, T> $crate::helix::BoundHelixClient<'a, C, T>
+        where
+            C: $crate::HttpClient + Sync + 'a,
+            T: twitch_oauth2::TwitchToken + Send + Sync + ?Sized,
+        {
+            $(#[$meta])*
+            $vis fn $name<$lt $(, $gen)*>(&$lt self, $($arg: $arg_ty),*) -> $ret {
+                helix_helper!(@bound self $target [] $($call)*)
+            }
+        }
+    };
+    (@bound $self:ident $target:ident [$($done:expr,)*] ctx $(, $($rest:tt)*)?) => {
+        helix_helper!(@bound $self $target [$($done,)* &$self.ctx,] $($($rest)*)?)
+    };
+    (@bound $self:ident $target:ident [$($done:expr,)*] broadcaster $(, $($rest:tt)*)?) => {
+        helix_helper!(@bound $self $target [$($done,)* &*$self.ctx.broadcaster_id,] $($($rest)*)?)
+    };
+    (@bound $self:ident $target:ident [$($done:expr,)*] user $(, $($rest:tt)*)?) => {
+        helix_helper!(@bound $self $target [$($done,)* &*$self.ctx.moderator_id,] $($($rest)*)?)
+    };
+    (@bound $self:ident $target:ident [$($done:expr,)*] $arg:ident $(, $($rest:tt)*)?) => {
+        helix_helper!(@bound $self $target [$($done,)* $arg,] $($($rest)*)?)
+    };
+    (@bound $self:ident $target:ident [$($done:expr,)*]) => {
+        $self.client.$target($($done,)* $self.token)
+    };
+    (@result $ret:ty) => {
+        Result<$ret, $crate::helix::ClientRequestError<<C as $crate::HttpClient>::Error>>
+    };
+    (@result $ret:ty, $err:ty) => {
+        Result<$ret, $crate::helix::ClientExtError<C, $err>>
+    };
+    (@errors mapped $err:ty) => {
+        concat!(
+            "Fails with [`ClientExtError::Other`](crate::helix::ClientExtError::Other) if Twitch returns an error described by [`",
+            stringify!($err),
+            "`], or with [`ClientExtError::ClientError`](crate::helix::ClientExtError::ClientError) for any other failed request."
+        )
+    };
+    (@errors) => {
+        "Fails with a [`ClientRequestError`](crate::helix::ClientRequestError) if a request fails."
+    };
+    (@errors $err:ty) => {
+        concat!(
+            "Fails with [`ClientExtError::Other`](crate::helix::ClientExtError::Other) if the request can't be made, see [`",
+            stringify!($err),
+            "`], or with [`ClientExtError::ClientError`](crate::helix::ClientExtError::ClientError) if a request fails."
+        )
+    };
+    (@send $response:expr) => {
+        $response.await?
+    };
+    (@send $response:expr, $err:ty) => {
+        $response
+            .await
+            .map_err($crate::helix::ClientExtError::ClientError)?
+    };
+}
+
+/// Describe the [`Request`] of an endpoint module for [`ENDPOINTS`]
+///
+/// Defines `ENDPOINT` in the invoking module and checks that the request implements the trait for the given method.
+/// Separate scopes can be given for requests whose scopes depend on a generic parameter.
+///
+/// ```rust, ignore
+/// endpoint_meta!(GET GetUsersRequest<'static>);
+/// endpoint_meta!(POST CreateEventSubSubscriptionRequest<ChannelUpdateV2>, scopes = twitch_oauth2::validator![]);
+/// ```
+macro_rules! endpoint_meta {
+    (GET $($rest:tt)*) => { endpoint_meta!(@meta GET RequestGet $($rest)*); };
+    (POST $($rest:tt)*) => { endpoint_meta!(@meta POST RequestPost $($rest)*); };
+    (PATCH $($rest:tt)*) => { endpoint_meta!(@meta PATCH RequestPatch $($rest)*); };
+    (PUT $($rest:tt)*) => { endpoint_meta!(@meta PUT RequestPut $($rest)*); };
+    (DELETE $($rest:tt)*) => { endpoint_meta!(@meta DELETE RequestDelete $($rest)*); };
+    (@meta $method:ident $trait:ident $request:ty) => {
+        endpoint_meta!(@meta $method $trait $request, scopes = <$request as $crate::helix::Request>::SCOPE);
+    };
+    (@meta $method:ident $trait:ident $request:ty, scopes = $scopes:expr) => {
+        pub(crate) const ENDPOINT: $crate::helix::EndpointMeta = $crate::helix::EndpointMeta {
+            path: <$request as $crate::helix::Request>::PATH,
+            method: http::Method::$method,
+            #[cfg(feature = "twitch_oauth2")]
+            scopes: $scopes,
+            module_path: module_path!(),
+            deprecated: <$request as $crate::helix::Request>::DEPRECATED,
+        };
+
+        const _: fn() = || {
+            fn uses_method<R: $crate::helix::$trait>() {}
+            uses_method::<$request>();
+        };
+    };
+}
+
 #[cfg(feature = "client")]
 pub mod client;
 mod endpoints;
 pub mod request;
 pub mod response;
 
+#[cfg(feature = "client")]
+pub(crate) use client::client_ext::make_list_stream;
 #[cfg(feature = "client")]
 #[doc(inline)]
-pub use client::{client_ext::make_stream, *};
+pub use client::{
+    client_ext::{drain_to_sink, make_stream, DrainError, PaginatedStream, PaginationProgress},
+    *,
+};
 pub use endpoints::*;
 #[cfg(feature = "client")]
 #[doc(inline)]
-pub use request::errors::ClientRequestError;
+pub use request::errors::{ClientRequestError, Phase};
 #[doc(inline)]
 pub use request::errors::{
-    CreateRequestError, HelixRequestDeleteError, HelixRequestGetError, HelixRequestPatchError,
-    HelixRequestPostError, HelixRequestPutError, InvalidUri, SerializeError,
+    CreateRequestError, HelixApiError, HelixRequestDeleteError, HelixRequestGetError,
+    HelixRequestPatchError, HelixRequestPostError, HelixRequestPutError, InvalidUri,
+    SerializeError,
 };
 #[doc(inline)]
-pub use request::{Request, RequestDelete, RequestGet, RequestPatch, RequestPost, RequestPut};
+pub use request::{
+    EndpointInfo, EndpointMeta, IdempotentPost, PageSize, PageSizeError, Request, RequestDelete,
+    RequestGet, RequestGetBorrowed, RequestPatch, RequestPost, RequestPut, TokenBoundRequest,
+    SLOW_ENDPOINT_TIMEOUT,
+};
 #[doc(inline)]
-pub use response::Response;
+pub use response::{
+    BorrowedData, BorrowedResponse, CacheValidator, Conditional, Response, SingleItemError,
+};
 
 pub(crate) mod ser;
 pub(crate) use crate::deserialize_default_from_null;
@@ -525,6 +920,12 @@ pub trait Paginated: Request {
     ///
     /// Pass [`Option::None`] if no cursor is found.
     fn set_pagination(&mut self, cursor: Option<Cursor>);
+
+    /// Set the amount of items to request per page, clamped to what the endpoint allows.
+    ///
+    /// Streams with an expected amount of items use this to size their pages.
+    /// Requests that can't change their page size ignore this, which is the default.
+    fn set_first(&mut self, first: usize) { let _ = first; }
 }
 
 /// A cursor for pagination. This is needed because of how pagination is represented in the [New Twitch API](https://dev.twitch.tv/docs/api)
@@ -561,21 +962,185 @@ pub enum BodyError {
 }
 
 /// Create a body. Used for specializing request bodies
+///
+/// Use [`body_json`](HelixRequestBody::body_json) to see the body that will be sent, e.g. in tests for how a request is constructed.
+///
+/// ```rust
+/// use twitch_api::helix::{self, HelixRequestBody};
+///
+/// let body = helix::moderation::BanUserBody::new("9876", "no reason", 300);
+/// assert_eq!(
+///     body.body_json().unwrap(),
+///     r#"{"data":{"user_id":"9876","duration":300,"reason":"no reason"}}"#
+/// );
+/// ```
+///
+/// # Bodies of your own requests
+///
+/// Requests defined outside this crate can use [`EmptyBody`] for endpoints without a body,
+/// and wrap anything that is [`Serialize`](serde::Serialize) in a [`JsonBody`] to send it as json.
+/// For other bodies, implement this trait on your own type.
+///
+/// ```rust
+/// use twitch_api::helix::{self, HelixRequestBody, Request, RequestPost};
+///
+/// /// A POST to `custom/endpoint?broadcaster_id=<id>`
+/// #[derive(serde_derive::Serialize, Debug, PartialEq)]
+/// pub struct CustomRequest {
+///     broadcaster_id: String,
+/// }
+///
+/// impl Request for CustomRequest {
+///     type Response = serde_json::Value;
+///
+///     const PATH: &'static str = "custom/endpoint";
+///     const SCOPE: twitch_api::twitch_oauth2::Validator =
+///         twitch_api::twitch_oauth2::validator![];
+/// }
+///
+/// impl RequestPost for CustomRequest {
+///     // or `helix::EmptyBody` if the endpoint takes no body
+///     type Body = helix::JsonBody<Payload>;
+///
+///     fn parse_inner_response(
+///         request: Option<Self>,
+///         uri: &http::Uri,
+///         response: &str,
+///         status: http::StatusCode,
+///     ) -> Result<
+///         helix::Response<Self, Self::Response>,
+///         helix::HelixRequestPostError,
+///     > {
+///         serde_json::from_str(response)
+///             .map(|data| helix::Response::with_data(data, request))
+///             .map_err(|_| helix::HelixRequestPostError::InvalidResponse {
+///                 reason: "response is not json",
+///                 response: response.to_owned(),
+///                 status,
+///                 uri: uri.clone(),
+///                 request_id: None,
+///             })
+///     }
+/// }
+///
+/// #[derive(serde_derive::Serialize)]
+/// pub struct Payload {
+///     message: String,
+/// }
+///
+/// let request = CustomRequest {
+///     broadcaster_id: "1234".to_owned(),
+/// };
+/// let body = helix::JsonBody(Payload {
+///     message: "hello".to_owned(),
+/// });
+/// let http_request = request.create_request(body, "token", "clientid")?;
+/// assert_eq!(
+///     http_request.uri(),
+///     "https://api.twitch.tv/helix/custom/endpoint?broadcaster_id=1234"
+/// );
+/// assert_eq!(&http_request.body()[..], br#"{"message":"hello"}"#);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// A POST without a body uses [`EmptyBody`], the request is sent with nothing in it.
+///
+/// ```rust
+/// use twitch_api::helix::{self, Request, RequestPost};
+///
+/// /// A POST to `custom/empty?broadcaster_id=<id>`
+/// #[derive(serde_derive::Serialize, Debug, PartialEq)]
+/// pub struct EmptyRequest {
+///     broadcaster_id: String,
+/// }
+///
+/// impl Request for EmptyRequest {
+///     type Response = ();
+///
+///     const PATH: &'static str = "custom/empty";
+///     const SCOPE: twitch_api::twitch_oauth2::Validator =
+///         twitch_api::twitch_oauth2::validator![];
+/// }
+///
+/// impl RequestPost for EmptyRequest {
+///     type Body = helix::EmptyBody;
+///
+///     fn parse_inner_response(
+///         request: Option<Self>,
+///         _uri: &http::Uri,
+///         _response: &str,
+///         _status: http::StatusCode,
+///     ) -> Result<
+///         helix::Response<Self, Self::Response>,
+///         helix::HelixRequestPostError,
+///     > {
+///         Ok(helix::Response::with_data((), request))
+///     }
+/// }
+///
+/// let request = EmptyRequest {
+///     broadcaster_id: "1234".to_owned(),
+/// };
+/// let http_request = request.create_request(helix::EmptyBody, "token", "clientid")?;
+/// assert_eq!(
+///     http_request.uri(),
+///     "https://api.twitch.tv/helix/custom/empty?broadcaster_id=1234"
+/// );
+/// assert!(http_request.body().is_empty());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// The request is sent like any other, with [`HelixClient::req_post`](crate::helix::HelixClient::req_post),
+/// or with [`HelixClient::req_post_custom`](crate::helix::HelixClient::req_post_custom) to read the response without parsing it first.
 pub trait HelixRequestBody {
     /// Create the body
     fn try_to_body(&self) -> Result<hyper::body::Bytes, BodyError>;
+
+    /// Get the body that will be sent as a string
+    ///
+    /// This is the body [`create_request`](RequestPost::create_request) puts in the request.
+    fn body_json(&self) -> Result<String, BodyError> {
+        Ok(String::from_utf8_lossy(&self.try_to_body()?).into_owned())
+    }
 }
 
 /// An empty body.
 ///
 /// Implements [`HelixRequestBody::try_to_body`], returning an empty vector
-#[derive(Default, Clone, Copy)]
+///
+/// ```rust
+/// use twitch_api::helix::{EmptyBody, HelixRequestBody};
+///
+/// assert!(EmptyBody.try_to_body().unwrap().is_empty());
+/// ```
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
 pub struct EmptyBody;
 
 impl HelixRequestBody for EmptyBody {
     fn try_to_body(&self) -> Result<hyper::body::Bytes, BodyError> { Ok(<_>::default()) }
 }
 
+/// A body sent as the json of `T`
+///
+/// Use this for the [body](RequestPost::Body) of your own requests, see [`HelixRequestBody`].
+///
+/// ```rust
+/// use twitch_api::helix::{HelixRequestBody, JsonBody};
+///
+/// let body = JsonBody(serde_json::json!({"data": [{"user_id": "1234"}]}));
+/// assert_eq!(body.body_json().unwrap(), r#"{"data":[{"user_id":"1234"}]}"#);
+/// ```
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JsonBody<T>(pub T);
+
+impl<T: serde::Serialize> HelixRequestBody for JsonBody<T> {
+    fn try_to_body(&self) -> Result<hyper::body::Bytes, BodyError> {
+        serde_json::to_vec(&self.0)
+            .map_err(Into::into)
+            .map(Into::into)
+    }
+}
+
 // TODO: I would want specialization for this. For now, to override this behavior for a body, we specify a sealed trait
 impl<T> HelixRequestBody for T
 where T: serde::Serialize + private::SealedSerialize
@@ -589,4 +1154,427 @@ where T: serde::Serialize + private::SealedSerialize
 
 pub(crate) mod private {
     pub trait SealedSerialize {}
+    pub trait SealedEmoteTemplate {}
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    /// Parses `base` as `T` with `field` missing, `null` and `""`, returning the parsed values.
+    #[track_caller]
+    fn optional_encodings<T: serde::de::DeserializeOwned>(base: Value, field: &str) -> [T; 3] {
+        let with = |v: Option<Value>| {
+            let mut obj = base.clone();
+            let map = obj.as_object_mut().expect("base should be an object");
+            match v {
+                Some(v) => map.insert(field.to_owned(), v),
+                None => map.remove(field),
+            };
+            crate::parse_json_value::<T>(obj, false)
+                .unwrap_or_else(|e| panic!("could not parse with `{field}` set: {e}"))
+        };
+        [with(None), with(Some(Value::Null)), with(Some(json!("")))]
+    }
+
+    #[test]
+    fn optional_timestamps_tolerate_missing_null_and_empty() {
+        for u in optional_encodings::<super::moderation::BannedUser>(
+            json!({
+                "user_id": "423374343",
+                "user_login": "glowillig",
+                "user_name": "glowillig",
+                "expires_at": "2022-03-15T02:00:28Z",
+                "reason": "Does not like pineapple on pizza.",
+                "moderator_id": "141981764",
+                "moderator_login": "twitchdev",
+                "moderator_name": "TwitchDev"
+            }),
+            "expires_at",
+        ) {
+            assert_eq!(u.expires_at, None);
+        }
+
+        for t in optional_encodings::<super::moderation::BlockedTerm>(
+            json!({
+                "broadcaster_id": "1234",
+                "moderator_id": "5678",
+                "id": "520e4d4e-0cda-49c7-821e-e5ef4f88c2f2",
+                "text": "A phrase I’m not fond of",
+                "created_at": "2021-09-29T19:45:37Z",
+                "updated_at": "2021-09-29T19:45:37Z",
+                "expires_at": "2021-09-29T20:45:37Z"
+            }),
+            "expires_at",
+        ) {
+            assert_eq!(t.expires_at, None);
+        }
+
+        for s in optional_encodings::<super::moderation::ShieldModeStatus>(
+            json!({
+                "is_active": false,
+                "moderator_id": "98765",
+                "moderator_name": "SimplySimple",
+                "moderator_login": "simplysimple",
+                "last_activated_at": "2022-07-26T17:16:03.123Z"
+            }),
+            "last_activated_at",
+        ) {
+            assert_eq!(s.last_shield_mode, None);
+        }
+
+        for b in optional_encodings::<super::moderation::BanUser>(
+            json!({
+                "broadcaster_id": "1234",
+                "moderator_id": "5678",
+                "user_id": "9876",
+                "created_at": "2021-09-28T19:27:31Z",
+                "end_time": "2021-09-28T19:22:31Z"
+            }),
+            "end_time",
+        ) {
+            assert_eq!(b.end_time, None);
+        }
+
+        for c in optional_encodings::<super::search::Channel>(
+            json!({
+                "broadcaster_language": "en",
+                "broadcaster_login": "a_seagull",
+                "display_name": "A_Seagull",
+                "game_id": "506442",
+                "game_name": "DOOM Eternal",
+                "id": "19070311",
+                "is_live": true,
+                "tag_ids": [],
+                "tags": ["English"],
+                "thumbnail_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/a_seagull-profile_image-4d2d235688c7dc66-300x300.png",
+                "title": "a_seagull",
+                "started_at": "2020-03-18T17:56:00Z"
+            }),
+            "started_at",
+        ) {
+            assert_eq!(c.started_at, None);
+        }
+
+        for r in optional_encodings::<super::points::CustomReward>(
+            json!({
+                "broadcaster_name": "torpedo09",
+                "broadcaster_login": "torpedo09",
+                "broadcaster_id": "274637212",
+                "id": "92af127c-7326-4483-a52b-b0da0be61c01",
+                "image": null,
+                "background_color": "#00E5CB",
+                "is_enabled": true,
+                "cost": 50000,
+                "title": "game analysis",
+                "prompt": "",
+                "is_user_input_required": false,
+                "max_per_stream_setting": { "is_enabled": false, "max_per_stream": 0 },
+                "max_per_user_per_stream_setting": { "is_enabled": false, "max_per_user_per_stream": 0 },
+                "global_cooldown_setting": { "is_enabled": false, "global_cooldown_seconds": 0 },
+                "is_paused": false,
+                "is_in_stock": true,
+                "default_image": null,
+                "should_redemptions_skip_request_queue": false,
+                "redemptions_redeemed_current_stream": null,
+                "cooldown_expires_at": "2021-03-10T15:04:21Z"
+            }),
+            "cooldown_expires_at",
+        ) {
+            assert_eq!(r.cooldown_expires_at, None);
+        }
+
+        let prediction = json!({
+            "id": "d6676d5c-c86e-44d2-bfc4-100fb48f0656",
+            "broadcaster_id": "55696719",
+            "broadcaster_name": "TwitchDev",
+            "broadcaster_login": "twitchdev",
+            "title": "Will there be any leaks today?",
+            "winning_outcome_id": null,
+            "outcomes": [],
+            "prediction_window": 600,
+            "status": "RESOLVED",
+            "created_at": "2021-04-28T16:03:06.320848689Z",
+            "ended_at": "2021-04-28T16:13:06.320848689Z",
+            "locked_at": "2021-04-28T16:13:06.320848689Z"
+        });
+        for field in ["ended_at", "locked_at"] {
+            for p in optional_encodings::<super::predictions::Prediction>(prediction.clone(), field)
+            {
+                match field {
+                    "ended_at" => assert_eq!(p.ended_at, None),
+                    _ => assert_eq!(p.locked_at, None),
+                }
+            }
+        }
+
+        for p in optional_encodings::<super::polls::Poll>(
+            json!({
+                "id": "ed961efd-8a3f-4cf5-a9d0-e616c590cd2a",
+                "broadcaster_id": "55696719",
+                "broadcaster_name": "TwitchDev",
+                "broadcaster_login": "twitchdev",
+                "title": "Heads or Tails?",
+                "choices": [],
+                "channel_points_voting_enabled": false,
+                "channel_points_per_vote": 0,
+                "status": "COMPLETED",
+                "duration": 1800,
+                "started_at": "2021-03-19T06:08:33.871278372Z",
+                "ended_at": "2021-03-19T06:38:33.871278372Z"
+            }),
+            "ended_at",
+        ) {
+            assert_eq!(p.ended_at, None);
+        }
+
+        for s in optional_encodings::<super::schedule::Segment>(
+            json!({
+                "id": "eyJzZWdtZW50SUQiOiJlNGFjYzcyNC0zNzFmLTQwMmMtODFjYS0yM2FkYTc5NzU5ZDQiLCJpc29ZZWFyIjoyMDIxLCJpc29XZWVrIjoyNn0=",
+                "start_time": "2021-07-01T18:00:00Z",
+                "end_time": "2021-07-01T19:00:00Z",
+                "title": "TwitchDev Monthly Update // July 1, 2021",
+                "canceled_until": "2021-07-08T18:00:00Z",
+                "category": null,
+                "is_recurring": false
+            }),
+            "canceled_until",
+        ) {
+            assert_eq!(s.canceled_until, None);
+        }
+
+        for s in optional_encodings::<super::schedule::ScheduledBroadcasts>(
+            json!({
+                "segments": [],
+                "broadcaster_id": "141981764",
+                "broadcaster_name": "TwitchDev",
+                "broadcaster_login": "twitchdev",
+                "vacation": {
+                    "start_time": "2021-07-01T18:00:00Z",
+                    "end_time": "2021-07-08T18:00:00Z"
+                }
+            }),
+            "vacation",
+        ) {
+            assert_eq!(s.vacation, None);
+        }
+    }
+
+    #[test]
+    fn optional_timestamps_keep_values() {
+        let s: super::schedule::ScheduledBroadcasts = crate::parse_json_value(
+            json!({
+                "segments": [],
+                "broadcaster_id": "141981764",
+                "broadcaster_name": "TwitchDev",
+                "broadcaster_login": "twitchdev",
+                "vacation": {
+                    "start_time": "2021-07-01T18:00:00Z",
+                    "end_time": "2021-07-08T18:00:00Z"
+                }
+            }),
+            false,
+        )
+        .unwrap();
+        let vacation = s.vacation.expect("vacation should be set");
+        assert_eq!(vacation.start_time.as_str(), "2021-07-01T18:00:00Z");
+        assert_eq!(vacation.end_time.as_str(), "2021-07-08T18:00:00Z");
+
+        let u: super::moderation::BannedUser = crate::parse_json_value(
+            json!({
+                "user_id": "423374343",
+                "user_login": "glowillig",
+                "user_name": "glowillig",
+                "expires_at": "2022-03-15T02:00:28Z",
+                "reason": "",
+                "moderator_id": "141981764",
+                "moderator_login": "twitchdev",
+                "moderator_name": "TwitchDev"
+            }),
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            u.expires_at.as_ref().map(|t| t.as_str()),
+            Some("2022-03-15T02:00:28Z")
+        );
+    }
+
+    /// Members of the response envelope next to `data` are kept in [`Response`](super::Response), even with `deny_unknown_fields`.
+    #[cfg(feature = "deny_unknown_fields")]
+    mod envelope_members {
+        use super::super::{chat, subscriptions, Request, RequestGet, Response};
+        use serde_json::json;
+
+        fn parse<R>(req: R, body: serde_json::Value) -> Response<R, <R as Request>::Response>
+        where R: RequestGet {
+            let uri = req.get_uri().unwrap();
+            let response = http::Response::builder()
+                .body(body.to_string().into_bytes())
+                .unwrap();
+            R::parse_response(Some(req), &uri, response).unwrap()
+        }
+
+        const TEMPLATE: &str =
+            "https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}";
+
+        #[test]
+        fn chatters_total() {
+            let response = parse(
+                chat::GetChattersRequest::new("1234", "4321"),
+                json!({
+                    "data": [],
+                    "pagination": {},
+                    "total": 8,
+                    "new_member": "value"
+                }),
+            );
+            assert_eq!(response.total, Some(8));
+            assert_eq!(
+                response
+                    .get_other::<_, String>("new_member")
+                    .unwrap()
+                    .as_deref(),
+                Some("value")
+            );
+        }
+
+        #[test]
+        fn broadcaster_subscriptions_points() {
+            let response = parse(
+                subscriptions::GetBroadcasterSubscriptionsRequest::broadcaster_id("1234"),
+                json!({
+                    "data": [],
+                    "pagination": {},
+                    "total": 13,
+                    "points": 13
+                }),
+            );
+            assert_eq!(response.total, Some(13));
+            assert_eq!(response.points().unwrap(), 13);
+        }
+
+        #[test]
+        fn channel_emotes_template() {
+            let response = parse(
+                chat::GetChannelEmotesRequest::broadcaster_id("1234"),
+                json!({ "data": [], "template": TEMPLATE }),
+            );
+            assert_eq!(response.template().unwrap().as_deref(), Some(TEMPLATE));
+        }
+
+        #[test]
+        fn emote_sets_template() {
+            let response = parse(
+                chat::GetEmoteSetsRequest::emote_set_ids(&"1234"),
+                json!({ "data": [], "template": TEMPLATE }),
+            );
+            assert_eq!(response.template().unwrap().as_deref(), Some(TEMPLATE));
+        }
+
+        #[test]
+        fn global_emotes_template() {
+            let response = parse(
+                chat::GetGlobalEmotesRequest::new(),
+                json!({ "data": [], "template": TEMPLATE }),
+            );
+            assert_eq!(response.template().unwrap().as_deref(), Some(TEMPLATE));
+        }
+    }
+
+    /// Responses without members next to `data`, `pagination` and `total` have no [`other`](super::Response::other).
+    #[test]
+    fn no_envelope_members() {
+        use super::{Request as _, RequestGet as _};
+
+        let req = super::chat::GetChattersRequest::new("1234", "4321");
+        let uri = req.get_uri().unwrap();
+        let response = http::Response::builder()
+            .body(
+                json!({
+                    "data": [],
+                    "pagination": {},
+                    "total": 0
+                })
+                .to_string()
+                .into_bytes(),
+            )
+            .unwrap();
+        let response =
+            super::chat::GetChattersRequest::parse_response(Some(req), &uri, response).unwrap();
+        assert_eq!(response.other, None);
+        assert_eq!(response.get_other::<_, String>("points").unwrap(), None);
+    }
+
+    #[derive(serde_derive::Serialize, Debug, PartialEq)]
+    struct CustomRequest<B> {
+        broadcaster_id: &'static str,
+        #[serde(skip)]
+        body: std::marker::PhantomData<B>,
+    }
+
+    impl<B> super::Request for CustomRequest<B> {
+        type Response = Value;
+
+        const PATH: &'static str = "custom/endpoint";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+    }
+
+    impl<B: super::HelixRequestBody> super::RequestPost for CustomRequest<B> {
+        type Body = B;
+
+        fn parse_inner_response(
+            request: Option<Self>,
+            _: &http::Uri,
+            response: &str,
+            _: http::StatusCode,
+        ) -> Result<super::Response<Self, Self::Response>, super::HelixRequestPostError> {
+            Ok(super::Response::with_data(
+                serde_json::from_str(response).unwrap(),
+                request,
+            ))
+        }
+    }
+
+    /// Requests defined outside the crate can send an [`EmptyBody`](super::EmptyBody) or a [`JsonBody`](super::JsonBody).
+    #[test]
+    fn custom_request_bodies() {
+        use super::RequestPost as _;
+
+        let request = CustomRequest::<super::EmptyBody> {
+            broadcaster_id: "1234",
+            body: std::marker::PhantomData,
+        };
+        let http_request = request
+            .create_request(super::EmptyBody, "token", "clientid")
+            .unwrap();
+        assert_eq!(http_request.method(), http::Method::POST);
+        assert_eq!(
+            http_request.uri().to_string(),
+            "https://api.twitch.tv/helix/custom/endpoint?broadcaster_id=1234"
+        );
+        assert_eq!(&http_request.body()[..], b"");
+
+        #[derive(serde_derive::Serialize)]
+        struct Payload {
+            user_ids: Vec<&'static str>,
+            reason: Option<&'static str>,
+        }
+
+        let request = CustomRequest::<super::JsonBody<Payload>> {
+            broadcaster_id: "1234",
+            body: std::marker::PhantomData,
+        };
+        let body = super::JsonBody(Payload {
+            user_ids: vec!["1", "2"],
+            reason: None,
+        });
+        let http_request = request.create_request(body, "token", "clientid").unwrap();
+        assert_eq!(
+            &http_request.body()[..],
+            br#"{"user_ids":["1","2"],"reason":null}"#
+        );
+        assert_eq!(http_request.headers()["Content-Type"], "application/json");
+    }
 }
@@ -2,10 +2,14 @@
 pub mod errors;
 use std::{convert::TryInto, str::FromStr};
 
-use crate::parse_json;
+use crate::{parse_json, types};
 
 use super::{ser, HelixRequestBody, HelixRequestError, InnerResponse, Response};
 use errors::*;
+
+/// [Timeout hint](Request::TIMEOUT_HINT) of endpoints that can take multiple seconds per page on big channels, like [Get Chatters](crate::helix::chat::GetChattersRequest)
+pub const SLOW_ENDPOINT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
 /// A request is a Twitch endpoint, see [New Twitch API](https://dev.twitch.tv/docs/api/reference) reference
 #[async_trait::async_trait]
 pub trait Request: serde::Serialize {
@@ -14,9 +18,27 @@ pub trait Request: serde::Serialize {
     /// Scopes needed for this endpoint
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: twitch_oauth2::Validator;
-    /// Optional scopes needed by this endpoint
+    /// Optional scopes needed by this endpoint, [`opt_scopes`](Request::opt_scopes) returns the ones a request makes use of
     #[cfg(feature = "twitch_oauth2")]
     const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[];
+    /// Timeout for endpoints known to be slow, used over the [client timeout](crate::helix::HelixClient::with_timeout)
+    const TIMEOUT_HINT: Option<std::time::Duration> = None;
+    /// Set on endpoints Twitch has deprecated or removed, with a hint on what to use instead
+    ///
+    /// With the `tracing` feature, the [client](crate::helix::HelixClient) logs it as a warning the first time the request is sent.
+    ///
+    /// ```rust
+    /// use twitch_api::helix;
+    /// let deprecated: Vec<_> = helix::ENDPOINTS
+    ///     .iter()
+    ///     .filter(|e| e.deprecated.is_some())
+    ///     .map(|e| e.path)
+    ///     .collect();
+    /// assert!(deprecated.contains(&"users/follows"));
+    /// ```
+    const DEPRECATED: Option<&'static str> = None;
+    /// Largest [page size](PageSize) the endpoint allows, for requests with a `first` parameter
+    const MAX_PAGE_SIZE: usize = 100;
     /// Response type. twitch's response will  deserialize to this.
     type Response: serde::de::DeserializeOwned + PartialEq;
     /// Defines layout of the url parameters.
@@ -37,6 +59,365 @@ pub trait Request: serde::Serialize {
         let url = crate::TWITCH_HELIX_URL.join(<Self as Request>::PATH)?;
         http::Uri::from_str(url.as_str()).map_err(Into::into)
     }
+    /// Returns the [scopes](Request::SCOPE) needed for this request.
+    #[cfg(feature = "twitch_oauth2")]
+    fn scopes(&self) -> twitch_oauth2::Validator { Self::SCOPE }
+    /// Returns the [optional scopes](Request::OPT_SCOPE) this request makes use of, see [`check_scopes`].
+    ///
+    /// Optional scopes give access to more data, e.g. on [Get Chat Settings](crate::helix::chat::GetChatSettingsRequest) with a moderator.
+    /// Requests that don't make use of it return no optional scopes, which is the default.
+    #[cfg(feature = "twitch_oauth2")]
+    fn opt_scopes(&self) -> &'static [twitch_oauth2::Scope] { &[] }
+    /// Returns this request as a [`TokenBoundRequest`], if it is one
+    ///
+    /// Requests that implement [`TokenBoundRequest`] return `Some(self)`, so the [client](crate::helix::HelixClient) can [validate](crate::helix::HelixClient::with_validation) them.
+    fn as_token_bound(&self) -> Option<&dyn TokenBoundRequest> { None }
+}
+
+/// A request that must be made with a token of a specific user
+///
+/// Some endpoints take the id of the user that makes the request, like the `moderator_id` of [Delete Chat Messages](crate::helix::moderation::DeleteChatMessagesRequest),
+/// and Twitch rejects the request if it's not the user of the token.
+/// With [validation](crate::helix::HelixClient::with_validation), the client checks this before sending the request
+/// and fails with [`ClientRequestError::TokenUserMismatch`](crate::helix::ClientRequestError::TokenUserMismatch) instead.
+///
+/// Implementations should also return `Some(self)` from [`Request::as_token_bound`].
+///
+/// ```rust
+/// use twitch_api::helix::{self, Request, TokenBoundRequest};
+///
+/// let request =
+///     helix::moderation::DeleteChatMessagesRequest::new("1234", "5678");
+/// assert_eq!(
+///     request
+///         .as_token_bound()
+///         .and_then(|r| r.token_bound_user_id())
+///         .map(|id| id.as_str()),
+///     Some("5678")
+/// );
+/// ```
+pub trait TokenBoundRequest {
+    /// The id of the user the token must be for, or `None` if the request can be made by anyone
+    fn token_bound_user_id(&self) -> Option<&types::UserIdRef>;
+}
+
+/// Amount of items per page of a request, the `first` parameter
+///
+/// Twitch rejects page sizes above the [`MAX_PAGE_SIZE`](Request::MAX_PAGE_SIZE) of the endpoint with `400 Bad Request`.
+/// The `first` setters of requests [clamp](PageSize::clamped) to their limit instead, use [`new`](PageSize::new) to get an error.
+/// `TryFrom<usize>` only checks against the largest limit of any endpoint, [`MAX_PAGE_SIZE`](PageSize::MAX_PAGE_SIZE).
+///
+/// Serializes as the plain number.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api::helix::{
+///     self, chat::GetChattersRequest, search::SearchChannelsRequest,
+/// };
+///
+/// assert!(helix::PageSize::new::<SearchChannelsRequest>(100).is_ok());
+/// assert!(helix::PageSize::new::<SearchChannelsRequest>(1000).is_err());
+/// assert_eq!(
+///     helix::PageSize::new::<GetChattersRequest>(1000)?.get(),
+///     1000
+/// );
+///
+/// let request = SearchChannelsRequest::query("hello").first(1000);
+/// assert_eq!(request.first.map(|first| first.get()), Some(100));
+/// # Ok::<(), helix::PageSizeError>(())
+/// ```
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde_derive::Serialize,
+    serde_derive::Deserialize,
+)]
+#[serde(transparent)]
+pub struct PageSize(usize);
+
+impl PageSize {
+    /// The largest page size of any endpoint
+    pub const MAX_PAGE_SIZE: usize = 1000;
+
+    /// Check that `size` is between 1 and the [`MAX_PAGE_SIZE`](Request::MAX_PAGE_SIZE) of `R`
+    pub fn new<R: Request>(size: usize) -> Result<Self, PageSizeError> {
+        Self::checked(size, R::MAX_PAGE_SIZE)
+    }
+
+    /// Clamp `size` to between 1 and the [`MAX_PAGE_SIZE`](Request::MAX_PAGE_SIZE) of `R`
+    ///
+    /// A `size` of 0 becomes 1, use [`new`](PageSize::new) to reject it instead.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::{self, chat::GetChattersRequest};
+    ///
+    /// assert_eq!(helix::PageSize::clamped::<GetChattersRequest>(0).get(), 1);
+    /// assert_eq!(helix::PageSize::clamped::<GetChattersRequest>(5000).get(), 1000);
+    /// ```
+    pub fn clamped<R: Request>(size: usize) -> Self { Self(size.clamp(1, R::MAX_PAGE_SIZE)) }
+
+    /// Use `size` as is, even if the endpoint doesn't allow it
+    pub const fn unchecked(size: usize) -> Self { Self(size) }
+
+    /// The amount of items per page
+    pub const fn get(self) -> usize { self.0 }
+
+    fn checked(size: usize, max: usize) -> Result<Self, PageSizeError> {
+        if (1..=max).contains(&size) {
+            Ok(Self(size))
+        } else {
+            Err(PageSizeError { got: size, max })
+        }
+    }
+}
+
+impl std::convert::TryFrom<usize> for PageSize {
+    type Error = PageSizeError;
+
+    fn try_from(size: usize) -> Result<Self, Self::Error> {
+        Self::checked(size, Self::MAX_PAGE_SIZE)
+    }
+}
+
+impl From<PageSize> for usize {
+    fn from(size: PageSize) -> Self { size.0 }
+}
+
+impl std::fmt::Display for PageSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { self.0.fmt(f) }
+}
+
+/// A [`PageSize`] that is zero or larger than the endpoint allows
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("page size {got} is not between 1 and {max}")]
+#[non_exhaustive]
+pub struct PageSizeError {
+    /// The page size
+    pub got: usize,
+    /// The largest page size allowed
+    pub max: usize,
+}
+
+/// Check if a token with `scopes` can make `request`
+///
+/// Fails if the [required scopes](Request::scopes) are missing.
+/// Missing [optional scopes](Request::opt_scopes) that the request makes use of are returned instead, as Twitch still responds without the extra data.
+/// With the `tracing` feature, they are also logged as a warning.
+///
+/// ```rust
+/// use twitch_api::{helix, twitch_oauth2::Scope};
+///
+/// let request = helix::chat::GetChatSettingsRequest::broadcaster_id("1234");
+/// assert!(helix::request::check_scopes(&request, &[])
+///     .unwrap()
+///     .is_empty());
+///
+/// // moderator_id makes use of `moderator:read:chat_settings`
+/// let request = request.moderator_id("4321");
+/// assert_eq!(
+///     helix::request::check_scopes(&request, &[]).unwrap(),
+///     vec![Scope::ModeratorReadChatSettings]
+/// );
+/// ```
+#[cfg(feature = "twitch_oauth2")]
+pub fn check_scopes<R: Request + ?Sized>(
+    request: &R,
+    scopes: &[twitch_oauth2::Scope],
+) -> Result<Vec<twitch_oauth2::Scope>, MissingScopesError> {
+    let required = request.scopes();
+    if !required.matches(scopes) {
+        return Err(MissingScopesError {
+            path: R::PATH,
+            required,
+        });
+    }
+    let missing: Vec<_> = request
+        .opt_scopes()
+        .iter()
+        .filter(|scope| !scopes.contains(scope))
+        .cloned()
+        .collect();
+    #[cfg(feature = "tracing")]
+    if !missing.is_empty() {
+        tracing::warn!(
+            path = R::PATH,
+            ?missing,
+            "token is missing optional scopes used by the request"
+        );
+    }
+    Ok(missing)
+}
+
+/// The [required scopes](Request::scopes) of a request are missing, returned by [`check_scopes`]
+#[cfg(feature = "twitch_oauth2")]
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("token is missing the scopes required for `{path}`")]
+#[non_exhaustive]
+pub struct MissingScopesError {
+    /// [Path](Request::PATH) of the request
+    pub path: &'static str,
+    /// The scopes required by the request
+    pub required: twitch_oauth2::Validator,
+}
+
+/// Get the smallest set of scopes needed to make requests with the given [scopes](Request::SCOPE) and [optional scopes](Request::OPT_SCOPE)
+///
+/// Scopes that are needed by multiple requests are only returned once.
+/// If a request accepts any of multiple scopes, a scope that is already needed by another request is preferred.
+/// Optional scopes are only included with `include_optional`.
+///
+/// Usually used through [`scopes_for!`](crate::scopes_for).
+#[cfg(feature = "twitch_oauth2")]
+pub fn required_scopes(
+    requests: &[(twitch_oauth2::Validator, &'static [twitch_oauth2::Scope])],
+    include_optional: bool,
+) -> Vec<twitch_oauth2::Scope> {
+    let mut scopes = twitch_oauth2::Scope::all();
+    // validators that can't be satisfied by any set of scopes would keep every scope
+    let validators: Vec<_> = requests
+        .iter()
+        .map(|(validator, _)| validator)
+        .filter(|validator| validator.matches(&scopes))
+        .collect();
+    let mut i = 0;
+    while i < scopes.len() {
+        let scope = scopes.remove(i);
+        if !validators
+            .iter()
+            .all(|validator| validator.matches(&scopes))
+        {
+            scopes.insert(i, scope);
+            i += 1;
+        }
+    }
+    if include_optional {
+        for scope in requests.iter().flat_map(|(_, optional)| optional.iter()) {
+            if !scopes.contains(scope) {
+                scopes.push(scope.clone());
+            }
+        }
+    }
+    scopes
+}
+
+/// Get the scopes needed to make the given [requests](crate::helix::Request), see [`required_scopes`](crate::helix::request::required_scopes)
+///
+/// Prefix the requests with `optional:` to include their [optional scopes](crate::helix::Request::OPT_SCOPE).
+///
+/// ```rust
+/// use twitch_api::{
+///     helix::{
+///         chat::GetChattersRequest, moderation::BanUserRequest, teams::get_teams::GetTeamsRequest,
+///     },
+///     twitch_oauth2::Scope,
+/// };
+///
+/// let scopes = twitch_api::scopes_for!(GetChattersRequest, BanUserRequest, GetTeamsRequest);
+/// assert_eq!(scopes.len(), 2);
+/// assert!(scopes.contains(&Scope::ModeratorReadChatters));
+///
+/// let scopes = twitch_api::scopes_for!(optional: GetTeamsRequest);
+/// assert_eq!(scopes, vec![Scope::UserReadEmail]);
+/// ```
+#[cfg(feature = "twitch_oauth2")]
+#[macro_export]
+macro_rules! scopes_for {
+    (@scopes $request:ty) => {
+        (
+            <$request as $crate::helix::Request>::SCOPE,
+            <$request as $crate::helix::Request>::OPT_SCOPE,
+        )
+    };
+    (optional: $($request:ty),* $(,)?) => {
+        $crate::helix::request::required_scopes(&[$($crate::scopes_for!(@scopes $request)),*], true)
+    };
+    ($($request:ty),* $(,)?) => {
+        $crate::helix::request::required_scopes(&[$($crate::scopes_for!(@scopes $request)),*], false)
+    };
+}
+
+/// Information about the endpoint a [`http::Request`] was created for.
+///
+/// Every request created with `create_request` carries this in its [extensions](http::Request::extensions),
+/// making it possible for a [`HttpClient`](crate::HttpClient) to label requests without parsing the uri.
+///
+/// ```rust
+/// use twitch_api::helix::{self, RequestGet};
+/// let request = helix::users::GetUsersRequest::new()
+///     .create_request("token", "clientid")
+///     .unwrap();
+/// let info = request.extensions().get::<helix::EndpointInfo>().unwrap();
+/// assert_eq!(info.path, "users");
+/// assert_eq!(info.method, http::Method::GET);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EndpointInfo {
+    /// The [path](Request::PATH) of the endpoint, relative to the helix root
+    pub path: &'static str,
+    /// The http method used for the request
+    pub method: http::Method,
+}
+
+impl EndpointInfo {
+    fn new<R: Request + ?Sized>(method: http::Method) -> Self {
+        Self {
+            path: R::PATH,
+            method,
+        }
+    }
+}
+
+/// Description of an endpoint this crate has a [`Request`] for, see [`ENDPOINTS`](crate::helix::ENDPOINTS)
+///
+/// ```rust
+/// use twitch_api::helix;
+/// let get_users = helix::ENDPOINTS
+///     .iter()
+///     .find(|e| e.path == "users" && e.method == http::Method::GET)
+///     .unwrap();
+/// assert_eq!(
+///     get_users.module_path,
+///     "twitch_api::helix::endpoints::users::get_users"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct EndpointMeta {
+    /// The [path](Request::PATH) of the endpoint, relative to the helix root
+    pub path: &'static str,
+    /// The http method used for the request
+    pub method: http::Method,
+    /// The [scopes](Request::SCOPE) needed for the endpoint
+    ///
+    /// For Create EventSub Subscription this is empty, the scopes depend on the subscription type.
+    #[cfg(feature = "twitch_oauth2")]
+    pub scopes: twitch_oauth2::Validator,
+    /// The module the [`Request`] is defined in
+    pub module_path: &'static str,
+    /// Why the endpoint is [deprecated](Request::DEPRECATED), if it is
+    pub deprecated: Option<&'static str>,
+}
+
+/// Add `token` as the bearer to `builder`, unless it is empty
+fn with_authorization(
+    builder: http::request::Builder,
+    token: &str,
+) -> Result<http::request::Builder, CreateRequestError> {
+    if token.is_empty() {
+        return Ok(builder);
+    }
+    let mut bearer = http::HeaderValue::from_str(&format!("Bearer {token}"))
+        .map_err(|_| CreateRequestError::Custom("Could not make token into headervalue".into()))?;
+    bearer.set_sensitive(true);
+    Ok(builder.header(http::header::AUTHORIZATION, bearer))
 }
 
 /// Helix endpoint POSTs information
@@ -45,6 +426,8 @@ pub trait RequestPost: Request {
     type Body: HelixRequestBody;
 
     /// Create a [`http::Request`] from this [`Request`] in your client
+    ///
+    /// An empty `token` sends the request without an `Authorization` header.
     fn create_request(
         &self,
         body: Self::Body,
@@ -56,16 +439,13 @@ pub trait RequestPost: Request {
         let body = body.try_to_body()?;
         // eprintln!("\n\nbody is ------------ {:?} ------------", body);
 
-        let mut bearer = http::HeaderValue::from_str(&format!("Bearer {token}")).map_err(|_| {
-            CreateRequestError::Custom("Could not make token into headervalue".into())
-        })?;
-        bearer.set_sensitive(true);
-        http::Request::builder()
+        let builder = http::Request::builder()
             .method(http::Method::POST)
             .uri(uri)
+            .extension(EndpointInfo::new::<Self>(http::Method::POST))
             .header("Client-ID", client_id)
-            .header("Content-Type", "application/json")
-            .header(http::header::AUTHORIZATION, bearer)
+            .header("Content-Type", "application/json");
+        with_authorization(builder, token)?
             .body(body)
             .map_err(Into::into)
     }
@@ -85,8 +465,15 @@ pub trait RequestPost: Request {
         Self: Sized,
     {
         let response: http::Response<hyper::body::Bytes> = response.map(|b| b.into());
+        let request_id = request_id(response.headers());
+        check_status(uri, &response)?;
         let text = std::str::from_utf8(response.body().as_ref()).map_err(|e| {
-            HelixRequestPostError::Utf8Error(response.body().clone(), e, uri.clone())
+            HelixRequestPostError::Utf8Error(
+                response.body().clone(),
+                e,
+                uri.clone(),
+                request_id.clone(),
+            )
         })?;
         if let Ok(HelixRequestError {
             error,
@@ -100,9 +487,12 @@ pub trait RequestPost: Request {
                 message,
                 uri: uri.clone(),
                 body: response.body().clone(),
+                request_id,
             });
         }
         <Self as RequestPost>::parse_inner_response(request, uri, text, response.status())
+            .map(|response| response.with_request_id(request_id.clone()))
+            .map_err(|e| e.with_request_id(request_id))
     }
 
     /// Parse a response string into the response.
@@ -122,6 +512,7 @@ pub trait RequestPost: Request {
                     e,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         Ok(Response::new(
@@ -129,17 +520,32 @@ pub trait RequestPost: Request {
             response.pagination.cursor,
             request,
             response.total,
-            response.other,
+            response.other.filter(|other| !other.is_empty()),
         ))
     }
 }
 
+/// Helix endpoint POSTs information and is safe to send again when it's unknown if an earlier attempt went through
+///
+/// An endpoint qualifies if sending the same request twice has the same effect as sending it once, either because
+///
+/// * it doesn't change anything, like [Check AutoMod Status](super::moderation::CheckAutoModStatusRequest), or
+/// * Twitch rejects a repeat of a request that was applied, like [creating an EventSub subscription](super::eventsub::CreateEventSubSubscriptionRequest) that already exists
+///   or [starting a raid](super::raids::StartARaidRequest) while the raid is in progress.
+///
+/// Endpoints that create something new on every request, like sending a chat message or creating a clip, must not implement this.
+///
+/// Only these endpoints are retried by [`HelixClient::req_post_idempotent`](super::HelixClient::req_post_idempotent), see [`HelixClient::with_retries`](super::HelixClient::with_retries).
+pub trait IdempotentPost: RequestPost {}
+
 /// Helix endpoint PATCHs information
 pub trait RequestPatch: Request {
     /// Body parameters
     type Body: HelixRequestBody;
 
     /// Create a [`http::Request`] from this [`Request`] in your client
+    ///
+    /// An empty `token` sends the request without an `Authorization` header.
     fn create_request(
         &self,
         body: Self::Body,
@@ -151,16 +557,13 @@ pub trait RequestPatch: Request {
         let body = body.try_to_body()?;
         // eprintln!("\n\nbody is ------------ {} ------------", body);
 
-        let mut bearer = http::HeaderValue::from_str(&format!("Bearer {token}")).map_err(|_| {
-            CreateRequestError::Custom("Could not make token into headervalue".into())
-        })?;
-        bearer.set_sensitive(true);
-        http::Request::builder()
+        let builder = http::Request::builder()
             .method(http::Method::PATCH)
             .uri(uri)
+            .extension(EndpointInfo::new::<Self>(http::Method::PATCH))
             .header("Client-ID", client_id)
-            .header("Content-Type", "application/json")
-            .header(http::header::AUTHORIZATION, bearer)
+            .header("Content-Type", "application/json");
+        with_authorization(builder, token)?
             .body(body)
             .map_err(Into::into)
     }
@@ -180,8 +583,15 @@ pub trait RequestPatch: Request {
         Self: Sized,
     {
         let response: http::Response<hyper::body::Bytes> = response.map(|b| b.into());
+        let request_id = request_id(response.headers());
+        check_status(uri, &response)?;
         let text = std::str::from_utf8(response.body().as_ref()).map_err(|e| {
-            HelixRequestPatchError::Utf8Error(response.body().clone(), e, uri.clone())
+            HelixRequestPatchError::Utf8Error(
+                response.body().clone(),
+                e,
+                uri.clone(),
+                request_id.clone(),
+            )
         })?;
         if let Ok(HelixRequestError {
             error,
@@ -195,9 +605,12 @@ pub trait RequestPatch: Request {
                 message,
                 uri: uri.clone(),
                 body: response.body().clone(),
+                request_id,
             });
         }
         <Self as RequestPatch>::parse_inner_response(request, uri, text, response.status())
+            .map(|response| response.with_request_id(request_id.clone()))
+            .map_err(|e| e.with_request_id(request_id))
     }
 
     /// Parse a response string into the response.
@@ -214,6 +627,8 @@ pub trait RequestPatch: Request {
 /// Helix endpoint DELETEs information
 pub trait RequestDelete: Request {
     /// Create a [`http::Request`] from this [`Request`] in your client
+    ///
+    /// An empty `token` sends the request without an `Authorization` header.
     fn create_request(
         &self,
         token: &str,
@@ -221,16 +636,13 @@ pub trait RequestDelete: Request {
     ) -> Result<http::Request<hyper::body::Bytes>, CreateRequestError> {
         let uri = self.get_uri()?;
 
-        let mut bearer = http::HeaderValue::from_str(&format!("Bearer {token}")).map_err(|_| {
-            CreateRequestError::Custom("Could not make token into headervalue".into())
-        })?;
-        bearer.set_sensitive(true);
-        http::Request::builder()
+        let builder = http::Request::builder()
             .method(http::Method::DELETE)
             .uri(uri)
+            .extension(EndpointInfo::new::<Self>(http::Method::DELETE))
             .header("Client-ID", client_id)
-            .header("Content-Type", "application/json")
-            .header(http::header::AUTHORIZATION, bearer)
+            .header("Content-Type", "application/json");
+        with_authorization(builder, token)?
             .body(Vec::with_capacity(0).into())
             .map_err(Into::into)
     }
@@ -249,8 +661,15 @@ pub trait RequestDelete: Request {
         Self: Sized,
     {
         let response: http::Response<hyper::body::Bytes> = response.map(|b| b.into());
+        let request_id = request_id(response.headers());
+        check_status(uri, &response)?;
         let text = std::str::from_utf8(response.body().as_ref()).map_err(|e| {
-            HelixRequestDeleteError::Utf8Error(response.body().clone(), e, uri.clone())
+            HelixRequestDeleteError::Utf8Error(
+                response.body().clone(),
+                e,
+                uri.clone(),
+                request_id.clone(),
+            )
         })?;
         if let Ok(HelixRequestError {
             error,
@@ -264,9 +683,12 @@ pub trait RequestDelete: Request {
                 message,
                 uri: uri.clone(),
                 body: response.body().clone(),
+                request_id,
             });
         }
         <Self as RequestDelete>::parse_inner_response(request, uri, text, response.status())
+            .map(|response| response.with_request_id(request_id.clone()))
+            .map_err(|e| e.with_request_id(request_id))
     }
     /// Parse a response string into the response.
     fn parse_inner_response(
@@ -285,6 +707,8 @@ pub trait RequestPut: Request {
     type Body: HelixRequestBody;
 
     /// Create a [`http::Request`] from this [`Request`] in your client
+    ///
+    /// An empty `token` sends the request without an `Authorization` header.
     fn create_request(
         &self,
         body: Self::Body,
@@ -296,16 +720,13 @@ pub trait RequestPut: Request {
         let body = body.try_to_body()?;
         // eprintln!("\n\nbody is ------------ {} ------------", body);
 
-        let mut bearer = http::HeaderValue::from_str(&format!("Bearer {token}")).map_err(|_| {
-            CreateRequestError::Custom("Could not make token into headervalue".into())
-        })?;
-        bearer.set_sensitive(true);
-        http::Request::builder()
+        let builder = http::Request::builder()
             .method(http::Method::PUT)
             .uri(uri)
+            .extension(EndpointInfo::new::<Self>(http::Method::PUT))
             .header("Client-ID", client_id)
-            .header("Content-Type", "application/json")
-            .header(http::header::AUTHORIZATION, bearer)
+            .header("Content-Type", "application/json");
+        with_authorization(builder, token)?
             .body(body)
             .map_err(Into::into)
     }
@@ -325,8 +746,15 @@ pub trait RequestPut: Request {
         Self: Sized,
     {
         let response: http::Response<hyper::body::Bytes> = response.map(|b| b.into());
+        let request_id = request_id(response.headers());
+        check_status(uri, &response)?;
         let text = std::str::from_utf8(response.body().as_ref()).map_err(|e| {
-            HelixRequestPutError::Utf8Error(response.body().clone(), e, uri.clone())
+            HelixRequestPutError::Utf8Error(
+                response.body().clone(),
+                e,
+                uri.clone(),
+                request_id.clone(),
+            )
         })?;
         if let Ok(HelixRequestError {
             error,
@@ -340,9 +768,12 @@ pub trait RequestPut: Request {
                 message,
                 uri: uri.clone(),
                 body: response.body().clone(),
+                request_id,
             });
         }
         <Self as RequestPut>::parse_inner_response(request, uri, text, response.status())
+            .map(|response| response.with_request_id(request_id.clone()))
+            .map_err(|e| e.with_request_id(request_id))
     }
 
     /// Parse a response string into the response.
@@ -359,6 +790,8 @@ pub trait RequestPut: Request {
 /// Helix endpoint GETs information
 pub trait RequestGet: Request {
     /// Create a [`http::Request`] from this [`Request`] in your client
+    ///
+    /// An empty `token` sends the request without an `Authorization` header.
     fn create_request(
         &self,
         token: &str,
@@ -366,16 +799,13 @@ pub trait RequestGet: Request {
     ) -> Result<http::Request<hyper::body::Bytes>, CreateRequestError> {
         let uri = self.get_uri()?;
 
-        let mut bearer = http::HeaderValue::from_str(&format!("Bearer {token}")).map_err(|_| {
-            CreateRequestError::Custom("Could not make token into headervalue".into())
-        })?;
-        bearer.set_sensitive(true);
-        http::Request::builder()
+        let builder = http::Request::builder()
             .method(http::Method::GET)
             .uri(uri)
+            .extension(EndpointInfo::new::<Self>(http::Method::GET))
             .header("Client-ID", client_id)
-            .header("Content-Type", "application/json")
-            .header(http::header::AUTHORIZATION, bearer)
+            .header("Content-Type", "application/json");
+        with_authorization(builder, token)?
             .body(Vec::with_capacity(0).into())
             .map_err(Into::into)
     }
@@ -394,24 +824,11 @@ pub trait RequestGet: Request {
         Self: Sized,
     {
         let response: http::Response<hyper::body::Bytes> = response.map(|b| b.into());
-        let text = std::str::from_utf8(response.body().as_ref()).map_err(|e| {
-            HelixRequestGetError::Utf8Error(response.body().clone(), e, uri.clone())
-        })?;
-        //eprintln!("\n\nmessage is ------------ {} ------------", text);
-        if let Ok(HelixRequestError {
-            error,
-            status,
-            message,
-        }) = parse_json::<HelixRequestError>(text, false)
-        {
-            return Err(HelixRequestGetError::Error {
-                error,
-                status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
-                message,
-                uri: uri.clone(),
-            });
-        }
+        let request_id = request_id(response.headers());
+        let text = check_get_response(uri, &response)?;
         <Self as RequestGet>::parse_inner_response(request, uri, text, response.status())
+            .map(|response| response.with_request_id(request_id.clone()))
+            .map_err(|e| e.with_request_id(request_id))
     }
 
     /// Parse a response string into the response.
@@ -425,18 +842,171 @@ pub trait RequestGet: Request {
         Self: Sized,
     {
         let response: InnerResponse<_> = parse_json(response, true).map_err(|e| {
-            HelixRequestGetError::DeserializeError(response.to_string(), e, uri.clone(), status)
+            HelixRequestGetError::DeserializeError(
+                response.to_string(),
+                e,
+                uri.clone(),
+                status,
+                None,
+            )
         })?;
         Ok(Response::new(
             response.data,
             response.pagination.cursor,
             request,
             response.total,
-            response.other,
+            response.other.filter(|other| !other.is_empty()),
         ))
     }
 }
 
+/// A page of a list-shaped [`RequestGet`] response, parsed with [`parse_list_page`]
+#[cfg(all(feature = "stream-parse", feature = "client"))]
+pub(crate) struct ListPage<Item> {
+    pub(crate) data: std::collections::VecDeque<Item>,
+    pub(crate) pagination: Option<super::Cursor>,
+    pub(crate) total: Option<i64>,
+    /// Hash of the `data` array as sent by Twitch, to notice the same page being returned again
+    pub(crate) digest: u64,
+}
+
+/// Parse a list-shaped [`RequestGet`] response item by item into a queue
+///
+/// The `data` array is split out of the body without parsing it, and every item is then pushed into the queue as it is parsed,
+/// without the intermediate [`Vec`] and the buffered extra fields of [`RequestGet::parse_response`].
+#[cfg(all(feature = "stream-parse", feature = "client"))]
+pub(crate) fn parse_list_page<Item, B>(
+    uri: &http::Uri,
+    response: http::Response<B>,
+) -> Result<ListPage<Item>, HelixRequestGetError>
+where
+    Item: serde::de::DeserializeOwned,
+    B: Into<hyper::body::Bytes>,
+{
+    #[derive(serde::Deserialize)]
+    struct RawPage<'a> {
+        #[serde(borrow)]
+        data: &'a serde_json::value::RawValue,
+        #[serde(default)]
+        pagination: super::Pagination,
+        #[serde(default)]
+        total: Option<i64>,
+    }
+
+    let response: http::Response<hyper::body::Bytes> = response.map(|b| b.into());
+    let text = check_get_response(uri, &response)?;
+    let error = |e| {
+        HelixRequestGetError::DeserializeError(
+            text.to_string(),
+            e,
+            uri.clone(),
+            response.status(),
+            request_id(response.headers()),
+        )
+    };
+    let page: RawPage = parse_json(text, false).map_err(error)?;
+    let data = parse_json(page.data.get(), true).map_err(error)?;
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    std::hash::Hash::hash(page.data.get(), &mut hasher);
+    Ok(ListPage {
+        data,
+        pagination: page.pagination.cursor,
+        total: page.total,
+        digest: std::hash::Hasher::finish(&hasher),
+    })
+}
+
+/// A [`RequestGet`] with a response that can borrow from the response body
+///
+/// Used by [`BorrowedResponse`](super::BorrowedResponse) to avoid allocating every string in large responses.
+pub trait RequestGetBorrowed: RequestGet {
+    /// Response type. twitch's response will deserialize to this, borrowing from the body.
+    type BorrowedResponse<'a>: serde::Deserialize<'a>;
+}
+
+/// Checks a GET response for errors, returning the body as a string.
+pub(crate) fn check_get_response<'r>(
+    uri: &http::Uri,
+    response: &'r http::Response<hyper::body::Bytes>,
+) -> Result<&'r str, HelixRequestGetError> {
+    check_status(uri, response)?;
+    let request_id = request_id(response.headers());
+    let text = std::str::from_utf8(response.body().as_ref()).map_err(|e| {
+        HelixRequestGetError::Utf8Error(response.body().clone(), e, uri.clone(), request_id.clone())
+    })?;
+    //eprintln!("\n\nmessage is ------------ {} ------------", text);
+    if let Ok(HelixRequestError {
+        error,
+        status,
+        message,
+    }) = parse_json::<HelixRequestError>(text, false)
+    {
+        return Err(HelixRequestGetError::Error {
+            error,
+            status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
+            message,
+            uri: uri.clone(),
+            request_id,
+        });
+    }
+    Ok(text)
+}
+
+/// Response headers that Twitch sends its id for the request in, checked in order, see [`request_id`]
+pub const REQUEST_ID_HEADERS: &[&str] = &["twitch-trace-id", "x-trace-id", "x-request-id"];
+
+/// Twitch's id for the request, from the first of the [`REQUEST_ID_HEADERS`] in `headers`
+///
+/// Twitch support asks for this id when investigating a failed request.
+/// It is kept on [`Response::request_id`] and on the errors created from a response, e.g [`HelixRequestGetError::request_id`].
+pub fn request_id(headers: &http::HeaderMap) -> Option<String> {
+    REQUEST_ID_HEADERS
+        .iter()
+        .find_map(|name| headers.get(*name)?.to_str().ok())
+        .map(str::to_owned)
+}
+
+/// Short-circuits rate limited responses and server errors that Twitch doesn't answer with JSON, e.g during an incident.
+fn check_status<E: errors::HelixRequestError>(
+    uri: &http::Uri,
+    response: &http::Response<hyper::body::Bytes>,
+) -> Result<(), E> {
+    let status = response.status();
+    if status == http::StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = response
+            .headers()
+            .get(http::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse().ok())
+            .map(std::time::Duration::from_secs);
+        let message = std::str::from_utf8(response.body().as_ref())
+            .ok()
+            .and_then(|text| parse_json::<HelixRequestError>(text, false).ok())
+            .map(|error| error.message);
+        return Err(E::rate_limited(
+            retry_after,
+            message,
+            uri.clone(),
+            request_id(response.headers()),
+        ));
+    }
+    if status.is_server_error()
+        && !std::str::from_utf8(response.body().as_ref()).map_or(false, |text| {
+            parse_json::<HelixRequestError>(text, false).is_ok()
+        })
+    {
+        let body = response.body();
+        let snippet = &body[..body.len().min(BODY_SNIPPET_LENGTH)];
+        return Err(E::service_unavailable(
+            status,
+            String::from_utf8_lossy(snippet).into_owned(),
+            uri.clone(),
+            request_id(response.headers()),
+        ));
+    }
+    Ok(())
+}
+
 /// Parses a response where Helix responds with a single datum inside `data`.
 ///
 /// An example response is `{ "data": [ { "foo": 1 } ]`.
@@ -468,3 +1038,544 @@ where
     };
     Ok(Response::with_data(resp, request))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoint_info_extension() {
+        let req = crate::helix::users::GetUsersRequest::new()
+            .create_request("token", "clientid")
+            .unwrap();
+        assert_eq!(
+            req.extensions().get::<EndpointInfo>(),
+            Some(&EndpointInfo {
+                path: "users",
+                method: http::Method::GET
+            })
+        );
+
+        let req = crate::helix::moderation::BanUserRequest::new("1234", "5678")
+            .create_request(
+                crate::helix::moderation::BanUserBody::new("9876", "no reason", 300),
+                "token",
+                "clientid",
+            )
+            .unwrap();
+        assert_eq!(
+            req.extensions().get::<EndpointInfo>(),
+            Some(&EndpointInfo {
+                path: "moderation/bans",
+                method: http::Method::POST
+            })
+        );
+    }
+
+    #[test]
+    fn service_unavailable() {
+        let html = format!("<html><body>{}</body></html>", "a".repeat(500));
+        let req = crate::helix::users::GetUsersRequest::new();
+        let uri = req.get_uri().unwrap();
+        let response = http::Response::builder()
+            .status(503)
+            .body(html.clone().into_bytes())
+            .unwrap();
+        match crate::helix::users::GetUsersRequest::parse_response(Some(req), &uri, response) {
+            Err(HelixRequestGetError::ServiceUnavailable {
+                status,
+                body_snippet,
+                ..
+            }) => {
+                assert_eq!(status, http::StatusCode::SERVICE_UNAVAILABLE);
+                assert_eq!(body_snippet, html[..BODY_SNIPPET_LENGTH]);
+            }
+            e => panic!("unexpected result: {e:?}"),
+        }
+
+        let req = crate::helix::moderation::UnbanUserRequest::new("1234", "5678", "9876");
+        let uri = req.get_uri().unwrap();
+        let response = http::Response::builder()
+            .status(502)
+            .body(b"Bad Gateway".to_vec())
+            .unwrap();
+        assert!(matches!(
+            crate::helix::moderation::UnbanUserRequest::parse_response(Some(req), &uri, response),
+            Err(HelixRequestDeleteError::ServiceUnavailable { status, body_snippet, .. })
+                if status == http::StatusCode::BAD_GATEWAY && body_snippet == "Bad Gateway"
+        ));
+
+        // json errors are still reported as such
+        let req = crate::helix::users::GetUsersRequest::new();
+        let uri = req.get_uri().unwrap();
+        let response = http::Response::builder()
+            .status(500)
+            .body(br#"{"error":"Internal Server Error","status":500,"message":""}"#.to_vec())
+            .unwrap();
+        assert!(matches!(
+            crate::helix::users::GetUsersRequest::parse_response(Some(req), &uri, response),
+            Err(HelixRequestGetError::Error { status, .. })
+                if status == http::StatusCode::INTERNAL_SERVER_ERROR
+        ));
+    }
+
+    #[test]
+    fn rate_limited() {
+        let req = crate::helix::users::GetUsersRequest::new();
+        let uri = req.get_uri().unwrap();
+        let response = http::Response::builder()
+            .status(429)
+            .header("Retry-After", "30")
+            .body(b"Too Many Requests".to_vec())
+            .unwrap();
+        assert!(matches!(
+            crate::helix::users::GetUsersRequest::parse_response(Some(req), &uri, response),
+            Err(HelixRequestGetError::RateLimited { retry_after: Some(d), message: None, .. })
+                if d == std::time::Duration::from_secs(30)
+        ));
+
+        let req = crate::helix::users::GetUsersRequest::new();
+        let uri = req.get_uri().unwrap();
+        let response = http::Response::builder()
+            .status(429)
+            .body(
+                br#"{"error":"Too Many Requests","status":429,"message":"rate limit exceeded"}"#
+                    .to_vec(),
+            )
+            .unwrap();
+        assert!(matches!(
+            crate::helix::users::GetUsersRequest::parse_response(Some(req), &uri, response),
+            Err(HelixRequestGetError::RateLimited { message: Some(m), .. })
+                if m == "rate limit exceeded"
+        ));
+
+        let req = crate::helix::moderation::UnbanUserRequest::new("1234", "5678", "9876");
+        let uri = req.get_uri().unwrap();
+        let response = http::Response::builder()
+            .status(429)
+            .body(Vec::new())
+            .unwrap();
+        assert!(matches!(
+            crate::helix::moderation::UnbanUserRequest::parse_response(Some(req), &uri, response),
+            Err(HelixRequestDeleteError::RateLimited {
+                retry_after: None,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "client")]
+    async fn response_too_large() {
+        let body = r#"{"data":[{"id":"1234","login":"justintv","display_name":"justintv","type":"","broadcaster_type":"","description":"","profile_image_url":"","offline_image_url":"","created_at":"2007-05-22T10:39:54Z"}]}"#;
+        let mock = crate::tests::MockClient::new();
+        mock.respond("users", 200, body).respond("users", 200, body);
+        let token = crate::tests::user_token("justintv", "1234");
+
+        let client = mock.helix().with_max_response_size(body.len() - 1);
+        match client
+            .req_get(crate::helix::users::GetUsersRequest::new(), &token)
+            .await
+        {
+            Err(ClientRequestError::ResponseTooLarge {
+                limit, got_hint, ..
+            }) => {
+                assert_eq!(limit, body.len() - 1);
+                assert_eq!(got_hint, body.len() as u64);
+            }
+            e => panic!("unexpected result: {e:?}"),
+        }
+
+        // the same body parses if it's within the limit
+        let client = client.with_max_response_size(body.len());
+        let response = client
+            .req_get(crate::helix::users::GetUsersRequest::new(), &token)
+            .await
+            .unwrap();
+        assert_eq!(response.data[0].id.as_str(), "1234");
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "client")]
+    async fn retries() {
+        let raid = r#"{"data":[{"created_at":"2022-02-18T07:20:50.52Z","is_mature":false}]}"#;
+        let mock = crate::tests::MockClient::new();
+        mock.respond("raids", 503, "")
+            .respond("raids", 200, raid)
+            .respond("moderation/bans", 503, "");
+        let token = crate::tests::user_token("justintv", "1234");
+        let client = mock.helix().with_retries(1);
+
+        // an idempotent request is sent again after a transient failure
+        client
+            .req_post_idempotent(
+                crate::helix::raids::StartARaidRequest::new("1234", "5678"),
+                crate::helix::EmptyBody,
+                &token,
+            )
+            .await
+            .unwrap();
+        assert_eq!(mock.requests().len(), 2);
+        assert_eq!(mock.requests()[0], mock.requests()[1]);
+
+        // others are sent once
+        match client
+            .req_post(
+                crate::helix::moderation::BanUserRequest::new("1234", "5678"),
+                crate::helix::moderation::BanUserBody::new("9876", "no reason", 300),
+                &token,
+            )
+            .await
+        {
+            Err(ClientRequestError::HelixRequestPostError(
+                HelixRequestPostError::ServiceUnavailable { .. },
+            )) => {}
+            e => panic!("unexpected result: {e:?}"),
+        }
+        assert_eq!(mock.requests().len(), 3);
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "client", feature = "eventsub"))]
+    async fn retried_conflict() {
+        let mock = crate::tests::MockClient::new();
+        mock.respond("eventsub/subscriptions", 502, "")
+            .respond(
+                "eventsub/subscriptions",
+                409,
+                r#"{"error":"Conflict","status":409,"message":"subscription already exists"}"#,
+            )
+            .respond(
+                "eventsub/subscriptions",
+                409,
+                r#"{"error":"Conflict","status":409,"message":"subscription already exists"}"#,
+            );
+        let token = crate::tests::app_token();
+        let client = mock.helix().with_retries(2);
+        let subscribe = || {
+            client.create_eventsub_subscription(
+                crate::eventsub::channel::ChannelUpdateV2::broadcaster_user_id("1234"),
+                crate::eventsub::Transport::webhook(
+                    "https://example.com/twitch",
+                    "secretabcd".to_owned(),
+                ),
+                &token,
+            )
+        };
+
+        // the first attempt might have gone through
+        match subscribe().await {
+            Err(ClientRequestError::AlreadyExists { uri }) => {
+                assert!(uri.path().ends_with("eventsub/subscriptions"))
+            }
+            e => panic!("unexpected result: {e:?}"),
+        }
+        assert_eq!(mock.requests().len(), 2);
+
+        // without a retry, the conflict is an ordinary error
+        match subscribe().await {
+            Err(ClientRequestError::HelixRequestPostError(HelixRequestPostError::Error {
+                status,
+                ..
+            })) => assert_eq!(status, http::StatusCode::CONFLICT),
+            e => panic!("unexpected result: {e:?}"),
+        }
+        assert_eq!(mock.requests().len(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    #[cfg(feature = "client")]
+    async fn timeout_precedence() {
+        use crate::helix::{chat::GetChattersRequest, users::GetUsersRequest};
+        use crate::tests::MockClientError;
+        use futures::TryStreamExt;
+        use std::time::Duration;
+
+        const USERS: &str = r#"{"data":[]}"#;
+        const CHATTERS: &str = r#"{"data":[],"pagination":{},"total":0}"#;
+        let mock = crate::tests::MockClient::new();
+        mock.delay(Duration::from_secs(10));
+        let token = crate::tests::user_token("justintv", "1234");
+        let client = mock.helix().with_timeout(Duration::from_secs(5));
+
+        // the client timeout is used for endpoints without a hint
+        mock.respond("users", 200, USERS);
+        let start = tokio::time::Instant::now();
+        match client.req_get(GetUsersRequest::new(), &token).await {
+            Err(ClientRequestError::RequestError(MockClientError::TimedOut(_, timeout))) => {
+                assert_eq!(timeout, Duration::from_secs(5))
+            }
+            e => panic!("unexpected result: {e:?}"),
+        }
+        assert_eq!(start.elapsed(), Duration::from_secs(5));
+
+        // the endpoint hint is used over the client timeout
+        assert_eq!(
+            GetChattersRequest::TIMEOUT_HINT,
+            Some(crate::helix::SLOW_ENDPOINT_TIMEOUT)
+        );
+        mock.respond("chat/chatters", 200, CHATTERS);
+        let start = tokio::time::Instant::now();
+        client
+            .req_get(GetChattersRequest::new("1234", "1234"), &token)
+            .await
+            .unwrap();
+        assert_eq!(start.elapsed(), Duration::from_secs(10));
+
+        // a timeout for the call is used over the endpoint hint
+        mock.respond("chat/chatters", 200, CHATTERS);
+        match client
+            .get_chatters_with_timeout("1234", "1234", 100, Duration::from_secs(1), &token)
+            .try_collect::<Vec<_>>()
+            .await
+        {
+            Err(ClientRequestError::RequestError(MockClientError::TimedOut(_, timeout))) => {
+                assert_eq!(timeout, Duration::from_secs(1))
+            }
+            e => panic!("unexpected result: {e:?}"),
+        }
+
+        // without a client timeout, requests wait for the response
+        mock.respond("users", 200, USERS);
+        let client = mock.helix();
+        assert_eq!(client.timeout(), None);
+        client
+            .req_get(GetUsersRequest::new(), &token)
+            .await
+            .unwrap();
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "twitch_oauth2")]
+    fn scopes_for() {
+        use crate::helix::{chat, moderation, teams};
+        use twitch_oauth2::Scope;
+
+        // overlapping scopes, including a request that accepts any of two scopes
+        let scopes = crate::scopes_for!(
+            moderation::BanUserRequest,
+            moderation::UnbanUserRequest,
+            moderation::GetBannedUsersRequest,
+            chat::GetChattersRequest,
+        );
+        assert_eq!(scopes.len(), 2);
+        assert!(scopes.contains(&Scope::ModeratorManageBannedUsers));
+        assert!(scopes.contains(&Scope::ModeratorReadChatters));
+
+        assert!(crate::scopes_for!(
+            teams::get_teams::GetTeamsRequest,
+            teams::get_channel_teams::GetChannelTeamsRequest
+        )
+        .is_empty());
+        assert_eq!(
+            crate::scopes_for!(optional: teams::get_teams::GetTeamsRequest, teams::get_channel_teams::GetChannelTeamsRequest),
+            vec![Scope::UserReadEmail]
+        );
+        assert_eq!(
+            crate::scopes_for!(optional: moderation::BanUserRequest, teams::get_teams::GetTeamsRequest),
+            vec![Scope::ModeratorManageBannedUsers, Scope::UserReadEmail]
+        );
+
+        let req = chat::GetChattersRequest::new("1234", "4321");
+        assert!(req.scopes().matches(&[Scope::ModeratorReadChatters]));
+        assert!(!req.scopes().matches(&[Scope::ModerationRead]));
+    }
+
+    #[test]
+    #[cfg(feature = "twitch_oauth2")]
+    fn check_scopes() {
+        use crate::helix::{chat, subscriptions, users};
+        use twitch_oauth2::Scope;
+
+        // the optional scope is only checked when the capability is used
+        let req = chat::GetChatSettingsRequest::broadcaster_id("1234");
+        assert_eq!(super::check_scopes(&req, &[]).unwrap(), vec![]);
+        let req = req.moderator_id("4321");
+        assert_eq!(
+            super::check_scopes(&req, &[]).unwrap(),
+            vec![Scope::ModeratorReadChatSettings]
+        );
+        assert_eq!(
+            super::check_scopes(&req, &[Scope::ModeratorReadChatSettings]).unwrap(),
+            vec![]
+        );
+
+        let req = users::GetUsersRequest::logins(&["twitchdev"][..]);
+        assert_eq!(super::check_scopes(&req, &[]).unwrap(), vec![]);
+        let req = users::GetUsersRequest::new();
+        assert_eq!(
+            super::check_scopes(&req, &[]).unwrap(),
+            vec![Scope::UserReadEmail]
+        );
+
+        // no optional scopes, but the required ones are still checked
+        let req = chat::GetChattersRequest::new("1234", "4321");
+        let err = super::check_scopes(&req, &[]).unwrap_err();
+        assert_eq!(err.path, "chat/chatters");
+        assert_eq!(
+            super::check_scopes(&req, &[Scope::ModeratorReadChatters]).unwrap(),
+            vec![]
+        );
+        let req = subscriptions::GetBroadcasterSubscriptionsRequest::broadcaster_id("1234");
+        assert!(super::check_scopes(&req, &[]).is_err());
+        assert_eq!(
+            super::check_scopes(&req, &[Scope::ChannelReadSubscriptions]).unwrap(),
+            vec![]
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "client")]
+    async fn token_user_validation() {
+        use crate::helix::{moderation::DeleteChatMessagesRequest, users::GetUsersRequest};
+
+        let mock = crate::tests::MockClient::new();
+        mock.respond("moderation/chat", 204, "")
+            .respond("moderation/chat", 204, "")
+            .respond("users", 200, r#"{"data":[]}"#);
+        let token = crate::tests::user_token("moderator", "5678");
+        let request = || DeleteChatMessagesRequest::new("1234", "9876");
+
+        // only checked with validation
+        let client = mock.helix();
+        assert!(!client.validation());
+        client.req_delete(request(), &token).await.unwrap();
+
+        let client = client.with_validation(true);
+        match client.req_delete(request(), &token).await {
+            Err(ClientRequestError::TokenUserMismatch { expected, got }) => {
+                assert_eq!(expected.as_str(), "9876");
+                assert_eq!(got.unwrap().as_str(), "5678");
+            }
+            e => panic!("unexpected result: {e:?}"),
+        }
+        client
+            .req_delete(DeleteChatMessagesRequest::new("1234", "5678"), &token)
+            .await
+            .unwrap();
+
+        // tokens without a user can't make the request
+        let app_token = crate::tests::app_token();
+        let err = client.req_delete(request(), &app_token).await.unwrap_err();
+        assert!(matches!(
+            err,
+            ClientRequestError::TokenUserMismatch { got: None, .. }
+        ));
+        assert_eq!(err.phase(), crate::helix::Phase::Build);
+        assert_eq!(
+            err.to_string(),
+            "the request must be made with a token of user 9876, but the token is not for a user"
+        );
+
+        let bound_user = |request: &dyn TokenBoundRequest| {
+            request
+                .token_bound_user_id()
+                .map(|id| id.as_str().to_owned())
+        };
+        let automod = crate::helix::moderation::CheckAutoModStatusRequest::broadcaster_id("1234");
+        assert_eq!(
+            bound_user(automod.as_token_bound().unwrap()).as_deref(),
+            Some("1234")
+        );
+        let segment =
+            crate::helix::schedule::CreateChannelStreamScheduleSegmentRequest::broadcaster_id(
+                "1234",
+            );
+        assert_eq!(
+            bound_user(segment.as_token_bound().unwrap()).as_deref(),
+            Some("1234")
+        );
+
+        // other requests are not checked
+        client
+            .req_get(GetUsersRequest::logins(&["twitchdev"][..]), &app_token)
+            .await
+            .unwrap();
+        assert_eq!(mock.requests().len(), 3);
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[test]
+    fn request_id_header() {
+        use crate::helix::users::GetUsersRequest;
+
+        let parse = |status: u16, headers: &[(&str, &str)], body: &str| {
+            let req = GetUsersRequest::new();
+            let uri = req.get_uri().unwrap();
+            let mut response = http::Response::builder().status(status);
+            for (name, value) in headers {
+                response = response.header(*name, *value);
+            }
+            GetUsersRequest::parse_response(
+                Some(req),
+                &uri,
+                response.body(body.to_owned()).unwrap(),
+            )
+        };
+        let bad_request = r#"{"error":"Bad Request","status":400,"message":"Invalid username(s), email(s), or ID(s). Bad Identifiers: 1234"}"#;
+
+        let response = parse(200, &[("Twitch-Trace-Id", "a1b2c3")], r#"{"data":[]}"#).unwrap();
+        assert_eq!(response.request_id.as_deref(), Some("a1b2c3"));
+        let response = parse(200, &[], r#"{"data":[]}"#).unwrap();
+        assert_eq!(response.request_id, None);
+
+        let err = parse(400, &[("Twitch-Trace-Id", "a1b2c3")], bad_request).unwrap_err();
+        assert!(matches!(err, HelixRequestGetError::Error { .. }));
+        assert_eq!(err.request_id(), Some("a1b2c3"));
+        let err = parse(400, &[], bad_request).unwrap_err();
+        assert_eq!(err.request_id(), None);
+
+        // errors from parsing the data also keep the id
+        let err = parse(200, &[("X-Request-Id", "d4e5f6")], r#"{"data":[{"id":}]}"#).unwrap_err();
+        assert!(matches!(err, HelixRequestGetError::DeserializeError(..)));
+        assert_eq!(err.request_id(), Some("d4e5f6"));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "client")]
+    async fn request_id_on_client_errors() {
+        use futures::TryStreamExt;
+
+        let mock = crate::tests::MockClient::new();
+        let bad_request = r#"{"error":"Bad Request","status":400,"message":"Missing required parameter \"broadcaster_id\""}"#;
+        mock.respond_with_headers(
+            "moderation/moderators",
+            400,
+            &[("Twitch-Trace-Id", "a1b2c3")],
+            bad_request,
+        )
+        .respond_with_headers(
+            "moderation/moderators",
+            200,
+            &[("Twitch-Trace-Id", "d4e5f6")],
+            r#"{"data":[{"user_id":"424596340","user_login":"quotrok","user_name":"quotrok"}],"pagination":{"cursor":"eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6IjEwMDQ3MzA2NDo4NjQwNjU3MToxSVZCVDFKMnY5M1BTOXh3d1E0dUdXMkJOMFcifX0"}}"#,
+        )
+        .respond_with_headers(
+            "moderation/moderators",
+            400,
+            &[("Twitch-Trace-Id", "g7h8i9")],
+            bad_request,
+        );
+        let client = mock.helix();
+        let token = crate::tests::user_token("broadcaster", "1234");
+        let request = || crate::helix::moderation::GetModeratorsRequest::broadcaster_id("1234");
+
+        let err = client.req_get(request(), &token).await.unwrap_err();
+        assert_eq!(err.request_id(), Some("a1b2c3"));
+        assert_eq!(
+            err.into_api_error().unwrap().request_id.as_deref(),
+            Some("a1b2c3")
+        );
+
+        let err =
+            crate::helix::make_stream(request(), &token, &client, std::collections::VecDeque::from)
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap_err();
+        assert_eq!(err.request_id(), Some("g7h8i9"));
+        assert_eq!(mock.pending(), 0);
+    }
+}
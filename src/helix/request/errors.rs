@@ -36,10 +36,103 @@ pub enum ClientRequestError<RE: std::error::Error + Send + Sync + 'static> {
     /// Got error from DELETE response
     #[error(transparent)]
     HelixRequestDeleteError(#[from] HelixRequestDeleteError),
+    /// The response body is larger than allowed, see [`HelixClient::with_max_response_size`](super::super::HelixClient::with_max_response_size)
+    #[error("response of at least {got_hint} bytes is larger than the limit of {limit} bytes when calling `{uri}`")]
+    ResponseTooLarge {
+        /// The maximum allowed size of the body
+        limit: usize,
+        /// The size of the body, or the `Content-Length` of the response if it is larger
+        got_hint: u64,
+        /// URI for the request
+        uri: http::Uri,
+    },
+    /// A retried [`IdempotentPost`](super::IdempotentPost) request was rejected with `409 Conflict`, an earlier attempt most likely went through
+    #[error("retried request was rejected with 409 Conflict when calling `{uri}`, an earlier attempt most likely went through")]
+    AlreadyExists {
+        /// URI for the request
+        uri: http::Uri,
+    },
+    /// The response did not contain the amount of results the helper expects
+    #[error(transparent)]
+    UnexpectedResponse(#[from] crate::helix::SingleItemError),
+    /// The request must be made by another user than the user of the token, see [`TokenBoundRequest`](crate::helix::TokenBoundRequest)
+    ///
+    /// Only returned with [validation](super::super::HelixClient::with_validation), before the request is sent.
+    #[error("the request must be made with a token of user {expected}, but the token is {}", .got.as_ref().map_or("not for a user".to_owned(), |got| format!("for user {got}")))]
+    TokenUserMismatch {
+        /// The user the token must be for
+        expected: crate::types::UserId,
+        /// The user of the token, `None` for tokens without a user like [app access tokens](twitch_oauth2::AppAccessToken)
+        got: Option<crate::types::UserId>,
+    },
+    /// The request needs scopes, but was made with [`NoAuth`](crate::client::NoAuth)
+    ///
+    /// Returned before the request is sent.
+    #[error("`{path}` needs a token with scopes, but the request was made without a token")]
+    NoToken {
+        /// [Path](crate::helix::Request::PATH) of the request
+        path: &'static str,
+        /// The scopes required by the request
+        required: twitch_oauth2::Validator,
+    },
     /// Custom error
+    ///
+    /// The helpers of the crate only return these before sending the request, e.g. when the token is missing a user id.
     #[error("{0}")]
     Custom(std::borrow::Cow<'static, str>),
 }
+
+/// The part of making a request that a [`ClientRequestError`] happened in, see [`ClientRequestError::phase`]
+#[cfg(feature = "client")]
+#[cfg_attr(nightly, doc(cfg(feature = "client")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Phase {
+    /// The request could not be made, it was never sent
+    Build,
+    /// The [`HttpClient`](crate::HttpClient) failed to send the request or receive the response
+    Transport,
+    /// The response was received, but could not be read
+    Parse,
+    /// Twitch answered with an error, e.g. with `400 Bad Request`, `429 Too Many Requests` or `503 Service Unavailable`
+    Api,
+}
+
+#[cfg(feature = "client")]
+impl<RE: std::error::Error + Send + Sync + 'static> ClientRequestError<RE> {
+    /// The part of making the request this error happened in
+    ///
+    /// Errors in the [build phase](Phase::Build) are not worth retrying, the request never left the machine.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::{self, Phase};
+    ///
+    /// let err: helix::ClientRequestError<std::io::Error> =
+    ///     helix::ClientRequestError::RequestError(
+    ///         std::io::ErrorKind::ConnectionReset.into(),
+    ///     );
+    /// assert_eq!(err.phase(), Phase::Transport);
+    /// ```
+    pub fn phase(&self) -> Phase {
+        match self {
+            Self::CreateRequestError(_)
+            | Self::TokenUserMismatch { .. }
+            | Self::NoToken { .. }
+            | Self::Custom(_) => Phase::Build,
+            Self::RequestError(_) | Self::HyperError(_) => Phase::Transport,
+            Self::NoPage | Self::ResponseTooLarge { .. } | Self::UnexpectedResponse(_) => {
+                Phase::Parse
+            }
+            Self::AlreadyExists { .. } => Phase::Api,
+            Self::HelixRequestGetError(e) => e.phase(),
+            Self::HelixRequestPutError(e) => e.phase(),
+            Self::HelixRequestPostError(e) => e.phase(),
+            Self::HelixRequestPatchError(e) => e.phase(),
+            Self::HelixRequestDeleteError(e) => e.phase(),
+        }
+    }
+}
+
 /// Could not create request
 #[derive(thiserror::Error, Debug, displaydoc::Display)]
 #[non_exhaustive]
@@ -80,16 +173,46 @@ pub enum HelixRequestGetError {
         message: String,
         /// URI to the endpoint
         uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
     },
     /// could not parse response as utf8 when calling `GET {2}`
-    Utf8Error(hyper::body::Bytes, #[source] std::str::Utf8Error, http::Uri),
+    Utf8Error(
+        hyper::body::Bytes,
+        #[source] std::str::Utf8Error,
+        http::Uri,
+        Option<String>,
+    ),
     /// deserialization failed when processing request response calling `GET {2}` with response: {3} - {0:?}
     DeserializeError(
         String,
         #[source] crate::DeserError,
         http::Uri,
         http::StatusCode,
+        Option<String>,
     ),
+    /// helix is unavailable, returned {status:?} when calling `GET {uri}` with response: {body_snippet:?}
+    ServiceUnavailable {
+        /// Status code of the response, 500-599
+        status: http::StatusCode,
+        /// The start of the response body, at most [`BODY_SNIPPET_LENGTH`] bytes
+        body_snippet: String,
+        /// URI to the endpoint
+        uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
+    },
+    /// rate limited when calling `GET {uri}`, retry after {retry_after:?}
+    RateLimited {
+        /// Time to wait before retrying, from the `Retry-After` header
+        retry_after: Option<std::time::Duration>,
+        /// The message returned by Twitch, if the response was json
+        message: Option<String>,
+        /// URI to the endpoint
+        uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
+    },
     /// invalid or unexpected response from twitch.
     InvalidResponse {
         /// Reason for error
@@ -100,6 +223,8 @@ pub enum HelixRequestGetError {
         status: http::StatusCode,
         /// Uri to endpoint
         uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
     },
 }
 
@@ -119,16 +244,46 @@ pub enum HelixRequestPutError {
         uri: http::Uri,
         /// Body sent to PUT response
         body: hyper::body::Bytes,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
     },
     /// could not parse response as utf8 when calling `PUT {2}`
-    Utf8Error(hyper::body::Bytes, #[source] std::str::Utf8Error, http::Uri),
+    Utf8Error(
+        hyper::body::Bytes,
+        #[source] std::str::Utf8Error,
+        http::Uri,
+        Option<String>,
+    ),
     /// deserialization failed when processing request response calling `PUT {2}` with response: {3} - {0:?}
     DeserializeError(
         String,
         #[source] crate::DeserError,
         http::Uri,
         http::StatusCode,
+        Option<String>,
     ),
+    /// helix is unavailable, returned {status:?} when calling `PUT {uri}` with response: {body_snippet:?}
+    ServiceUnavailable {
+        /// Status code of the response, 500-599
+        status: http::StatusCode,
+        /// The start of the response body, at most [`BODY_SNIPPET_LENGTH`] bytes
+        body_snippet: String,
+        /// URI to the endpoint
+        uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
+    },
+    /// rate limited when calling `PUT {uri}`, retry after {retry_after:?}
+    RateLimited {
+        /// Time to wait before retrying, from the `Retry-After` header
+        retry_after: Option<std::time::Duration>,
+        /// The message returned by Twitch, if the response was json
+        message: Option<String>,
+        /// URI to the endpoint
+        uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
+    },
     /// invalid or unexpected response from twitch.
     InvalidResponse {
         /// Reason for error
@@ -139,6 +294,8 @@ pub enum HelixRequestPutError {
         status: http::StatusCode,
         /// Uri to endpoint
         uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
     },
 }
 
@@ -158,16 +315,46 @@ pub enum HelixRequestPostError {
         uri: http::Uri,
         /// Body sent to POST response
         body: hyper::body::Bytes,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
     },
     /// could not parse response as utf8 when calling `POST {2}`
-    Utf8Error(hyper::body::Bytes, #[source] std::str::Utf8Error, http::Uri),
+    Utf8Error(
+        hyper::body::Bytes,
+        #[source] std::str::Utf8Error,
+        http::Uri,
+        Option<String>,
+    ),
     /// deserialization failed when processing request response calling `POST {2}` with response: {3} - {0:?}
     DeserializeError(
         String,
         #[source] crate::DeserError,
         http::Uri,
         http::StatusCode,
+        Option<String>,
     ),
+    /// helix is unavailable, returned {status:?} when calling `POST {uri}` with response: {body_snippet:?}
+    ServiceUnavailable {
+        /// Status code of the response, 500-599
+        status: http::StatusCode,
+        /// The start of the response body, at most [`BODY_SNIPPET_LENGTH`] bytes
+        body_snippet: String,
+        /// URI to the endpoint
+        uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
+    },
+    /// rate limited when calling `POST {uri}`, retry after {retry_after:?}
+    RateLimited {
+        /// Time to wait before retrying, from the `Retry-After` header
+        retry_after: Option<std::time::Duration>,
+        /// The message returned by Twitch, if the response was json
+        message: Option<String>,
+        /// URI to the endpoint
+        uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
+    },
     /// invalid or unexpected response from twitch.
     InvalidResponse {
         /// Reason for error
@@ -178,6 +365,8 @@ pub enum HelixRequestPostError {
         status: http::StatusCode,
         /// Uri to endpoint
         uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
     },
 }
 
@@ -197,16 +386,46 @@ pub enum HelixRequestPatchError {
         uri: http::Uri,
         /// Body sent to POST response
         body: hyper::body::Bytes,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
     },
     /// could not parse response as utf8 when calling `POST {2}`
-    Utf8Error(hyper::body::Bytes, #[source] std::str::Utf8Error, http::Uri),
+    Utf8Error(
+        hyper::body::Bytes,
+        #[source] std::str::Utf8Error,
+        http::Uri,
+        Option<String>,
+    ),
     /// deserialization failed when processing request response calling `POST {2}` with response: {3} - {0:?}
     DeserializeError(
         String,
         #[source] crate::DeserError,
         http::Uri,
         http::StatusCode,
+        Option<String>,
     ),
+    /// helix is unavailable, returned {status:?} when calling `PATCH {uri}` with response: {body_snippet:?}
+    ServiceUnavailable {
+        /// Status code of the response, 500-599
+        status: http::StatusCode,
+        /// The start of the response body, at most [`BODY_SNIPPET_LENGTH`] bytes
+        body_snippet: String,
+        /// URI to the endpoint
+        uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
+    },
+    /// rate limited when calling `PATCH {uri}`, retry after {retry_after:?}
+    RateLimited {
+        /// Time to wait before retrying, from the `Retry-After` header
+        retry_after: Option<std::time::Duration>,
+        /// The message returned by Twitch, if the response was json
+        message: Option<String>,
+        /// URI to the endpoint
+        uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
+    },
     /// invalid or unexpected response from twitch.
     InvalidResponse {
         /// Reason for error
@@ -217,6 +436,8 @@ pub enum HelixRequestPatchError {
         status: http::StatusCode,
         /// Uri to endpoint
         uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
     },
 }
 
@@ -236,9 +457,38 @@ pub enum HelixRequestDeleteError {
         uri: http::Uri,
         /// Body sent to DELETE response
         body: hyper::body::Bytes,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
     },
     /// could not parse response as utf8 when calling `DELETE {2}`
-    Utf8Error(hyper::body::Bytes, #[source] std::str::Utf8Error, http::Uri),
+    Utf8Error(
+        hyper::body::Bytes,
+        #[source] std::str::Utf8Error,
+        http::Uri,
+        Option<String>,
+    ),
+    /// helix is unavailable, returned {status:?} when calling `DELETE {uri}` with response: {body_snippet:?}
+    ServiceUnavailable {
+        /// Status code of the response, 500-599
+        status: http::StatusCode,
+        /// The start of the response body, at most [`BODY_SNIPPET_LENGTH`] bytes
+        body_snippet: String,
+        /// URI to the endpoint
+        uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
+    },
+    /// rate limited when calling `DELETE {uri}`, retry after {retry_after:?}
+    RateLimited {
+        /// Time to wait before retrying, from the `Retry-After` header
+        retry_after: Option<std::time::Duration>,
+        /// The message returned by Twitch, if the response was json
+        message: Option<String>,
+        /// URI to the endpoint
+        uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
+    },
     /// invalid or unexpected response from twitch.
     InvalidResponse {
         /// Reason for error
@@ -249,9 +499,166 @@ pub enum HelixRequestDeleteError {
         status: http::StatusCode,
         /// Uri to endpoint
         uri: http::Uri,
+        /// Twitch's id for the request, see [`request_id`](super::request_id)
+        request_id: Option<String>,
     },
 }
 
+macro_rules! impl_phase {
+    ($($t:ty),*) => {
+        $(#[cfg(feature = "client")]
+        impl $t {
+            /// The [phase](Phase) of the request this error happened in, either [`Phase::Api`] or [`Phase::Parse`]
+            pub fn phase(&self) -> Phase {
+                match self {
+                    Self::Error { .. } | Self::ServiceUnavailable { .. } | Self::RateLimited { .. } => {
+                        Phase::Api
+                    }
+                    _ => Phase::Parse,
+                }
+            }
+        })*
+    };
+}
+
+impl_phase!(
+    HelixRequestGetError,
+    HelixRequestPatchError,
+    HelixRequestPostError,
+    HelixRequestPutError,
+    HelixRequestDeleteError
+);
+
+macro_rules! impl_request_id {
+    ($($t:ty: $($tuple:ident),*;)*) => {
+        $(impl $t {
+            /// Twitch's id for the request, if the error comes from a response that had one, see [`request_id`](super::request_id)
+            pub fn request_id(&self) -> Option<&str> {
+                match self {
+                    Self::Error { request_id, .. }
+                    | Self::ServiceUnavailable { request_id, .. }
+                    | Self::RateLimited { request_id, .. }
+                    | Self::InvalidResponse { request_id, .. }
+                    $(| Self::$tuple(.., request_id))* => request_id.as_deref(),
+                }
+            }
+
+            /// Set the id of the request, unless it is already set
+            pub(crate) fn with_request_id(mut self, id: Option<String>) -> Self {
+                match &mut self {
+                    Self::Error { request_id, .. }
+                    | Self::ServiceUnavailable { request_id, .. }
+                    | Self::RateLimited { request_id, .. }
+                    | Self::InvalidResponse { request_id, .. }
+                    $(| Self::$tuple(.., request_id))* => {
+                        if request_id.is_none() {
+                            *request_id = id;
+                        }
+                    }
+                }
+                self
+            }
+        })*
+    };
+}
+
+impl_request_id!(
+    HelixRequestGetError: Utf8Error, DeserializeError;
+    HelixRequestPutError: Utf8Error, DeserializeError;
+    HelixRequestPostError: Utf8Error, DeserializeError;
+    HelixRequestPatchError: Utf8Error, DeserializeError;
+    HelixRequestDeleteError: Utf8Error;
+);
+
+/// Maximum length of the body kept in `ServiceUnavailable` errors, e.g [`HelixRequestGetError::ServiceUnavailable`]
+pub const BODY_SNIPPET_LENGTH: usize = 256;
+
+/// An error returned by helix, as in the `Error` variant of e.g [`HelixRequestPostError::Error`]
+#[derive(thiserror::Error, Debug, displaydoc::Display, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// helix returned error {status:?} - {error}: {message:?} when calling `{uri}`
+pub struct HelixApiError {
+    /// Error message related to status code
+    pub error: String,
+    /// Status code of error, usually 400-499
+    pub status: http::StatusCode,
+    /// Error message from Twitch
+    pub message: String,
+    /// URI to the endpoint
+    pub uri: http::Uri,
+    /// Twitch's id for the request, see [`request_id`](super::request_id)
+    pub request_id: Option<String>,
+}
+
+#[cfg(feature = "client")]
+impl<RE: std::error::Error + Send + Sync + 'static> ClientRequestError<RE> {
+    /// Twitch's id for the request, if the error comes from a response that had one, see [`request_id`](super::request_id)
+    ///
+    /// Give this to Twitch support when asking about a failed request.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::HelixRequestGetError(e) => e.request_id(),
+            Self::HelixRequestPutError(e) => e.request_id(),
+            Self::HelixRequestPostError(e) => e.request_id(),
+            Self::HelixRequestPatchError(e) => e.request_id(),
+            Self::HelixRequestDeleteError(e) => e.request_id(),
+            _ => None,
+        }
+    }
+
+    /// Take the [error returned by helix](HelixApiError) out of this error, if there is one
+    pub(crate) fn into_api_error(self) -> Result<HelixApiError, Self> {
+        match self {
+            Self::HelixRequestGetError(HelixRequestGetError::Error {
+                error,
+                status,
+                message,
+                uri,
+                request_id,
+            })
+            | Self::HelixRequestPutError(HelixRequestPutError::Error {
+                error,
+                status,
+                message,
+                uri,
+                request_id,
+                ..
+            })
+            | Self::HelixRequestPostError(HelixRequestPostError::Error {
+                error,
+                status,
+                message,
+                uri,
+                request_id,
+                ..
+            })
+            | Self::HelixRequestPatchError(HelixRequestPatchError::Error {
+                error,
+                status,
+                message,
+                uri,
+                request_id,
+                ..
+            })
+            | Self::HelixRequestDeleteError(HelixRequestDeleteError::Error {
+                error,
+                status,
+                message,
+                uri,
+                request_id,
+                ..
+            }) => Ok(HelixApiError {
+                error,
+                status,
+                message,
+                uri,
+                request_id,
+            }),
+            e => Err(e),
+        }
+    }
+}
+
 /// Helper trait to allow construction of any error for an invalid response
 pub(crate) trait HelixRequestError {
     fn invalid_response(
@@ -260,6 +667,20 @@ pub(crate) trait HelixRequestError {
         status: http::StatusCode,
         uri: http::Uri,
     ) -> Self;
+
+    fn service_unavailable(
+        status: http::StatusCode,
+        body_snippet: String,
+        uri: http::Uri,
+        request_id: Option<String>,
+    ) -> Self;
+
+    fn rate_limited(
+        retry_after: Option<std::time::Duration>,
+        message: Option<String>,
+        uri: http::Uri,
+        request_id: Option<String>,
+    ) -> Self;
 }
 
 /// Helper trait to allow construction of any error for a deserailization error (not available for DELETE requests)
@@ -286,6 +707,35 @@ macro_rules! impl_request_error {
                     response,
                     status,
                     uri,
+                    request_id: None,
+                }
+            }
+
+            fn service_unavailable(
+                status: http::StatusCode,
+                body_snippet: String,
+                uri: http::Uri,
+                request_id: Option<String>,
+            ) -> Self {
+                Self::ServiceUnavailable {
+                    status,
+                    body_snippet,
+                    uri,
+                    request_id,
+                }
+            }
+
+            fn rate_limited(
+                retry_after: Option<std::time::Duration>,
+                message: Option<String>,
+                uri: http::Uri,
+                request_id: Option<String>,
+            ) -> Self {
+                Self::RateLimited {
+                    retry_after,
+                    message,
+                    uri,
+                    request_id,
                 }
             }
         })*
@@ -301,7 +751,7 @@ macro_rules! impl_request_deser_error {
                 uri: http::Uri,
                 status: http::StatusCode,
             ) -> Self {
-                Self::DeserializeError(body, err, uri, status)
+                Self::DeserializeError(body, err, uri, status, None)
             }
         })*
     };
@@ -321,3 +771,102 @@ impl_request_deser_error!(
     HelixRequestPostError,
     HelixRequestPutError
 );
+
+#[cfg(all(test, feature = "client"))]
+mod phase_tests {
+    use super::*;
+    use crate::helix::{self, Request, RequestGet};
+    use futures::TryStreamExt;
+
+    /// A request with a path that isn't a valid url
+    #[derive(PartialEq, Eq, serde_derive::Serialize, Clone, Debug)]
+    struct BadPathRequest {}
+
+    impl Request for BadPathRequest {
+        type Response = Vec<serde_json::Value>;
+
+        const PATH: &'static str = "https://[";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+    }
+
+    impl RequestGet for BadPathRequest {}
+
+    #[tokio::test]
+    async fn phases() {
+        let mock = crate::tests::MockClient::new();
+        let client = mock.helix();
+        let token = crate::tests::app_token();
+
+        let err = client.req_get(BadPathRequest {}, &token).await.unwrap_err();
+        assert!(matches!(err, ClientRequestError::CreateRequestError(_)));
+        assert_eq!(err.phase(), Phase::Build);
+        assert!(mock.requests().is_empty());
+
+        // nothing is queued, so the client fails
+        let err = client
+            .req_get(helix::users::GetUsersRequest::new(), &token)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ClientRequestError::RequestError(_)));
+        assert_eq!(err.phase(), Phase::Transport);
+
+        mock.respond("users", 200, r#"{"data":[{"id":}]}"#);
+        let err = client
+            .req_get(helix::users::GetUsersRequest::new(), &token)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ClientRequestError::HelixRequestGetError(HelixRequestGetError::DeserializeError(..))
+        ));
+        assert_eq!(err.phase(), Phase::Parse);
+
+        mock.respond(
+            "users",
+            400,
+            r#"{"error":"Bad Request","status":400,"message":"Invalid username(s), email(s), or ID(s). Bad Identifiers: 1234"}"#,
+        );
+        let err = client
+            .req_get(helix::users::GetUsersRequest::new(), &token)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ClientRequestError::HelixRequestGetError(HelixRequestGetError::Error { .. })
+        ));
+        assert_eq!(err.phase(), Phase::Api);
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn streams_keep_the_phase() {
+        let mock = crate::tests::MockClient::new();
+        let client = mock.helix();
+        let token = crate::tests::user_token("broadcaster", "1234");
+
+        mock.respond(
+            "moderation/moderators",
+            200,
+            r#"{"data":[{"user_id":"424596340","user_login":"quotrok","user_name":"quotrok"}],"pagination":{"cursor":"eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6IjEwMDQ3MzA2NDo4NjQwNjU3MToxSVZCVDFKMnY5M1BTOXh3d1E0dUdXMkJOMFcifX0"}}"#,
+        )
+        .respond("moderation/moderators", 200, "not json");
+        let err = helix::make_stream(
+            helix::moderation::GetModeratorsRequest::broadcaster_id("1234"),
+            &token,
+            &client,
+            std::collections::VecDeque::from,
+        )
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap_err();
+        assert_eq!(err.phase(), Phase::Parse);
+
+        let err = client
+            .get_moderators_in_channel_from_id("1234", None, &token)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap_err();
+        assert_eq!(err.phase(), Phase::Transport);
+    }
+}
@@ -1,5 +1,8 @@
 //! Responses contains the return values of a [request](super::Request).
-use super::{Cursor, Request};
+use super::{
+    request::{check_get_response, RequestGetBorrowed},
+    Cursor, HelixRequestGetError, Request,
+};
 
 /// Response retrieved from endpoint. Data is the type in [`Request::Response`]
 #[derive(PartialEq, Eq, Debug)]
@@ -18,8 +21,15 @@ where
     pub total: Option<i64>,
     /// Fields which are not part of the data response, but are returned by the endpoint.
     ///
-    /// See for example [Get Broadcaster Subscriptions](https://dev.twitch.tv/docs/api/reference#get-broadcaster-subscriptions) which returns this.
+    /// Any top-level member of the response other than `data`, `pagination` and `total` ends up here, [`None`] if there are none.
+    /// Use [`get_other`](Response::get_other) to deserialize a single field.
+    ///
+    /// See for example [Get Broadcaster Subscriptions](https://dev.twitch.tv/docs/api/reference#get-broadcaster-subscriptions) which returns `points`.
     pub other: Option<serde_json::Map<String, serde_json::Value>>,
+    /// Twitch's id for the request, see [`request_id`](super::request::request_id)
+    ///
+    /// Set when the response was [parsed](super::RequestGet::parse_response) from a [`http::Response`] that had one.
+    pub request_id: Option<String>,
 }
 
 impl<R, D> Response<R, D>
@@ -78,6 +88,7 @@ where
     ///                 response: response.to_string(),
     ///                 status,
     ///                 uri: uri.clone(),
+    ///                 request_id: None,
     ///             }),
     ///         }
     ///     }
@@ -96,6 +107,7 @@ where
             request,
             total,
             other,
+            request_id: None,
         }
     }
 
@@ -147,6 +159,7 @@ where
     ///                 response: response.to_string(),
     ///                 status,
     ///                 uri: uri.clone(),
+    ///                 request_id: None,
     ///             }),
     ///         }
     ///     }
@@ -156,7 +169,18 @@ where
         Self::new(data, None, request, None, None)
     }
 
+    /// Set the id of the request, see [`request_id`](Response::request_id)
+    pub(crate) fn with_request_id(mut self, request_id: Option<String>) -> Self {
+        self.request_id = request_id;
+        self
+    }
+
+    /// The cursor to get the next page with, [`None`] if this is the last page.
+    pub fn cursor(&self) -> Option<&Cursor> { self.pagination.as_ref() }
+
     /// Get a field from the response that is not part of `data`.
+    ///
+    /// Returns `Ok(None)` if the field is missing, and an error if it can't be deserialized into `V`.
     pub fn get_other<Q, V>(&self, key: &Q) -> Result<Option<V>, serde_json::Error>
     where
         String: std::borrow::Borrow<Q>,
@@ -189,6 +213,324 @@ where
 {
     /// Get first result of this response.
     pub fn first(self) -> Option<T> { self.data.into_iter().next() }
+
+    /// Get the first result of this response, or `err` if there is none.
+    pub fn first_or<E>(self, err: E) -> Result<T, E> { self.first().ok_or(err) }
+
+    /// Get the only result of this response.
+    ///
+    /// Fails if the response contains no or more than one result.
+    pub fn into_single(self) -> Result<T, SingleItemError> {
+        self.into_optional()?.ok_or(SingleItemError::Empty)
+    }
+
+    /// Get the only result of this response, if there is one.
+    ///
+    /// Fails if the response contains more than one result.
+    pub fn into_optional(self) -> Result<Option<T>, SingleItemError> {
+        let mut data = self.data.into_iter();
+        let first = data.next();
+        match data.count() {
+            0 => Ok(first),
+            rest => Err(SingleItemError::Multiple { count: rest + 1 }),
+        }
+    }
+}
+
+impl<R, D> Response<R, D>
+where
+    R: Request + Clone + super::Paginated,
+    D: serde::de::DeserializeOwned + PartialEq,
+{
+    /// The request for the next page, [`None`] if this is the last page or no request is attached.
+    ///
+    /// Unlike [`get_next`](Response::get_next) this doesn't consume the response or send anything,
+    /// so the request can be sent whenever the next page is wanted, and sent again if it fails.
+    /// See the [helix module docs](super#manual-pagination) for a loop using it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twitch_api::helix::{self, streams::GetStreamsRequest};
+    ///
+    /// let request = GetStreamsRequest::default().first(20);
+    /// let page: helix::Response<_, Vec<helix::streams::Stream>> =
+    ///     helix::Response::new(
+    ///         vec![],
+    ///         Some("abc".into()),
+    ///         Some(request),
+    ///         None,
+    ///         None,
+    ///     );
+    ///
+    /// let next = page.next_request().unwrap();
+    /// assert_eq!(next.after.as_deref().map(|c| c.as_str()), Some("abc"));
+    /// assert_eq!(next.first.map(|first| first.get()), Some(20));
+    /// ```
+    pub fn next_request(&self) -> Option<R> {
+        let cursor = self.pagination.clone()?;
+        let mut request = self.request.clone()?;
+        request.set_pagination(Some(cursor));
+        Some(request)
+    }
+}
+
+/// A [response](Response) did not contain exactly one result
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SingleItemError {
+    /// The response contained no results
+    #[error("response contained no results")]
+    Empty,
+    /// The response contained more than one result
+    #[error("response contained {count} results, expected one")]
+    Multiple {
+        /// Amount of results in the response
+        count: usize,
+    },
+}
+
+/// Headers of a response to send back with a conditional request, like `HelixClient::req_get_conditional`
+///
+/// Twitch answers a conditional request with `304 Not Modified` and no body when the data is the same as when these were returned.
+/// Store the validator together with the data, for example to keep emotes between restarts.
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, Hash, serde_derive::Serialize, serde_derive::Deserialize,
+)]
+#[non_exhaustive]
+pub struct CacheValidator {
+    /// The `ETag` header, sent back as `If-None-Match`
+    pub etag: Option<String>,
+    /// The `Last-Modified` header, sent back as `If-Modified-Since`
+    pub last_modified: Option<String>,
+}
+
+impl CacheValidator {
+    /// Get the validator from the headers of a response
+    pub fn from_headers(headers: &http::HeaderMap) -> Self {
+        let get = |name| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(ToOwned::to_owned)
+        };
+        Self {
+            etag: get(http::header::ETAG),
+            last_modified: get(http::header::LAST_MODIFIED),
+        }
+    }
+
+    /// Whether the response had neither an `ETag` nor a `Last-Modified` header, a request with this validator is not conditional
+    pub fn is_empty(&self) -> bool { self.etag.is_none() && self.last_modified.is_none() }
+
+    /// Add `If-None-Match` and `If-Modified-Since` to the headers of a request
+    pub fn apply(&self, headers: &mut http::HeaderMap) {
+        let values = [
+            (http::header::IF_NONE_MATCH, &self.etag),
+            (http::header::IF_MODIFIED_SINCE, &self.last_modified),
+        ];
+        for (name, value) in values {
+            if let Some(value) = value
+                .as_deref()
+                .and_then(|v| http::HeaderValue::from_str(v).ok())
+            {
+                headers.insert(name, value);
+            }
+        }
+    }
+}
+
+/// Outcome of a conditional request, like `HelixClient::req_get_conditional`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Conditional<T> {
+    /// The data changed, or the request was not conditional
+    Modified {
+        /// The new data
+        data: T,
+        /// The validator to send with the next request
+        validator: CacheValidator,
+    },
+    /// Twitch answered with `304 Not Modified`, the data stored with the validator that was sent is still current
+    NotModified,
+}
+
+impl<T> Conditional<T> {
+    /// Map the data, if it was modified
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Conditional<U> {
+        match self {
+            Self::Modified { data, validator } => Conditional::Modified {
+                data: f(data),
+                validator,
+            },
+            Self::NotModified => Conditional::NotModified,
+        }
+    }
+
+    /// The new data, [`None`] if it was not modified
+    pub fn modified(self) -> Option<T> {
+        match self {
+            Self::Modified { data, .. } => Some(data),
+            Self::NotModified => None,
+        }
+    }
+}
+
+/// Response that keeps the body around, so the data can borrow from it
+///
+/// Data is parsed from the body with [`data`](BorrowedResponse::data) into [`RequestGetBorrowed::BorrowedResponse`],
+/// which borrows strings from the body instead of allocating them. Each call parses the body again.
+///
+/// This is an alternative to [`Response`] for large responses, like [Get Chatters](super::chat::get_chatters) with 1000 chatters per page.
+/// Errors from Twitch are returned when creating the response, like with [`RequestGet::parse_response`](super::RequestGet::parse_response).
+///
+/// # Examples
+///
+/// ```rust, no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+/// use twitch_api::helix;
+///
+/// let request = helix::chat::GetChattersRequest::new("1234", "4321").first(1000);
+/// let response = client.req_get_borrowed(request, &token).await?;
+/// for chatter in response.data()?.data {
+///     println!("{}", chatter.user_login);
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// A response can also be parsed from an [`http::Response`]
+///
+/// ```rust
+/// use twitch_api::helix::{self, chat::GetChattersRequest};
+///
+/// let body = br#"{"data":[{"user_id":"1234","user_login":"justinfan","user_name":"justinfan"}],"pagination":{},"total":1}"#;
+/// let request = GetChattersRequest::new("1234", "4321");
+/// let uri = helix::Request::get_uri(&request)?;
+/// let response = http::Response::builder().body(body.to_vec())?;
+/// let response = helix::BorrowedResponse::parse_response(Some(request), &uri, response)?;
+/// assert_eq!(response.data()?.data[0].user_login.as_str(), "justinfan");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// The data can't outlive the response it borrows from
+///
+/// ```rust, compile_fail
+/// use twitch_api::helix::{self, chat::GetChattersRequest};
+///
+/// let body = br#"{"data":[{"user_id":"1234","user_login":"justinfan","user_name":"justinfan"}],"pagination":{},"total":1}"#;
+/// let get = || {
+///     let request = GetChattersRequest::new("1234", "4321");
+///     let uri = helix::Request::get_uri(&request).unwrap();
+///     let response = http::Response::builder().body(body.to_vec()).unwrap();
+///     helix::BorrowedResponse::parse_response(Some(request), &uri, response).unwrap()
+/// };
+/// let chatters = {
+///     let response = get();
+///     response.data().unwrap().data
+/// };
+/// # drop(chatters);
+/// ```
+///
+/// ```rust, compile_fail
+/// use twitch_api::helix::{self, chat::GetChattersRequest};
+///
+/// let body = br#"{"data":[{"user_id":"1234","user_login":"justinfan","user_name":"justinfan"}],"pagination":{},"total":1}"#;
+/// let get = || {
+///     let request = GetChattersRequest::new("1234", "4321");
+///     let uri = helix::Request::get_uri(&request).unwrap();
+///     let response = http::Response::builder().body(body.to_vec()).unwrap();
+///     helix::BorrowedResponse::parse_response(Some(request), &uri, response).unwrap()
+/// };
+/// let response = get();
+/// let data = response.data().unwrap();
+/// drop(response);
+/// # drop(data);
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct BorrowedResponse<R> {
+    body: hyper::body::Bytes,
+    uri: http::Uri,
+    status: http::StatusCode,
+    request_id: Option<String>,
+    /// The request that was sent, used for [pagination](super::Paginated).
+    pub request: Option<R>,
+}
+
+/// Data of a [`BorrowedResponse`]
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub struct BorrowedData<D> {
+    /// Twitch's response field for `data`.
+    pub data: D,
+    /// A cursor value, to be used in a subsequent request to specify the starting point of the next set of results.
+    pub pagination: Option<Cursor>,
+    /// Response would return this many results if fully paginated. Sometimes this is not emmitted or correct for this purpose, in those cases, this value will be `None`.
+    pub total: Option<i64>,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct BorrowedInnerResponse<D> {
+    data: D,
+    #[serde(default)]
+    pagination: super::Pagination,
+    #[serde(default)]
+    total: Option<i64>,
+}
+
+impl<R: RequestGetBorrowed> BorrowedResponse<R> {
+    /// Create a response from a [`http::Response`], checking it for errors
+    ///
+    /// # Notes
+    ///
+    /// Pass in the request to enable [pagination](super::Paginated).
+    pub fn parse_response<B: Into<hyper::body::Bytes>>(
+        request: Option<R>,
+        uri: &http::Uri,
+        response: http::Response<B>,
+    ) -> Result<Self, HelixRequestGetError> {
+        let response: http::Response<hyper::body::Bytes> = response.map(|b| b.into());
+        check_get_response(uri, &response)?;
+        Ok(Self {
+            status: response.status(),
+            request_id: super::request::request_id(response.headers()),
+            body: response.into_body(),
+            uri: uri.clone(),
+            request,
+        })
+    }
+
+    /// Parse the data, borrowing from the body
+    pub fn data(&self) -> Result<BorrowedData<R::BorrowedResponse<'_>>, HelixRequestGetError> {
+        let text = self.body_str();
+        let response: BorrowedInnerResponse<R::BorrowedResponse<'_>> =
+            crate::parse_json(text, true).map_err(|e| {
+                HelixRequestGetError::DeserializeError(
+                    text.to_owned(),
+                    e,
+                    self.uri.clone(),
+                    self.status,
+                    self.request_id.clone(),
+                )
+            })?;
+        Ok(BorrowedData {
+            data: response.data,
+            pagination: response.pagination.cursor,
+            total: response.total,
+        })
+    }
+
+    /// Twitch's id for the request, see [`request_id`](super::request::request_id)
+    pub fn request_id(&self) -> Option<&str> { self.request_id.as_deref() }
+
+    /// The body of the response
+    pub fn body_str(&self) -> &str {
+        std::str::from_utf8(&self.body).expect("body was checked to be utf-8")
+    }
 }
 
 // impl<R, D, T> CustomResponse<'_, R, D>
@@ -207,21 +549,48 @@ where
     D: serde::de::DeserializeOwned + std::fmt::Debug + PartialEq,
 {
     /// Get the next page in the responses.
+    ///
+    /// If the next page returns the same cursor that was used to get it, the page is returned without a cursor, ending the pagination.
+    /// If the next page doesn't include a [total](Response::total), the total of this page is kept.
     pub async fn get_next<'a, C: crate::HttpClient + 'a>(
         self,
         client: &'a super::HelixClient<'a, C>,
         token: &(impl super::TwitchToken + ?Sized),
     ) -> Result<Option<Response<R, D>>, super::ClientRequestError<<C as crate::HttpClient>::Error>>
+    {
+        self.get_next_with_timeout(client, token, None).await
+    }
+
+    /// Get the next page in the responses like [`get_next`](Response::get_next), timing out after `timeout` if given
+    pub(crate) async fn get_next_with_timeout<'a, C: crate::HttpClient + 'a>(
+        self,
+        client: &'a super::HelixClient<'a, C>,
+        token: &(impl super::TwitchToken + ?Sized),
+        timeout: Option<std::time::Duration>,
+    ) -> Result<Option<Response<R, D>>, super::ClientRequestError<<C as crate::HttpClient>::Error>>
     {
         if let Some(mut req) = self.request.clone() {
             if self.pagination.is_some() {
-                req.set_pagination(self.pagination);
-                let res = client.req_get(req, token).await.map(Some);
-                if let Ok(Some(r)) = res {
+                req.set_pagination(self.pagination.clone());
+                let res = client
+                    .req_get_with_timeout(req, token, timeout)
+                    .await
+                    .map(Some);
+                if let Ok(Some(mut r)) = res {
                     // FIXME: Workaround for https://github.com/twitchdev/issues/issues/18
                     if r.data == self.data {
                         Ok(None)
                     } else {
+                        // The twitch-cli mock server (and sometimes twitch) can return the cursor that was just used, which would loop forever.
+                        if r.pagination == self.pagination {
+                            r.pagination = None;
+                        }
+                        if r.total.is_none() {
+                            r.total = self.total;
+                        }
+                        if r.other.is_none() {
+                            r.other = self.other;
+                        }
                         Ok(Some(r))
                     }
                 } else {
@@ -238,3 +607,226 @@ where
         }
     }
 }
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_get_next_same_cursor() {
+    use futures::TryStreamExt;
+
+    let mock = crate::tests::MockClient::new();
+    let token = crate::tests::user_token("broadcaster", "1234");
+    let page = |id: &str| {
+        format!(
+            r#"{{"data":[{{"user_id":"{id}","user_name":"Mod{id}","user_login":"mod{id}"}}],"pagination":{{"cursor":"abc"}}}}"#
+        )
+    };
+    mock.respond("moderation/moderators", 200, &page("1"))
+        .respond("moderation/moderators", 200, &page("2"))
+        .respond("moderation/moderators", 200, &page("3"));
+
+    let client = mock.helix();
+    let moderators: Vec<crate::helix::moderation::Moderator> = client
+        .get_moderators_in_channel_from_id("1234", None, &token)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(moderators.len(), 2);
+    assert_eq!(moderators[1].user_id.as_str(), "2");
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[1].1.query().unwrap().contains("after=abc"));
+    assert_eq!(mock.pending(), 1);
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_next_request() {
+    use crate::helix::moderation::GetModeratorsRequest;
+
+    let mock = crate::tests::MockClient::new();
+    let token = crate::tests::user_token("broadcaster", "1234");
+    let page = |id: &str, cursor: &str| {
+        format!(
+            r#"{{"data":[{{"user_id":"{id}","user_name":"Mod{id}","user_login":"mod{id}"}}],"pagination":{{{cursor}}}}}"#
+        )
+    };
+    mock.respond(
+        "moderation/moderators",
+        200,
+        &page("1", r#""cursor":"abc""#),
+    )
+    .respond("moderation/moderators", 503, "")
+    .respond("moderation/moderators", 200, &page("2", ""));
+    let client = mock.helix();
+
+    let first = client
+        .req_get(
+            GetModeratorsRequest::broadcaster_id("1234").first(1),
+            &token,
+        )
+        .await
+        .unwrap();
+    assert_eq!(first.cursor().map(|c| c.as_str()), Some("abc"));
+    let next = first.next_request().unwrap();
+    assert_eq!(next.after.as_deref().map(|c| c.as_str()), Some("abc"));
+    assert_eq!(next.first.map(|first| first.get()), Some(1));
+    // the response is still there, and gives the same request again
+    assert_eq!(first.next_request(), Some(next.clone()));
+
+    assert!(client.req_get(next.clone(), &token).await.is_err());
+    let second = client.req_get(next, &token).await.unwrap();
+    assert_eq!(second.data[0].user_id.as_str(), "2");
+    assert_eq!(second.cursor(), None);
+    assert_eq!(second.next_request(), None);
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[1].1, requests[2].1);
+    assert_eq!(
+        requests[1].1.query(),
+        Some("broadcaster_id=1234&after=abc&first=1")
+    );
+    assert_eq!(mock.pending(), 0);
+
+    let response = Response::<GetModeratorsRequest<'static>, Vec<u32>>::new(
+        vec![],
+        Some("abc".into()),
+        None,
+        None,
+        None,
+    );
+    assert_eq!(response.next_request(), None);
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_get_next_keeps_request_and_total() {
+    use futures::TryStreamExt;
+
+    let mock = crate::tests::MockClient::new();
+    let token = crate::tests::user_token("broadcaster", "1234");
+    let follower = |id: &str| {
+        format!(
+            r#"{{"user_id":"{id}","user_name":"User{id}","user_login":"user{id}","followed_at":"2022-05-24T22:22:08Z"}}"#
+        )
+    };
+    mock.respond(
+        "channels/followers",
+        200,
+        &format!(
+            r#"{{"total":3,"data":[{},{}],"pagination":{{"cursor":"abc"}}}}"#,
+            follower("1"),
+            follower("2")
+        ),
+    )
+    .respond(
+        "channels/followers",
+        200,
+        &format!(r#"{{"data":[{}],"pagination":{{}}}}"#, follower("3")),
+    );
+
+    let client = mock.helix();
+    let req = crate::helix::channels::GetChannelFollowersRequest::broadcaster_id("1234").first(2);
+    let mut stream =
+        crate::helix::make_stream(req, &token, &client, std::collections::VecDeque::from);
+    assert_eq!(stream.total(), None);
+    let mut followers = vec![];
+    while let Some(follower) = stream.try_next().await.unwrap() {
+        followers.push(follower);
+        assert_eq!(stream.total(), Some(3));
+    }
+    assert_eq!(followers.len(), 3);
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    let query = requests[1].1.query().unwrap();
+    assert!(query.contains("first=2"), "{query}");
+    assert!(query.contains("broadcaster_id=1234"), "{query}");
+    assert!(query.contains("after=abc"), "{query}");
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_paginated_stream_progress_on_error() {
+    use futures::TryStreamExt;
+
+    let mock = crate::tests::MockClient::new();
+    let token = crate::tests::user_token("broadcaster", "1234");
+    let page = |id: &str, cursor: &str| {
+        format!(
+            r#"{{"data":[{{"user_id":"{id}","user_name":"User{id}","user_login":"user{id}"}}],"pagination":{{"cursor":"{cursor}"}}}}"#
+        )
+    };
+    mock.respond("moderation/moderators", 200, &page("1", "page2"))
+        .respond("moderation/moderators", 200, &page("2", "page3"))
+        .respond(
+            "moderation/moderators",
+            400,
+            r#"{"error":"Bad Request","status":400,"message":"Invalid cursor"}"#,
+        )
+        .respond("moderation/moderators", 200, &page("3", ""));
+
+    let client = mock.helix();
+    let req = crate::helix::moderation::GetModeratorsRequest::broadcaster_id("1234");
+    let mut stream =
+        crate::helix::make_stream(req, &token, &client, std::collections::VecDeque::from);
+    let progress = stream.progress();
+    assert_eq!(stream.pages_fetched(), 0);
+    assert_eq!(
+        stream.try_next().await.unwrap().unwrap().user_id.as_str(),
+        "1"
+    );
+    assert_eq!(
+        stream.try_next().await.unwrap().unwrap().user_id.as_str(),
+        "2"
+    );
+    assert!(stream.try_next().await.is_err());
+    drop(stream);
+
+    assert_eq!(progress.pages_fetched(), 2);
+    assert_eq!(progress.last_cursor().unwrap().as_str(), "page3");
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(requests[2].1.query().unwrap().contains("after=page3"));
+
+    // failing on the first request fetches no pages
+    let mock = crate::tests::MockClient::new();
+    mock.respond(
+        "moderation/moderators",
+        400,
+        r#"{"error":"Bad Request","status":400,"message":"Missing broadcaster_id"}"#,
+    );
+    let client = mock.helix();
+    let req = crate::helix::moderation::GetModeratorsRequest::broadcaster_id("1234");
+    let mut stream =
+        crate::helix::make_stream(req, &token, &client, std::collections::VecDeque::from);
+    assert!(stream.try_next().await.is_err());
+    assert_eq!(stream.pages_fetched(), 0);
+    assert_eq!(stream.last_cursor(), None);
+}
+
+#[cfg(test)]
+#[test]
+fn test_single() {
+    let response = |data: Vec<u32>| {
+        Response::<crate::helix::users::GetUsersRequest<'static>, _>::with_data(data, None)
+    };
+
+    assert_eq!(response(vec![]).into_single(), Err(SingleItemError::Empty));
+    assert_eq!(response(vec![]).into_optional(), Ok(None));
+    assert_eq!(response(vec![]).first_or("none"), Err("none"));
+
+    assert_eq!(response(vec![1]).into_single(), Ok(1));
+    assert_eq!(response(vec![1]).into_optional(), Ok(Some(1)));
+    assert_eq!(response(vec![1]).first_or("none"), Ok(1));
+
+    assert_eq!(
+        response(vec![1, 2, 3]).into_single(),
+        Err(SingleItemError::Multiple { count: 3 })
+    );
+    assert_eq!(
+        response(vec![1, 2]).into_optional(),
+        Err(SingleItemError::Multiple { count: 2 })
+    );
+    assert_eq!(response(vec![1, 2]).first_or("none"), Ok(1));
+}
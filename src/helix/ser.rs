@@ -5,24 +5,64 @@
 use std::borrow::Cow;
 
 use serde::ser::{self, Impossible, Serialize};
-use url::form_urlencoded::Serializer as UrlEncodedSerializer;
+
+/// Expected length of a value in a sequence, used to reserve space in the query.
+///
+/// Most sequences are ids, which are usually around this long.
+const ESTIMATED_VALUE_LENGTH: usize = 10;
 
 pub fn to_string<T: ser::Serialize>(input: T) -> Result<String, Error> {
-    let mut urlencoder = UrlEncodedSerializer::new("".to_owned());
+    let mut urlencoder = QueryWriter::default();
     input.serialize(Serializer::new(&mut urlencoder))?;
-    Ok(urlencoder.finish())
+    Ok(urlencoder.query)
 }
 
-pub struct Serializer<'input, 'output> {
-    urlencoder: &'output mut UrlEncodedSerializer<'input, String>,
+/// Writes `application/x-www-form-urlencoded` pairs directly into the query
+///
+/// Produces the same output as [`url::form_urlencoded::Serializer`], but lets us reserve space up front.
+#[derive(Default)]
+pub struct QueryWriter {
+    query: String,
 }
 
-impl<'input, 'output> Serializer<'input, 'output> {
-    fn new(urlencoder: &'output mut UrlEncodedSerializer<'input, String>) -> Self {
-        Serializer { urlencoder }
+impl QueryWriter {
+    fn append_pair(&mut self, key: &str, value: &str) {
+        self.append_key(key);
+        self.query
+            .extend(url::form_urlencoded::byte_serialize(value.as_bytes()));
+    }
+
+    /// Append a value that never needs to be percent-encoded, like numbers and bools, without allocating
+    fn append_display(&mut self, key: &str, value: impl std::fmt::Display) {
+        use std::fmt::Write as _;
+
+        self.append_key(key);
+        write!(self.query, "{value}").expect("writing to a string can't fail");
+    }
+
+    fn append_key(&mut self, key: &str) {
+        if !self.query.is_empty() {
+            self.query.push('&');
+        }
+        self.query
+            .extend(url::form_urlencoded::byte_serialize(key.as_bytes()));
+        self.query.push('=');
+    }
+
+    fn reserve(&mut self, key: &str, len: usize) {
+        self.query
+            .reserve(len * (key.len() + ESTIMATED_VALUE_LENGTH + 2));
     }
 }
 
+pub struct Serializer<'output> {
+    urlencoder: &'output mut QueryWriter,
+}
+
+impl<'output> Serializer<'output> {
+    fn new(urlencoder: &'output mut QueryWriter) -> Self { Serializer { urlencoder } }
+}
+
 #[derive(Debug, thiserror::Error, displaydoc::Display)]
 #[non_exhaustive]
 /// Errors from the query serializer
@@ -85,12 +125,12 @@ impl ser::Error for Error {
     fn custom<T: std::fmt::Display>(msg: T) -> Self { Error::Custom(msg.to_string().into()) }
 }
 
-impl<'input, 'output> ser::Serializer for Serializer<'input, 'output> {
+impl<'output> ser::Serializer for Serializer<'output> {
     type Error = Error;
-    type Ok = &'output mut UrlEncodedSerializer<'input, String>;
-    type SerializeMap = MapSerializer<'input, 'output>;
+    type Ok = &'output mut QueryWriter;
+    type SerializeMap = MapSerializer<'output>;
     type SerializeSeq = Impossible<Self::Ok, Error>;
-    type SerializeStruct = StructSerializer<'input, 'output>;
+    type SerializeStruct = StructSerializer<'output>;
     type SerializeStructVariant = Impossible<Self::Ok, Error>;
     // FIXME: This should be implemented.
     type SerializeTuple = Impossible<Self::Ok, Error>;
@@ -256,13 +296,13 @@ impl<'input, 'output> ser::Serializer for Serializer<'input, 'output> {
     }
 }
 
-pub struct MapSerializer<'input, 'output> {
-    urlencoder: &'output mut UrlEncodedSerializer<'input, String>,
+pub struct MapSerializer<'output> {
+    urlencoder: &'output mut QueryWriter,
 }
 
-impl<'input, 'output> ser::SerializeMap for MapSerializer<'input, 'output> {
+impl<'output> ser::SerializeMap for MapSerializer<'output> {
     type Error = Error;
-    type Ok = &'output mut UrlEncodedSerializer<'input, String>;
+    type Ok = &'output mut QueryWriter;
 
     fn serialize_entry<K, V>(&mut self, key: &K, value: &V) -> Result<(), Self::Error>
     where
@@ -288,13 +328,13 @@ impl<'input, 'output> ser::SerializeMap for MapSerializer<'input, 'output> {
     fn end(self) -> Result<Self::Ok, Self::Error> { Ok(self.urlencoder) }
 }
 
-pub struct StructSerializer<'input, 'output> {
-    urlencoder: &'output mut UrlEncodedSerializer<'input, String>,
+pub struct StructSerializer<'output> {
+    urlencoder: &'output mut QueryWriter,
 }
 
-impl<'input, 'output> ser::SerializeStruct for StructSerializer<'input, 'output> {
+impl<'output> ser::SerializeStruct for StructSerializer<'output> {
     type Error = Error;
-    type Ok = &'output mut UrlEncodedSerializer<'input, String>;
+    type Ok = &'output mut QueryWriter;
 
     fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
     where T: serde::Serialize + ?Sized {
@@ -308,15 +348,15 @@ impl<'input, 'output> ser::SerializeStruct for StructSerializer<'input, 'output>
     fn end(self) -> Result<Self::Ok, Self::Error> { Ok(self.urlencoder) }
 }
 
-pub struct FieldSerializer<'input, 'output> {
+pub struct FieldSerializer<'output> {
     key: &'static str,
-    urlencoder: &'output mut UrlEncodedSerializer<'input, String>,
+    urlencoder: &'output mut QueryWriter,
 }
 
-impl<'input, 'output> ser::Serializer for FieldSerializer<'input, 'output> {
+impl<'output> ser::Serializer for FieldSerializer<'output> {
     type Error = Error;
-    type Ok = &'output mut UrlEncodedSerializer<'input, String>;
-    type SerializeMap = MapSerializer<'input, 'output>;
+    type Ok = &'output mut QueryWriter;
+    type SerializeMap = MapSerializer<'output>;
     type SerializeSeq = Self;
     type SerializeStruct = Impossible<Self::Ok, Error>;
     type SerializeStructVariant = Impossible<Self::Ok, Error>;
@@ -333,7 +373,10 @@ impl<'input, 'output> ser::Serializer for FieldSerializer<'input, 'output> {
         Ok(self.urlencoder)
     }
 
-    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        if let Some(len) = len {
+            self.urlencoder.reserve(self.key, len);
+        }
         Ok(self)
     }
 
@@ -529,9 +572,9 @@ impl<'input, 'output> ser::Serializer for FieldSerializer<'input, 'output> {
     }
 }
 
-impl<'input, 'output> ser::SerializeSeq for FieldSerializer<'input, 'output> {
+impl<'output> ser::SerializeSeq for FieldSerializer<'output> {
     type Error = Error;
-    type Ok = &'output mut UrlEncodedSerializer<'input, String>;
+    type Ok = &'output mut QueryWriter;
 
     fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where T: serde::Serialize + ?Sized {
@@ -545,9 +588,9 @@ impl<'input, 'output> ser::SerializeSeq for FieldSerializer<'input, 'output> {
     fn end(self) -> Result<Self::Ok, Self::Error> { Ok(self.urlencoder) }
 }
 
-impl<'input, 'output> ser::SerializeTuple for FieldSerializer<'input, 'output> {
+impl<'output> ser::SerializeTuple for FieldSerializer<'output> {
     type Error = Error;
-    type Ok = &'output mut UrlEncodedSerializer<'input, String>;
+    type Ok = &'output mut QueryWriter;
 
     fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where T: serde::Serialize + ?Sized {
@@ -561,14 +604,14 @@ impl<'input, 'output> ser::SerializeTuple for FieldSerializer<'input, 'output> {
     fn end(self) -> Result<Self::Ok, Self::Error> { Ok(self.urlencoder) }
 }
 
-struct PairSerializer<'input, 'output> {
+struct PairSerializer<'output> {
     key: &'static str,
-    urlencoder: &'output mut UrlEncodedSerializer<'input, String>,
+    urlencoder: &'output mut QueryWriter,
 }
 
-impl<'input, 'output> ser::Serializer for PairSerializer<'input, 'output> {
+impl<'output> ser::Serializer for PairSerializer<'output> {
     type Error = Error;
-    type Ok = &'output mut UrlEncodedSerializer<'input, String>;
+    type Ok = &'output mut QueryWriter;
     type SerializeMap = Impossible<Self::Ok, Error>;
     type SerializeSeq = Impossible<Self::Ok, Error>;
     type SerializeStruct = Impossible<Self::Ok, Error>;
@@ -578,74 +621,62 @@ impl<'input, 'output> ser::Serializer for PairSerializer<'input, 'output> {
     type SerializeTupleVariant = Impossible<Self::Ok, Error>;
 
     fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
-        self.urlencoder
-            .append_pair(self.key, &v.serialize(ValueSerializer)?);
+        self.urlencoder.append_pair(self.key, v);
         Ok(self.urlencoder)
     }
 
     fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
-        self.urlencoder
-            .append_pair(self.key, &v.serialize(ValueSerializer)?);
+        self.urlencoder.append_display(self.key, v);
         Ok(self.urlencoder)
     }
 
     fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
-        self.urlencoder
-            .append_pair(self.key, &v.serialize(ValueSerializer)?);
+        self.urlencoder.append_display(self.key, v);
         Ok(self.urlencoder)
     }
 
     fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
-        self.urlencoder
-            .append_pair(self.key, &v.serialize(ValueSerializer)?);
+        self.urlencoder.append_display(self.key, v);
         Ok(self.urlencoder)
     }
 
     fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
-        self.urlencoder
-            .append_pair(self.key, &v.serialize(ValueSerializer)?);
+        self.urlencoder.append_display(self.key, v);
         Ok(self.urlencoder)
     }
 
     fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
-        self.urlencoder
-            .append_pair(self.key, &v.serialize(ValueSerializer)?);
+        self.urlencoder.append_display(self.key, v);
         Ok(self.urlencoder)
     }
 
     fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
-        self.urlencoder
-            .append_pair(self.key, &v.serialize(ValueSerializer)?);
+        self.urlencoder.append_display(self.key, v);
         Ok(self.urlencoder)
     }
 
     fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
-        self.urlencoder
-            .append_pair(self.key, &v.serialize(ValueSerializer)?);
+        self.urlencoder.append_display(self.key, v);
         Ok(self.urlencoder)
     }
 
     fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
-        self.urlencoder
-            .append_pair(self.key, &v.serialize(ValueSerializer)?);
+        self.urlencoder.append_display(self.key, v);
         Ok(self.urlencoder)
     }
 
     fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
-        self.urlencoder
-            .append_pair(self.key, &v.serialize(ValueSerializer)?);
+        self.urlencoder.append_display(self.key, v);
         Ok(self.urlencoder)
     }
 
     fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
-        self.urlencoder
-            .append_pair(self.key, &v.serialize(ValueSerializer)?);
+        self.urlencoder.append_display(self.key, v);
         Ok(self.urlencoder)
     }
 
     fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
-        self.urlencoder
-            .append_pair(self.key, &v.serialize(ValueSerializer)?);
+        self.urlencoder.append_display(self.key, v);
         Ok(self.urlencoder)
     }
 
@@ -753,9 +784,9 @@ impl<'input, 'output> ser::Serializer for PairSerializer<'input, 'output> {
     }
 }
 
-impl<'input, 'output> ser::SerializeTuple for PairSerializer<'input, 'output> {
+impl<'output> ser::SerializeTuple for PairSerializer<'output> {
     type Error = Error;
-    type Ok = &'output mut UrlEncodedSerializer<'input, String>;
+    type Ok = &'output mut QueryWriter;
 
     fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where T: serde::Serialize + ?Sized {
@@ -970,3 +1001,54 @@ fn serialize_query() {
         "filter=1&possibly=sure+thing&ids=2&ids=3&ids2=4&stuff=32&stuff=-35&stuff=ha&1=one&2=two&username=justintv&variant=hello&variant2=world&num=123"
     )
 }
+
+#[cfg(test)]
+#[test]
+fn serialize_query_matches_form_urlencoded() {
+    #[derive(serde_derive::Serialize)]
+    struct Request<'a> {
+        id: Vec<&'a str>,
+        login: Vec<&'a str>,
+        first: Option<u32>,
+        after: Option<&'a str>,
+        ratio: f64,
+        flag: Option<bool>,
+    }
+
+    let ids: Vec<String> = (0..100).map(|i| (141981764 + i * 7).to_string()).collect();
+    let logins = [
+        "twitchdev",
+        "with space",
+        "a&b=c",
+        "plus+sign",
+        "100%",
+        "ünïcödé",
+        "字",
+        "😀",
+        "",
+    ];
+    let req = Request {
+        id: ids.iter().map(String::as_str).collect(),
+        login: logins.to_vec(),
+        first: Some(100),
+        after: Some("eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6IjEwIn19"),
+        ratio: -0.5,
+        flag: Some(true),
+    };
+
+    // what the serializer produced before it wrote the query directly
+    let mut expected = url::form_urlencoded::Serializer::new(String::new());
+    for id in &ids {
+        expected.append_pair("id", id);
+    }
+    for login in logins {
+        expected.append_pair("login", login);
+    }
+    expected
+        .append_pair("first", "100")
+        .append_pair("after", "eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6IjEwIn19")
+        .append_pair("ratio", "-0.5")
+        .append_pair("flag", "true");
+
+    assert_eq!(to_string(req).unwrap(), expected.finish());
+}
@@ -102,24 +102,30 @@
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>helix</code></span> | Enables [Helix](helix) endpoints |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>eventsub</code></span> | Enables deserializable structs for [EventSub](eventsub) |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>hmac</code></span> | Enable [message authentication](eventsub::Event::verify_payload) using HMAC on [EventSub](eventsub) |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>jwt</code></span> | Enables [signing JWTs](extensions::ExtensionJwt) for Twitch Extensions |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>base64</code></span> | Enables [decoding schedule segment ids](helix::schedule::StreamSegmentIdExt) |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>stream-parse</code></span> | Parses pages of list endpoints like [Get Chatters](helix::chat::GetChattersRequest) item by item when streamed with the [`HelixClient`] helpers, keeping fewer copies of large pages in memory |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>time</code></span> | Enable time utilities on [Timestamp](types::Timestamp) |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>config</code></span> | Enables [serde-friendly wrappers](config) for scope lists and channels in user configuration files |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>all</code></span> | Enables all above features. Do not use this in production, it's better if you specify exactly what you need |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>ureq</code></span> | Enables ureq for [`HttpClient`]. |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>surf</code></span> | Enables surf for [`HttpClient`]. Note that this does not enable any default client backend, if you get a compile error, specify `surf` in your `Cargo.toml`. By default, `surf` uses feature `curl-client` |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>reqwest</code></span> | Enables reqwest for [`HttpClient`]. Note that this does not enable any default TLS backend, if you get `invalid URL, scheme is not http`, specify `reqwest` in your Cargo.toml. By default, `reqwest` uses feature `default-tls` |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>tower</code></span> | Enables using [tower services](client::TowerService) for [`HttpClient`]. |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>schemars</code></span> | Implements [`JsonSchema`](schemars::JsonSchema) on the [EventSub](eventsub) payloads, conditions and transports, see [`eventsub::export_schemas`] |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>beta</code></span> | Enables beta endpoints, topics or features. Breakage may occur, semver compatibility not guaranteed. |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>unsupported</code></span> | Enables undocumented or experimental endpoints, including beta endpoints, topics or features. Breakage may occur, semver compatibility not guaranteed. |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>trace_unknown_fields</code></span> | Logs ignored fields as `WARN` log messages where  applicable. Please consider using this and filing an issue or PR when a new field has been added to the endpoint but not added to this library. |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>deny_unknown_fields</code></span> | Adds `#[serde(deny_unknown_fields)]` on all applicable structs/enums. Please consider using this and filing an issue or PR when a new field has been added to the endpoint but not added to this library. |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>deser_borrow</code></span> | Makes fields on [`Deserialize`](serde::Deserialize)-able structs borrow if they can be borrowed, this feature is enabled by default, but exists to enable using [`serde::de::DeserializeOwned`] or [`for<'de> serde::Deserialize<'de>`](serde::Deserialize) by disabling this feature. |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>automod_user_id</code></span> | Keeps the deprecated `user_id` on [`CheckAutoModStatusBody`](helix::moderation::CheckAutoModStatusBody), which Twitch no longer reads. It will be removed together with the field. |
 
 /// Doc test for README
 #[doc = include_str!("../README.md")]
 #[doc(hidden)]
 pub struct ReadmeDoctests;
 
-pub use twitch_types as types;
+pub mod types;
 
 #[cfg(feature = "helix")]
 pub mod helix;
@@ -134,6 +140,18 @@ pub mod pubsub;
 #[cfg(feature = "eventsub")]
 pub mod eventsub;
 
+#[cfg(feature = "jwt")]
+pub mod extensions;
+
+#[cfg(all(
+    feature = "helix",
+    feature = "client",
+    feature = "eventsub",
+    feature = "twitch_oauth2",
+    feature = "time"
+))]
+pub mod guide;
+
 #[cfg(all(feature = "helix", feature = "client"))]
 #[doc(inline)]
 pub use crate::helix::HelixClient;
@@ -141,6 +159,11 @@ pub use crate::helix::HelixClient;
 /// Extra types not defined in [`twitch_types`]
 pub mod extra;
 
+#[cfg(feature = "config")]
+pub mod config;
+
+pub mod prelude;
+
 #[cfg(any(feature = "twitch_oauth2", all(feature = "helix", feature = "client")))]
 #[doc(no_inline)]
 pub use twitch_oauth2;
@@ -359,7 +382,10 @@ where
 
 #[cfg(any(feature = "helix", feature = "eventsub"))]
 #[allow(dead_code)]
-/// Deserialize "" as <T as Default>::Default
+/// Deserialize "" or `null` as [`None`]
+///
+/// Use together with `#[serde(default)]` to also accept a missing field.
+/// Objects are passed through to `S`, so this also works for nested structs.
 fn deserialize_none_from_empty_string<'de, D, S>(deserializer: D) -> Result<Option<S>, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -372,7 +398,7 @@ where
         type Value = Option<S>;
 
         fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            formatter.write_str("any string")
+            formatter.write_str("any string, object or null")
         }
 
         fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
@@ -395,11 +421,67 @@ where
         where E: serde::de::Error {
             Ok(None)
         }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where E: serde::de::Error {
+            Ok(None)
+        }
+
+        fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+        where M: serde::de::MapAccess<'de> {
+            S::deserialize(serde::de::value::MapAccessDeserializer::new(map)).map(Some)
+        }
     }
 
     deserializer.deserialize_any(Inner(std::marker::PhantomData))
 }
 
+#[cfg(feature = "helix")]
+/// Deserialize a string as [`Cow::Borrowed`](std::borrow::Cow::Borrowed) when it can be borrowed from the input
+///
+/// Strings with escapes can't be borrowed and are deserialized as [`Cow::Owned`](std::borrow::Cow::Owned).
+/// Use together with `#[serde(borrow)]`.
+fn deserialize_borrowed_cow<'de: 'a, 'a, D, T>(
+    deserializer: D,
+) -> Result<std::borrow::Cow<'a, T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: ToOwned + ?Sized + 'a,
+    &'a T: From<&'a str>,
+    T::Owned: From<String>, {
+    use std::borrow::Cow;
+    struct Inner<'a, T: ?Sized>(std::marker::PhantomData<&'a T>);
+    impl<'de: 'a, 'a, T> serde::de::Visitor<'de> for Inner<'a, T>
+    where
+        T: ToOwned + ?Sized + 'a,
+        &'a T: From<&'a str>,
+        T::Owned: From<String>,
+    {
+        type Value = Cow<'a, T>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str("a string")
+        }
+
+        fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E>
+        where E: serde::de::Error {
+            Ok(Cow::Borrowed(value.into()))
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where E: serde::de::Error {
+            Ok(Cow::Owned(value.to_owned().into()))
+        }
+
+        fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+        where E: serde::de::Error {
+            Ok(Cow::Owned(value.into()))
+        }
+    }
+
+    deserializer.deserialize_str(Inner(std::marker::PhantomData))
+}
+
 /// Helper functions for tests
 #[cfg(test)]
 pub mod tests {
@@ -415,4 +497,362 @@ pub mod tests {
         )
         .expect("could not convert back from cbor");
     }
+
+    /// Checks that `val` serialized to JSON deserializes back to the same value.
+    ///
+    /// In pseudocode, this tests `deserialize(serialize(val)) == val`.
+    #[track_caller]
+    pub fn roundtrip_eq<T>(val: &T)
+    where T: serde::de::DeserializeOwned + serde::Serialize + PartialEq + std::fmt::Debug {
+        let json = serde_json::to_value(val).expect("could not make into json");
+        let back = serde_json::from_value::<T>(json.clone())
+            .unwrap_or_else(|e| panic!("could not convert back from json: {e}\n{json}"));
+        assert_eq!(&back, val, "value changed in roundtrip through {json}");
+    }
+
+    /// Generates a test named `$name` that parses each fixture, with its status, as the response of the request `$req`,
+    /// and checks that the data of the response roundtrips with [`roundtrip_eq`].
+    ///
+    /// ```ignore
+    /// #[cfg(test)]
+    /// crate::tests::roundtrip_test! {
+    ///     test_roundtrip: GetVipsRequest,
+    ///     200 => FIXTURE,
+    /// }
+    /// ```
+    macro_rules! roundtrip_test {
+        ($name:ident: $req:ty, $($status:literal => $fixture:expr),+ $(,)?) => {
+            #[test]
+            fn $name() {
+                #[allow(unused_imports)]
+                use $crate::helix::{
+                    RequestDelete as _, RequestGet as _, RequestPatch as _, RequestPost as _,
+                    RequestPut as _,
+                };
+                let uri = http::Uri::from_static("https://api.twitch.tv/helix/");
+                $(
+                    let body = ::std::convert::AsRef::<[u8]>::as_ref(&$fixture).to_vec();
+                    let http_response = http::Response::builder().status($status).body(body).unwrap();
+                    let response = <$req>::parse_response(None, &uri, http_response).unwrap();
+                    $crate::tests::roundtrip_eq(&response.data);
+                )+
+            }
+        };
+    }
+    pub(crate) use roundtrip_test;
+
+    /// Checks that `json` deserializes to `T` and serializes back to the same JSON.
+    ///
+    /// Objects are compared field by field, ignoring the order of fields. A field that is `null` on one side and missing on the other
+    /// is only accepted if its path is in `allow_null`. Paths are written like `/data/*/tiers/*/color`, with `*` for array indices.
+    #[track_caller]
+    pub fn roundtrip_fixture<T: serde::de::DeserializeOwned + serde::Serialize>(
+        json: &str,
+        allow_null: &[&str],
+    ) -> T {
+        let original: serde_json::Value =
+            serde_json::from_str(json).expect("fixture is not valid json");
+        let val: T = serde_json::from_value(original.clone()).expect("could not parse fixture");
+        let serialized = serde_json::to_value(&val).expect("could not make into json");
+
+        let mut differences = vec![];
+        json_differences(
+            &original,
+            &serialized,
+            &mut String::new(),
+            allow_null,
+            &mut differences,
+        );
+        assert!(
+            differences.is_empty(),
+            "fixture did not roundtrip:\n{}",
+            differences.join("\n")
+        );
+        val
+    }
+
+    /// The decoded query of `uri`, as `(key, value)` pairs in order.
+    ///
+    /// Panics if the query is not valid `application/x-www-form-urlencoded`, i.e. if it contains characters that should have been encoded.
+    #[track_caller]
+    pub fn query_pairs(uri: &http::Uri) -> Vec<(String, String)> {
+        let query = uri.query().unwrap_or_default();
+        assert!(
+            query
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"&=+%*-._".contains(&b)),
+            "query is not encoded: {query}"
+        );
+        url::form_urlencoded::parse(query.as_bytes())
+            .into_owned()
+            .collect()
+    }
+
+    fn json_differences(
+        original: &serde_json::Value,
+        serialized: &serde_json::Value,
+        path: &mut String,
+        allow_null: &[&str],
+        differences: &mut Vec<String>,
+    ) {
+        use serde_json::Value;
+
+        let len = path.len();
+        match (original, serialized) {
+            (Value::Object(original), Value::Object(serialized)) => {
+                for key in original.keys().chain(
+                    serialized
+                        .keys()
+                        .filter(|key| !original.contains_key(key.as_str())),
+                ) {
+                    path.push('/');
+                    path.push_str(key);
+                    match (original.get(key), serialized.get(key)) {
+                        (Some(original), Some(serialized)) => {
+                            json_differences(original, serialized, path, allow_null, differences)
+                        }
+                        (Some(Value::Null), None) | (None, Some(Value::Null))
+                            if allow_null.contains(&path.as_str()) => {}
+                        (Some(_), None) => differences.push(format!("{path}: dropped")),
+                        (None, Some(_)) => differences.push(format!("{path}: added")),
+                        (None, None) => unreachable!(),
+                    }
+                    path.truncate(len);
+                }
+            }
+            (Value::Array(original), Value::Array(serialized))
+                if original.len() == serialized.len() =>
+            {
+                path.push_str("/*");
+                for (original, serialized) in original.iter().zip(serialized) {
+                    json_differences(original, serialized, path, allow_null, differences);
+                }
+                path.truncate(len);
+            }
+            (original, serialized) if original == serialized => {}
+            (original, serialized) => {
+                differences.push(format!("{path}: expected {original}, got {serialized}"))
+            }
+        }
+    }
+
+    #[test]
+    fn fixture_differences() {
+        let differences = |original: &str, serialized: &str, allow_null: &[&str]| {
+            let mut differences = vec![];
+            json_differences(
+                &serde_json::from_str(original).unwrap(),
+                &serde_json::from_str(serialized).unwrap(),
+                &mut String::new(),
+                allow_null,
+                &mut differences,
+            );
+            differences
+        };
+
+        assert!(
+            differences(r#"{"a":1,"b":[{"c":2}]}"#, r#"{"b":[{"c":2}],"a":1}"#, &[]).is_empty()
+        );
+        assert_eq!(
+            differences(
+                r#"{"a":1,"b":[{"c":2}]}"#,
+                r#"{"B":[{"c":2}],"a":1.0}"#,
+                &[]
+            ),
+            ["/a: expected 1, got 1.0", "/b: dropped", "/B: added"]
+        );
+        assert_eq!(
+            differences(r#"[{"a":null},{}]"#, r#"[{},{"a":null}]"#, &[]),
+            ["/*/a: dropped", "/*/a: added"]
+        );
+        assert!(differences(r#"[{"a":null},{}]"#, r#"[{},{"a":null}]"#, &["/*/a"]).is_empty());
+        assert_eq!(
+            differences(r#"{"a":"1"}"#, r#"{}"#, &["/a"]),
+            ["/a: dropped"]
+        );
+    }
+
+    /// A [`HttpClient`](crate::HttpClient) that answers requests with queued responses.
+    ///
+    /// Responses are queued per path, relative to the helix base url, e.g. `moderation/banned`.
+    /// Other urls use their full path, e.g. `/oauth2/validate`.
+    #[cfg(feature = "client")]
+    #[derive(Debug, Default, Clone)]
+    pub struct MockClient {
+        #[allow(clippy::type_complexity)]
+        responses: std::sync::Arc<
+            std::sync::Mutex<std::collections::VecDeque<(String, crate::client::Response)>>,
+        >,
+        #[allow(clippy::type_complexity)]
+        requests:
+            std::sync::Arc<std::sync::Mutex<Vec<(http::Method, http::Uri, crate::client::Bytes)>>>,
+        delay: std::sync::Arc<std::sync::Mutex<std::time::Duration>>,
+        request_headers: std::sync::Arc<std::sync::Mutex<Vec<http::HeaderMap>>>,
+    }
+
+    /// Error of a request on the [`MockClient`]
+    #[cfg(feature = "client")]
+    #[derive(Debug, thiserror::Error)]
+    pub enum MockClientError {
+        /// No response was queued for the requested path
+        #[error("no response queued for {0}")]
+        NoResponse(String),
+        /// The [`RequestTimeout`](crate::client::RequestTimeout) of the request was shorter than the [delay](MockClient::delay)
+        #[error("request to {0} timed out after {1:?}")]
+        TimedOut(String, std::time::Duration),
+    }
+
+    #[cfg(feature = "client")]
+    impl MockClient {
+        /// Create a client without any queued responses
+        pub fn new() -> Self { Self::default() }
+
+        /// Queue a response for the next request to `path`
+        pub fn respond(&self, path: &str, status: u16, body: &str) -> &Self {
+            self.respond_with_headers(path, status, &[], body)
+        }
+
+        /// Queue a response with `headers` for the next request to `path`
+        pub fn respond_with_headers(
+            &self,
+            path: &str,
+            status: u16,
+            headers: &[(&str, &str)],
+            body: &str,
+        ) -> &Self {
+            let mut response = http::Response::builder().status(status);
+            for (name, value) in headers {
+                response = response.header(*name, *value);
+            }
+            let response = response
+                .body(body.as_bytes().to_vec().into())
+                .expect("invalid mock response");
+            self.responses
+                .lock()
+                .unwrap()
+                .push_back((path.to_owned(), response));
+            self
+        }
+
+        /// All requests made on this client, in order
+        pub fn requests(&self) -> Vec<(http::Method, http::Uri, crate::client::Bytes)> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        /// The headers of all requests made on this client, in the same order as [`requests`](MockClient::requests)
+        pub fn request_headers(&self) -> Vec<http::HeaderMap> {
+            self.request_headers.lock().unwrap().clone()
+        }
+
+        /// Take `delay` to answer every request, failing requests with a shorter [`RequestTimeout`](crate::client::RequestTimeout)
+        pub fn delay(&self, delay: std::time::Duration) -> &Self {
+            *self.delay.lock().unwrap() = delay;
+            self
+        }
+
+        /// Number of queued responses that have not been requested yet
+        pub fn pending(&self) -> usize { self.responses.lock().unwrap().len() }
+
+        /// Create a [`HelixClient`](crate::HelixClient) using this client
+        #[cfg(feature = "helix")]
+        pub fn helix(&self) -> crate::HelixClient<'static, Self> {
+            crate::HelixClient::with_client(self.clone())
+        }
+    }
+
+    #[cfg(feature = "client")]
+    impl crate::HttpClient for MockClient {
+        type Error = MockClientError;
+
+        fn req(
+            &self,
+            request: crate::client::Request,
+        ) -> crate::client::BoxedFuture<'_, Result<crate::client::Response, Self::Error>> {
+            let path = request
+                .uri()
+                .path()
+                .trim_start_matches("/helix/")
+                .to_owned();
+            let response = {
+                let mut responses = self.responses.lock().unwrap();
+                responses
+                    .iter()
+                    .position(|(p, _)| *p == path)
+                    .and_then(|i| responses.remove(i))
+                    .map(|(_, r)| r)
+            };
+            let delay = *self.delay.lock().unwrap();
+            let timeout = request
+                .extensions()
+                .get::<crate::client::RequestTimeout>()
+                .map(|t| t.0);
+            self.request_headers
+                .lock()
+                .unwrap()
+                .push(request.headers().clone());
+            self.requests.lock().unwrap().push((
+                request.method().clone(),
+                request.uri().clone(),
+                request.into_body(),
+            ));
+            Box::pin(async move {
+                match timeout {
+                    Some(timeout) if timeout < delay => {
+                        tokio::time::sleep(timeout).await;
+                        return Err(MockClientError::TimedOut(path, timeout));
+                    }
+                    _ if !delay.is_zero() => tokio::time::sleep(delay).await,
+                    _ => (),
+                }
+                response.ok_or(MockClientError::NoResponse(path))
+            })
+        }
+    }
+
+    /// A user token for `login` with id `id` that is never validated
+    #[cfg(feature = "twitch_oauth2")]
+    pub fn user_token(login: &str, id: &str) -> twitch_oauth2::UserToken {
+        twitch_oauth2::UserToken::from_existing_unchecked(
+            twitch_oauth2::AccessToken::new("mocktoken".to_string()),
+            None,
+            twitch_oauth2::ClientId::new("mockclientid".to_string()),
+            None,
+            login.into(),
+            id.into(),
+            None,
+            None,
+        )
+    }
+
+    /// An app access token that is never validated
+    #[cfg(feature = "twitch_oauth2")]
+    pub fn app_token() -> twitch_oauth2::AppAccessToken {
+        twitch_oauth2::AppAccessToken::from_existing_unchecked(
+            twitch_oauth2::AccessToken::new("mocktoken".to_string()),
+            None,
+            twitch_oauth2::ClientId::new("mockclientid".to_string()),
+            twitch_oauth2::ClientSecret::new("mockclientsecret".to_string()),
+            None,
+            None,
+        )
+    }
+
+    /// A user token like [`user_token`] with `scopes`
+    #[cfg(feature = "twitch_oauth2")]
+    pub fn user_token_with_scopes(
+        login: &str,
+        id: &str,
+        scopes: Vec<twitch_oauth2::Scope>,
+    ) -> twitch_oauth2::UserToken {
+        twitch_oauth2::UserToken::from_existing_unchecked(
+            twitch_oauth2::AccessToken::new("mocktoken".to_string()),
+            None,
+            twitch_oauth2::ClientId::new("mockclientid".to_string()),
+            None,
+            login.into(),
+            id.into(),
+            Some(scopes),
+            None,
+        )
+    }
 }
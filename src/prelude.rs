@@ -0,0 +1,113 @@
+//! Commonly used traits and types
+//!
+//! Glob-importing this module brings in the traits needed to call methods on [`HelixClient`](crate::HelixClient),
+//! create and parse requests manually and consume paginated streams, together with the most common [types] braids.
+//!
+//! Modules like [`helix`](crate::helix) and [`eventsub`](crate::eventsub) are re-exported as modules instead of glob-exporting
+//! their contents, as many names exist in more than one place, e.g [`helix::search::Channel`](crate::helix::search::Channel)
+//! and [`helix::channels::ChannelInformation`](crate::helix::channels::ChannelInformation).
+//!
+//! ```rust, no_run
+//! use twitch_api::prelude::*;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: HelixClient<'static, twitch_api::client::DummyHttpClient> = HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//!
+//! let users: Vec<helix::users::User> = client
+//!     .get_users_from_ids(&["1234", "4321"][..].into(), &token)
+//!     .try_collect()
+//!     .await?;
+//! let id: &UserIdRef = &users[0].id;
+//!
+//! let request = helix::channels::GetChannelInformationRequest::broadcaster_ids(&["1234"][..]);
+//! let uri = request.get_uri()?;
+//! # Ok(()) }
+//! ```
+
+#[doc(no_inline)]
+pub use crate::{extra, types};
+
+#[cfg(any(feature = "helix", feature = "pubsub", feature = "jwt"))]
+#[doc(no_inline)]
+pub use crate::types::{DisplayName, DisplayNameRef, UserId, UserIdRef, UserName, UserNameRef};
+
+#[cfg(any(feature = "helix", feature = "pubsub", feature = "eventsub"))]
+#[doc(no_inline)]
+pub use crate::types::{Timestamp, TimestampRef};
+
+#[cfg(feature = "helix")]
+#[doc(no_inline)]
+pub use crate::helix::{
+    self, Paginated, Request, RequestDelete, RequestGet, RequestPatch, RequestPost, RequestPut,
+};
+
+#[cfg(all(feature = "helix", feature = "client"))]
+#[doc(no_inline)]
+pub use crate::HelixClient;
+
+#[cfg(feature = "client")]
+#[doc(no_inline)]
+pub use crate::HttpClient;
+
+#[cfg(feature = "client")]
+#[doc(no_inline)]
+pub use futures::TryStreamExt;
+
+#[cfg(any(feature = "twitch_oauth2", all(feature = "helix", feature = "client")))]
+#[doc(no_inline)]
+pub use twitch_oauth2::{self, TwitchToken};
+
+#[cfg(feature = "eventsub")]
+#[doc(no_inline)]
+pub use crate::eventsub::{self, EventSubscription};
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::prelude::*;
+
+    #[cfg(feature = "helix")]
+    #[test]
+    fn request_traits() {
+        let request = helix::users::GetUsersRequest::ids(&["1234"][..]);
+        assert_eq!(
+            request.get_uri().unwrap().to_string(),
+            "https://api.twitch.tv/helix/users?id=1234"
+        );
+        let id: &UserIdRef = UserIdRef::from_static("1234");
+        assert_eq!(id.as_str(), "1234");
+    }
+
+    #[cfg(all(feature = "helix", feature = "client"))]
+    #[tokio::test]
+    async fn client_flow() {
+        async fn users<C: HttpClient + Sync, T: TwitchToken + Send + Sync + ?Sized>(
+            client: &HelixClient<'_, C>,
+            token: &T,
+        ) -> Vec<helix::users::User> {
+            client
+                .get_users_from_ids(&["1234"][..].into(), token)
+                .try_collect()
+                .await
+                .unwrap()
+        }
+
+        let mock = crate::tests::MockClient::new();
+        mock.respond("users", 200, r#"{"data":[]}"#);
+        let token = twitch_oauth2::UserToken::from_existing_unchecked(
+            twitch_oauth2::AccessToken::new("mocktoken".to_string()),
+            None,
+            twitch_oauth2::ClientId::new("mockclientid".to_string()),
+            None,
+            "twitchdev".into(),
+            "1234".into(),
+            None,
+            None,
+        );
+
+        assert!(users(&mock.helix(), &token).await.is_empty());
+        assert_eq!(mock.requests()[0].1.query(), Some("id=1234"));
+    }
+}
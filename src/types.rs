@@ -0,0 +1,191 @@
+//! Twitch related types
+//!
+//! Everything in [`twitch_types`], and types used by this crate that are not defined there yet.
+
+#[doc(inline)]
+pub use twitch_types::*;
+
+macro_rules! language_codes {
+    ($($(#[$meta:meta])* $variant:ident = $code:literal,)*) => {
+        /// A language of a channel or stream
+        ///
+        /// Known languages are [ISO 639-1](https://en.wikipedia.org/wiki/List_of_ISO_639-1_codes) codes, parsing is case-insensitive.
+        /// Codes that are not known are kept in [`LanguageCode::Other`].
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use twitch_api::types::LanguageCode;
+        ///
+        /// let language: LanguageCode = "EN".parse().unwrap();
+        /// assert_eq!(language, LanguageCode::En);
+        /// assert_eq!(language, "en");
+        /// assert_eq!(LanguageCode::from("zh-hk"), LanguageCode::Other("zh-hk".to_string()));
+        /// ```
+        #[derive(PartialEq, Eq, Hash, Debug, Clone)]
+        #[non_exhaustive]
+        pub enum LanguageCode {
+            $(
+                $(#[$meta])*
+                $variant,
+            )*
+            /// A language that is not in Twitch's list of languages, `other`
+            OtherLanguage,
+            /// A code that is not known, stored in lowercase
+            Other(String),
+        }
+
+        impl LanguageCode {
+            /// The code of this language, e.g. `en`
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(LanguageCode::$variant => $code,)*
+                    LanguageCode::OtherLanguage => "other",
+                    LanguageCode::Other(code) => code,
+                }
+            }
+        }
+
+        impl From<String> for LanguageCode {
+            fn from(code: String) -> Self {
+                let code = code.to_lowercase();
+                match code.as_str() {
+                    $($code => LanguageCode::$variant,)*
+                    "other" => LanguageCode::OtherLanguage,
+                    _ => LanguageCode::Other(code),
+                }
+            }
+        }
+    };
+}
+
+language_codes! {
+    /// Arabic
+    Ar = "ar",
+    /// Bulgarian
+    Bg = "bg",
+    /// Catalan
+    Ca = "ca",
+    /// Czech
+    Cs = "cs",
+    /// Danish
+    Da = "da",
+    /// German
+    De = "de",
+    /// Greek
+    El = "el",
+    /// English
+    En = "en",
+    /// Spanish
+    Es = "es",
+    /// Finnish
+    Fi = "fi",
+    /// French
+    Fr = "fr",
+    /// Hindi
+    Hi = "hi",
+    /// Hungarian
+    Hu = "hu",
+    /// Indonesian
+    Id = "id",
+    /// Italian
+    It = "it",
+    /// Japanese
+    Ja = "ja",
+    /// Korean
+    Ko = "ko",
+    /// Malay
+    Ms = "ms",
+    /// Dutch
+    Nl = "nl",
+    /// Norwegian
+    No = "no",
+    /// Polish
+    Pl = "pl",
+    /// Portuguese
+    Pt = "pt",
+    /// Romanian
+    Ro = "ro",
+    /// Russian
+    Ru = "ru",
+    /// Slovak
+    Sk = "sk",
+    /// Swedish
+    Sv = "sv",
+    /// Thai
+    Th = "th",
+    /// Tagalog
+    Tl = "tl",
+    /// Turkish
+    Tr = "tr",
+    /// Ukrainian
+    Uk = "uk",
+    /// Vietnamese
+    Vi = "vi",
+    /// Chinese
+    Zh = "zh",
+}
+
+impl From<&str> for LanguageCode {
+    fn from(code: &str) -> Self { Self::from(code.to_owned()) }
+}
+
+impl std::str::FromStr for LanguageCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> { Ok(Self::from(s)) }
+}
+
+impl std::fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::ops::Deref for LanguageCode {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target { self.as_str() }
+}
+
+impl PartialEq<str> for LanguageCode {
+    fn eq(&self, other: &str) -> bool { self.as_str() == other }
+}
+
+impl PartialEq<&str> for LanguageCode {
+    fn eq(&self, other: &&str) -> bool { self.as_str() == *other }
+}
+
+impl serde::Serialize for LanguageCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for LanguageCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_language_code() {
+    for code in ["en", "de", "zh", "other", "zh-hk", ""] {
+        let language = LanguageCode::from(code);
+        assert_eq!(language.to_string(), code);
+        let json = serde_json::to_string(&language).unwrap();
+        assert_eq!(
+            serde_json::from_str::<LanguageCode>(&json).unwrap(),
+            language
+        );
+    }
+    assert_eq!("DE".parse::<LanguageCode>().unwrap(), LanguageCode::De);
+    assert_eq!(LanguageCode::from("Other"), LanguageCode::OtherLanguage);
+    assert_eq!(
+        serde_json::from_str::<LanguageCode>(r#""zh-hk""#).unwrap(),
+        LanguageCode::Other("zh-hk".to_string())
+    );
+    assert!(LanguageCode::from("zh-HK") == "zh-hk");
+    assert!(LanguageCode::En.starts_with('e'));
+}